@@ -221,6 +221,9 @@ def collect_issues(root: Path) -> list[tuple[str, str, str]]:
             name = match.group(1)
             if name == "_" or has_c_release(name, "free", code, match.end()):
                 continue
+            if re.search(r"(?:->|\.)\s*$", code[:match.start(1)]):
+                # Stored into a struct field: the owning object releases it.
+                continue
             issue = (
                 format_location(base, path, match.start(), text),
                 "malloc_heap",
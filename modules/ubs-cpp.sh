@@ -65,7 +65,7 @@ OUTPUT_FILE=""
 FORMAT="text"          # text|json|sarif|counts
 CI_MODE=0
 FAIL_ON_WARNING=0
-INCLUDE_EXT="cpp,cc,cxx,cppm,mpp,ixx,h,hpp,hxx,hh,ipp,tpp"
+INCLUDE_EXT="c,cpp,cc,cxx,cppm,mpp,ixx,h,hpp,hxx,hh,ipp,tpp"
 QUIET=0
 NO_COLOR_FLAG=0
 EXTRA_EXCLUDES=""
@@ -396,6 +396,9 @@ run_search_raw() { "${GREP_RN[@]}" -e "$1" "${TARGETS[@]}" 2>/dev/null || true;
 run_search_raw_i() { "${GREP_RNI[@]}" -e "$1" "${TARGETS[@]}" 2>/dev/null || true; }
 search_count() { run_search_raw "$1" | count_lines; }
 search_count_i() { run_search_raw_i "$1" | count_lines; }
+# C++-idiom advice (nullptr, <cstdio>, std::format) does not apply to plain .c sources.
+run_search_cxx() { run_search_raw "$1" | grep -Ev '^[^:]*\.c:[0-9]+:' || true; }
+search_count_cxx() { run_search_cxx "$1" | count_lines; }
 search_files_for() { run_search_raw "$1" | cut -d: -f1 | sort -u || true; }
 search_show() { local p=$1; local n=${2:-$DETAIL_LIMIT}; run_search_raw "$p" | head -n "$n"; }
 
@@ -510,6 +513,18 @@ show_detailed_finding() {
   done || true
 }
 
+show_detailed_finding_cxx() {
+  local pattern=$1; local limit=${2:-$DETAIL_LIMIT}; local printed=0
+  run_search_cxx "$pattern" | head -n "$limit" | while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    [[ "$rawline" == *"ubs:ignore"* ]] && continue
+    parse_grep_line "$rawline" || continue
+    [[ -z "$PARSED_FILE" ]] && continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"; printed=$((printed+1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done || true
+}
+
 run_resource_lifecycle_checks() {
   print_subheader "Resource lifecycle correlation"
   local helper="$SCRIPT_DIR/helpers/resource_lifecycle_cpp.py"
@@ -608,6 +623,314 @@ run_async_error_checks() {
   fi
 }
 
+run_c_memory_safety_checks() {
+  print_subheader "C memory and format-string safety (use-after-free, leaks, size overflow, format strings)"
+  if ! command -v python3 >/dev/null 2>&1; then
+    print_finding "info" 0 "python3 not available" "Install python3 to enable C memory safety checks"
+    return
+  fi
+  local report mode count printed total=0
+  report=$(python3 - "$PROJECT_DIR" <<'PY'
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(sys.argv[1]).resolve()
+BASE_DIR = ROOT if ROOT.is_dir() else ROOT.parent
+SKIP_DIRS = {'.git', '.hg', '.svn', 'vendor', 'node_modules', '.cache', 'build', 'cmake-build-debug', 'cmake-build-release', 'dist', 'out'}
+EXTS = {'.c', '.cc', '.cpp', '.cxx', '.c++', '.h', '.hh', '.hpp', '.hxx', '.ipp', '.tpp', '.ixx', '.cppm', '.mpp'}
+MODES = ('uaf', 'leak', 'alloc', 'format')
+
+IDENT = r'[A-Za-z_][A-Za-z0-9_]*'
+FUNC_HEAD_RE = re.compile(r'^[A-Za-z_][^;{}()#]*?\b(?P<name>' + IDENT + r')\s*\((?P<params>[^;{}]*)\)\s*(?:const\s*)?\{', re.M)
+FREE_RE = re.compile(r'(?<![\w.>])free\s*\(\s*(?P<var>' + IDENT + r')\s*\)\s*;')
+ALLOC_ASSIGN_RE = re.compile(
+    r'(?:^|[;{}]\s*|\b(?:struct\s+)?' + IDENT + r'\s*\*+\s*)(?P<var>' + IDENT + r')\s*=\s*(?:\([^()]*\)\s*)?'
+    r'(?P<fn>malloc|calloc|realloc|strdup|strndup)\s*\(', re.M)
+ALLOC_CALL_RE = re.compile(r'(?<![\w.>])(?P<fn>malloc|realloc|reallocarray)\s*\(')
+SAFE_MUL_RE = re.compile(r'SIZE_MAX|max_size\s*\(|__builtin_mul_overflow|ckd_mul|safe_mul|checked_mul|mul_overflow')
+FORMAT_FUNCS = {'printf': 0, 'vprintf': 0, 'fprintf': 1, 'vfprintf': 1, 'dprintf': 1, 'sprintf': 1,
+                'vsprintf': 1, 'snprintf': 2, 'vsnprintf': 2, 'syslog': 1, 'err': 1, 'warn': 0, 'warnx': 0, 'errx': 1}
+FORMAT_CALL_RE = re.compile(r'(?<![\w.>:])(?P<fn>' + '|'.join(sorted(FORMAT_FUNCS, key=len, reverse=True)) + r')\s*\(')
+NON_OWNING_CALLS = {'memcpy', 'memmove', 'memset', 'memcmp', 'strcpy', 'strncpy', 'strcat', 'strncat', 'strlen',
+                    'strcmp', 'strncmp', 'strchr', 'strrchr', 'strstr', 'strtok', 'sprintf', 'snprintf', 'printf',
+                    'fprintf', 'puts', 'fputs', 'fgets', 'fread', 'fwrite', 'read', 'write', 'recv', 'send',
+                    'sizeof', 'assert', 'if', 'while', 'for', 'switch', 'return', 'free', 'realloc', 'toupper', 'tolower'}
+
+def should_skip(path: Path) -> bool:
+    return any(part in SKIP_DIRS for part in path.parts)
+
+def iter_files(root: Path):
+    if root.is_file():
+        if root.suffix.lower() in EXTS:
+            yield root
+        return
+    for path in root.rglob('*'):
+        if path.is_file() and path.suffix.lower() in EXTS and not should_skip(path):
+            yield path
+
+def mask_code(text: str, keep_strings: bool = False) -> str:
+    out = []
+    i = 0
+    n = len(text)
+    while i < n:
+        ch = text[i]
+        nxt = text[i + 1] if i + 1 < n else ''
+        if ch == '/' and nxt == '/':
+            while i < n and text[i] != '\n':
+                out.append(' ')
+                i += 1
+            continue
+        if ch == '/' and nxt == '*':
+            while i < n and not (text[i] == '*' and i + 1 < n and text[i + 1] == '/'):
+                out.append('\n' if text[i] == '\n' else ' ')
+                i += 1
+            out.append('  ')
+            i += 2
+            continue
+        if ch in ('"', "'"):
+            quote = ch
+            out.append(ch)
+            i += 1
+            while i < n and text[i] != quote and text[i] != '\n':
+                if text[i] == '\\':
+                    out.append('  ' if not keep_strings else text[i:i + 2])
+                    i += 2
+                    continue
+                out.append(text[i] if keep_strings else ' ')
+                i += 1
+            if i < n and text[i] == quote:
+                out.append(quote)
+                i += 1
+            continue
+        if ch == '#' and (i == 0 or text[i - 1] == '\n'):
+            while i < n and text[i] != '\n':
+                out.append(' ')
+                i += 1
+            continue
+        out.append(ch)
+        i += 1
+    return ''.join(out)
+
+def matching(text: str, open_idx: int, opener: str, closer: str) -> int:
+    depth = 0
+    for pos in range(open_idx, len(text)):
+        if text[pos] == opener:
+            depth += 1
+        elif text[pos] == closer:
+            depth -= 1
+            if depth == 0:
+                return pos
+    return len(text) - 1
+
+def split_args(arg_text: str):
+    args = []
+    depth = 0
+    current = []
+    for ch in arg_text:
+        if ch in '([{':
+            depth += 1
+        elif ch in ')]}':
+            depth -= 1
+        if ch == ',' and depth == 0:
+            args.append(''.join(current).strip())
+            current = []
+            continue
+        current.append(ch)
+    if current:
+        args.append(''.join(current).strip())
+    return args
+
+def line_of(text: str, pos: int) -> int:
+    return text.count('\n', 0, pos)
+
+def relpath(path: Path) -> str:
+    try:
+        return str(path.relative_to(BASE_DIR))
+    except ValueError:
+        return str(path)
+
+def functions(masked: str):
+    for head in FUNC_HEAD_RE.finditer(masked):
+        if head.group('name') in {'if', 'for', 'while', 'switch', 'catch', 'return', 'sizeof'}:
+            continue
+        open_idx = head.end() - 1
+        yield head, open_idx, matching(masked, open_idx, '{', '}')
+
+def uses(var: str):
+    escaped = re.escape(var)
+    return re.compile(
+        r'(?<![\w.>])(?:' + escaped + r'\s*(?:->|\[|\.)|\*\s*' + escaped + r'\b|' + escaped + r'\s*\)|'
+        r'(?<=[(,])\s*' + escaped + r'\s*[,)])'
+    )
+
+def reset(var: str):
+    return re.compile(r'(?<![\w.>])' + re.escape(var) + r'\s*=(?!=)')
+
+EXIT_RE = re.compile(r'\b(?:return|goto|break|continue|exit|abort|_exit)\b')
+
+def block_exit(text: str):
+    depth = 0
+    for match in re.finditer(r'[{}]|' + EXIT_RE.pattern, text):
+        token = match.group(0)
+        if token == '{':
+            depth += 1
+        elif token == '}':
+            if depth == 0:
+                return None
+            depth -= 1
+        elif depth == 0:
+            return match.end()
+    return None
+
+def scan_uaf(masked, lines, body_start, body, add):
+    for free in FREE_RE.finditer(body):
+        var = free.group('var')
+        rest = body[free.end():]
+        reassigned = reset(var).search(rest)
+        window = rest[:reassigned.start()] if reassigned else rest
+        # When the freeing block leaves (return/goto/break), only code up to that exit can see the pointer.
+        exit_pos = block_exit(window)
+        if exit_pos is not None:
+            window = window[:exit_pos]
+        again = FREE_RE.search(window)
+        use = uses(var).search(window)
+        hit = use
+        label = var + ' used after free()'
+        if again and again.group('var') == var and (not use or again.start() <= use.start()):
+            hit = again
+            label = var + ' freed twice'
+        if not hit:
+            continue
+        free_line = line_of(masked, body_start + free.start()) + 1
+        offset = hit.start() + max(0, hit.group(0).find(var))
+        idx = line_of(masked, body_start + free.end() + offset)
+        add('uaf', idx, label + ' (freed at line ' + str(free_line) + ')')
+
+def scan_leak(masked, lines, body_start, body, add):
+    for alloc in ALLOC_ASSIGN_RE.finditer(body):
+        var = alloc.group('var')
+        if alloc.group('fn') == 'realloc':
+            continue
+        rest = body[alloc.end():]
+        escaped = re.escape(var)
+        if re.search(r'(?<![\w.>])free\s*\(\s*' + escaped + r'\s*\)', rest):
+            continue
+        if re.search(r'\breturn\b[^;]*(?<![\w.>])' + escaped + r'\b', rest):
+            continue
+        if re.search(r'(?:->|\.|\*|\])\s*' + IDENT + r'?\s*=\s*' + escaped + r'\b|=\s*' + escaped + r'\s*;', rest):
+            continue
+        owned = False
+        for call in re.finditer(r'(?<![\w.>])(?P<fn>' + IDENT + r')\s*\(', rest):
+            close = matching(rest, call.end() - 1, '(', ')')
+            args = rest[call.end():close]
+            if call.group('fn') in NON_OWNING_CALLS:
+                continue
+            if re.search(r'(?<![\w.>])' + escaped + r'\b', args):
+                owned = True
+                break
+        if owned:
+            continue
+        idx = line_of(masked, body_start + alloc.start('var'))
+        add('leak', idx, var + ' from ' + alloc.group('fn') + '() never freed, returned, or stored')
+
+def scan_alloc(masked, lines, body_start, body, add):
+    for call in ALLOC_CALL_RE.finditer(body):
+        close = matching(body, call.end() - 1, '(', ')')
+        args = split_args(body[call.end():close])
+        size = args[-1] if call.group('fn') == 'realloc' and len(args) >= 2 else (args[0] if args else '')
+        if call.group('fn') == 'reallocarray':
+            continue
+        if '*' not in size:
+            continue
+        factors = [f.strip() for f in re.split(r'\*', size)]
+        dynamic = [f for f in factors if f and not re.fullmatch(r'\(?\s*(?:\d+[uUlL]*|sizeof\s*\(?[^)]*\)?|[A-Z][A-Z0-9_]*)\s*\)?', f)]
+        if not dynamic:
+            continue
+        prior = body[:call.start()]
+        if SAFE_MUL_RE.search(prior):
+            continue
+        names = [re.sub(r'^[\s(*&]+|[\s)]+$', '', f) for f in dynamic]
+        if any(name and re.search(r'(?<![\w.>])' + re.escape(name) + r'\s*[<>]=?[^;{]*/', prior) for name in names):
+            continue
+        idx = line_of(masked, body_start + call.start())
+        add('alloc', idx, size.strip() + ' can wrap before ' + call.group('fn') + '(); use calloc() or check against SIZE_MAX')
+
+def scan_format(masked_strings, masked, lines, body_start, body, add):
+    text = masked_strings[body_start:body_start + len(body)]
+    for call in FORMAT_CALL_RE.finditer(text):
+        close = matching(text, call.end() - 1, '(', ')')
+        args = split_args(text[call.end():close])
+        pos = FORMAT_FUNCS[call.group('fn')]
+        if len(args) <= pos:
+            continue
+        fmt = args[pos]
+        if not fmt or fmt.startswith('"') or re.fullmatch(r'[A-Z][A-Z0-9_]*', fmt) or fmt.startswith(('_(', 'gettext(', 'N_(')):
+            continue
+        if len(args) > pos + 1 and call.group('fn').startswith('v'):
+            continue
+        idx = line_of(masked, body_start + call.start())
+        add('format', idx, call.group('fn') + '() format argument ' + fmt + ' is not a string literal')
+
+def analyze(path: Path, issues):
+    try:
+        text = path.read_text(encoding='utf-8', errors='ignore')
+    except OSError:
+        return
+    masked = mask_code(text)
+    masked_strings = mask_code(text, keep_strings=True)
+    lines = text.split('\n')
+    seen = set()
+
+    def add(mode, idx, tag):
+        key = (mode, idx)
+        if key in seen or idx >= len(lines) or 'ubs:ignore' in lines[idx]:
+            return
+        seen.add(key)
+        issues[mode].append((relpath(path), idx + 1, lines[idx].strip().replace('\t', ' ') + '  [' + tag + ']'))
+
+    for _head, open_idx, close_idx in functions(masked):
+        body = masked[open_idx:close_idx + 1]
+        scan_uaf(masked, lines, open_idx, body, add)
+        scan_leak(masked, lines, open_idx, body, add)
+        scan_alloc(masked, lines, open_idx, body, add)
+        scan_format(masked_strings, masked, lines, open_idx, body, add)
+
+issues = {mode: [] for mode in MODES}
+for file_path in iter_files(ROOT):
+    analyze(file_path, issues)
+
+for mode in MODES:
+    print(f"__COUNT__\t{mode}\t{len(issues[mode])}")
+    for file_name, line_no, code in issues[mode][:25]:
+        print(f"__SAMPLE__\t{mode}\t{file_name}\t{line_no}\t{code}")
+PY
+  )
+  for mode in uaf leak alloc format; do
+    count=$(printf '%s\n' "$report" | awk -F'\t' -v m="$mode" '$1=="__COUNT__" && $2==m {print $3+0}')
+    count=${count:-0}
+    [[ "$count" -gt 0 ]] || continue
+    total=$((total + count))
+    case "$mode" in
+      uaf) print_finding "critical" "$count" "Pointer used or freed again after free()" "Set the pointer to NULL right after free() and stop using it; restructure ownership so exactly one path releases it" ;;
+      leak) print_finding "warning" "$count" "Heap allocation never freed on any path" "free() the buffer before every return, hand it to an owner, or return it to the caller" ;;
+      alloc) print_finding "warning" "$count" "malloc(n * size) multiplication can overflow" "Use calloc(n, size) or reallocarray(), or reject n > SIZE_MAX / size before multiplying" ;;
+      format) print_finding "critical" "$count" "Non-literal printf-family format string" "Pass a literal format such as \"%s\" and supply the data as an argument" ;;
+    esac
+    printed=0
+    while IFS=$'\t' read -r tag sample_mode sample_path sample_line sample_text; do
+      [[ "$tag" == "__SAMPLE__" && "$sample_mode" == "$mode" ]] || continue
+      if [[ "$printed" -lt "$DETAIL_LIMIT" && "$printed" -lt "$MAX_DETAILED" ]]; then
+        print_code_sample "$sample_path" "$sample_line" "$sample_text"
+        printed=$((printed + 1))
+      fi
+    done <<<"$report"
+  done
+  if [[ "$total" -eq 0 ]]; then
+    print_finding "good" "No use-after-free, leaked allocations, unchecked size multiplication, or non-literal format strings detected"
+  fi
+}
+
 run_archive_extraction_checks() {
   print_subheader "Archive extraction path traversal"
   if ! command -v python3 >/dev/null 2>&1; then
@@ -3009,7 +3332,7 @@ count=$(search_count "const_cast<|reinterpret_cast<")
 if [ "$count" -gt 0 ]; then print_finding "warning" "$count" "Dangerous casts present" "Verify lifetime/aliasing"; fi
 
 print_subheader "NULL used instead of nullptr"
-count=$(search_count "\\bNULL\\b")
+count=$(search_count_cxx "\\bNULL\\b")
 if [ "$count" -gt 0 ]; then print_finding "info" "$count" "Use nullptr in C++ code"; fi
 
 fi
@@ -3152,7 +3475,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if should_skip 7; then
 print_header "7. UNDEFINED BEHAVIOR RISK ZONE"
-print_category "Detects: dangerous casts, unsafe C APIs, security-sensitive non-crypto randomness, request path traversal, open redirects, response header injection, outbound URL SSRF, archive traversal, delete mismatch" \
+print_category "Detects: dangerous casts, unsafe C APIs, use-after-free, leaked mallocs, malloc(n*size) overflow, format strings, security-sensitive non-crypto randomness, request path traversal, open redirects, response header injection, outbound URL SSRF, archive traversal, delete mismatch" \
   "UB can pass tests and still crash in production"
 
 print_subheader "Dangerous functions (strcpy/gets/scanf/sprintf)"
@@ -3162,6 +3485,8 @@ if [ "$count" -gt 0 ]; then
   show_detailed_finding "\\b(gets|strcpy|strcat|sprintf|scanf)\\s*\\(" 5
 fi
 
+run_c_memory_safety_checks
+
 print_subheader "Shell command execution APIs"
 shell_exec_pattern="\\b(std::)?system\\s*\\(|\\bpopen\\s*\\(|\\bShellExecute(A|W)?\\s*\\("
 count=$(search_count "$shell_exec_pattern")
@@ -3210,7 +3535,7 @@ if [ "$count" -gt 0 ]; then
 fi
 
 print_subheader "C headers included in C++"
-count=$(search_count "#include[[:space:]]*<(stdio|stdlib|string|math)\\.h>")
+count=$(search_count_cxx "#include[[:space:]]*<(stdio|stdlib|string|math)\\.h>")
 if [ "$count" -gt 0 ]; then print_finding "info" "$count" "Prefer <cstdio>/<cstdlib>/<cstring>/<cmath>"; fi
 
 print_subheader "using namespace std in headers"
@@ -3251,10 +3576,10 @@ print_category "Detects: printf-family, dangerous scanf formats, fmt migration"
   "Type-safety and format correctness prevent latent crashes"
 
 print_subheader "printf/scanf/sprintf family usage"
-count=$(search_count "\\b(printf|fprintf|sprintf|snprintf|scanf|sscanf)\\s*\\(")
+count=$(search_count_cxx "\\b(printf|fprintf|sprintf|snprintf|scanf|sscanf)\\s*\\(")
 if [ "$count" -gt 0 ]; then
   print_finding "info" "$count" "C-format APIs in C++" "Prefer std::format/fmt for type-safe formatting"
-  show_detailed_finding "\\b(printf|fprintf|sprintf|snprintf|scanf|sscanf)\\s*\\(" 5
+  show_detailed_finding_cxx "\\b(printf|fprintf|sprintf|snprintf|scanf|sscanf)\\s*\\(" 5
 fi
 
 print_subheader "std::endl usage"
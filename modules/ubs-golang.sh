@@ -2773,6 +2773,522 @@ PY
 )
 }
 
+run_goroutine_leak_checks() {
+  print_subheader "Goroutines without a cancellation path"
+  if ! command -v python3 >/dev/null 2>&1; then
+    print_finding "info" 0 "python3 not available" "Install python3 to enable goroutine leak checks"
+    return
+  fi
+  local printed=0
+  while IFS=$'\t' read -r tag a b c; do
+    case "$tag" in
+      __COUNT__)
+        if [[ "$a" -gt 0 ]]; then
+          print_finding "warning" "$a" "Goroutine can leak: no cancellation or exit path" "Select on ctx.Done() (or a done channel) inside long-running goroutines, and buffer result channels (make(chan T, 1)) when the caller may stop waiting"
+        else
+          print_finding "good" "No leaking goroutine patterns detected"
+        fi
+        ;;
+      __SAMPLE__)
+        if [[ "$printed" -lt "$DETAIL_LIMIT" && "$printed" -lt "$MAX_DETAILED" ]]; then
+          print_code_sample "$a" "$b" "$c"
+          printed=$((printed + 1))
+        fi
+        ;;
+    esac
+  done < <(python3 - "$PROJECT_DIR" <<'PY'
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(sys.argv[1]).resolve()
+BASE_DIR = ROOT if ROOT.is_dir() else ROOT.parent
+SKIP_DIRS = {'.git', 'vendor', 'node_modules', '.cache', 'bin', 'build', 'dist'}
+
+def should_skip(path: Path) -> bool:
+    return any(part in SKIP_DIRS for part in path.parts)
+
+def iter_files(root: Path):
+    if root.is_file():
+        if root.suffix.lower() == '.go':
+            yield root
+        return
+    for path in root.rglob('*.go'):
+        if path.is_file() and not should_skip(path):
+            yield path
+
+def mask_code(text: str) -> str:
+    out = []
+    i = 0
+    n = len(text)
+    while i < n:
+        ch = text[i]
+        nxt = text[i + 1] if i + 1 < n else ''
+        if ch == '/' and nxt == '/':
+            while i < n and text[i] != '\n':
+                out.append(' ')
+                i += 1
+            continue
+        if ch == '/' and nxt == '*':
+            while i < n and not (text[i] == '*' and i + 1 < n and text[i + 1] == '/'):
+                out.append('\n' if text[i] == '\n' else ' ')
+                i += 1
+            out.append('  ')
+            i += 2
+            continue
+        if ch in ('"', "'", '`'):
+            quote = ch
+            out.append(ch)
+            i += 1
+            while i < n and text[i] != quote:
+                if text[i] == '\\' and quote != '`':
+                    out.append('  ')
+                    i += 2
+                    continue
+                out.append('\n' if text[i] == '\n' else ' ')
+                i += 1
+            if i < n:
+                out.append(quote)
+                i += 1
+            continue
+        out.append(ch)
+        i += 1
+    return ''.join(out)
+
+def matching_brace(text: str, open_idx: int) -> int:
+    depth = 0
+    for pos in range(open_idx, len(text)):
+        if text[pos] == '{':
+            depth += 1
+        elif text[pos] == '}':
+            depth -= 1
+            if depth == 0:
+                return pos
+    return len(text) - 1
+
+def line_of(text: str, pos: int) -> int:
+    return text.count('\n', 0, pos)
+
+def relpath(path: Path) -> str:
+    try:
+        return str(path.relative_to(BASE_DIR))
+    except ValueError:
+        return str(path)
+
+def add_issue(issues, seen, path: Path, lines, idx: int, tag: str):
+    key = (relpath(path), idx + 1)
+    if key in seen or 'ubs:ignore' in lines[idx]:
+        return
+    seen.add(key)
+    issues.append((relpath(path), idx + 1, lines[idx].strip().replace('\t', ' ') + '  [' + tag + ']'))
+
+GO_FUNC_RE = re.compile(r'\bgo\s+func\s*\([^)]*\)\s*\{')
+FUNC_RE = re.compile(r'^func\s*(?:\([^)]*\)\s*)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*\((?P<params>[^)]*)\)[^{\n]*\{', re.M)
+
+BARE_FOR_RE = re.compile(r'\bfor\s*\{')
+EXIT_RE = re.compile(r'\breturn\b|\bbreak\s+[A-Za-z_]|\bgoto\b|\.Done\(\)|\bos\.Exit\b|\blog\.Fatal')
+UNBUFFERED_RE = re.compile(r'\b(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*:?=\s*make\s*\(\s*chan\s+[^,)]+\)')
+SEND_RE = r'\b{name}\s*<-'
+TIMEOUT_SELECT_RE = re.compile(r'\bselect\s*\{[\s\S]*?(?:time\.After\s*\(|\.Done\(\))')
+
+def enclosing_function(masked: str, pos: int):
+    best = None
+    for match in FUNC_RE.finditer(masked):
+        if match.start() > pos:
+            break
+        close = matching_brace(masked, match.end() - 1)
+        if close >= pos:
+            best = (match.end() - 1, close)
+    return best
+
+def analyze(path: Path, issues):
+    try:
+        text = path.read_text(encoding='utf-8', errors='ignore')
+    except OSError:
+        return
+    masked = mask_code(text)
+    lines = text.splitlines()
+    seen = set()
+    for match in GO_FUNC_RE.finditer(masked):
+        open_idx = match.end() - 1
+        close_idx = matching_brace(masked, open_idx)
+        body = masked[open_idx:close_idx + 1]
+        go_line = line_of(masked, match.start())
+        loop = BARE_FOR_RE.search(body)
+        if loop and not EXIT_RE.search(body):
+            add_issue(issues, seen, path, lines, go_line, 'goroutine loops forever with no ctx.Done(), return, or labeled break')
+            continue
+        func = enclosing_function(masked, match.start())
+        if not func:
+            continue
+
+        before = masked[func[0]:match.start()]
+        after = masked[close_idx + 1:func[1] + 1]
+        for chan in UNBUFFERED_RE.finditer(before):
+            name = chan.group('name')
+            send = re.search(SEND_RE.format(name=re.escape(name)), body)
+            if not send:
+                continue
+            if re.search(r'\bselect\s*\{', body):
+                continue
+            guarded = TIMEOUT_SELECT_RE.search(after)
+            if not guarded or not re.search(r'<-\s*' + re.escape(name) + r'\b', after[guarded.start():]):
+                continue
+            send_line = line_of(masked, open_idx + send.start())
+            add_issue(issues, seen, path, lines, send_line, 'send on unbuffered ' + name + ' blocks forever once the caller stops waiting')
+            break
+
+
+issues = []
+for file_path in iter_files(ROOT):
+    analyze(file_path, issues)
+
+print(f"__COUNT__\t{len(issues)}")
+for file_name, line_no, code in issues[:25]:
+    print(f"__SAMPLE__\t{file_name}\t{line_no}\t{code}")
+PY
+  )
+}
+
+run_shared_map_race_checks() {
+  print_subheader "Shared maps written without synchronization"
+  if ! command -v python3 >/dev/null 2>&1; then
+    print_finding "info" 0 "python3 not available" "Install python3 to enable shared map race checks"
+    return
+  fi
+  local printed=0
+  while IFS=$'\t' read -r tag a b c; do
+    case "$tag" in
+      __COUNT__)
+        if [[ "$a" -gt 0 ]]; then
+          print_finding "critical" "$a" "Map written concurrently without a lock" "Guard shared maps with sync.Mutex/RWMutex, switch to sync.Map, or confine writes to one goroutine and communicate over channels; concurrent map writes are fatal at runtime"
+        else
+          print_finding "good" "No unsynchronized concurrent map writes detected"
+        fi
+        ;;
+      __SAMPLE__)
+        if [[ "$printed" -lt "$DETAIL_LIMIT" && "$printed" -lt "$MAX_DETAILED" ]]; then
+          print_code_sample "$a" "$b" "$c"
+          printed=$((printed + 1))
+        fi
+        ;;
+    esac
+  done < <(python3 - "$PROJECT_DIR" <<'PY'
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(sys.argv[1]).resolve()
+BASE_DIR = ROOT if ROOT.is_dir() else ROOT.parent
+SKIP_DIRS = {'.git', 'vendor', 'node_modules', '.cache', 'bin', 'build', 'dist'}
+
+def should_skip(path: Path) -> bool:
+    return any(part in SKIP_DIRS for part in path.parts)
+
+def iter_files(root: Path):
+    if root.is_file():
+        if root.suffix.lower() == '.go':
+            yield root
+        return
+    for path in root.rglob('*.go'):
+        if path.is_file() and not should_skip(path):
+            yield path
+
+def mask_code(text: str) -> str:
+    out = []
+    i = 0
+    n = len(text)
+    while i < n:
+        ch = text[i]
+        nxt = text[i + 1] if i + 1 < n else ''
+        if ch == '/' and nxt == '/':
+            while i < n and text[i] != '\n':
+                out.append(' ')
+                i += 1
+            continue
+        if ch == '/' and nxt == '*':
+            while i < n and not (text[i] == '*' and i + 1 < n and text[i + 1] == '/'):
+                out.append('\n' if text[i] == '\n' else ' ')
+                i += 1
+            out.append('  ')
+            i += 2
+            continue
+        if ch in ('"', "'", '`'):
+            quote = ch
+            out.append(ch)
+            i += 1
+            while i < n and text[i] != quote:
+                if text[i] == '\\' and quote != '`':
+                    out.append('  ')
+                    i += 2
+                    continue
+                out.append('\n' if text[i] == '\n' else ' ')
+                i += 1
+            if i < n:
+                out.append(quote)
+                i += 1
+            continue
+        out.append(ch)
+        i += 1
+    return ''.join(out)
+
+def matching_brace(text: str, open_idx: int) -> int:
+    depth = 0
+    for pos in range(open_idx, len(text)):
+        if text[pos] == '{':
+            depth += 1
+        elif text[pos] == '}':
+            depth -= 1
+            if depth == 0:
+                return pos
+    return len(text) - 1
+
+def line_of(text: str, pos: int) -> int:
+    return text.count('\n', 0, pos)
+
+def relpath(path: Path) -> str:
+    try:
+        return str(path.relative_to(BASE_DIR))
+    except ValueError:
+        return str(path)
+
+def add_issue(issues, seen, path: Path, lines, idx: int, tag: str):
+    key = (relpath(path), idx + 1)
+    if key in seen or 'ubs:ignore' in lines[idx]:
+        return
+    seen.add(key)
+    issues.append((relpath(path), idx + 1, lines[idx].strip().replace('\t', ' ') + '  [' + tag + ']'))
+
+GO_FUNC_RE = re.compile(r'\bgo\s+func\s*\([^)]*\)\s*\{')
+FUNC_RE = re.compile(r'^func\s*(?:\([^)]*\)\s*)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*\((?P<params>[^)]*)\)[^{\n]*\{', re.M)
+
+PKG_MAP_RE = re.compile(r'^var\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:=\s*(?:make\s*\(\s*)?map\[|map\[)', re.M)
+LOCAL_MAP_RE = re.compile(r'\b(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*:=\s*(?:make\s*\(\s*)?map\[')
+VAR_BLOCK_RE = re.compile(r'^var\s*\((?P<body>[\s\S]*?)^\)', re.M)
+BLOCK_MAP_RE = re.compile(r'^\s*(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:=\s*(?:make\s*\(\s*)?map\[|map\[)', re.M)
+HANDLER_PARAMS_RE = re.compile(r'\bhttp\.ResponseWriter\b[^)]*\*\s*http\.Request\b|\*\s*gin\.Context\b|\becho\.Context\b|\*\s*fiber\.Ctx\b')
+LOCK_RE = re.compile(r'\.(?:Lock|RLock)\s*\(\s*\)')
+
+def write_sites(body: str, name: str):
+    escaped = re.escape(name)
+    pattern = re.compile(
+        r'(?<![\w.])' + escaped + r'\s*\[[^\]\n]*\]\s*(?:[-+*/|&^]?=(?!=)|\+\+|--)'
+        r'|\bdelete\s*\(\s*' + escaped + r'\s*,'
+    )
+    return list(pattern.finditer(body))
+
+def shadowed(body: str, name: str) -> bool:
+    return bool(re.search(r'(?<![\w.])' + re.escape(name) + r'\s*:=|\bvar\s+' + re.escape(name) + r'\b', body))
+
+def first_unlocked_write(body: str, name: str):
+    for site in write_sites(body, name):
+        if not LOCK_RE.search(body[:site.start()]):
+            return site
+    return None
+
+def analyze(path: Path, issues):
+    try:
+        text = path.read_text(encoding='utf-8', errors='ignore')
+    except OSError:
+        return
+    masked = mask_code(text)
+    lines = text.splitlines()
+    seen = set()
+    package_maps = {m.group('name') for m in PKG_MAP_RE.finditer(masked)}
+    for block in VAR_BLOCK_RE.finditer(masked):
+        package_maps.update(m.group('name') for m in BLOCK_MAP_RE.finditer(block.group('body')))
+
+    for func in FUNC_RE.finditer(masked):
+        open_idx = func.end() - 1
+        close_idx = matching_brace(masked, open_idx)
+        func_body = masked[open_idx:close_idx + 1]
+        if HANDLER_PARAMS_RE.search(func.group('params')):
+            for name in sorted(package_maps):
+                if shadowed(func_body, name):
+                    continue
+                site = first_unlocked_write(func_body, name)
+                if site:
+                    add_issue(issues, seen, path, lines, line_of(masked, open_idx + site.start()),
+                              'package map ' + name + ' written from concurrent handler ' + func.group('name') + ' without a lock')
+        local_maps = {m.group('name') for m in LOCAL_MAP_RE.finditer(func_body)}
+        candidates = package_maps | local_maps
+        for go in GO_FUNC_RE.finditer(func_body):
+            go_open = go.end() - 1
+            go_close = matching_brace(func_body, go_open)
+            go_body = func_body[go_open:go_close + 1]
+            for name in sorted(candidates):
+                if shadowed(go_body, name):
+                    continue
+                site = first_unlocked_write(go_body, name)
+                if site:
+                    add_issue(issues, seen, path, lines, line_of(masked, open_idx + go_open + site.start()),
+                              'map ' + name + ' written inside a goroutine without a lock')
+
+issues = []
+for file_path in iter_files(ROOT):
+    analyze(file_path, issues)
+
+print(f"__COUNT__\t{len(issues)}")
+for file_name, line_no, code in issues[:25]:
+    print(f"__SAMPLE__\t{file_name}\t{line_no}\t{code}")
+PY
+  )
+}
+
+run_library_panic_checks() {
+  print_subheader "panic in library packages"
+  if ! command -v python3 >/dev/null 2>&1; then
+    print_finding "info" 0 "python3 not available" "Install python3 to enable library panic checks"
+    return
+  fi
+  local printed=0
+  while IFS=$'\t' read -r tag a b c; do
+    case "$tag" in
+      __COUNT__)
+        if [[ "$a" -gt 0 ]]; then
+          print_finding "warning" "$a" "panic in library package" "Return an error so callers can decide how to fail; reserve panic for Must* helpers, init(), and truly unreachable states"
+        else
+          print_finding "good" "No panic calls in library packages"
+        fi
+        ;;
+      __SAMPLE__)
+        if [[ "$printed" -lt "$DETAIL_LIMIT" && "$printed" -lt "$MAX_DETAILED" ]]; then
+          print_code_sample "$a" "$b" "$c"
+          printed=$((printed + 1))
+        fi
+        ;;
+    esac
+  done < <(python3 - "$PROJECT_DIR" <<'PY'
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(sys.argv[1]).resolve()
+BASE_DIR = ROOT if ROOT.is_dir() else ROOT.parent
+SKIP_DIRS = {'.git', 'vendor', 'node_modules', '.cache', 'bin', 'build', 'dist'}
+
+def should_skip(path: Path) -> bool:
+    return any(part in SKIP_DIRS for part in path.parts)
+
+def iter_files(root: Path):
+    if root.is_file():
+        if root.suffix.lower() == '.go':
+            yield root
+        return
+    for path in root.rglob('*.go'):
+        if path.is_file() and not should_skip(path):
+            yield path
+
+def mask_code(text: str) -> str:
+    out = []
+    i = 0
+    n = len(text)
+    while i < n:
+        ch = text[i]
+        nxt = text[i + 1] if i + 1 < n else ''
+        if ch == '/' and nxt == '/':
+            while i < n and text[i] != '\n':
+                out.append(' ')
+                i += 1
+            continue
+        if ch == '/' and nxt == '*':
+            while i < n and not (text[i] == '*' and i + 1 < n and text[i + 1] == '/'):
+                out.append('\n' if text[i] == '\n' else ' ')
+                i += 1
+            out.append('  ')
+            i += 2
+            continue
+        if ch in ('"', "'", '`'):
+            quote = ch
+            out.append(ch)
+            i += 1
+            while i < n and text[i] != quote:
+                if text[i] == '\\' and quote != '`':
+                    out.append('  ')
+                    i += 2
+                    continue
+                out.append('\n' if text[i] == '\n' else ' ')
+                i += 1
+            if i < n:
+                out.append(quote)
+                i += 1
+            continue
+        out.append(ch)
+        i += 1
+    return ''.join(out)
+
+def matching_brace(text: str, open_idx: int) -> int:
+    depth = 0
+    for pos in range(open_idx, len(text)):
+        if text[pos] == '{':
+            depth += 1
+        elif text[pos] == '}':
+            depth -= 1
+            if depth == 0:
+                return pos
+    return len(text) - 1
+
+def line_of(text: str, pos: int) -> int:
+    return text.count('\n', 0, pos)
+
+def relpath(path: Path) -> str:
+    try:
+        return str(path.relative_to(BASE_DIR))
+    except ValueError:
+        return str(path)
+
+def add_issue(issues, seen, path: Path, lines, idx: int, tag: str):
+    key = (relpath(path), idx + 1)
+    if key in seen or 'ubs:ignore' in lines[idx]:
+        return
+    seen.add(key)
+    issues.append((relpath(path), idx + 1, lines[idx].strip().replace('\t', ' ') + '  [' + tag + ']'))
+
+GO_FUNC_RE = re.compile(r'\bgo\s+func\s*\([^)]*\)\s*\{')
+FUNC_RE = re.compile(r'^func\s*(?:\([^)]*\)\s*)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*\((?P<params>[^)]*)\)[^{\n]*\{', re.M)
+
+PACKAGE_RE = re.compile(r'^package\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)', re.M)
+PANIC_RE = re.compile(r'(?<![\w.])panic\s*\(')
+EXEMPT_FUNC_RE = re.compile(r'^(?:init|[Mm]ust[A-Z0-9_]\w*|[Mm]ust)$')
+
+def analyze(path: Path, issues):
+    if path.name.endswith('_test.go'):
+        return
+    try:
+        text = path.read_text(encoding='utf-8', errors='ignore')
+    except OSError:
+        return
+    masked = mask_code(text)
+    package = PACKAGE_RE.search(masked)
+    if not package or package.group('name') == 'main':
+        return
+    lines = text.splitlines()
+    seen = set()
+    for func in FUNC_RE.finditer(masked):
+        if EXEMPT_FUNC_RE.match(func.group('name')):
+            continue
+        open_idx = func.end() - 1
+        close_idx = matching_brace(masked, open_idx)
+        body = masked[open_idx:close_idx + 1]
+        for call in PANIC_RE.finditer(body):
+            # Re-panicking a recovered value keeps the original failure intact.
+            if re.search(r'\brecover\s*\(\s*\)', body[:call.start()]):
+                continue
+            idx = line_of(masked, open_idx + call.start())
+            add_issue(issues, seen, path, lines, idx,
+                      'panic in library package ' + package.group('name') + ', func ' + func.group('name') + ' should return an error')
+
+issues = []
+for file_path in iter_files(ROOT):
+    analyze(file_path, issues)
+
+print(f"__COUNT__\t{len(issues)}")
+for file_name, line_no, code in issues[:25]:
+    print(f"__SAMPLE__\t{file_name}\t{line_no}\t{code}")
+PY
+  )
+}
+
 # Temporarily relax pipefail for grep-heavy scans
 begin_scan_section(){
   if [[ "$DISABLE_PIPEFAIL_DURING_SCAN" -eq 1 ]]; then set +o pipefail; fi
@@ -4941,7 +5457,7 @@ grep_count_scoped_i() {
 # ═══════════════════════════════════════════════════════════════════════════
 if should_skip 1; then
 print_header "1. CONCURRENCY & GOROUTINE SAFETY"
-print_category "Detects: goroutines in loops, WaitGroup imbalance, manual lock/unlock, tickers not stopped" \
+print_category "Detects: goroutines in loops, WaitGroup imbalance, manual lock/unlock, tickers not stopped, goroutine leaks, unsynchronized map writes" \
   "Race-prone constructs and lifecycle mistakes cause leaks and deadlocks"
 
 print_subheader "Goroutines launched"
@@ -4989,6 +5505,8 @@ print_subheader "time.NewTicker without Stop"
 count=$([[ "$HAS_AST_GREP" -eq 1 && -f "$AST_JSON" ]] && ast_count "go.resource.ticker-no-stop" || echo 0)
 if [ "$count" -gt 0 ]; then print_finding "warning" "$count" "Ticker created without Stop (AST)"; fi
 
+run_goroutine_leak_checks
+run_shared_map_race_checks
 run_async_error_checks
 fi
 
@@ -5295,6 +5813,7 @@ if [ "$count" -gt 0 ]; then print_finding "info" "$count" "err shadowed via :=;
 print_subheader "panic usage"
 panic_count=$([[ "$HAS_AST_GREP" -eq 1 && -f "$AST_JSON" ]] && ast_count "go.panic-call" || echo 0)
 if [ "$panic_count" -gt 0 ]; then print_finding "warning" "$panic_count" "panic used; prefer errors in libraries"; fi
+run_library_panic_checks
 
 print_subheader "recover outside deferred func"
 rec_count=$([[ "$HAS_AST_GREP" -eq 1 && -f "$AST_JSON" ]] && ast_count "go.recover-not-in-defer" || echo 0)
@@ -2263,6 +2263,358 @@ PY
 )
 }
 
+run_jvm_core_rule_checks() {
+  local mode="$1"
+  local subheader title remediation good
+  case "$mode" in
+    swallow)
+      subheader="Swallowed exceptions (empty catch blocks)"
+      title="Exception caught and silently discarded"
+      remediation="Log, rethrow, or translate the exception; name the variable 'ignored' and add a comment when dropping it is intentional"
+      good="No empty catch blocks detected"
+      ;;
+    dateformat)
+      subheader="SimpleDateFormat shared across threads"
+      title="Shared SimpleDateFormat/DateFormat instance"
+      remediation="SimpleDateFormat is mutable and not thread-safe; use java.time.DateTimeFormatter, a ThreadLocal, or a per-call instance"
+      good="No static or singleton SimpleDateFormat fields detected"
+      ;;
+    closeable)
+      subheader="Kotlin closeables without use { }"
+      title="Kotlin Closeable opened without use { }"
+      remediation="Wrap streams, readers, sockets, and JDBC statements in .use { } so they close on every path"
+      good="Kotlin closeables appear scoped with use { }"
+      ;;
+    sql)
+      subheader="SQL built with string templates or String.format"
+      title="SQL assembled from string templates or format placeholders"
+      remediation="Bind values with PreparedStatement placeholders (?) instead of interpolating them into the query text"
+      good="No templated or formatted SQL strings detected"
+      ;;
+    *) return 0 ;;
+  esac
+  print_subheader "$subheader"
+  if ! command -v python3 >/dev/null 2>&1; then
+    print_finding "info" 0 "python3 not available" "Install python3 to enable JVM rule pack checks"
+    return
+  fi
+  local printed=0
+  while IFS=$'\t' read -r tag a b c; do
+    case "$tag" in
+      __COUNT__)
+        if [[ "$a" -gt 0 ]]; then
+          print_finding "warning" "$a" "$title" "$remediation"
+        else
+          print_finding "good" "$good"
+        fi
+        ;;
+      __SAMPLE__)
+        if [[ "$printed" -lt "$DETAIL_LIMIT" && "$printed" -lt "$MAX_DETAILED" ]]; then
+          print_code_sample "$a" "$b" "$c"
+          printed=$((printed + 1))
+        fi
+        ;;
+    esac
+  done < <(python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(sys.argv[1]).resolve()
+MODE = sys.argv[2] if len(sys.argv) > 2 else ''
+BASE_DIR = ROOT if ROOT.is_dir() else ROOT.parent
+SKIP_DIRS = {'.git', '.gradle', '.mvn', 'build', 'target', 'out', 'node_modules', '.cache'}
+EXTS = {'.java', '.kt', '.kts'}
+IGNORED_CATCH_NAMES = {'ignored', 'ignore', 'expected', 'unused', '_'}
+SINGLETON_MARKERS = re.compile(
+    r'@(?:Service|Component|Controller|RestController|Repository|Singleton|ApplicationScoped|Configuration)\b'
+    r'|\bextends\s+(?:Http|Generic)?Servlet\b|:\s*(?:Http)?Servlet\s*\('
+)
+TYPE_HEADER_RE = re.compile(r'\b(?:class|interface|enum|record)\s+[A-Za-z_$]')
+OBJECT_HEADER_RE = re.compile(r'(?:^|[\s;}])(?:companion\s+)?object\b')
+DATE_FORMAT_INIT_RE = re.compile(
+    r'\b(?:new\s+)?(?:java\.text\.)?SimpleDateFormat\s*\('
+    r'|\b(?:java\.text\.)?DateFormat\s*\.\s*get(?:Date|Time|DateTime)?Instance\s*\('
+)
+DATE_FORMAT_DECL_RE = re.compile(
+    r'(?:^|[;{}]\s*|\n\s*)(?P<mods>(?:(?:@[\w.]+(?:\([^)]*\))?|public|protected|private|internal|static|final|'
+    r'const|lateinit|override)\s+)*)(?:(?:val|var)\s+(?P<kname>[A-Za-z_$][\w$]*)(?:\s*:\s*[\w.<>?]+)?'
+    r'|(?:java\.text\.)?(?:Simple)?DateFormat\s+(?P<jname>[A-Za-z_$][\w$]*))\s*=\s*(?P<rhs>[^;\n]*)'
+)
+CATCH_RE = re.compile(r'\bcatch\s*\(')
+KOTLIN_CLOSEABLE_RE = re.compile(
+    r'^\s*(?:val|var)\s+(?P<name>[A-Za-z_][\w]*)(?:\s*:\s*[\w.<>?]+)?\s*=\s*(?P<rhs>'
+    r'(?:java\.io\.|java\.util\.|java\.net\.|java\.util\.zip\.)?'
+    r'(?:FileInputStream|FileOutputStream|FileReader|FileWriter|BufferedReader|BufferedWriter|'
+    r'InputStreamReader|OutputStreamWriter|PrintWriter|Scanner|RandomAccessFile|Socket|ServerSocket|ZipFile|JarFile)\s*\('
+    r'|[^\n]*\.(?:bufferedReader|bufferedWriter|reader|writer|inputStream|outputStream|openStream|'
+    r'getConnection|prepareStatement|createStatement|newInputStream|newOutputStream|newBufferedReader|newBufferedWriter)\s*\(.*)'
+)
+SQL_TEXT_RE = re.compile(
+    r'(?is)\bselect\b.+\bfrom\b|\binsert\s+into\b|\bupdate\s+[\w.`"]+\s+set\b|\bdelete\s+from\b'
+    r'|\bwhere\s+\w+\s*(?:=|\blike\b|\bin\b)'
+)
+SQL_SHAPE_RE = re.compile(r'\b(?:SELECT|INSERT|UPDATE|DELETE|WHERE)\b|(?i:\bwhere\b|\bvalues\s*\()')
+KOTLIN_TEMPLATE_RE = re.compile(r'(?<!\\)\$(?:\{[^}]*\}|[A-Za-z_][\w]*)')
+FORMAT_PLACEHOLDER_RE = re.compile(r'%(?:\d+\$)?[-#+ 0,(]*\d*(?:\.\d+)?[sdf]')
+
+
+def iter_files(root):
+    if root.is_file():
+        if root.suffix.lower() in EXTS:
+            yield root
+        return
+    for path in root.rglob('*'):
+        if not path.is_file() or path.suffix.lower() not in EXTS:
+            continue
+        if any(part in SKIP_DIRS for part in path.relative_to(root).parts):
+            continue
+        yield path
+
+
+def relpath(path):
+    try:
+        return path.relative_to(BASE_DIR).as_posix()
+    except ValueError:
+        return path.as_posix()
+
+
+def mask(text, keep_comments=False):
+    out = list(text)
+    i = 0
+    n = len(text)
+    while i < n:
+        ch = text[i]
+        nxt = text[i + 1] if i + 1 < n else ''
+        if ch == '/' and nxt == '/':
+            end = text.find('\n', i)
+            end = n if end == -1 else end
+            if not keep_comments:
+                for j in range(i, end):
+                    out[j] = ' '
+            i = end
+            continue
+        if ch == '/' and nxt == '*':
+            end = text.find('*/', i + 2)
+            end = n if end == -1 else end + 2
+            if not keep_comments:
+                for j in range(i, end):
+                    if out[j] != '\n':
+                        out[j] = ' '
+            i = end
+            continue
+        if text.startswith('"""', i):
+            end = text.find('"""', i + 3)
+            end = n if end == -1 else end + 3
+            for j in range(i + 3, max(i + 3, end - 3)):
+                if out[j] != '\n':
+                    out[j] = ' '
+            i = end
+            continue
+        if ch in ('"', "'"):
+            j = i + 1
+            while j < n and text[j] != ch and text[j] != '\n':
+                if text[j] == '\\':
+                    out[j] = ' '
+                    j += 1
+                    if j < n and text[j] != '\n':
+                        out[j] = ' '
+                    j += 1
+                    continue
+                out[j] = ' '
+                j += 1
+            i = j + 1
+            continue
+        i += 1
+    return ''.join(out)
+
+
+def line_of(text, offset):
+    return text.count('\n', 0, offset) + 1
+
+
+def match_close(text, start, open_ch, close_ch):
+    depth = 0
+    for idx in range(start, len(text)):
+        if text[idx] == open_ch:
+            depth += 1
+        elif text[idx] == close_ch:
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def source_line(lines, line_no):
+    if 1 <= line_no <= len(lines):
+        return lines[line_no - 1].strip()
+    return ''
+
+
+def has_ignore(lines, line_no):
+    for idx in (line_no - 1, line_no - 2):
+        if 0 <= idx < len(lines) and 'ubs:ignore' in lines[idx]:
+            return True
+    return False
+
+
+def swallowed_catches(path, text, code, commented, lines, issues):
+    for match in CATCH_RE.finditer(code):
+        paren = match.end() - 1
+        close = match_close(code, paren, '(', ')')
+        if close == -1:
+            continue
+        param = code[paren + 1:close].strip()
+        rest = code[close + 1:]
+        stripped = rest.lstrip()
+        if not stripped.startswith('{'):
+            continue
+        body_start = close + 1 + (len(rest) - len(stripped))
+        body_end = match_close(code, body_start, '{', '}')
+        if body_end == -1:
+            continue
+        if code[body_start + 1:body_end].strip():
+            continue
+        if commented[body_start + 1:body_end].strip():
+            continue
+        if ':' in param:
+            name = param.split(':', 1)[0].strip()
+        else:
+            name = param.replace('final ', '').split()[-1] if param.split() else ''
+        if name in IGNORED_CATCH_NAMES or name.lower().startswith('ignored'):
+            continue
+        line_no = line_of(code, match.start())
+        if has_ignore(lines, line_no):
+            continue
+        exc = param.split(':', 1)[1].strip() if ':' in param else ' '.join(param.replace('final ', '').split()[:-1])
+        issues.append((relpath(path), line_no, f"{source_line(lines, line_no)}  [catch ({exc or param}) discards the exception]"))
+
+
+def scope_kinds(code):
+    kinds = []
+    stack = []
+    last = 0
+    for idx, ch in enumerate(code):
+        if ch == '{':
+            header = code[last:idx]
+            if TYPE_HEADER_RE.search(header):
+                kind = 'singleton-type' if SINGLETON_MARKERS.search(header) else 'type'
+            elif OBJECT_HEADER_RE.search(header) and not re.search(r'\bfun\b|=', header.split('object', 1)[0][-40:]):
+                kind = 'object'
+            else:
+                kind = 'block'
+            stack.append(kind)
+            last = idx + 1
+        elif ch == '}':
+            if stack:
+                stack.pop()
+            last = idx + 1
+        elif ch == ';':
+            last = idx + 1
+        kinds.append(tuple(stack))
+    return kinds
+
+
+def shared_date_formats(path, text, code, lines, issues):
+    if 'DateFormat' not in code:
+        return
+    kinds = scope_kinds(code)
+    kotlin = path.suffix.lower() in {'.kt', '.kts'}
+    for match in DATE_FORMAT_DECL_RE.finditer(code):
+        rhs = match.group('rhs')
+        if not DATE_FORMAT_INIT_RE.search(rhs) or 'ThreadLocal' in rhs:
+            continue
+        name = match.group('kname') or match.group('jname')
+        offset = match.start('rhs')
+        stack = kinds[offset] if offset < len(kinds) else ()
+        mods = match.group('mods') or ''
+        shared = False
+        if not stack:
+            shared = kotlin and bool(match.group('kname'))
+        elif stack[-1] == 'object':
+            shared = True
+        elif stack[-1] in {'type', 'singleton-type'}:
+            shared = 'static' in mods.split() or stack[-1] == 'singleton-type'
+        if not shared:
+            continue
+        line_no = line_of(code, match.start('rhs'))
+        if has_ignore(lines, line_no):
+            continue
+        issues.append((relpath(path), line_no, f"{source_line(lines, line_no)}  [{name} is a DateFormat shared across threads]"))
+
+
+def kotlin_unclosed_resources(path, text, code, lines, issues):
+    if path.suffix.lower() not in {'.kt', '.kts'}:
+        return
+    code_lines = code.split('\n')
+    for idx, line in enumerate(code_lines, start=1):
+        match = KOTLIN_CLOSEABLE_RE.match(line)
+        if not match:
+            continue
+        rhs = match.group('rhs')
+        if re.search(r'\.use\s*\{|\.useLines\s*\{', rhs):
+            continue
+        name = match.group('name')
+        rest = '\n'.join(code_lines[idx - 1:])
+        if re.search(rf'\b{re.escape(name)}\s*\.\s*(?:use|useLines|close)\b', rest):
+            continue
+        if re.search(rf'\breturn\s+{re.escape(name)}\s*$|=\s*{re.escape(name)}\s*$|\b(?:apply|also)\s*\{{[^}}]*\b{re.escape(name)}\b', rest, re.M):
+            continue
+        if has_ignore(lines, idx):
+            continue
+        issues.append((relpath(path), idx, f"{source_line(lines, idx)}  [{name} is never closed; wrap it in .use {{ }}]"))
+
+
+def templated_sql(path, text, code, lines, issues):
+    kotlin = path.suffix.lower() in {'.kt', '.kts'}
+    for match in re.finditer(r'"""(.*?)"""|"((?:[^"\\\n]|\\.)*)"', text, re.S):
+        literal = match.group(1) if match.group(1) is not None else match.group(2)
+        if code[match.start()] != '"' or not SQL_TEXT_RE.search(literal) or not SQL_SHAPE_RE.search(literal):
+            continue
+        reason = ''
+        if kotlin and KOTLIN_TEMPLATE_RE.search(literal):
+            reason = 'SQL string template interpolates ' + KOTLIN_TEMPLATE_RE.search(literal).group(0)
+        elif FORMAT_PLACEHOLDER_RE.search(literal):
+            before = code[max(0, match.start() - 40):match.start()]
+            after = code[match.end():match.end() + 20]
+            if re.search(r'\bString\s*\.\s*format\s*\(\s*(?:Locale\.\w+\s*,\s*)?$', before) or re.match(r'\s*\.\s*(?:formatted|format)\s*\(', after):
+                reason = 'SQL assembled with a format placeholder'
+        if not reason:
+            continue
+        line_no = line_of(text, match.start())
+        if has_ignore(lines, line_no):
+            continue
+        issues.append((relpath(path), line_no, f"{source_line(lines, line_no)}  [{reason}]"))
+
+
+CHECKS = {
+    'swallow': swallowed_catches,
+    'dateformat': shared_date_formats,
+    'closeable': kotlin_unclosed_resources,
+    'sql': templated_sql,
+}
+
+issues = []
+check = CHECKS.get(MODE)
+if check is not None:
+    for file_path in iter_files(ROOT):
+        try:
+            text = file_path.read_text(encoding='utf-8', errors='ignore')
+        except OSError:
+            continue
+        lines = text.split('\n')
+        code = mask(text)
+        if check is swallowed_catches:
+            check(file_path, text, code, mask(text, keep_comments=True), lines, issues)
+        else:
+            check(file_path, text, code, lines, issues)
+print(f"__COUNT__\t{len(issues)}")
+for file_name, line_no, code_text in issues[:25]:
+    print(f"__SAMPLE__\t{file_name}\t{line_no}\t{code_text}")
+PY
+)
+}
+
 run_kotlin_type_narrowing_checks() {
   if [[ "$HAS_KOTLIN_FILES" -ne 1 ]]; then
     return 0
@@ -3485,7 +3837,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if should_run 3; then
 print_header "3. CONCURRENCY & THREADING"
-print_category "Detects: synchronized(this), Thread.start, newCachedThreadPool, sleep in synchronized, notify()" \
+print_category "Detects: synchronized(this), Thread.start, newCachedThreadPool, sleep in synchronized, notify(), shared SimpleDateFormat" \
   "Concurrency misuse leads to deadlocks and performance issues"
 
 print_subheader "synchronized(this) blocks"
@@ -3508,6 +3860,8 @@ print_subheader "notify() usage"
 notify_count=$(( $(ast_search '$O.notify()' || echo 0) + $("${GREP_RN[@]}" -e "\.notify\(\)" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
 if [ "$notify_count" -gt 0 ]; then print_finding "info" "$notify_count" "notify() calls detected - ensure correct semantics"; fi
 
+run_jvm_core_rule_checks dateformat
+
 run_async_error_checks
 fi
 
@@ -3575,7 +3929,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if should_run 5; then
 print_header "5. I/O & RESOURCES"
-print_category "Detects: missing charset, blocking reads in loops, delete() unchecked" \
+print_category "Detects: missing charset, blocking reads in loops, delete() unchecked, Kotlin closeables without use { }" \
   "I/O patterns that cause correctness or performance issues"
 
 print_subheader "InputStreamReader without charset"
@@ -3599,6 +3953,8 @@ if [ "$twr_candidates" -gt 0 ]; then
 else
   print_finding "good" "Closeable resources appear wrapped in try-with-resources"
 fi
+
+run_jvm_core_rule_checks closeable
 fi
 
 # ═══════════════════════════════════════════════════════════════════════════
@@ -3606,7 +3962,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if should_run 6; then
 print_header "6. LOGGING & DEBUGGING"
-print_category "Detects: System.out/err.println, printStackTrace, TODO/FIXME/HACK markers" \
+print_category "Detects: System.out/err.println, printStackTrace, swallowed exceptions, TODO/FIXME/HACK markers" \
   "Debug code left in production affects performance and leaks info"
 
 print_subheader "System.out/err.println"
@@ -3617,6 +3973,8 @@ print_subheader "printStackTrace calls"
 pst_cnt=$(( $(ast_search '$E.printStackTrace()' || echo 0) + $("${GREP_RN[@]}" -e "\.printStackTrace\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
 if [ "$pst_cnt" -gt 0 ]; then print_finding "warning" "$pst_cnt" "printStackTrace leaks details"; fi
 
+run_jvm_core_rule_checks swallow
+
 print_subheader "Technical debt markers"
 todo_count=$("${GREP_RNI[@]}" "TODO" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
 fixme_count=$("${GREP_RNI[@]}" "FIXME" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
@@ -3763,7 +4121,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if should_run 13; then
 print_header "13. SQL CONSTRUCTION (HEURISTICS)"
-print_category "Detects: string-concatenated SQL, Statement.executeQuery with + operator" \
+print_category "Detects: string-concatenated SQL, Statement.executeQuery with + operator, templated/formatted SQL" \
   "Prefer prepared statements with parameters to avoid injection"
 
 print_subheader "String-concatenated SQL"
@@ -3790,6 +4148,8 @@ elif [ "$sql_concat" -eq 0 ]; then
     print_finding "warning" "$sql_fallback" "SQL built via concatenation - prefer parameters" "$sql_desc"
   fi
 fi
+
+run_jvm_core_rule_checks sql
 fi
 
 # ═══════════════════════════════════════════════════════════════════════════
@@ -5192,6 +5192,121 @@ if [ "$async_promise_executor_count" -gt 0 ]; then
   done <<<"$async_promise_executor_samples"
 fi
 
+print_subheader "async function calls missing await"
+async_missing_await_report=$(python3 - "$PROJECT_DIR" <<'PY' 2>/dev/null
+import os
+import re
+import sys
+from pathlib import Path
+
+root = Path(sys.argv[1]).resolve()
+exts = {'.js', '.jsx', '.ts', '.tsx', '.mjs', '.cjs'}
+skip_dirs = {'.git', 'node_modules', 'dist', 'build', 'coverage', '.next', '.cache', '.turbo'}
+
+async_function_re = re.compile(r'\basync\s+function\s*\*?\s*([A-Za-z_$][\w$]*)')
+async_arrow_re = re.compile(r'\b(?:const|let|var)\s+([A-Za-z_$][\w$]*)\s*(?::[^=]+)?=\s*async\b')
+async_method_re = re.compile(r'^\s*(?:(?:public|private|protected|static|override|readonly)\s+)*async\s+\*?\s*([A-Za-z_$][\w$]*)\s*[<(]')
+statement_end_re = re.compile(r'[;{}]\s*$')
+
+def strip_strings(text):
+    return re.sub(r'"(?:\\.|[^"\\])*"|\'(?:\\.|[^\'\\])*\'|`(?:\\.|[^`\\])*`', '""', text)
+
+issues = []
+if root.is_file():
+    candidates = [root]
+    sample_root = root.parent
+else:
+    candidates = []
+    sample_root = root
+    for dirpath, dirnames, filenames in os.walk(root):
+        dirnames[:] = [d for d in dirnames if d not in skip_dirs]
+        for fname in filenames:
+            candidates.append(Path(dirpath) / fname)
+
+for path in candidates:
+    if path.suffix.lower() not in exts:
+        continue
+    try:
+        lines = path.read_text(encoding='utf-8', errors='ignore').splitlines()
+    except Exception:
+        continue
+    functions = set()
+    methods = set()
+    for line in lines:
+        declared = async_function_re.findall(line) + async_arrow_re.findall(line)
+        match = async_method_re.match(line)
+        if match and match.group(1) not in {'function', 'constructor'}:
+            declared_methods = [match.group(1)]
+        else:
+            declared_methods = []
+        if not declared and not declared_methods:
+            continue
+        functions.update(declared)
+        methods.update(declared_methods)
+    if not functions and not methods:
+        continue
+    names = '|'.join(sorted(re.escape(name) for name in functions))
+    method_names = '|'.join(sorted(re.escape(name) for name in methods))
+    alternatives = []
+    if names:
+        alternatives.append(r'(?:' + names + r')')
+    if method_names:
+        alternatives.append(r'this\.(?:' + method_names + r')')
+    call_re = re.compile(r'^(?:' + '|'.join(alternatives) + r')\s*(?:<[^>()]*>)?\s*\(')
+    previous = ''
+    for idx, line in enumerate(lines):
+        stripped = line.strip()
+        if not stripped or stripped.startswith(("//", "/*", "*")):
+            continue
+        prior = previous
+        previous = stripped
+        if not call_re.match(stripped):
+            continue
+        if prior and not statement_end_re.search(prior):
+            continue
+        statement_lines = []
+        paren_balance = 0
+        saw_paren = False
+        for stmt_idx in range(idx, min(len(lines), idx + 14)):
+            current = strip_strings(lines[stmt_idx].strip())
+            statement_lines.append(current)
+            paren_balance += current.count('(') - current.count(')')
+            saw_paren = saw_paren or '(' in current
+            if saw_paren and paren_balance <= 0:
+                following = lines[stmt_idx + 1].strip() if stmt_idx + 1 < len(lines) else ''
+                if not following.startswith('.'):
+                    break
+        statement = ' '.join(statement_lines)
+        if 'ubs:ignore' in line or 'ubs:ignore' in statement:
+            continue
+        if re.search(r'\.\s*(?:catch|then)\s*\(', statement):
+            continue
+        if not re.search(r'\)\s*;?\s*$', statement):
+            continue
+        try:
+            rel = path.relative_to(sample_root)
+        except ValueError:
+            rel = path
+        issues.append((str(rel), idx + 1, stripped.replace('\t', ' ')))
+
+print(len(issues))
+for entry in issues[:25]:
+    print('\t'.join(str(part) for part in entry))
+PY
+)
+async_missing_await_count=$(printf '%s\n' "$async_missing_await_report" | head -n1 | awk 'END{print $0+0}')
+async_missing_await_samples=$(printf '%s\n' "$async_missing_await_report" | tail -n +2)
+if [ "$async_missing_await_count" -gt 0 ]; then
+  print_finding "warning" "$async_missing_await_count" "async function called without await" "Await the call, return it, chain .catch(), or mark intentional fire-and-forget with void"
+  sample_limit=3
+  while IFS=$'\t' read -r sample_path sample_line sample_text; do
+    [ -z "$sample_path" ] && continue
+    print_code_sample "$sample_path" "$sample_line" "$sample_text"
+    sample_limit=$((sample_limit - 1))
+    [ "$sample_limit" -le 0 ] && break
+  done <<<"$async_missing_await_samples"
+fi
+
 run_async_error_checks
 run_hooks_dependency_checks
 run_type_narrowing_checks
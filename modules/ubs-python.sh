@@ -824,8 +824,8 @@ SINKS = [
     (re.compile(r"(?:engine|db)\.(?:execute|text)\s*\((.+)\)"), 'py.taint.sql', 'SQL engine execute'),
     (re.compile(r"subprocess\.(?:run|Popen|call|check_output|check_call)\s*\((.+)\)"), 'py.taint.command', 'subprocess execution'),
     (re.compile(r"os\.(?:system|popen|execv)\s*\((.+)\)"), 'py.taint.command', 'os command execution'),
-    (re.compile(r"eval\s*\((.+)\)"), 'py.taint.eval', 'eval'),
-    (re.compile(r"exec\s*\((.+)\)"), 'py.taint.eval', 'exec'),
+    (re.compile(r"(?<!\w)eval\s*\((.+)\)"), 'py.taint.eval', 'eval'),
+    (re.compile(r"(?<!\w)exec\s*\((.+)\)"), 'py.taint.eval', 'exec'),
 ]
 
 ASSIGN_SIMPLE = re.compile(r"^(?P<targets>[A-Za-z_][\w]*(?:\s*,\s*[A-Za-z_][\w]*)*)\s*=\s*(?P<expr>.+)")
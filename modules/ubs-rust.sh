@@ -45,9 +45,11 @@ shopt -s extglob
 TMP_FILES=()
 AST_RULE_DIR=""
 AST_CONFIG_FILE=""
+RUST_MATCH_CACHE_DIR=""
 cleanup() {
   local ec=$?
   if [[ -n "${AST_RULE_DIR:-}" && -d "$AST_RULE_DIR" && "$AST_RULE_DIR" != "/" && "$AST_RULE_DIR" != "." ]]; then rm -rf -- "$AST_RULE_DIR" || true; fi
+  if [[ -n "${RUST_MATCH_CACHE_DIR:-}" && -d "$RUST_MATCH_CACHE_DIR" && "$RUST_MATCH_CACHE_DIR" != "/" ]]; then rm -rf -- "$RUST_MATCH_CACHE_DIR" || true; fi
   if [[ ${#TMP_FILES[@]} -gt 0 ]]; then for f in "${TMP_FILES[@]}"; do [[ -e "$f" ]] && rm -f "$f" || true; done; fi
   exit "$ec"
 }
@@ -118,6 +120,15 @@ DUMP_RULES_DIR=""
 LIST_RULES=0
 STRICT_GITIGNORE=0
 EXCLUDE_TESTS=0
+UNSAFE_SAFETY_MIN_LINES="${UBS_RUST_UNSAFE_MIN_LINES:-0}"
+OVERFLOW_TYPES="${UBS_RUST_OVERFLOW_TYPES:-}"
+FLOAT_EQ_IGNORE_ZERO="${UBS_RUST_FLOAT_EQ_IGNORE_ZERO:-0}"
+PANIC_MACRO_SEVERITY="${UBS_RUST_PANIC_MACRO_SEVERITY:-}"
+CLONE_LOOP_MIN_BYTES="${UBS_RUST_CLONE_LOOP_MIN_BYTES:-64}"
+TAINT_SOURCES="${UBS_RUST_TAINT_SOURCES:-}"
+COMMAND_SINKS="${UBS_RUST_COMMAND_SINKS:-}"
+SECRET_ALLOWLIST="${UBS_RUST_SECRET_ALLOWLIST:-}"
+DEBUG_OUTPUT_TARGETS="${UBS_RUST_DEBUG_OUTPUT_TARGETS:-lib}"
 
 # New (v3.x): internal-only toggles
 AST_GREP_RUN_STYLE=0
@@ -149,12 +160,27 @@ Options:
   --emit-findings-json=FILE  Write full findings (structured JSON)
   --strict-gitignore         Honor .gitignore even without ripgrep
   --exclude-tests            Exclude matches inside test functions/modules
+  --unsafe-min-lines=N       Only report undocumented unsafe blocks spanning >= N lines
+  --overflow-types=CSV       Restrict untrusted-arithmetic findings to these int types (e.g. u8,u16,i32)
+  --float-eq-ignore-zero     Do not report float ==/!= against a literal 0.0
+  --panic-macro-severity=LIST
+                             Per-macro severity, e.g. todo=critical,unreachable=info,panic=off
+  --clone-loop-min-bytes=N   Only report read-only clones in loops of types >= N bytes
+                             (heap-owning types always qualify; default: 64)
+  --taint-sources=CSV        Extra untrusted-input sources (param types or calls, e.g. Query,Json,ctx.input)
+  --command-sinks=CSV        Extra process-spawning calls treated like Command::new (e.g. duct::cmd)
+  --secret-allowlist=CSV     Path globs (tests/fixtures/*) or literal values exempt from secret checks
+  --debug-output-targets=CSV Target kinds where dbg!/println! are reported
+                             (lib,bin,example,bench,build,test; default: lib)
   --fail-critical=N          Exit non-zero if critical issues >= N (default: 1)
   --fail-warning=N           Exit non-zero if warnings  >= N (default: 0)
   -h, --help                 Show help
 
 Env:
-  JOBS, NO_COLOR, CI
+  JOBS, NO_COLOR, CI, UBS_RUST_UNSAFE_MIN_LINES, UBS_RUST_OVERFLOW_TYPES,
+  UBS_RUST_FLOAT_EQ_IGNORE_ZERO, UBS_RUST_PANIC_MACRO_SEVERITY,
+  UBS_RUST_CLONE_LOOP_MIN_BYTES, UBS_RUST_TAINT_SOURCES, UBS_RUST_COMMAND_SINKS,
+  UBS_RUST_SECRET_ALLOWLIST, UBS_RUST_DEBUG_OUTPUT_TARGETS
 
 Args:
   PROJECT_DIR                Directory to scan (default: ".")
@@ -186,6 +212,15 @@ while [[ $# -gt 0 ]]; do
     --emit-findings-json=*) EMIT_FINDINGS_JSON="${1#*=}"; shift;;
     --strict-gitignore) STRICT_GITIGNORE=1; shift;;
     --exclude-tests) EXCLUDE_TESTS=1; shift;;
+    --unsafe-min-lines=*) UNSAFE_SAFETY_MIN_LINES="${1#*=}"; shift;;
+    --overflow-types=*) OVERFLOW_TYPES="${1#*=}"; shift;;
+    --float-eq-ignore-zero) FLOAT_EQ_IGNORE_ZERO=1; shift;;
+    --panic-macro-severity=*) PANIC_MACRO_SEVERITY="${1#*=}"; shift;;
+    --clone-loop-min-bytes=*) CLONE_LOOP_MIN_BYTES="${1#*=}"; shift;;
+    --taint-sources=*) TAINT_SOURCES="${1#*=}"; shift;;
+    --command-sinks=*) COMMAND_SINKS="${1#*=}"; shift;;
+    --secret-allowlist=*) SECRET_ALLOWLIST="${1#*=}"; shift;;
+    --debug-output-targets=*) DEBUG_OUTPUT_TARGETS="${1#*=}"; shift;;
     --fail-critical=*) FAIL_CRITICAL_THRESHOLD="${1#*=}"; shift;;
     --fail-warning=*)  FAIL_WARNING_THRESHOLD="${1#*=}"; shift;;
     -h|--help)    print_usage; exit 0;;
@@ -251,20 +286,22 @@ FIND_TTL=()
 FIND_DESC=()
 FIND_CAT=()
 FIND_SAMPLES=()
+FIND_FIX=()
 add_finding() {
-  local severity="$1" count="$2" title="$3" desc="${4:-}" category="${5:-}" samples="${6:-[]}"
+  local severity="$1" count="$2" title="$3" desc="${4:-}" category="${5:-}" samples="${6:-[]}" fix="${7:-}"
   FIND_SEV+=("$severity")
   FIND_CNT+=("$count")
   FIND_TTL+=("$title")
   FIND_DESC+=("$desc")
   FIND_CAT+=("$category")
   FIND_SAMPLES+=("$samples")
+  FIND_FIX+=("$fix")
 }
 json_escape() {
   local s=""
   if [[ $# -gt 0 ]]; then s="$1"; else s="$(cat 2>/dev/null || true)"; fi
   s="${s//\\/\\\\}"
-  s="${s//"/\\"}"
+  s="${s//\"/\\\"}"
   s="${s//$'	'/\\t}"
   s="${s//$''/\\r}"
   s="${s//$'\n'/\\n}"
@@ -284,15 +321,19 @@ emit_findings_json() {
     echo '  "findings": ['
     local first=1 i n
     n=${#FIND_SEV[@]}
+    local fix_field
     for ((i=0;i<n;i++)); do
       [[ $first -eq 0 ]] && echo ','
       first=0
-      printf '    {"severity":"%s","count":%s,"category":"%s","title":"%s","description":"%s","samples":%s}' \
+      fix_field=""
+      [[ -n "${FIND_FIX[$i]:-}" ]] && fix_field=",\"fix\":\"$(json_escape "${FIND_FIX[$i]}")\""
+      printf '    {"severity":"%s","count":%s,"category":"%s","title":"%s","description":"%s","samples":%s%s}' \
         "$(json_escape "${FIND_SEV[$i]}")" "$(printf '%s' "${FIND_CNT[$i]}" | awk 'END{print $0+0}')" \
         "$(json_escape "${FIND_CAT[$i]}")" \
         "$(json_escape "${FIND_TTL[$i]}")" \
         "$(json_escape "${FIND_DESC[$i]}")" \
-        "${FIND_SAMPLES[$i]:-[]}"
+        "${FIND_SAMPLES[$i]:-[]}" \
+        "$fix_field"
     done
     echo
     echo '  ]'
@@ -409,6 +450,9 @@ declare -A ASYNC_ERROR_SEVERITY=(
 
 have_python3=0
 if command -v python3 >/dev/null 2>&1; then have_python3=1; fi
+# Shared source walking/masking for the embedded Python checks (helpers/rust_scan_common.py).
+UBS_RUST_HELPER_DIR="$SCRIPT_DIR/helpers"
+export UBS_RUST_HELPER_DIR
 
 # Category names (for JSON category tagging & --list-categories)
 declare -A CATEGORY_NAME=()
@@ -613,6 +657,23 @@ fi
 
 count_lines() { grep -v 'ubs:ignore' | filter_test_lines | awk 'END{print (NR+0)}'; }
 
+# A Python matcher feeds its count, printed examples and JSON samples; run it once per
+# matcher/mode and replay the cached output for the other two.
+cached_rust_matches() {
+  local fn="$1"; shift
+  if [[ -z "$RUST_MATCH_CACHE_DIR" ]]; then
+    "$fn" "$@"
+    return
+  fi
+  local key="${fn}${*:+.$*}"
+  local cache="$RUST_MATCH_CACHE_DIR/${key//[^A-Za-z0-9_.-]/_}"
+  if [[ ! -f "$cache" ]]; then
+    "$fn" "$@" >"$cache.tmp" || true
+    mv -f "$cache.tmp" "$cache"
+  fi
+  cat "$cache"
+}
+
 # ---------------------------------------------------------------------------
 # --exclude-tests: filter out matches inside test functions/modules
 # ---------------------------------------------------------------------------
@@ -872,25 +933,87 @@ run_async_error_checks() {
   local issues=0
   while IFS=$'\n' read -r file; do
     [[ -z "$file" ]] && continue
-    local missing=""
+    local missing="" hit_lines=""
     if [[ $have_python3 -eq 1 ]]; then
-      missing=$(python3 - "$file" <<'PY2'
+      # Output: first line lists dropped handle names; remaining lines are file line numbers.
+      hit_lines=$(python3 - "$file" <<'PY2'
 import sys, re
 from pathlib import Path
 path = Path(sys.argv[1])
 text = path.read_text(encoding="utf-8", errors="replace")
-names = re.findall(r'\blet\s+([A-Za-z_][A-Za-z0-9_]*)\s*=\s*tokio::spawn', text)
+# Blank out comments and string contents so mentions do not count as handle uses.
+masked = re.sub(r'//[^\n]*|/\*.*?\*/|"(?:\\.|[^"\\])*"', lambda m: re.sub(r'[^\n]', ' ', m.group(0)), text, flags=re.S)
+line_of = lambda offset: masked.count("\n", 0, offset) + 1
+source_lines = text.splitlines()
+ignored = lambda offset: "ubs:ignore" in (source_lines[line_of(offset) - 1] if line_of(offset) <= len(source_lines) else "")
+
+
+def closing_brace(open_index):
+    depth = 0
+    for idx in range(open_index, len(masked)):
+        if masked[idx] == "{":
+            depth += 1
+        elif masked[idx] == "}":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return len(masked)
+
+
+def fn_body_end(offset):
+    """End of the innermost fn body around offset, so a same-named handle elsewhere does not count."""
+    end = len(masked)
+    for fn in re.finditer(r'\bfn\s+[A-Za-z_][A-Za-z0-9_]*[^;{]*\{', masked[:offset]):
+        close = closing_brace(fn.end() - 1)
+        if close >= offset:
+            end = close
+    return end
+
+
 missing = []
-for name in names:
-    patt_await = re.compile(rf"\b{name}\.await")
-    patt_abort = re.compile(rf"\b{name}\.abort")
-    if patt_await.search(text) or patt_abort.search(text):
+lines = []
+for match in re.finditer(r'\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=\s*tokio::spawn\b', masked):
+    if ignored(match.start()):
+        continue
+    name = match.group(1)
+    if name == "_":
+        missing.append("_")
+        lines.append(line_of(match.start()))
+        continue
+    rest = masked[match.end():fn_body_end(match.start())]
+    consumed = (
+        rf"\b{name}\s*\.\s*(?:await|abort\s*\(|is_finished\s*\()",
+        rf"\b(?:push|push_back|insert|extend|send|join_all|try_join_all)\s*\(\s*{name}\b",
+        rf"\b(?:join|try_join|select)!\s*[({{][^;]*\b{name}\b",
+        rf"\breturn\s+(?:Ok\s*\(\s*|Some\s*\(\s*)?{name}\b",
+    )
+    if any(re.search(pattern, rest) for pattern in consumed):
         continue
     missing.append(name)
+    lines.append(line_of(match.start()))
+# A bare `tokio::spawn(...);` statement detaches the task and drops the handle on the spot.
+for match in re.finditer(r'(?:^|(?<=[;{}]))\s*tokio::spawn\s*\(', masked, re.M):
+    depth = 0
+    for idx in range(match.end() - 1, len(masked)):
+        if masked[idx] == "(":
+            depth += 1
+        elif masked[idx] == ")":
+            depth -= 1
+            if depth == 0:
+                break
+    else:
+        continue
+    if re.match(r'\s*;', masked[idx + 1:]) and not ignored(match.end() - 1):
+        missing.append("<detached>")
+        lines.append(line_of(match.end() - 1))
 if missing:
-    print(','.join(missing))
+    print(','.join(sorted(set(missing))))
+    for line in sorted(set(lines)):
+        print(line)
 PY2
 )
+      missing=$(printf '%s\n' "$hit_lines" | head -n 1)
+      hit_lines=$(printf '%s\n' "$hit_lines" | tail -n +2)
     else
       local names
       names=$(grep -nE '\blet[[:space:]]+([A-Za-z_][A-Za-z0-9_]*)[[:space:]]*=[[:space:]]*tokio::spawn' "$file" 2>/dev/null | sed -E 's/.*let[[:space:]]+([A-Za-z_][A-Za-z0-9_]*).*/\1/' | sort -u)
@@ -903,8 +1026,28 @@ PY2
     if [[ -n "$missing" ]]; then
       issues=1
       local rel="${file#"$PROJECT_DIR"/}"
-      print_finding "warning" 1 "tokio::spawn JoinHandle dropped" "Await or abort JoinHandles returned by tokio::spawn ($rel)"
-      add_finding "warning" 1 "tokio::spawn JoinHandle dropped" "Await or abort JoinHandles returned by tokio::spawn ($rel)" "Concurrency/Async" "$(collect_samples_rg "tokio::spawn" 3)"
+      local samples="[]" ln code
+      if [[ -n "$hit_lines" ]]; then
+        samples="["
+        local i=0
+        while IFS= read -r ln; do
+          [[ -z "$ln" ]] && continue
+          code=$(sed -n "${ln}p" "$file" | sed -E 's/^[[:space:]]+//')
+          [[ $i -lt 3 ]] || break
+          [[ $i -gt 0 ]] && samples+=","
+          samples+="\"$(printf '%s' "$file:$ln:$code" | json_escape)\""
+          i=$((i + 1))
+        done <<<"$hit_lines"
+        samples+="]"
+      else
+        samples="$(collect_samples_rg "tokio::spawn" 3)"
+      fi
+      print_finding "warning" 1 "tokio::spawn JoinHandle dropped" "Await or abort JoinHandles returned by tokio::spawn; detached tasks swallow panics and errors ($rel: ${missing})"
+      while IFS= read -r ln; do
+        [[ -z "$ln" ]] && continue
+        print_code_sample "$file" "$ln" "$(sed -n "${ln}p" "$file")"
+      done < <(printf '%s\n' "$hit_lines" | head -n 3)
+      add_finding "warning" 1 "tokio::spawn JoinHandle dropped" "Await or abort JoinHandles returned by tokio::spawn; detached tasks swallow panics and errors ($rel: ${missing})" "Concurrency/Async" "$samples"
     fi
   done <<<"$files"
   if [[ $issues -eq 0 ]]; then
@@ -1045,33 +1188,61 @@ rust_async_context_matches() {
   local mode="$1"
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, find_matching_paren, line_number, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 mode = sys.argv[2]
 
 patterns = {
     "sleep": re.compile(r"\b(?:std::)?thread::sleep\s*\("),
-    "fs": re.compile(r"\b(?:std::)?fs::(?:read|read_to_string|write|rename|copy|remove_file)\s*\("),
-    "block_on": re.compile(r"\b(?:futures::executor::block_on|tokio::runtime::Runtime::block_on)\s*\("),
+    "fs": re.compile(
+        r"(?<!tokio::)\b(?:std::)?fs::(?:read|read_to_string|read_dir|read_link|write|rename|copy|hard_link|remove_file|remove_dir|remove_dir_all|create_dir|create_dir_all|metadata|symlink_metadata|canonicalize|set_permissions)\s*\("
+        r"|(?<!tokio::)\b(?:std::)?fs::(?:File|OpenOptions)::(?:open|create|new)\s*\("
+    ),
+    "net": re.compile(
+        r"(?<!tokio::)(?<!async_std::)\b(?:std::)?net::(?:TcpStream|TcpListener|UdpSocket)::(?:connect|connect_timeout|bind)\s*\("
+        r"|\.to_socket_addrs\s*\(\s*\)"
+    ),
+    "stdin": re.compile(r"(?<!tokio::)\b(?:std::)?io::stdin\s*\(\s*\)\s*(?:\.\s*lock\s*\(\s*\)\s*)?\.\s*(?:read_line|read_to_string|lines)\s*\("),
+    "block_on": re.compile(
+        r"\b(?:futures::executor::|executor::|tokio::runtime::Runtime::|Runtime::|Handle::)?block_on\s*\("
+        r"|\.\s*block_on\s*\("
+    ),
     "thread_spawn": re.compile(r"\b(?:std::)?thread::spawn\s*\("),
+    "cpu": re.compile(
+        r"\b(?:bcrypt::(?:hash|verify)|pbkdf2::pbkdf2\w*|pbkdf2_hmac\w*|scrypt::scrypt|blake3::hash"
+        r"|(?:Sha256|Sha512|Sha3_256|Blake2b\w*)::digest)\s*\(|\.\s*(?:hash_password|verify_password)\s*\("
+        r"|\b(?:GzEncoder|GzDecoder|ZlibEncoder|ZlibDecoder|DeflateEncoder|DeflateDecoder)::new\s*\("
+        r"|\bzstd::(?:stream::)?(?:encode_all|decode_all)\s*\(|\b(?:brotli|lz4_flex|snap)::[A-Za-z_:]+\s*\("
+        r"|\.\s*par_iter(?:_mut)?\s*\(\s*\)"
+    ),
 }
-
+# Loops and per-item serialization only count as CPU-bound when they never yield back to the executor.
+cpu_range_loop = re.compile(r"\bfor\s+[A-Za-z_][A-Za-z0-9_]*\s+in\s+0\s*\.\.=?\s*(?P<bound>\d[\d_]*)\s*\{")
+cpu_serialize = re.compile(r"\b(?:serde_json|bincode|rmp_serde|serde_yaml|toml)::(?:to|from)_[a-z_]+\s*\(")
+cpu_loop = re.compile(r"\b(?:for|while|loop)\b[^{;]*\{")
+
+# fs_fix reports the tokio::fs replacement for each blocking call instead of its location.
+fix_mode = mode == "fs_fix"
+if fix_mode:
+    mode = "fs"
 pattern = patterns[mode]
-
-
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    skip_dirs = {".git", "target", ".cargo", "node_modules"}
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
+# Bare File/TcpStream names are only blocking when the file imports the std versions.
+bare_std_imports = {
+    "fs": (re.compile(r"\buse\s+std::fs::(?:File\b|OpenOptions\b|\{[^}]*\b(?:File|OpenOptions)\b)"),
+           re.compile(r"(?<![A-Za-z0-9_:])(?:File|OpenOptions)::(?:open|create)\s*\(")),
+    "net": (re.compile(r"\buse\s+std::net::(?:TcpStream\b|TcpListener\b|UdpSocket\b|\{[^}]*\b(?:TcpStream|TcpListener|UdpSocket)\b)"),
+            re.compile(r"(?<![A-Za-z0-9_:])(?:TcpStream|TcpListener|UdpSocket)::(?:connect|connect_timeout|bind)\s*\(")),
+}
+offload_call = re.compile(r"\b(?:spawn_blocking|block_in_place)\s*\(")
 
 
 def mask_comments_and_strings(text: str) -> str:
@@ -1128,25 +1299,71 @@ def mask_comments_and_strings(text: str) -> str:
     return "".join(chars)
 
 
-def find_matching_brace(text: str, open_index: int) -> int:
-    depth = 0
-    for idx in range(open_index, len(text)):
-        ch = text[idx]
-        if ch == "{":
-            depth += 1
-        elif ch == "}":
-            depth -= 1
-            if depth == 0:
-                return idx
-    return -1
+sync_fn = re.compile(r"(?<!async )\bfn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;()]*?>)?\s*\([^{;]*\{")
 
 
-def line_number(text: str, offset: int) -> int:
-    return text.count("\n", 0, offset) + 1
+def blocking_helpers(masked: str):
+    """Same-file sync fns that reach block_on, directly or through other sync helpers."""
+    bodies = {}
+    for fn in sync_fn.finditer(masked):
+        if re.search(r"\basync\s+(?:unsafe\s+)?$", masked[max(0, fn.start() - 20):fn.start()]):
+            continue
+        close = find_matching_brace(masked, fn.end() - 1)
+        if close > 0:
+            bodies.setdefault(fn.group("name"), masked[fn.end():close])
+    blocking = {name for name, body in bodies.items() if patterns["block_on"].search(body)}
+    changed = True
+    while changed:
+        changed = False
+        for name, body in bodies.items():
+            if name in blocking:
+                continue
+            if any(re.search(r"(?:\bself\s*\.\s*|\bSelf\s*::\s*|(?<![.:A-Za-z0-9_]))" + re.escape(other) + r"\s*\(", body) for other in blocking):
+                blocking.add(name)
+                changed = True
+    return blocking
+
+
+def cpu_loop_hits(body: str):
+    hits = []
+    for loop in cpu_range_loop.finditer(body):
+        end = find_matching_brace(body, loop.end() - 1)
+        if int(loop.group("bound").replace("_", "")) >= 10_000 and end > 0 and ".await" not in body[loop.end():end]:
+            hits.append(loop)
+    loops = []
+    for loop in cpu_loop.finditer(body):
+        end = find_matching_brace(body, loop.end() - 1)
+        if end > 0 and not re.search(r"\.\s*await\b", body[loop.end():end]):
+            loops.append((loop.end(), end))
+    for call in cpu_serialize.finditer(body):
+        if any(start < call.start() < end for start, end in loops):
+            hits.append(call)
+    return hits
+
+
+def cpu_tag(hit: str) -> str:
+    if hit.startswith("for"):
+        return "tight loop with no .await"
+    if cpu_serialize.match(hit):
+        return "serialization inside a loop"
+    if "par_iter" in hit:
+        return "rayon parallel iterator"
+    if re.search(r"Encoder|Decoder|zstd|brotli|lz4_flex|snap", hit):
+        return "compression"
+    return "hashing"
+
+
+def tokio_fs_fix(call: str) -> str:
+    name = re.sub(r"\s+", "", call).rstrip("(")
+    name = re.sub(r"^(?:std::)?(?:fs::)?", "", name)
+    return f"{call.strip().rstrip('(').strip()}(..) -> tokio::fs::{name}(..).await"
 
 
 async_fn = re.compile(r"\basync\s+fn\s+[A-Za-z_][A-Za-z0-9_]*[^{;]*\{", re.MULTILINE)
+# async blocks handed to spawn/join run on the executor just like async fn bodies.
+async_block = re.compile(r"\basync\s+(?:move\s+)?\{")
 seen = set()
+fixes = []
 
 for path in rust_files(root):
     try:
@@ -1155,7 +1372,17 @@ for path in rust_files(root):
         continue
     masked = mask_comments_and_strings(text)
     lines = text.splitlines()
-    for fn_match in async_fn.finditer(masked):
+    contexts = sorted(list(async_fn.finditer(masked)) + list(async_block.finditer(masked)), key=lambda item: item.start())
+    helper_call = None
+    if mode == "block_on":
+        helpers = blocking_helpers(masked)
+        if helpers:
+            helper_call = re.compile(
+                r"(?:\bself\s*\.\s*|\bSelf\s*::\s*|(?<![.:A-Za-z0-9_]))(?P<helper>"
+                + "|".join(re.escape(name) for name in sorted(helpers))
+                + r")\s*\("
+            )
+    for fn_match in contexts:
         open_brace = masked.find("{", fn_match.start())
         if open_brace < 0:
             continue
@@ -1163,7 +1390,26 @@ for path in rust_files(root):
         if close_brace < 0:
             continue
         body = masked[open_brace:close_brace + 1]
-        for hit in pattern.finditer(body):
+        offloaded = []
+        for call in offload_call.finditer(body):
+            paren = body.find("(", call.start())
+            end = find_matching_paren(body, paren)
+            if end > 0:
+                offloaded.append((paren, end))
+        hits = list(pattern.finditer(body))
+        if mode in bare_std_imports and bare_std_imports[mode][0].search(masked):
+            hits.extend(bare_std_imports[mode][1].finditer(body))
+        if mode == "cpu":
+            hits.extend(cpu_loop_hits(body))
+        if helper_call:
+            hits.extend(helper_call.finditer(body))
+        for hit in sorted(hits, key=lambda item: item.start()):
+            if any(start < hit.start() < end for start, end in offloaded):
+                continue
+            call_open = body.rfind("(", hit.start(), hit.end())
+            call_close = find_matching_paren(body, call_open) if call_open >= 0 else -1
+            if call_close > 0 and re.match(r"\s*\.\s*await\b", body[call_close + 1:]):
+                continue
             offset = open_brace + hit.start()
             line = line_number(masked, offset)
             key = (str(path), line, mode)
@@ -1173,14 +1419,28 @@ for path in rust_files(root):
             code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
             if "ubs:ignore" in code:
                 continue
+            if fix_mode:
+                fix = tokio_fs_fix(body[hit.start():call_open + 1] if call_open >= 0 else hit.group(0))
+                if fix not in fixes:
+                    fixes.append(fix)
+                continue
+            if mode == "cpu":
+                print(f"{path}:{line}:{code}  [{cpu_tag(hit.group(0))}]")
+                continue
+            if helper_call and helper_call.match(hit.group(0)):
+                print(f"{path}:{line}:{code}  [{hit.group('helper')}() reaches block_on]")
+                continue
             print(f"{path}:{line}:{code}")
+
+for fix in fixes:
+    print(fix)
 PY
 }
 
 count_async_context_matches() {
   local mode="$1"
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_async_context_matches "$mode" | count_lines || true
+    cached_rust_matches rust_async_context_matches "$mode" | count_lines || true
   else
     return 1
   fi
@@ -1197,7 +1457,7 @@ show_async_context_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_async_context_matches "$mode" | head -n "$limit")
+  done < <(cached_rust_matches rust_async_context_matches "$mode" | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -1208,7 +1468,7 @@ collect_samples_async_context() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_async_context_matches "$mode" | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_async_context_matches "$mode" | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -1224,10 +1484,16 @@ rust_loop_context_matches() {
   local mode="$1"
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 mode = sys.argv[2]
 
@@ -1235,111 +1501,30 @@ patterns = {
     "regex_new": re.compile(r"\b(?:regex::)?Regex::new\s*\("),
     "clone": re.compile(r"\.clone\s*\("),
     "string_alloc": re.compile(r"\bformat!\s*\(|\.to_string\s*\(|\.to_owned\s*\(|\bString::from\s*\("),
+    "expensive_ctor": re.compile(
+        r"\b(?:reqwest::(?:blocking::)?)?Client::(?:new\s*\(|builder\s*\()"
+        r"|\b(?:regex::)?(?:RegexSet|RegexBuilder|bytes::Regex)::new\s*\("
+        r"|\bAhoCorasick::(?:new|builder)\s*\(|\bhyper::Client::(?:new|builder)\s*\("
+        r"|\b(?:rustls::)?ClientConfig::builder\s*\(|\bTera::new\s*\("
+    ),
+    "chars_nth": re.compile(r"\.\s*chars\s*\(\s*\)\s*\.\s*nth(?:_back)?\s*\((?P<arg>[^()]*)\)"),
 }
 
 pattern = patterns[mode]
+# Compile-once constructors also count when built per item inside iterator closures.
+closure_modes = {"regex_new", "expensive_ctor", "chars_nth"}
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    skip_dirs = {".git", "target", ".cargo", "node_modules"}
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
-def mask_range(chars, start, end):
-    for pos in range(start, min(end, len(chars))):
-        if chars[pos] != "\n":
-            chars[pos] = " "
-
-
-def mask_comments_and_strings(text: str) -> str:
-    chars = list(text)
-    i = 0
-    n = len(chars)
-    state = "code"
-    while i < n:
-        ch = chars[i]
-        nxt = chars[i + 1] if i + 1 < n else ""
-        if state == "code":
-            if ch == "/" and nxt == "/":
-                start = i
-                i += 2
-                while i < n and chars[i] != "\n":
-                    i += 1
-                mask_range(chars, start, i)
-                continue
-            if ch == "/" and nxt == "*":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "block"
-                continue
-            if ch == "r":
-                j = i + 1
-                while j < n and chars[j] == "#":
-                    j += 1
-                if j < n and chars[j] == '"':
-                    hashes = j - i - 1
-                    close = '"' + ("#" * hashes)
-                    end = text.find(close, j + 1)
-                    if end == -1:
-                        end = n - 1
-                    else:
-                        end += len(close)
-                    mask_range(chars, i, end)
-                    i = end
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "string"
-                continue
-        elif state == "block":
-            if ch == "*" and nxt == "/":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        elif state == "string":
-            if ch == "\\":
-                chars[i] = " "
-                if i + 1 < n and chars[i + 1] != "\n":
-                    chars[i + 1] = " "
-                    i += 2
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        i += 1
-    return "".join(chars)
-
-
-def find_matching_brace(text: str, open_index: int) -> int:
-    depth = 0
-    for idx in range(open_index, len(text)):
-        ch = text[idx]
-        if ch == "{":
-            depth += 1
-        elif ch == "}":
-            depth -= 1
-            if depth == 0:
-                return idx
-    return -1
-
-
-def line_number(text: str, offset: int) -> int:
-    return text.count("\n", 0, offset) + 1
+def indexed_by(hit, header: str) -> bool:
+    """chars().nth(i) is only quadratic when i is the loop counter or closure argument."""
+    if mode != "chars_nth":
+        return True
+    loop_vars = set(re.findall(r"[A-Za-z_][A-Za-z0-9_]*", header.split(" in ")[0] if " in " in header else header))
+    loop_vars -= {"for", "while", "let", "Some", "mut", "move"}
+    return bool(set(re.findall(r"[A-Za-z_][A-Za-z0-9_]*", hit.group("arg"))) & loop_vars)
+iter_closure = re.compile(
+    r"\.\s*(?:map|for_each|try_for_each|filter|filter_map|flat_map|any|all|find|find_map|fold|inspect|par_iter\s*\(\s*\)\s*\.\s*map)\s*\(\s*(?:move\s*)?\|"
+)
 
 
 loop_start = re.compile(r"\b(?:for\b[^{;]*|while\b[^{;]*|loop\s*)\{", re.MULTILINE)
@@ -1361,6 +1546,8 @@ for path in rust_files(root):
             continue
         body = masked[open_brace:close_brace + 1]
         for hit in pattern.finditer(body):
+            if not indexed_by(hit, masked[loop_match.start():open_brace]):
+                continue
             offset = open_brace + hit.start()
             line = line_number(masked, offset)
             key = (str(path), line, mode, hit.group(0))
@@ -1371,13 +1558,41 @@ for path in rust_files(root):
             if "ubs:ignore" in code:
                 continue
             print(f"{path}:{line}:{code}")
+    if mode not in closure_modes:
+        continue
+    for closure in iter_closure.finditer(masked):
+        open_paren = masked.rfind("(", closure.start(), closure.end())
+        depth = 0
+        close_paren = -1
+        for idx in range(open_paren, len(masked)):
+            if masked[idx] == "(":
+                depth += 1
+            elif masked[idx] == ")":
+                depth -= 1
+                if depth == 0:
+                    close_paren = idx
+                    break
+        if close_paren < 0:
+            continue
+        for hit in pattern.finditer(masked, closure.end(), close_paren):
+            if not indexed_by(hit, masked[closure.end():masked.find("|", closure.end())]):
+                continue
+            line = line_number(masked, hit.start())
+            key = (str(path), line, mode, hit.group(0))
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}")
 PY
 }
 
 count_loop_context_matches() {
   local mode="$1"
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_loop_context_matches "$mode" | count_lines || true
+    cached_rust_matches rust_loop_context_matches "$mode" | count_lines || true
   else
     return 1
   fi
@@ -1394,7 +1609,7 @@ show_loop_context_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_loop_context_matches "$mode" | head -n "$limit")
+  done < <(cached_rust_matches rust_loop_context_matches "$mode" | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -1405,7 +1620,7 @@ collect_samples_loop_context() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_loop_context_matches "$mode" | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_loop_context_matches "$mode" | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -1420,122 +1635,338 @@ collect_samples_loop_context() {
 rust_drop_panic_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    skip_dirs = {".git", "target", ".cargo", "node_modules"}
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
+impl_drop = re.compile(r"\bimpl\b[^{};]*\bDrop\b[^{};]*\bfor\b[^{;]*\{", re.MULTILINE)
+drop_fn = re.compile(r"\bfn\s+drop\s*\(\s*&mut\s+self\s*\)\s*(?:->[^{]+)?\{", re.MULTILINE)
+panic_surface = re.compile(
+    r"\b(?:panic|unreachable|todo|unimplemented|assert|assert_eq|assert_ne)!\s*\(|\.(?:unwrap|expect)\s*\("
+    r"|(?<![\w.:])[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*\s*\[(?![^\]]*;)[^\]]+\]"
+)
+impl_inherent = re.compile(r"\bimpl\s*(?:<[^{;]*?>\s*)?([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;]*?>\s*)?\{", re.MULTILINE)
+method_fn = re.compile(r"\bfn\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^>{]*>)?\s*\([^)]*\bself\b[^)]*\)\s*(?:->[^{]+)?\{", re.MULTILINE)
+panicking_guard = re.compile(r"\bif\s+!\s*(?:std::)?thread::panicking\s*\(\s*\)\s*\{")
+seen = set()
 
 
-def mask_range(chars, start, end):
-    for pos in range(start, min(end, len(chars))):
-        if chars[pos] != "\n":
-            chars[pos] = " "
+def panicking_ranges(masked: str, body: str, base: int):
+    ranges = []
+    for guard in panicking_guard.finditer(body):
+        open_idx = base + guard.end() - 1
+        close_idx = find_matching_brace(masked, open_idx)
+        if close_idx > 0:
+            ranges.append((open_idx, close_idx))
+    return ranges
 
 
-def mask_comments_and_strings(text: str) -> str:
-    chars = list(text)
-    i = 0
-    n = len(chars)
-    state = "code"
-    while i < n:
-        ch = chars[i]
-        nxt = chars[i + 1] if i + 1 < n else ""
-        if state == "code":
-            if ch == "/" and nxt == "/":
-                start = i
-                i += 2
-                while i < n and chars[i] != "\n":
-                    i += 1
-                mask_range(chars, start, i)
-                continue
-            if ch == "/" and nxt == "*":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "block"
+def panicking_methods(masked: str):
+    """Map type name -> {method: True} for inherent methods that can panic."""
+    methods = {}
+    for impl_match in impl_inherent.finditer(masked):
+        open_idx = impl_match.end() - 1
+        close_idx = find_matching_brace(masked, open_idx)
+        if close_idx < 0:
+            continue
+        impl_body = masked[open_idx:close_idx + 1]
+        for fn_match in method_fn.finditer(impl_body):
+            fn_open = open_idx + fn_match.end() - 1
+            fn_close = find_matching_brace(masked, fn_open)
+            if fn_close < 0 or fn_close > close_idx:
                 continue
-            if ch == "r":
-                j = i + 1
-                while j < n and chars[j] == "#":
-                    j += 1
-                if j < n and chars[j] == '"':
-                    hashes = j - i - 1
-                    close = '"' + ("#" * hashes)
-                    end = text.find(close, j + 1)
-                    if end == -1:
-                        end = n - 1
-                    else:
-                        end += len(close)
-                    mask_range(chars, i, end)
-                    i = end
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "string"
+            if panic_surface.search(masked[fn_open:fn_close + 1]):
+                methods.setdefault(impl_match.group(1), set()).add(fn_match.group(1))
+    return methods
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    helpers = panicking_methods(masked) if "Drop" in masked else {}
+    for impl_match in impl_drop.finditer(masked):
+        impl_open = masked.rfind("{", impl_match.start(), impl_match.end())
+        if impl_open < 0:
+            continue
+        impl_close = find_matching_brace(masked, impl_open)
+        if impl_close < 0:
+            continue
+        impl_body = masked[impl_open:impl_close + 1]
+        for fn_match in drop_fn.finditer(impl_body):
+            fn_open = impl_open + impl_body.find("{", fn_match.start(), fn_match.end())
+            if fn_open < impl_open:
                 continue
-        elif state == "block":
-            if ch == "*" and nxt == "/":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "code"
+            fn_close = find_matching_brace(masked, fn_open)
+            if fn_close < 0 or fn_close > impl_close:
                 continue
-            if ch != "\n":
-                chars[i] = " "
-        elif state == "string":
-            if ch == "\\":
-                chars[i] = " "
-                if i + 1 < n and chars[i + 1] != "\n":
-                    chars[i + 1] = " "
-                    i += 2
+            drop_body = masked[fn_open:fn_close + 1]
+            guarded = panicking_ranges(masked, drop_body, fn_open)
+            type_name = re.search(r"\bfor\s+(?:[A-Za-z_][A-Za-z0-9_]*::)*([A-Za-z_][A-Za-z0-9_]*)", impl_match.group(0))
+            hits = list(panic_surface.finditer(drop_body))
+            panicky = helpers.get(type_name.group(1), set()) if type_name else set()
+            if panicky:
+                call_re = re.compile(r"\bself\s*\.\s*(" + "|".join(sorted(map(re.escape, panicky))) + r")\s*\(")
+                hits.extend(call_re.finditer(drop_body))
+            for hit in sorted(hits, key=lambda m: m.start()):
+                offset = fn_open + hit.start()
+                if any(lo <= offset <= hi for lo, hi in guarded):
                     continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        i += 1
-    return "".join(chars)
+                line = line_number(masked, offset)
+                key = (str(path), line, hit.group(0))
+                if key in seen:
+                    continue
+                seen.add(key)
+                code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+                if "ubs:ignore" in code:
+                    continue
+                print(f"{path}:{line}:{code}")
+PY
+}
 
+count_drop_panic_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_drop_panic_matches | count_lines || true
+  else
+    return 1
+  fi
+}
 
-def find_matching_brace(text: str, open_index: int) -> int:
-    depth = 0
-    for idx in range(open_index, len(text)):
-        ch = text[idx]
-        if ch == "{":
-            depth += 1
-        elif ch == "}":
-            depth -= 1
-            if depth == 0:
-                return idx
-    return -1
+show_drop_panic_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_drop_panic_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_drop_panic() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_drop_panic_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_unguarded_unwrap_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
 
-def line_number(text: str, offset: int) -> int:
-    return text.count("\n", 0, offset) + 1
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
 
 
-impl_drop = re.compile(r"\bimpl\b[^{};]*\bDrop\b[^{};]*\bfor\b[^{;]*\{", re.MULTILINE)
-drop_fn = re.compile(r"\bfn\s+drop\s*\(\s*&mut\s+self\s*\)\s*(?:->[^{]+)?\{", re.MULTILINE)
-panic_surface = re.compile(
-    r"\b(?:panic|unreachable|todo|unimplemented|assert|assert_eq|assert_ne)!\s*\(|\.(?:unwrap|expect)\s*\("
+def match_arms(text: str, open_index: int, close_index: int):
+    """Yield (pattern, body_start, body_end) for each top-level arm of a match body."""
+    i = open_index + 1
+    while i < close_index:
+        depth = 0
+        arrow = -1
+        j = i
+        while j < close_index:
+            ch = text[j]
+            if ch in "([{":
+                depth += 1
+            elif ch in ")]}":
+                depth -= 1
+            elif depth == 0 and text.startswith("=>", j):
+                arrow = j
+                break
+            j += 1
+        if arrow < 0:
+            return
+        pattern = text[i:arrow].strip()
+        j = arrow + 2
+        while j < close_index and text[j].isspace():
+            j += 1
+        if j < close_index and text[j] == "{":
+            end = find_matching_brace(text, j)
+            if end < 0 or end > close_index:
+                return
+            k = end + 1
+            while k < close_index and text[k].isspace():
+                k += 1
+            if k < close_index and text[k] == ",":
+                k += 1
+            yield pattern, j, end
+            i = k
+            continue
+        depth = 0
+        k = j
+        while k < close_index:
+            ch = text[k]
+            if ch in "([{":
+                depth += 1
+            elif ch in ")]}":
+                depth -= 1
+            elif ch == "," and depth == 0:
+                break
+            k += 1
+        yield pattern, j, k
+        i = k + 1
+
+
+NAME = r"(?:self\.)?[A-Za-z_][A-Za-z0-9_]*(?:\.[A-Za-z_][A-Za-z0-9_]*)*"
+ADAPTERS = re.compile(r"(?:\s*\.\s*(?:as_ref|as_mut|as_deref|as_deref_mut|clone)\s*\(\s*\))+$")
+fn_start = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*[^{;]*\{")
+unwrap_call = re.compile(r"\.\s*(?:unwrap|expect)\s*\(")
+receiver_tail = re.compile(
+    r"(?<![A-Za-z0-9_.])(" + NAME + r"(?:\s*\.\s*(?:as_ref|as_mut|as_deref|as_deref_mut|clone)\s*\(\s*\))*)\s*$"
+)
+branch_kw = re.compile(r"\b(?:if|while)\b")
+positive_method = re.compile(r"^(" + NAME + r")\s*\.\s*(?:is_some|is_ok)\s*\(\s*\)$")
+positive_let = re.compile(r"\blet\s+(?:Some|Ok)\s*\(.*?\)\s*=\s*&?(?:mut\s+)?(" + NAME + r")\b")
+negative_method = re.compile(
+    r"^(?:!\s*(" + NAME + r")\s*\.\s*(?:is_some|is_ok)|(" + NAME + r")\s*\.\s*(?:is_none|is_err))\s*\(\s*\)$"
 )
+let_else = re.compile(r"\blet\s+(?:Some|Ok)\s*\(.*?\)\s*=\s*&?(?:mut\s+)?(" + NAME + r")\s*else\s*\{")
+match_head = re.compile(r"\bmatch\s+&?(?:mut\s+)?(" + NAME + r")((?:\s*\.\s*(?:as_ref|as_mut|as_deref)\s*\(\s*\))*)\s*\{")
+exits = re.compile(r"\b(?:return|continue|break)\b|\b(?:panic|unreachable|todo|unimplemented)!\s*\(|\?\s*;")
 seen = set()
 
+
+def strip_adapters(name: str) -> str:
+    return ADAPTERS.sub("", name).strip()
+
+
+def split_top_level(condition: str, op: str):
+    """Split a condition on a boolean operator outside parentheses into (term, end offset) pairs."""
+    parts = []
+    depth = 0
+    start = 0
+    i = 0
+    while i < len(condition):
+        ch = condition[i]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif depth == 0 and condition.startswith(op, i):
+            parts.append((unwrap_parens(condition[start:i]), i))
+            i += len(op)
+            start = i
+            continue
+        i += 1
+    parts.append((unwrap_parens(condition[start:]), len(condition)))
+    return parts
+
+
+def unwrap_parens(term: str) -> str:
+    term = term.strip()
+    while term.startswith("(") and term.endswith(")"):
+        depth = 0
+        for idx, ch in enumerate(term):
+            if ch == "(":
+                depth += 1
+            elif ch == ")":
+                depth -= 1
+                if depth == 0 and idx != len(term) - 1:
+                    return term
+        term = term[1:-1].strip()
+    return term
+
+
+def positive_guards(condition: str):
+    """Receivers proven Some/Ok when the whole condition holds (only && joins)."""
+    if len(split_top_level(condition, "||")) != 1:
+        return []
+    names = []
+    for term, end in split_top_level(condition, "&&"):
+        hit = positive_method.match(term)
+        if hit:
+            names.append((strip_adapters(hit.group(1)), end))
+    return names
+
+
+def negative_guards(condition: str):
+    """Receivers proven Some/Ok once an exiting branch is skipped (only || joins)."""
+    names = []
+    for term, _ in split_top_level(condition, "||"):
+        hit = negative_method.match(term)
+        if hit:
+            names.append(strip_adapters(hit.group(1) or hit.group(2)))
+    return names
+
+
+def reassigned(name: str, text: str) -> bool:
+    pattern = r"(?<![A-Za-z0-9_.])(?:let\s+(?:mut\s+)?)?" + re.escape(name) + r"\s*=(?![=>])"
+    return re.search(pattern, text) is not None
+
+
+def guard_regions(body: str, base: int):
+    """Collect (receiver, start, end) spans where the receiver is known Some/Ok."""
+    regions = []
+    fn_end = base + len(body)
+    for kw in branch_kw.finditer(body):
+        open_brace = body.find("{", kw.end())
+        if open_brace < 0:
+            continue
+        condition = body[kw.end():open_brace]
+        if ";" in condition:
+            continue
+        close_brace = find_matching_brace(body, open_brace)
+        if close_brace < 0:
+            continue
+        for name, end in positive_guards(condition):
+            regions.append((name, base + kw.end() + end, base + close_brace))
+        for hit in positive_let.finditer(condition):
+            regions.append((strip_adapters(hit.group(1)), base + open_brace, base + close_brace))
+        block = body[open_brace:close_brace + 1]
+        if kw.group(0) == "if" and exits.search(block):
+            for name in negative_guards(condition):
+                regions.append((name, base + close_brace, fn_end))
+    for hit in let_else.finditer(body):
+        open_brace = body.find("{", hit.end() - 1)
+        close_brace = find_matching_brace(body, open_brace)
+        if close_brace >= 0:
+            regions.append((strip_adapters(hit.group(1)), base + close_brace, fn_end))
+    for hit in match_head.finditer(body):
+        open_brace = hit.end() - 1
+        close_brace = find_matching_brace(body, open_brace)
+        if close_brace < 0:
+            continue
+        name = strip_adapters(hit.group(1))
+        for pattern, arm_start, arm_end in match_arms(body, open_brace, close_brace):
+            if re.match(r"(?:Some|Ok)\s*\(", pattern):
+                regions.append((name, base + arm_start, base + arm_end))
+    return regions
+
+
 for path in rust_files(root):
     try:
         text = path.read_text(encoding="utf-8", errors="replace")
@@ -1543,45 +1974,48 @@ for path in rust_files(root):
         continue
     masked = mask_comments_and_strings(text)
     lines = text.splitlines()
-    for impl_match in impl_drop.finditer(masked):
-        impl_open = masked.rfind("{", impl_match.start(), impl_match.end())
-        if impl_open < 0:
+    functions = []
+    for fn_match in fn_start.finditer(masked):
+        open_brace = fn_match.end() - 1
+        close_brace = find_matching_brace(masked, open_brace)
+        if close_brace >= 0:
+            functions.append((open_brace, close_brace))
+    for hit in unwrap_call.finditer(masked):
+        offset = hit.start()
+        tail = receiver_tail.search(masked, max(0, offset - 160), offset)
+        receiver = strip_adapters(tail.group(1)) if tail else ""
+        guarded = False
+        if receiver:
+            enclosing = [span for span in functions if span[0] < offset < span[1]]
+            if enclosing:
+                fn_open, fn_close = max(enclosing, key=lambda span: span[0])
+                for name, start, end in guard_regions(masked[fn_open:fn_close + 1], fn_open):
+                    if name == receiver and start <= offset <= end and not reassigned(name, masked[start:offset]):
+                        guarded = True
+                        break
+        if guarded:
             continue
-        impl_close = find_matching_brace(masked, impl_open)
-        if impl_close < 0:
+        line = line_number(masked, offset)
+        key = (str(path), line, offset)
+        if key in seen:
             continue
-        impl_body = masked[impl_open:impl_close + 1]
-        for fn_match in drop_fn.finditer(impl_body):
-            fn_open = impl_open + impl_body.find("{", fn_match.start(), fn_match.end())
-            if fn_open < impl_open:
-                continue
-            fn_close = find_matching_brace(masked, fn_open)
-            if fn_close < 0 or fn_close > impl_close:
-                continue
-            drop_body = masked[fn_open:fn_close + 1]
-            for hit in panic_surface.finditer(drop_body):
-                offset = fn_open + hit.start()
-                line = line_number(masked, offset)
-                key = (str(path), line, hit.group(0))
-                if key in seen:
-                    continue
-                seen.add(key)
-                code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
-                if "ubs:ignore" in code:
-                    continue
-                print(f"{path}:{line}:{code}")
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
 PY
 }
 
-count_drop_panic_matches() {
+count_unguarded_unwrap_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_drop_panic_matches | count_lines || true
+    cached_rust_matches rust_unguarded_unwrap_matches | count_lines || true
   else
     return 1
   fi
 }
 
-show_drop_panic_examples() {
+show_unguarded_unwrap_examples() {
   local limit="${1:-$DETAIL_LIMIT}"
   local printed=0
   [[ "$have_python3" -eq 1 ]] || return 1
@@ -1591,17 +2025,17 @@ show_drop_panic_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_drop_panic_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_unguarded_unwrap_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
-collect_samples_drop_panic() {
+collect_samples_unguarded_unwrap() {
   local limit="${1:-$DETAIL_LIMIT}"
   if [[ "$have_python3" -ne 1 ]]; then
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_drop_panic_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_unguarded_unwrap_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -1615,108 +2049,67 @@ collect_samples_drop_panic() {
 
 rust_path_traversal_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
-  python3 - "$PROJECT_DIR" <<'PY'
+  UBS_RUST_TAINT_SOURCES="$TAINT_SOURCES" python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
-pathish = re.compile(r"(?:^|_|\.)((?:path|dir|root|base|folder|upload|download|dest|target|tmp|temp|cache|out)s?)(?:$|_|\.)")
+pathish = re.compile(r"(?:^|_|\.)((?:path|dir|root|base|folder|upload|download|dest|target|tmp|temp|cache|out|static|public|assets|storage)s?)(?:$|_|\.)")
 untrusted = re.compile(r"(?:^|_)(?:user|input|upload|file|filename|path|rel|relative|request|req|param|name|key|entry|member|archive)(?:$|_)")
 call = re.compile(
-    r"\b(?P<recv>[A-Za-z_][A-Za-z0-9_]*(?:\.[A-Za-z_][A-Za-z0-9_]*)*)"
-    r"\s*\.\s*(?P<method>join|push)\s*\(\s*&?\s*(?P<arg>[A-Za-z_][A-Za-z0-9_]*)"
+    r"(?:\b(?P<recv>[A-Za-z_][A-Za-z0-9_]*(?:\.[A-Za-z_][A-Za-z0-9_]*)*)|(?P<ctor>\b(?:PathBuf\s*::\s*from|Path\s*::\s*new)\s*\([^;]*?\)))"
+    r"\s*\.\s*(?P<method>join|push)\s*\("
 )
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+# Client-controlled values: web extractors, request accessors, argv/stdin, and configured sources.
+source_re = re.compile(
+    r"\benv\s*::\s*args(?:_os)?\s*\(|\bstdin\s*\(\s*\)|\bread_line\s*\("
+    r"|\.\s*(?:match_info|query_string|uri|headers|param|query)\s*\("
+)
+tainted_param_type = re.compile(r"\b(?:Query|Json|Form|Path|Multipart|HttpRequest|Request|RawQuery)\s*<|\bHttpRequest\b")
+extra_sources = [item.strip() for item in os.environ.get("UBS_RUST_TAINT_SOURCES", "").split(",") if item.strip()]
+extra_source_re = re.compile(
+    r"(?<![A-Za-z0-9_])(?:" + "|".join(r"\s*(?:\.|::)\s*".join(re.escape(p) for p in re.split(r"\.|::", item)) for item in extra_sources) + r")(?![A-Za-z0-9_])"
+) if extra_sources else None
+# Any of these in the same fn means the segment is filtered or the joined path is re-checked.
+guard_re = re.compile(
+    r"\bcanonicalize\s*\([^;]*;[\s\S]*\bstarts_with\s*\(|\bstarts_with\s*\([\s\S]*\bcanonicalize\s*\("
+    r"|\bComponent\s*::\s*(?:ParentDir|Normal|RootDir|Prefix)\b|\bParentDir\b|\bis_absolute\s*\(|\bhas_root\s*\("
+    r"|\bsanitize(?:_filename|_path)?\s*(?:::\s*sanitize\s*)?\(|\b(?:is_safe|validate|safe)_(?:path|name|filename|segment)\s*\("
+    r"|\benclosed_name\s*\(|\bstrip_prefix\s*\(|\bunpack_in\s*\("
+)
+# Reducing the input to its final component drops any "../" it carried.
+sanitizing_rhs = re.compile(r"\.\s*file_name\s*\(\s*\)\s*\.\s*(?:and_then|map|ok_or|unwrap|expect|to_str)|\bPath\s*::\s*new\s*\([^;]*\)\s*\.\s*file_name\s*\(")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?(\(?[A-Za-z_][A-Za-z0-9_,\s]*\)?)\s*(?::[^=;]*)?=([^;]*);")
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
-def mask_range(chars, start, end):
-    for pos in range(start, min(end, len(chars))):
-        if chars[pos] != "\n":
-            chars[pos] = " "
+def find_matching(text: str, open_index: int, pair: str) -> int:
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch == pair[0]:
+            depth += 1
+        elif ch == pair[1]:
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
 
 
-def mask_comments_and_strings(text: str) -> str:
-    chars = list(text)
-    i = 0
-    n = len(chars)
-    state = "code"
-    while i < n:
-        ch = chars[i]
-        nxt = chars[i + 1] if i + 1 < n else ""
-        if state == "code":
-            if ch == "/" and nxt == "/":
-                start = i
-                i += 2
-                while i < n and chars[i] != "\n":
-                    i += 1
-                mask_range(chars, start, i)
-                continue
-            if ch == "/" and nxt == "*":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "block"
-                continue
-            if ch == "r":
-                j = i + 1
-                while j < n and chars[j] == "#":
-                    j += 1
-                if j < n and chars[j] == '"':
-                    hashes = j - i - 1
-                    close = '"' + ("#" * hashes)
-                    end = text.find(close, j + 1)
-                    if end == -1:
-                        end = n - 1
-                    else:
-                        end += len(close)
-                    mask_range(chars, i, end)
-                    i = end
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "string"
-                continue
-        elif state == "block":
-            if ch == "*" and nxt == "/":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        elif state == "string":
-            if ch == "\\":
-                chars[i] = " "
-                if i + 1 < n and chars[i + 1] != "\n":
-                    chars[i + 1] = " "
-                    i += 2
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        i += 1
-    return "".join(chars)
+def idents(expr: str):
+    return set(re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", expr))
 
 
-def line_number(text: str, offset: int) -> int:
-    return text.count("\n", 0, offset) + 1
+def is_source(expr: str) -> bool:
+    return bool(source_re.search(expr) or (extra_source_re and extra_source_re.search(expr)))
 
 
 seen = set()
@@ -1727,28 +2120,65 @@ for path in rust_files(root):
         continue
     masked = mask_comments_and_strings(text)
     lines = text.splitlines()
-    for hit in call.finditer(masked):
-        recv = hit.group("recv").lower()
-        arg = hit.group("arg").lower()
-        if not pathish.search(recv):
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching(masked, fn.end() - 1, "()")
+        if close_paren < 0:
             continue
-        if not untrusted.search(arg):
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
             continue
-        line = line_number(masked, hit.start())
-        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
-        if "ubs:ignore" in code:
+        end = find_matching(masked, brace, "{}")
+        if end < 0:
             continue
-        key = (str(path), line, code)
-        if key in seen:
+        body = masked[brace:end + 1]
+        raw_body = text[brace:end + 1]
+        if guard_re.search(body) or re.search(r"contains\s*\(\s*\"\.\.\"", raw_body):
             continue
-        seen.add(key)
-        print(f"{path}:{line}:{code}")
+        tainted = set()
+        sanitized = set()
+        for param in masked[fn.end():close_paren].split(","):
+            names, _, ty = param.partition(":")
+            if tainted_param_type.search(ty) or (extra_source_re and extra_source_re.search(ty)):
+                tainted.update(idents(names) - {"mut", "ref", "self"})
+        for stmt in let_re.finditer(body):
+            names = idents(stmt.group(1)) - {"mut", "ref"}
+            rhs = stmt.group(2)
+            if sanitizing_rhs.search(rhs):
+                tainted.difference_update(names)
+                sanitized.update(names)
+            elif is_source(rhs) or idents(rhs) & tainted:
+                tainted.update(names)
+        for hit in call.finditer(body):
+            recv = (hit.group("recv") or hit.group("ctor") or "").lower()
+            if hit.group("recv") and not pathish.search(recv):
+                continue
+            close = find_matching(body, hit.end() - 1, "()")
+            if close < 0:
+                continue
+            arg = body[hit.end():close]
+            arg_names = idents(arg)
+            inline = re.findall(r"(?<!\{)\{([A-Za-z_][A-Za-z0-9_]*)(?::[^}]*)?\}", raw_body[hit.end():close])
+            arg_names.update(inline)
+            lead = re.match(r"\s*&?\s*([A-Za-z_][A-Za-z0-9_]*)\s*$", arg)
+            named = bool(lead and lead.group(1) not in sanitized and untrusted.search(lead.group(1).lower()))
+            if not (named or arg_names & tainted or is_source(arg)):
+                continue
+            line = line_number(masked, brace + hit.start())
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            key = (str(path), line, code)
+            if key in seen:
+                continue
+            seen.add(key)
+            print(f"{path}:{line}:{code}")
 PY
 }
 
 count_path_traversal_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_path_traversal_matches | count_lines || true
+    cached_rust_matches rust_path_traversal_matches | count_lines || true
   else
     return 1
   fi
@@ -1764,7 +2194,7 @@ show_path_traversal_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_path_traversal_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_path_traversal_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -1774,7 +2204,7 @@ collect_samples_path_traversal() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_path_traversal_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_path_traversal_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -1789,13 +2219,18 @@ collect_samples_path_traversal() {
 rust_archive_entry_path_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 archive_receiver = re.compile(r"(?:entry|file|member|archive|zip|tar)", re.IGNORECASE)
 direct_join = re.compile(
     r"\b[A-Za-z_][A-Za-z0-9_]*(?:\.[A-Za-z_][A-Za-z0-9_]*)*"
@@ -1818,98 +2253,10 @@ safe_context = re.compile(
 )
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
-def mask_range(chars, start, end):
-    for pos in range(start, min(end, len(chars))):
-        if chars[pos] != "\n":
-            chars[pos] = " "
-
-
-def mask_comments_and_strings(text: str) -> str:
-    chars = list(text)
-    i = 0
-    n = len(chars)
-    state = "code"
-    while i < n:
-        ch = chars[i]
-        nxt = chars[i + 1] if i + 1 < n else ""
-        if state == "code":
-            if ch == "/" and nxt == "/":
-                start = i
-                i += 2
-                while i < n and chars[i] != "\n":
-                    i += 1
-                mask_range(chars, start, i)
-                continue
-            if ch == "/" and nxt == "*":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "block"
-                continue
-            if ch == "r":
-                j = i + 1
-                while j < n and chars[j] == "#":
-                    j += 1
-                if j < n and chars[j] == '"':
-                    hashes = j - i - 1
-                    close = '"' + ("#" * hashes)
-                    end = text.find(close, j + 1)
-                    if end == -1:
-                        end = n - 1
-                    else:
-                        end += len(close)
-                    mask_range(chars, i, end)
-                    i = end
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "string"
-                continue
-        elif state == "block":
-            if ch == "*" and nxt == "/":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        elif state == "string":
-            if ch == "\\":
-                chars[i] = " "
-                if i + 1 < n and chars[i + 1] != "\n":
-                    chars[i + 1] = " "
-                    i += 2
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        i += 1
-    return "".join(chars)
-
-
-def line_number(text: str, offset: int) -> int:
-    return text.count("\n", 0, offset) + 1
-
-
-def surrounding_code(lines, line_index):
-    start = max(0, line_index - 8)
-    end = min(len(lines), line_index + 4)
-    return "\n".join(lines[start:end])
+def surrounding_code(lines, line_index):
+    start = max(0, line_index - 8)
+    end = min(len(lines), line_index + 4)
+    return "\n".join(lines[start:end])
 
 
 seen = set()
@@ -1962,7 +2309,7 @@ PY
 
 count_archive_entry_path_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_archive_entry_path_matches | count_lines || true
+    cached_rust_matches rust_archive_entry_path_matches | count_lines || true
   else
     return 1
   fi
@@ -1978,7 +2325,7 @@ show_archive_entry_path_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_archive_entry_path_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_archive_entry_path_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -1988,7 +2335,7 @@ collect_samples_archive_entry_path() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_archive_entry_path_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_archive_entry_path_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -2003,13 +2350,18 @@ collect_samples_archive_entry_path() {
 rust_open_redirect_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 path_limit = 4
 redirect_key = r"(?:return[_-]?to|return[_-]?url|redirect(?:[_-]?url)?|next|continue|callback|target|destination|location|uri|url)"
 
@@ -2081,17 +2433,6 @@ local_path_re = re.compile(
 reject_re = re.compile(r'\b(?:return\s+Err|Err\s*\(|bail!\s*\(|ensure!\s*\(|anyhow!\s*\(|return\s+None|None\b|panic!\s*\()\b')
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
 def strip_line_comments(line: str) -> str:
     out = []
     quote = ""
@@ -2285,7 +2626,7 @@ PY
 
 count_open_redirect_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_open_redirect_matches | count_lines || true
+    cached_rust_matches rust_open_redirect_matches | count_lines || true
   else
     return 1
   fi
@@ -2301,7 +2642,7 @@ show_open_redirect_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_open_redirect_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_open_redirect_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -2311,7 +2652,7 @@ collect_samples_open_redirect() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_open_redirect_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_open_redirect_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -2326,13 +2667,18 @@ collect_samples_open_redirect() {
 rust_host_header_url_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 path_limit = 4
 
 host_key = r"(?:host|x-forwarded-host|forwarded|x-original-host)"
@@ -2376,17 +2722,6 @@ reject_re = re.compile(
 )
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
 def strip_line_comments(line: str) -> str:
     out = []
     quote = ""
@@ -2641,7 +2976,7 @@ PY
 
 count_host_header_url_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_host_header_url_matches | count_lines || true
+    cached_rust_matches rust_host_header_url_matches | count_lines || true
   else
     return 1
   fi
@@ -2657,7 +2992,7 @@ show_host_header_url_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_host_header_url_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_host_header_url_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -2667,7 +3002,7 @@ collect_samples_host_header_url() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_host_header_url_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_host_header_url_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -2682,13 +3017,18 @@ collect_samples_host_header_url() {
 rust_response_header_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 path_limit = 4
 
 source_re = re.compile(
@@ -2751,17 +3091,6 @@ reject_re = re.compile(
 )
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
 def strip_line_comments(line: str) -> str:
     out = []
     quote = ""
@@ -2895,7 +3224,6 @@ def refs_in_expr(expr: str, tainted):
     return refs
 
 
-
 def taint_from_expr(expr: str, tainted):
     if is_safe_expr(expr):
         return None
@@ -2997,7 +3325,7 @@ PY
 
 count_response_header_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_response_header_matches | count_lines || true
+    cached_rust_matches rust_response_header_matches | count_lines || true
   else
     return 1
   fi
@@ -3013,7 +3341,7 @@ show_response_header_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_response_header_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_response_header_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -3023,7 +3351,7 @@ collect_samples_response_header() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_response_header_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_response_header_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -3038,13 +3366,18 @@ collect_samples_response_header() {
 rust_request_url_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 path_limit = 4
 
 source_re = re.compile(
@@ -3086,17 +3419,6 @@ host_check_re = re.compile(
 reject_re = re.compile(r'\b(?:return\s+Err|Err\s*\(|bail!\s*\(|ensure!\s*\(|anyhow!\s*\(|panic!\s*\()\b')
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
 def strip_line_comments(line: str) -> str:
     out = []
     quote = ""
@@ -3280,7 +3602,7 @@ PY
 
 count_request_url_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_request_url_matches | count_lines || true
+    cached_rust_matches rust_request_url_matches | count_lines || true
   else
     return 1
   fi
@@ -3296,7 +3618,7 @@ show_request_url_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_request_url_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_request_url_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -3306,7 +3628,7 @@ collect_samples_request_url() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_request_url_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_request_url_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -3321,13 +3643,18 @@ collect_samples_request_url() {
 rust_sql_injection_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 path_limit = 5
 
 source_re = re.compile(
@@ -3377,17 +3704,6 @@ construction_re = re.compile(
 )
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
 def strip_line_comments(line: str) -> str:
     out = []
     quote = ""
@@ -3692,7 +4008,7 @@ PY
 
 count_sql_injection_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_sql_injection_matches | count_lines || true
+    cached_rust_matches rust_sql_injection_matches | count_lines || true
   else
     return 1
   fi
@@ -3708,7 +4024,7 @@ show_sql_injection_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_sql_injection_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_sql_injection_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -3718,7 +4034,7 @@ collect_samples_sql_injection() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_sql_injection_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_sql_injection_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -3733,12 +4049,17 @@ collect_samples_sql_injection() {
 rust_request_regex_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 
 source_re = re.compile(
     r'\b(?:params|query|form|body|json|payload|data|input)\s*\.\s*get\s*\('
@@ -3766,16 +4087,6 @@ safe_re = re.compile(
 )
 PATH_LIMIT = 5
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
 def strip_line_comments(line: str) -> str:
     out = []
     quote = ""
@@ -3992,7 +4303,7 @@ PY
 
 count_request_regex_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_request_regex_matches | count_lines || true
+    cached_rust_matches rust_request_regex_matches | count_lines || true
   else
     return 1
   fi
@@ -4008,7 +4319,7 @@ show_request_regex_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_request_regex_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_request_regex_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -4018,7 +4329,7 @@ collect_samples_request_regex() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_request_regex_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_request_regex_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -4033,12 +4344,17 @@ collect_samples_request_regex() {
 rust_unbounded_request_body_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import mask_range, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 
 TO_BYTES_RE = re.compile(r"\b(?:(?:hyper|axum)::)?body::to_bytes\s*\(")
 UNSAFE_LIMIT_RE = re.compile(r"\b(?:(?:std::)?usize|u64|u32)::MAX\b")
@@ -4050,21 +4366,6 @@ SAFE_CONTEXT_RE = re.compile(
 )
 CONTENT_LENGTH_GUARD_RE = re.compile(r"\b(?:CONTENT_LENGTH|content_length|content-length)\b[\s\S]{0,240}\b(?:>|>=)\b")
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-def mask_range(chars, start, end):
-    for pos in range(start, min(end, len(chars))):
-        if chars[pos] != "\n":
-            chars[pos] = " "
-
 def mask_comments_and_strings(text: str) -> str:
     chars = list(text)
     i = 0
@@ -4231,7 +4532,7 @@ PY
 
 count_unbounded_request_body_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_unbounded_request_body_matches | count_lines || true
+    cached_rust_matches rust_unbounded_request_body_matches | count_lines || true
   else
     return 1
   fi
@@ -4247,7 +4548,7 @@ show_unbounded_request_body_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_unbounded_request_body_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_unbounded_request_body_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -4257,7 +4558,7 @@ collect_samples_unbounded_request_body() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_unbounded_request_body_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_unbounded_request_body_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -4272,13 +4573,18 @@ collect_samples_unbounded_request_body() {
 rust_cors_credential_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 
 credential_re = re.compile(
     r'Access-Control-Allow-Credentials|ACCESS_CONTROL_ALLOW_CREDENTIALS|'
@@ -4318,17 +4624,6 @@ safe_re = re.compile(
 reject_re = re.compile(r'\b(?:return|return\s+Err|Err\s*\(|bail!\s*\(|ensure!\s*\()\b')
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
 def strip_line_comments(line: str) -> str:
     out = []
     quote = ""
@@ -4515,7 +4810,7 @@ PY
 
 count_cors_credential_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_cors_credential_matches | count_lines || true
+    cached_rust_matches rust_cors_credential_matches | count_lines || true
   else
     return 1
   fi
@@ -4531,7 +4826,7 @@ show_cors_credential_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_cors_credential_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_cors_credential_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -4541,7 +4836,7 @@ collect_samples_cors_credential() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_cors_credential_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_cors_credential_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -4556,13 +4851,18 @@ collect_samples_cors_credential() {
 rust_command_executable_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 identifier = r"[A-Za-z_][A-Za-z0-9_]*"
 call = re.compile(
     rf"\b(?:std::process::)?Command\s*::\s*new\s*\(\s*&?\s*"
@@ -4574,94 +4874,6 @@ untrusted = re.compile(
 adapter_methods = {"as_str", "as_ref", "to_string", "into_string"}
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
-def mask_range(chars, start, end):
-    for pos in range(start, min(end, len(chars))):
-        if chars[pos] != "\n":
-            chars[pos] = " "
-
-
-def mask_comments_and_strings(text: str) -> str:
-    chars = list(text)
-    i = 0
-    n = len(chars)
-    state = "code"
-    while i < n:
-        ch = chars[i]
-        nxt = chars[i + 1] if i + 1 < n else ""
-        if state == "code":
-            if ch == "/" and nxt == "/":
-                start = i
-                i += 2
-                while i < n and chars[i] != "\n":
-                    i += 1
-                mask_range(chars, start, i)
-                continue
-            if ch == "/" and nxt == "*":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "block"
-                continue
-            if ch == "r":
-                j = i + 1
-                while j < n and chars[j] == "#":
-                    j += 1
-                if j < n and chars[j] == '"':
-                    hashes = j - i - 1
-                    close = '"' + ("#" * hashes)
-                    end = text.find(close, j + 1)
-                    if end == -1:
-                        end = n - 1
-                    else:
-                        end += len(close)
-                    mask_range(chars, i, end)
-                    i = end
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "string"
-                continue
-        elif state == "block":
-            if ch == "*" and nxt == "/":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        elif state == "string":
-            if ch == "\\":
-                chars[i] = " "
-                if i + 1 < n and chars[i + 1] != "\n":
-                    chars[i + 1] = " "
-                    i += 2
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        i += 1
-    return "".join(chars)
-
-
-def line_number(text: str, offset: int) -> int:
-    return text.count("\n", 0, offset) + 1
-
-
 def suspicious_expr(expr: str) -> bool:
     parts = [part.strip().lower() for part in expr.split(".") if part.strip()]
     while parts and parts[-1] in adapter_methods:
@@ -4695,7 +4907,7 @@ PY
 
 count_command_executable_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_command_executable_matches | count_lines || true
+    cached_rust_matches rust_command_executable_matches | count_lines || true
   else
     return 1
   fi
@@ -4711,7 +4923,7 @@ show_command_executable_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_command_executable_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_command_executable_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -4721,7 +4933,7 @@ collect_samples_command_executable() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_command_executable_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_command_executable_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -4736,13 +4948,18 @@ collect_samples_command_executable() {
 rust_temp_file_race_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 identifier = r"[A-Za-z_][A-Za-z0-9_]*"
 temp_assignment = re.compile(
     rf"\blet\s+(?:mut\s+)?(?P<var>{identifier})\s*(?::[^=]+)?=\s*(?P<expr>[^;]*temp_dir\s*\(\s*\)[^;]*\.\s*join\s*\([^;]*)"
@@ -4764,98 +4981,10 @@ safe_context = re.compile(
 )
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
-def mask_range(chars, start, end):
-    for pos in range(start, min(end, len(chars))):
-        if chars[pos] != "\n":
-            chars[pos] = " "
-
-
-def mask_comments_and_strings(text: str) -> str:
-    chars = list(text)
-    i = 0
-    n = len(chars)
-    state = "code"
-    while i < n:
-        ch = chars[i]
-        nxt = chars[i + 1] if i + 1 < n else ""
-        if state == "code":
-            if ch == "/" and nxt == "/":
-                start = i
-                i += 2
-                while i < n and chars[i] != "\n":
-                    i += 1
-                mask_range(chars, start, i)
-                continue
-            if ch == "/" and nxt == "*":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "block"
-                continue
-            if ch == "r":
-                j = i + 1
-                while j < n and chars[j] == "#":
-                    j += 1
-                if j < n and chars[j] == '"':
-                    hashes = j - i - 1
-                    close = '"' + ("#" * hashes)
-                    end = text.find(close, j + 1)
-                    if end == -1:
-                        end = n - 1
-                    else:
-                        end += len(close)
-                    mask_range(chars, i, end)
-                    i = end
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "string"
-                continue
-        elif state == "block":
-            if ch == "*" and nxt == "/":
-                chars[i] = chars[i + 1] = " "
-                i += 2
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        elif state == "string":
-            if ch == "\\":
-                chars[i] = " "
-                if i + 1 < n and chars[i + 1] != "\n":
-                    chars[i + 1] = " "
-                    i += 2
-                    continue
-            if ch == '"':
-                chars[i] = " "
-                i += 1
-                state = "code"
-                continue
-            if ch != "\n":
-                chars[i] = " "
-        i += 1
-    return "".join(chars)
-
-
-def line_number(text: str, offset: int) -> int:
-    return text.count("\n", 0, offset) + 1
-
-
-def surrounding_code(lines, line_index):
-    start = max(0, line_index - 5)
-    end = min(len(lines), line_index + 6)
-    return "\n".join(lines[start:end])
+def surrounding_code(lines, line_index):
+    start = max(0, line_index - 5)
+    end = min(len(lines), line_index + 6)
+    return "\n".join(lines[start:end])
 
 
 def statement_from(lines, idx, max_lines=8):
@@ -4932,7 +5061,7 @@ PY
 
 count_temp_file_race_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_temp_file_race_matches | count_lines || true
+    cached_rust_matches rust_temp_file_race_matches | count_lines || true
   else
     return 1
   fi
@@ -4948,7 +5077,7 @@ show_temp_file_race_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_temp_file_race_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_temp_file_race_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -4958,7 +5087,7 @@ collect_samples_temp_file_race() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_temp_file_race_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_temp_file_race_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -4974,9 +5103,11 @@ rust_tls_indirect_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
 import re
+import signal
 import sys
 from pathlib import Path
 
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1]).resolve()
 base = root if root.is_dir() else root.parent
 skip_dirs = {".git", "target", ".cargo", "node_modules"}
@@ -5040,13 +5171,18 @@ PY
 rust_security_randomness_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 
-skip_dirs = {".git", "target", ".cargo", "node_modules"}
 rng_methods = (
     "gen", "gen_range", "gen_bool", "gen_ratio", "random", "random_range",
     "random_bool", "fill", "fill_bytes", "next_u32", "next_u64", "sample",
@@ -5098,17 +5234,6 @@ predictable_source_re = re.compile(
 )
 
 
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
-
 def strip_line_comments(line: str) -> str:
     out = []
     quote = ""
@@ -5227,7 +5352,30 @@ def has_security_context(statement: str, fn_name: str) -> bool:
     compact = normalized(visible)
     if any(term in compact for term in security_terms):
         return True
-    return bool(re.search(r"(?<![A-Za-z0-9_])(?:key|sig)(?![A-Za-z0-9_])", visible, re.IGNORECASE))
+    return bool(re.search(r"(?<![A-Za-z0-9_])(?:key|sig|sid|iv)(?![A-Za-z0-9_])", visible, re.IGNORECASE))
+
+
+fill_target_re = re.compile(r"\.\s*(?:fill|fill_bytes|try_fill|try_fill_bytes)\s*\(\s*&\s*mut\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)")
+
+
+def random_value_target(statement: str):
+    """Name bound to an insecure RNG result: `let n = rng.gen()` or `rng.fill(&mut buf)`."""
+    fill = fill_target_re.search(statement)
+    if fill:
+        return fill.group("name")
+    assign = assign_re.match(statement)
+    return assign.group("lhs") if assign else None
+
+
+def uses_random_value(statement: str, random_vars):
+    visible = without_string_literals(statement)
+    for name in random_vars:
+        if re.search(rf"(?<![A-Za-z0-9_.]){re.escape(name)}(?![A-Za-z0-9_])", visible):
+            return name
+        # Inline format captures such as format!("{raw:x}") live inside the literal.
+        if re.search(rf"\{{{re.escape(name)}(?::[^}}]*)?\}}", statement):
+            return name
+    return None
 
 
 def has_ignore(lines, line_no):
@@ -5295,6 +5443,7 @@ def analyze(path: Path, issues):
         return
     lines = text.splitlines()
     insecure_rng_vars = set()
+    random_vars = {}
     function_stack = []
     pending_function = ""
     brace_depth = 0
@@ -5308,6 +5457,7 @@ def analyze(path: Path, issues):
         opens = visible_line.count("{")
         closes = visible_line.count("}")
         if function_name:
+            random_vars.clear()
             if opens > 0:
                 function_stack.append((function_name, brace_depth + opens))
                 pending_function = ""
@@ -5332,6 +5482,19 @@ def analyze(path: Path, issues):
             if key not in seen:
                 seen.add(key)
                 issues.append((path, line_no, f"{source_line(lines, line_no)}  [{source} in security-sensitive generation context]"))
+        elif source and not predictable_source_re.search(source):
+            target = random_value_target(statement)
+            if target:
+                random_vars[target] = (line_no, source)
+        elif line_sensitive and random_vars:
+            # A plain random number becomes a secret once it is named or formatted as one.
+            name = uses_random_value(statement, random_vars)
+            if name:
+                origin_line, origin = random_vars[name]
+                key = (str(path), line_no, name)
+                if key not in seen:
+                    seen.add(key)
+                    issues.append((path, line_no, f"{source_line(lines, line_no)}  [{name} from {origin} (line {origin_line}) flows into security-sensitive value]"))
         brace_depth += opens - closes
 
 
@@ -5346,7 +5509,7 @@ PY
 
 count_security_randomness_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_security_randomness_matches | count_lines || true
+    cached_rust_matches rust_security_randomness_matches | count_lines || true
   else
     return 1
   fi
@@ -5362,7 +5525,7 @@ show_security_randomness_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_security_randomness_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_security_randomness_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -5372,7 +5535,7 @@ collect_samples_security_randomness() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_security_randomness_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_security_randomness_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -5389,9 +5552,11 @@ rust_constant_time_compare_matches() {
   python3 - "$PROJECT_DIR" <<'PY'
 import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 skip_dirs = {".git", "target", ".cargo", "node_modules"}
 
@@ -5650,7 +5815,7 @@ PY
 
 count_constant_time_compare_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_constant_time_compare_matches | count_lines || true
+    cached_rust_matches rust_constant_time_compare_matches | count_lines || true
   else
     return 1
   fi
@@ -5666,7 +5831,7 @@ show_constant_time_compare_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_constant_time_compare_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_constant_time_compare_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -5676,7 +5841,7 @@ collect_samples_constant_time_compare() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_constant_time_compare_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_constant_time_compare_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -5693,9 +5858,11 @@ rust_jwt_verification_matches() {
   python3 - "$PROJECT_DIR" <<'PY'
 import os
 import re
+import signal
 import sys
 from pathlib import Path
 
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
 root = Path(sys.argv[1])
 skip_dirs = {".git", "target", ".cargo", "node_modules"}
 call_suffix = r"(?:\s*::\s*<[^>\n]+>)?\s*\("
@@ -6056,7 +6223,7 @@ PY
 
 count_jwt_verification_matches() {
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_jwt_verification_matches | count_lines || true
+    cached_rust_matches rust_jwt_verification_matches | count_lines || true
   else
     return 1
   fi
@@ -6072,7 +6239,7 @@ show_jwt_verification_examples() {
     print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
     printed=$((printed + 1))
     [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-  done < <(rust_jwt_verification_matches | head -n "$limit")
+  done < <(cached_rust_matches rust_jwt_verification_matches | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
@@ -6082,7 +6249,7 @@ collect_samples_jwt_verification() {
     printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_jwt_verification_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_jwt_verification_matches | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -6097,26 +6264,16 @@ collect_samples_jwt_verification() {
 rust_format_literal_matches() {
   [[ "$have_python3" -eq 1 ]] || return 1
   python3 - "$PROJECT_DIR" <<'PY'
+import os
+import signal
 import sys
 from pathlib import Path
 
-root = Path(sys.argv[1])
-
-
-def rust_files(path: Path):
-    if path.is_file():
-        if path.suffix == ".rs":
-            yield path
-        return
-    skip_dirs = {".git", "target", ".cargo", "node_modules"}
-    for child in path.rglob("*.rs"):
-        if skip_dirs.intersection(child.parts):
-            continue
-        yield child
-
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, rust_files
 
-def line_number(text: str, offset: int) -> int:
-    return text.count("\n", 0, offset) + 1
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
 
 
 def is_ident(ch: str) -> bool:
@@ -6258,48 +6415,13834 @@ for path in rust_files(root):
         text = path.read_text(encoding="utf-8", errors="replace")
     except OSError:
         continue
-    for line, code in iter_format_literal_lines(path, text):
-        key = (str(path), line)
+    for line, code in iter_format_literal_lines(path, text):
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_format_literal_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_format_literal_matches | count_lines || true
+  else
+    "${GREP_RN[@]}" -e "format!\(\s*([rR]?#?\"[^\{\}]*\"#?)\s*,?\s*\)" "$PROJECT_DIR" 2>/dev/null | count_lines || true
+  fi
+}
+
+show_format_literal_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  if [[ "$have_python3" -eq 1 ]]; then
+    while IFS= read -r rawline; do
+      [[ -z "$rawline" ]] && continue
+      parse_grep_line "$rawline" || continue
+      print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+      printed=$((printed + 1))
+      [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+    done < <(cached_rust_matches rust_format_literal_matches | head -n "$limit")
+  else
+    show_detailed_finding "format!\(\s*([rR]?#?\"[^\{\}]*\"#?)\s*,?\s*\)" "$limit"
+    return $?
+  fi
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_format_literal() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    collect_samples_rg "format!\(\s*([rR]?#?\"[^\{\}]*\"#?)\s*,?\s*\)" "$limit"
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_format_literal_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_transmute_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def split_top_level(text: str, sep: str = ","):
+    parts = []
+    depth = 0
+    current = []
+    for ch in text:
+        if ch in "<([{":
+            depth += 1
+        elif ch in ">)]}":
+            depth -= 1
+        if ch == sep and depth == 0:
+            parts.append("".join(current).strip())
+            current = []
+            continue
+        current.append(ch)
+    tail = "".join(current).strip()
+    if tail:
+        parts.append(tail)
+    return parts
+
+
+PRIMITIVE_SIZES = {
+    "u8": 1, "i8": 1, "bool": 1,
+    "u16": 2, "i16": 2,
+    "u32": 4, "i32": 4, "f32": 4, "char": 4,
+    "u64": 8, "i64": 8, "f64": 8, "usize": 8, "isize": 8,
+    "u128": 16, "i128": 16,
+}
+INTEGER_TYPES = {name for name in PRIMITIVE_SIZES if name not in {"bool", "char", "f32", "f64"}}
+HEAP_OWNERS = re.compile(
+    r"^(?:std::(?:vec|string|boxed|rc|sync|collections|ffi|path)::)?"
+    r"(?:Vec|String|Box|Rc|Arc|HashMap|HashSet|BTreeMap|BTreeSet|VecDeque|CString|OsString|PathBuf)\b"
+)
+HEAP_INITS = [
+    (re.compile(r"^(?:vec!\s*\[|(?:std::vec::)?Vec::)"), "Vec<_>"),
+    (re.compile(r"^(?:(?:std::string::)?String::|format!\s*\()|\.to_string\(\s*\)$"), "String"),
+    (re.compile(r"^(?:std::boxed::)?Box::"), "Box<_>"),
+    (re.compile(r"^(?:std::rc::)?Rc::"), "Rc<_>"),
+    (re.compile(r"^(?:std::sync::)?Arc::"), "Arc<_>"),
+]
+
+
+def type_size(ty: str):
+    ty = re.sub(r"\s+", " ", ty.strip())
+    if ty in PRIMITIVE_SIZES:
+        return PRIMITIVE_SIZES[ty]
+    array = re.fullmatch(r"\[(.+);\s*([0-9_]+)\]", ty)
+    if array:
+        inner = type_size(array.group(1))
+        return inner * int(array.group(2).replace("_", "")) if inner is not None else None
+    pointer = re.fullmatch(r"(?:&\s*(?:'[A-Za-z_]+\s+)?(?:mut\s+)?|\*\s*(?:const|mut)\s+)(.+)", ty)
+    if pointer:
+        inner = pointer.group(1).strip()
+        if inner.startswith("[") and ";" not in inner or inner == "str" or inner.startswith("dyn "):
+            return 16
+        return 8
+    if re.match(r"^(?:std::(?:vec|string)::)?(?:Vec|String)\b", ty):
+        return 24
+    boxed = re.fullmatch(r"(?:std::boxed::)?Box\s*<(.+)>", ty)
+    if boxed:
+        inner = boxed.group(1).strip()
+        if inner.startswith("[") and ";" not in inner or inner == "str" or inner.startswith("dyn "):
+            return 16
+        return 8
+    return None
+
+
+LIFETIME = re.compile(r"'[A-Za-z_][A-Za-z0-9_]*\b\s*")
+
+
+def lifetimes_only_differ(src: str, dst: str) -> bool:
+    """True when the two types are identical once lifetimes are erased, i.e. the transmute only extends a borrow."""
+    def erase(ty: str) -> str:
+        ty = LIFETIME.sub("", ty)
+        ty = re.sub(r"<\s*,\s*", "<", ty)
+        ty = re.sub(r",\s*>", ">", ty)
+        ty = re.sub(r"<\s*>", "", ty)
+        return re.sub(r"\s+", "", ty)
+    if "'" not in src + dst:
+        return False
+    return erase(src) == erase(dst) and re.sub(r"\s+", "", src) != re.sub(r"\s+", "", dst)
+
+
+def is_heap_owner(ty: str) -> bool:
+    return bool(HEAP_OWNERS.match(ty.strip()))
+
+
+def is_integer_like(ty: str) -> bool:
+    ty = ty.strip()
+    return ty in INTEGER_TYPES or bool(re.match(r"^\*\s*(?:const|mut)\b", ty))
+
+
+def fn_context(masked: str, offset: int, functions):
+    enclosing = [span for span in functions if span[1] < offset < span[2]]
+    if not enclosing:
+        return None
+    return max(enclosing, key=lambda span: span[1])
+
+
+def param_types(signature: str):
+    types = {}
+    open_paren = signature.find("(")
+    close_paren = find_matching_paren(signature, open_paren) if open_paren >= 0 else -1
+    if close_paren < 0:
+        return types
+    for param in split_top_level(signature[open_paren + 1:close_paren]):
+        match = re.match(r"(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(.+)$", param)
+        if match:
+            types[match.group(1)] = match.group(2).strip()
+    return types
+
+
+def return_type(signature: str):
+    match = re.search(r"\)\s*->\s*([^{]+?)\s*(?:where\b[^{]*)?\{?$", signature.strip())
+    return match.group(1).strip() if match else None
+
+
+def infer_expr_type(expr: str, body: str, params):
+    expr = expr.strip()
+    for pattern, label in HEAP_INITS:
+        if pattern.search(expr):
+            return label
+    if not re.fullmatch(r"[A-Za-z_][A-Za-z0-9_]*", expr):
+        return None
+    if expr in params:
+        return params[expr]
+    for let in re.finditer(
+        r"\blet\s+(?:mut\s+)?" + re.escape(expr) + r"\b\s*(?::\s*([^=;]+?))?\s*=\s*([^;]+);", body
+    ):
+        if let.group(1):
+            return let.group(1).strip()
+        inferred = infer_expr_type(let.group(2), "", {})
+        if inferred:
+            return inferred
+    return None
+
+
+def target_type(masked: str, call_start: int, call_end: int, fn_span):
+    line_start = masked.rfind("\n", 0, call_start) + 1
+    statement_start = max(masked.rfind(";", 0, call_start), masked.rfind("{", 0, line_start)) + 1
+    prefix = masked[statement_start:call_start]
+    annotated = re.search(r"\blet\s+(?:mut\s+)?[A-Za-z_][A-Za-z0-9_]*\s*:\s*([^=]+?)\s*=", prefix)
+    if annotated:
+        return annotated.group(1).strip()
+    if fn_span is None:
+        return None
+    signature, _, fn_close = fn_span[0], fn_span[1], fn_span[2]
+    trailing = masked[call_end + 1:fn_close]
+    if re.fullmatch(r"[\s}]*", trailing) or re.match(r"\s*;", trailing) and re.search(r"\breturn\s*(?:unsafe\s*\{\s*)?$", prefix):
+        return return_type(signature)
+    return None
+
+
+call_re = re.compile(r"(?<![A-Za-z0-9_])(?:(?:std|core)::)?(?:mem::)?transmute\s*(?:::\s*<(?P<turbofish>[^()]*?)>)?\s*\(")
+fn_start = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*[^{;]*\{")
+struct_re = re.compile(r"(?P<attrs>(?:#\s*\[[^\]]*\]\s*)*)(?:pub(?:\s*\([^)]*\))?\s+)?struct\s+(?P<name>[A-Z][A-Za-z0-9_]*)")
+repr_re = re.compile(r"#\s*\[\s*repr\s*\(([^)]*)\)")
+ptr_cast_re = re.compile(r"\bas\s+\*\s*(?:const|mut)\s+(?P<dst>[A-Za-z_][A-Za-z0-9_:]*)|\.\s*cast(?:_mut)?\s*::\s*<\s*(?P<cast>[A-Za-z_][A-Za-z0-9_:]*)\s*>\s*\(\s*\)")
+seen = set()
+
+# repr of every struct in the project; False means the default repr(Rust) layout.
+# Definitions in the same file win over same-named structs elsewhere.
+struct_reprs = {}
+file_reprs = {}
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "struct" not in text:
+        continue
+    for item in struct_re.finditer(mask_comments_and_strings(text)):
+        reprs = set()
+        for attr in repr_re.finditer(item.group("attrs")):
+            reprs.update(part.strip().split("(")[0] for part in attr.group(1).split(","))
+        previous = struct_reprs.get(item.group("name"))
+        stable = bool(reprs & {"C", "transparent"})
+        struct_reprs[item.group("name")] = stable if previous is None else previous and stable
+        file_reprs.setdefault(str(path), {})[item.group("name")] = stable
+
+
+def pointee_struct(ty, reprs):
+    if not ty:
+        return None
+    ty = re.sub(r"^(?:&\s*(?:'[A-Za-z_]+\s+)?(?:mut\s+)?|\*\s*(?:const|mut)\s+|(?:std::boxed::)?Box\s*<)", "", ty.strip())
+    name = re.match(r"(?:[A-Za-z_][A-Za-z0-9_]*::)*([A-Z][A-Za-z0-9_]*)", ty)
+    if name and name.group(1) in reprs:
+        return name.group(1)
+    return None
+
+
+def layout_mismatch(src, dst, path):
+    """Name the repr(Rust) side when src and dst are different project structs."""
+    reprs = {**struct_reprs, **file_reprs.get(str(path), {})}
+    src_struct, dst_struct = pointee_struct(src, reprs), pointee_struct(dst, reprs)
+    if not src_struct or not dst_struct or src_struct == dst_struct:
+        return None
+    for name in (src_struct, dst_struct):
+        if not reprs[name]:
+            return f"{src_struct} -> {dst_struct}, {name} has no #[repr(C)]/#[repr(transparent)]"
+    return None
+
+
+def cast_source_type(expr: str, body: str, params):
+    expr = expr.strip()
+    chained = re.search(r"\bas\s+(\*\s*(?:const|mut)\s+[A-Za-z_][A-Za-z0-9_:]*)\s*$", expr)
+    if chained:
+        return chained.group(1)
+    borrowed = re.fullmatch(r"&\s*(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)", expr)
+    if borrowed:
+        inner = infer_expr_type(borrowed.group(1), body, params)
+        return "&" + inner if inner else None
+    return infer_expr_type(expr, body, params)
+
+
+def cast_operand(masked: str, hit_start: int) -> str:
+    depth = 0
+    idx = hit_start
+    while idx > 0:
+        ch = masked[idx - 1]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                break
+            depth -= 1
+        elif depth == 0 and ch in ";{},=\n":
+            break
+        idx -= 1
+    return masked[idx:hit_start]
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    functions = []
+    for fn_match in fn_start.finditer(masked):
+        open_brace = fn_match.end() - 1
+        close_brace = find_matching_brace(masked, open_brace)
+        if close_brace >= 0:
+            functions.append((masked[fn_match.start():open_brace + 1], open_brace, close_brace))
+    for hit in call_re.finditer(masked):
+        if re.search(r"\bfn\s+$", masked[max(0, hit.start() - 8):hit.start()]) or "use " in masked[masked.rfind("\n", 0, hit.start()) + 1:hit.start()]:
+            continue
+        open_paren = hit.end() - 1
+        close_paren = find_matching_paren(masked, open_paren)
+        if close_paren < 0:
+            continue
+        fn_span = fn_context(masked, hit.start(), functions)
+        params = param_types(fn_span[0]) if fn_span else {}
+        body = masked[fn_span[1]:fn_span[2]] if fn_span else ""
+        src = dst = None
+        if hit.group("turbofish"):
+            generics = split_top_level(hit.group("turbofish"))
+            if len(generics) == 2:
+                src, dst = (g if g != "_" else None for g in generics)
+        if src is None:
+            src = infer_expr_type(masked[open_paren + 1:close_paren], body, params)
+        if dst is None:
+            dst = target_type(masked, hit.start(), close_paren, fn_span)
+        escalated = False
+        layout = layout_mismatch(src, dst, path)
+        if src and dst and lifetimes_only_differ(src, dst):
+            kind = "lifetime"
+        elif layout and type_size(src) is None:
+            kind = "layout"
+        elif src and dst:
+            if is_heap_owner(src) and (is_integer_like(dst) or dst.startswith("[")):
+                escalated = True
+            else:
+                src_size, dst_size = type_size(src), type_size(dst)
+                if src_size is not None and dst_size is not None and src_size != dst_size:
+                    escalated = True
+            kind = "escalated" if escalated else "plain"
+        else:
+            kind = "plain"
+        if kind != mode:
+            continue
+        line = line_number(masked, hit.start())
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        if kind == "lifetime":
+            print(f"{path}:{line}:{code}  [{src} -> {dst}]")
+            continue
+        if kind == "layout":
+            print(f"{path}:{line}:{code}  [transmute {layout}]")
+            continue
+        print(f"{path}:{line}:{code}")
+    if mode != "layout" or not struct_reprs:
+        continue
+    for hit in ptr_cast_re.finditer(masked):
+        dst = hit.group("dst") or hit.group("cast")
+        if not pointee_struct(dst, struct_reprs):
+            continue
+        fn_span = fn_context(masked, hit.start(), functions)
+        params = param_types(fn_span[0]) if fn_span else {}
+        body = masked[fn_span[1]:fn_span[2]] if fn_span else ""
+        src = cast_source_type(cast_operand(masked, hit.start()), body, params)
+        layout = layout_mismatch(src, dst, path)
+        if not layout:
+            continue
+        line = line_number(masked, hit.start())
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}  [pointer cast {layout}]")
+PY
+}
+
+count_transmute_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_transmute_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_transmute_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_transmute_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_transmute() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_transmute_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_guard_across_await_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def enclosing_block(text: str, offset: int, lower: int) -> int:
+    depth = 0
+    for idx in range(offset - 1, lower - 1, -1):
+        ch = text[idx]
+        if ch == "}":
+            depth += 1
+        elif ch == "{":
+            if depth == 0:
+                return idx
+            depth -= 1
+    return -1
+
+
+def statement_end(text: str, start: int, limit: int) -> int:
+    depth = 0
+    for idx in range(start, limit):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            if depth == 0:
+                return -1
+            depth -= 1
+        elif ch == ";" and depth == 0:
+            return idx
+    return -1
+
+
+async_context = re.compile(r"\basync\s+(?:fn\s+[A-Za-z_][A-Za-z0-9_]*[^{;]*|move\s*|)\{")
+let_binding = re.compile(r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=(?!=)")
+std_guard = re.compile(
+    r"\.\s*(?:lock|read|write)\s*\(\s*\)\s*(?:\.\s*(?:unwrap|expect|unwrap_or_else)\s*\((?:[^()]|\([^()]*\))*\)|\?)$"
+)
+async_guard = re.compile(r"\.\s*(?:lock|read|write)\s*\(\s*\)\s*\.await\s*\??$")
+await_point = re.compile(r"\.await\b")
+loop_header = re.compile(r"\b(?:(?P<kind>for|while)\b[^{;]*|(?P<bare>loop)\s*)\{")
+# sync_only: tokio Mutexes whose guards never live across another .await could be a cheaper std Mutex.
+tokio_mutex_import = re.compile(r"\buse\s+tokio::sync::(?:Mutex\b|\{[^}]*\bMutex\b)")
+std_mutex_import = re.compile(r"\buse\s+std::sync::(?:Mutex\b|\{[^}]*\bMutex\b)")
+tokio_mutex_decl = re.compile(
+    r"\b(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*:\s*(?:Arc\s*<\s*)?(?P<path>tokio::sync::)?Mutex\s*<"
+    r"|\blet\s+(?:mut\s+)?(?P<local>[A-Za-z_][A-Za-z0-9_]*)\s*=\s*(?:Arc::new\s*\(\s*)?(?P<lpath>tokio::sync::)?Mutex::new\s*\("
+)
+tokio_lock_site = re.compile(
+    r"(?P<recv>[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\.\s*lock\s*\(\s*\)\s*\.await\b"
+)
+seen = set()
+
+
+def tokio_mutex_sync_only(masked: str, contexts):
+    """Yield (offset, name) for tokio Mutex declarations that no guard ever holds across an .await."""
+    imported = tokio_mutex_import.search(masked) and not std_mutex_import.search(masked)
+    declared = {}
+    for decl in tokio_mutex_decl.finditer(masked):
+        name = decl.group("name") or decl.group("local")
+        explicit = decl.group("path") or decl.group("lpath")
+        if explicit or imported:
+            declared.setdefault(name, decl.start())
+    for name, decl_offset in declared.items():
+        sites = 0
+        held = False
+        for site in tokio_lock_site.finditer(masked):
+            if re.sub(r"\s+", "", site.group("recv")).split(".")[-1] != name:
+                continue
+            sites += 1
+            owner = None
+            for open_brace, close_brace in contexts:
+                if open_brace < site.start() < close_brace and (owner is None or open_brace > owner[0]):
+                    owner = (open_brace, close_brace)
+            if owner is None:
+                continue
+            statement_start = max(masked.rfind(";", owner[0], site.start()), masked.rfind("{", owner[0], site.start())) + 1
+            statement_start += len(masked[statement_start:site.start()]) - len(masked[statement_start:site.start()].lstrip())
+            end = statement_end(masked, site.end(), owner[1])
+            if end < 0:
+                end = owner[1]
+            binding = let_binding.match(masked, statement_start)
+            if binding and async_guard.search(masked[binding.end():end].strip()):
+                # `let guard = m.lock().await;` lives to the end of its block or drop(guard).
+                scope_open = enclosing_block(masked, binding.start(), owner[0])
+                scope_close = find_matching_brace(masked, scope_open) if scope_open >= 0 else owner[1]
+                release = re.search(r"\b(?:(?:std::)?mem::)?drop\s*\(\s*" + re.escape(binding.group(1)) + r"\s*\)", masked[site.end():scope_close])
+                region = (site.end(), site.end() + release.start() if release else scope_close)
+            else:
+                # Temporaries (`m.lock().await.push(x);`) die with the statement.
+                region = (site.end(), end)
+            if await_point.search(masked, region[0], region[1]):
+                held = True
+                break
+        if sites and not held:
+            yield decl_offset, name
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    contexts = []
+    for ctx in async_context.finditer(masked):
+        open_brace = ctx.end() - 1
+        close_brace = find_matching_brace(masked, open_brace)
+        if close_brace >= 0:
+            contexts.append((open_brace, close_brace))
+    if mode == "sync_only":
+        for offset, name in tokio_mutex_sync_only(masked, contexts):
+            line = line_number(masked, offset)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code or (str(path), line) in seen:
+                continue
+            seen.add((str(path), line))
+            print(f"{path}:{line}:{code}  [tokio Mutex {name} is never held across .await]")
+        continue
+    for open_brace, close_brace in contexts:
+        nested = [span for span in contexts if open_brace < span[0] and span[1] < close_brace]
+        for binding in let_binding.finditer(masked, open_brace, close_brace):
+            if any(start < binding.start() < end for start, end in nested):
+                continue
+            end = statement_end(masked, binding.end(), close_brace)
+            if end < 0:
+                continue
+            init = masked[binding.end():end].strip()
+            if init.startswith(("*", "&")):
+                continue
+            if mode in ("std", "loop"):
+                if ".await" in init or not std_guard.search(init):
+                    continue
+            elif not async_guard.search(init):
+                continue
+            scope_open = enclosing_block(masked, binding.start(), open_brace)
+            if scope_open < 0:
+                continue
+            scope_close = find_matching_brace(masked, scope_open)
+            if scope_close < 0:
+                continue
+            name = binding.group(1)
+            region_end = scope_close
+            release = re.search(r"\b(?:(?:std::)?mem::)?drop\s*\(\s*" + re.escape(name) + r"\s*\)", masked[end:scope_close])
+            if release:
+                region_end = end + release.start()
+            held_across = False
+            for point in await_point.finditer(masked, end, region_end):
+                if any(start < point.start() < stop for start, stop in nested):
+                    continue
+                held_across = True
+                break
+            if not held_across:
+                continue
+            enclosing_loop = None
+            if mode == "loop":
+                # loop: the guard is re-acquired every iteration, so each pass waits on the previous one's await.
+                for header in loop_header.finditer(masked, open_brace + 1, binding.start()):
+                    body_open = header.end() - 1
+                    body_close = find_matching_brace(masked, body_open)
+                    if body_close < 0 or not (body_open < binding.start() < body_close):
+                        continue
+                    if any(start < header.start() < stop for start, stop in nested):
+                        continue
+                    enclosing_loop = (f"{header.group('kind')} loop" if header.group("kind") else "loop", line_number(masked, header.start()))
+                if enclosing_loop is None:
+                    continue
+            line = line_number(masked, binding.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            if enclosing_loop:
+                print(f"{path}:{line}:{code}  [{name} locked on every pass of the {enclosing_loop[0]} at line {enclosing_loop[1]} and held across .await]")
+                continue
+            print(f"{path}:{line}:{code}")
+PY
+}
+
+count_guard_across_await_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_guard_across_await_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_guard_across_await_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_guard_across_await_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_guard_across_await() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_guard_across_await_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_unawaited_future_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def enclosing_block_end(text: str, offset: int) -> int:
+    depth = 0
+    for idx in range(offset - 1, -1, -1):
+        ch = text[idx]
+        if ch == "}":
+            depth += 1
+        elif ch == "{":
+            if depth == 0:
+                close = find_matching_brace(text, idx)
+                return close if close >= 0 else len(text)
+            depth -= 1
+    return len(text)
+
+
+fn_def = re.compile(r"\b(?P<is_async>async\s+)?(?:unsafe\s+)?fn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)")
+statement_call = re.compile(
+    r"(?:^|(?<=[;{}]))(?P<lead>\s*)"
+    r"(?:let\s+(?:mut\s+)?(?P<bind>[A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=\s*)?"
+    r"(?P<callee>(?:(?:self|Self|[A-Za-z_][A-Za-z0-9_]*)\s*(?:\.|::)\s*)*(?P<name>[A-Za-z_][A-Za-z0-9_]*))\s*(?:::\s*<[^;{}]*?>\s*)?\(",
+    re.MULTILINE,
+)
+statement_async_block = re.compile(
+    r"(?:^|(?<=[;{}]))\s*(?:let\s+(?:mut\s+)?(?P<bind>[A-Za-z_][A-Za-z0-9_]*)\s*=\s*)?async\s+(?:move\s*)?\{",
+    re.MULTILINE,
+)
+seen = set()
+
+
+def discarded(masked: str, bind, stmt_end: int) -> bool:
+    """A future is lost when discarded outright or bound to a name that is never used again."""
+    if bind is None or bind.startswith("_"):
+        return True
+    scope_end = enclosing_block_end(masked, stmt_end)
+    return re.search(r"\b" + re.escape(bind) + r"\b", masked[stmt_end + 1:scope_end]) is None
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if "async" not in masked:
+        continue
+    async_names = set()
+    sync_names = set()
+    for definition in fn_def.finditer(masked):
+        (async_names if definition.group("is_async") else sync_names).add(definition.group("name"))
+    # Names defined both sync and async in one file are ambiguous; skip them.
+    async_names -= sync_names
+    lines = text.splitlines()
+    hits = []
+    local_items = set(re.findall(r"\b(?:struct|enum|trait|mod|type)\s+([A-Za-z_][A-Za-z0-9_]*)", masked))
+    local_items.update({"self", "Self", "super", "crate"})
+    for stmt in statement_call.finditer(masked):
+        if stmt.group("name") not in async_names:
+            continue
+        callee = re.sub(r"\s+", "", stmt.group("callee"))
+        if "::" in callee:
+            # Path calls only resolve to this file's async fns through local modules/types.
+            segments = callee.split("::")[:-1]
+            if segments[0] in {"std", "core", "alloc"} or segments[-1].split(".")[-1] not in local_items:
+                continue
+        open_paren = masked.find("(", stmt.end("name"))
+        close_paren = find_matching_paren(masked, open_paren)
+        if close_paren < 0:
+            continue
+        if not re.match(r"\s*;", masked[close_paren + 1:]):
+            continue
+        stmt_end = masked.find(";", close_paren)
+        if discarded(masked, stmt.group("bind"), stmt_end):
+            hits.append(stmt.start("callee"))
+    for stmt in statement_async_block.finditer(masked):
+        open_brace = stmt.end() - 1
+        close_brace = find_matching_brace(masked, open_brace)
+        if close_brace < 0 or not re.match(r"\s*;", masked[close_brace + 1:]):
+            continue
+        stmt_end = masked.find(";", close_brace)
+        if discarded(masked, stmt.group("bind"), stmt_end):
+            hits.append(stmt.start() + len(stmt.group(0)) - len(stmt.group(0).lstrip()))
+    for offset in sorted(hits):
+        line = line_number(masked, offset)
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_unawaited_future_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_unawaited_future_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_unawaited_future_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_unawaited_future_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_unawaited_future() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_unawaited_future_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_spawn_unwrap_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_paren, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+spawn_call = re.compile(
+    r"(?<![A-Za-z0-9_])(?:(?:tokio::(?:task::)?|async_std::task::|(?:std::)?thread::|task::)"
+    r"(?:spawn|spawn_local|spawn_blocking))\s*\("
+)
+panic_call = re.compile(r"\.\s*(?:unwrap|expect)\s*\(")
+seen = set()
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for call in spawn_call.finditer(masked):
+        open_paren = call.end() - 1
+        close_paren = find_matching_paren(masked, open_paren)
+        if close_paren < 0:
+            continue
+        for hit in panic_call.finditer(masked, open_paren, close_paren):
+            line = line_number(masked, hit.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}")
+PY
+}
+
+count_spawn_unwrap_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_spawn_unwrap_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_spawn_unwrap_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_spawn_unwrap_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_spawn_unwrap() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_spawn_unwrap_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_select_cancel_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def find_matching(text: str, open_index: int) -> int:
+    pairs = {"(": ")", "{": "}", "[": "]"}
+    opener = text[open_index]
+    closer = pairs[opener]
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch == opener:
+            depth += 1
+        elif ch == closer:
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def scan_top_level(text: str, start: int, end: int, stops):
+    """Return the offset of the first depth-0 token from stops within [start, end)."""
+    depth = 0
+    idx = start
+    while idx < end:
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif depth == 0:
+            for token in stops:
+                if text.startswith(token, idx):
+                    if token == "=" and (text[idx + 1:idx + 2] in ("=", ">") or text[idx - 1:idx] in ("=", "!", "<", ">")):
+                        break
+                    return idx, token
+        idx += 1
+    return -1, None
+
+
+def select_branches(text: str, open_index: int, close_index: int):
+    """Yield (pattern, future_start, future_end) for every `pat = fut => handler` branch."""
+    i = open_index + 1
+    while i < close_index:
+        while i < close_index and (text[i].isspace() or text[i] == ","):
+            i += 1
+        if text.startswith("biased", i):
+            semi = text.find(";", i, close_index)
+            if semi >= 0:
+                i = semi + 1
+                continue
+        eq, _ = scan_top_level(text, i, close_index, ("=>", "="))
+        if eq < 0:
+            return
+        if text.startswith("=>", eq):
+            # `else => handler` branch
+            fut_start = fut_end = eq
+            pattern = text[i:eq].strip()
+            arrow = eq
+        else:
+            pattern = text[i:eq].strip()
+            arrow, _ = scan_top_level(text, eq + 1, close_index, ("=>",))
+            if arrow < 0:
+                return
+            fut_start = eq + 1
+            fut_end = arrow
+            guard, _ = scan_top_level(text, fut_start, fut_end, (",",))
+            if guard >= 0:
+                fut_end = guard
+        yield pattern, fut_start, fut_end
+        j = arrow + 2
+        while j < close_index and text[j].isspace():
+            j += 1
+        if j < close_index and text[j] == "{":
+            end = find_matching(text, j)
+            if end < 0:
+                return
+            i = end + 1
+        else:
+            comma, _ = scan_top_level(text, j, close_index, (",",))
+            i = comma + 1 if comma >= 0 else close_index
+
+
+select_macro = re.compile(r"(?<![A-Za-z0-9_])(?:(?:tokio|futures)::)?select(?:_biased)?!\s*[({\[]")
+not_cancel_safe = re.compile(
+    r"\.\s*(?:read_exact|read_to_end|read_to_string|read_line|write_all|write_all_buf|"
+    r"collect|fold|for_each|try_collect|try_for_each|forward)\s*(?:::\s*<[^>]*>\s*)?\("
+    r"|\b(?:tokio::)?io::copy\s*\("
+)
+owned_send = re.compile(r"\.\s*(?:send|send_timeout)\s*\(\s*([A-Za-z_][A-Za-z0-9_]*)\s*[,)]")
+seen = set()
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for macro in select_macro.finditer(masked):
+        open_index = macro.end() - 1
+        close_index = find_matching(masked, open_index)
+        if close_index < 0:
+            continue
+        for pattern, fut_start, fut_end in select_branches(masked, open_index, close_index):
+            future = masked[fut_start:fut_end]
+            if mode == "unsafe_op":
+                hit = not_cancel_safe.search(future)
+            else:
+                hit = owned_send.search(future)
+            if not hit:
+                continue
+            line = line_number(masked, fut_start + hit.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}")
+PY
+}
+
+count_select_cancel_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_select_cancel_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_select_cancel_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_select_cancel_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_select_cancel() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_select_cancel_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_unbounded_channel_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def loop_spans(masked: str):
+    spans = []
+    for match in loop_re.finditer(masked):
+        # `for x in iter {` / `while cond {`: skip to the block opener at paren depth 0
+        depth = 0
+        idx = match.end()
+        while idx < len(masked):
+            ch = masked[idx]
+            if ch in "([":
+                depth += 1
+            elif ch in ")]":
+                depth -= 1
+            elif ch == "{" and depth == 0:
+                break
+            elif ch == ";" and depth == 0:
+                idx = -1
+                break
+            idx += 1
+        if idx < 0 or idx >= len(masked):
+            continue
+        end = find_matching_brace(masked, idx)
+        if end > idx:
+            spans.append((idx, end))
+    return spans
+
+
+unbounded_ctor = re.compile(
+    r"let\s+\(\s*(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*,\s*(?:mut\s+)?[A-Za-z_][A-Za-z0-9_]*\s*\)\s*"
+    r"(?::[^=;]+)?=\s*"
+    r"((?:(?:tokio::sync::)?mpsc::)?unbounded_channel\s*(?:::\s*<[^>]*>\s*)?\(\s*\)"
+    r"|(?:crossbeam(?:_channel)?::(?:channel::)?|channel::)?unbounded\s*(?:::\s*<[^>]*>\s*)?\(\s*\)"
+    r"|(?:std::sync::)?mpsc::channel\s*(?:::\s*<[^>]*>\s*)?\(\s*\)"
+    r"|(?:flume::)?unbounded\s*(?:::\s*<[^>]*>\s*)?\(\s*\))"
+)
+std_channel_import = re.compile(r"use\s+std::sync::mpsc::(?:\{[^}]*\bchannel\b[^}]*\}|channel\b)")
+bare_channel_ctor = re.compile(
+    r"let\s+\(\s*(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*,\s*(?:mut\s+)?[A-Za-z_][A-Za-z0-9_]*\s*\)\s*(?::[^=;]+)?=\s*channel\s*(?:::\s*<[^>]*>\s*)?\(\s*\)"
+)
+loop_re = re.compile(r"(?<![A-Za-z0-9_])(?:loop|while|for)\b")
+seen = set()
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    ctors = [(m.start(), m.group(1)) for m in unbounded_ctor.finditer(masked)]
+    if std_channel_import.search(masked):
+        ctors.extend((m.start(), m.group(1)) for m in bare_channel_ctor.finditer(masked))
+    if not ctors:
+        continue
+    spans = loop_spans(masked)
+    for start, sender in ctors:
+        names = {sender}
+        changed = True
+        while changed:
+            changed = False
+            for alias in re.finditer(
+                r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=\s*([A-Za-z_][A-Za-z0-9_]*)\s*\.\s*clone\s*\(\s*\)",
+                masked[start:],
+            ):
+                if alias.group(2) in names and alias.group(1) not in names:
+                    names.add(alias.group(1))
+                    changed = True
+        send_re = re.compile(
+            r"(?<![A-Za-z0-9_.])(?:" + "|".join(re.escape(n) for n in sorted(names)) + r")\s*\.\s*(?:send|try_send|unbounded_send)\s*\("
+        )
+        in_loop = False
+        for send in send_re.finditer(masked, start):
+            if any(lo < send.start() < hi for lo, hi in spans):
+                in_loop = True
+                break
+        if not in_loop:
+            continue
+        line = line_number(masked, start)
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_unbounded_channel_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_unbounded_channel_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_unbounded_channel_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_unbounded_channel_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_unbounded_channel() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_unbounded_channel_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_channel_unwrap_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def find_matching_brace(text: str, open_index: int) -> int:
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch == "{":
+            depth += 1
+        elif ch == "}":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+channel_op = re.compile(
+    r"\.\s*(send|try_send|blocking_send|send_timeout|recv|try_recv|blocking_recv|recv_timeout)\s*\("
+)
+unwrap_tail = re.compile(r"\s*(?:\.\s*await\s*)?\.\s*(?:unwrap|expect)\s*\(")
+test_attr = re.compile(r"#\s*\[\s*(?:cfg\s*\(\s*test\s*\)|(?:[A-Za-z_][A-Za-z0-9_]*::)*test(?:\s*\([^\]]*\))?)\s*\]")
+spawn_re = re.compile(r"(?<![A-Za-z0-9_])(?:std\s*::\s*)?thread\s*::\s*spawn\s*\(")
+seen = set()
+
+
+def in_test_dir(path: Path) -> bool:
+    try:
+        parts = path.relative_to(root).parts if root.is_dir() else path.parts[-1:]
+    except ValueError:
+        parts = path.parts
+    return "tests" in parts[:-1]
+
+
+def test_regions(masked: str):
+    regions = []
+    for attr in test_attr.finditer(masked):
+        brace = masked.find("{", attr.end())
+        semi = masked.find(";", attr.end())
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end > 0:
+            regions.append((attr.start(), end))
+    return regions
+
+
+def returned_spawns(masked: str):
+    """Spans of thread::spawn calls whose JoinHandle is the function's result, so a panic reaches the joiner."""
+    spans = []
+    for spawn in spawn_re.finditer(masked):
+        close = find_matching_paren(masked, spawn.end() - 1)
+        if close > 0 and masked[close + 1:].lstrip().startswith("}"):
+            spans.append((spawn.end(), close))
+    return spans
+
+
+for path in rust_files(root):
+    # A failed send or recv in a test is the test failure itself.
+    if in_test_dir(path):
+        continue
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    skipped = test_regions(masked) + returned_spawns(masked)
+    for match in channel_op.finditer(masked):
+        if any(lo <= match.start() <= hi for lo, hi in skipped):
+            continue
+        op = match.group(1)
+        open_index = match.end() - 1
+        close_index = find_matching_paren(masked, open_index)
+        if close_index < 0:
+            continue
+        args = masked[open_index + 1:close_index].strip()
+        if op.startswith("send") or op.endswith("_send"):
+            # Channel sends take the message by value; request builders (`.send()`)
+            # and sockets (`.send(&buf)`) do not.
+            if not args or args.startswith("&"):
+                continue
+        elif op in ("recv", "try_recv", "blocking_recv") and args:
+            # Socket reads such as `socket.recv(&mut buf)`.
+            continue
+        if not unwrap_tail.match(masked, close_index + 1):
+            continue
+        line = line_number(masked, match.start())
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_channel_unwrap_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_channel_unwrap_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_channel_unwrap_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_channel_unwrap_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_channel_unwrap() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_channel_unwrap_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_refcell_borrow_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def enclosing_block_end(text: str, offset: int) -> int:
+    depth = 0
+    for idx in range(offset, len(text)):
+        ch = text[idx]
+        if ch == "{":
+            depth += 1
+        elif ch == "}":
+            if depth == 0:
+                return idx
+            depth -= 1
+    return len(text)
+
+
+refcell_field = re.compile(r"\b([A-Za-z_][A-Za-z0-9_]*)\s*:\s*[^,;=(){}]*\bRefCell\s*<")
+refcell_let = re.compile(
+    r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=\s*(?:(?:std::)?(?:rc::)?Rc::new\s*\(\s*|(?:std::sync::)?Arc::new\s*\(\s*)?(?:std::cell::)?RefCell::new\s*\("
+)
+borrow_call = re.compile(
+    r"(?<![A-Za-z0-9_.])((?:[A-Za-z_][A-Za-z0-9_]*\s*\.\s*)*[A-Za-z_][A-Za-z0-9_]*)\s*\.\s*(borrow_mut|borrow)\s*\(\s*\)"
+)
+guard_let = re.compile(r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=\s*$")
+seen = set()
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "RefCell" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    cells = {m.group(1) for m in refcell_field.finditer(masked)}
+    cells.update(m.group(1) for m in refcell_let.finditer(masked))
+    if not cells:
+        continue
+    calls = []
+    for match in borrow_call.finditer(masked):
+        receiver = re.sub(r"\s+", "", match.group(1))
+        if receiver.split(".")[-1] not in cells:
+            continue
+        calls.append((match.start(), match.end(), receiver, match.group(2) == "borrow_mut"))
+
+    overlaps = set()
+    for start, end, receiver, is_mut in calls:
+        line_start = masked.rfind("\n", 0, start) + 1
+        stmt_start = max(masked.rfind(";", 0, start), masked.rfind("{", 0, start), masked.rfind("}", 0, start)) + 1
+        prefix = masked[max(line_start, stmt_start):start]
+        bound = guard_let.search(prefix)
+        rest = masked[end:]
+        if bound and re.match(r"\s*;", rest):
+            guard = bound.group(1)
+            scope_end = enclosing_block_end(masked, end)
+            dropped = re.compile(r"(?:drop|mem::drop)\s*\(\s*" + re.escape(guard) + r"\s*\)")
+            drop_match = dropped.search(masked, end, scope_end)
+            live_end = drop_match.start() if drop_match else scope_end
+            for other_start, _other_end, other_receiver, other_mut in calls:
+                if end <= other_start < live_end and other_receiver == receiver and (is_mut or other_mut):
+                    overlaps.add(other_start)
+        # Two borrows in one statement keep both temporaries alive until the `;`.
+        stmt_end = masked.find(";", end)
+        if stmt_end < 0:
+            stmt_end = len(masked)
+        for other_start, _other_end, other_receiver, other_mut in calls:
+            if end <= other_start < stmt_end and other_receiver == receiver and (is_mut or other_mut):
+                if masked.count("{", end, other_start) == masked.count("}", end, other_start):
+                    overlaps.add(other_start)
+
+    overlap_lines = {line_number(masked, start) for start in overlaps}
+    for start, _end, _receiver, _is_mut in calls:
+        if mode == "overlap":
+            if start not in overlaps:
+                continue
+        elif line_number(masked, start) in overlap_lines:
+            continue
+        line = line_number(masked, start)
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_refcell_borrow_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_refcell_borrow_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_refcell_borrow_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_refcell_borrow_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_refcell_borrow() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_refcell_borrow_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_rc_cycle_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+struct_re = re.compile(r"\bstruct\s+([A-Z][A-Za-z0-9_]*)\s*(?:<[^{;(]*>)?\s*(?:where[^{;]*)?([{(])")
+strong_re = re.compile(r"\b(Rc|Arc)\s*<")
+interior_re = re.compile(r"\b(?:RefCell|Cell|Mutex|RwLock|OnceCell)\s*<")
+collection_re = re.compile(r"\b(?:Vec|VecDeque|LinkedList|HashMap|BTreeMap|HashSet|BTreeSet|SmallVec)\s*<")
+
+
+def generic_span(text: str, open_index: int) -> int:
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch == "<":
+            depth += 1
+        elif ch == ">" and text[idx - 1:idx] != "-":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return len(text)
+
+
+def struct_fields(masked: str, body_start: int, body_end: int):
+    """Yield (offset, type_text) for each field in a struct body."""
+    depth = 0
+    field_start = body_start + 1
+    for idx in range(body_start + 1, body_end + 1):
+        ch = masked[idx] if idx < body_end else ","
+        if ch in "<([{":
+            depth += 1
+        elif ch in ">)]}" and not (ch == ">" and masked[idx - 1:idx] == "-"):
+            depth -= 1
+        elif ch == "," and depth == 0:
+            chunk = masked[field_start:idx]
+            colon = re.search(r"(?<!:):(?!:)", chunk)
+            type_text = chunk[colon.end():] if colon else chunk
+            lead = len(type_text) - len(type_text.lstrip())
+            offset = field_start + (colon.end() if colon else 0) + lead
+            if type_text.strip():
+                yield offset, type_text
+            field_start = idx + 1
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "Rc" not in text and "Arc" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    structs = {}
+    order = []
+    for match in struct_re.finditer(masked):
+        name = match.group(1)
+        open_index = match.end() - 1
+        if match.group(2) == "{":
+            close_index = find_matching_brace(masked, open_index)
+        else:
+            depth = 0
+            close_index = -1
+            for idx in range(open_index, len(masked)):
+                if masked[idx] == "(":
+                    depth += 1
+                elif masked[idx] == ")":
+                    depth -= 1
+                    if depth == 0:
+                        close_index = idx
+                        break
+        if close_index < 0 or name in structs:
+            continue
+        structs[name] = (open_index, close_index)
+        order.append(name)
+    if not structs:
+        continue
+
+    # edges[src] = [(dst, offset, interior_mutable, inside_collection)]
+    edges = {name: [] for name in order}
+    for name in order:
+        open_index, close_index = structs[name]
+        for offset, type_text in struct_fields(masked, open_index, close_index):
+            for ptr in strong_re.finditer(type_text):
+                inner_start = ptr.end() - 1
+                inner_end = generic_span(type_text, inner_start)
+                inner = type_text[inner_start + 1:inner_end]
+                before = type_text[:ptr.start()]
+                if re.search(r"\bWeak\s*<\s*$", before):
+                    continue
+                targets = set(re.findall(r"\b([A-Z][A-Za-z0-9_]*)\b", inner))
+                if "Self" in targets:
+                    targets.discard("Self")
+                    targets.add(name)
+                interior = bool(interior_re.search(inner) or interior_re.search(before))
+                collection = bool(collection_re.search(before))
+                for dst in targets:
+                    if dst in edges:
+                        edges[name].append((dst, offset + ptr.start(), interior, collection))
+
+    # Tarjan SCC over the strong-pointer struct graph.
+    index_of, low, stack, on_stack, sccs = {}, {}, [], set(), []
+    counter = [0]
+
+    def strongconnect(node):
+        index_of[node] = low[node] = counter[0]
+        counter[0] += 1
+        stack.append(node)
+        on_stack.add(node)
+        for dst, *_rest in edges[node]:
+            if dst not in index_of:
+                strongconnect(dst)
+                low[node] = min(low[node], low[dst])
+            elif dst in on_stack:
+                low[node] = min(low[node], index_of[dst])
+        if low[node] == index_of[node]:
+            component = set()
+            while True:
+                member = stack.pop()
+                on_stack.discard(member)
+                component.add(member)
+                if member == node:
+                    break
+            sccs.append(component)
+
+    for name in order:
+        if name not in index_of:
+            strongconnect(name)
+
+    rank = {name: idx for idx, name in enumerate(order)}
+    reported = set()
+    for component in sccs:
+        inner_edges = [(src, edge) for src in component for edge in edges[src] if edge[0] in component]
+        if len(component) == 1:
+            # A node can only be linked back to itself through interior mutability,
+            # and a self-typed collection alone is just the owning child list.
+            inner_edges = [(src, edge) for src, edge in inner_edges if edge[2]]
+            if all(edge[3] for _src, edge in inner_edges):
+                continue
+        # Collections usually hold the owning (parent -> child) edges; single
+        # pointers are the back-edges. Without that hint, fall back to
+        # declaration order and blame edges pointing at an earlier struct.
+        back_edges = [(src, edge) for src, edge in inner_edges if not edge[3]]
+        if not back_edges:
+            back_edges = [(src, edge) for src, edge in inner_edges if rank[edge[0]] <= rank[src]]
+        elif len(back_edges) > 1 and len(back_edges) == len(inner_edges):
+            back_edges = sorted(back_edges, key=lambda item: item[1][1])[1:]
+        for _src, (_dst, offset, _interior, _collection) in back_edges:
+            line = line_number(masked, offset)
+            key = (str(path), line)
+            if key in reported:
+                continue
+            reported.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}")
+PY
+}
+
+count_rc_cycle_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_rc_cycle_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_rc_cycle_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_rc_cycle_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_rc_cycle() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_rc_cycle_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_static_mut_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+static_mut_decl = re.compile(
+    r"(?<![A-Za-z0-9_])(?:pub(?:\s*\([^)]*\))?\s+)?static\s+mut\s+([A-Za-z_][A-Za-z0-9_]*)\s*:\s*([^=;]+?)\s*(?:=|;)"
+)
+atomic_types = {
+    "bool": "AtomicBool", "u8": "AtomicU8", "u16": "AtomicU16", "u32": "AtomicU32", "u64": "AtomicU64",
+    "usize": "AtomicUsize", "i8": "AtomicI8", "i16": "AtomicI16", "i32": "AtomicI32", "i64": "AtomicI64",
+    "isize": "AtomicIsize",
+}
+
+
+def suggest_fix(name: str, ty: str) -> str:
+    ty = re.sub(r"\s+", " ", ty).strip()
+    atomic = atomic_types.get(ty)
+    if atomic:
+        return f"static {name}: std::sync::atomic::{atomic} (load/store/fetch_add)"
+    option = re.fullmatch(r"Option\s*<\s*(.+)\s*>", ty)
+    if option:
+        return f"static {name}: std::sync::OnceLock<{option.group(1)}> (get_or_init)"
+    return f"static {name}: std::sync::Mutex<{ty}> (or OnceLock<{ty}> if written once)"
+
+
+seen = set()
+fixes = []
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "static" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    decls = list(static_mut_decl.finditer(masked))
+    if not decls:
+        continue
+    hits = []
+    for decl in decls:
+        name = decl.group(1)
+        hits.append(decl.start())
+        fixes.append(suggest_fix(name, decl.group(2)))
+        use_re = re.compile(r"(?<![A-Za-z0-9_])" + re.escape(name) + r"(?![A-Za-z0-9_])")
+        for use in use_re.finditer(masked):
+            if decl.start() <= use.start() < decl.end():
+                continue
+            # Paths like `other::NAME` still read the same global; field names do not.
+            if masked[max(0, use.start() - 1):use.start()] == ".":
+                continue
+            hits.append(use.start())
+    if mode == "fix":
+        continue
+    for offset in sorted(hits):
+        line = line_number(masked, offset)
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+
+if mode == "fix":
+    for fix in dict.fromkeys(fixes):
+        print(fix)
+PY
+}
+
+count_static_mut_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_static_mut_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_static_mut_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_static_mut_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_static_mut() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_static_mut_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_unsafe_safety_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  UBS_RUST_UNSAFE_MIN_LINES="$UNSAFE_SAFETY_MIN_LINES" python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+try:
+    min_lines = max(0, int(os.environ.get("UBS_RUST_UNSAFE_MIN_LINES", "0") or 0))
+except ValueError:
+    min_lines = 0
+unsafe_block = re.compile(r"(?<![A-Za-z0-9_])unsafe\s*\{")
+unsafe_fn = re.compile(
+    r"(?<![A-Za-z0-9_])unsafe\s+(?:extern\s+(?:\"[^\"]*\"\s+)?)?fn\s+[A-Za-z_][A-Za-z0-9_]*"
+)
+safety_note = re.compile(r"SAFETY\s*:|#\s*Safety\b", re.IGNORECASE)
+leading_note = re.compile(r"^\s*(?://|/\*|\*|#\[)")
+seen = set()
+
+
+def documented(lines, line: int, offset_in_line: str) -> bool:
+    """True when the unsafe line itself or the comment/attribute run above it carries a SAFETY note."""
+    if safety_note.search(offset_in_line):
+        return True
+    idx = line - 2
+    # An unsafe block nested in a multi-line call or `let` is documented above the statement start.
+    while idx >= 0 and lines[idx].strip() and not leading_note.match(lines[idx]) and not re.search(r"[;{}]\s*$", lines[idx]):
+        idx -= 1
+    while idx >= 0:
+        candidate = lines[idx]
+        if not leading_note.match(candidate):
+            # `let value = // SAFETY: ...` style trailing notes on the previous line.
+            return bool(re.search(r"//.*SAFETY\s*:", candidate))
+        if safety_note.search(candidate):
+            return True
+        idx -= 1
+    return False
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "unsafe" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    candidates = []
+    for match in unsafe_block.finditer(masked):
+        candidates.append((match.start(), match.end() - 1))
+    for match in unsafe_fn.finditer(masked):
+        brace = masked.find("{", match.end())
+        semi = masked.find(";", match.end())
+        if brace < 0 or (0 <= semi < brace):
+            continue  # trait/extern declarations without a body
+        candidates.append((match.start(), brace))
+    for start, brace in sorted(candidates):
+        close = find_matching_brace(masked, brace)
+        if close < 0:
+            continue
+        line = line_number(masked, start)
+        end_line = line_number(masked, close)
+        if end_line - line + 1 < min_lines:
+            continue
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        raw = lines[line - 1] if 0 < line <= len(lines) else ""
+        if documented(lines, line, raw):
+            continue
+        code = raw.strip()
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_unsafe_safety_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_unsafe_safety_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_unsafe_safety_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_unsafe_safety_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_unsafe_safety() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_unsafe_safety_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_unchecked_tainted_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+unchecked_call = re.compile(
+    r"(?:\.\s*(?:get_unchecked_mut|get_unchecked|slice_unchecked_mut|slice_unchecked|get_unchecked_range)"
+    r"|\b(?:str::|std::str::|String::|std::string::String::)?from_utf8_unchecked(?:_mut)?"
+    r"|\bslice::from_raw_parts(?:_mut)?)\s*\("
+)
+io_source = re.compile(
+    r"\.\s*(?:read|read_exact|read_to_end|read_to_string|read_line|read_buf|recv|recv_from|peek)\s*\("
+    r"|\bstdin\s*\(|\benv::args\b|\benv::var(?:_os)?\s*\(|\bargs\s*\(\s*\)\s*\.\s*nth\b"
+)
+
+
+def fn_spans(masked: str):
+    for match in fn_re.finditer(masked):
+        open_index = match.end() - 1
+        close_index = find_matching_paren(masked, open_index)
+        if close_index < 0:
+            continue
+        brace = masked.find("{", close_index)
+        semi = masked.find(";", close_index)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        params = set()
+        for chunk in masked[open_index + 1:close_index].split(","):
+            name = re.match(r"\s*(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:", chunk)
+            if name and name.group(1) != "self":
+                params.add(name.group(1))
+        yield brace, end, params
+
+
+def idents(expr: str):
+    return set(re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", expr))
+
+
+seen = set()
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "unchecked" not in text and "from_raw_parts" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for body_start, body_end, params in fn_spans(masked):
+        body = masked[body_start:body_end]
+        if not unchecked_call.search(body):
+            continue
+        io_tainted = set()
+        for stmt in re.finditer(r"let\s+(?:mut\s+)?\(?\s*([A-Za-z_][A-Za-z0-9_]*)[^=;]*=([^;]*);", body):
+            if io_source.search(stmt.group(2)):
+                io_tainted.add(stmt.group(1))
+        for buf in re.finditer(
+            r"\.\s*(?:read|read_exact|read_to_end|read_to_string|read_line|read_buf|recv|recv_from)\s*\(\s*&mut\s+([A-Za-z_][A-Za-z0-9_]*)",
+            body,
+        ):
+            io_tainted.add(buf.group(1))
+        tainted = set(params) | io_tainted
+        for names in (tainted, io_tainted):
+            changed = True
+            while changed:
+                changed = False
+                for stmt in re.finditer(r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)[^=;]*=([^;]*);", body):
+                    if stmt.group(1) not in names and idents(stmt.group(2)) & names:
+                        names.add(stmt.group(1))
+                        changed = True
+        for call in unchecked_call.finditer(body):
+            open_index = call.end() - 1
+            close_index = find_matching_paren(body, open_index)
+            if close_index < 0:
+                continue
+            args = body[open_index + 1:close_index]
+            receiver = ""
+            if body[call.start()] == ".":
+                recv_match = re.search(r"([A-Za-z_][A-Za-z0-9_]*)\s*(?:\[[^\]]*\]\s*)?$", body[:call.start()])
+                receiver = recv_match.group(1) if recv_match else ""
+            if "from_raw_parts" in call.group(0):
+                # A (ptr, len) pair handed in by the caller is the normal FFI
+                # contract; only lengths read off a stream or socket escalate.
+                flowing = idents(args) & io_tainted
+            else:
+                flowing = (idents(args) | ({receiver} if receiver else set())) & tainted
+            if not flowing:
+                continue
+            before = body[:call.start()]
+            checked = False
+            for name in flowing:
+                escaped = re.escape(name)
+                if re.search(
+                    escaped + r"\s*<=?\s*[^;{]*\.len\s*\(\s*\)"
+                    + r"|\.len\s*\(\s*\)\s*>=?\s*" + escaped + r"\b"
+                    + r"|is_char_boundary\s*\(\s*" + escaped + r"\b"
+                    + r"|from_utf8\s*\(\s*&?\s*" + escaped + r"\b",
+                    before,
+                ):
+                    checked = True
+                    break
+            if checked:
+                continue
+            line = line_number(masked, body_start + call.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}")
+PY
+}
+
+count_unchecked_tainted_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_unchecked_tainted_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_unchecked_tainted_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_unchecked_tainted_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_unchecked_tainted() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_unchecked_tainted_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_raw_ptr_after_drop_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def enclosing_block(text: str, offset: int, floor: int):
+    """Return (open, close) of the innermost {...} around offset, not above floor."""
+    depth = 0
+    idx = offset
+    while idx > floor:
+        idx -= 1
+        ch = text[idx]
+        if ch == "}":
+            depth += 1
+        elif ch == "{":
+            if depth == 0:
+                return idx, find_matching_brace(text, idx)
+            depth -= 1
+    return floor, find_matching_brace(text, floor)
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+ident = r"[A-Za-z_][A-Za-z0-9_]*"
+ptr_let = re.compile(
+    r"(?<![A-Za-z0-9_.])(?:let\s+(?:mut\s+)?)?(" + ident + r")\s*(?::[^=;]+)?=\s*(?:"
+    r"&\s*(?:mut\s+)?\*?\s*(" + ident + r")\s+as\s+\*\s*(?:const|mut)\b"
+    r"|(" + ident + r")\s*\.\s*(?:as_ptr|as_mut_ptr)\s*\(\s*\)"
+    r"|(?:std::)?(?:ptr::)?addr_of(?:_mut)?!\s*\(\s*(" + ident + r")\s*\)"
+    r")"
+)
+
+
+def kills(body: str, source: str, start: int):
+    """Offsets where `source` is dropped, moved out with `let x = source;`, or overwritten."""
+    name = re.escape(source)
+    pattern = re.compile(
+        r"(?:\bdrop|\bmem::drop|\bstd::mem::drop)\s*\(\s*" + name + r"\s*\)"
+        r"|let\s+(?:mut\s+)?" + ident + r"\s*(?::[^=;]+)?=\s*" + name + r"\s*;"
+        r"|(?<![A-Za-z0-9_.])" + name + r"\s*=(?![=>])"
+    )
+    return [m.start() for m in pattern.finditer(body, start)]
+
+
+def derefs(body: str, ptr: str, start: int):
+    name = re.escape(ptr)
+    pattern = re.compile(
+        r"(?:(?<=[({\[=,;:!&|])|(?<=^)|(?<=\breturn)|(?<=\s))\*\s*" + name + r"(?![A-Za-z0-9_])"
+        r"|(?<![A-Za-z0-9_.])" + name + r"\s*\.\s*(?:read|read_volatile|read_unaligned|as_ref|as_mut|write|write_volatile)\s*\("
+        r"|\bptr::read(?:_volatile|_unaligned)?\s*\(\s*" + name + r"\s*\)",
+        re.MULTILINE,
+    )
+    hits = []
+    for match in pattern.finditer(body, start):
+        text = match.group(0)
+        if text.lstrip().startswith("*"):
+            prev = body[:match.start()].rstrip()
+            # `a * ptr` is multiplication, `*const T` / `*mut T` are types.
+            if prev and (prev[-1].isalnum() or prev[-1] in "_)]") and not prev.endswith("return"):
+                continue
+        hits.append(match.start())
+    return hits
+
+
+seen = set()
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "as *" not in text and "as_ptr" not in text and "as_mut_ptr" not in text and "addr_of" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        for ptr_match in ptr_let.finditer(body):
+            ptr = ptr_match.group(1)
+            source = ptr_match.group(2) or ptr_match.group(3) or ptr_match.group(4)
+            if not source or source == "self":
+                continue
+            created = ptr_match.end()
+            dead_from = None
+            killed = kills(body, source, created)
+            if killed:
+                dead_from = killed[0]
+            # A source declared in an inner block dies at that block's closing brace.
+            decl = None
+            for decl_match in re.finditer(r"let\s+(?:mut\s+)?" + re.escape(source) + r"\b", body[:ptr_match.start()]):
+                decl = decl_match.start()
+            if decl is not None:
+                block_open, block_close = enclosing_block(body, decl, 0)
+                if block_open > 0 and block_close > 0:
+                    dead_from = block_close if dead_from is None else min(dead_from, block_close)
+            if dead_from is None:
+                continue
+            for hit in derefs(body, ptr, dead_from):
+                line = line_number(masked, brace + hit)
+                key = (str(path), line)
+                if key in seen:
+                    continue
+                seen.add(key)
+                code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+                if "ubs:ignore" in code:
+                    continue
+                print(f"{path}:{line}:{code}")
+PY
+}
+
+count_raw_ptr_after_drop_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_raw_ptr_after_drop_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_raw_ptr_after_drop_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_raw_ptr_after_drop_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_raw_ptr_after_drop() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_raw_ptr_after_drop_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_probable_leak_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^>{]*>)?\s*\(")
+forget_re = re.compile(r"(?<![A-Za-z0-9_])(?:std::|core::)?mem::forget\s*\(")
+leak_re = re.compile(r"(?<![A-Za-z0-9_])Box::leak\s*\(")
+manually_re = re.compile(
+    r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=\s*(?:std::mem::|core::mem::|mem::)?ManuallyDrop::new\s*\("
+)
+ffi_note = re.compile(r"\b(?:FFI|ffi|foreign|C side|caller frees|ownership (?:is )?(?:passed|transferred|handed)|handed (?:off|over) to)\b")
+static_context = re.compile(r"get_or_init\s*\(|\blazy_static!|\bLazy(?:Lock)?::new\s*\(|\bstatic\s+[A-Z_][A-Z0-9_]*\s*:")
+seen = set()
+
+
+def fn_bodies(masked: str):
+    for match in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, match.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        header_start = masked.rfind("\n", 0, match.start()) + 1
+        yield match.group(1), header_start, brace, end
+
+
+def documented_handoff(lines, line: int) -> bool:
+    idx = line - 1
+    if ffi_note.search(lines[idx] if idx < len(lines) else ""):
+        return True
+    idx -= 1
+    while idx >= 0 and lines[idx].strip().startswith(("//", "/*", "*")):
+        if ffi_note.search(lines[idx]):
+            return True
+        idx -= 1
+    return False
+
+
+def emit(path, masked, lines, offset):
+    line = line_number(masked, offset)
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    if documented_handoff(lines, line):
+        return
+    code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+    if "ubs:ignore" in code:
+        return
+    print(f"{path}:{line}:{code}")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "forget" not in text and "leak" not in text and "ManuallyDrop" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for name, header_start, brace, end in fn_bodies(masked):
+        header = masked[max(0, header_start - 200):brace]
+        # extern "C" entry points and #[no_mangle] exports hand ownership across the boundary.
+        if re.search(r"extern\s+\"C\"|#\s*\[\s*(?:unsafe\s*\(\s*)?no_mangle", header):
+            continue
+        body = masked[brace:end + 1]
+        for match in forget_re.finditer(body):
+            close_paren = find_matching_paren(body, match.end() - 1)
+            arg = body[match.end():close_paren].strip() if close_paren > 0 else ""
+            if re.fullmatch(r"[A-Za-z_][A-Za-z0-9_]*", arg):
+                # `let ptr = v.as_mut_ptr(); mem::forget(v);` is the classic handoff idiom.
+                if re.search(r"\b" + re.escape(arg) + r"\s*\.\s*(?:as_ptr|as_mut_ptr|as_raw_fd|into_raw_fd)\s*\(", body[:match.start()]):
+                    continue
+            emit(path, masked, lines, brace + match.start())
+        for match in leak_re.finditer(body):
+            if name == "main":
+                continue
+            statement_start = max(body.rfind(";", 0, match.start()), body.rfind("{", 0, match.start())) + 1
+            if static_context.search(body[statement_start:match.start()]):
+                continue
+            emit(path, masked, lines, brace + match.start())
+        for match in manually_re.finditer(body):
+            binding = re.escape(match.group(1))
+            rest = body[match.end():]
+            if re.search(
+                r"ManuallyDrop::(?:drop|into_inner|take)\s*\(\s*(?:&\s*mut\s+)?" + binding + r"\b"
+                r"|\b" + binding + r"\s*\.\s*(?:as_ptr|as_mut_ptr)\s*\(",
+                rest,
+            ):
+                continue
+            if re.search(r"(?:return\s+|^\s*)" + binding + r"\s*$|[(,{:]\s*" + binding + r"\s*[,)}]", rest, re.MULTILINE):
+                # moved into a struct/return value that owns the drop responsibility
+                continue
+            emit(path, masked, lines, brace + match.start())
+PY
+}
+
+count_probable_leak_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_probable_leak_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_probable_leak_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_probable_leak_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_probable_leak() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_probable_leak_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_assume_init_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+assume_re = re.compile(r"\.\s*assume_init(?:_ref|_mut|_read)?\s*\(\s*\)")
+uninit_ctor = r"(?:(?:std|core)::mem::)?MaybeUninit\s*(?:::\s*<[^=\n]*?>\s*)?::\s*uninit\s*(?:::\s*<[^=\n]*?>\s*)?\(\s*\)"
+type_text = r"(?:[^=;\[\]]|\[[^\]]*\])+"
+uninit_let = re.compile(r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::(" + type_text + r"))?=\s*" + uninit_ctor)
+direct_chain = re.compile(uninit_ctor + r"\s*$")
+turbofish_re = re.compile(r"MaybeUninit\s*::\s*<(.*)>\s*::\s*uninit\b", re.S)
+wrapper_re = re.compile(r"\s*(?:(?:std|core)::mem::)?MaybeUninit\s*<(.*)>\s*", re.S)
+# An array of MaybeUninit (or a nested MaybeUninit) needs no initialization: the documented
+# `let a: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };` idiom.
+uninit_payload_re = re.compile(r"\s*(?:\[\s*(?:(?:std|core)::mem::)?MaybeUninit\s*<.*;[^\]]*\]|(?:(?:std|core)::mem::)?MaybeUninit\s*<.*>)\s*", re.S)
+let_annotation_re = re.compile(r"\blet\s+(?:mut\s+)?[A-Za-z_][A-Za-z0-9_]*\s*:\s*(" + type_text + r"?)\s*=\s*(?:unsafe\s*\{\s*)?$")
+seen = set()
+
+
+def statement_start(text: str, pos: int) -> int:
+    depth = 0
+    for idx in range(pos - 1, -1, -1):
+        ch = text[idx]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                return idx + 1
+            depth -= 1
+        elif ch in ";{}" and depth == 0:
+            return idx + 1
+    return 0
+
+
+def payload_needs_no_init(ctor: str, annotation: str) -> bool:
+    turbofish = turbofish_re.search(ctor)
+    if turbofish and uninit_payload_re.fullmatch(turbofish.group(1)):
+        return True
+    return bool(annotation and uninit_payload_re.fullmatch(annotation))
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "assume_init" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    spans = []
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end > brace:
+            spans.append((brace, end))
+    for call in assume_re.finditer(masked):
+        prefix_start = statement_start(masked, call.start())
+        prefix = masked[prefix_start:call.start()]
+        chain = direct_chain.search(prefix)
+        uninitialized = bool(chain)
+        verified = False
+        if chain:
+            chain_start = prefix_start + chain.start()
+            stmt_start = statement_start(masked, chain_start)
+            if masked[stmt_start - 1:stmt_start] == "{":
+                stmt_start = statement_start(masked, stmt_start - 1)
+            binding = let_annotation_re.search(masked, stmt_start, chain_start)
+            if payload_needs_no_init(chain.group(0), binding.group(1) if binding else ""):
+                continue
+        if not uninitialized:
+            receiver = re.search(r"([A-Za-z_][A-Za-z0-9_]*)\s*$", prefix)
+            fn_start = max((lo for lo, hi in spans if lo < call.start() < hi), default=0)
+            if receiver:
+                name = receiver.group(1)
+                decls = [m for m in uninit_let.finditer(masked, fn_start, call.start()) if m.group(1) == name]
+                if decls:
+                    wrapper = wrapper_re.fullmatch(decls[-1].group(2) or "")
+                    if payload_needs_no_init(decls[-1].group(0), wrapper.group(1) if wrapper else ""):
+                        continue
+                    between = masked[decls[-1].end():call.start()]
+                    escaped = re.escape(name)
+                    initialized = re.search(
+                        r"\b" + escaped + r"\s*\.\s*(?:write|as_mut_ptr)\s*\("
+                        r"|&\s*mut\s+" + escaped + r"\b"
+                        r"|\b" + escaped + r"\s*=\s*(?:(?:std|core)::mem::)?MaybeUninit\s*(?:::\s*<[^=\n]*?>\s*)?::\s*new\s*\(",
+                        between,
+                    )
+                    uninitialized = not initialized
+                    verified = bool(initialized)
+        if mode == "uninit" and not uninitialized:
+            continue
+        if mode != "uninit" and (uninitialized or verified):
+            continue
+        line = line_number(masked, call.start())
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_assume_init_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_assume_init_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_assume_init_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_assume_init_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_assume_init() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_assume_init_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_untrusted_overflow_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  UBS_RUST_OVERFLOW_TYPES="$OVERFLOW_TYPES" python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+INT_TYPES = {"u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"}
+restrict = {t.strip() for t in os.environ.get("UBS_RUST_OVERFLOW_TYPES", "").split(",") if t.strip()}
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+let_re = re.compile(r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::\s*([A-Za-z0-9_]+)\s*)?=([^;]*);")
+parse_src = re.compile(r"\.\s*parse\s*(?:::\s*<\s*([A-Za-z0-9_]+)\s*>)?\s*\(\s*\)|\b([ui](?:8|16|32|64|128|size))::from_str(?:_radix)?\s*\(")
+network_src = re.compile(
+    r"\b([ui](?:8|16|32|64|128|size))::from_(?:be|le|ne)_bytes\s*\("
+    r"|\.\s*(?:read|get)_([ui](?:8|16|32|64|128))(?:_le|_be|_ne)?\s*(?:::\s*<[^>]*>\s*)?\("
+)
+seen = set()
+
+
+def classify(annotation, expr, tainted):
+    """Return (kind, type) for tainted lets, or None."""
+    expr = expr.strip()
+    parsed = parse_src.search(expr)
+    if parsed:
+        ty = annotation or parsed.group(1) or parsed.group(2)
+        if ty in ("f32", "f64"):
+            return None
+        return "input", ty
+    net = network_src.search(expr)
+    if net:
+        return "input", annotation or net.group(1) or net.group(2)
+    if re.search(r"\.\s*len\s*\(\s*\)\s*$", expr):
+        return "length", annotation or "usize"
+    names = set(re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", expr))
+    inherited = [tainted[name] for name in names if name in tainted]
+    if inherited and not re.search(r"checked_|saturating_|wrapping_|overflowing_|\.min\s*\(|\.clamp\s*\(", expr):
+        kind = "input" if any(k == "input" for k, *_ in inherited) else "length"
+        return kind, annotation or inherited[0][1]
+    return None
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        tainted = {}
+        for stmt in let_re.finditer(body):
+            result = classify(stmt.group(2), stmt.group(3), tainted)
+            if result:
+                tainted[stmt.group(1)] = result + (stmt.end(),)
+        tainted = {k: v for k, v in tainted.items() if v[1] is None or v[1] in INT_TYPES}
+        if restrict:
+            tainted = {k: v for k, v in tainted.items() if v[1] in restrict}
+        for name, (kind, _ty, defined_at) in tainted.items():
+            escaped = re.escape(name)
+            ops = "[-*]" if kind == "length" else "[-+*]"
+            op_re = re.compile(
+                r"(?<![A-Za-z0-9_.])" + escaped + r"\s*(" + ops + r")=?\s*(?=[A-Za-z0-9_(])"
+                r"|(?<=[A-Za-z0-9_)\]])\s*(" + ops + r")\s*" + escaped + r"(?![A-Za-z0-9_(])"
+            )
+            guard_re = re.compile(
+                r"(?<![A-Za-z0-9_.])" + escaped + r"\s*(?:<=?|>=?)\s*[A-Za-z0-9_]"
+                r"|(?:<=?|>=?)\s*" + escaped + r"(?![A-Za-z0-9_])"
+                r"|" + escaped + r"\s*\.\s*(?:min|clamp)\s*\("
+            )
+            for op in op_re.finditer(body, defined_at):
+                stmt_start = max(body.rfind(";", 0, op.start()), body.rfind("{", 0, op.start()), body.rfind("}", 0, op.start())) + 1
+                statement_prefix = body[stmt_start:op.start()]
+                if re.search(r"(?:checked|saturating|wrapping|overflowing)_\w*\s*\(\s*$", statement_prefix):
+                    continue
+                if guard_re.search(body, defined_at, op.start()):
+                    continue
+                line = line_number(masked, brace + op.start())
+                key = (str(path), line)
+                if key in seen:
+                    continue
+                seen.add(key)
+                code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+                if "ubs:ignore" in code:
+                    continue
+                print(f"{path}:{line}:{code}")
+PY
+}
+
+count_untrusted_overflow_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_untrusted_overflow_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_untrusted_overflow_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_untrusted_overflow_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_untrusted_overflow() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_untrusted_overflow_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_lossy_cast_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+WIDTH = {"u8": 8, "u16": 16, "u32": 32, "u64": 64, "u128": 128, "usize": 64,
+         "i8": 8, "i16": 16, "i32": 32, "i64": 64, "i128": 128, "isize": 64}
+INT = "|".join(sorted(WIDTH, key=len, reverse=True))
+cast_re = re.compile(r"\bas\s+(" + INT + r")\b")
+typed_binding = re.compile(r"\b(?:let\s+(?:mut\s+)?)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*&?\s*(?:mut\s+)?(" + INT + r")\b(?!\s*::)")
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+seen = set()
+
+
+def operand_before(text: str, pos: int):
+    """Return the source-expression text immediately preceding `as` at pos."""
+    idx = pos
+    while idx > 0 and text[idx - 1].isspace():
+        idx -= 1
+    end = idx
+    if idx > 0 and text[idx - 1] == ")":
+        depth = 0
+        while idx > 0:
+            idx -= 1
+            if text[idx] == ")":
+                depth += 1
+            elif text[idx] == "(":
+                depth -= 1
+                if depth == 0:
+                    break
+        # include a method/call name before the parens: `x.len()`, `foo(..)`
+        while idx > 0 and (text[idx - 1].isalnum() or text[idx - 1] in "_.:"):
+            idx -= 1
+    else:
+        while idx > 0 and (text[idx - 1].isalnum() or text[idx - 1] in "_."):
+            idx -= 1
+    return text[idx:end].strip()
+
+
+def source_type(expr: str, types: dict):
+    literal = re.fullmatch(r"[0-9][0-9_]*(" + INT + r")", expr)
+    if literal:
+        return literal.group(1)
+    nested = re.search(r"\bas\s+(" + INT + r")\s*\)?$", expr)
+    if nested:
+        return nested.group(1)
+    if re.search(r"\.\s*(?:len|count|capacity)\s*\(\s*\)$", expr):
+        return "usize"
+    decoded = re.match(r"\(?\s*(" + INT + r")::(?:from_(?:be|le|ne)_bytes|MAX|MIN)\b", expr)
+    if decoded:
+        return decoded.group(1)
+    name = re.fullmatch(r"\(?\s*(?:[A-Za-z_][A-Za-z0-9_]*\.)*([A-Za-z_][A-Za-z0-9_]*)\s*\)?", expr)
+    if name:
+        return types.get(name.group(1))
+    return None
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if " as " not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    # Struct fields are visible file-wide; params and lets are scoped to their fn.
+    file_types = {}
+    for struct in re.finditer(r"\bstruct\s+[A-Za-z_][A-Za-z0-9_]*[^{;]*\{", masked):
+        close = find_matching_brace(masked, struct.end() - 1)
+        for binding in typed_binding.finditer(masked, struct.end(), max(close, struct.end())):
+            file_types[binding.group(1)] = binding.group(2)
+    spans = []
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        types = dict(file_types)
+        for binding in typed_binding.finditer(masked, fn.end() - 1, end):
+            types[binding.group(1)] = binding.group(2)
+        for binding in re.finditer(
+            r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*=\s*(?:[0-9][0-9_]*(" + INT + r")\b|[^;]*?\bas\s+(" + INT + r")\s*;|\s*(" + INT + r")::from_(?:be|le|ne)_bytes\b)",
+            masked[brace:end],
+        ):
+            types[binding.group(1)] = binding.group(2) or binding.group(3) or binding.group(4)
+        spans.append((brace, end, types))
+    for cast in cast_re.finditer(masked):
+        target = cast.group(1)
+        types = next((t for lo, hi, t in spans if lo < cast.start() < hi), file_types)
+        expr = operand_before(masked, cast.start())
+        if not expr:
+            continue
+        if re.search(r"&\s*0x[0-9A-Fa-f_]+|\.\s*(?:min|clamp)\s*\(|%\s*[0-9]", expr):
+            continue  # explicitly masked or clamped to the target range
+        source = source_type(expr, types)
+        if not source or source == target:
+            continue
+        narrowing = WIDTH[target] < WIDTH[source]
+        signed_source = source.startswith("i")
+        signed_target = target.startswith("i")
+        flips = signed_source != signed_target and (signed_source or WIDTH[target] <= WIDTH[source])
+        if mode == "truncate":
+            if not narrowing:
+                continue
+            if re.search(r"\.\s*(?:len|count)\s*\(\s*\)$", expr):
+                continue  # reported by the len()/count() narrowing check
+        else:
+            if narrowing or not flips:
+                continue
+        line = line_number(masked, cast.start())
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_lossy_cast_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_lossy_cast_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_lossy_cast_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_lossy_cast_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_lossy_cast() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_lossy_cast_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_index_oob_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def find_matching_bracket(text: str, open_index: int) -> int:
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch == "[":
+            depth += 1
+        elif ch == "]":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+index_re = re.compile(r"((?:[A-Za-z_][A-Za-z0-9_]*\s*\.\s*)*[A-Za-z_][A-Za-z0-9_]*)\s*\[")
+input_src = re.compile(
+    r"\.\s*parse\s*(?:::\s*<[^>]*>\s*)?\(|\benv::args\b|\benv::var(?:_os)?\s*\(|\bstdin\s*\("
+    r"|\b[ui](?:8|16|32|64|128|size)::from_(?:be|le|ne)_bytes\s*\(|\.\s*as_u64\s*\(|\.\s*as_i64\s*\("
+    r"|\.\s*read(?:_line)?\s*\("
+)
+inclusive_loop = re.compile(
+    r"\bfor\s+([A-Za-z_][A-Za-z0-9_]*)\s+in\s+[^{;]*?\.\.=\s*((?:[A-Za-z_][A-Za-z0-9_]*\s*\.\s*)*[A-Za-z_][A-Za-z0-9_]*)\s*\.\s*len\s*\(\s*\)\s*\{"
+)
+seen = set()
+
+
+def collection_name(expr: str) -> str:
+    return re.sub(r"\s+", "", expr).split(".")[-1]
+
+
+def emit(path, masked, lines, offset):
+    line = line_number(masked, offset)
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+    if "ubs:ignore" in code:
+        return
+    print(f"{path}:{line}:{code}")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "[" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        tainted = set()
+        lengths = {}
+        for stmt in re.finditer(r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)[^=;]*=([^;]*);", body):
+            name, expr = stmt.group(1), stmt.group(2)
+            if input_src.search(expr):
+                tainted.add(name)
+            elif set(re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", expr)) & tainted:
+                tainted.add(name)
+            length = re.fullmatch(r"\s*((?:[A-Za-z_][A-Za-z0-9_]*\s*\.\s*)*[A-Za-z_][A-Za-z0-9_]*)\s*\.\s*len\s*\(\s*\)\s*", expr)
+            if length:
+                lengths[name] = collection_name(length.group(1))
+
+        loops = []
+        for loop in inclusive_loop.finditer(body):
+            loop_end = find_matching_brace(body, loop.end() - 1)
+            if loop_end > 0:
+                loops.append((loop.end(), loop_end, loop.group(1), collection_name(loop.group(2))))
+
+        for access in index_re.finditer(body):
+            target = collection_name(access.group(1))
+            if target in ("vec", "matches", "assert", "println", "format", "write", "writeln"):
+                continue
+            before_bracket = access.end() - 1
+            close = find_matching_bracket(body, before_bracket)
+            if close < 0:
+                continue
+            inner = body[before_bracket + 1:close].strip()
+            if not inner:
+                continue
+            # Skip array types / literals like `[u8; 4]` that follow a name.
+            if ";" in inner:
+                continue
+            before = body[:access.start()]
+            escaped_target = re.escape(target)
+            nonempty_guard = re.search(
+                r"!\s*(?:[A-Za-z_][A-Za-z0-9_]*\s*\.\s*)*" + escaped_target + r"\s*\.\s*is_empty\s*\(\s*\)"
+                r"|" + escaped_target + r"\s*\.\s*is_empty\s*\(\s*\)\s*\{\s*return"
+                r"|" + escaped_target + r"\s*\.\s*len\s*\(\s*\)\s*(?:>|>=|!=)\s*[0-9]"
+                r"|" + escaped_target + r"\s*\.\s*(?:first|last)\s*\(\s*\)",
+                before,
+            )
+            # len() - 1 on a collection that may be empty
+            last_index = re.fullmatch(
+                r"(?:(?:[A-Za-z_][A-Za-z0-9_]*\s*\.\s*)*" + escaped_target + r"\s*\.\s*len\s*\(\s*\)|([A-Za-z_][A-Za-z0-9_]*))\s*-\s*1",
+                inner,
+            )
+            if last_index and (last_index.group(1) is None or lengths.get(last_index.group(1)) == target):
+                if not nonempty_guard and not re.search(r"\b" + (re.escape(last_index.group(1)) if last_index.group(1) else "$^") + r"\s*(?:==|>|>=|!=)\s*0", before):
+                    emit(path, masked, lines, brace + access.start())
+                continue
+            # loop counter with an inclusive `..=len()` bound
+            counter_hit = False
+            for lo, hi, counter, bound in loops:
+                if lo <= access.start() < hi and re.fullmatch(re.escape(counter), inner) and bound == target:
+                    counter_hit = True
+                    break
+            if counter_hit:
+                emit(path, masked, lines, brace + access.start())
+                continue
+            # index or range bound straight from parsed/IO input
+            used = set(re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", inner)) & tainted
+            if not used:
+                continue
+            guarded = False
+            for name in used:
+                escaped = re.escape(name)
+                if re.search(
+                    escaped + r"\s*(?:<|<=|>=|>)\s*[^;{]*\.len\s*\(\s*\)"
+                    r"|\.len\s*\(\s*\)\s*(?:<|<=|>=|>)\s*" + escaped + r"\b"
+                    r"|\.get\s*\(\s*" + escaped + r"\b"
+                    r"|" + escaped + r"\s*=\s*[^;]*\.min\s*\(",
+                    before,
+                ):
+                    guarded = True
+                    break
+            if not guarded:
+                emit(path, masked, lines, brace + access.start())
+PY
+}
+
+count_index_oob_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_index_oob_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_index_oob_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_index_oob_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_index_oob() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_index_oob_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_div_zero_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+INT_TYPES = {"u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"}
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+param_re = re.compile(r"(?:^|,)\s*(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*&?\s*(?:mut\s+)?([A-Za-z0-9_]+)\s*(?=,|$)")
+let_re = re.compile(r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::\s*([A-Za-z0-9_]+)\s*)?=([^;]*);")
+tainted_src = re.compile(
+    r"\.\s*parse\s*(?:::\s*<\s*([A-Za-z0-9_]+)\s*>)?\s*\(|\b[ui](?:8|16|32|64|128|size)::from_(?:str(?:_radix)?|be_bytes|le_bytes|ne_bytes)\s*\("
+    r"|\.\s*(?:len|count)\s*\(\s*\)|\.\s*read_[ui](?:8|16|32|64|128)"
+)
+nonzero_expr = re.compile(r"\.\s*(?:max|clamp)\s*\(\s*[1-9]|\bNonZero|\+\s*[1-9][0-9_]*\s*$|^\s*[1-9][0-9_]*(?:[ui](?:8|16|32|64|128|size))?\s*$")
+divisor_re = re.compile(
+    r"(?<![/*])([/%])=?\s*(\(\s*)?([A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*?)(\s*\.\s*len\s*\(\s*\))?(?=\s*(?:[^A-Za-z0-9_.(:\s]|$|\s))"
+)
+seen = set()
+
+
+def zero_guard(name: str, body: str) -> bool:
+    esc = re.escape(name)
+    return bool(re.search(
+        r"(?<![A-Za-z0-9_.])" + esc + r"\s*(?:==|!=|>|>=|<=|<)\s*0(?![.0-9])"
+        r"|(?<![A-Za-z0-9_.])" + esc + r"\s*(?:>=)\s*1\b"
+        r"|\b0\s*(?:==|!=|<|<=)\s*" + esc + r"\b"
+        r"|assert_ne!\s*\(\s*" + esc + r"\s*,\s*0"
+        r"|(?<![A-Za-z0-9_.])" + esc + r"\s*\.\s*is_zero\s*\("
+        r"|match\s+" + esc + r"\s*\{\s*0\s*=>"
+        r"|matches!\s*\(\s*" + esc + r"\s*,\s*0",
+        body,
+    ))
+
+
+def empty_guard(collection: str, body: str) -> bool:
+    esc = re.escape(collection)
+    return bool(re.search(
+        r"(?<![A-Za-z0-9_])" + esc + r"\s*\.\s*is_empty\s*\(\s*\)"
+        r"|(?<![A-Za-z0-9_])" + esc + r"\s*\.\s*len\s*\(\s*\)\s*(?:==|!=|>|>=)\s*[01]\b",
+        body,
+    ))
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "/" not in text and "%" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        candidates = {}
+        params = masked[fn.end():close_paren]
+        for param in param_re.finditer(params):
+            if param.group(2) in INT_TYPES:
+                candidates[param.group(1)] = None
+        for stmt in let_re.finditer(body):
+            name, annotation, expr = stmt.group(1), stmt.group(2), stmt.group(3)
+            if nonzero_expr.search(expr) or re.search(r"\bas\s+f(?:32|64)\b", expr) or annotation in ("f32", "f64"):
+                candidates.pop(name, None)
+                continue
+            if re.search(r"\.\s*parse\s*::\s*<\s*f(?:32|64)\s*>", expr):
+                candidates.pop(name, None)
+                continue
+            length = re.fullmatch(r"\s*([A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\.\s*len\s*\(\s*\)\s*", expr)
+            if annotation in INT_TYPES or tainted_src.search(expr):
+                candidates[name] = re.sub(r"\s+", "", length.group(1)) if length else None
+            else:
+                candidates.pop(name, None)
+        if not candidates and ".len" not in body:
+            continue
+        for div in divisor_re.finditer(body):
+            operand = re.sub(r"\s+", "", div.group(3))
+            rest = body[div.end():div.end() + 3].lstrip()
+            if rest.startswith(("(", "::", "!")):
+                continue
+            if div.group(4):
+                collection = operand
+                if empty_guard(collection, body):
+                    continue
+            else:
+                if operand not in candidates:
+                    continue
+                if div.group(2) and rest.startswith(("+", "-", "*")):
+                    continue
+                if zero_guard(operand, body):
+                    continue
+                collection = candidates.get(operand)
+                if collection and empty_guard(collection, body):
+                    continue
+            line = line_number(masked, brace + div.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}")
+PY
+}
+
+count_div_zero_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_div_zero_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_div_zero_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_div_zero_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_div_zero() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_div_zero_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_float_eq_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  UBS_RUST_FLOAT_EQ_IGNORE_ZERO="$FLOAT_EQ_IGNORE_ZERO" python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+ignore_zero = os.environ.get("UBS_RUST_FLOAT_EQ_IGNORE_ZERO", "0") not in ("", "0", "false", "no")
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+field_re = re.compile(r"(?:^|[{,(])\s*(?:pub(?:\([^)]*\))?\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(f32|f64)\b", re.M)
+param_re = re.compile(r"(?:^|,)\s*(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*&?\s*(?:mut\s+)?(f32|f64)\b")
+let_re = re.compile(r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::\s*([A-Za-z0-9_]+)\s*)?=([^;]*);")
+float_literal = re.compile(r"(?<![A-Za-z0-9_.])(?:\d[\d_]*\.\d[\d_]*(?:e[+-]?\d+)?(?:_?f(?:32|64))?|\d[\d_]*_?f(?:32|64))(?![A-Za-z0-9_])")
+float_expr = re.compile(
+    r"\bas\s+f(?:32|64)\b|\bf(?:32|64)::(?:consts::|EPSILON|NAN|MAX|MIN|from)|\.\s*parse\s*::\s*<\s*f(?:32|64)\s*>"
+    r"|\.\s*(?:sqrt|powf|powi|sin|cos|tan|ln|log10|log2|exp|to_radians|to_degrees|hypot|atan2|fract|recip)\s*\("
+)
+zero_literal = re.compile(r"^-?\s*0(?:\.0*)?(?:_?f(?:32|64))?$|^-?\s*0\.$")
+seen = set()
+
+
+def split_left(text: str, op_start: int, floor: int) -> str:
+    depth = 0
+    idx = op_start - 1
+    while idx >= floor:
+        ch = text[idx]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                break
+            depth -= 1
+        elif depth == 0:
+            if ch in "{};,\n":
+                break
+            if ch in "&|" and idx > floor and text[idx - 1] == ch:
+                break
+            if ch == "=" and text[idx - 1:idx + 1] not in ("==", "!=", "<=", ">="):
+                break
+        idx -= 1
+    left = text[idx + 1:op_start].strip()
+    left = re.sub(r"^(?:if|while|return|else\s+if|assert!\s*\(|debug_assert!\s*\()\s+", "", left)
+    return left
+
+
+def split_right(text: str, op_end: int, ceil: int) -> str:
+    depth = 0
+    idx = op_end
+    while idx < ceil:
+        ch = text[idx]
+        if ch in "([":
+            depth += 1
+        elif ch in ")]":
+            if depth == 0:
+                break
+            depth -= 1
+        elif depth == 0:
+            if ch in "{};,\n":
+                break
+            if ch in "&|" and idx + 1 < ceil and text[idx + 1] == ch:
+                break
+        idx += 1
+    return text[op_end:idx].strip()
+
+
+def is_float(operand: str, floats: set, fields: set) -> bool:
+    if not operand:
+        return False
+    if float_literal.search(operand) or float_expr.search(operand):
+        return True
+    for match in re.finditer(r"(\.\s*)?([A-Za-z_][A-Za-z0-9_]*)(\s*\()?", operand):
+        if match.group(3):
+            continue
+        name = match.group(2)
+        if match.group(1):
+            if name in fields:
+                return True
+        elif name in floats:
+            return True
+    return False
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "==" not in text and "!=" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    fields = {m.group(1) for m in field_re.finditer(masked)}
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        floats = set()
+        for param in param_re.finditer(masked[fn.end():close_paren]):
+            floats.add(param.group(1))
+        body = masked[brace:end + 1]
+        for stmt in let_re.finditer(body):
+            name, annotation, expr = stmt.group(1), stmt.group(2), stmt.group(3)
+            if annotation in ("f32", "f64") or (annotation is None and is_float(expr, floats, fields) and not re.search(r"\bas\s+[ui](?:8|16|32|64|128|size)\s*$", expr)):
+                floats.add(name)
+            else:
+                floats.discard(name)
+        if not floats and not fields and not float_literal.search(body) and not float_expr.search(body):
+            continue
+        for op in re.finditer(r"(?<![=!<>])(==|!=)(?!=)", body):
+            left = split_left(body, op.start(), 0)
+            right = split_right(body, op.end(), len(body))
+            if not (is_float(left, floats, fields) or is_float(right, floats, fields)):
+                continue
+            if re.search(r"\bas\s+[ui](?:8|16|32|64|128|size)\s*\)?$", left) and re.search(r"\bas\s+[ui](?:8|16|32|64|128|size)\b", right):
+                continue
+            if ignore_zero and (zero_literal.match(left) or zero_literal.match(right)):
+                continue
+            emit_offset = brace + op.start()
+            line = line_number(masked, emit_offset)
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}")
+        for macro in re.finditer(r"\b(?:debug_)?assert_(?:eq|ne)!\s*\(", body):
+            close = find_matching_paren(body, macro.end() - 1)
+            if close < 0:
+                continue
+            args = body[macro.end():close]
+            depth = 0
+            parts, current = [], []
+            for ch in args:
+                if ch in "([{":
+                    depth += 1
+                elif ch in ")]}":
+                    depth -= 1
+                if ch == "," and depth == 0:
+                    parts.append("".join(current))
+                    current = []
+                    continue
+                current.append(ch)
+            parts.append("".join(current))
+            if len(parts) < 2:
+                continue
+            left, right = parts[0].strip(), parts[1].strip()
+            if not (is_float(left, floats, fields) or is_float(right, floats, fields)):
+                continue
+            if ignore_zero and (zero_literal.match(left) or zero_literal.match(right)):
+                continue
+            line = line_number(masked, brace + macro.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}")
+PY
+}
+
+count_float_eq_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_float_eq_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_float_eq_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_float_eq_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_float_eq() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_float_eq_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_panic_macro_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+macro_re = re.compile(r"(?<![A-Za-z0-9_:])" + re.escape(mode) + r"!\s*[({\[]")
+test_attr = re.compile(r"#\s*\[\s*(?:cfg\s*\(\s*test\s*\)|(?:[A-Za-z_][A-Za-z0-9_]*::)*test(?:\s*\([^\]]*\))?|bench)\s*\]")
+seen = set()
+
+
+def in_test_dir(path: Path) -> bool:
+    try:
+        parts = path.relative_to(root).parts if root.is_dir() else path.parts[-1:]
+    except ValueError:
+        parts = path.parts
+    return any(part in ("tests", "benches") for part in parts[:-1])
+
+
+def test_regions(masked: str):
+    regions = []
+    for attr in test_attr.finditer(masked):
+        brace = masked.find("{", attr.end())
+        semi = masked.find(";", attr.end())
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end > 0:
+            regions.append((attr.start(), end))
+    return regions
+
+
+for path in rust_files(root):
+    if in_test_dir(path):
+        continue
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if mode + "!" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    regions = test_regions(masked)
+    for match in macro_re.finditer(masked):
+        if any(start <= match.start() <= end for start, end in regions):
+            continue
+        line = line_number(masked, match.start())
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_panic_macro_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_panic_macro_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_panic_macro_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_panic_macro_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_panic_macro() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_panic_macro_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+# Severity for panic!/todo!/unimplemented!/unreachable! findings; overridable
+# per macro via --panic-macro-severity=todo=critical,unreachable=off,...
+panic_macro_severity() {
+  local name="$1" sev entry
+  case "$name" in
+    panic|todo) sev="critical";;
+    unimplemented) sev="warning";;
+    *) sev="info";;
+  esac
+  local IFS=','
+  for entry in $PANIC_MACRO_SEVERITY; do
+    entry="${entry// /}"
+    [[ "${entry%%=*}" == "$name" ]] || continue
+    case "${entry#*=}" in
+      critical|warning|info|off) sev="${entry#*=}";;
+    esac
+  done
+  printf '%s' "$sev"
+}
+
+rust_ffi_panic_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+extern_fn = re.compile(
+    r"((?:#\s*\[[^\]]*\]\s*)*)(?:pub(?:\([^)]*\))?\s+)?(?:unsafe\s+)?extern\s*(\"[^\"]*\")?\s*fn\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^>{]*>)?\s*\("
+)
+panic_surface = re.compile(
+    r"\b(?:panic|unreachable|todo|unimplemented|assert|assert_eq|assert_ne)!\s*\(|\.(?:unwrap|expect)\s*\("
+    r"|(?<![\w.:])[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*\s*\[(?![^\]]*;)[^\]]+\]"
+)
+seen = set()
+
+
+def cargo_sets_abort(path: Path) -> bool:
+    base = path if path.is_dir() else path.parent
+    candidates = list(base.rglob("Cargo.toml")) if path.is_dir() else []
+    for parent in [base, *base.parents]:
+        manifest = parent / "Cargo.toml"
+        if manifest.is_file():
+            candidates.append(manifest)
+            break
+    for manifest in candidates:
+        if {".git", "target"}.intersection(manifest.parts):
+            continue
+        try:
+            content = manifest.read_text(encoding="utf-8", errors="replace")
+        except OSError:
+            continue
+        if re.search(r"(?m)^\s*panic\s*=\s*\"abort\"", content):
+            return True
+    return False
+
+
+abort_profile = cargo_sets_abort(root) if mode == "unwind" else False
+
+
+def emit(path, masked, lines, offset):
+    line = line_number(masked, offset)
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+    if "ubs:ignore" in code:
+        return
+    print(f"{path}:{line}:{code}")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "extern" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in extern_fn.finditer(masked):
+        abi_text = text[fn.start():fn.end()]
+        abi_match = re.search(r"extern\s*\"([^\"]*)\"", abi_text)
+        abi = abi_match.group(1) if abi_match else "C"
+        attrs = fn.group(1) or ""
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        exported = bool(re.search(r"no_mangle|export_name", attrs)) or masked[fn.start() + len(attrs):fn.end()].lstrip().startswith("pub")
+        unwinds = abi.endswith("-unwind")
+        if mode == "unwind":
+            if not exported:
+                continue
+            if unwinds:
+                emit(path, masked, lines, fn.start() + len(attrs))
+            elif abort_profile and "catch_unwind" in body:
+                emit(path, masked, lines, brace + body.find("catch_unwind"))
+            continue
+        if unwinds or abi in ("Rust",) or "catch_unwind" in body:
+            continue
+        for hit in panic_surface.finditer(body):
+            emit(path, masked, lines, brace + hit.start())
+PY
+}
+
+count_ffi_panic_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_ffi_panic_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_ffi_panic_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_ffi_panic_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_ffi_panic() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_ffi_panic_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_cstring_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+cstring_new = re.compile(r"\bCString\s*::\s*new\s*\(")
+from_ptr = re.compile(r"\bCStr\s*::\s*from_ptr\s*\(")
+unwrap_tail = re.compile(r"\s*(?:\.\s*unwrap\s*\(\s*\)|\.\s*expect\s*\(|\?)")
+nul_doc = re.compile(r"(?i)nul[- ]terminated|null[- ]terminated|valid c string|\bNUL\b")
+seen = set()
+
+
+def emit(path, masked, lines, offset):
+    line = line_number(masked, offset)
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+    if "ubs:ignore" in code:
+        return
+    print(f"{path}:{line}:{code}")
+
+
+def statement_start(body: str, idx: int) -> int:
+    return max(body.rfind(";", 0, idx), body.rfind("{", 0, idx), body.rfind("}", 0, idx)) + 1
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "CString" not in text and "CStr" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    prev_end = 0
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        raw_body = text[brace:end + 1]
+        if mode in ("unwrap", "dangling"):
+            for hit in cstring_new.finditer(body):
+                close = find_matching_paren(body, hit.end() - 1)
+                if close < 0:
+                    continue
+                arg_raw = raw_body[hit.end():close].strip()
+                tail = unwrap_tail.match(body, close + 1)
+                if not tail or (mode == "unwrap" and tail.group(0).strip() == "?"):
+                    continue
+                after = body[tail.end():]
+                if tail.group(0).strip().startswith(".expect"):
+                    expect_close = find_matching_paren(body, body.find("(", tail.start()))
+                    after = body[expect_close + 1:] if expect_close > 0 else after
+                as_ptr = re.match(r"\s*\.\s*as_ptr\s*\(\s*\)", after)
+                if mode == "dangling":
+                    if not as_ptr:
+                        continue
+                    rest = after[as_ptr.end():]
+                    stmt = body[statement_start(body, hit.start()):hit.start()]
+                    binds = re.search(r"(?:\blet\b[^=]*|[A-Za-z0-9_\]\)]\s*)=\s*$|\breturn\s*$|:\s*$", stmt)
+                    stored = re.match(r"\s*(?:as\s+\*(?:const|mut)\s+[A-Za-z0-9_:]+\s*)?[;,}]", rest)
+                    if binds and stored:
+                        emit(path, masked, lines, brace + hit.start())
+                    elif re.match(r"\s*}", rest):
+                        emit(path, masked, lines, brace + hit.start())
+                    continue
+                # A plain literal without an embedded \0 cannot fail.
+                if re.fullmatch(r"(?:b?\"(?:[^\"\\\\]|\\\\[^0x]|\\\\x(?!00)[0-9a-fA-F]{2})*\")", arg_raw):
+                    continue
+                emit(path, masked, lines, brace + hit.start())
+            continue
+        # from_ptr: the pointer must come from something visibly NUL-terminated.
+        doc_start = max(masked.rfind("}", 0, fn.start()), masked.rfind(";", 0, fn.start()), 0)
+        docs = text[doc_start:brace]
+        if nul_doc.search(docs) or nul_doc.search(raw_body):
+            continue
+        for hit in from_ptr.finditer(body):
+            close = find_matching_paren(body, hit.end() - 1)
+            if close < 0:
+                continue
+            arg = body[hit.end():close].strip()
+            # Only the outermost expression counts: getenv(name.as_ptr()) still returns an unknown pointer.
+            if re.search(r"\.\s*(?:as_ptr|into_raw)\s*\(\s*\)\s*$|\bc\s*$", arg) or raw_body[hit.end():close].lstrip().startswith(('c"', 'b"')):
+                continue
+            name = re.fullmatch(r"([A-Za-z_][A-Za-z0-9_]*)(?:\s+as\s+[^)]*)?", arg)
+            if name:
+                local = re.search(
+                    r"let\s+(?:mut\s+)?" + re.escape(name.group(1)) + r"\b[^=;]*=\s*(?:c\"|[^;]*\.\s*(?:as_ptr|into_raw)\s*\(\s*\)\s*;)",
+                    raw_body[:hit.start()],
+                )
+                if local:
+                    continue
+            emit(path, masked, lines, brace + hit.start())
+PY
+}
+
+count_cstring_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_cstring_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_cstring_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_cstring_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_cstring() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_cstring_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_env_unwrap_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+env_call = re.compile(r"(?<![A-Za-z0-9_])(?:std\s*::\s*)?env\s*::\s*var(?:_os)?\s*\(")
+test_attr = re.compile(r"#\s*\[\s*(?:cfg\s*\(\s*test\s*\)|(?:[A-Za-z_][A-Za-z0-9_]*::)*test(?:\s*\([^\]]*\))?|bench)\s*\]")
+seen = set()
+
+
+def in_test_dir(path: Path) -> bool:
+    try:
+        parts = path.relative_to(root).parts if root.is_dir() else path.parts[-1:]
+    except ValueError:
+        parts = path.parts
+    return any(part in ("tests", "benches") for part in parts[:-1])
+
+
+def test_regions(masked: str):
+    regions = []
+    for attr in test_attr.finditer(masked):
+        brace = masked.find("{", attr.end())
+        semi = masked.find(";", attr.end())
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end > 0:
+            regions.append((attr.start(), end))
+    return regions
+
+
+names = []
+for path in rust_files(root):
+    if in_test_dir(path):
+        continue
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "env" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    regions = test_regions(masked)
+    for call in env_call.finditer(masked):
+        if any(start <= call.start() <= end for start, end in regions):
+            continue
+        close = find_matching_paren(masked, call.end() - 1)
+        if close < 0:
+            continue
+        if not re.match(r"\s*\.\s*(?:unwrap|expect)\s*\(", masked[close + 1:]):
+            continue
+        arg = text[call.end():close].strip()
+        literal = re.fullmatch(r"\"((?:[^\"\\\\]|\\\\.)*)\"", arg)
+        if not literal and re.fullmatch(r"[A-Z_][A-Z0-9_]*", arg):
+            literal = re.search(r"\bconst\s+" + re.escape(arg) + r"\s*:\s*&(?:'static\s+)?str\s*=\s*\"((?:[^\"\\\\]|\\\\.)*)\"", text)
+        name = literal.group(1) if literal else arg
+        line = line_number(masked, call.start())
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        if mode == "names":
+            if name and name not in names:
+                names.append(name)
+            continue
+        print(f"{path}:{line}:{code}")
+
+if mode == "names":
+    for name in names:
+        print(name)
+PY
+}
+
+count_env_unwrap_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_env_unwrap_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_env_unwrap_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_env_unwrap_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_env_unwrap() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_env_unwrap_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_external_parse_matches() {
+  local mode="${1:-parse}"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+source_re = re.compile(
+    r"\benv\s*::\s*args(?:_os)?\s*\(|\bstdin\s*\(\s*\)|\bfs\s*::\s*read_to_string\s*\(|\bread_to_string\s*\(\s*[A-Za-z_]"
+    r"|\.\s*text\s*\(\s*\)\s*\.\s*await|\bto_bytes\s*\(|\.\s*body\s*\(\s*\)|\.\s*headers\s*\(\s*\)|\.\s*query_pairs\s*\(|\.\s*uri\s*\(\s*\)\s*\.\s*query"
+    r"|\benv\s*::\s*var(?:_os)?\s*\(|\bfs\s*::\s*read\s*\(|\bFile\s*::\s*open\s*\(|\.\s*bytes\s*\(\s*\)\s*\.\s*await|\.\s*into_body\s*\(|\.\s*read_to_end\s*\("
+)
+# Handler parameters that carry a raw request body.
+body_param_re = re.compile(
+    r"\b([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(?:[A-Za-z_]+\s*::\s*)*(?:Bytes|BytesMut)\b"
+    r"|\b((?:body|payload|raw_body|request_body|input)[A-Za-z0-9_]*)\s*:\s*(?:String|&\s*str|Vec\s*<\s*u8\s*>|&\s*\[\s*u8\s*\])"
+)
+serde_unwrap = re.compile(
+    r"\b(serde_json|serde_yaml|serde_yml|toml|json5|ron|bincode|rmp_serde|ciborium|simd_json)\s*::\s*"
+    r"(from_[a-z_]+|deserialize[a-z_]*|de\s*::\s*from_[a-z_]+)\s*(?:::\s*<[^>]*>\s*)?\("
+)
+fill_re = re.compile(r"\.\s*read_(?:line|to_string)\s*\(\s*&\s*mut\s+([A-Za-z_][A-Za-z0-9_]*)\s*\)")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?(\(?[A-Za-z_][A-Za-z0-9_,\s]*\)?)\s*(?::[^=;]*)?=([^;]*);")
+for_re = re.compile(r"\bfor\s+(\(?[A-Za-z_][A-Za-z0-9_,\s()]*?\)?)\s+in\s+([^{]*)\{")
+parse_unwrap = re.compile(
+    r"\.\s*parse\s*(?:::\s*<[^>]*>\s*)?\(\s*\)\s*\.\s*(?:unwrap|expect)\s*\("
+)
+from_str_unwrap = re.compile(r"\b(?:[A-Za-z_][A-Za-z0-9_]*\s*::\s*)?from_str\s*\(")
+seen = set()
+
+
+def idents(expr: str):
+    return set(re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", expr))
+
+
+def receiver_start(body: str, end: int) -> int:
+    depth = 0
+    idx = end - 1
+    while idx >= 0:
+        ch = body[idx]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                break
+            depth -= 1
+        elif depth == 0 and not (ch.isalnum() or ch in "_.:" or ch.isspace() or ch in "<>&?"):
+            break
+        elif depth == 0 and ch.isspace():
+            prev = body[:idx].rstrip()
+            if not prev.endswith((".", "::")) and not body[idx:end].lstrip().startswith("."):
+                break
+        idx -= 1
+    return idx + 1
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "parse" not in text and "from_" not in text and "deserialize" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        events = []
+        for stmt in let_re.finditer(body):
+            events.append((stmt.start(), "let", stmt))
+        for loop in for_re.finditer(body):
+            events.append((loop.start(), "for", loop))
+        for fill in fill_re.finditer(body):
+            events.append((fill.start(), "fill", fill))
+        events.sort(key=lambda item: item[0])
+        tainted = set()
+        if mode == "serde":
+            for param in body_param_re.finditer(masked[fn.end():close_paren]):
+                tainted.add(param.group(1) or param.group(2))
+
+        def is_tainted(expr: str) -> bool:
+            return bool(source_re.search(expr) or idents(expr) & tainted)
+
+        for _, kind, match in events:
+            if kind == "fill":
+                tainted.add(match.group(1))
+                continue
+            names = idents(match.group(1)) - {"mut", "ref"}
+            if is_tainted(match.group(2)):
+                tainted.update(names)
+            elif kind == "let":
+                tainted.difference_update(names)
+
+        hits = []
+        if mode == "serde":
+            for hit in serde_unwrap.finditer(body):
+                close = find_matching_paren(body, hit.end() - 1)
+                if close < 0 or not re.match(r"\s*\.\s*(?:unwrap|expect)\s*\(", body[close + 1:]):
+                    continue
+                hits.append((hit.start(), body[hit.end():close], hit))
+        else:
+            for hit in parse_unwrap.finditer(body):
+                start = receiver_start(body, hit.start())
+                hits.append((start, body[start:hit.start()], hit))
+            for hit in from_str_unwrap.finditer(body):
+                if serde_unwrap.match(body, hit.start()):
+                    continue
+                close = find_matching_paren(body, hit.end() - 1)
+                if close < 0 or not re.match(r"\s*\.\s*(?:unwrap|expect)\s*\(", body[close + 1:]):
+                    continue
+                hits.append((hit.start(), body[hit.end():close], hit))
+        for start, receiver, hit in hits:
+            flagged = is_tainted(receiver)
+            if not flagged:
+                stmt_start = max(body.rfind(";", 0, start), body.rfind("{", 0, start)) + 1
+                stmt = body[stmt_start:start]
+                for closure in re.finditer(r"\|\s*([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^|]*)?\|", stmt):
+                    if closure.group(1) in idents(receiver) and is_tainted(stmt[:closure.start()]):
+                        flagged = True
+                        break
+            if not flagged:
+                continue
+            line = line_number(masked, brace + hit.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            if mode == "serde":
+                call = re.sub(r"\s+", "", hit.group(1) + "::" + hit.group(2))
+                print(f"{path}:{line}:{code}  [{call}(..) on external input unwrapped]")
+            else:
+                print(f"{path}:{line}:{code}")
+PY
+}
+
+count_external_parse_matches() {
+  local mode="${1:-parse}"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_external_parse_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_external_parse_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_external_parse_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_external_parse() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_external_parse_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_clone_readonly_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  UBS_RUST_CLONE_LOOP_MIN_BYTES="$CLONE_LOOP_MIN_BYTES" python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+try:
+    min_bytes = int(os.environ.get("UBS_RUST_CLONE_LOOP_MIN_BYTES", "64"))
+except ValueError:
+    min_bytes = 64
+HEAP_TYPES = {
+    "String", "Vec", "VecDeque", "HashMap", "HashSet", "BTreeMap", "BTreeSet", "BinaryHeap",
+    "PathBuf", "OsString", "Box", "BytesMut", "LinkedList", "IndexMap", "IndexSet",
+}
+SCALAR_SIZES = {
+    "u8": 1, "i8": 1, "bool": 1, "u16": 2, "i16": 2, "u32": 4, "i32": 4, "f32": 4, "char": 4,
+    "u64": 8, "i64": 8, "f64": 8, "usize": 8, "isize": 8, "u128": 16, "i128": 16,
+    "Rc": 8, "Arc": 8, "Duration": 16, "Instant": 16,
+}
+READ_METHODS = {
+    "len", "is_empty", "iter", "get", "contains", "contains_key", "starts_with", "ends_with", "as_str",
+    "as_slice", "as_ref", "first", "last", "keys", "values", "chars", "bytes", "lines", "split",
+    "trim", "find", "to_lowercase", "to_uppercase", "eq", "cmp", "display", "as_path", "exists",
+    "borrow", "deref", "capacity", "windows", "chunks", "binary_search", "join", "to_string",
+}
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+struct_re = re.compile(r"\bstruct\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^>{;]*>)?\s*\{")
+typed_re = re.compile(r"(?:^|[,({])\s*(?:pub(?:\([^)]*\))?\s+)?(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*&?\s*(?:mut\s+)?((?:[A-Za-z_][A-Za-z0-9_]*::)*[A-Za-z_][A-Za-z0-9_]*)", re.M)
+let_re = re.compile(r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::\s*&?\s*((?:[A-Za-z_][A-Za-z0-9_]*::)*[A-Za-z_][A-Za-z0-9_]*))?\s*=([^;]*);")
+ctor_re = re.compile(r"^\s*(?:(?:std::)?(?:collections::)?([A-Z][A-Za-z0-9_]*)\s*::\s*(?:new|with_capacity|from|default)\b|(vec!)|(format!))")
+loop_re = re.compile(r"\b(?:for\b[^{;]*|while\b[^{;]*|loop\s*)\{")
+clone_re = re.compile(r"((?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)?)\s*\.\s*(clone|to_owned)\s*\(\s*\)")
+seen = set()
+
+
+def base_type(ty: str) -> str:
+    return ty.split("::")[-1] if ty else ""
+
+
+struct_sizes = {}
+
+
+def type_size(ty: str, depth: int = 0) -> int:
+    ty = base_type(ty)
+    if ty in HEAP_TYPES:
+        return 1 << 20
+    if ty in SCALAR_SIZES:
+        return SCALAR_SIZES[ty]
+    if ty in struct_sizes and depth < 4:
+        return sum(type_size(field, depth + 1) * count for field, count in struct_sizes[ty])
+    return 0
+
+
+def heavy(ty: str) -> bool:
+    return bool(ty) and type_size(ty) >= min_bytes
+
+
+def read_only_use(rest: str, raw_rest: str, name: str) -> bool:
+    uses = list(re.finditer(r"(?<![A-Za-z0-9_.])" + re.escape(name) + r"(?![A-Za-z0-9_])", rest))
+    if not uses:
+        # Only referenced through inline format captures such as "{name}".
+        return bool(re.search(r"\{" + re.escape(name) + r"[}:]", raw_rest))
+    for use in uses:
+        before = rest[:use.start()].rstrip()
+        after = rest[use.end():]
+        if before.endswith("&") and not before.endswith("&&"):
+            continue
+        method = re.match(r"\s*\.\s*([A-Za-z_][A-Za-z0-9_]*)\s*(\(|\[)?", after)
+        if method:
+            if method.group(2) == "[" or method.group(1) in READ_METHODS:
+                continue
+            return False
+        if re.match(r"\s*\[", after):
+            continue
+        if re.match(r"\s*(?:==|!=|<|>)", after) or re.search(r"(?:==|!=)\s*$", before):
+            continue
+        if re.search(r"\b(?:println|print|eprintln|format|write|writeln|debug|info|warn|error|trace)!\s*\([^;]*$", before):
+            continue
+        return False
+    return True
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if ".clone" not in text and ".to_owned" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    struct_sizes.clear()
+    field_types = {}
+    copy_structs = set(re.findall(r"#\s*\[\s*derive\s*\([^)]*\bCopy\b[^)]*\)\s*\]\s*(?:pub(?:\([^)]*\))?\s+)?struct\s+([A-Za-z_][A-Za-z0-9_]*)", masked))
+    for struct in struct_re.finditer(masked):
+        open_idx = struct.end() - 1
+        close_idx = find_matching_brace(masked, open_idx)
+        if close_idx < 0:
+            continue
+        struct_body = masked[open_idx:close_idx + 1]
+        fields = typed_re.findall(struct_body)
+        arrays = re.findall(r"(?:^|[,{])\s*(?:pub(?:\([^)]*\))?\s+)?[A-Za-z_][A-Za-z0-9_]*\s*:\s*\[\s*([A-Za-z0-9_]+)\s*;\s*(\d+)\s*\]", struct_body, re.M)
+        struct_sizes[struct.group(1)] = [(ty, 1) for _, ty in fields] + [(ty, int(count)) for ty, count in arrays]
+        for name, ty in fields:
+            field_types.setdefault(name, ty)
+    for name in copy_structs:
+        struct_sizes.pop(name, None)
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        types = {name: ty for name, ty in typed_re.findall(masked[fn.end():close_paren])}
+        body = masked[brace:end + 1]
+        for stmt in let_re.finditer(body):
+            name, annotation, expr = stmt.group(1), stmt.group(2), stmt.group(3)
+            if annotation:
+                types[name] = annotation
+                continue
+            ctor = ctor_re.match(expr)
+            if ctor:
+                types[name] = ctor.group(1) or ("Vec" if ctor.group(2) else "String")
+        for loop in loop_re.finditer(body):
+            open_idx = loop.end() - 1
+            close_idx = find_matching_brace(body, open_idx)
+            if close_idx < 0:
+                continue
+            header = body[loop.start():open_idx]
+            loop_vars = set(re.findall(r"[A-Za-z_][A-Za-z0-9_]*", header.split(" in ")[0])) if header.startswith("for") else set()
+            loop_body = body[open_idx:close_idx + 1]
+            for hit in clone_re.finditer(loop_body):
+                receiver = re.sub(r"\s+", "", hit.group(1))
+                root_name = receiver.split(".")[0]
+                if root_name in loop_vars:
+                    continue
+                if receiver.startswith("self.") or "." in receiver:
+                    ty = field_types.get(receiver.split(".")[-1], "")
+                else:
+                    ty = types.get(receiver, "")
+                if not heavy(ty):
+                    continue
+                # Receiver mutated in the loop: the per-iteration snapshot may be intentional.
+                if re.search(r"(?<![A-Za-z0-9_.])" + re.escape(receiver) + r"\s*\.\s*(?:push|insert|remove|clear|extend|truncate|retain|drain|pop)\w*\s*\(|(?<![A-Za-z0-9_.=!<>])" + re.escape(receiver) + r"\s*(?:[+\-*/]?=)(?!=)", loop_body):
+                    continue
+                stmt_start = max(loop_body.rfind(";", 0, hit.start()), loop_body.rfind("{", 0, hit.start()), loop_body.rfind("}", 0, hit.start())) + 1
+                prefix = loop_body[stmt_start:hit.start()]
+                after = loop_body[hit.end():]
+                binding = re.search(r"\blet\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=]*)?=\s*$", prefix)
+                if binding:
+                    if not re.match(r"\s*;", after):
+                        continue
+                    rest_offset = brace + open_idx + hit.end() + after.find(";") + 1
+                    raw_rest = text[rest_offset:brace + close_idx + 1]
+                    if not read_only_use(after[after.find(";") + 1:], raw_rest, binding.group(1)):
+                        continue
+                elif re.search(r"&\s*$", prefix):
+                    pass
+                else:
+                    method = re.match(r"\s*\.\s*([A-Za-z_][A-Za-z0-9_]*)\s*\(", after)
+                    if not method or method.group(1) not in READ_METHODS:
+                        continue
+                line = line_number(masked, brace + open_idx + hit.start())
+                key = (str(path), line)
+                if key in seen:
+                    continue
+                seen.add(key)
+                code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+                if "ubs:ignore" in code:
+                    continue
+                print(f"{path}:{line}:{code}")
+PY
+}
+
+count_clone_readonly_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_clone_readonly_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_clone_readonly_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_clone_readonly_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_clone_readonly() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_clone_readonly_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_string_concat_matches() {
+  local mode="$1"
+  local output="${2:-hits}"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" "$output" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+output = sys.argv[3]
+
+
+loop_re = re.compile(r"\b(?:for\b[^{;]*|while\b[^{;]*|loop\s*)\{")
+closure_re = re.compile(r"\.\s*(?:for_each|try_for_each|fold)\s*\(")
+plus_re = re.compile(
+    r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)\s*=\s*\1\s*\+\s*([^;]+);"
+)
+format_re = re.compile(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)\s*=\s*format!\s*\(")
+push_format_re = re.compile(
+    r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)\s*(?:\+=\s*&\s*|\.\s*push_str\s*\(\s*&\s*)format!\s*\("
+)
+string_init = (
+    r"(?::\s*String\b[^=;]*)?=\s*(?:String\s*::\s*(?:new|with_capacity|from)\s*\(|format!"
+    r"|[^;]*\.\s*(?:to_string|to_owned|into_string)\s*\(\s*\)\s*;)"
+)
+# `s = format!("{s}..")` copies the whole accumulator each pass; `s = s + &x` appends in place.
+mode_kinds = {"quadratic": ("format",), "append": ("plus", "push_format")}
+seen = set()
+
+
+def loop_ranges(masked: str):
+    ranges = []
+    for loop in loop_re.finditer(masked):
+        open_idx = loop.end() - 1
+        close_idx = find_matching_brace(masked, open_idx)
+        if close_idx > 0:
+            ranges.append((open_idx, close_idx))
+    for closure in closure_re.finditer(masked):
+        depth = 0
+        for idx in range(closure.end() - 1, len(masked)):
+            if masked[idx] == "(":
+                depth += 1
+            elif masked[idx] == ")":
+                depth -= 1
+                if depth == 0:
+                    ranges.append((closure.end(), idx))
+                    break
+    return ranges
+
+
+def split_args(args: str):
+    parts, current, depth, in_str, prev = [], [], 0, False, ""
+    for ch in args:
+        if ch == '"' and prev != "\\":
+            in_str = not in_str
+        elif not in_str and ch in "([{":
+            depth += 1
+        elif not in_str and ch in ")]}":
+            depth -= 1
+        prev = ch
+        if ch == "," and depth == 0 and not in_str:
+            parts.append("".join(current).strip())
+            current = []
+            continue
+        current.append(ch)
+    if "".join(current).strip():
+        parts.append("".join(current).strip())
+    return parts
+
+
+def rewrite(kind: str, target: str, payload: str) -> str:
+    if kind == "plus":
+        pushes = []
+        for piece in split_plus(payload):
+            piece = piece.strip()
+            pushes.append(f"{target}.push_str({piece});")
+        return " ".join(pushes)
+    if kind == "format":
+        args = split_args(payload)
+        if not args or not args[0].startswith('"'):
+            return ""
+        inner = args[0][1:-1]
+        if inner.startswith("{" + target + "}"):
+            return f'let _ = write!({target}, "{inner[len(target) + 2:]}"' + "".join(", " + a for a in args[1:]) + ");"
+        if inner.startswith("{}") and len(args) > 1 and args[1] == target:
+            return f'let _ = write!({target}, "{inner[2:]}"' + "".join(", " + a for a in args[2:]) + ");"
+        return ""
+    return f"let _ = write!({target}, {payload.strip()});"
+
+
+def split_plus(expr: str):
+    parts, current, depth, in_str = [], [], 0, False
+    prev = ""
+    for ch in expr:
+        if ch == '"' and prev != "\\":
+            in_str = not in_str
+        elif not in_str and ch in "([{":
+            depth += 1
+        elif not in_str and ch in ")]}":
+            depth -= 1
+        if ch == "+" and depth == 0 and not in_str:
+            parts.append("".join(current))
+            current = []
+        else:
+            current.append(ch)
+        prev = ch
+    parts.append("".join(current))
+    return [p for p in parts if p.strip()]
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "+" not in text and "format!" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    ranges = loop_ranges(masked)
+    if not ranges:
+        continue
+    for kind, pattern in (("plus", plus_re), ("format", format_re), ("push_format", push_format_re)):
+        if kind not in mode_kinds.get(mode, ()):
+            continue
+        for match in pattern.finditer(masked):
+            if not any(lo < match.start() < hi for lo, hi in ranges):
+                continue
+            target = match.group(1)
+            if kind == "plus":
+                payload = text[match.start(2):match.end(2)]
+                # Numeric accumulators (`total = total + n`) are not string building.
+                if not re.search(r"&|\.\s*as_str\s*\(|\.\s*to_string\s*\(|\bformat!|\"", payload):
+                    declared = re.compile(r"\blet\s+mut\s+" + re.escape(target) + r"\s*" + string_init)
+                    if not declared.search(masked, 0, match.start()):
+                        continue
+            else:
+                close = find_matching_paren(masked, match.end() - 1)
+                if close < 0:
+                    continue
+                payload = text[match.end():close]
+                if kind == "format":
+                    args = split_args(payload)
+                    if not args or ("{" + target + "}" not in args[0] and target not in args[1:]):
+                        continue
+            line = line_number(masked, match.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            if output == "fix":
+                fixed = rewrite(kind, target, payload)
+                if fixed:
+                    print(f"{path.name}:{line}: {fixed}")
+                continue
+            print(f"{path}:{line}:{code}")
+PY
+}
+
+count_string_concat_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_string_concat_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_string_concat_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_string_concat_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_string_concat() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_string_concat_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_needless_collect_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+vec_collect = r"\.\s*collect\s*(?:::\s*<\s*Vec\s*<[^>]*>\s*>\s*)?\(\s*\)"
+let_collect = re.compile(
+    r"\blet\s+(mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(:\s*Vec\s*<[^=;]*>)?\s*=\s*[^;]*?" + vec_collect + r"\s*;"
+)
+chained = re.compile(
+    vec_collect + r"\s*\.\s*(?:iter|into_iter)\s*\(\s*\)|" + r"\.\s*collect\s*::\s*<\s*Vec\s*<[^>]*>\s*>\s*\(\s*\)\s*\.\s*(?:is_empty\s*\(\s*\)|len\s*\(\s*\)\s*(?:==|>|!=)\s*0\b)"
+)
+iterate_use = re.compile(r"\s*\.\s*(?:iter|into_iter)\s*\(\s*\)")
+empty_use = re.compile(r"\s*\.\s*(?:is_empty\s*\(\s*\)|len\s*\(\s*\)\s*(?:==|>|!=)\s*0\b)")
+seen = set()
+
+
+def emit(path, masked, lines, offset):
+    line = line_number(masked, offset)
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+    if "ubs:ignore" in code:
+        return
+    print(f"{path}:{line}:{code}")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "collect" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for hit in chained.finditer(masked):
+        emit(path, masked, lines, hit.start())
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        for stmt in let_collect.finditer(body):
+            if stmt.group(1):
+                continue
+            if not stmt.group(3) and "Vec" not in stmt.group(0):
+                continue
+            name = stmt.group(2)
+            rest = body[stmt.end():]
+            # The binding's scope ends with the enclosing block.
+            depth = 0
+            scope_end = len(rest)
+            for idx, ch in enumerate(rest):
+                if ch == "{":
+                    depth += 1
+                elif ch == "}":
+                    depth -= 1
+                    if depth < 0:
+                        scope_end = idx
+                        break
+            rest = rest[:scope_end]
+            if re.search(r"\blet\s+(?:mut\s+)?" + re.escape(name) + r"\b", rest):
+                continue
+            uses = list(re.finditer(r"(?<![A-Za-z0-9_.])" + re.escape(name) + r"(?![A-Za-z0-9_])", rest))
+            if len(uses) != 1:
+                continue
+            use = uses[0]
+            after = rest[use.end():]
+            before = rest[:use.start()]
+            single_pass = (
+                iterate_use.match(after)
+                or empty_use.match(after)
+                or re.search(r"\bfor\s+[^{;]*?\bin\s+&?\s*$", before)
+            )
+            if single_pass:
+                emit(path, masked, lines, brace + stmt.start())
+PY
+}
+
+count_needless_collect_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_needless_collect_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_needless_collect_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_needless_collect_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_needless_collect() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_needless_collect_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_command_injection_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  UBS_RUST_TAINT_SOURCES="$TAINT_SOURCES" UBS_RUST_COMMAND_SINKS="$COMMAND_SINKS" python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def find_matching_paren(text: str, open_index: int) -> int:
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch in "([":
+            depth += 1
+        elif ch in ")]":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def split_top_level(text: str, start: int, end: int, raw: str = ""):
+    """Comma-separated spans of text[start:end], ignoring nested commas."""
+    spans = []
+    depth = 0
+    begin = start
+    for idx in range(start, end):
+        ch = text[idx]
+        if ch in "([{<":
+            depth += 1
+        elif ch in ")]}>" and depth > 0:
+            depth -= 1
+        elif ch == "," and depth == 0:
+            spans.append((begin, idx))
+            begin = idx + 1
+    spans.append((begin, end))
+    raw = raw or text
+    return [(a, b) for a, b in spans if raw[a:b].strip()]
+
+
+def config_list(name: str):
+    return [item.strip() for item in os.environ.get(name, "").split(",") if item.strip()]
+
+
+ident = r"[A-Za-z_][A-Za-z0-9_]*"
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+sink_names = [r"(?:[A-Za-z_][A-Za-z0-9_]*\s*::\s*)*Command\s*::\s*new"]
+sink_names += [r"\s*::\s*".join(re.escape(part) for part in item.rstrip("!").split("::")) + ("!" if item.endswith("!") else "")
+               for item in config_list("UBS_RUST_COMMAND_SINKS")]
+sink_re = re.compile(r"(?<![A-Za-z0-9_])(?:" + "|".join(sink_names) + r")\s*\(")
+source_re = re.compile(
+    r"\benv\s*::\s*(?:args(?:_os)?|var(?:_os)?)\s*\(|\bstdin\s*\(\s*\)|\bread_to_string\s*\(|\bread_line\s*\("
+    r"|\.\s*text\s*\(\s*\)\s*\.\s*await|\bto_bytes\s*\(|\.\s*body\s*\(\s*\)|\.\s*headers\s*\(\s*\)"
+    r"|\.\s*query_pairs\s*\(|\.\s*uri\s*\(\s*\)|\.\s*match_info\s*\(|\.\s*query_string\s*\("
+)
+extra_sources = config_list("UBS_RUST_TAINT_SOURCES")
+extra_source_re = re.compile(
+    r"(?<![A-Za-z0-9_])(?:" + "|".join(r"\s*(?:\.|::)\s*".join(re.escape(p) for p in re.split(r"\.|::", item)) for item in extra_sources) + r")(?![A-Za-z0-9_])"
+) if extra_sources else None
+# Web framework extractors and request types carry client-controlled data.
+tainted_param_type = re.compile(
+    r"\b(?:Query|Json|Form|Path|Multipart|Bytes|HttpRequest|Request|RawQuery|TypedHeader|HeaderMap)\b"
+)
+tainted_param_name = re.compile(r"(?:^|_)(?:user|input|untrusted|payload|query|param|params|request|req|body|form|arg|args)(?:$|_)")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?(\(?[A-Za-z_][A-Za-z0-9_,\s]*\)?)\s*(?::[^=;]*)?=([^;]*);")
+for_re = re.compile(r"\bfor\s+(\(?[A-Za-z_][A-Za-z0-9_,\s()]*?\)?)\s+in\s+([^{]*)\{")
+fill_re = re.compile(r"\.\s*read_(?:line|to_string)\s*\(\s*&\s*mut\s+([A-Za-z_][A-Za-z0-9_]*)\s*\)")
+shell_programs = {"sh", "bash", "zsh", "dash", "ksh", "fish", "cmd", "cmd.exe", "powershell", "powershell.exe", "pwsh"}
+shell_flags = {"-c", "-lc", "-ic", "/c", "/C", "-Command", "-command"}
+seen = set()
+
+
+def idents(expr: str):
+    return set(re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", expr))
+
+
+def inline_captures(raw: str):
+    names = set()
+    for literal in re.finditer(r'"(?:[^"\\]|\\.)*"', raw):
+        names.update(re.findall(r"(?<!\{)\{([A-Za-z_][A-Za-z0-9_]*)(?::[^}]*)?\}", literal.group(0)))
+    return names
+
+
+def literal_value(raw: str):
+    match = re.fullmatch(r'\s*&?\s*"((?:[^"\\]|\\.)*)"\s*', raw)
+    return match.group(1) if match else None
+
+
+interpolation = re.compile(r"\b(?:format|concat)\s*!\s*\(|\+|\.\s*(?:join|concat|replace)\s*\(|\.\s*push_str\s*\(")
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if not sink_re.search(text):
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        raw_body = text[brace:end + 1]
+        if not sink_re.search(body):
+            continue
+
+        def expr_idents(start: int, stop: int):
+            return idents(body[start:stop]) | inline_captures(raw_body[start:stop])
+
+        def is_source(expr: str) -> bool:
+            return bool(source_re.search(expr) or (extra_source_re and extra_source_re.search(expr)))
+
+        tainted = set()
+        for param in split_top_level(masked, fn.end(), close_paren):
+            piece = masked[param[0]:param[1]]
+            if ":" not in piece:
+                continue
+            names, _, ty = piece.partition(":")
+            bound = idents(names) - {"mut", "ref", "self"}
+            if tainted_param_type.search(ty) or (extra_source_re and extra_source_re.search(ty)):
+                tainted.update(bound)
+            elif re.search(r"&\s*(?:mut\s+)?str\b|\bString\b|\bOsStr|\bPath", ty):
+                tainted.update(name for name in bound if tainted_param_name.search(name.lower()))
+
+        built = {}
+        events = []
+        for stmt in let_re.finditer(body):
+            events.append((stmt.start(), "let", stmt))
+        for loop in for_re.finditer(body):
+            events.append((loop.start(), "for", loop))
+        for fill in fill_re.finditer(body):
+            events.append((fill.start(), "fill", fill))
+        events.sort(key=lambda item: item[0])
+        for _, kind, match in events:
+            if kind == "fill":
+                tainted.add(match.group(1))
+                continue
+            names = idents(match.group(1)) - {"mut", "ref"}
+            rhs = match.group(2)
+            rhs_names = expr_idents(match.start(2), match.end(2))
+            if is_source(rhs) or rhs_names & tainted:
+                tainted.update(names)
+            elif kind == "let":
+                tainted.difference_update(names)
+            if kind == "let" and len(names) == 1 and interpolation.search(rhs):
+                built[next(iter(names))] = match.start()
+        # String buffers grown with push_str/write! are interpolated too.
+        for grow in re.finditer(r"\b(" + ident + r")\s*\.\s*push_str\s*\(|\bwrite(?:ln)?\s*!\s*\(\s*&?\s*mut\s+(" + ident + r")", body):
+            name = grow.group(1) or grow.group(2)
+            built.setdefault(name, grow.start())
+            paren = body.find("(", grow.start())
+            close = find_matching_paren(body, paren)
+            if close > 0 and expr_idents(paren, close) & tainted:
+                tainted.add(name)
+
+        def interpolated(start: int, stop: int) -> bool:
+            expr = body[start:stop]
+            if interpolation.search(expr):
+                return True
+            name = expr.strip().lstrip("&").strip()
+            name = re.sub(r"\s*\.\s*(?:as_str|as_ref|to_string|clone)\s*\(\s*\)$", "", name)
+            return name in built and built[name] < start
+
+        def untrusted(start: int, stop: int) -> bool:
+            return bool(is_source(body[start:stop]) or expr_idents(start, stop) & tainted)
+
+        def chain_args(pos: int):
+            """(method, [(start, stop)]) for .arg/.args calls chained from pos."""
+            found = []
+            while True:
+                step = re.match(r"\s*\.\s*(" + ident + r")\s*\(", body[pos:])
+                if not step:
+                    return found
+                paren = pos + step.end() - 1
+                close = find_matching_paren(body, paren)
+                if close < 0:
+                    return found
+                method = step.group(1)
+                if method == "arg":
+                    found.append((paren + 1, close))
+                elif method == "args":
+                    inner = re.match(r"\s*&?\s*(?:vec\s*!\s*)?\[", body[paren + 1:close])
+                    if inner:
+                        open_bracket = paren + inner.end()
+                        close_bracket = find_matching_paren(body, open_bracket)
+                        found.extend(split_top_level(body, open_bracket + 1, close_bracket, raw_body))
+                    else:
+                        found.append((paren + 1, close))
+                pos = close + 1
+
+        for sink in sink_re.finditer(body):
+            paren = sink.end() - 1
+            close = find_matching_paren(body, paren)
+            if close < 0:
+                continue
+            own = split_top_level(body, paren + 1, close, raw_body)
+            if not own:
+                continue
+            program = literal_value(raw_body[own[0][0]:own[0][1]])
+            args = []
+            for start, stop in own[1:]:
+                inner = re.match(r"\s*&?\s*(?:vec\s*!\s*)?\[", body[start:stop])
+                if inner:
+                    open_bracket = start + inner.end() - 1
+                    args.extend(split_top_level(body, open_bracket + 1, find_matching_paren(body, open_bracket), raw_body))
+                else:
+                    args.append((start, stop))
+            args.extend(chain_args(close + 1))
+            owner = re.search(r"\blet\s+(?:mut\s+)?(" + ident + r")\s*(?::[^=;]*)?=\s*$", body[:sink.start()])
+            if owner:
+                for use in re.finditer(r"(?<![A-Za-z0-9_.])" + re.escape(owner.group(1)) + r"(?=\s*\.\s*args?\s*\()", body[close:]):
+                    args.extend(chain_args(close + use.end()))
+            hits = []
+            base = os.path.basename(program) if program else ""
+            if base in shell_programs:
+                for idx, (start, stop) in enumerate(args[:-1]):
+                    if literal_value(raw_body[start:stop]) in shell_flags:
+                        script = args[idx + 1]
+                        if interpolated(*script) or untrusted(*script):
+                            hits.append(("shell", script[0]))
+                        break
+            else:
+                for start, stop in args:
+                    if interpolated(start, stop) and untrusted(start, stop):
+                        hits.append(("argv", start))
+            for kind, offset in hits:
+                if kind != mode:
+                    continue
+                line = line_number(masked, brace + offset)
+                key = (str(path), line, kind)
+                if key in seen:
+                    continue
+                seen.add(key)
+                code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+                if "ubs:ignore" in code:
+                    continue
+                print(f"{path}:{line}:{code}")
+PY
+}
+
+count_command_injection_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_command_injection_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_command_injection_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_command_injection_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_command_injection() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_command_injection_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_sql_concat_candidates() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def find_matching_paren(text: str, open_index: int) -> int:
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch in "([":
+            depth += 1
+        elif ch in ")]":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def split_top_level(text: str, start: int, end: int, raw: str = ""):
+    spans = []
+    depth = 0
+    begin = start
+    for idx in range(start, end):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}" and depth > 0:
+            depth -= 1
+        elif ch == "," and depth == 0:
+            spans.append((begin, idx))
+            begin = idx + 1
+    spans.append((begin, end))
+    raw = raw or text
+    return [(a, b) for a, b in spans if raw[a:b].strip()]
+
+
+ident = r"[A-Za-z_][A-Za-z0-9_]*"
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+path_sink = re.compile(
+    r"(?<![A-Za-z0-9_:.])(?:sqlx\s*::\s*)?(?:query|query_as|query_scalar|query_with|query_as_with|query_scalar_with)"
+    r"\s*(?:::\s*<[^;{]*?>\s*)?\(|(?<![A-Za-z0-9_:.])(?:diesel\s*::\s*)?sql_query\s*\("
+)
+# rusqlite/postgres take the SQL text as the first argument of these methods.
+method_sink = re.compile(
+    r"\.\s*(?:execute|execute_batch|batch_execute|simple_query|prepare|prepare_cached|query|query_one|query_opt|query_row|query_raw)\s*\("
+)
+sql_keyword = re.compile(r"\b(?:SELECT|INSERT\s+INTO|UPDATE|DELETE\s+FROM|WHERE|VALUES|ORDER\s+BY|CREATE\s+TABLE|DROP\s+TABLE)\b", re.IGNORECASE)
+placeholder = re.compile(r"(?<!\{)\{([^{}]*)\}")
+const_like = re.compile(r"^\s*&?\s*(?:Self\s*::\s*|[A-Za-z_][A-Za-z0-9_]*\s*::\s*)*[A-Z][A-Z0-9_]*\s*$")
+seen = set()
+
+
+def strip_adapters(expr: str) -> str:
+    expr = expr.strip()
+    expr = re.sub(r"^&\s*\*?\s*", "", expr)
+    return re.sub(r"\s*\.\s*(?:as_str|as_ref|to_string|to_owned|clone)\s*\(\s*\)$", "", expr).strip()
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if not sql_keyword.search(text):
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    uses_sqlx = "sqlx" in masked
+    uses_diesel = "diesel" in masked
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        raw_body = text[brace:end + 1]
+
+        def literal_is_sql(start: int, stop: int) -> bool:
+            return any(sql_keyword.search(lit.group(0)) for lit in re.finditer(r'"(?:[^"\\]|\\.)*"', raw_body[start:stop]))
+
+        def dynamic_format(start: int, stop: int) -> bool:
+            """format!(SQL, ..) splicing anything other than constants."""
+            fmt = re.search(r"\bformat\s*!\s*\(", body[start:stop])
+            if not fmt:
+                return False
+            open_paren = start + fmt.end() - 1
+            close = find_matching_paren(body, open_paren)
+            if close < 0:
+                return False
+            parts = split_top_level(body, open_paren + 1, close, raw_body)
+            if not parts or not literal_is_sql(*parts[0]):
+                return False
+            template = raw_body[parts[0][0]:parts[0][1]]
+            spliced = [raw_body[a:b] for a, b in parts[1:] if "=" not in body[a:b] or "==" in body[a:b]]
+            spliced += [raw_body[a:b].partition("=")[2] for a, b in parts[1:] if "=" in body[a:b] and "==" not in body[a:b]]
+            for holder in placeholder.finditer(template):
+                name = holder.group(1).partition(":")[0].strip()
+                if re.fullmatch(ident, name):
+                    spliced.append(name)
+            return any(not const_like.match(expr) and not re.fullmatch(r'\s*"(?:[^"\\]|\\.)*"\s*', expr) for expr in spliced)
+
+        def concatenated(start: int, stop: int) -> bool:
+            expr = body[start:stop]
+            if "+" not in expr or not literal_is_sql(start, stop):
+                return False
+            operands = re.split(r"\+", raw_body[start:stop])
+            return any(
+                not const_like.match(op) and not re.fullmatch(r'\s*&?\s*"(?:[^"\\]|\\.)*"\s*', op)
+                for op in operands
+                if op.strip()
+            )
+
+        def built_sql(start: int, stop: int, depth: int = 0) -> bool:
+            if dynamic_format(start, stop) or concatenated(start, stop):
+                return True
+            name = strip_adapters(body[start:stop])
+            if depth > 2 or not re.fullmatch(ident, name):
+                return False
+            binding = None
+            for stmt in re.finditer(r"\blet\s+(?:mut\s+)?" + re.escape(name) + r"\s*(?::[^=;]*)?=([^;]*);", body[:start]):
+                binding = stmt
+            if not binding:
+                return False
+            if built_sql(binding.start(1), binding.end(1), depth + 1):
+                return True
+            if not literal_is_sql(binding.start(1), binding.end(1)) and not re.search(r"\bString\s*::\s*(?:new|with_capacity)\s*\(", body[binding.start(1):binding.end(1)]):
+                return False
+            # SQL buffers grown with push_str/+= after the initial literal.
+            grow_re = re.compile(
+                r"(?<![A-Za-z0-9_.])" + re.escape(name) + r"\s*(?:\.\s*push_str\s*\(|\+=)|\bwrite(?:ln)?\s*!\s*\(\s*&?\s*mut\s+" + re.escape(name) + r"\b"
+            )
+            for grow in grow_re.finditer(body, binding.end(), start):
+                stmt_end = body.find(";", grow.end())
+                raw_piece = raw_body[grow.end():stmt_end if stmt_end > 0 else start]
+                if re.search(r"\bformat\s*!|\bwrite", raw_piece + grow.group(0)) and placeholder.search(raw_piece):
+                    return True
+                rest = re.sub(r'"(?:[^"\\]|\\.)*"', "", raw_piece)
+                rest = re.sub(r"\b(?:as_str|as_ref|to_string|to_owned|clone|format)\b", "", rest)
+                if re.search(r"\b[a-z_][A-Za-z0-9_]*\b", rest):
+                    return True
+            return False
+
+        candidates = []
+        for sink in path_sink.finditer(body):
+            text_at = sink.group(0)
+            if "sqlx" not in text_at and "diesel" not in text_at:
+                if text_at.lstrip().startswith("sql_query") and not uses_diesel:
+                    continue
+                if not text_at.lstrip().startswith("sql_query") and not uses_sqlx:
+                    continue
+            candidates.append(sink)
+        candidates.extend(method_sink.finditer(body))
+        for sink in candidates:
+            paren = sink.end() - 1
+            close = find_matching_paren(body, paren)
+            if close < 0:
+                continue
+            args = split_top_level(body, paren + 1, close, raw_body)
+            if not args or not built_sql(*args[0]):
+                continue
+            line = line_number(masked, brace + sink.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if 0 < line <= len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}")
+PY
+}
+
+# Lines the taint-tracking SQL rule already reports stay with that critical finding.
+rust_sql_concat_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  UBS_SQL_TAINTED="$(rust_sql_injection_matches | cut -d: -f1,2)" \
+    awk -F: 'BEGIN { n = split(ENVIRON["UBS_SQL_TAINTED"], keys, "\n"); for (i = 1; i <= n; i++) skip[keys[i]] = 1 }
+      !(($1 ":" $2) in skip)' < <(rust_sql_concat_candidates)
+}
+
+count_sql_concat_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_sql_concat_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_sql_concat_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_sql_concat_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_sql_concat() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_sql_concat_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_weak_crypto_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+weak_hash = re.compile(
+    r"\b(?:md5|md_5|sha1|sha1_smol)\s*::\s*[A-Za-z_]|\b(?:Md5|Sha1)\s*(?:::|>|,|\))"
+    r"|\bMessageDigest\s*::\s*(?:md5|sha1)\s*\(|\bSHA1_FOR_LEGACY_USE_ONLY\b|\bdigest\s*::\s*SHA1\b"
+)
+weak_cipher = re.compile(
+    r"\bdes\s*::\s*[A-Za-z_]|\b(?:Des|TdesEde2|TdesEde3|TdesEee2|TdesEee3|Rc4|Blowfish)\b\s*(?:::|<|>|,|\))"
+    r"|\brc4\s*::\s*[A-Za-z_]|\bCipher\s*::\s*(?:des|rc4|bf)_[a-z0-9_]*\s*\(|\bCipher\s*::\s*rc4\s*\("
+    r"|\becb\s*::\s*(?:Encryptor|Decryptor)\b|\bEcb\s*<|\bblock_modes\s*::\s*Ecb\b|\bCipher\s*::\s*[a-z0-9_]+_ecb\s*\("
+)
+fn_sig = re.compile(r"\bfn\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^>{]*>)?\s*\(")
+use_line = re.compile(r"^\s*(?:pub(?:\([^)]*\))?\s+)?use\s")
+sensitive_words = {
+    "password", "passwd", "pwd", "passphrase", "secret", "token", "signature", "sign", "signed", "signing",
+    "verify", "hmac", "mac", "auth", "authenticate", "session", "credential", "credentials", "apikey",
+    "otp", "salt", "login", "jwt", "cookie", "csrf", "nonce",
+}
+seen = set()
+
+
+def words(text: str):
+    found = set()
+    for name in re.findall(r"[A-Za-z_][A-Za-z0-9_]*", text):
+        spaced = re.sub(r"([a-z0-9])([A-Z])", r"\1_\2", name).lower()
+        found.update(part for part in spaced.split("_") if part)
+        found.add(name.lower().replace("_", ""))
+    return found
+
+
+def statement_bounds(text: str, pos: int, lo: int, hi: int):
+    start = max(text.rfind(";", lo, pos), text.rfind("{", lo, pos), text.rfind("}", lo, pos)) + 1
+    end = text.find(";", pos, hi)
+    return max(start, lo), end if end >= 0 else hi
+
+
+def enclosing_call(before: str) -> str:
+    depth = 0
+    for idx in range(len(before) - 1, -1, -1):
+        if before[idx] == ")":
+            depth += 1
+        elif before[idx] == "(":
+            if depth == 0:
+                callee = re.search(r"([A-Za-z_][A-Za-z0-9_]*)\s*!?\s*$", before[:idx])
+                return callee.group(1) if callee else ""
+            depth -= 1
+    return ""
+
+
+def sink_words(stmt: str, start: int, end: int):
+    """Words for whatever the value at stmt[start:end] feeds: binding, field, callee, comparison, or method args."""
+    before, after = stmt[:start], stmt[end:]
+    found = set()
+    method = re.match(r"\s*\.\s*([A-Za-z_][A-Za-z0-9_]*)\s*\(", after)
+    if method:
+        close = find_matching_paren(after, method.end() - 1)
+        found |= words(method.group(1) + " " + after[method.end():close if close > 0 else len(after)])
+    targets = (
+        re.search(r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=(?!=)[^;]*$", before)
+        or re.search(r"([A-Za-z_][A-Za-z0-9_.]*)\s*(?<![=!<>])=(?!=)[^;]*$", before),
+        re.search(r"([A-Za-z_][A-Za-z0-9_]*)\s*:\s*&?\s*$", before),
+        re.search(r"([A-Za-z_][A-Za-z0-9_.]*)\s*[=!]=\s*&?\s*$", before),
+        re.match(r"\s*[=!]=\s*&?\s*([A-Za-z_][A-Za-z0-9_.]*)", after),
+    )
+    for target in targets:
+        if target:
+            found |= words(target.group(1))
+    found |= words(enclosing_call(before))
+    return found
+
+
+def hash_feeds_secret(masked: str, hit: int, body_start: int, body_end: int, fn_name: str) -> bool:
+    """True when the digest's input or result reaches a password/signature/token identifier in the fn."""
+    stmt_start, stmt_end = statement_bounds(masked, hit, body_start, body_end)
+    stmt = masked[stmt_start:stmt_end]
+    local = hit - stmt_start
+    open_paren = stmt.find("(", local)
+    close_paren = find_matching_paren(stmt, open_paren) if open_paren >= 0 else -1
+    if close_paren < 0:
+        return False
+    if words(stmt[open_paren + 1:close_paren]) & sensitive_words:
+        return True
+    if sink_words(stmt, local, close_paren + 1) & sensitive_words:
+        return True
+    binding = re.match(r"\s*let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)", stmt)
+    tracked = [binding.group(1)] if binding else []
+    for name in tracked:
+        use_re = re.compile(r"(?<![A-Za-z0-9_.])" + re.escape(name) + r"\b")
+        for use in use_re.finditer(masked, stmt_end, body_end):
+            use_start, use_end = statement_bounds(masked, use.start(), body_start, body_end)
+            use_stmt = masked[use_start:use_end]
+            if sink_words(use_stmt, use.start() - use_start, use.end() - use_start) & sensitive_words:
+                return True
+            derived = re.match(r"\s*let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)", use_stmt)
+            if derived and derived.group(1) not in tracked and len(tracked) < 8:
+                tracked.append(derived.group(1))
+            returned = re.match(r"\s*return\b", use_stmt) or use_end >= body_end
+            if returned and words(fn_name) & sensitive_words:
+                return True
+    return False
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if not (weak_hash.search(masked) or weak_cipher.search(masked)):
+        continue
+    lines = text.splitlines()
+    masked_lines = masked.splitlines()
+    signatures = []
+    for sig in fn_sig.finditer(masked):
+        close = find_matching_paren(masked, sig.end() - 1)
+        brace = masked.find("{", close if close > 0 else sig.end())
+        end = find_matching_brace(masked, brace) if brace >= 0 else -1
+        if close > 0 and end > 0:
+            signatures.append((brace, end, masked[sig.start():close + 1], sig.group(1)))
+    for idx, code_line in enumerate(masked_lines):
+        if use_line.match(code_line):
+            continue
+        kind = "cipher" if weak_cipher.search(code_line) else "hash" if weak_hash.search(code_line) else ""
+        if not kind:
+            continue
+        offset = sum(len(item) + 1 for item in masked_lines[:idx])
+        enclosing = [item for item in signatures if item[0] <= offset <= item[1]]
+        if kind == "hash":
+            hit = offset + weak_hash.search(code_line).start()
+            body_start, body_end, _, fn_name = enclosing[-1] if enclosing else (0, len(masked), "", "")
+            sensitive = hash_feeds_secret(masked, hit, body_start, body_end, fn_name)
+        else:
+            context = " ".join(masked_lines[max(0, idx - 2):idx + 1])
+            for _, _, signature, _ in enclosing:
+                context += " " + signature
+            sensitive = bool(words(context) & sensitive_words)
+        label = "sensitive" if sensitive else kind
+        if label != mode:
+            continue
+        line = idx + 1
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[idx].strip() if idx < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_weak_crypto_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_weak_crypto_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_weak_crypto_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_weak_crypto_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_weak_crypto() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_weak_crypto_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_tls_verifier_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+impl_re = re.compile(
+    r"\bimpl\s*(?:<[^>{]*>\s*)?(?:[A-Za-z_][A-Za-z0-9_]*\s*::\s*)*"
+    r"(?P<trait>ServerCertVerifier|ClientCertVerifier)\s+for\s+[^{;]+\{"
+)
+verify_fn_re = re.compile(
+    r"\bfn\s+(?P<name>verify_server_cert|verify_client_cert|verify_tls12_signature|verify_tls13_signature)\s*(?:<[^>{]*>)?\s*\("
+)
+callback_re = re.compile(
+    r"\.set_verify_callback\s*\(\s*[^,]+,\s*(?:move\s+)?\|[^|]*\|\s*(?:\{\s*)?true\s*\}?\s*\)"
+)
+rejecting_re = re.compile(r"\bErr\b|\?|\bmap_err\b|\bpanic!|\bunimplemented!|\btodo!")
+seen = set()
+
+
+def always_ok(body: str) -> bool:
+    inner = body.strip()
+    if rejecting_re.search(inner):
+        return False
+    statements = [part.strip() for part in inner.split(";") if part.strip()]
+    if not statements:
+        return False
+    tail = statements[-1]
+    if tail.startswith("return "):
+        tail = tail[len("return "):].strip()
+    return tail.startswith("Ok(") or tail.startswith("Ok (")
+
+
+def report(path, text, lines, offset):
+    line = line_number(text, offset)
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+    if "ubs:ignore" in code:
+        return
+    print(f"{path}:{line}:{code}")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if "CertVerifier" not in masked and "set_verify_callback" not in masked:
+        continue
+    lines = text.splitlines()
+    for item in impl_re.finditer(masked):
+        impl_open = item.end() - 1
+        impl_close = find_matching_brace(masked, impl_open)
+        if impl_close < 0:
+            continue
+        impl_body = masked[impl_open:impl_close + 1]
+        for verify in verify_fn_re.finditer(impl_body):
+            brace = impl_body.find("{", verify.end())
+            end = find_matching_brace(impl_body, brace) if brace >= 0 else -1
+            if end < 0:
+                continue
+            if always_ok(impl_body[brace + 1:end]):
+                report(path, text, lines, impl_open + verify.start())
+    for callback in callback_re.finditer(masked):
+        report(path, text, lines, callback.start())
+PY
+}
+
+count_tls_verifier_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_tls_verifier_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_tls_verifier_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_tls_verifier_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_tls_verifier() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_tls_verifier_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_untrusted_deserialize_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^{;]*?>)?\s*\(")
+ident_re = re.compile(r"[A-Za-z_][A-Za-z0-9_]*")
+network_type_re = re.compile(
+    r"\b(?:TcpStream|UnixStream|TlsStream|SslStream|WebSocket|Incoming|hyper::body::Body|reqwest::blocking::Response)\b"
+)
+network_source_re = re.compile(
+    r"\b(?:TcpStream|UnixStream)::connect\s*\(|\.accept\s*\(\s*\)|\.incoming\s*\(\s*\)"
+    r"|\breqwest::blocking::get\s*\(|\.into_body\s*\(\s*\)|\.into_reader\s*\(\s*\)|\b(?:ureq|attohttpc)::get\s*\("
+)
+param_re = re.compile(r"(?:mut\s+)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*:\s*(?P<ty>[^,]+)")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?(?:\(\s*)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)[^=;]*=\s*(?P<rhs>[^;]+);")
+for_re = re.compile(r"\bfor\s+(?:mut\s+)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s+in\s+(?P<rhs>[^{]+)\{")
+read_all_re = re.compile(
+    r"\b(?P<src>[A-Za-z_][A-Za-z0-9_]*)\s*\.\s*read_to_(?:end|string)\s*\(\s*&mut\s+(?P<buf>[A-Za-z_][A-Za-z0-9_]*)"
+)
+sink_re = re.compile(
+    r"\b(?:serde_json::(?:from_reader|from_slice|from_str)"
+    r"|bincode::(?:deserialize_from|deserialize|decode_from_std_read|decode_from_slice|serde::decode_from_std_read)"
+    r"|rmp_serde::(?:from_read|from_slice)|serde_cbor::(?:from_reader|from_slice)"
+    r"|ciborium::(?:de::)?from_reader|postcard::from_bytes|serde_yaml::from_reader)\s*\("
+)
+bounded_re = re.compile(r"\.take\s*\(|\bwith_limit\s*(?:::\s*<[^>]*>\s*)?\(|\bLimited::new\s*\(|\bwith_size_limit\s*\(")
+derive_re = re.compile(r"#\s*\[\s*derive\s*\([^)]*\bDeserialize\b[^)]*\)\s*\]")
+struct_re = re.compile(r"\bstruct\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;]*>)?\s*\{")
+attr_re = re.compile(r"#\s*\[[^\]]*\]")
+field_re = re.compile(r"^\s*(?:pub(?:\s*\([^)]*\))?\s+)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*:", re.MULTILINE)
+config_name_re = re.compile(r"(?:Config|Settings|Policy|Options|Conf|Cfg|Manifest)$")
+sensitive_words = {
+    "auth", "authn", "authz", "token", "secret", "password", "passwd", "tls", "ssl", "cert", "certs",
+    "certificate", "key", "keys", "permission", "permissions", "role", "roles", "admin", "cors", "acl",
+    "allow", "allowed", "deny", "jwt", "oauth", "session", "csrf", "security", "trusted", "verify",
+    "signing", "credentials", "credential", "issuer", "audience", "scopes", "mfa", "sandbox",
+}
+seen = set()
+
+
+def words(name: str):
+    spaced = re.sub(r"([a-z0-9])([A-Z])", r"\1_\2", name).lower()
+    return {part for part in spaced.split("_") if part}
+
+
+def report(path, text, lines, offset):
+    line = line_number(text, offset)
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+    if "ubs:ignore" in code:
+        return
+    print(f"{path}:{line}:{code}")
+
+
+def scan_streams(path, text, masked, lines):
+    for fn in fn_re.finditer(masked):
+        close = find_matching_paren(masked, fn.end() - 1)
+        if close < 0:
+            continue
+        brace = masked.find("{", close)
+        semi = masked.find(";", close)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        params = masked[fn.end():close]
+        body = masked[brace:end + 1]
+        network = set()
+        for param in param_re.finditer(params):
+            if network_type_re.search(param.group("ty")):
+                network.add(param.group("name"))
+        changed = True
+        while changed:
+            changed = False
+            for binding in list(let_re.finditer(body)) + list(for_re.finditer(body)):
+                name, rhs = binding.group("name"), binding.group("rhs")
+                if name in network or bounded_re.search(rhs):
+                    continue
+                if network_source_re.search(rhs) or set(ident_re.findall(rhs)) & network:
+                    network.add(name)
+                    changed = True
+            for read in read_all_re.finditer(body):
+                if read.group("src") in network and read.group("buf") not in network:
+                    network.add(read.group("buf"))
+                    changed = True
+        if not network:
+            continue
+        for sink in sink_re.finditer(body):
+            arg_close = find_matching_paren(body, sink.end() - 1)
+            if arg_close < 0:
+                continue
+            args = body[sink.end():arg_close]
+            if bounded_re.search(args):
+                continue
+            if set(ident_re.findall(args)) & network or network_source_re.search(args):
+                report(path, text, lines, brace + sink.start())
+
+
+def scan_structs(path, text, masked, lines):
+    for derive in derive_re.finditer(masked):
+        struct = struct_re.search(masked, derive.end())
+        if not struct:
+            continue
+        between = masked[derive.end():struct.start()]
+        if re.search(r"[;{}]", between):
+            continue
+        name = struct.group("name")
+        if not config_name_re.search(name):
+            continue
+        attrs = masked[derive.start():struct.start()]
+        raw_attrs = text[derive.start():struct.start()]
+        if "deny_unknown_fields" in raw_attrs or "deny_unknown_fields" in attrs:
+            continue
+        close = find_matching_brace(masked, struct.end() - 1)
+        if close < 0:
+            continue
+        fields_text = masked[struct.end():close]
+        if re.search(r"#\s*\[\s*serde\s*\([^)]*\bflatten\b", fields_text):
+            continue
+        field_words = set()
+        for field in field_re.finditer(attr_re.sub("", fields_text)):
+            field_words |= words(field.group("name"))
+        if (words(name) | field_words) & sensitive_words:
+            report(path, text, lines, struct.start())
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    if mode == "stream" and sink_re.search(masked):
+        scan_streams(path, text, masked, lines)
+    elif mode == "fields" and "Deserialize" in masked:
+        scan_structs(path, text, masked, lines)
+PY
+}
+
+count_untrusted_deserialize_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_untrusted_deserialize_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_untrusted_deserialize_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_untrusted_deserialize_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_untrusted_deserialize() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_untrusted_deserialize_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_network_timeout_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^{;]*?>)?\s*\(")
+http_re = re.compile(
+    r"\b(?:reqwest::(?:blocking::)?)?Client::(?:new|builder)\s*\(\s*\)"
+    r"|\b(?:reqwest::(?:blocking::)?)?ClientBuilder::new\s*\(\s*\)"
+    r"|\breqwest::(?:blocking::)?get\s*\("
+)
+tcp_re = re.compile(r"\b(?P<prefix>(?:[A-Za-z_][A-Za-z0-9_]*::)*)TcpStream::connect(?:_timeout)?\s*\(")
+http_timeout_re = re.compile(r"(?<![A-Za-z0-9_])(?:timeout|read_timeout)\s*\(")
+tcp_timeout_re = re.compile(r"\.\s*set_read_timeout\s*\(\s*Some\b|\.\s*set_read_timeout\s*\([A-Za-z_]")
+async_timeout_re = re.compile(r"(?<![A-Za-z0-9_])timeout\s*\(")
+let_head_re = re.compile(r"\blet\s+(?:mut\s+)?[A-Za-z_][A-Za-z0-9_]*[^=;]*=\s*$")
+seen = set()
+
+
+def report(path, text, lines, offset):
+    line = line_number(text, offset)
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+    if "ubs:ignore" in code:
+        return
+    print(f"{path}:{line}:{code}")
+
+
+def fn_bodies(masked: str):
+    for fn in fn_re.finditer(masked):
+        close = find_matching_paren(masked, fn.end() - 1)
+        if close < 0:
+            continue
+        brace = masked.find("{", close)
+        semi = masked.find(";", close)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end > 0:
+            yield brace, end
+
+
+def innermost(bodies, offset):
+    best = None
+    for brace, end in bodies:
+        if brace <= offset <= end and (best is None or brace > best[0]):
+            best = (brace, end)
+    return best
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if mode == "http" and "reqwest" not in masked:
+        continue
+    if mode == "tcp" and "TcpStream" not in masked:
+        continue
+    lines = text.splitlines()
+    bodies = list(fn_bodies(masked))
+    if mode == "http":
+        for call in http_re.finditer(masked):
+            scope = innermost(bodies, call.start())
+            if scope is None:
+                continue
+            if http_timeout_re.search(masked[scope[0]:scope[1] + 1]):
+                continue
+            report(path, text, lines, call.start())
+    else:
+        for call in tcp_re.finditer(masked):
+            scope = innermost(bodies, call.start())
+            if scope is None:
+                continue
+            body = masked[scope[0]:scope[1] + 1]
+            line_start = masked.rfind("\n", 0, call.start()) + 1
+            if not let_head_re.search(masked[line_start:call.start()]):
+                continue
+            close = find_matching_paren(masked, call.end() - 1)
+            stmt_end = masked.find(";", close if close > 0 else call.end())
+            statement = masked[call.start():stmt_end if stmt_end > 0 else call.end()]
+            if "tokio" in call.group("prefix") or ".await" in statement:
+                if async_timeout_re.search(body):
+                    continue
+            elif tcp_timeout_re.search(body):
+                continue
+            report(path, text, lines, call.start())
+PY
+}
+
+count_network_timeout_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_network_timeout_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_network_timeout_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_network_timeout_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_network_timeout() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_network_timeout_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_discarded_result_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+let_discard_re = re.compile(r"(?:^|(?<=[;{}]))\s*let\s+_\s*(?::[^=;]+)?=\s*", re.MULTILINE)
+fn_sig_re = re.compile(
+    r"\bfn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;]*?>)?\s*\([^{;]*?\)\s*->\s*(?P<ret>[^{;]+?)\s*(?:where\b[^{]*)?\{"
+)
+drop_fn_re = re.compile(r"\bfn\s+drop\s*\(\s*&\s*mut\s+self\s*\)\s*\{")
+fs_fallible = {
+    "read", "read_to_string", "read_dir", "write", "copy", "rename", "remove_file", "remove_dir",
+    "remove_dir_all", "create_dir", "create_dir_all", "metadata", "symlink_metadata", "set_permissions",
+    "hard_link", "canonicalize", "read_link",
+}
+type_fallible = {
+    ("File", "open"), ("File", "create"), ("File", "create_new"), ("TcpStream", "connect"),
+    ("TcpListener", "bind"), ("UdpSocket", "bind"), ("Regex", "new"), ("OpenOptions", "open"),
+}
+# Guard-returning calls (lock, borrow, enter) belong to the `let _ =` guard check, not this one.
+method_fallible = {
+    "write_all", "flush", "read_exact", "read_to_end", "read_to_string", "sync_all", "sync_data",
+    "set_len", "set_read_timeout", "set_write_timeout", "set_nonblocking", "shutdown", "status",
+    "output", "kill", "wait", "parse", "try_into", "try_from", "join", "persist", "commit",
+    "execute", "remove_file", "create_dir_all", "send", "try_send",
+}
+infallible_tail = re.compile(
+    r"(?:\?|\.\s*(?:unwrap|expect|ok|err|unwrap_or|unwrap_or_default|unwrap_or_else|is_ok|is_err|map_err)\s*\([^;]*\))\s*$"
+)
+# A panicked worker or poisoned lock is handled once the caller recovers the poisoned state afterwards.
+poison_recovery_re = re.compile(
+    r"\bPoisonError\s*::\s*into_inner\b|\.\s*(?:is_poisoned|clear_poison)\s*\(|\bunwrap_or_else\s*\(\s*\|\s*(\w+)\s*\|\s*\1\s*\.\s*into_inner\s*\("
+)
+fn_open_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*[^;{]*\{")
+seen = set()
+
+
+def matching_open(text: str, close_index: int) -> int:
+    depth = 0
+    for idx in range(close_index, -1, -1):
+        ch = text[idx]
+        if ch == ")":
+            depth += 1
+        elif ch == "(":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def statement_end(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            if depth == 0:
+                return -1
+            depth -= 1
+        elif ch == ";" and depth == 0:
+            return idx
+    return -1
+
+
+def final_call(expr: str):
+    """Return (qualifier, separator, name) for the outermost trailing call of expr."""
+    if not expr.endswith(")"):
+        return None
+    open_idx = matching_open(expr, len(expr) - 1)
+    if open_idx <= 0:
+        return None
+    head = expr[:open_idx].rstrip()
+    head = re.sub(r"::\s*<[^<>]*(?:<[^<>]*>[^<>]*)*>\s*$", "", head)
+    match = re.search(
+        r"(?:(?P<qual>[A-Za-z_][A-Za-z0-9_]*)?\s*(?P<sep>\.|::)\s*)?(?<![A-Za-z0-9_])(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?P<bang>!)?$",
+        head,
+    )
+    if not match or match.group("bang"):
+        return None
+    return match.group("qual") or "", match.group("sep") or "", match.group("name")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if "let _" not in masked:
+        continue
+    lines = text.splitlines()
+    result_fns = set()
+    other_fns = set()
+    for sig in fn_sig_re.finditer(masked):
+        (result_fns if re.search(r"\bResult\b", sig.group("ret")) else other_fns).add(sig.group("name"))
+    result_fns -= other_fns
+    drop_spans = []
+    for drop in drop_fn_re.finditer(masked):
+        end = find_matching_brace(masked, drop.end() - 1)
+        if end > 0:
+            drop_spans.append((drop.start(), end))
+    for stmt in let_discard_re.finditer(masked):
+        start = stmt.end()
+        end = statement_end(masked, start)
+        if end < 0:
+            continue
+        if any(lo <= start <= hi for lo, hi in drop_spans):
+            continue
+        expr = re.sub(r"\s+", " ", masked[start:end]).strip()
+        if infallible_tail.search(expr):
+            continue
+        awaited = False
+        if re.search(r"\.\s*await$", expr):
+            expr = re.sub(r"\s*\.\s*await$", "", expr)
+            awaited = True
+        call = final_call(expr)
+        if call is None:
+            continue
+        qual, sep, name = call
+        fallible = False
+        if name in result_fns and (sep != "::" or qual in {"", "self", "Self", "crate", "super"} or qual[:1].isupper()):
+            fallible = True
+        elif sep == "::" and qual == "fs" and name in fs_fallible:
+            fallible = True
+        elif sep == "::" and (qual, name) in type_fallible:
+            fallible = True
+        elif sep == "." and name in method_fallible and not awaited:
+            fallible = True
+        if not fallible:
+            continue
+        if sep == "." and name == "join":
+            body_end = len(masked)
+            for fn_open in fn_open_re.finditer(masked, 0, start):
+                close = find_matching_brace(masked, fn_open.end() - 1)
+                if close > start:
+                    body_end = close
+            if poison_recovery_re.search(masked, end, body_end):
+                continue
+        line = line_number(masked, start)
+        idx = line - 1
+        code = lines[idx].strip() if idx < len(lines) else ""
+        previous = lines[idx - 1].strip() if idx > 0 else ""
+        if "ubs:ignore" in code or "//" in code or previous.startswith("//"):
+            continue
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line}:{code}")
+PY
+}
+
+count_discarded_result_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_discarded_result_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_discarded_result_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_discarded_result_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_discarded_result() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_discarded_result_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_ok_discard_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+stmt_start_re = re.compile(r"(?:^|(?<=[;{}]))\s*(?=[A-Za-z_(&*])", re.MULTILINE)
+iflet_re = re.compile(r"\b(?:if|while)\s+let\s+Some\s*\(")
+expected_errors = re.compile(
+    r"\b(?:env::var|var|var_os|try_recv|recv|recv_timeout|try_lock|try_read|try_write|try_borrow|try_borrow_mut|peek)\s*\([^()]*\)\s*\.\s*ok\s*\(\s*\)\s*$"
+)
+seen = set()
+
+
+def statement_end(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            if depth == 0:
+                return -1
+            depth -= 1
+        elif ch == ";" and depth == 0:
+            return idx
+    return -1
+
+
+def brace_at_depth(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([":
+            depth += 1
+        elif ch in ")]":
+            depth -= 1
+        elif ch == "{" and depth == 0:
+            return idx
+        elif ch == ";" and depth == 0:
+            return -1
+    return -1
+
+
+def report(path, text, lines, offset):
+    line = line_number(text, offset)
+    idx = line - 1
+    code = lines[idx].strip() if idx < len(lines) else ""
+    previous = lines[idx - 1].strip() if idx > 0 else ""
+    # An explanatory comment on the line (or just above) marks the discard as deliberate.
+    if "ubs:ignore" in code or "//" in code or previous.startswith("//"):
+        return
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    print(f"{path}:{line}:{code}")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if not re.search(r"\.\s*ok\s*\(\s*\)", masked):
+        continue
+    lines = text.splitlines()
+    if mode == "statement":
+        for stmt in stmt_start_re.finditer(masked):
+            start = stmt.end()
+            if re.match(r"(?:let|return|break|if|while|match|for|fn|use|pub|mod|impl|struct|enum|const|static|type|loop|else)\b", masked[start:]):
+                continue
+            end = statement_end(masked, start)
+            if end < 0:
+                continue
+            expr = masked[start:end].rstrip()
+            if "\n\n" in expr or not re.search(r"\.\s*ok\s*\(\s*\)$", expr):
+                continue
+            if re.search(r"(?<![=!<>])=(?!=)", expr):
+                continue
+            report(path, text, lines, start)
+    else:
+        for stmt in iflet_re.finditer(masked):
+            eq = masked.find("=", stmt.end())
+            if eq < 0:
+                continue
+            brace = brace_at_depth(masked, eq + 1)
+            if brace < 0:
+                continue
+            expr = masked[eq + 1:brace].strip()
+            if not re.search(r"\.\s*ok\s*\(\s*\)$", expr) or expected_errors.search(expr):
+                continue
+            report(path, text, lines, stmt.start())
+PY
+}
+
+count_ok_discard_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_ok_discard_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_ok_discard_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_ok_discard_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_ok_discard() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_ok_discard_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_unused_must_use_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+stmt_start_re = re.compile(r"(?:^|(?<=[;{}]))\s*(?=[A-Za-z_(&*$])", re.MULTILINE)
+keyword_re = re.compile(
+    r"(?:let|return|break|continue|if|while|match|for|fn|use|pub|mod|impl|struct|enum|const|static|type|loop|else|unsafe|async|where|trait|extern|crate|macro_rules)\b"
+)
+must_use_fn_re = re.compile(
+    r"#\s*\[\s*must_use\b[^\]]*\]\s*(?:#\s*\[[^\]]*\]\s*)*(?:pub(?:\s*\([^)]*\))?\s+)?(?:const\s+)?(?:async\s+)?(?:unsafe\s+)?fn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)"
+)
+async_fn_re = re.compile(r"\basync\s+(?:unsafe\s+)?fn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)")
+result_fn_re = re.compile(
+    r"\bfn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;]*?>)?\s*\([^{;]*?\)\s*->\s*(?P<ret>[^{;]+?)\s*(?:where\b[^{]*)?\{"
+)
+iterator_adapters = {
+    "map", "filter", "filter_map", "flat_map", "flatten", "enumerate", "zip", "chain", "rev", "skip",
+    "take", "skip_while", "take_while", "peekable", "cloned", "copied", "inspect", "step_by", "map_while",
+    "scan", "fuse", "cycle", "iter", "iter_mut", "into_iter", "chars", "char_indices", "bytes", "lines",
+    "split_whitespace", "windows", "chunks", "keys", "values",
+}
+pure_methods = {
+    "trim", "trim_start", "trim_end", "to_uppercase", "to_lowercase", "to_ascii_uppercase",
+    "to_ascii_lowercase", "to_string", "to_owned", "to_vec", "clone", "replace", "replacen", "repeat",
+    "abs", "pow", "len", "is_empty", "is_some", "is_none", "is_ok", "is_err", "contains", "starts_with",
+    "ends_with", "clamp", "concat", "min", "max",
+}
+arith_prefixes = ("wrapping_", "checked_", "saturating_", "overflowing_")
+guard_methods = {"lock", "read", "write", "borrow", "borrow_mut", "try_lock"}
+fs_fallible = {
+    "read", "read_to_string", "read_dir", "write", "copy", "rename", "remove_file", "remove_dir",
+    "remove_dir_all", "create_dir", "create_dir_all", "metadata", "set_permissions", "canonicalize",
+}
+type_fallible = {("File", "open"), ("File", "create"), ("TcpStream", "connect"), ("TcpListener", "bind")}
+seen = set()
+
+
+def matching_open(text: str, close_index: int) -> int:
+    depth = 0
+    for idx in range(close_index, -1, -1):
+        ch = text[idx]
+        if ch == ")":
+            depth += 1
+        elif ch == "(":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def statement_end(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            if depth == 0:
+                return -1
+            depth -= 1
+        elif ch == ";" and depth == 0:
+            return idx
+    return -1
+
+
+def final_call(expr: str):
+    """Return (qualifier, separator, name, args) for the outermost trailing call of expr."""
+    if not expr.endswith(")"):
+        return None
+    open_idx = matching_open(expr, len(expr) - 1)
+    if open_idx <= 0:
+        return None
+    args = expr[open_idx + 1:-1].strip()
+    head = expr[:open_idx].rstrip()
+    head = re.sub(r"::\s*<[^<>]*(?:<[^<>]*>[^<>]*)*>\s*$", "", head)
+    match = re.search(
+        r"(?:(?P<qual>[A-Za-z_][A-Za-z0-9_]*)?\s*(?P<sep>\.|::)\s*)?(?<![A-Za-z0-9_])(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?P<bang>!)?$",
+        head,
+    )
+    if not match or match.group("bang"):
+        return None
+    return match.group("qual") or "", match.group("sep") or "", match.group("name"), args
+
+
+def classify(expr: str, must_use_fns, result_fns):
+    guard_tail = re.search(
+        r"\.\s*(?P<guard>lock|read|write|borrow_mut|borrow)\s*\(\s*\)\s*(?:\.\s*(?:unwrap|expect)\s*\([^()]*\))?$", expr
+    )
+    if guard_tail:
+        return "guard dropped immediately"
+    call = final_call(expr)
+    if call is None:
+        return None
+    qual, sep, name, args = call
+    if name in must_use_fns:
+        return "#[must_use] fn " + name
+    if sep == ".":
+        if name in iterator_adapters:
+            return "lazy iterator never consumed"
+        if name in pure_methods or name.startswith(arith_prefixes):
+            return "pure method result unused"
+        if name == "join" and not args:
+            return "Result"
+    if name in result_fns and (sep != "::" or qual in {"", "self", "Self", "crate", "super"} or qual[:1].isupper()):
+        return "Result"
+    if sep == "::" and (qual == "fs" and name in fs_fallible or (qual, name) in type_fallible):
+        return "Result"
+    return None
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    must_use_fns = {m.group("name") for m in must_use_fn_re.finditer(masked)}
+    result_fns = set()
+    other_fns = set()
+    for sig in result_fn_re.finditer(masked):
+        (result_fns if re.search(r"\bResult\b", sig.group("ret")) else other_fns).add(sig.group("name"))
+    result_fns -= other_fns
+    async_fns = {m.group("name") for m in async_fn_re.finditer(masked)}
+    for stmt in stmt_start_re.finditer(masked):
+        start = stmt.end()
+        if keyword_re.match(masked, start):
+            continue
+        end = statement_end(masked, start)
+        if end < 0:
+            continue
+        expr = re.sub(r"\s+", " ", masked[start:end]).strip()
+        if not expr or re.search(r"(?<![=!<>])=(?![=>])", expr) or expr.endswith("?"):
+            continue
+        awaited = re.search(r"\.\s*await$", expr) is not None
+        expr = re.sub(r"\s*\.\s*await$", "", expr)
+        kind = classify(expr, must_use_fns, result_fns)
+        if kind is None:
+            continue
+        # An un-awaited async call yields a Future, not its output; the never-awaited check reports it.
+        call = final_call(expr)
+        if not awaited and call and call[2] in async_fns:
+            continue
+        line = line_number(masked, start)
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line}:{code}  [{kind}]")
+PY
+}
+
+count_unused_must_use_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_unused_must_use_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_unused_must_use_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_unused_must_use_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_unused_must_use() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_unused_must_use_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_error_context_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+map_err_re = re.compile(r"\.\s*map_err\s*\(")
+closure_re = re.compile(r"^\s*(?:move\s+)?\|\s*(?P<param>[A-Za-z_][A-Za-z0-9_]*)\s*(?::[^|]*)?\|\s*(?P<body>.*)$", re.DOTALL)
+derive_error_re = re.compile(r"#\s*\[\s*derive\s*\([^)]*\b(?:thiserror::)?Error\b[^)]*\)\s*\]")
+enum_re = re.compile(r"\benum\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^{;]*>)?\s*\{")
+attr_re = re.compile(r"#\s*\[[^\]]*\]")
+# Receivers whose error values carry nothing worth chaining (missing env var, poisoned lock, elapsed timer).
+opaque_receiver_re = re.compile(
+    r"(?:\benv::var(?:_os)?\s*\([^()]*\)|\.\s*(?:lock|read|write|try_lock|try_into|recv|try_recv)\s*\(\s*\)"
+    r"|\bHeaderValue::from_(?:str|bytes)\s*\([^()]*\)|\btry_from\s*\([^()]*\)|\btimeout\s*\([\s\S]*\.\s*await)\s*$"
+)
+error_type_re = re.compile(r"(?:^|::|<|\s)(?:[A-Z][A-Za-z0-9]*)?Error\s*(?:>|$)|\bdyn\s+(?:std::error::)?Error\b|\banyhow::Error\b")
+seen = set()
+
+
+def find_matching(text: str, open_index: int, pair="()") -> int:
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch == pair[0]:
+            depth += 1
+        elif ch == pair[1]:
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def split_top(text: str, base: int):
+    """Split on top-level commas, yielding (offset, chunk) pairs."""
+    depth = 0
+    start = 0
+    for idx, ch in enumerate(text):
+        if ch in "([{<":
+            depth += 1
+        elif ch in ")]}>":
+            depth -= 1
+        elif ch == "," and depth == 0:
+            yield base + start, text[start:idx]
+            start = idx + 1
+    if text[start:].strip():
+        yield base + start, text[start:]
+
+
+def report(path, text, lines, offset):
+    line = line_number(text, offset)
+    key = (str(path), line)
+    if key in seen:
+        return
+    seen.add(key)
+    code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+    if "ubs:ignore" in code:
+        return
+    print(f"{path}:{line}:{code}")
+
+
+def classify_map_err(masked_arg: str, raw_arg: str):
+    closure = closure_re.match(masked_arg)
+    if not closure:
+        return None
+    param = closure.group("param")
+    body = masked_arg[closure.start("body"):].strip()
+    raw_body = raw_arg[closure.start("body"):].strip()
+    mentions = re.search(r"(?<![A-Za-z0-9_])" + re.escape(param) + r"(?![A-Za-z0-9_])", raw_body)
+    if param == "_" or param.startswith("_") or not mentions:
+        # A bare message for a `&'static str` error type has nowhere to keep a source.
+        if re.fullmatch(r'"(?:[^"\\]|\\.)*"', raw_arg[masked_arg.index("|", closure.end("param")) + 1:].strip()):
+            return None
+        return "drop"
+    inner = body[1:-1].strip() if body.startswith("{") and body.endswith("}") else body
+    if re.fullmatch(re.escape(param) + r"\s*\.\s*to_string\s*\(\s*\)(?:\s*\.\s*into\s*\(\s*\))?", inner):
+        return "stringify"
+    if re.match(r"(?:format!|[A-Za-z_][A-Za-z0-9_:]*\s*::\s*(?:from|new)\s*\(\s*format!)", inner) and "source" not in inner:
+        return "stringify"
+    return None
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    if mode in {"drop", "stringify"}:
+        for call in map_err_re.finditer(masked):
+            open_idx = call.end() - 1
+            close_idx = find_matching(masked, open_idx)
+            if close_idx < 0:
+                continue
+            kind = classify_map_err(masked[open_idx + 1:close_idx], text[open_idx + 1:close_idx])
+            stmt_start = max(masked.rfind(";", 0, call.start()), masked.rfind("{", 0, call.start()), masked.rfind("}", 0, call.start()))
+            if kind == mode and not opaque_receiver_re.search(masked[stmt_start + 1:call.start()]):
+                report(path, text, lines, call.start())
+        continue
+    for derive in derive_error_re.finditer(masked):
+        enum = enum_re.search(masked, derive.end())
+        if not enum or re.search(r"[;{}]", masked[derive.end():enum.start()]):
+            continue
+        if "transparent" in text[derive.start():enum.start()]:
+            continue
+        body_open = enum.end() - 1
+        body_close = find_matching(masked, body_open, "{}")
+        if body_close < 0:
+            continue
+        for offset, variant in split_top(masked[body_open + 1:body_close], body_open + 1):
+            raw_variant = text[offset:offset + len(variant)]
+            if re.search(r"#\s*\[\s*(?:from|source)\b|#\s*\[\s*error\s*\(\s*transparent", raw_variant):
+                continue
+            payload = re.search(r"[({]", attr_re.sub(lambda m: " " * len(m.group(0)), variant))
+            if not payload:
+                continue
+            closer = ")" if payload.group(0) == "(" else "}"
+            fields_start = payload.end()
+            fields_end = variant.rfind(closer)
+            if fields_end <= fields_start:
+                continue
+            for field_offset, field in split_top(variant[fields_start:fields_end], offset + fields_start):
+                cleaned = attr_re.sub("", field).strip()
+                named = re.match(r"(?:pub(?:\s*\([^)]*\))?\s+)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*:(?!:)(?P<ty>.*)$", cleaned, re.DOTALL)
+                ty = named.group("ty") if closer == "}" and named else cleaned
+                if closer == "}" and named and named.group("name") == "source":
+                    continue
+                if error_type_re.search(" " + ty.strip()):
+                    report(path, text, lines, field_offset + len(field) - len(field.lstrip()))
+PY
+}
+
+count_error_context_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_error_context_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_error_context_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_error_context_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_error_context() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_error_context_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_eager_default_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_paren, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+eager_re = re.compile(r"\.\s*(?P<method>unwrap_or|ok_or|map_or|or|get_or_insert)\s*\(")
+lazy_name = {
+    "unwrap_or": "unwrap_or_else", "ok_or": "ok_or_else", "map_or": "map_or_else",
+    "or": "or_else", "get_or_insert": "get_or_insert_with",
+}
+call_re = re.compile(r"(?P<path>(?:[A-Za-z_][A-Za-z0-9_]*\s*::\s*)*)(?P<dot>\.\s*)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:::\s*<[^()]*>\s*)?(?P<bang>!)?\s*[(\[]")
+cheap_methods = {
+    "len", "is_empty", "as_str", "as_ref", "as_deref", "as_slice", "as_bytes", "clone", "copied", "cloned",
+    "into", "min", "max", "abs", "get", "unwrap_or", "is_some", "is_none", "to_bits", "as_path",
+}
+cheap_ctors = {"new", "default", "empty", "none", "zero"}
+const_ctors = {"from_secs", "from_millis", "from_micros", "from_nanos"}
+expensive_macros = {"format", "vec"}
+seen = set()
+
+
+def first_arg(args: str) -> str:
+    depth = 0
+    for idx, ch in enumerate(args):
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif ch == "," and depth == 0:
+            return args[:idx]
+        elif ch == "|" and depth == 0 and not args[:idx].strip():
+            return ""
+    return args
+
+
+def expensive_call(arg: str):
+    """Return the callee that makes arg costly to build eagerly, or None for literals/Copy-ish values."""
+    if not arg or re.match(r"\s*(?:move\s+)?\|", arg):
+        return None
+    for call in call_re.finditer(arg):
+        name = call.group("name")
+        if call.group("bang"):
+            if name in expensive_macros:
+                return name + "!"
+            continue
+        if name in {"Some", "Ok", "Err", "Box"} and not call.group("path"):
+            continue
+        if call.group("dot"):
+            if name in cheap_methods:
+                continue
+            return "." + name + "()"
+        path = re.sub(r"\s+", "", call.group("path"))
+        if path and name in const_ctors:
+            continue
+        if path and name in cheap_ctors:
+            args_open = call.end() - 1
+            args_close = find_matching_paren(arg, args_open)
+            if args_close > 0 and not arg[args_open + 1:args_close].strip():
+                continue
+        if name[:1].isupper() and not path:
+            # Tuple-struct or variant literal such as Point(0, 0).
+            continue
+        if path and path.rstrip(":").split("::")[-1][:1].isupper() and name[:1].isupper():
+            continue
+        return path + name + "()"
+    return None
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for call in eager_re.finditer(masked):
+        open_idx = call.end() - 1
+        close_idx = find_matching_paren(masked, open_idx)
+        if close_idx < 0:
+            continue
+        arg = first_arg(masked[open_idx + 1:close_idx]).strip()
+        costly = expensive_call(arg)
+        if costly is None:
+            continue
+        line = line_number(masked, call.start())
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line}:{code}  [{costly} evaluated eagerly; use {lazy_name[call.group('method')]}]")
+PY
+}
+
+count_eager_default_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_eager_default_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_eager_default_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_eager_default_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_eager_default() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_eager_default_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_relaxed_publication_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^{;]*?>)?\s*\(")
+atomic_op_re = re.compile(
+    r"(?P<recv>(?:\*\s*)?(?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*)"
+    r"\s*\.\s*(?P<op>store|load|swap|compare_exchange|compare_exchange_weak)\s*\("
+)
+relaxed_re = re.compile(r"\b(?:(?:std::sync::)?(?:atomic::)?Ordering::)?Relaxed\b")
+shared_write_re = re.compile(
+    r"(?:^|[;{}])\s*(?:unsafe\s*\{[^}]*?)?(?P<target>(?:\*\s*[A-Za-z_][A-Za-z0-9_.]*(?:\(\s*\))?|self\s*\.\s*[A-Za-z_][A-Za-z0-9_.]*|[A-Z][A-Z0-9_]+)(?:\s*\[[^\]]*\])?)"
+    r"\s*(?:=(?!=)|\+=|-=|\.\s*(?:push|insert|extend|write|set|copy_from_slice|clone_from_slice|push_str)\s*\()",
+    re.MULTILINE,
+)
+condition_re = re.compile(r"\b(?:while|if)\b[^{;]*$")
+seen = set()
+
+
+def fn_spans(masked: str):
+    spans = []
+    for fn in fn_re.finditer(masked):
+        close = find_matching_paren(masked, fn.end() - 1)
+        if close < 0:
+            continue
+        brace = masked.find("{", close)
+        semi = masked.find(";", close)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end > 0:
+            spans.append((brace, end))
+    return spans
+
+
+def atomic_key(recv: str) -> str:
+    return re.sub(r"\s+", "", recv).lstrip("*").split(".")[-1]
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if not relaxed_re.search(masked):
+        continue
+    lines = text.splitlines()
+    spans = fn_spans(masked)
+    ops = []
+    for op in atomic_op_re.finditer(masked):
+        open_idx = op.end() - 1
+        close_idx = find_matching_paren(masked, open_idx)
+        if close_idx < 0:
+            continue
+        args = masked[open_idx + 1:close_idx]
+        ops.append((op.start(), op.group("op"), atomic_key(op.group("recv")), bool(relaxed_re.search(args))))
+    publishes = {}
+    for offset, op, key, relaxed in ops:
+        if op not in {"store", "swap"}:
+            continue
+        scope = None
+        for brace, end in spans:
+            if brace <= offset <= end and (scope is None or brace > scope[0]):
+                scope = (brace, end)
+        if scope is None:
+            continue
+        # Only writes since the previous atomic op in this fn are published by this store.
+        previous_atomic = max((o for o, _, _, _ in ops if scope[0] < o < offset), default=scope[0])
+        window = masked[previous_atomic:offset]
+        writes = [w.group("target") for w in shared_write_re.finditer(window)]
+        writes = [re.sub(r"\s+", "", w) for w in writes if atomic_key(w.split("[")[0]) != key]
+        if writes:
+            publishes.setdefault(key, []).append((offset, relaxed, writes[-1]))
+    hits = []
+    for key, stores in publishes.items():
+        store_line = line_number(masked, stores[0][0])
+        for offset, relaxed, target in stores:
+            if relaxed:
+                hits.append((offset, f"store publishes {target} written above; use Ordering::Release"))
+        for offset, op, other_key, relaxed in ops:
+            if other_key != key or op != "load" or not relaxed:
+                continue
+            line_start = masked.rfind("\n", 0, offset) + 1
+            if condition_re.search(masked[line_start:offset]) or re.search(r"\bloop\b", masked[max(0, offset - 120):offset]):
+                hits.append((offset, f"load pairs with the publishing store at line {store_line}; use Ordering::Acquire"))
+    for offset, note in sorted(hits):
+        line = line_number(masked, offset)
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}  [{note}]")
+PY
+}
+
+count_relaxed_publication_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_relaxed_publication_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_relaxed_publication_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_relaxed_publication_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_relaxed_publication() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_relaxed_publication_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_double_lock_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^{;]*?>)?\s*\(")
+lock_call_re = re.compile(
+    r"(?P<recv>(?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\.\s*(?P<kind>lock|write|read)\s*\(\s*\)"
+)
+let_guard_re = re.compile(r"\blet\s+(?:mut\s+)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=(?!=)")
+scrutinee_re = re.compile(r"\b(?:if\s+let|while\s+let|match)\b")
+closure_re = re.compile(r"\|[^|;{}]*\|\s*\{")
+seen = set()
+
+
+def enclosing_block(text: str, offset: int, lower: int) -> int:
+    depth = 0
+    for idx in range(offset - 1, lower - 1, -1):
+        ch = text[idx]
+        if ch == "}":
+            depth += 1
+        elif ch == "{":
+            if depth == 0:
+                return idx
+            depth -= 1
+    return -1
+
+
+def statement_end(text: str, start: int, limit: int) -> int:
+    depth = 0
+    for idx in range(start, limit):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            if depth == 0:
+                return -1
+            depth -= 1
+        elif ch == ";" and depth == 0:
+            return idx
+    return -1
+
+
+def conflicts(first: str, second: str) -> bool:
+    # Two shared read() guards can coexist; anything involving lock()/write() blocks.
+    return not (first == "read" and second == "read")
+
+
+def key_of(recv: str) -> str:
+    return re.sub(r"\s+", "", recv)
+
+
+def guard_locks(text: str, start: int, end: int):
+    return [m for m in lock_call_re.finditer(text, start, end)]
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if not re.search(r"\.\s*(?:lock|write)\s*\(\s*\)", masked) or "ReentrantMutex" in masked:
+        continue
+    lines = text.splitlines()
+    closures = []
+    for closure in closure_re.finditer(masked):
+        close = find_matching_brace(masked, closure.end() - 1)
+        if close > 0:
+            closures.append((closure.end() - 1, close))
+    hits = []
+    for fn in fn_re.finditer(masked):
+        paren_close = find_matching_paren(masked, fn.end() - 1)
+        if paren_close < 0:
+            continue
+        body_open = masked.find("{", paren_close)
+        semi = masked.find(";", paren_close)
+        if body_open < 0 or (0 <= semi < body_open):
+            continue
+        body_close = find_matching_brace(masked, body_open)
+        if body_close < 0:
+            continue
+        regions = []
+        for binding in let_guard_re.finditer(masked, body_open, body_close):
+            end = statement_end(masked, binding.end(), body_close)
+            if end < 0:
+                continue
+            init = masked[binding.end():end].strip()
+            first = re.match(
+                r"(?P<recv>(?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\.\s*(?P<kind>lock|write|read)\s*\(\s*\)"
+                r"\s*(?:\.\s*unwrap\s*\(\s*\)|\.\s*expect\s*\([^()]*\)|\?)?$",
+                init,
+            )
+            if not first:
+                continue
+            scope_open = enclosing_block(masked, binding.start(), body_open)
+            scope_close = find_matching_brace(masked, scope_open) if scope_open >= 0 else -1
+            if scope_close < 0:
+                continue
+            name = binding.group("name")
+            release = re.search(r"\b(?:(?:std::)?mem::)?drop\s*\(\s*" + re.escape(name) + r"\s*\)", masked[end:scope_close])
+            region_end = end + release.start() if release else scope_close
+            regions.append((first, end, region_end, binding.start()))
+        for scrutinee in scrutinee_re.finditer(masked, body_open, body_close):
+            block_open = masked.find("{", scrutinee.end())
+            if block_open < 0 or block_open > body_close:
+                continue
+            head = masked[scrutinee.end():block_open]
+            first = lock_call_re.search(head)
+            if not first:
+                continue
+            block_close = find_matching_brace(masked, block_open)
+            if block_close < 0:
+                continue
+            # Temporaries in an if-let/match scrutinee live until the whole block ends.
+            tail_else = re.match(r"\s*else\s*\{", masked[block_close + 1:])
+            if tail_else:
+                else_close = find_matching_brace(masked, block_close + tail_else.end())
+                block_close = else_close if else_close > 0 else block_close
+            regions.append((first, block_open, block_close, scrutinee.start()))
+        for first, start, stop, anchor in regions:
+            first_key = key_of(first.group("recv"))
+            for second in guard_locks(masked, start, stop):
+                if any(lo < second.start() < hi for lo, hi in closures if not (lo < anchor < hi)):
+                    continue
+                if key_of(second.group("recv")) == first_key and conflicts(first.group("kind"), second.group("kind")):
+                    hits.append((second.start(), line_number(masked, anchor)))
+                    break
+        for stmt in re.finditer(r"(?:^|(?<=[;{}]))[^;{}]+(?:;|(?=\}))", masked[body_open + 1:body_close + 1], re.MULTILINE):
+            segment_start = body_open + 1 + stmt.start()
+            calls = guard_locks(masked, segment_start, body_open + 1 + stmt.end())
+            for idx, call in enumerate(calls):
+                later = [c for c in calls[idx + 1:] if key_of(c.group("recv")) == key_of(call.group("recv")) and conflicts(call.group("kind"), c.group("kind"))]
+                if later:
+                    hits.append((later[0].start(), line_number(masked, call.start())))
+                    break
+    for offset, first_line in sorted(set(hits)):
+        line = line_number(masked, offset)
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}  [guard from line {first_line} still alive]")
+PY
+}
+
+count_double_lock_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_double_lock_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_double_lock_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_double_lock_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_double_lock() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_double_lock_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_lock_order_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;]*?>)?\s*\(")
+lock_call_re = re.compile(
+    r"(?P<recv>(?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\.\s*(?:lock|write|read)\s*\(\s*\)"
+)
+guard_init_re = re.compile(
+    r"(?P<recv>(?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\.\s*(?:lock|write|read)\s*\(\s*\)"
+    r"\s*(?:\.\s*unwrap\s*\(\s*\)|\.\s*expect\s*\([^()]*\)|\?)?$"
+)
+let_guard_re = re.compile(r"\blet\s+(?:mut\s+)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=(?!=)")
+local_call_re = re.compile(r"(?:\bself\s*\.\s*|(?<![.:A-Za-z0-9_]))(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*\(")
+seen = set()
+
+
+def enclosing_block(text: str, offset: int, lower: int) -> int:
+    depth = 0
+    for idx in range(offset - 1, lower - 1, -1):
+        ch = text[idx]
+        if ch == "}":
+            depth += 1
+        elif ch == "{":
+            if depth == 0:
+                return idx
+            depth -= 1
+    return -1
+
+
+def statement_end(text: str, start: int, limit: int) -> int:
+    depth = 0
+    for idx in range(start, limit):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            if depth == 0:
+                return -1
+            depth -= 1
+        elif ch == ";" and depth == 0:
+            return idx
+    return -1
+
+
+def lock_key(recv: str) -> str:
+    return re.sub(r"\s+", "", recv).split(".")[-1]
+
+
+def find_cycle_edges(edges):
+    """Return the set of (a, b) edges that sit on a cycle of the lock-order graph."""
+    graph = {}
+    for a, b in edges:
+        graph.setdefault(a, set()).add(b)
+
+    def reaches(start, goal):
+        stack, visited = [start], set()
+        while stack:
+            node = stack.pop()
+            if node == goal:
+                return True
+            if node in visited:
+                continue
+            visited.add(node)
+            stack.extend(graph.get(node, ()))
+        return False
+
+    return {(a, b) for a, b in edges if reaches(b, a)}
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if len(lock_call_re.findall(masked)) < 2:
+        continue
+    lines = text.splitlines()
+    fns = {}
+    for fn in fn_re.finditer(masked):
+        paren_close = find_matching_paren(masked, fn.end() - 1)
+        if paren_close < 0:
+            continue
+        body_open = masked.find("{", paren_close)
+        semi = masked.find(";", paren_close)
+        if body_open < 0 or (0 <= semi < body_open):
+            continue
+        body_close = find_matching_brace(masked, body_open)
+        if body_close > 0:
+            fns.setdefault(fn.group("name"), (body_open, body_close))
+    direct = {}
+    for name, (body_open, body_close) in fns.items():
+        direct[name] = {lock_key(m.group("recv")) for m in lock_call_re.finditer(masked, body_open, body_close)}
+    # Locks a call may take, following same-file callees a few levels deep.
+    acquired = {name: set(keys) for name, keys in direct.items()}
+    for _ in range(3):
+        for name, (body_open, body_close) in fns.items():
+            for call in local_call_re.finditer(masked, body_open, body_close):
+                callee = call.group("name")
+                if callee in fns and callee != name:
+                    acquired[name] |= acquired[callee]
+    edges = {}
+    for name, (body_open, body_close) in fns.items():
+        for binding in let_guard_re.finditer(masked, body_open, body_close):
+            end = statement_end(masked, binding.end(), body_close)
+            if end < 0:
+                continue
+            first = guard_init_re.match(masked[binding.end():end].strip())
+            if not first:
+                continue
+            scope_open = enclosing_block(masked, binding.start(), body_open)
+            scope_close = find_matching_brace(masked, scope_open) if scope_open >= 0 else -1
+            if scope_close < 0:
+                continue
+            guard = binding.group("name")
+            release = re.search(r"\b(?:(?:std::)?mem::)?drop\s*\(\s*" + re.escape(guard) + r"\s*\)", masked[end:scope_close])
+            region_end = end + release.start() if release else scope_close
+            held = lock_key(first.group("recv"))
+            for inner in lock_call_re.finditer(masked, end, region_end):
+                other = lock_key(inner.group("recv"))
+                if other != held:
+                    edges.setdefault((held, other), []).append((inner.start(), name, None))
+            for call in local_call_re.finditer(masked, end, region_end):
+                callee = call.group("name")
+                if callee in fns and callee != name:
+                    for other in acquired[callee] - {held}:
+                        edges.setdefault((held, other), []).append((call.start(), name, callee))
+    cyclic = find_cycle_edges(set(edges))
+    for held, other in sorted(cyclic):
+        reverse = edges.get((other, held))
+        counter = ""
+        if reverse:
+            counter = f"; {reverse[0][1]}() takes {other} then {held} at line {line_number(masked, reverse[0][0])}"
+        for offset, fn_name, callee in edges[(held, other)]:
+            line = line_number(masked, offset)
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            via = f" via {callee}()" if callee else ""
+            print(f"{path}:{line}:{code}  [{fn_name}() takes {held} then {other}{via}{counter}]")
+PY
+}
+
+count_lock_order_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_lock_order_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_lock_order_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_lock_order_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_lock_order() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_lock_order_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_busy_wait_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+loop_re = re.compile(r"\b(?P<kw>while|loop)\b")
+call_re = re.compile(r"(?P<sep>\.|::)?\s*(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?P<bang>!)?\s*\(")
+poll_calls = {
+    "load", "compare_exchange", "compare_exchange_weak", "compare_and_swap", "swap",
+    "try_lock", "try_read", "try_write", "try_recv", "is_finished", "is_empty", "is_some", "is_none",
+    "is_ok", "is_err", "get", "fetch_add", "fetch_sub", "fetch_or", "fetch_and",
+}
+backoff_re = re.compile(
+    r"\b(?:yield_now|sleep|park|park_timeout|spin_loop|wait|wait_timeout|wait_while|recv|recv_timeout|"
+    r"select|block_on|snooze|spin|backoff)\b|\.\s*await\b"
+)
+keyword_names = {"if", "while", "match", "return", "for", "in", "loop", "Some", "Ok", "Err", "None"}
+bare_flag_re = re.compile(r"^!?\s*(?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*$")
+seen = set()
+
+
+def body_calls(body: str):
+    names = []
+    for call in call_re.finditer(body):
+        name = call.group("name")
+        if name in keyword_names or (not call.group("sep") and not call.group("bang") and name[:1].isupper()):
+            continue
+        names.append(name + ("!" if call.group("bang") else ""))
+    return names
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if "while" not in masked and "loop" not in masked:
+        continue
+    lines = text.splitlines()
+    for match in loop_re.finditer(masked):
+        body_open = masked.find("{", match.end())
+        if body_open < 0:
+            continue
+        cond = masked[match.end():body_open].strip()
+        if match.group("kw") == "loop" and cond:
+            continue
+        if ";" in cond or "}" in cond:
+            continue
+        body_close = find_matching_brace(masked, body_open)
+        if body_close < 0:
+            continue
+        body = masked[body_open + 1:body_close]
+        if loop_re.search(body) or backoff_re.search(body) or backoff_re.search(cond):
+            continue
+        if re.search(r"\bfor\b[^{]*\bin\b", body):
+            continue
+        calls = body_calls(body)
+        if any(name.rstrip("!") not in poll_calls for name in calls):
+            continue
+        cond_calls = body_calls(cond)
+        if match.group("kw") == "while":
+            polled = [name for name in cond_calls if name in poll_calls]
+            if len(polled) != len(cond_calls):
+                continue
+            if not polled and not bare_flag_re.match(cond):
+                continue
+            if not polled and body.strip():
+                # A plain bool flag is only a spin if nothing in the body can change it.
+                flag = cond.lstrip("!").strip().split(".")[-1].strip()
+                if re.search(r"\b" + re.escape(flag) + r"\s*(?:=|\+=|-=)(?!=)", body):
+                    continue
+            what = f"{polled[0]}()" if polled else cond
+        else:
+            polled = [name for name in calls if name in poll_calls]
+            if not polled or not re.search(r"\b(?:break|return)\b", body):
+                continue
+            what = f"{polled[0]}()"
+        line = line_number(masked, match.start())
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}  [spins on {what} without yield_now/sleep/park]")
+PY
+}
+
+count_busy_wait_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_busy_wait_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_busy_wait_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_busy_wait_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_busy_wait() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_busy_wait_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_infinite_loop_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+loop_kw_re = re.compile(r"(?:'(?P<label>[A-Za-z_][A-Za-z0-9_]*)\s*:\s*)?\b(?P<kw>loop|while|for)\b")
+closure_re = re.compile(r"(?:\|[^|;{}]*\||\basync(?:\s+move)?)\s*\{")
+exit_call_re = re.compile(
+    r"\b(?:process\s*::\s*)?(?:exit|abort)\s*\(|\b(?:panic|unreachable|todo|unimplemented)\s*!"
+)
+fn_re = re.compile(r"\bfn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)[^{;]*?(?P<ret>->\s*!)?\s*(?:where[^{;]*)?\{")
+worker_fn_re = re.compile(r"worker|_loop$|^run_forever$|^event_loop$|^serve$", re.IGNORECASE)
+named_thread_re = re.compile(r"\.\s*name\s*\(")
+spawn_re = re.compile(r"\bspawn\s*\(")
+seen = set()
+
+
+def nested_ranges(body: str, offset: int):
+    """Nested loops (which own unlabeled breaks) and closures/async blocks (which own return/?/await)."""
+    loops, closures = [], []
+    for nested in loop_kw_re.finditer(body):
+        open_idx = body.find("{", nested.end())
+        if open_idx < 0:
+            continue
+        close_idx = find_matching_brace(body, open_idx)
+        if close_idx > 0:
+            loops.append((open_idx + offset, close_idx + offset))
+    for nested in closure_re.finditer(body):
+        open_idx = nested.end() - 1
+        close_idx = find_matching_brace(body, open_idx)
+        if close_idx > 0:
+            closures.append((open_idx + offset, close_idx + offset))
+    return loops, closures
+
+
+def inside(ranges, pos):
+    return any(start < pos < end for start, end in ranges)
+
+
+def loop_exits(masked: str, open_idx: int, close_idx: int, label):
+    body = masked[open_idx + 1:close_idx]
+    loops, closures = nested_ranges(body, open_idx + 1)
+    for brk in re.finditer(r"\bbreak\b(?:\s*'(?P<label>[A-Za-z_][A-Za-z0-9_]*))?", masked[open_idx:close_idx]):
+        pos = open_idx + brk.start()
+        if inside(closures, pos):
+            continue
+        if not inside(loops, pos) or (label and brk.group("label") == label):
+            return True
+    for leave in re.finditer(r"\breturn\b|\?|\.\s*await\b", masked[open_idx:close_idx]):
+        if not inside(closures, open_idx + leave.start()):
+            return True
+    return bool(exit_call_re.search(body))
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if not re.search(r"\bloop\s*\{", masked):
+        continue
+    lines = text.splitlines()
+    fns = []
+    for fn in fn_re.finditer(masked):
+        body_open = fn.end() - 1
+        body_close = find_matching_brace(masked, body_open)
+        if body_close > 0:
+            fns.append((body_open, body_close, fn.group("name"), bool(fn.group("ret"))))
+    for match in loop_kw_re.finditer(masked):
+        if match.group("kw") != "loop":
+            continue
+        open_idx = masked.find("{", match.end())
+        if open_idx < 0 or masked[match.end():open_idx].strip():
+            continue
+        close_idx = find_matching_brace(masked, open_idx)
+        if close_idx < 0 or loop_exits(masked, open_idx, close_idx, match.group("label")):
+            continue
+        owner = None
+        for body_open, body_close, name, diverges in fns:
+            if body_open < match.start() < body_close and (owner is None or body_open > owner[0]):
+                owner = (body_open, body_close, name, diverges)
+        if owner and owner[3]:
+            # `-> !` declares the loop never ends.
+            continue
+        worker = bool(owner and worker_fn_re.search(owner[2]))
+        if not worker:
+            for spawn in spawn_re.finditer(masked, owner[0] if owner else 0, match.start()):
+                arg_close = find_matching_paren(masked, spawn.end() - 1)
+                if arg_close > match.start():
+                    statement_start = max(masked.rfind(";", 0, spawn.start()), masked.rfind("{", 0, spawn.start()))
+                    if named_thread_re.search(masked, statement_start + 1, spawn.start()):
+                        worker = True
+        if worker != (mode == "worker"):
+            continue
+        line = line_number(masked, match.start())
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        context = f"worker loop in {owner[2]}()" if worker and owner and worker_fn_re.search(owner[2]) else (
+            "named worker thread" if worker else "no break, return, ? or .await"
+        )
+        print(f"{path}:{line}:{code}  [{context}]")
+PY
+}
+
+count_infinite_loop_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_infinite_loop_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_infinite_loop_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_infinite_loop_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_infinite_loop() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_infinite_loop_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_unbounded_recursion_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+fn_re = re.compile(r"\bfn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;()]*?>)?\s*\(")
+branch_re = re.compile(r"\b(?:if|match|while|for|return|else)\b|\?|&&|\|\|")
+depth_guard_re = re.compile(r"\b(?:[a-z_]*depth|level|levels|limit|max_[a-z_]+|remaining|budget|fuel|nesting|[a-z_]*_left)\b")
+tree_type_re = re.compile(r"\b(?:Value|Json|Node|Expr|Ast|Tree|Element|Token|Tokens|Item|Entry|Dir)\b")
+sequence_type_re = re.compile(r"&\s*(?:mut\s+)?(?:\[|str\b)")
+shrink_arg_re = re.compile(
+    r"\[\s*[^\]]*\.\.[^\]]*\]|\.\s*(?:split_at|split_first|split_last|strip_prefix|strip_suffix|children|left|right|next|parent|"
+    r"get|as_array|as_object|iter|values|trim_start_matches)\b"
+)
+seen = set()
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if "fn " not in masked:
+        continue
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        name = fn.group("name")
+        params_close = find_matching_paren(masked, fn.end() - 1)
+        if params_close < 0:
+            continue
+        body_open = masked.find("{", params_close)
+        semi = masked.find(";", params_close)
+        if body_open < 0 or (0 <= semi < body_open):
+            continue
+        body_close = find_matching_brace(masked, body_open)
+        if body_close < 0:
+            continue
+        if re.search(r"\basync\s+(?:unsafe\s+)?$", masked[max(0, fn.start() - 20):fn.start()]):
+            continue
+        params = masked[fn.end():params_close]
+        body = masked[body_open + 1:body_close]
+        self_call_re = re.compile(
+            r"(?:\bself\s*\.\s*|\bSelf\s*::\s*|(?<![.:A-Za-z0-9_]))" + re.escape(name) + r"\s*(?:::\s*<[^>]*>\s*)?\("
+        )
+        calls = []
+        for call in self_call_re.finditer(body):
+            arg_open = body_open + 1 + call.end() - 1
+            arg_close = find_matching_paren(masked, arg_open)
+            if arg_close > 0:
+                calls.append((body_open + 1 + call.start(), masked[arg_open + 1:arg_close]))
+        if not calls:
+            continue
+        # Nested fn items with the same name would shadow the outer one; ignore those bodies.
+        if re.search(r"\bfn\s+" + re.escape(name) + r"\b", body):
+            continue
+        tag = None
+        if mode == "nobase":
+            if branch_re.search(body):
+                continue
+            tag = f"{name}() calls itself on every path"
+        else:
+            if not branch_re.search(body):
+                continue
+            if depth_guard_re.search(params) or depth_guard_re.search(re.sub(r"\b" + re.escape(name) + r"\b", "", body)):
+                continue
+            # Tree-shaped inputs recurse once per nesting level; slices and strs once per element peeled off.
+            if not tree_type_re.search(params):
+                if not sequence_type_re.search(params):
+                    continue
+                if not any(shrink_arg_re.search(args) for _, args in calls):
+                    continue
+            tag = f"{name}() recursion depth follows input nesting/length with no depth limit"
+        offset = calls[0][0]
+        line = line_number(masked, offset)
+        key = (str(path), line, mode)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code or "ubs:ignore" in (lines[line_number(masked, fn.start()) - 1] if lines else ""):
+            continue
+        print(f"{path}:{line}:{code}  [{tag}]")
+PY
+}
+
+count_unbounded_recursion_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_unbounded_recursion_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_unbounded_recursion_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_unbounded_recursion_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_unbounded_recursion() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_unbounded_recursion_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_async_recursion_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\b(?P<async>async\s+)?(?:unsafe\s+)?fn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;()]*?>)?\s*\(")
+boxed_re = re.compile(r"\bBox\s*::\s*pin\s*\(|\.\s*boxed(?:_local)?\s*\(\s*\)")
+seen = set()
+
+
+def boxed_spans(masked: str, start: int, end: int):
+    spans = []
+    for boxed in boxed_re.finditer(masked, start, end):
+        if boxed.group(0).startswith("."):
+            # fut.boxed(): the receiver chain ends at the previous statement/argument boundary.
+            left = max(masked.rfind(ch, start, boxed.start()) for ch in ";{,=")
+            spans.append((left, boxed.end()))
+        else:
+            close = find_matching_paren(masked, boxed.end() - 1)
+            if close > 0:
+                spans.append((boxed.start(), close))
+    return spans
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if "async" not in masked:
+        continue
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        name = fn.group("name")
+        params_close = find_matching_paren(masked, fn.end() - 1)
+        if params_close < 0:
+            continue
+        body_open = masked.find("{", params_close)
+        semi = masked.find(";", params_close)
+        if body_open < 0 or (0 <= semi < body_open):
+            continue
+        body_close = find_matching_brace(masked, body_open)
+        if body_close < 0:
+            continue
+        signature = masked[params_close:body_open]
+        attrs = masked[max(0, masked.rfind("\n\n", 0, fn.start())):fn.start()]
+        if re.search(r"#\s*\[\s*(?:async_recursion|async_recursion::async_recursion)\b", attrs):
+            continue
+        if fn.group("async"):
+            kind = "async fn"
+            scan_from = body_open
+        elif re.search(r"->\s*impl\s+(?:std\s*::\s*future\s*::\s*|core\s*::\s*future\s*::\s*)?Future\b", signature):
+            async_block = re.compile(r"\basync\s+(?:move\s+)?\{").search(masked, body_open, body_close)
+            if not async_block:
+                continue
+            kind = "impl Future"
+            scan_from = async_block.start()
+        else:
+            continue
+        self_call_re = re.compile(
+            r"(?:\bself\s*\.\s*|\bSelf\s*::\s*|(?<![.:A-Za-z0-9_]))" + re.escape(name) + r"\s*(?:::\s*<[^>]*>\s*)?\("
+        )
+        spans = boxed_spans(masked, body_open, body_close)
+        for call in self_call_re.finditer(masked, scan_from, body_close):
+            if any(start <= call.start() <= end for start, end in spans):
+                continue
+            if re.search(r"\bfn\s+" + re.escape(name) + r"\b", masked[body_open:body_close]):
+                break
+            line = line_number(masked, call.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}  [{kind} {name}() recurses without Box::pin]")
+PY
+}
+
+count_async_recursion_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_async_recursion_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_async_recursion_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_async_recursion_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_async_recursion() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_async_recursion_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_runtime_ctor_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+runtime_ctor_re = re.compile(
+    r"\b(?:tokio\s*::\s*runtime\s*::\s*)?(?:Runtime\s*::\s*new\s*\(\s*\)"
+    r"|Builder\s*::\s*new_(?:multi_thread|current_thread)\s*\(\s*\))"
+)
+fn_re = re.compile(r"\bfn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;()]*?>)?\s*\(")
+loop_re = re.compile(r"\b(?:for\b[^{;]*|while\b[^{;]*|loop\s*)\{")
+iter_closure_re = re.compile(r"\.\s*(?:map|for_each|try_for_each|filter_map|flat_map|fold|par_iter\s*\(\s*\)\s*\.\s*map)\s*\(\s*(?:move\s*)?\|")
+handler_param_re = re.compile(
+    r"\b(?:HttpRequest|Request|Json|Query|Path|Form|State|Extension|web\s*::\s*\w+|Bytes|Payload|Message|Event)\b"
+)
+handler_name_re = re.compile(r"^(?:handle|on_|serve|process|route|endpoint)|_handler$|_request$")
+once_init_re = re.compile(r"\b(?:Lazy|LazyLock|OnceCell|OnceLock|lazy_static|thread_local)\b|get_or_init\s*\(")
+owner_return_re = re.compile(r"->\s*(?:[A-Za-z_:]*Result\s*<\s*)?(?:tokio\s*::\s*runtime\s*::\s*)?(?:Runtime|Self)\b")
+seen = set()
+
+
+def is_binary_entry(path: Path) -> bool:
+    parts = path.parts
+    return path.name in {"main.rs", "build.rs"} or "bin" in parts or "examples" in parts or "benches" in parts
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if not runtime_ctor_re.search(masked):
+        continue
+    lines = text.splitlines()
+    fns = []
+    for fn in fn_re.finditer(masked):
+        params_close = find_matching_paren(masked, fn.end() - 1)
+        if params_close < 0:
+            continue
+        body_open = masked.find("{", params_close)
+        semi = masked.find(";", params_close)
+        if body_open < 0 or (0 <= semi < body_open):
+            continue
+        body_close = find_matching_brace(masked, body_open)
+        if body_close < 0:
+            continue
+        attrs = masked[max(0, masked.rfind("\n\n", 0, fn.start())):fn.start()]
+        fns.append({
+            "name": fn.group("name"),
+            "start": fn.start(),
+            "open": body_open,
+            "close": body_close,
+            "params": masked[fn.end():params_close],
+            "ret": masked[params_close:body_open],
+            "test": bool(re.search(r"#\s*\[\s*(?:\w+\s*::\s*)?test\b", attrs)),
+        })
+    loops = []
+    for loop in loop_re.finditer(masked):
+        close = find_matching_brace(masked, loop.end() - 1)
+        if close > 0:
+            loops.append((loop.end(), close))
+    for closure in iter_closure_re.finditer(masked):
+        close = find_matching_paren(masked, masked.rfind("(", closure.start(), closure.end()))
+        if close > 0:
+            loops.append((closure.end(), close))
+    test_module = re.search(r"#\s*\[\s*cfg\s*\(\s*test\s*\)\s*\]\s*mod\s+\w+\s*\{", masked)
+    test_range = (test_module.end(), find_matching_brace(masked, test_module.end() - 1)) if test_module else (-1, -1)
+    for hit in runtime_ctor_re.finditer(masked):
+        offset = hit.start()
+        owner = None
+        for fn in fns:
+            if fn["open"] < offset < fn["close"] and (owner is None or fn["open"] > owner["open"]):
+                owner = fn
+        if owner is None or owner["test"] or test_range[0] < offset < test_range[1]:
+            continue
+        statement_start = max(masked.rfind(";", 0, offset), masked.rfind("{", owner["open"], offset))
+        if once_init_re.search(masked, statement_start + 1, offset) or once_init_re.search(
+            masked[max(owner["open"], offset - 200):offset]
+        ):
+            continue
+        if any(start < offset < end for start, end in loops):
+            kind = "loop"
+            tag = "runtime built on every iteration"
+        elif handler_param_re.search(owner["params"]) or handler_name_re.search(owner["name"]):
+            kind = "handler"
+            tag = f"runtime built per call in handler {owner['name']}()"
+        else:
+            if owner["name"] == "main" or is_binary_entry(path):
+                continue
+            # Constructors that hand the runtime to their caller own it once; that is the shared-runtime pattern.
+            if owner_return_re.search(owner["ret"]) or re.match(r"(?:new|build|init|with_)", owner["name"]):
+                continue
+            kind = "library"
+            tag = f"library fn {owner['name']}() creates its own runtime"
+        if kind != mode:
+            continue
+        line = line_number(masked, offset)
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}  [{tag}]")
+PY
+}
+
+count_runtime_ctor_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_runtime_ctor_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_runtime_ctor_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_runtime_ctor_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_runtime_ctor() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_runtime_ctor_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_unsafe_send_sync_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+struct_re = re.compile(r"\bstruct\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;()]*?>)?\s*(?:where[^{;(]*)?(?P<open>[{(])")
+impl_re = re.compile(
+    r"\bunsafe\s+impl\s*(?:<[^{;]*?>)?\s*(?:(?:std|core)\s*::\s*marker\s*::\s*)?(?P<trait>Send|Sync)\s+for\s+"
+    r"(?P<name>[A-Za-z_][A-Za-z0-9_]*)"
+)
+rc_field_re = re.compile(r"\b(?P<kind>Rc|Cell|RefCell|OnceCell)\s*<")
+ptr_field_re = re.compile(r"\*\s*(?:const|mut)\b|\bNonNull\s*<")
+# Wrapping a non-thread-safe field in a lock or thread-local makes the outer impl defensible.
+guarded_re = re.compile(r"\b(?:Mutex|RwLock|ThreadLocal|thread_local|Fragile|SendWrapper)\s*<")
+safety_re = re.compile(r"^\s*//\s*SAFETY\s*:", re.IGNORECASE)
+seen = set()
+
+
+def documented(lines, index: int) -> bool:
+    """A `// SAFETY:` comment directly above the impl (attributes and comment continuations allowed)."""
+    for prev in range(index - 1, max(-1, index - 12), -1):
+        stripped = lines[prev].strip()
+        if safety_re.match(stripped):
+            return True
+        if not (stripped.startswith("//") or stripped.startswith("#[")):
+            return False
+    return False
+
+
+def split_fields(body: str):
+    fields, depth, current = [], 0, ""
+    for ch in body:
+        if ch in "<([{":
+            depth += 1
+        elif ch in ">)]}":
+            depth -= 1
+        if ch == "," and depth == 0:
+            fields.append(current)
+            current = ""
+        else:
+            current += ch
+    if current.strip():
+        fields.append(current)
+    return [field.strip() for field in fields if field.strip()]
+
+
+files = []
+structs = {}
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    files.append((path, text, masked))
+    for decl in struct_re.finditer(masked):
+        open_idx = decl.end() - 1
+        close_idx = find_matching_brace(masked, open_idx) if decl.group("open") == "{" else find_matching_paren(masked, open_idx)
+        if close_idx < 0:
+            continue
+        fields = []
+        for index, field in enumerate(split_fields(masked[open_idx + 1:close_idx])):
+            field = re.sub(r"#\s*\[[^\]]*\]", "", field).strip()
+            field = re.sub(r"^pub(?:\s*\([^)]*\))?\s+", "", field)
+            if decl.group("open") == "{":
+                name, _, ty = field.partition(":")
+            else:
+                name, ty = str(index), field
+            fields.append((name.strip(), re.sub(r"\s+", " ", ty.strip())))
+        structs.setdefault(decl.group("name"), fields)
+
+for path, text, masked in files:
+    lines = text.splitlines()
+    for impl in impl_re.finditer(masked):
+        trait = impl.group("trait")
+        fields = structs.get(impl.group("name"))
+        if not fields:
+            continue
+        culprit = None
+        for field_name, ty in fields:
+            if guarded_re.search(ty):
+                continue
+            rc = rc_field_re.search(ty)
+            if mode == "rc" and rc and (rc.group("kind") == "Rc" or trait == "Sync"):
+                culprit = (field_name, ty)
+                break
+            if mode == "ptr" and not rc and ptr_field_re.search(ty):
+                culprit = (field_name, ty)
+                break
+        if not culprit:
+            continue
+        line = line_number(masked, impl.start())
+        # A raw pointer is sound to share once ownership is documented; Rc/Cell never are.
+        if mode == "ptr" and documented(lines, line - 1):
+            continue
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}  [{impl.group('name')}.{culprit[0]}: {culprit[1]}]")
+PY
+}
+
+count_unsafe_send_sync_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_unsafe_send_sync_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_unsafe_send_sync_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_unsafe_send_sync_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_unsafe_send_sync() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_unsafe_send_sync_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_str_slice_boundary_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^{;()]*?>)?\s*\(")
+str_param_re = re.compile(
+    r"(?:mut\s+)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*:\s*(?:&\s*(?:'[A-Za-z_]+\s+)?(?:mut\s+)?(?:str|String)\b|String\b|Cow\s*<\s*(?:'[A-Za-z_]+\s*,\s*)?str\s*>)"
+)
+str_let_re = re.compile(
+    r"\blet\s+(?:mut\s+)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?::\s*(?P<ty>[^=;]+))?=\s*(?P<init>[^;]+);"
+)
+str_init_re = re.compile(
+    r"\bString::(?:from|new|with_capacity)\s*\(|\bformat!\s*\(|\.\s*(?:to_string|to_lowercase|to_uppercase|trim|trim_start|trim_end|as_str)\s*\(\s*\)$"
+    r"|read_to_string\s*\("
+)
+safe_source_re = re.compile(
+    r"\.\s*(?:find|rfind|char_indices|match_indices|rmatch_indices|floor_char_boundary|ceil_char_boundary|len_utf8)\b"
+)
+index_re = re.compile(r"(?<![A-Za-z0-9_.])(?P<recv>[A-Za-z_][A-Za-z0-9_]*)\s*\[(?P<range>[^\[\];]*\.\.[^\[\];]*)\]")
+seen = set()
+
+
+def risky_bound(bound: str, recv: str, int_params, safe_vars):
+    """Return why a slice bound is a raw byte offset, or None when it is a known char boundary."""
+    bound = bound.strip().lstrip("=").strip()
+    if not bound or bound == "0":
+        return None
+    if re.fullmatch(re.escape(recv) + r"\s*\.\s*len\s*\(\s*\)", bound):
+        return None
+    names = set(re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", bound))
+    if safe_source_re.search(bound) or names & safe_vars:
+        return None
+    if re.fullmatch(r"\d[\d_]*", bound):
+        return f"fixed byte offset {bound}"
+    if re.search(r"[+\-*/]", bound):
+        return "byte arithmetic " + re.sub(r"\s+", " ", bound)
+    if names & int_params:
+        return f"caller-supplied byte index {bound}"
+    return None
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if ".." not in masked:
+        continue
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        params_close = find_matching_paren(masked, fn.end() - 1)
+        if params_close < 0:
+            continue
+        body_open = masked.find("{", params_close)
+        semi = masked.find(";", params_close)
+        if body_open < 0 or (0 <= semi < body_open):
+            continue
+        body_close = find_matching_brace(masked, body_open)
+        if body_close < 0:
+            continue
+        params = masked[fn.end():params_close]
+        body = masked[body_open:body_close]
+        strings = {m.group("name") for m in str_param_re.finditer(params)}
+        int_params = set(re.findall(r"(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(?:usize|u32|u64|i32|i64)\b", params))
+        safe_vars = set()
+        for let in str_let_re.finditer(body):
+            init = let.group("init").strip()
+            ty = (let.group("ty") or "").strip()
+            if re.match(r"&\s*(?:'[A-Za-z_]+\s+)?(?:mut\s+)?str\b|String\b", ty) or str_init_re.search(init):
+                strings.add(let.group("name"))
+            if safe_source_re.search(init):
+                safe_vars.add(let.group("name"))
+        for binding in re.finditer(r"\b(?:Some|Ok)\s*\(\s*(?:\(\s*)?([A-Za-z_][A-Za-z0-9_]*)[^=]*=\s*[^;{]*" + safe_source_re.pattern, body):
+            safe_vars.add(binding.group(1))
+        for loop in re.finditer(r"\bfor\s*\(\s*([A-Za-z_][A-Za-z0-9_]*)\s*,[^)]*\)\s*in\s+[^{]*char_indices", body):
+            safe_vars.add(loop.group(1))
+        if not strings:
+            continue
+        for hit in index_re.finditer(body):
+            recv = hit.group("recv")
+            if recv not in strings:
+                continue
+            if re.search(r"\b" + re.escape(recv) + r"\s*\.\s*is_ascii\s*\(|is_char_boundary\s*\(", body[:hit.start()]):
+                continue
+            start, _, end = hit.group("range").partition("..")
+            reason = risky_bound(end, recv, int_params, safe_vars) or risky_bound(start, recv, int_params, safe_vars)
+            if not reason:
+                continue
+            line = line_number(masked, body_open + hit.start())
+            key = (str(path), line)
+            if key in seen:
+                continue
+            seen.add(key)
+            code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+            if "ubs:ignore" in code:
+                continue
+            print(f"{path}:{line}:{code}  [{reason} on str {recv}]")
+PY
+}
+
+count_str_slice_boundary_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_str_slice_boundary_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_str_slice_boundary_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_str_slice_boundary_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_str_slice_boundary() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_str_slice_boundary_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_signal_handler_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;()]*?>)?\s*\(")
+fn_handler_re = re.compile(
+    r"\b(?:libc\s*::\s*)?signal\s*\(\s*[^,()]+,\s*(?P<name>[A-Za-z_][A-Za-z0-9_]*)\s+as\b"
+    r"|\bsa_sigaction\s*(?::|=)\s*(?P<sa>[A-Za-z_][A-Za-z0-9_]*)\s+as\b"
+    r"|\bSigHandler\s*::\s*(?:Handler|SigAction)\s*\(\s*(?P<nix>[A-Za-z_][A-Za-z0-9_]*)\s*\)"
+)
+closure_handler_re = re.compile(
+    r"\b(?:signal_hook(?:_registry)?\s*::\s*(?:low_level\s*::\s*)?)register(?:_sigaction|_unchecked)?\s*\(\s*[^,]+,\s*(?:move\s*)?\|[^|]*\|\s*"
+)
+unsafe_ops = [
+    ("locking", re.compile(r"\.\s*(?:lock|read|write|try_lock)\s*\(\s*\)|\bCondvar\b")),
+    ("allocation", re.compile(
+        r"\b(?:format|vec)!\s*[\(\[]|\b(?:String|Vec|Box|Rc|Arc|HashMap|BTreeMap)\s*::\s*(?:new|from|with_capacity)\s*\("
+        r"|\.\s*(?:to_string|to_owned|to_vec|push|push_str|insert|collect)\s*\("
+    )),
+    ("printing", re.compile(
+        r"\b(?:println|eprintln|print|eprint|dbg|info|warn|error|debug|trace)!\s*\(|\blog\s*::\s*\w+!|\bstd\s*::\s*io\s*::\s*std(?:out|err)\s*\("
+    )),
+    ("exit", re.compile(r"\b(?:std\s*::\s*)?process\s*::\s*exit\s*\(|\bpanic!\s*\(|\.\s*(?:unwrap|expect)\s*\(")),
+]
+seen = set()
+
+
+def report(path, lines, masked, start, end, handler):
+    found = {}
+    # unsafe_ops is ordered by severity, so a line that locks and allocates is reported as locking.
+    for kind, op_re in unsafe_ops:
+        for op in op_re.finditer(masked, start, end):
+            found.setdefault(line_number(masked, op.start()), kind)
+    for line, kind in sorted(found.items()):
+        key = (str(path), line)
+        if key in seen:
+            continue
+        seen.add(key)
+        code = lines[line - 1].strip() if line - 1 < len(lines) else ""
+        if "ubs:ignore" in code:
+            continue
+        print(f"{path}:{line}:{code}  [{kind} in signal handler {handler}]")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    if "signal" not in masked and "sa_sigaction" not in masked:
+        continue
+    lines = text.splitlines()
+    bodies = {}
+    for fn in fn_re.finditer(masked):
+        params_close = find_matching_paren(masked, fn.end() - 1)
+        if params_close < 0:
+            continue
+        body_open = masked.find("{", params_close)
+        semi = masked.find(";", params_close)
+        if body_open < 0 or (0 <= semi < body_open):
+            continue
+        body_close = find_matching_brace(masked, body_open)
+        if body_close > 0:
+            bodies.setdefault(fn.group("name"), (body_open, body_close))
+    handlers = set()
+    for reg in fn_handler_re.finditer(masked):
+        name = reg.group("name") or reg.group("sa") or reg.group("nix")
+        if name in bodies:
+            handlers.add(name)
+    for name in sorted(handlers, key=lambda item: bodies[item][0]):
+        report(path, lines, masked, bodies[name][0], bodies[name][1], f"{name}()")
+    for reg in closure_handler_re.finditer(masked):
+        if masked[reg.end():reg.end() + 1] == "{":
+            close = find_matching_brace(masked, reg.end())
+        else:
+            call_close = find_matching_paren(masked, masked.find("(", reg.start()))
+            close = call_close
+        if close > 0:
+            report(path, lines, masked, reg.end(), close, "closure")
+PY
+}
+
+count_signal_handler_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_signal_handler_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_signal_handler_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_signal_handler_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_signal_handler() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_signal_handler_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_underscore_guard_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+underscore_let = re.compile(r"\blet\s+_\s*(?::[^=;]+)?=(?!=)")
+guard_tail = re.compile(
+    r"\.\s*(lock|try_lock|read|write|try_read|try_write|borrow|borrow_mut|lock_owned|read_owned|write_owned|"
+    r"acquire|acquire_owned|acquire_many|enter|entered|lock_exclusive|lock_shared)\s*\(\s*\)"
+    r"(?:\s*\.\s*await)?"
+    r"(?:\s*\.\s*(?:unwrap|expect|unwrap_or_else|map_err|context|with_context)\s*\((?:.|\n)*\))?\s*\??\s*$"
+)
+ctor_tail = re.compile(r"\b(scopeguard::guard|guard|defer|TempDir::new|tempdir|NamedTempFile::new|DropGuard::new)\s*\(")
+seen = set()
+
+
+def statement_end(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            if depth == 0:
+                return -1
+            depth -= 1
+        elif ch == ";" and depth == 0:
+            return idx
+    return -1
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for binding in underscore_let.finditer(masked):
+        end = statement_end(masked, binding.end())
+        if end < 0:
+            continue
+        init = masked[binding.end():end].strip()
+        found = guard_tail.search(init)
+        if found:
+            method = found.group(1)
+            if method in {"read", "write"} and re.search(r"\b(?:fs|File|io)\s*::", init):
+                continue
+            what = method + "() guard"
+        else:
+            ctor = ctor_tail.match(init)
+            if not ctor or not init.endswith(")"):
+                continue
+            what = ctor.group(1) + "(..) value"
+        line_no = line_number(text, binding.start())
+        line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+        if "ubs:ignore" in line:
+            continue
+        key = (path, line_no)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line_no}:{line.strip()}  [{what} dropped at end of statement]")
+PY
+}
+
+count_underscore_guard_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_underscore_guard_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_underscore_guard_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_underscore_guard_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_underscore_guard() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_underscore_guard_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_dangling_temp_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def find_matching_paren(text: str, start: int) -> int:
+    pairs = {"(": ")", "[": "]", "{": "}"}
+    opener = text[start]
+    closer = pairs[opener]
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch == opener:
+            depth += 1
+        elif ch == closer:
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def find_opening(text: str, close: int) -> int:
+    pairs = {")": "(", "]": "[", "}": "{"}
+    closer = text[close]
+    opener = pairs[closer]
+    depth = 0
+    for idx in range(close, -1, -1):
+        ch = text[idx]
+        if ch == closer:
+            depth += 1
+        elif ch == opener:
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{;]*>)?\s*\(")
+ptr_call = re.compile(r"\.\s*(as_ptr|as_mut_ptr)\s*\(\s*\)")
+borrow_call = re.compile(r"\.\s*(as_str|as_slice|as_bytes|as_path|as_os_str|as_mut_slice)\s*\(\s*\)")
+owned_method = re.compile(
+    r"\.\s*(to_vec|to_owned|to_string|to_string_lossy|into_bytes|into_boxed_slice|into_owned|to_path_buf|join|concat|repeat|collect|to_uppercase|to_lowercase|replace)\s*(?:::\s*<[^;]*>)?\s*$"
+)
+owned_ctor = re.compile(r"(?:\b(?:Vec|String|PathBuf|Box)\s*::\s*(?:from|new|with_capacity|from_utf8_lossy)|\b(vec|format)\s*!)\s*$")
+seen = set()
+
+
+def owned_temporary(recv: str):
+    """Return a label when recv (the text before .as_ptr()) ends in a freshly built owned value."""
+    recv = recv.rstrip()
+    if not recv or recv[-1] not in ")]":
+        return None
+    open_idx = find_opening(recv, len(recv) - 1)
+    if open_idx < 0:
+        return None
+    head = recv[:open_idx]
+    method = owned_method.search(head)
+    if method:
+        return method.group(1) + "()"
+    ctor = owned_ctor.search(head)
+    if ctor:
+        text = re.sub(r"\s+", "", ctor.group(0))
+        return text + ("[..]" if text.endswith("!") and recv[-1] == "]" else "(..)")
+    return None
+
+
+def statement_start(body: str, idx: int) -> int:
+    depth = 0
+    for pos in range(idx - 1, -1, -1):
+        ch = body[pos]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                return pos + 1
+            depth -= 1
+        elif ch in ";{}" and depth == 0:
+            return pos + 1
+    return 0
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        ret = masked[close_paren + 1:brace]
+        returns_borrow = "->" in ret and "&" in ret.split("->", 1)[1]
+        body = masked[brace:end + 1]
+        pattern = ptr_call if mode == "ptr" else borrow_call
+        for hit in pattern.finditer(body):
+            start = statement_start(body, hit.start())
+            recv = body[start:hit.start()]
+            prefix = recv
+            if mode == "ptr":
+                stored_prefix = re.match(
+                    r"\s*(?:let\s+(?:mut\s+)?[A-Za-z_][A-Za-z0-9_]*\s*(?::[^=]+)?=|[A-Za-z_][A-Za-z0-9_.\[\]]*\s*=(?!=)|return\b|[A-Za-z_][A-Za-z0-9_]*\s*:(?!:))",
+                    prefix,
+                )
+                if not stored_prefix:
+                    continue
+                recv = recv[stored_prefix.end():]
+                rest = body[hit.end():]
+                if not re.match(r"\s*(?:as\s+\*\s*(?:const|mut)\s+[A-Za-z0-9_:<>]+\s*|\.\s*cast(?:_mut)?\s*(?:::\s*<[^>]*>)?\s*\(\s*\)\s*)?[;,}]", rest):
+                    continue
+            else:
+                if not returns_borrow:
+                    continue
+                lead = re.match(r"\s*return\b", prefix)
+                rest = body[hit.end():]
+                if lead:
+                    recv = recv[lead.end():]
+                    if not re.match(r"\s*;", rest):
+                        continue
+                elif not re.match(r"\s*}", rest) or re.match(r"\s*(?:let\b|[A-Za-z_][A-Za-z0-9_.]*\s*=(?!=))", prefix):
+                    continue
+            if "CString" in recv:
+                continue
+            label = owned_temporary(recv)
+            if not label:
+                continue
+            line_no = line_number(masked, brace + hit.start())
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            print(f"{path}:{line_no}:{line.strip()}  [{hit.group(1)}() on temporary {label}]")
+PY
+}
+
+count_dangling_temp_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_dangling_temp_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_dangling_temp_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_dangling_temp_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_dangling_temp() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_dangling_temp_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_iter_mutation_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def loop_header_end(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([":
+            depth += 1
+        elif ch in ")]":
+            depth -= 1
+        elif ch == "{" and depth == 0:
+            return idx
+        elif ch == ";" and depth == 0:
+            return -1
+    return -1
+
+
+def depth_at(body: str, pos: int) -> int:
+    depth = 0
+    for ch in body[:pos]:
+        if ch == "{":
+            depth += 1
+        elif ch == "}":
+            depth -= 1
+    return depth
+
+
+path_re = r"(?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*"
+for_re = re.compile(r"\bfor\s+(.+?)\s+in\s+")
+while_re = re.compile(r"\bwhile\s+([A-Za-z_][A-Za-z0-9_]*)\s*<\s*(" + path_re + r")\s*\.\s*len\s*\(\s*\)\s*\{")
+range_re = re.compile(r"\(?\s*[A-Za-z0-9_]+\s*\.\.=?\s*(" + path_re + r")\s*\.\s*len\s*\(\s*\)\s*\)?")
+iter_re = re.compile(
+    r"(?:&\s*(?:mut\s+)?(" + path_re + r")|(" + path_re + r")\s*(?:\.\s*borrow\s*\(\s*\)\s*)?"
+    r"\.\s*(?:iter|iter_mut|keys|values|values_mut)\s*\(\s*\))"
+)
+shrink_ops = "remove|swap_remove|insert|truncate|drain|retain|pop|clear"
+iter_ops = "remove|swap_remove|insert|push|push_back|push_front|pop|truncate|clear|drain|retain|extend|entry"
+seen = set()
+
+
+def mutation_re(coll: str, ops: str):
+    parts = [re.escape(p.strip()) for p in coll.split(".")]
+    return re.compile(
+        r"(?<![A-Za-z0-9_.])" + r"\s*\.\s*".join(parts)
+        + r"\s*(?:\.\s*borrow_mut\s*\(\s*\)\s*)?\.\s*(" + ops + r")\s*\("
+    )
+
+
+def leaves_loop(body: str, pos: int) -> bool:
+    return bool(re.match(r"[^;{}]*;\s*(?:break|return|continue)\b", body[pos:]))
+
+
+def emit(path, text, lines, offset, tag):
+    line_no = line_number(text, offset)
+    line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+    if "ubs:ignore" in line:
+        return
+    key = (path, line_no)
+    if key in seen:
+        return
+    seen.add(key)
+    print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for loop in for_re.finditer(masked):
+        brace = loop_header_end(masked, loop.end())
+        if brace < 0:
+            continue
+        close = find_matching_brace(masked, brace)
+        if close < 0:
+            continue
+        expr = masked[loop.end():brace].strip()
+        body = masked[brace:close + 1]
+        ranged = range_re.fullmatch(expr)
+        if ranged:
+            coll = re.sub(r"\s+", "", ranged.group(1))
+            for hit in mutation_re(coll, shrink_ops).finditer(body):
+                if leaves_loop(body, hit.end()):
+                    continue
+                emit(path, masked, lines, brace + hit.start(), coll + "." + hit.group(1) + "() while looping over 0.." + coll + ".len()")
+            continue
+        iterated = iter_re.fullmatch(expr)
+        if iterated:
+            coll = re.sub(r"\s+", "", iterated.group(1) or iterated.group(2))
+            for hit in mutation_re(coll, iter_ops).finditer(body):
+                if leaves_loop(body, hit.end()):
+                    continue
+                emit(path, masked, lines, brace + hit.start(), coll + "." + hit.group(1) + "() while iterating " + coll)
+    for loop in while_re.finditer(masked):
+        brace = loop.end() - 1
+        close = find_matching_brace(masked, brace)
+        if close < 0:
+            continue
+        index = loop.group(1)
+        coll = re.sub(r"\s+", "", loop.group(2))
+        body = masked[brace:close + 1]
+        bumps = [m for m in re.finditer(r"\b" + re.escape(index) + r"\s*\+=\s*1\b", body) if depth_at(body, m.start()) == 1]
+        if not bumps:
+            continue
+        for hit in mutation_re(coll, "remove|swap_remove").finditer(body):
+            call_open = body.find("(", hit.end() - 1)
+            call_close = find_matching_paren(body, call_open)
+            if call_close < 0 or not re.fullmatch(r"\s*" + re.escape(index) + r"\s*", body[call_open + 1:call_close]):
+                continue
+            if leaves_loop(body, hit.end()):
+                continue
+            if not any(b.start() > hit.start() for b in bumps):
+                continue
+            emit(path, masked, lines, brace + hit.start(), coll + "." + hit.group(1) + "(" + index + ") followed by an unconditional " + index + " += 1")
+PY
+}
+
+count_iter_mutation_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_iter_mutation_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_iter_mutation_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_iter_mutation_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_iter_mutation() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_iter_mutation_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_off_by_one_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def find_matching_bracket(text: str, open_index: int) -> int:
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch == "[":
+            depth += 1
+        elif ch == "]":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+path_re = r"(?:[A-Za-z_][A-Za-z0-9_]*\s*\.\s*)*[A-Za-z_][A-Za-z0-9_]*"
+short_loop = re.compile(
+    r"\bfor\s+([A-Za-z_][A-Za-z0-9_]*)\s+in\s+\(?\s*0\s*\.\.\s*(" + path_re + r")\s*\.\s*len\s*\(\s*\)\s*-\s*1\s*\)?\s*\{"
+)
+inclusive_loop = re.compile(
+    r"\bfor\s+([A-Za-z_][A-Za-z0-9_]*)\s+in\s+\(?\s*0\s*\.\.=\s*(" + path_re + r")\s*\.\s*len\s*\(\s*\)\s*\)?\s*\{"
+)
+le_loop = re.compile(r"\bwhile\s+([A-Za-z_][A-Za-z0-9_]*)\s*<=\s*(" + path_re + r")\s*\.\s*len\s*\(\s*\)\s*(?:&&[^{]*)?\{")
+slice_re = re.compile(r"(" + path_re + r")\s*\[")
+seen = set()
+
+
+def squash(expr: str) -> str:
+    return re.sub(r"\s+", "", expr)
+
+
+def emit(path, text, lines, offset, tag):
+    line_no = line_number(text, offset)
+    line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+    if "ubs:ignore" in line:
+        return
+    key = (path, line_no)
+    if key in seen:
+        return
+    seen.add(key)
+    print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if ".len()" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    if mode == "short":
+        for loop in short_loop.finditer(masked):
+            close = find_matching_brace(masked, loop.end() - 1)
+            if close < 0:
+                continue
+            counter, coll = loop.group(1), squash(loop.group(2))
+            body = masked[loop.end():close]
+            esc = re.escape(counter)
+            # Pairwise walks (v[i] vs v[i + 1]) legitimately stop one short.
+            if re.search(r"\b" + esc + r"\s*\+\s*1\b", body):
+                continue
+            if not re.search(r"\b" + re.escape(coll.split(".")[-1]) + r"\s*\[\s*" + esc + r"\s*\]", body):
+                continue
+            emit(path, masked, lines, loop.start(), "0.." + coll + ".len() - 1 never visits the last element")
+    elif mode == "inclusive":
+        for access in slice_re.finditer(masked):
+            coll = squash(access.group(1))
+            if coll in ("vec", "matches", "assert"):
+                continue
+            bracket = access.end() - 1
+            close = find_matching_bracket(masked, bracket)
+            if close < 0:
+                continue
+            inner = squash(masked[bracket + 1:close])
+            tail = coll.split(".")[-1]
+            if re.fullmatch(r"[^\[\]]*\.\.=(?:[A-Za-z0-9_]+\.)*" + re.escape(tail) + r"\.len\(\)", inner):
+                emit(path, masked, lines, access.start(), coll + "[.." + "=" + coll + ".len()] reads one past the end")
+    elif mode == "inclusive_loop":
+        for loop in inclusive_loop.finditer(masked):
+            close = find_matching_brace(masked, loop.end() - 1)
+            if close < 0:
+                continue
+            counter, coll = loop.group(1), squash(loop.group(2))
+            body = masked[loop.end():close]
+            esc, tail = re.escape(counter), re.escape(coll.split(".")[-1])
+            # A sentinel branch on the final pass (if i == v.len() { .. }) keeps v[i] in bounds.
+            if re.search(r"\b" + esc + r"\s*(?:==|<|>=)\s*(?:[A-Za-z0-9_]+\s*\.\s*)*" + tail + r"\s*\.\s*len\s*\(", body):
+                continue
+            if not re.search(r"\b" + tail + r"\s*\[\s*" + esc + r"\s*\]", body):
+                continue
+            emit(path, masked, lines, loop.start(), "0..=" + coll + ".len() indexes " + coll + "[" + counter + "] past the end")
+    else:
+        for loop in le_loop.finditer(masked):
+            close = find_matching_brace(masked, loop.end() - 1)
+            if close < 0:
+                continue
+            counter, coll = loop.group(1), squash(loop.group(2))
+            body = masked[loop.end():close]
+            index = re.search(r"\b" + re.escape(coll.split(".")[-1]) + r"\s*\[\s*" + re.escape(counter) + r"\s*\]", body)
+            if not index:
+                continue
+            emit(path, masked, lines, loop.start(), "while " + counter + " <= " + coll + ".len() indexes " + coll + "[" + counter + "]")
+PY
+}
+
+count_off_by_one_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_off_by_one_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_off_by_one_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_off_by_one_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_off_by_one() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_off_by_one_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_shadowed_result_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_paren, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def find_opening_paren(text: str, close: int) -> int:
+    depth = 0
+    for idx in range(close, -1, -1):
+        ch = text[idx]
+        if ch == ")":
+            depth += 1
+        elif ch == "(":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def statement_end(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            if depth == 0:
+                return -1
+            depth -= 1
+        elif ch == ";" and depth == 0:
+            return idx
+    return -1
+
+
+fn_re = re.compile(r"\bfn\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^>{;]*>)?\s*\(")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?([A-Za-z][A-Za-z0-9_]*)\s*(?::\s*([^=;]+))?=(?!=)")
+guard_re = re.compile(
+    r"\.\s*(?:lock|read|write)\s*\(\s*\)\s*(?:\.\s*(?:unwrap|expect)\s*\((?:.|\n)*\)|\?)\s*$"
+)
+handled_tail = re.compile(
+    r"(?:\?|\.\s*(?:unwrap|expect|ok|err|unwrap_or|unwrap_or_default|unwrap_or_else|is_ok|is_err|map_or|map_or_else)\s*\((?:.|\n)*\))\s*$"
+)
+fallible_head = re.compile(
+    r"(?:\b(?:std\s*::\s*)?fs\s*::\s*[a-z_]+|\bFile\s*::\s*(?:open|create|create_new)|\bserde_json\s*::\s*from_[a-z_]+"
+    r"|\b[A-Za-z_][A-Za-z0-9_]*\s*::\s*try_[a-z_]+|\bTcpStream\s*::\s*connect|\bTcpListener\s*::\s*bind"
+    r"|\.\s*(?:parse(?:\s*::\s*<[^>]*>)?|send|try_send|join|write_all|flush|sync_all|try_into|recv|try_recv|output|status|read_to_string|read_to_end|execute|commit))\s*$"
+)
+seen = set()
+
+
+def depth_between(text: str, start: int, end: int) -> int:
+    depth = 0
+    low = 0
+    for ch in text[start:end]:
+        if ch == "{":
+            depth += 1
+        elif ch == "}":
+            depth -= 1
+            low = min(low, depth)
+    return low
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    result_fns = set()
+    for fn in fn_re.finditer(masked):
+        close = find_matching_paren(masked, fn.end() - 1)
+        if close < 0:
+            continue
+        brace = masked.find("{", close)
+        semi = masked.find(";", close)
+        stop = brace if brace >= 0 and not (0 <= semi < brace) else semi
+        if stop >= 0 and re.search(r"->\s*(?:[A-Za-z_:]*::)?Result\b", masked[close:stop]):
+            result_fns.add(fn.group(1))
+
+    def kind_of(ty, init):
+        init = init.strip()
+        if guard_re.search(init):
+            return "lock guard"
+        if ty and re.search(r"\bResult\b", ty):
+            return "Result"
+        if handled_tail.search(init) or not init.endswith(")"):
+            return None
+        opening = find_opening_paren(init, len(init) - 1)
+        if opening < 0:
+            return None
+        head = init[:opening]
+        if fallible_head.search(head):
+            return "Result"
+        local = re.search(r"(?:^|[^A-Za-z0-9_])(?:self\s*\.\s*|Self\s*::\s*)?([A-Za-z_][A-Za-z0-9_]*)\s*$", head)
+        if local and local.group(1) in result_fns:
+            return "Result"
+        return None
+
+    bindings = []
+    for binding in let_re.finditer(masked):
+        end = statement_end(masked, binding.end())
+        if end < 0:
+            continue
+        bindings.append((binding, end))
+    for idx, (binding, end) in enumerate(bindings):
+        name = binding.group(1)
+        kind = kind_of(binding.group(2), masked[binding.end():end])
+        if not kind:
+            continue
+        for later, later_end in bindings[idx + 1:]:
+            if later.group(1) != name:
+                continue
+            # The rebinding must sit in the same block (or a nested one).
+            if depth_between(masked, end, later.start()) < 0:
+                break
+            between = masked[end + 1:later.start()] + masked[later.end():later_end]
+            if re.search(r"(?<![A-Za-z0-9_])" + re.escape(name) + r"(?![A-Za-z0-9_])", between):
+                break
+            line_no = line_number(masked, binding.start())
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                break
+            key = (path, line_no)
+            if key in seen:
+                break
+            seen.add(key)
+            later_line = line_number(masked, later.start())
+            print(f"{path}:{line_no}:{line.strip()}  [{kind} in {name} shadowed at line {later_line} before use]")
+            break
+PY
+}
+
+count_shadowed_result_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_shadowed_result_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_shadowed_result_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_shadowed_result_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_shadowed_result() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_shadowed_result_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_constant_condition_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def header_end(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([":
+            depth += 1
+        elif ch in ")]":
+            depth -= 1
+        elif ch == "{" and depth == 0:
+            return idx
+        elif ch == ";" and depth == 0:
+            return -1
+    return -1
+
+
+def split_top(expr: str, op: str):
+    parts = []
+    depth = 0
+    last = 0
+    idx = 0
+    while idx < len(expr):
+        ch = expr[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif depth == 0 and expr.startswith(op, idx):
+            parts.append(expr[last:idx])
+            idx += len(op)
+            last = idx
+            continue
+        idx += 1
+    parts.append(expr[last:])
+    return parts
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{;]*>)?\s*\(")
+path_re = r"(?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*"
+uint = r"(?:u8|u16|u32|u64|u128|usize)"
+lead = r"(?:^|[(\[{,;!&|]|(?<![=!<>])=|\bif|\bwhile|\breturn|=>)\s*$"
+unsigned_cmp = re.compile(
+    r"(?<![A-Za-z0-9_.])(" + path_re + r"(?:\s*\.\s*(?:len|count|capacity)\s*\(\s*\))?)\s*(>=|<)\s*0(?![.\dxXbBoO_A-Za-z])"
+    r"|(?<![A-Za-z0-9_.])0\s*(<=|>)\s*(" + path_re + r"(?:\s*\.\s*(?:len|count|capacity)\s*\(\s*\))?)(?![A-Za-z0-9_(.\[])"
+)
+self_cmp = re.compile(r"(?<![A-Za-z0-9_.])(" + path_re + r")\s*(==|!=|<=|>=|<|>)\s*(" + path_re + r")(?![A-Za-z0-9_(.\[:!<])")
+cond_re = re.compile(r"\b(?:if|while)\s+(?!let\b)")
+term_re = re.compile(r"\(?\s*(" + path_re + r")\s*(==|!=|<=|>=|<|>)\s*(-?\d[\d_]*)(?:[iu](?:8|16|32|64|128|size))?\s*\)?")
+term_rev = re.compile(r"\(?\s*(-?\d[\d_]*)(?:[iu](?:8|16|32|64|128|size))?\s*(==|!=|<=|>=|<|>)\s*(" + path_re + r")\s*\)?")
+flip = {"<": ">", ">": "<", "<=": ">=", ">=": "<=", "==": "==", "!=": "!="}
+seen = set()
+
+
+def squash(expr: str) -> str:
+    return re.sub(r"\s+", "", expr)
+
+
+def emit(path, text, lines, offset, tag):
+    line_no = line_number(text, offset)
+    line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+    if "ubs:ignore" in line:
+        return
+    key = (path, line_no)
+    if key in seen:
+        return
+    seen.add(key)
+    print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
+
+
+def parse_term(term: str):
+    term = term.strip()
+    hit = term_re.fullmatch(term)
+    if hit:
+        return squash(hit.group(1)), hit.group(2), int(hit.group(3).replace("_", ""))
+    hit = term_rev.fullmatch(term)
+    if hit:
+        return squash(hit.group(3)), flip[hit.group(2)], int(hit.group(1).replace("_", ""))
+    return None
+
+
+def interval(op: str, value: int):
+    inf = float("inf")
+    return {
+        "==": (value, value), "<": (-inf, value - 1), "<=": (-inf, value),
+        ">": (value + 1, inf), ">=": (value, inf),
+    }.get(op)
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        params = masked[fn.end():close_paren]
+        body = masked[brace:end + 1]
+        scope = params + ";" + body
+        if mode == "unsigned":
+            unsigned = set(re.findall(r"\b([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(?:&\s*(?:mut\s+)?)?" + uint + r"\b(?!\s*[\[<])", scope))
+            unsigned |= set(re.findall(r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*=[^;]*(?:\.\s*(?:len|count|capacity)\s*\(\s*\)|\bas\s+" + uint + r")\s*;", body))
+            for hit in unsigned_cmp.finditer(body):
+                operand = squash(hit.group(1) or hit.group(4))
+                op = hit.group(2) or hit.group(3)
+                if not re.search(lead, body[max(0, hit.start() - 40):hit.start()]):
+                    continue
+                last = operand.split(".")[-1]
+                if last.endswith(")"):
+                    kind = last[:-2] + "()"
+                elif last in unsigned:
+                    kind = last
+                else:
+                    continue
+                always = "true" if op in (">=", "<=") else "false"
+                shown = (operand + " " + op + " 0") if hit.group(1) else ("0 " + op + " " + operand)
+                emit(path, masked, lines, brace + hit.start(), kind + " is unsigned: " + shown + " is always " + always)
+        elif mode == "self":
+            floats = set(re.findall(r"\b([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(?:&\s*)?f(?:32|64)\b", scope))
+            for hit in self_cmp.finditer(body):
+                left, op, right = squash(hit.group(1)), hit.group(2), squash(hit.group(3))
+                if left != right or left in ("true", "false", "self"):
+                    continue
+                if not re.search(lead, body[max(0, hit.start() - 40):hit.start()]):
+                    continue
+                if op in ("==", "!=") and left.split(".")[-1] in floats:
+                    continue
+                always = "true" if op in ("==", "<=", ">=") else "false"
+                emit(path, masked, lines, brace + hit.start(), left + " " + op + " " + right + " compares a value with itself (always " + always + ")")
+        else:
+            for cond in cond_re.finditer(body):
+                stop = header_end(body, cond.end())
+                if stop < 0:
+                    continue
+                expr = body[cond.end():stop].strip()
+                ands = split_top(expr, "&&")
+                ors = split_top(expr, "||")
+                if len(ands) > 1 and len(ors) == 1:
+                    bounds = {}
+                    for term in ands:
+                        parsed = parse_term(term)
+                        if not parsed or parsed[1] == "!=":
+                            continue
+                        name, op, value = parsed
+                        lo, hi = interval(op, value)
+                        cur_lo, cur_hi = bounds.get(name, (float("-inf"), float("inf")))
+                        bounds[name] = (max(cur_lo, lo), min(cur_hi, hi))
+                    dead = [name for name, (lo, hi) in bounds.items() if lo > hi]
+                    if dead:
+                        emit(path, masked, lines, brace + cond.start(), "&& chain on " + dead[0] + " can never be true")
+                elif len(ors) > 1 and len(ands) == 1:
+                    excluded = {}
+                    for term in ors:
+                        parsed = parse_term(term)
+                        if parsed and parsed[1] == "!=":
+                            excluded.setdefault(parsed[0], set()).add(parsed[2])
+                    always = [name for name, values in excluded.items() if len(values) > 1]
+                    if always:
+                        emit(path, masked, lines, brace + cond.start(), "|| of != on " + always[0] + " is always true")
+PY
+}
+
+count_constant_condition_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_constant_condition_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_constant_condition_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_constant_condition_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_constant_condition() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_constant_condition_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_match_arm_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def match_arms(text: str, open_index: int, close_index: int):
+    """Yield (pattern, body_start, body_end) for each top-level arm of a match body."""
+    i = open_index + 1
+    while i < close_index:
+        depth = 0
+        arrow = -1
+        j = i
+        while j < close_index:
+            ch = text[j]
+            if ch in "([{":
+                depth += 1
+            elif ch in ")]}":
+                depth -= 1
+            elif depth == 0 and text.startswith("=>", j):
+                arrow = j
+                break
+            j += 1
+        if arrow < 0:
+            return
+        pattern = text[i:arrow].strip()
+        j = arrow + 2
+        while j < close_index and text[j].isspace():
+            j += 1
+        if j < close_index and text[j] == "{":
+            end = find_matching_brace(text, j)
+            if end < 0 or end > close_index:
+                return
+            k = end + 1
+            while k < close_index and text[k].isspace():
+                k += 1
+            if k < close_index and text[k] == ",":
+                k += 1
+            yield pattern, j, end
+            i = k
+            continue
+        depth = 0
+        k = j
+        while k < close_index:
+            ch = text[k]
+            if ch in "([{":
+                depth += 1
+            elif ch in ")]}":
+                depth -= 1
+            elif ch == "," and depth == 0:
+                break
+            k += 1
+        yield pattern, j, k
+        i = k + 1
+
+
+def header_end(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([":
+            depth += 1
+        elif ch in ")]":
+            depth -= 1
+        elif ch == "{" and depth == 0:
+            return idx
+        elif ch == ";" and depth == 0:
+            return -1
+    return -1
+
+
+def split_top(expr: str, sep: str):
+    parts = []
+    depth = 0
+    last = 0
+    for idx, ch in enumerate(expr):
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif ch == sep and depth == 0:
+            if sep == "|" and (expr[idx - 1:idx] == "|" or expr[idx + 1:idx + 2] == "|"):
+                continue
+            parts.append(expr[last:idx])
+            last = idx + 1
+    parts.append(expr[last:])
+    return parts
+
+
+match_re = re.compile(r"\bmatch\s+")
+enum_re = re.compile(r"((?:#\s*\[[^\]]*\]\s*)*)(?:pub(?:\s*\([^)]*\))?\s+)?enum\s+([A-Za-z_][A-Za-z0-9_]*)")
+placeholder = r"(?:\(\)|None|false|true|-?\d+|[A-Za-z_:]*default\(\)|Vec::new\(\)|String::new\(\))"
+silent_body = re.compile(r"(?:return)?(?:|continue|break|" + placeholder + r"|Ok\(" + placeholder + r"\))")
+seen = set()
+
+
+def squash(expr: str) -> str:
+    return re.sub(r"\s+", "", expr)
+
+
+def split_guard(pattern: str):
+    depth = 0
+    for idx in range(len(pattern)):
+        ch = pattern[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif depth == 0 and re.match(r"\bif\b", pattern[idx:]) and (idx == 0 or not (pattern[idx - 1].isalnum() or pattern[idx - 1] == "_")):
+            return pattern[:idx].strip(), pattern[idx + 2:].strip()
+    return pattern.strip(), ""
+
+
+def emit(path, text, lines, offset, tag):
+    line_no = line_number(text, offset)
+    line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+    if "ubs:ignore" in line:
+        return
+    key = (path, line_no)
+    if key in seen:
+        return
+    seen.add(key)
+    print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
+
+
+sources = []
+local_enums = set()
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    sources.append((path, text, masked))
+    for enum in enum_re.finditer(masked):
+        if "non_exhaustive" not in enum.group(1):
+            local_enums.add(enum.group(2))
+
+for path, text, masked in sources:
+    if "match" not in masked:
+        continue
+    lines = text.splitlines()
+    for head in match_re.finditer(masked):
+        open_brace = header_end(masked, head.end())
+        if open_brace < 0:
+            continue
+        close_brace = find_matching_brace(masked, open_brace)
+        if close_brace < 0:
+            continue
+        scrutinee = masked[head.end():open_brace]
+        arms = []
+        for _pattern, arm_start, arm_end in match_arms(masked, open_brace, close_brace):
+            arms.append((arm_start, arm_end))
+        prev = open_brace + 1
+        parsed = []
+        for arm_start, arm_end in arms:
+            raw = text[prev:arm_start]
+            arrow = raw.rfind("=>")
+            pattern_raw = re.sub(r"//[^\n]*", "", raw[:arrow]).strip().lstrip(",").strip()
+            pattern_raw = re.sub(r"^(?:#\s*\[[^\]]*\]\s*)+", "", pattern_raw)
+            core, guard = split_guard(pattern_raw)
+            offset = prev + (len(raw[:arrow]) - len(raw[:arrow].lstrip(", \n\t")))
+            parsed.append((squash(core), guard, offset, squash(masked[arm_start:arm_end]), arm_start))
+            prev = arm_end + 1
+        if mode == "unreachable":
+            catch_all = None
+            matched = {}
+            for core, guard, offset, body, arm_start in parsed:
+                if catch_all is not None:
+                    emit(path, masked, lines, arm_start, "arm after the catch-all " + catch_all[0] + " at line " + str(catch_all[1]) + " never runs")
+                    continue
+                alternatives = [alt.strip() for alt in split_top(core, "|") if alt.strip()]
+                if alternatives and all(alt in matched for alt in alternatives):
+                    emit(path, masked, lines, arm_start, "pattern " + alternatives[0] + " already matched at line " + str(matched[alternatives[0]]))
+                    continue
+                if guard:
+                    continue
+                if core == "_" or (re.fullmatch(r"[a-z_][a-z0-9_]*", core) and core not in ("true", "false")):
+                    catch_all = (core, line_number(masked, arm_start))
+                for alt in alternatives:
+                    matched.setdefault(alt, line_number(masked, arm_start))
+        else:
+            enum_names = set()
+            for core, _guard, _offset, _body, _start in parsed:
+                enum_names.update(name for name in re.findall(r"\b([A-Z][A-Za-z0-9_]*)\s*::\s*[A-Z]", core) if name in local_enums)
+            error_enums = [name for name in enum_names if re.search(r"(?:Error|Err)$", name)]
+            if not error_enums and not (enum_names and re.search(r"(?i)\berr(?:or)?\b|\.kind\s*\(", scrutinee)):
+                continue
+            name = (error_enums or sorted(enum_names))[0]
+            for core, guard, offset, body, arm_start in parsed:
+                if guard or core not in ("_", "Err(_)"):
+                    continue
+                if not silent_body.fullmatch(body.strip("{},;")):
+                    continue
+                emit(path, masked, lines, arm_start, core + " silently swallows the remaining " + name + " variants")
+PY
+}
+
+count_match_arm_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_match_arm_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_match_arm_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_match_arm_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_match_arm() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_match_arm_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_non_exhaustive_match_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def match_arms(text: str, open_index: int, close_index: int):
+    """Yield (pattern, body_start, body_end) for each top-level arm of a match body."""
+    i = open_index + 1
+    while i < close_index:
+        depth = 0
+        arrow = -1
+        j = i
+        while j < close_index:
+            ch = text[j]
+            if ch in "([{":
+                depth += 1
+            elif ch in ")]}":
+                depth -= 1
+            elif depth == 0 and text.startswith("=>", j):
+                arrow = j
+                break
+            j += 1
+        if arrow < 0:
+            return
+        pattern = text[i:arrow].strip()
+        j = arrow + 2
+        while j < close_index and text[j].isspace():
+            j += 1
+        if j < close_index and text[j] == "{":
+            end = find_matching_brace(text, j)
+            if end < 0 or end > close_index:
+                return
+            k = end + 1
+            while k < close_index and text[k].isspace():
+                k += 1
+            if k < close_index and text[k] == ",":
+                k += 1
+            yield pattern, j, end
+            i = k
+            continue
+        depth = 0
+        k = j
+        while k < close_index:
+            ch = text[k]
+            if ch in "([{":
+                depth += 1
+            elif ch in ")]}":
+                depth -= 1
+            elif ch == "," and depth == 0:
+                break
+            k += 1
+        yield pattern, j, k
+        i = k + 1
+
+
+def header_end(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([":
+            depth += 1
+        elif ch in ")]":
+            depth -= 1
+        elif ch == "{" and depth == 0:
+            return idx
+        elif ch == ";" and depth == 0:
+            return -1
+    return -1
+
+
+# Enums the standard library and common crates mark #[non_exhaustive].
+known_non_exhaustive = {
+    "ErrorKind", "IntErrorKind", "BacktraceStatus", "Ipv6MulticastScope",
+    "Expr", "Item", "Pat", "Type", "Lit", "Stmt", "Meta", "ImplItem", "TraitItem", "ForeignItem",
+    "Category",
+}
+known_paths = re.compile(r"\b(?:io|std::io|num|std::num|backtrace|syn|serde_json::error)\s*::\s*$")
+match_re = re.compile(r"\bmatch\s+")
+enum_re = re.compile(r"((?:#\s*\[[^\]]*\]\s*)*)(?:pub(?:\s*\([^)]*\))?\s+)?enum\s+([A-Za-z_][A-Za-z0-9_]*)")
+variant_re = re.compile(r"((?:[A-Za-z_][A-Za-z0-9_]*\s*::\s*)*)([A-Z][A-Za-z0-9_]*)\s*::\s*[A-Z][A-Za-z0-9_]*")
+panic_body = re.compile(r"\{?\s*(?:unreachable|panic|todo|unimplemented)\s*!\s*\(")
+seen = set()
+
+
+def crate_of(path: Path) -> Path:
+    for parent in path.parents:
+        if (parent / "Cargo.toml").is_file():
+            return parent
+        if parent == root:
+            break
+    return root
+
+
+def emit(path, text, lines, offset, tag):
+    line_no = line_number(text, offset)
+    line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+    if "ubs:ignore" in line:
+        return
+    key = (path, line_no)
+    if key in seen:
+        return
+    seen.add(key)
+    print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
+
+
+sources = []
+workspace_enums = {}
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    sources.append((path, text, masked))
+    for enum in enum_re.finditer(masked):
+        if "non_exhaustive" in enum.group(1):
+            workspace_enums.setdefault(enum.group(2), set()).add(crate_of(path))
+
+for path, text, masked in sources:
+    if "match" not in masked:
+        continue
+    lines = text.splitlines()
+    here = crate_of(path)
+    for head in match_re.finditer(masked):
+        open_brace = header_end(masked, head.end())
+        if open_brace < 0:
+            continue
+        close_brace = find_matching_brace(masked, open_brace)
+        if close_brace < 0:
+            continue
+        enum_name = None
+        catch_all = None
+        prev = open_brace + 1
+        for _pattern, arm_start, arm_end in match_arms(masked, open_brace, close_brace):
+            raw = masked[prev:arm_start]
+            arrow = raw.rfind("=>")
+            # A variant named only in the `if` guard is compared, not matched on.
+            pattern = re.split(r"\bif\b", raw[:arrow], maxsplit=1)[0]
+            pattern = re.sub(r"\s+", "", pattern).lstrip(",")
+            pattern = re.sub(r"^(?:#\[[^\]]*\])+", "", pattern)
+            prev = arm_end + 1
+            if pattern == "_" or re.fullmatch(r"[a-z_][a-z0-9_]*", pattern) or pattern in ("Err(_)", "Some(_)"):
+                if catch_all is None:
+                    catch_all = (arm_start, masked[arm_start:arm_end])
+                continue
+            for variant in variant_re.finditer(pattern):
+                name = variant.group(2)
+                qualifier = variant.group(1)
+                if name in workspace_enums and here not in workspace_enums[name]:
+                    enum_name = name
+                elif name in known_non_exhaustive and name not in workspace_enums:
+                    if name in ("ErrorKind", "IntErrorKind", "BacktraceStatus", "Ipv6MulticastScope") or known_paths.search(qualifier):
+                        enum_name = name
+                if enum_name:
+                    break
+        if not enum_name:
+            continue
+        if mode == "missing":
+            if catch_all is None:
+                emit(path, masked, lines, head.start(), "match on #[non_exhaustive] " + enum_name + " has no wildcard arm")
+        elif catch_all is not None and panic_body.match(catch_all[1].lstrip()):
+            emit(path, masked, lines, catch_all[0], "wildcard arm for #[non_exhaustive] " + enum_name + " panics")
+PY
+}
+
+count_non_exhaustive_match_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_non_exhaustive_match_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_non_exhaustive_match_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_non_exhaustive_match_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_non_exhaustive_match() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_non_exhaustive_match_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_test_sleep_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+test_attr = re.compile(r"#\s*\[\s*((?:[A-Za-z_][A-Za-z0-9_]*\s*::\s*)?test)\b\s*(\([^\]]*\))?\s*\]")
+fn_re = re.compile(r"\bfn\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^>{;]*>)?\s*\(")
+sleep_re = re.compile(
+    r"(?:\b(?:std\s*::\s*)?thread\s*::\s*sleep|\b(?:tokio\s*::\s*)?time\s*::\s*sleep|\basync_std\s*::\s*task\s*::\s*sleep|(?<![A-Za-z0-9_.:])sleep)\s*\("
+)
+spawn_re = re.compile(r"\b(?:spawn|spawn_blocking|scope)\s*\(|\.\s*send\s*\(|\bstart_server\b|\bspawn_[a-z_]+\s*\(")
+paused_re = re.compile(r"\btime\s*::\s*(?:pause|advance)\s*\(|\bstart_paused\s*=\s*true|\bMockClock\b|\bfake_clock\b")
+seen = set()
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "test" not in text or "sleep" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    spawners = set()
+    for fn in fn_re.finditer(masked):
+        brace = masked.find("{", fn.end())
+        semi = masked.find(";", fn.end())
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end > 0 and re.search(r"\b(?:spawn|spawn_blocking)\s*\(", masked[brace:end]):
+            spawners.add(fn.group(1))
+    for attr in test_attr.finditer(masked):
+        fn = fn_re.search(masked, attr.end())
+        if not fn or masked[attr.end():fn.start()].count(";"):
+            continue
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        if brace < 0:
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        if paused_re.search(body) or paused_re.search(masked[attr.start():brace]):
+            continue
+        concurrent = bool(spawn_re.search(body)) or any(
+            re.search(r"(?<![A-Za-z0-9_])" + re.escape(name) + r"\s*\(", body) for name in spawners if name != fn.group(1)
+        )
+        if (mode == "spawn") != concurrent:
+            continue
+        for hit in sleep_re.finditer(body):
+            line_no = line_number(masked, brace + hit.start())
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            kind = "tokio::time::sleep" if re.search(r"time\s*::\s*sleep|\.await", masked[brace + hit.start():masked.find(";", brace + hit.start())]) else "thread::sleep"
+            attr_name = re.sub(r"\s+", "", attr.group(1))
+            what = "waits for spawned work" if concurrent else "sleeps on the real clock"
+            print(f"{path}:{line_no}:{line.strip()}  [{kind} in #[{attr_name}] {fn.group(1)}() {what}]")
+PY
+}
+
+count_test_sleep_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_test_sleep_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_test_sleep_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_test_sleep_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_test_sleep() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_test_sleep_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_clock_panic_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{;]*>)?\s*\(")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::\s*([^=;]+))?=([^;]*);")
+systime_src = re.compile(r"\bSystemTime\s*::\s*now\s*\(|\.\s*(?:modified|accessed|created)\s*\(|\bUNIX_EPOCH\b|\bSystemTime\b")
+instant_src = re.compile(r"\bInstant\s*::\s*now\s*\(|\bInstant\b")
+duration_src = re.compile(r"\bDuration\s*::|\bDuration\b")
+since_re = re.compile(r"\.\s*(duration_since|elapsed)\s*\(")
+panic_tail = re.compile(r"\s*\.\s*(unwrap|expect)\s*\(")
+sub_re = re.compile(r"(?<![-=<>!])-(?![=>])")
+path_re = r"[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*"
+seen = set()
+
+
+def emit(path, text, lines, offset, tag):
+    line_no = line_number(text, offset)
+    line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+    if "ubs:ignore" in line:
+        return
+    key = (path, line_no)
+    if key in seen:
+        return
+    seen.add(key)
+    print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
+
+
+def receiver(body: str, dot: int) -> str:
+    idx = dot
+    depth = 0
+    while idx > 0:
+        ch = body[idx - 1]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                break
+            depth -= 1
+        elif depth == 0 and not (ch.isalnum() or ch in "_.: \t\n"):
+            break
+        idx -= 1
+    return body[idx:dot].strip()
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "SystemTime" not in text and "Instant" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        params = masked[fn.end():close_paren]
+        body = masked[brace:end + 1]
+        systimes, instants, durations = set(), set(), set()
+        for name, ty in re.findall(r"\b([A-Za-z_][A-Za-z0-9_]*)\s*:\s*&?\s*((?:[A-Za-z_]+::)*[A-Za-z_]+)", params):
+            if ty.endswith("SystemTime"):
+                systimes.add(name)
+            elif ty.endswith("Instant"):
+                instants.add(name)
+            elif ty.endswith("Duration"):
+                durations.add(name)
+        for stmt in let_re.finditer(body):
+            name, ty, init = stmt.group(1), stmt.group(2) or "", stmt.group(3)
+            source = ty + " " + init
+            if instant_src.search(source) and "elapsed" not in init and "duration_since" not in init:
+                instants.add(name)
+            elif systime_src.search(source) and "duration_since" not in init and "elapsed" not in init:
+                systimes.add(name)
+            elif duration_src.search(source):
+                durations.add(name)
+        if mode == "systime":
+            for hit in since_re.finditer(body):
+                recv = receiver(body, hit.start())
+                recv_name = re.sub(r"\s+", "", recv)
+                if not (systime_src.search(recv) or recv_name in systimes or recv_name.split(".")[-1] in systimes):
+                    continue
+                if instant_src.search(recv) or recv_name in instants:
+                    continue
+                call_close = find_matching_paren(body, hit.end() - 1)
+                if call_close < 0:
+                    continue
+                arg = body[hit.end():call_close]
+                tail = panic_tail.match(body, call_close + 1)
+                if not tail:
+                    continue
+                if hit.group(1) == "duration_since" and re.search(r"\bUNIX_EPOCH\b", arg):
+                    continue
+                emit(path, masked, lines, brace + hit.start(), "SystemTime::" + hit.group(1) + ("(..)." if hit.group(1) == "duration_since" else "().") + tail.group(1) + "() panics if the wall clock moved backwards")
+        else:
+            for hit in sub_re.finditer(body):
+                lhs = receiver(body, hit.start())
+                lhs_name = re.sub(r"\s+", "", lhs)
+                if not (re.search(r"\bInstant\s*::\s*now\s*\(\s*\)$", lhs_name) or lhs_name in instants):
+                    continue
+                rhs = re.match(r"\s*(" + path_re + r"(?:\s*::\s*[A-Za-z_][A-Za-z0-9_]*)*\s*(?:\([^;{}]*\))?)", body[hit.end():])
+                if not rhs:
+                    continue
+                rhs_text = rhs.group(1)
+                rhs_name = re.sub(r"\s+", "", rhs_text.split("(")[0])
+                if not (duration_src.search(rhs_text) or rhs_name in durations):
+                    continue
+                emit(path, masked, lines, brace + hit.start(), "Instant - Duration panics if it reaches before the clock's origin; use checked_sub")
+PY
+}
+
+count_clock_panic_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_clock_panic_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_clock_panic_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_clock_panic_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_clock_panic() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_clock_panic_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_fallible_conversion_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def receiver(body: str, dot: int) -> str:
+    idx = dot
+    depth = 0
+    while idx > 0:
+        ch = body[idx - 1]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                break
+            depth -= 1
+        elif depth == 0 and not (ch.isalnum() or ch in "_.:"):
+            break
+        idx -= 1
+    return body[idx:dot].strip()
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{;]*>)?\s*\(")
+try_into_re = re.compile(r"\.\s*try_into\s*\(\s*\)")
+try_from_re = re.compile(r"\b((?:[A-Za-z_][A-Za-z0-9_]*\s*::\s*)*[A-Za-z_][A-Za-z0-9_]*(?:\s*::\s*<[^>]*>)?)\s*::\s*try_from\s*\(")
+nonzero_re = re.compile(r"\b((?:std\s*::\s*num\s*::\s*)?NonZero(?:U8|U16|U32|U64|U128|Usize|I8|I16|I32|I64|I128|Isize)?(?:\s*::\s*<[^>]*>)?)\s*::\s*new\s*\(")
+panic_tail = re.compile(r"\s*\.\s*(unwrap|expect)\s*\(")
+constant = re.compile(r"-?\s*(?:\d[\d_]*(?:[iu](?:8|16|32|64|128|size))?|0x[0-9a-fA-F_]+|[A-Z][A-Z0-9_]*(?:\s*::\s*[A-Z][A-Z0-9_]*)?|(?:[A-Za-z_][A-Za-z0-9_]*\s*::\s*)+(?:MAX|MIN)|b'.'|'.')")
+seen = set()
+
+
+def emit(path, text, lines, offset, tag):
+    line_no = line_number(text, offset)
+    line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+    if "ubs:ignore" in line:
+        return
+    key = (path, line_no)
+    if key in seen:
+        return
+    seen.add(key)
+    print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
+
+
+def squash(expr: str) -> str:
+    return re.sub(r"\s+", "", expr)
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "try_" not in text and "NonZero" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        if brace < 0 or ";" in re.sub(r"\[[^\]]*\]", "", masked[close_paren:brace]):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        for hit in try_into_re.finditer(body):
+            if not panic_tail.match(body, hit.end()):
+                continue
+            recv = receiver(body, hit.start())
+            if not recv or constant.fullmatch(recv):
+                continue
+            emit(path, masked, lines, brace + hit.start(), "try_into() on " + squash(recv) + " unwrapped")
+        for hit in try_from_re.finditer(body):
+            call_close = find_matching_paren(body, hit.end() - 1)
+            if call_close < 0 or not panic_tail.match(body, call_close + 1):
+                continue
+            arg = body[hit.end():call_close].strip()
+            if not arg or constant.fullmatch(arg):
+                continue
+            emit(path, masked, lines, brace + hit.start(), squash(hit.group(1)) + "::try_from(" + squash(arg) + ") unwrapped")
+        for hit in nonzero_re.finditer(body):
+            call_close = find_matching_paren(body, hit.end() - 1)
+            if call_close < 0 or not panic_tail.match(body, call_close + 1):
+                continue
+            arg = body[hit.end():call_close].strip()
+            if not arg or constant.fullmatch(arg):
+                continue
+            name = re.escape(squash(arg))
+            if re.search(r"\b" + name + r"\s*(?:!=|>)\s*0\b|\b" + name + r"\s*==\s*0\s*\{\s*return|\.max\s*\(\s*1\s*\)", body[:hit.start()]) or re.search(r"\.\s*max\s*\(\s*1\s*\)\s*$", arg):
+                continue
+            emit(path, masked, lines, brace + hit.start(), squash(hit.group(1)).split("::")[-1] + "::new(" + squash(arg) + ") unwrapped")
+PY
+}
+
+count_fallible_conversion_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_fallible_conversion_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_fallible_conversion_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_fallible_conversion_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_fallible_conversion() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_fallible_conversion_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+
+rust_secret_logging_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def find_matching_paren(text: str, start: int) -> int:
+    depth = 0
+    for idx in range(start, len(text)):
+        ch = text[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+def split_args(text: str, masked: str, start: int, end: int):
+    args = []
+    depth = 0
+    begin = start
+    for idx in range(start, end):
+        ch = masked[idx]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif ch == "," and depth == 0:
+            args.append((begin, idx))
+            begin = idx + 1
+    if text[begin:end].strip():
+        args.append((begin, end))
+    return args
+
+
+macro_re = re.compile(
+    r"(?<![A-Za-z0-9_])(?:(?:log|tracing)\s*::\s*)?"
+    r"(panic|unreachable|print|println|eprint|eprintln|dbg|trace|debug|info|warn|error|event)\s*!\s*\("
+)
+expect_re = re.compile(r"\.\s*expect\s*\(\s*&?\s*format\s*!\s*\(")
+literal_re = re.compile(r'"((?:[^"\\]|\\.)*)"')
+placeholder_re = re.compile(r"\{([A-Za-z_][A-Za-z0-9_]*|\d+)?(?::([^}]*))?\}")
+key_re = re.compile(r"^\s*[%?]?\s*([A-Za-z_][A-Za-z0-9_.]*)\s*=(?!=)\s*(.*)$", re.S)
+ident_re = re.compile(r"[A-Za-z_][A-Za-z0-9_]*")
+secret_name_re = re.compile(
+    r"(?:^|_)(?:password|passwd|passphrase|pwd|secret|token|credentials?|bearer|jwt|"
+    r"api_?key|private_?key|access_?key|secret_?key|signing_?key|client_?secret|authorization)(?:$|_)"
+)
+benign_part_re = re.compile(
+    r"(?:^|_)(?:count|len|length|num|max|min|id|ids|kind|type|name|names|path|file|url|uri|endpoint|"
+    r"expiry|expires|expired|ttl|index|idx|limit|prefix|hash|hashed|digest|fingerprint|redacted|masked|"
+    r"is|has|valid|present|missing|required|source|env|var|header_name)(?:$|_)"
+)
+redact_re = re.compile(
+    r"\b(?:redact\w*|mask\w*|hash\w*|sha\d*\w*|fingerprint\w*|len|is_empty|is_some|is_none|is_ok|is_err|"
+    r"chars\s*\(\s*\)\s*\.\s*count)\b|\bRedacted\b"
+)
+lexer_re = re.compile(r"\b(?:enum|struct)\s+Token\b|\bToken\s*::|\bTokenKind\b|\blexer\b|\bLexer\b|\btokenize\b")
+secrecy_re = re.compile(r"\bsecrecy\b|\bSecretString\b|\bSecretBox\b|\bSecret\s*<")
+seen = set()
+
+
+def secret_ident(expr: str, lexer: bool):
+    if redact_re.search(expr):
+        return None
+    found = None
+    for ident in ident_re.findall(expr):
+        lowered = re.sub(r"(?<=[a-z0-9])([A-Z])", r"_\1", ident).lower()
+        if not secret_name_re.search(lowered) or benign_part_re.search(lowered):
+            continue
+        if lexer and lowered in ("token", "tokens"):
+            continue
+        found = ident
+    return found
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    lowered_text = text.lower()
+    if not any(word in lowered_text for word in ("password", "passwd", "pwd", "secret", "token", "key", "credential", "bearer", "jwt", "authorization")):
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    lexer = bool(lexer_re.search(masked))
+    secrecy = bool(secrecy_re.search(masked))
+    calls = []
+    for hit in macro_re.finditer(masked):
+        calls.append((hit.start(), hit.end() - 1, hit.group(1) + "!()"))
+    for hit in expect_re.finditer(masked):
+        calls.append((hit.start(), hit.end() - 1, "expect(format!())"))
+    for start, open_paren, label in sorted(calls):
+        close = find_matching_paren(masked, open_paren)
+        if close < 0:
+            continue
+        args = split_args(text, masked, open_paren + 1, close)
+        fmt_index = None
+        specs = []
+        for idx, (a_start, a_end) in enumerate(args):
+            if masked[a_start:a_end].strip() == "" and '"' in text[a_start:a_end]:
+                lit = literal_re.search(text, a_start, a_end)
+                if lit:
+                    fmt_index = idx
+                    body = lit.group(1).replace("{{", "").replace("}}", "")
+                    specs = [(ph.group(1), ph.group(2) or "") for ph in placeholder_re.finditer(body)]
+                break
+        leaked = None
+        # Inline {name} placeholders in the format string capture variables directly.
+        for name, spec in specs:
+            if name and not name.isdigit():
+                ident = secret_ident(name, lexer)
+                if ident and not (secrecy and "?" in spec):
+                    leaked = ident
+                    break
+        positional = [spec for name, spec in specs if not name]
+        pos = 0
+        if not leaked:
+            value_args = args[fmt_index + 1:] if fmt_index is not None else args
+            if fmt_index is None and label not in ("dbg!()", "trace!()", "debug!()", "info!()", "warn!()", "error!()", "event!()"):
+                value_args = []
+            for a_start, a_end in value_args:
+                expr = masked[a_start:a_end]
+                if '"' in text[a_start:a_end] and not expr.strip():
+                    continue
+                keyed = key_re.match(expr)
+                spec = ""
+                if keyed:
+                    key, value = keyed.group(1), keyed.group(2)
+                    if re.match(r"^\s*(?:target|parent|name)\s*$", key):
+                        continue
+                    if not value.strip() or redact_re.search(value):
+                        continue
+                    ident = secret_ident(value, lexer) or secret_ident(key.split(".")[-1], lexer)
+                    spec = "?" if value.lstrip().startswith("?") or expr.lstrip().startswith("?") else ""
+                else:
+                    ident = secret_ident(expr, lexer)
+                    if fmt_index is not None:
+                        spec = positional[pos] if pos < len(positional) else ""
+                        pos += 1
+                if ident and not (secrecy and "?" in spec):
+                    leaked = ident
+                    break
+        if not leaked:
+            continue
+        line_no = line_number(masked, start)
+        line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+        if "ubs:ignore" in line:
+            continue
+        key = (path, line_no)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line_no}:{line.strip()}  [{leaked} interpolated into {label}]")
+PY
+}
+
+count_secret_logging_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_secret_logging_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_secret_logging_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_secret_logging_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_secret_logging() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_secret_logging_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_debug_output_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  UBS_RUST_DEBUG_OUTPUT_TARGETS="$DEBUG_OUTPUT_TARGETS" python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+macro_re = re.compile(r"(?<![A-Za-z0-9_:])(?:std\s*::\s*)?(dbg|println|eprintln|print|eprint)\s*!\s*[({\[]")
+test_attr = re.compile(r"#\s*\[\s*(?:cfg\s*\(\s*test\s*\)|(?:[A-Za-z_][A-Za-z0-9_]*::)*test(?:\s*\([^\]]*\))?|bench)\s*\]")
+debug_gate_re = re.compile(r"#\s*\[\s*cfg\s*\(\s*(?:debug_assertions|feature\s*=)[^\]]*\]|\bif\s+cfg\s*!\s*\(\s*debug_assertions\s*\)")
+main_fn_re = re.compile(r"^(?:pub\s+)?(?:async\s+)?fn\s+main\s*\(", re.M)
+bin_path_re = re.compile(r"^\s*path\s*=\s*\"([^\"]+)\"", re.M)
+targets = {
+    item.strip().lower()
+    for item in (os.environ.get("UBS_RUST_DEBUG_OUTPUT_TARGETS") or "lib").split(",")
+    if item.strip()
+}
+manifest_cache = {}
+seen = set()
+
+
+def crate_info(path: Path):
+    for parent in path.parents:
+        manifest = parent / "Cargo.toml"
+        if manifest in manifest_cache:
+            return manifest_cache[manifest]
+        if manifest.is_file():
+            try:
+                text = manifest.read_text(encoding="utf-8", errors="replace")
+            except OSError:
+                text = ""
+            bins = set()
+            for section in re.split(r"^\s*\[", text, flags=re.M):
+                if section.startswith("[bin]]"):
+                    for declared in bin_path_re.findall(section):
+                        bins.add((parent / declared).resolve())
+            has_lib = (parent / "src" / "lib.rs").is_file() or re.search(r"^\s*\[lib\]", text, re.M) is not None
+            info = (parent, bins, has_lib)
+            manifest_cache[manifest] = info
+            return info
+        if parent == root:
+            break
+    return None
+
+
+def target_kind(path: Path, masked: str) -> str:
+    info = crate_info(path)
+    if info is None:
+        # Loose .rs files outside any crate are snippets or scripts; there is no library to protect.
+        return "unknown"
+    crate_root = info[0]
+    try:
+        parts = path.resolve().relative_to(crate_root.resolve()).parts
+    except ValueError:
+        parts = path.parts
+    dirs = parts[:-1]
+    if "tests" in dirs:
+        return "test"
+    if "benches" in dirs:
+        return "bench"
+    if "examples" in dirs:
+        return "example"
+    if parts == ("build.rs",):
+        return "build"
+    if path.resolve() in info[1]:
+        return "bin"
+    if parts[-1] == "main.rs" or "bin" in dirs or main_fn_re.search(masked):
+        return "bin"
+    # A crate without a library target compiles every module into a binary.
+    if not info[2]:
+        return "bin"
+    return "lib"
+
+
+def gated_regions(masked: str, attr_re):
+    regions = []
+    for attr in attr_re.finditer(masked):
+        brace = masked.find("{", attr.end())
+        semi = masked.find(";", attr.end())
+        if brace < 0 or (0 <= semi < brace):
+            if semi >= 0:
+                regions.append((attr.start(), semi))
+            continue
+        end = find_matching_brace(masked, brace)
+        if end > 0:
+            regions.append((attr.start(), end))
+    return regions
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if not any(name in text for name in ("dbg!", "print!", "println!", "eprintln!", "eprint!")):
+        continue
+    masked = mask_comments_and_strings(text)
+    kind = target_kind(path, masked)
+    if kind not in targets:
+        continue
+    lines = text.splitlines()
+    skipped = gated_regions(masked, debug_gate_re)
+    if "test" not in targets:
+        skipped += gated_regions(masked, test_attr)
+    for hit in macro_re.finditer(masked):
+        macro = hit.group(1)
+        if (mode == "dbg") != (macro == "dbg"):
+            continue
+        if any(start <= hit.start() <= end for start, end in skipped):
+            continue
+        line_no = line_number(masked, hit.start())
+        line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+        if "ubs:ignore" in line:
+            continue
+        key = (path, line_no)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line_no}:{line.strip()}  [{macro}! in {kind} target]")
+PY
+}
+
+count_debug_output_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_debug_output_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_debug_output_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_debug_output_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_debug_output() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_debug_output_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_untrusted_alloc_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_brace, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+def find_matching_paren(text: str, open_index: int) -> int:
+    pairs = {"(": ")", "[": "]"}
+    close = pairs[text[open_index]]
+    depth = 0
+    for idx in range(open_index, len(text)):
+        ch = text[idx]
+        if ch == text[open_index]:
+            depth += 1
+        elif ch == close:
+            depth -= 1
+            if depth == 0:
+                return idx
+    return -1
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+let_re = re.compile(r"let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::\s*([A-Za-z0-9_]+)\s*)?=([^;]*);")
+# Integers that arrive from the outside world: parsed text, wire-format reads, and declared lengths.
+source_re = re.compile(
+    r"\.\s*parse\s*(?:::\s*<\s*(?P<parse>[A-Za-z0-9_]+)\s*>)?\s*\(\s*\)"
+    r"|\b(?P<fromstr>[ui](?:8|16|32|64|128|size))::from_str(?:_radix)?\s*\("
+    r"|\b(?P<bytes>[ui](?:8|16|32|64|128|size))::from_(?:be|le|ne)_bytes\s*\("
+    r"|\.\s*(?:read|get)_(?P<wire>[ui](?:8|16|32|64|128))(?:_le|_be|_ne)?\s*(?:::\s*<[^>]*>\s*)?\("
+    r"|\.\s*content_length\s*\(\s*\)"
+)
+sink_re = re.compile(
+    r"\b(?P<ctor>Vec|VecDeque|String|HashMap|HashSet|IndexMap|BytesMut|BinaryHeap)\s*::\s*with_capacity\s*\("
+    r"|\bvec!\s*\["
+    r"|\.\s*(?P<method>reserve|reserve_exact|resize|resize_with)\s*\("
+)
+small_types = {"u8", "u16", "i8", "i16", "f32", "f64", "bool", "char"}
+seen = set()
+
+
+def taint_of(annotation, expr, tainted):
+    hit = source_re.search(expr)
+    if hit:
+        ty = annotation or next((g for g in hit.groups() if g), None)
+        return None if ty in small_types else "input"
+    names = set(re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", expr))
+    if names & set(tainted) and not re.search(r"\.\s*(?:min|clamp)\s*\(|\bcmp\s*::\s*min\s*\(", expr):
+        return None if annotation in small_types else "input"
+    return None
+
+
+def sink_argument(body: str, hit):
+    if hit.group(0).startswith("vec!"):
+        open_idx = hit.end() - 1
+        close = find_matching_paren(body, open_idx)
+        if close < 0:
+            return None
+        inner = body[open_idx + 1:close]
+        depth = 0
+        for idx, ch in enumerate(inner):
+            if ch in "([{":
+                depth += 1
+            elif ch in ")]}":
+                depth -= 1
+            elif ch == ";" and depth == 0:
+                return inner[idx + 1:], close
+        return None
+    open_idx = hit.end() - 1
+    close = find_matching_paren(body, open_idx)
+    if close < 0:
+        return None
+    inner = body[open_idx + 1:close]
+    depth = 0
+    for idx, ch in enumerate(inner):
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif ch == "," and depth == 0:
+            return inner[:idx], close
+    return inner, close
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if not any(word in text for word in ("with_capacity", "vec!", "reserve", "resize")):
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        tainted = {}
+        for stmt in let_re.finditer(body):
+            if taint_of(stmt.group(2), stmt.group(3), tainted):
+                tainted[stmt.group(1)] = stmt.end()
+        for hit in sink_re.finditer(body):
+            parsed = sink_argument(body, hit)
+            if not parsed:
+                continue
+            arg, sink_close = parsed
+            names = [name for name in re.findall(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)", arg) if name in tainted and tainted[name] <= hit.start()]
+            direct = source_re.search(arg)
+            if not names and not (direct and taint_of(None, arg, {})):
+                continue
+            if re.search(r"\.\s*(?:min|clamp)\s*\(|\bcmp\s*::\s*min\s*\(", arg):
+                continue
+            bounded = False
+            for name in names:
+                escaped = re.escape(name)
+                guard_re = re.compile(
+                    r"(?<![A-Za-z0-9_.])" + escaped + r"\s*(?:<=?|>=?)\s*[A-Za-z0-9_(]"
+                    r"|(?:<=?|>=?)\s*" + escaped + r"(?![A-Za-z0-9_])"
+                    r"|" + escaped + r"\s*\.\s*(?:min|clamp)\s*\("
+                    r"|\b" + escaped + r"\s*\.\s*(?:checked_mul|checked_add)\s*\("
+                )
+                if guard_re.search(body, 0, hit.start()):
+                    bounded = True
+            if bounded:
+                continue
+            line_no = line_number(masked, brace + hit.start())
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            size = re.sub(r"\s+", " ", arg.strip()) or "n"
+            if hit.group("ctor"):
+                sink = f"{hit.group('ctor')}::with_capacity({size})"
+            elif hit.group("method"):
+                sink = f".{hit.group('method')}({size})"
+            else:
+                sink = f"vec![..; {size}]"
+            tail = body[sink_close:body.find(";", sink_close) + 1 if body.find(";", sink_close) >= 0 else len(body)]
+            statement_start = max(body.rfind(";", 0, hit.start()), body.rfind("{", 0, hit.start())) + 1
+            statement = body[statement_start:hit.start()] + tail
+            buffer = re.match(r"\s*let\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)", body[statement_start:hit.start()])
+            if re.search(r"\bread_exact\s*\(", statement) or (
+                buffer and re.search(r"\bread_exact\s*\(\s*&\s*mut\s+" + re.escape(buffer.group(1)) + r"\b", body[sink_close:])
+            ):
+                sink += " sized by untrusted input, filled by read_exact"
+            else:
+                sink += " sized by untrusted input"
+            print(f"{path}:{line_no}:{line.strip()}  [{sink}]")
+PY
+}
+
+count_untrusted_alloc_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_untrusted_alloc_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_untrusted_alloc_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_untrusted_alloc_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_untrusted_alloc() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_untrusted_alloc_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_zip_truncation_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+zip_method_re = re.compile(r"\.\s*zip\s*\(")
+zip_fn_re = re.compile(r"(?<![A-Za-z0-9_.])(?:(?:std\s*::\s*)?iter\s*::\s*)?zip\s*\(")
+base_re = re.compile(r"^\s*&?\s*(?:mut\s+)?((?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*(?![A-Za-z0-9_]|\s*[(!:<]))*)")
+# Iterators whose length is unbounded or deliberately adjusted; zipping them is the point.
+deliberate_re = re.compile(
+    r"\.\.|\brepeat(?:_with)?\s*\(|\.\s*(?:cycle|skip|take|step_by|skip_while|take_while|chunks|windows)\s*\(|\bsuccessors\s*\(|\bfrom_fn\s*\("
+)
+array_param_re = re.compile(r"([A-Za-z_][A-Za-z0-9_]*)\s*:\s*&?\s*(?:mut\s+)?\[[^\];]+;\s*[A-Za-z0-9_]+\s*\]")
+seen = set()
+
+
+def receiver(masked: str, dot: int, floor: int) -> str:
+    """Expression text that ends right before the `.zip(` at dot."""
+    depth = 0
+    idx = dot
+    while idx > floor:
+        ch = masked[idx - 1]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                break
+            depth -= 1
+        elif depth == 0 and (ch in ";{},=|" or (ch.isspace() and not re.match(r"\s*\.", masked[idx:]))):
+            if not (ch.isspace() and masked[idx - 2:idx - 1] in (".", "&")):
+                break
+        idx -= 1
+    return masked[idx:dot]
+
+
+def split_args(inner: str):
+    depth = 0
+    for idx, ch in enumerate(inner):
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif ch == "," and depth == 0:
+            return inner[:idx], inner[idx + 1:]
+    return None
+
+
+def base_of(expr: str):
+    expr = expr.strip()
+    if not expr or deliberate_re.search(expr):
+        return None
+    match = base_re.match(expr)
+    if not match:
+        return None
+    rest = expr[match.end():].lstrip()
+    if rest and not rest.startswith("."):
+        return None
+    return re.sub(r"\s+", "", match.group(1))
+
+
+def lengths_related(body: str, left: str, right: str) -> bool:
+    if re.search(r"\bzip_eq\b", body):
+        return True
+    def len_re(base):
+        return re.compile(r"(?<![A-Za-z0-9_])" + r"\s*\.\s*".join(re.escape(part) for part in base.split(".")) + r"\s*\.\s*len\s*\(\s*\)")
+    left_len, right_len = len_re(left), len_re(right)
+    for statement in re.split(r"[;{}]", body):
+        if left_len.search(statement) and right_len.search(statement):
+            return True
+    return False
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "zip" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        arrays = set(array_param_re.findall(masked[fn.end():close_paren]))
+        pairs = []
+        for hit in zip_method_re.finditer(body):
+            open_paren = hit.end() - 1
+            close = find_matching_paren(body, open_paren)
+            if close < 0:
+                continue
+            pairs.append((hit.start(), receiver(body, hit.start(), 0), body[open_paren + 1:close]))
+        for hit in zip_fn_re.finditer(body):
+            if re.search(r"\bfn\s+$", body[max(0, hit.start() - 6):hit.start()]):
+                continue
+            open_paren = hit.end() - 1
+            close = find_matching_paren(body, open_paren)
+            args = split_args(body[open_paren + 1:close]) if close > 0 else None
+            if args:
+                pairs.append((hit.start(), args[0], args[1]))
+        for offset, left_expr, right_expr in pairs:
+            left, right = base_of(left_expr), base_of(right_expr)
+            if not left or not right or left == right:
+                continue
+            if left.split(".")[0] in arrays and right.split(".")[0] in arrays:
+                continue
+            if lengths_related(body, left, right):
+                continue
+            line_no = line_number(masked, brace + offset)
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            print(f"{path}:{line_no}:{line.strip()}  [{left} zipped with {right}, lengths never compared]")
+PY
+}
+
+count_zip_truncation_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_zip_truncation_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_zip_truncation_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_zip_truncation_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_zip_truncation() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_zip_truncation_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+
+rust_hash_order_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+hash_type = r"(?:std\s*::\s*collections\s*::\s*)?(?:HashMap|HashSet|FxHashMap|FxHashSet|AHashMap|AHashSet|DashMap)\b"
+typed_re = re.compile(r"(?<![A-Za-z0-9_])([A-Za-z_][A-Za-z0-9_]*)\s*:\s*&?\s*(?:'[A-Za-z_]+\s+)?(?:mut\s+)?" + hash_type)
+ctor_re = re.compile(
+    r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*=\s*(?:" + hash_type + r"\s*::\s*(?:new|with_capacity|default|from)\b"
+    r"|[^;]*\.\s*collect\s*::\s*<\s*" + hash_type + r")"
+)
+iter_call = r"\.\s*(?:iter|iter_mut|keys|values|values_mut|into_iter|into_keys|into_values|drain)\s*\(\s*\)"
+first_re = re.compile(iter_call + r"\s*\.\s*(?:next|last|nth|take)\s*\(")
+for_re = re.compile(r"\bfor\s+[^{;]*?\bin\s+(?P<expr>[^{;]*)\{")
+output_re = re.compile(
+    r"\b(?:println|print|eprintln|eprint|write|writeln|format)\s*!\s*\(|\.\s*(?:push_str|write_all|write_str)\s*\("
+)
+hash_sink_re = re.compile(r"\.\s*(?:update|hash|write|input|chain_update|digest)\s*\(|\bhasher\b|\bdigest\b")
+join_re = re.compile(iter_call + r"[^;]*?\.\s*collect\s*::\s*<\s*Vec\s*<[^;]*?\.\s*join\s*\(")
+serialize_re = re.compile(r"\bserde_json\s*::\s*to_(?:string|string_pretty|vec|writer)\s*\(\s*&?\s*([A-Za-z_][A-Za-z0-9_.]*)\s*[,)]")
+sorted_re = re.compile(r"\.\s*(?:sort|sort_unstable|sort_by|sort_by_key|sort_unstable_by|sort_unstable_by_key)\s*\(|\bBTree(?:Map|Set)\b|\.\s*sorted\s*\(")
+seen = set()
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+struct_re = re.compile(r"\bstruct\s+[A-Za-z_][A-Za-z0-9_]*[^{;]*\{")
+
+
+def names_in(expr: str, local, fields):
+    for name in local:
+        if re.search(r"(?<![A-Za-z0-9_.])" + re.escape(name) + r"(?![A-Za-z0-9_])", expr):
+            return name
+    for name in fields:
+        if re.search(r"\bself\s*\.\s*" + re.escape(name) + r"(?![A-Za-z0-9_])", expr):
+            return name
+    return None
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "Hash" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    fields = set()
+    for item in struct_re.finditer(masked):
+        close = find_matching_brace(masked, item.end() - 1)
+        if close > 0:
+            fields.update(typed_re.findall(masked[item.end():close]))
+    hits = []
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        # Names are resolved per function so a BTreeMap elsewhere with the same name is not confused.
+        local = set(typed_re.findall(masked[fn.end():brace])) | set(typed_re.findall(body)) | set(ctor_re.findall(body))
+        if not local and not fields:
+            continue
+        for hit in first_re.finditer(body):
+            start = max(body.rfind(";", 0, hit.start()), body.rfind("{", 0, hit.start()), body.rfind("\n", 0, hit.start())) + 1
+            name = names_in(body[start:hit.start()], local, fields)
+            if name:
+                hits.append((brace + hit.start(), name, "picks the \"first\" element"))
+        for hit in join_re.finditer(body):
+            start = max(body.rfind(";", 0, hit.start()), body.rfind("{", 0, hit.start())) + 1
+            name = names_in(body[start:hit.start()], local, fields)
+            if name and not sorted_re.search(body[start:hit.end()]):
+                hits.append((brace + hit.start(), name, "decides the joined string"))
+        for hit in serialize_re.finditer(body):
+            name = names_in(hit.group(1), local, fields)
+            if name:
+                hits.append((brace + hit.start(), name, "decides the serialized output"))
+        for loop in for_re.finditer(body):
+            name = names_in(loop.group("expr"), local, fields)
+            if not name or sorted_re.search(loop.group("expr")):
+                continue
+            # Only the bare collection (or one of its iterators) sets the order.
+            if not re.fullmatch(r"\s*&?\s*(?:mut\s+)?(?:self\s*\.\s*)?" + re.escape(name) + r"\s*(?:" + iter_call + r")?\s*", loop.group("expr")):
+                continue
+            loop_brace = loop.end() - 1
+            close = find_matching_brace(body, loop_brace)
+            if close < 0:
+                continue
+            loop_body = body[loop_brace:close]
+            if hash_sink_re.search(loop_body):
+                what = "feeds a hash or digest"
+            elif output_re.search(loop_body):
+                what = "decides the output order"
+            else:
+                continue
+            hits.append((brace + loop.start(), name, what))
+    for offset, name, what in sorted(hits):
+        line_no = line_number(masked, offset)
+        line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+        if "ubs:ignore" in line:
+            continue
+        key = (path, line_no)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line_no}:{line.strip()}  [{name} iteration order {what}]")
+PY
+}
+
+count_hash_order_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_hash_order_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_hash_order_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_hash_order_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_hash_order() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_hash_order_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_dangerous_delete_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+delete_re = re.compile(r"(?<![A-Za-z0-9_])(?:(?:std|tokio|async_std)\s*::\s*)?fs\s*::\s*(remove_dir_all|remove_file|remove_dir)\s*\(")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=([^;]*);")
+push_re = re.compile(r"(?<![A-Za-z0-9_.])([A-Za-z_][A-Za-z0-9_]*)\s*\.\s*push\s*\(([^;]*)\)\s*;")
+ident_re = re.compile(r"(?<![A-Za-z0-9_.:])([a-z_][A-Za-z0-9_]*)\b(?!\s*[(!:])")
+untrusted_re = re.compile(
+    r"\benv\s*::\s*(?:var|var_os|args|args_os)\s*\(|\bstdin\s*\(\s*\)|\bread_line\s*\("
+    r"|\.\s*(?:match_info|query_string|param|query|headers)\s*\("
+)
+untrusted_param = re.compile(r"\b(?:Query|Json|Form|Path|Multipart|HttpRequest|Request)\s*<|\bHttpRequest\b")
+join_var_re = re.compile(r"\.\s*(?:join|push)\s*\(\s*&?\s*[a-z_]")
+# A default or fallback that can leave the path empty, or a literal root.
+empty_re = re.compile(
+    r"\.\s*unwrap_or_default\s*\(\s*\)|\.\s*unwrap_or\s*\(\s*(?:\"\"|String\s*::\s*new\s*\(\s*\)|PathBuf\s*::\s*new\s*\(\s*\))\s*\)"
+    r"|\bunwrap_or_else\s*\(\s*\|[^|]*\|\s*(?:String|PathBuf)\s*::\s*(?:new|default)\s*\(\s*\)\s*\)"
+    r"|\b(?:String|PathBuf|OsString)\s*::\s*(?:new|default)\s*\(\s*\)|\bPath\s*::\s*new\s*\(\s*\"/?\"\s*\)|^\s*\"/?\"\s*$|\bPathBuf\s*::\s*from\s*\(\s*\"/\"\s*\)"
+)
+temp_re = re.compile(r"\btemp(?:file|dir)\b|\bTempDir\b|\btempdir\s*\(|\btemp_dir\s*\(\s*\)|\bNamedTempFile\b")
+inline_arg_re = re.compile(r"\{([a-z_][A-Za-z0-9_]*)(?::[^}]*)?\}")
+seen = set()
+
+
+def guard_re(name: str):
+    escaped = re.escape(name)
+    return re.compile(
+        r"(?<![A-Za-z0-9_])" + escaped + r"\s*(?:\.\s*[A-Za-z_][A-Za-z0-9_]*\s*\(\s*\)\s*)*\.\s*"
+        r"(?:starts_with|strip_prefix|canonicalize|exists|try_exists|is_dir|is_file|is_empty|is_absolute|components|parent)\s*\("
+        r"|(?<![A-Za-z0-9_])" + escaped + r"\s*(?:==|!=)"
+        r"|\b(?:canonicalize|ensure_within|validate_path|is_safe_path|safe_path|within_root)\w*\s*\([^;]*\b" + escaped + r"\b"
+    )
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "remove_" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        raw_body = text[brace:end + 1]
+        signature = masked[fn.end():close_paren]
+        params = {m.group(1): m.group(2) for m in re.finditer(r"(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*([^,]+)", signature)}
+        assigns = {}
+        for stmt in let_re.finditer(body):
+            assigns.setdefault(stmt.group(1), []).append((stmt.start(), raw_body[stmt.start(2):stmt.end(2)], stmt.group(2)))
+        for stmt in push_re.finditer(body):
+            assigns.setdefault(stmt.group(1), []).append((stmt.start(), raw_body[stmt.start(2):stmt.end(2)], stmt.group(2) + " .push("))
+        for hit in delete_re.finditer(body):
+            open_paren = hit.end() - 1
+            close = find_matching_paren(body, open_paren)
+            if close < 0:
+                continue
+            arg_masked = body[open_paren + 1:close]
+            arg_raw = raw_body[open_paren + 1:close]
+            # Follow let bindings back to where the path's parts came from.
+            origin_raw = [arg_raw]
+            origin_masked = [arg_masked]
+            names = set()
+            pending = ident_re.findall(arg_masked) + inline_arg_re.findall(arg_raw)
+            while pending:
+                name = pending.pop()
+                if name in names or name in ("self", "mut", "ref"):
+                    continue
+                names.add(name)
+                for offset, rhs_raw, rhs_masked in assigns.get(name, []):
+                    if offset < hit.start():
+                        origin_raw.append(rhs_raw)
+                        origin_masked.append(rhs_masked)
+                        pending.extend(ident_re.findall(rhs_masked) + inline_arg_re.findall(rhs_raw))
+            if not names:
+                continue
+            joined_masked = " ; ".join(origin_masked)
+            if temp_re.search(joined_masked):
+                continue
+            if any(guard_re(name).search(body, 0, hit.start()) for name in names):
+                continue
+            may_be_empty = any(empty_re.search(part) for part in origin_raw)
+            untrusted = bool(untrusted_re.search(joined_masked)) or any(untrusted_param.search(params.get(name, "")) for name in names)
+            # A join/push of a variable segment, or a format! that mixes several variables.
+            constructed = bool(join_var_re.search(joined_masked)) or any(
+                re.search(r"\bformat\s*!\s*\(", part) and len(set(inline_arg_re.findall(raw)) | set(ident_re.findall(part))) >= 2
+                for part, raw in zip(origin_masked, origin_raw)
+            )
+            if may_be_empty:
+                kind = "empty"
+            elif untrusted or constructed:
+                kind = "unvalidated"
+            else:
+                continue
+            if kind != mode:
+                continue
+            line_no = line_number(masked, brace + hit.start())
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            target = re.sub(r"\s+", " ", arg_raw.strip())
+            if len(target) > 60:
+                target = target[:57] + "..."
+            if kind == "empty":
+                why = "may be empty or /"
+            elif untrusted:
+                why = "built from untrusted input, never validated"
+            else:
+                why = "constructed from variables, never validated"
+            print(f"{path}:{line_no}:{line.strip()}  [{hit.group(1)}({target}) {why}]")
+PY
+}
+
+count_dangerous_delete_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_dangerous_delete_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_dangerous_delete_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_dangerous_delete_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_dangerous_delete() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_dangerous_delete_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_toctou_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+method_check_re = re.compile(r"\.\s*(exists|try_exists|is_file|is_dir|is_symlink|metadata|symlink_metadata)\s*\(\s*\)")
+fn_check_re = re.compile(r"(?<![A-Za-z0-9_])(?:(?:std|tokio)\s*::\s*)?fs\s*::\s*(metadata|symlink_metadata|try_exists)\s*\(")
+use_re = re.compile(
+    r"(?<![A-Za-z0-9_])(?:(?:std|tokio)\s*::\s*)?(?:fs\s*::\s*)?File\s*::\s*(open|create)\s*\("
+    r"|(?<![A-Za-z0-9_])(?:(?:std|tokio)\s*::\s*)?fs\s*::\s*(write|remove_file|remove_dir|remove_dir_all|create_dir|rename|copy|read|read_to_string|set_permissions|hard_link)\s*\("
+    r"|\.\s*(open)\s*\("
+)
+# metadata()?.permissions() reads the current mode to update it; it does not test whether the path exists.
+mode_read_re = re.compile(r"\s*\??\s*\.\s*permissions\s*\(")
+seen = set()
+
+
+def receiver(body: str, dot: int) -> str:
+    depth = 0
+    idx = dot
+    while idx > 0:
+        ch = body[idx - 1]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                break
+            depth -= 1
+        elif depth == 0 and (ch.isspace() or ch in ";{},=!|&"):
+            break
+        idx -= 1
+    return body[idx:dot]
+
+
+def normalize(expr: str) -> str:
+    expr = re.sub(r"\s+", "", expr)
+    expr = re.sub(r"^&(?:mut)?", "", expr)
+    expr = re.sub(r"\.(?:as_path|as_ref|as_os_str|to_path_buf|clone)\(\)$", "", expr)
+    wrapped = re.fullmatch(r"(?:std::path::)?(?:Path::new|PathBuf::from)\(&?(.+)\)", expr)
+    if wrapped:
+        expr = wrapped.group(1)
+    return expr
+
+
+def first_arg(body: str, open_paren: int):
+    close = find_matching_paren(body, open_paren)
+    if close < 0:
+        return None
+    inner = body[open_paren + 1:close]
+    depth = 0
+    for idx, ch in enumerate(inner):
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+        elif ch == "," and depth == 0:
+            return inner[:idx]
+    return inner
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if not any(word in text for word in ("exists", "is_file", "is_dir", "metadata")):
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        checks = []
+        for hit in method_check_re.finditer(body):
+            if mode_read_re.match(body, hit.end()):
+                continue
+            target = normalize(receiver(body, hit.start()))
+            if target and target not in ("self",):
+                checks.append((hit.start(), target, hit.group(1)))
+        for hit in fn_check_re.finditer(body):
+            close = find_matching_paren(body, hit.end() - 1)
+            if close >= 0 and mode_read_re.match(body, close + 1):
+                continue
+            arg = first_arg(body, hit.end() - 1)
+            if arg:
+                checks.append((hit.start(), normalize(arg), hit.group(1)))
+        if not checks:
+            continue
+        for hit in use_re.finditer(body):
+            op = next(group for group in hit.groups() if group)
+            if hit.group(3):
+                # OpenOptions::...open(path): create_new(true) already makes the open atomic.
+                statement_start = max(body.rfind(";", 0, hit.start()), body.rfind("{", 0, hit.start())) + 1
+                chain = body[statement_start:hit.start()]
+                if "OpenOptions" not in chain or re.search(r"\bcreate_new\s*\(\s*true\s*\)", chain):
+                    continue
+                label = "OpenOptions::open"
+            elif hit.group(1):
+                label = f"File::{op}"
+            else:
+                label = f"fs::{op}"
+            arg = first_arg(body, hit.end() - 1)
+            if not arg:
+                continue
+            target = normalize(arg)
+            prior = [check for check in checks if check[0] < hit.start() and check[1] == target]
+            if not prior:
+                continue
+            check_offset, _, check_kind = prior[-1]
+            line_no = line_number(masked, brace + hit.start())
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            check_line = line_number(masked, brace + check_offset)
+            print(f"{path}:{line_no}:{line.strip()}  [{check_kind}() check on {target} at line {check_line}, then {label}]")
+PY
+}
+
+count_toctou_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_toctou_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_toctou_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_toctou_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_toctou() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_toctou_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_file_permissions_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+mode_re = re.compile(
+    r"(?:\b(?:set_mode|from_mode)|\.\s*mode|\b(?:libc\s*::\s*)?(?:chmod|fchmod|mkdir)\s*\([^,;()]*,)\s*\(?\s*"
+    r"(?P<lit>0o[0-7_]+|0x[0-9A-Fa-f_]+|[0-9][0-9_]*)(?:u32|u16|i32)?\s*\)?"
+)
+readonly_re = re.compile(r"\.\s*set_readonly\s*\(\s*false\s*\)")
+secret_re = re.compile(
+    r"secret|private[_ ]?key|signing[_ ]?key|api[_ ]?key|\.pem\b|\.key\b|\.p12\b|\.pfx\b|id_rsa|id_ed25519|id_ecdsa|"
+    r"credential|token|password|passwd|keystore|keyring|\.netrc|\.env\b|ssh",
+    re.IGNORECASE,
+)
+seen = set()
+
+
+def parse_mode(literal: str):
+    literal = literal.replace("_", "")
+    try:
+        if literal.startswith("0o"):
+            return int(literal[2:], 8)
+        if literal.startswith("0x"):
+            return int(literal[2:], 16)
+        return int(literal, 10)
+    except ValueError:
+        return None
+
+
+def fn_span(offset: int, spans):
+    enclosing = [span for span in spans if span[0] <= offset <= span[1]]
+    return max(enclosing) if enclosing else None
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if not any(word in text for word in ("mode", "set_readonly", "chmod")):
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    spans = []
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        brace = masked.find("{", close_paren) if close_paren > 0 else -1
+        end = find_matching_brace(masked, brace) if brace > 0 else -1
+        if end > 0:
+            spans.append((fn.start(), end))
+    hits = []
+    for hit in mode_re.finditer(masked):
+        value = parse_mode(hit.group("lit"))
+        if value is None:
+            continue
+        literal = hit.group("lit")
+        decimal = not literal.startswith(("0o", "0x"))
+        # Decimal literals only count when they look like an octal mode typed without 0o.
+        if decimal and not re.fullmatch(r"[0-7]{3,4}", literal.replace("_", "")):
+            continue
+        world_writable = bool(value & 0o002)
+        others_read = bool(value & 0o066)
+        span = fn_span(hit.start(), spans)
+        # Secret-ness comes from the surrounding function: its name, path literals, and variables.
+        context = text[span[0]:span[1]] if span else lines[line_number(masked, hit.start()) - 1]
+        secret = bool(secret_re.search(context))
+        if secret and others_read:
+            kind, what = "secret", f"mode {oct(value)} lets other users read a secret or key file"
+        elif world_writable:
+            kind, what = "world", f"mode {oct(value)} is world-writable"
+        else:
+            continue
+        if decimal:
+            what += f" (decimal {literal} is {oct(value)}; write 0o{literal})"
+        hits.append((hit.start(), kind, what))
+    for hit in readonly_re.finditer(masked):
+        hits.append((hit.start(), "world", "set_readonly(false) makes the file writable by everyone on Unix"))
+    for offset, kind, what in sorted(hits):
+        if kind != mode:
+            continue
+        line_no = line_number(masked, offset)
+        line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+        if "ubs:ignore" in line:
+            continue
+        key = (path, line_no)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line_no}:{line.strip()}  [{what}]")
+PY
+}
+
+count_file_permissions_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_file_permissions_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_file_permissions_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_file_permissions_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_file_permissions() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_file_permissions_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_stream_read_all_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+read_all_re = re.compile(r"\.\s*(read_to_end|read_to_string)\s*\(")
+stream_type_re = re.compile(r"\b(?:TcpStream|UnixStream|TlsStream|SslStream|ServerTlsStream|ClientTlsStream|WsStream|OwnedReadHalf|ReadHalf)\b")
+stream_ctor_re = re.compile(
+    r"\b(?:TcpStream|UnixStream)\s*::\s*connect\s*\(|\.\s*accept\s*\(\s*\)|\.\s*incoming\s*\(\s*\)"
+    r"|\b(?:connector|acceptor|tls)\s*\.\s*(?:connect|accept)\s*\(|\.\s*into_split\s*\(\s*\)|\.\s*split\s*\(\s*\)"
+)
+body_reader_re = re.compile(
+    r"\.\s*(?:body_mut|as_reader|into_reader|reader|into_body|body)\s*\(\s*\)|\b(?:req|request|http_request)\b"
+)
+stream_name_re = re.compile(r"^(?:mut\s+)?(?:stream|socket|sock|conn|connection|peer|tcp|tls|client_stream|upstream|downstream)(?:_[a-z0-9]+)?$")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?(\(?[^=;:]*?\)?)\s*(?::\s*([^=;]+))?=([^;]*);")
+for_re = re.compile(r"\bfor\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s+in\s+([^{]*)\{")
+limit_re = re.compile(r"\.\s*take\s*\(|\bLimited\s*::\s*new\s*\(|\bwith_limit\s*\(")
+seen = set()
+
+
+def receiver(body: str, dot: int) -> str:
+    depth = 0
+    idx = dot
+    while idx > 0:
+        ch = body[idx - 1]
+        if ch in ")]":
+            depth += 1
+        elif ch in "([":
+            if depth == 0:
+                break
+            depth -= 1
+        elif depth == 0 and (ch.isspace() or ch in ";{},=!|&"):
+            if not (ch.isspace() and re.match(r"\s*\.", body[idx - 1:])):
+                break
+        idx -= 1
+    return body[idx:dot]
+
+
+def origins(name: str, body: str, params):
+    """Declared type and initializer text for a local or parameter name."""
+    found = []
+    if name in params:
+        found.append(params[name])
+    for stmt in let_re.finditer(body):
+        names = re.findall(r"[A-Za-z_][A-Za-z0-9_]*", stmt.group(1))
+        if name in names:
+            found.append((stmt.group(2) or "") + " = " + stmt.group(3))
+    for loop in for_re.finditer(body):
+        if loop.group(1) == name:
+            found.append(" = " + loop.group(2))
+    return found
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "read_to_" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        params = {}
+        for param in re.finditer(r"(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*([^,]+)", masked[fn.end():close_paren]):
+            params[param.group(1)] = param.group(2)
+        for hit in read_all_re.finditer(body):
+            expr = re.sub(r"\s+", "", receiver(body, hit.start()))
+            if not expr or limit_re.search(expr):
+                continue
+            base = re.match(r"&?(?:mut)?([A-Za-z_][A-Za-z0-9_]*)", expr)
+            if not base:
+                continue
+            name = base.group(1)
+            declared = origins(name, body, params)
+            if any(limit_re.search(item) for item in declared):
+                continue
+            source = None
+            if any(stream_type_re.search(item) or stream_ctor_re.search(item) for item in declared):
+                source = "socket"
+            elif body_reader_re.search(expr) or any(body_reader_re.search(item) for item in declared):
+                source = "request body"
+            elif stream_name_re.match(name) and not any(re.search(r"\bFile\b|\bstdin\b|\bCursor\b|&\s*\[u8\]|\bVec\s*<", item) for item in declared):
+                source = "socket"
+            if not source:
+                continue
+            line_no = line_number(masked, brace + hit.start())
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            print(f"{path}:{line_no}:{line.strip()}  [{hit.group(1)}() on {source} {name} without take(limit)]")
+PY
+}
+
+count_stream_read_all_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_stream_read_all_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_stream_read_all_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_stream_read_all_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_stream_read_all() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_stream_read_all_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_detached_thread_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+spawn_re = re.compile(
+    r"(?<![A-Za-z0-9_.:])(?:std\s*::\s*)?thread\s*::\s*(?:spawn\s*\(|Builder\s*::\s*new\s*\(\s*\)[^;]*?\.\s*spawn\s*\()"
+)
+let_prefix_re = re.compile(r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=\s*$")
+unwrap_tail_re = re.compile(r"\s*(?:\?|\.\s*(?:unwrap|expect)\s*\([^;]*\))?\s*;")
+seen = set()
+
+
+def closure_is_worker_loop(closure: str) -> bool:
+    """True when the spawned closure body is a bare `loop { .. }` with no break out of it."""
+    body = re.sub(r"^\s*(?:move\s*)?\|[^|]*\|\s*", "", closure).strip()
+    if body.startswith("{") and body.endswith("}"):
+        body = body[1:-1].strip()
+    if not re.match(r"loop\s*\{", body):
+        return False
+    open_brace = body.find("{")
+    end = find_matching_brace(body, open_brace)
+    if end < 0 or body[end + 1:].strip():
+        return False
+    inner = body[open_brace + 1:end]
+    return not loop_exits(inner)
+
+
+def loop_exits(inner: str) -> bool:
+    """True when a loop body can leave the loop: return, ?, or a break that is not bound to a nested loop."""
+    if re.search(r"\breturn\b|\?", inner) or re.search(r"\bbreak\s+'", inner):
+        return True
+    nested = []
+    for head in re.finditer(r"\b(?:loop|while|for)\b[^;{]*\{", inner):
+        close = find_matching_brace(inner, head.end() - 1)
+        if close > 0:
+            nested.append((head.end(), close))
+    return any(
+        not any(lo <= brk.start() <= hi for lo, hi in nested)
+        for brk in re.finditer(r"\bbreak\b", inner)
+    )
+
+
+def closure_drains_channel(closure: str, masked: str) -> bool:
+    """True when the spawned closure only drains a channel Receiver without panicking, so it ends once every Sender is dropped."""
+    body = re.sub(r"^\s*(?:move\s*)?\|[^|]*\|\s*", "", closure).strip()
+    if body.startswith("{") and body.endswith("}"):
+        body = body[1:-1].strip()
+    head = re.match(
+        r"(?:for\s+.+?\s+in\s+(?:&\s*)?([A-Za-z_][A-Za-z0-9_]*)(?:\s*\.\s*(?:iter|into_iter)\s*\(\s*\))?"
+        r"|while\s+let\s+(?:Ok|Some)\s*\(.*?\)\s*=\s*([A-Za-z_][A-Za-z0-9_]*)\s*\.\s*recv\s*\(\s*\))\s*\{",
+        body,
+    )
+    if not head:
+        return False
+    end = find_matching_brace(body, head.end() - 1)
+    if end < 0 or body[end + 1:].strip():
+        return False
+    if re.search(r"\.\s*(?:unwrap|expect)\s*\(|\b(?:panic|unreachable|todo|unimplemented|assert\w*)\s*!", body):
+        return False
+    rx = re.escape(head.group(1) or head.group(2))
+    return bool(
+        re.search(rf"\b{rx}\s*:\s*(?:[A-Za-z_][A-Za-z0-9_]*\s*::\s*)*Receiver\b", masked)
+        or re.search(rf"\blet\s*\(\s*(?:mut\s+)?[A-Za-z_][A-Za-z0-9_]*\s*,\s*(?:mut\s+)?{rx}\s*\)\s*=[^;]*channel", masked)
+    )
+
+
+def handle_consumed(name: str, rest: str) -> bool:
+    escaped = re.escape(name)
+    consumed = (
+        rf"\b{escaped}\s*\.\s*(?:join|is_finished)\s*\(",
+        rf"\b(?:push|push_back|insert|extend|send|replace|Some|Ok)\s*\(\s*{escaped}\b",
+        rf"\breturn\s+(?:Ok\s*\(\s*|Some\s*\(\s*)?{escaped}\b",
+        rf"[{{,]\s*(?:[A-Za-z_][A-Za-z0-9_]*\s*:\s*)?{escaped}\s*[,}}]",
+        rf"=\s*(?:Some\s*\(\s*)?{escaped}\s*\)?\s*;",
+        rf"\(\s*(?:[^()]*,\s*)?{escaped}\s*(?:,[^()]*)?\)",
+        rf"\b{escaped}\s*\}}\s*$",
+    )
+    return any(re.search(pattern, rest) for pattern in consumed)
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "thread" not in text or "spawn" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for hit in spawn_re.finditer(masked):
+        open_paren = hit.end() - 1
+        close_paren = find_matching_paren(masked, open_paren)
+        if close_paren < 0:
+            continue
+        line_start = masked.rfind("\n", 0, hit.start()) + 1
+        prefix = masked[line_start:hit.start()]
+        stmt_start = max(masked.rfind(";", 0, hit.start()), masked.rfind("{", 0, hit.start()), masked.rfind("}", 0, hit.start())) + 1
+        lead = masked[stmt_start:hit.start()]
+        tag = None
+        binding = let_prefix_re.search(lead)
+        if binding:
+            name = binding.group(1)
+            if not unwrap_tail_re.match(masked[close_paren + 1:]):
+                continue
+            if name == "_" or name.startswith("_"):
+                tag = f"JoinHandle bound to {name} and dropped"
+            else:
+                scope_end = len(masked)
+                depth = 0
+                for idx in range(close_paren + 1, len(masked)):
+                    ch = masked[idx]
+                    if ch == "{":
+                        depth += 1
+                    elif ch == "}":
+                        if depth == 0:
+                            scope_end = idx + 1
+                            break
+                        depth -= 1
+                rest = masked[close_paren + 1:scope_end]
+                if handle_consumed(name, rest):
+                    continue
+                tag = f"JoinHandle {name} never joined or stored"
+        elif not lead.strip() and unwrap_tail_re.match(masked[close_paren + 1:]):
+            tag = "JoinHandle dropped at statement end"
+        if not tag:
+            continue
+        line_no = line_number(masked, hit.start())
+        line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+        if "ubs:ignore" in line:
+            continue
+        closure = masked[open_paren + 1:close_paren]
+        if closure_drains_channel(closure, masked):
+            continue
+        if closure_is_worker_loop(closure):
+            loop_at = open_paren + 1 + closure.find("loop")
+            loop_line = lines[line_number(masked, loop_at) - 1]
+            if "ubs:ignore" in loop_line:
+                continue
+            tag += "; infinite worker loop, mark with ubs:ignore if intentional"
+        key = (path, line_no)
+        if key in seen:
+            continue
+        seen.add(key)
+        print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
+PY
+}
+
+count_detached_thread_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_detached_thread_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_detached_thread_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_detached_thread_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_detached_thread() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_detached_thread_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_poisoned_lock_matches() {
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+alias_re = re.compile(
+    r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=\s*"
+    r"(?:&\s*)?(?:([A-Za-z_][A-Za-z0-9_]*)\s*\.\s*clone\s*\(\s*\)|(?:Arc|Rc)\s*::\s*clone\s*\(\s*&\s*([A-Za-z_][A-Za-z0-9_]*)\s*\))\s*;"
+)
+spawn_re = re.compile(r"(?:\bthread\s*::\s*spawn|\.\s*spawn)\s*\(")
+guard_re = re.compile(
+    r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*=\s*([A-Za-z_][A-Za-z0-9_]*)\s*\.\s*(?:lock|write)\s*\(\s*\)\s*"
+    r"\.\s*(?:unwrap\s*\(\s*\)|expect\s*\([^;]*\))\s*;"
+)
+panic_re = re.compile(
+    r"\b(?:panic|unreachable|todo|unimplemented|assert|assert_eq|assert_ne)!|\.\s*(?:unwrap|expect)\s*\("
+)
+unwrap_lock_re = re.compile(r"\b([A-Za-z_][A-Za-z0-9_]*)\s*\.\s*(?:lock|read|write)\s*\(\s*\)\s*\.\s*unwrap\s*\(\s*\)")
+seen = set()
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "spawn" not in text or ".unwrap()" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        if not spawn_re.search(body):
+            continue
+        parent = {}
+
+        def root_of(name):
+            while parent.get(name, name) != name:
+                name = parent[name]
+            return name
+
+        for alias in alias_re.finditer(body):
+            source = alias.group(2) or alias.group(3)
+            parent[root_of(alias.group(1))] = root_of(source)
+        poisoned = {}
+        for spawn in spawn_re.finditer(body):
+            open_paren = spawn.end() - 1
+            close = find_matching_paren(body, open_paren)
+            if close < 0:
+                continue
+            closure = body[open_paren + 1:close]
+            for guard in guard_re.finditer(closure):
+                live = closure[guard.end():]
+                dropped = re.search(rf"\bdrop\s*\(\s*{re.escape(guard.group(1))}\s*\)", live)
+                if dropped:
+                    live = live[:dropped.start()]
+                panic = panic_re.search(live)
+                if not panic:
+                    continue
+                offset = brace + open_paren + 1 + guard.end() + panic.start()
+                poisoned.setdefault(root_of(guard.group(2)), line_number(masked, offset))
+        if not poisoned:
+            continue
+        for hit in unwrap_lock_re.finditer(body):
+            group = root_of(hit.group(1))
+            if group not in poisoned:
+                continue
+            line_no = line_number(masked, brace + hit.start())
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            print(f"{path}:{line_no}:{line.strip()}  [{hit.group(1)} is also locked by a spawned thread that can panic at line {poisoned[group]} while holding the guard]")
+PY
+}
+
+count_poisoned_lock_matches() {
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_poisoned_lock_matches | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_poisoned_lock_examples() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_poisoned_lock_matches | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_poisoned_lock() {
+  local limit="${1:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_poisoned_lock_matches | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_handler_panic_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+METHODS = r"get|post|put|delete|patch|head|options|trace|connect"
+actix_attr_re = re.compile(
+    rf"#\s*\[\s*(?:actix_web\s*::\s*)?({METHODS}|route|routes)\s*\((?:[^\]]*)\]\s*"
+    r"(?:#\s*\[[^\]]*\]\s*)*(?:pub(?:\([^)]*\))?\s+)?async\s+fn\s+([A-Za-z_][A-Za-z0-9_]*)"
+)
+actix_to_re = re.compile(r"\.\s*to\s*\(\s*([A-Za-z_][A-Za-z0-9_]*(?:\s*::\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\)")
+axum_route_re = re.compile(r"\.\s*route\s*\(")
+axum_method_re = re.compile(
+    rf"(?<![A-Za-z0-9_])({METHODS}|any)\s*\(\s*([A-Za-z_][A-Za-z0-9_]*(?:\s*::\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\)"
+)
+async_fn_re = re.compile(r"\basync\s+fn\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^>{]*>)?\s*\(")
+unwrap_re = re.compile(r"\.\s*(unwrap|expect)\s*\(")
+index_re = re.compile(
+    r"(?<![\w.:])([A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\[(?![^\]]*;)(?!\s*\.\.\s*\])([^\]]+)\]"
+)
+call_index_re = re.compile(r"\)\s*\[(?![^\]]*;)(?!\s*\.\.\s*\])[^\]]+\]")
+NON_VALUES = {"vec", "Some", "Ok", "Err", "let", "in", "return", "mut", "ref", "match", "if", "else"}
+seen = set()
+
+files = []
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    framework = None
+    if re.search(r"\bactix_web\b", masked):
+        framework = "actix"
+    elif re.search(r"\baxum\b", masked):
+        framework = "axum"
+    files.append((path, text, masked, framework))
+
+handlers = {}
+for path, text, masked, framework in files:
+    if framework == "actix":
+        for attr in actix_attr_re.finditer(masked):
+            handlers.setdefault(attr.group(2), f"actix #[{attr.group(1)}]")
+        for to in actix_to_re.finditer(masked):
+            name = re.split(r"\s*::\s*", to.group(1))[-1]
+            handlers.setdefault(name, "actix .to()")
+    elif framework == "axum":
+        for route in axum_route_re.finditer(masked):
+            close = find_matching_paren(masked, route.end() - 1)
+            if close < 0:
+                continue
+            for method in axum_method_re.finditer(masked[route.end():close]):
+                name = re.split(r"\s*::\s*", method.group(2))[-1]
+                handlers.setdefault(name, f"axum {method.group(1)}()")
+
+for path, text, masked, framework in files:
+    if not framework or not handlers:
+        continue
+    lines = text.splitlines()
+    for fn in async_fn_re.finditer(masked):
+        name = fn.group(1)
+        kind = handlers.get(name)
+        if not kind or not kind.startswith(framework):
+            continue
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        if brace < 0:
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        hits = []
+        if mode == "unwrap":
+            for hit in unwrap_re.finditer(body):
+                hits.append((hit.start(), f"{hit.group(1)}()"))
+        else:
+            for hit in index_re.finditer(body):
+                target = re.sub(r"\s+", "", hit.group(1))
+                if target.split(".")[0] in NON_VALUES or target[:1].isupper():
+                    continue
+                hits.append((hit.start(), f"{target}[..] indexing"))
+            for hit in call_index_re.finditer(body):
+                hits.append((hit.start(), "call result indexing"))
+        for offset, what in hits:
+            line_no = line_number(masked, brace + offset)
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            print(f"{path}:{line_no}:{line.strip()}  [{what} in {kind} handler {name}]")
+PY
+}
+
+count_handler_panic_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_handler_panic_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_handler_panic_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_handler_panic_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_handler_panic() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_handler_panic_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_ffi_buffer_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def split_args(args: str):
+    parts, depth, start = [], 0, 0
+    for idx, ch in enumerate(args):
+        if ch in "([{<":
+            depth += 1
+        elif ch in ")]}>":
+            depth -= 1
+        elif ch == "," and depth == 0:
+            parts.append(args[start:idx].strip())
+            start = idx + 1
+    tail = args[start:].strip()
+    if tail:
+        parts.append(tail)
+    return parts
+
+
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+raw_parts_re = re.compile(r"\b(?:slice\s*::\s*)?from_raw_parts(?:_mut)?\s*\(")
+copy_re = re.compile(r"\b(?:ptr\s*::\s*)?(copy_nonoverlapping|copy)\s*\(")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=([^;]*);")
+owner_re = re.compile(
+    r"^&?\s*(?:mut\s+)?((?:[A-Za-z_][A-Za-z0-9_]*\s*\.\s*)*[A-Za-z_][A-Za-z0-9_]*)\s*(?:\[[^\]]*\]\s*)?\.\s*(?:as_ptr|as_mut_ptr|as_bytes|as_mut)\s*\(\s*\)"
+)
+DST_NAME = re.compile(r"^(?:dst|dest|destination|out|output|target|to|sink)(?:_|$)|_(?:dst|dest|out|output|target)$")
+SRC_NAME = re.compile(r"^(?:src|source|input|from|origin)(?:_|$)|_(?:src|source|input|in)$")
+seen = set()
+
+
+def strip_ptr(expr: str) -> str:
+    expr = expr.strip()
+    while True:
+        nxt = re.sub(r"\s+as\s+\*\s*(?:const|mut)\s+[^,)]+$", "", expr)
+        nxt = re.sub(r"\s*\.\s*(?:cast|cast_mut|cast_const)\s*(?:::\s*<[^>]*>)?\s*\(\s*\)$", "", nxt)
+        nxt = re.sub(r"\s*\.\s*(?:add|offset|byte_add)\s*\([^()]*\)$", "", nxt)
+        nxt = nxt.strip()
+        if nxt.startswith("(") and nxt.endswith(")"):
+            nxt = nxt[1:-1].strip()
+        if nxt == expr:
+            return expr
+        expr = nxt
+
+
+def pointer_owner(expr: str, lets, params, depth=0):
+    """Name of the allocation a pointer expression points into, 'param:NAME' for raw pointer params."""
+    expr = strip_ptr(expr)
+    owned = owner_re.match(expr)
+    if owned:
+        return re.sub(r"\s+", "", owned.group(1))
+    field = re.match(r"^(self\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)$", expr)
+    if field:
+        return "self"
+    name = re.match(r"^[A-Za-z_][A-Za-z0-9_]*$", expr)
+    if not name:
+        return None
+    if expr in params:
+        return "param:" + expr
+    if expr in lets and depth < 4:
+        return pointer_owner(lets[expr], lets, params, depth + 1)
+    return None
+
+
+def length_sources(expr: str, lets, depth=0):
+    """Identifiers a length expression is ultimately computed from."""
+    found = set()
+    for ident in re.findall(r"(?<![A-Za-z0-9_:])(?:self\s*\.\s*)?[A-Za-z_][A-Za-z0-9_]*", expr):
+        ident = re.sub(r"\s+", "", ident)
+        found.add(ident)
+        if ident in lets and depth < 4:
+            found |= length_sources(lets[ident], lets, depth + 1)
+    return found
+
+
+def arg_base(expr: str) -> str:
+    expr = strip_ptr(expr)
+    match = re.match(r"^&?\s*(?:mut\s+)?(?:self\s*\.\s*)?([A-Za-z_][A-Za-z0-9_]*)", expr)
+    return match.group(1) if match else ""
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "from_raw_parts" not in text and "copy" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end < 0:
+            continue
+        body = masked[brace:end + 1]
+        params = {}
+        for chunk in split_args(masked[fn.end():close_paren]):
+            param = re.match(r"(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(.+)$", chunk, re.S)
+            if param and param.group(1) != "self":
+                params[param.group(1)] = param.group(2)
+        lets = {stmt.group(1): stmt.group(2) for stmt in let_re.finditer(body)}
+        hits = []
+        if mode == "len":
+            for call in raw_parts_re.finditer(body):
+                close = find_matching_paren(body, call.end() - 1)
+                if close < 0:
+                    continue
+                args = split_args(body[call.end():close])
+                if len(args) != 2:
+                    continue
+                owner = pointer_owner(args[0], lets, params)
+                if not owner:
+                    continue
+                sources = length_sources(args[1], lets)
+                names = {name for name in sources if not re.match(r"^(?:[A-Z0-9_]+|usize|u\d+|i\d+|mem|size_of|size_of_val|min|max|len|capacity|as|std)$", name)}
+                if not names:
+                    continue
+                if owner.startswith("param:"):
+                    # A raw pointer parameter is paired with whatever integer length the caller passes in.
+                    length_params = {name for name, ty in params.items() if re.match(r"\s*(?:usize|u\d+|i\d+|c_\w+|libc\s*::\s*\w+|size_t)\s*$", ty)}
+                    if names & length_params or any(name.startswith("self.") for name in names):
+                        continue
+                    label = owner.split(":", 1)[1]
+                elif owner == "self":
+                    if any(name.startswith("self.") or name == "self" for name in names):
+                        continue
+                    label = "self"
+                else:
+                    base = owner.split(".")[-1]
+                    if owner in names or base in names or f"self.{base}" in names:
+                        continue
+                    label = owner
+                length = re.sub(r"\s+", " ", args[1].strip())
+                hits.append((call.start(), f"length {length} is not derived from {label}, the allocation behind the pointer"))
+        else:
+            for call in copy_re.finditer(body):
+                if body[max(0, call.start() - 1)] == ".":
+                    continue
+                close = find_matching_paren(body, call.end() - 1)
+                if close < 0:
+                    continue
+                args = split_args(body[call.end():close])
+                if len(args) != 3:
+                    continue
+                src, dst = args[0], args[1]
+                src_base, dst_base = arg_base(src), arg_base(dst)
+                reason = None
+                if re.search(r"\.\s*as_mut_ptr\s*\(", src) and re.search(r"\.\s*as_ptr\s*\(", dst):
+                    reason = f"source {src_base}.as_mut_ptr() and destination {dst_base}.as_ptr() look swapped"
+                elif DST_NAME.search(src_base) or SRC_NAME.search(dst_base):
+                    reason = f"{call.group(1)}(src, dst, n) called with {src_base} as src and {dst_base} as dst"
+                if reason:
+                    hits.append((call.start(), reason))
+        for offset, reason in hits:
+            line_no = line_number(masked, brace + offset)
+            line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+            if "ubs:ignore" in line:
+                continue
+            key = (path, line_no)
+            if key in seen:
+                continue
+            seen.add(key)
+            print(f"{path}:{line_no}:{line.strip()}  [{reason}]")
+PY
+}
+
+count_ffi_buffer_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_ffi_buffer_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_ffi_buffer_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_ffi_buffer_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_ffi_buffer() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_ffi_buffer_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_drop_order_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import (
+    find_matching_brace,
+    find_matching_paren,
+    line_number,
+    mask_comments_and_strings,
+    rust_files,
+)
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+def split_top(text: str):
+    parts, depth, start = [], 0, 0
+    for idx, ch in enumerate(text):
+        if ch in "([{<":
+            depth += 1
+        elif ch in ")]}>":
+            depth -= 1
+        elif ch == "," and depth == 0:
+            parts.append((start, text[start:idx]))
+            start = idx + 1
+    if text[start:].strip():
+        parts.append((start, text[start:]))
+    return parts
+
+
+def enclosing_block(text: str, offset: int) -> int:
+    depth = 0
+    for idx in range(offset - 1, -1, -1):
+        ch = text[idx]
+        if ch == "}":
+            depth += 1
+        elif ch == "{":
+            if depth == 0:
+                return idx
+            depth -= 1
+    return -1
+
+
+struct_re = re.compile(r"\bstruct\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^{;]*>)?\s*(?:where[^{;]*)?\{")
+fn_re = re.compile(r"\bfn\s+[A-Za-z_][A-Za-z0-9_]*\s*(?:<[^>{]*>)?\s*\(")
+let_re = re.compile(r"\blet\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*(?::[^=;]+)?=([^;]*);")
+static_borrow_re = re.compile(r"<\s*'static\b|&\s*'static\s+(?:mut\s+)?[A-Za-z_]")
+resource_re = re.compile(
+    r"\b(?:File\s*::\s*(?:create|open)|OpenOptions\s*::\s*new|BufWriter\s*::\s*new|Connection\s*::\s*open"
+    r"|TcpStream\s*::\s*connect|UnixStream\s*::\s*connect)\s*\(|\.\s*(?:spawn|begin|transaction|connect)\s*\(|\bpool\s*\.\s*get\s*\("
+)
+guard_re = re.compile(
+    r"\.\s*(?P<permit>acquire|acquire_owned|acquire_many|try_acquire|try_acquire_owned)\s*\("
+    r"|\.\s*(?P<flock>lock_exclusive|try_lock_exclusive|lock_shared|try_lock_shared)\s*\(|\bflock\s*\("
+    r"|\.\s*(?P<lock>lock|write)\s*\(\s*\)"
+)
+seen = set()
+
+
+def expand(expr: str, lets, depth=0):
+    found = set(re.findall(r"(?<![A-Za-z0-9_.])[A-Za-z_][A-Za-z0-9_]*", expr))
+    if depth < 4:
+        for ident in list(found):
+            if ident in lets:
+                found |= expand(lets[ident], lets, depth + 1)
+    return found
+
+
+def emit(path, lines, masked, offset, tag):
+    line_no = line_number(masked, offset)
+    line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+    if "ubs:ignore" in line:
+        return
+    key = (path, line_no)
+    if key in seen:
+        return
+    seen.add(key)
+    print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    fns = []
+    for fn in fn_re.finditer(masked):
+        close_paren = find_matching_paren(masked, fn.end() - 1)
+        if close_paren < 0:
+            continue
+        brace = masked.find("{", close_paren)
+        semi = masked.find(";", close_paren)
+        if brace < 0 or (0 <= semi < brace):
+            continue
+        end = find_matching_brace(masked, brace)
+        if end >= 0:
+            fns.append((brace, end))
+    if mode == "fields":
+        for struct in struct_re.finditer(masked):
+            name = struct.group(1)
+            open_brace = struct.end() - 1
+            close_brace = find_matching_brace(masked, open_brace)
+            if close_brace < 0:
+                continue
+            fields = []
+            for rel, chunk in split_top(masked[open_brace + 1:close_brace]):
+                field = re.match(r"\s*(?:#\s*\[[^\]]*\]\s*)*(?:pub(?:\s*\([^)]*\))?\s+)?([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(.+)$", chunk, re.S)
+                if field:
+                    lead = len(chunk) - len(chunk.lstrip())
+                    fields.append((field.group(1), field.group(2).strip(), open_brace + 1 + rel + lead))
+            if len(fields) < 2:
+                continue
+            order = {field[0]: index for index, field in enumerate(fields)}
+            # A borrow widened to 'static still runs its own Drop against the owner, after the struct's Drop.
+            borrowers = {}
+            for field_name, field_type, offset in fields:
+                if static_borrow_re.search(field_type):
+                    borrowers[field_name] = offset
+            if not borrowers:
+                continue
+            literal_re = re.compile(rf"(?<![A-Za-z0-9_])(?:{re.escape(name)}|Self)\s*\{{")
+            for fn_start, fn_end in fns:
+                body = masked[fn_start:fn_end + 1]
+                lets = {stmt.group(1): stmt.group(2) for stmt in let_re.finditer(body)}
+                for literal in literal_re.finditer(body):
+                    lit_end = find_matching_brace(body, literal.end() - 1)
+                    if lit_end < 0:
+                        continue
+                    values = {}
+                    for _, chunk in split_top(body[literal.end():lit_end]):
+                        init = re.match(r"\s*([A-Za-z_][A-Za-z0-9_]*)\s*(?::\s*(.+))?$", chunk, re.S)
+                        if init and init.group(1) in order:
+                            values[init.group(1)] = (init.group(2) or init.group(1)).strip()
+                    for borrower, decl_offset in borrowers.items():
+                        if borrower not in values:
+                            continue
+                        reach = expand(values[borrower], lets)
+                        for owner, owner_value in values.items():
+                            if owner == borrower or order[owner] > order[borrower]:
+                                continue
+                            if not re.match(r"^[A-Za-z_][A-Za-z0-9_]*$", owner_value) or owner_value not in reach:
+                                continue
+                            emit(path, lines, masked, decl_offset,
+                                 f"{borrower} borrows from {owner} as 'static, but {owner} is declared first and is dropped before it")
+                            break
+    else:
+        for fn_start, fn_end in fns:
+            body = masked[fn_start:fn_end + 1]
+            stmts = list(let_re.finditer(body))
+            for idx, resource in enumerate(stmts):
+                if not resource_re.search(resource.group(2)):
+                    continue
+                block = enclosing_block(body, resource.start())
+                block_end = find_matching_brace(body, block)
+                if block < 0 or block_end < 0:
+                    continue
+                res_name = resource.group(1)
+                for guard in stmts[idx + 1:]:
+                    if guard.start() > block_end:
+                        break
+                    kind = guard_re.search(guard.group(2))
+                    if not kind or enclosing_block(body, guard.start()) != block:
+                        continue
+                    if re.search(rf"\b{re.escape(res_name)}\b", guard.group(2)):
+                        continue
+                    after = body[guard.end():block_end]
+                    if not re.search(rf"\b{re.escape(res_name)}\b", after):
+                        continue
+                    if re.search(rf"\bdrop\s*\(\s*{re.escape(res_name)}\s*\)", after):
+                        continue
+                    label = "permit" if kind.group("permit") else "lock guard"
+                    emit(path, lines, masked, fn_start + guard.start(),
+                         f"{label} {guard.group(1)} taken after {res_name}; locals drop in reverse order, so it is released before {res_name} is closed")
+                    break
+PY
+}
+
+count_drop_order_matches() {
+  local mode="$1"
+  if [[ "$have_python3" -eq 1 ]]; then
+    cached_rust_matches rust_drop_order_matches "$mode" | count_lines || true
+  else
+    return 1
+  fi
+}
+
+show_drop_order_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  local printed=0
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_drop_order_matches "$mode" | head -n "$limit")
+  [[ "$printed" -gt 0 ]]
+}
+
+collect_samples_drop_order() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
+  if [[ "$have_python3" -ne 1 ]]; then
+    printf '[]'
+    return
+  fi
+  mapfile -t lines < <(cached_rust_matches rust_drop_order_matches "$mode" | head -n "$limit")
+  printf '['
+  local i=0
+  local line
+  for line in "${lines[@]}"; do
+    [[ $i -gt 0 ]] && printf ','
+    printf '"%s"' "$(printf '%s' "$line" | json_escape)"
+    i=$((i + 1))
+  done
+  printf ']'
+}
+
+rust_duration_unit_matches() {
+  local mode="$1"
+  [[ "$have_python3" -eq 1 ]] || return 1
+  python3 - "$PROJECT_DIR" "$mode" <<'PY'
+import os
+import re
+import signal
+import sys
+from pathlib import Path
+
+sys.path.insert(0, os.environ["UBS_RUST_HELPER_DIR"])
+from rust_scan_common import find_matching_paren, line_number, mask_comments_and_strings, rust_files
+
+signal.signal(signal.SIGPIPE, signal.SIG_DFL)
+root = Path(sys.argv[1])
+mode = sys.argv[2]
+
+
+UNIT_WORDS = {
+    "ms": "ms", "msec": "ms", "msecs": "ms", "millis": "ms", "milli": "ms", "milliseconds": "ms", "millisecond": "ms",
+    "s": "s", "sec": "s", "secs": "s", "second": "s", "seconds": "s",
+    "us": "us", "usec": "us", "usecs": "us", "micros": "us", "microseconds": "us",
+    "ns": "ns", "nanos": "ns", "nanoseconds": "ns",
+    "min": "min", "mins": "min", "minute": "min", "minutes": "min",
+    "h": "h", "hr": "h", "hrs": "h", "hour": "h", "hours": "h",
+}
+UNIT_NAMES = {"ms": "milliseconds", "s": "seconds", "us": "microseconds", "ns": "nanoseconds", "min": "minutes", "h": "hours"}
+CTOR_UNITS = {"from_secs": "s", "from_secs_f32": "s", "from_secs_f64": "s", "from_millis": "ms", "from_micros": "us", "from_nanos": "ns", "from_mins": "min", "from_hours": "h"}
+AS_UNITS = {"as_secs": "s", "as_secs_f32": "s", "as_secs_f64": "s", "as_millis": "ms", "as_micros": "us", "as_nanos": "ns"}
+TIME_WORDS = {"timeout", "delay", "interval", "ttl", "deadline", "expiry", "expiration", "backoff", "period", "elapsed", "duration", "wait", "grace", "lifetime", "age"}
+ctor_re = re.compile(r"\bDuration\s*::\s*(from_(?:secs(?:_f32|_f64)?|millis|micros|nanos|mins|hours))\s*\(")
+as_re = re.compile(r"\.\s*(as_(?:secs(?:_f32|_f64)?|millis|micros|nanos))\s*\(\s*\)")
+cmp_re = re.compile(r"(?<![=!<>])(?:<=|>=|==|!=|<|>)(?![=>])")
+seen = set()
+
+
+def name_tokens(name: str):
+    spaced = re.sub(r"([a-z0-9])([A-Z])", r"\1_\2", name)
+    return [tok.lower() for tok in spaced.split("_") if tok]
+
+
+def name_unit(expr: str):
+    """Unit implied by the last identifier of a plain variable/field expression."""
+    expr = re.sub(r"\s+as\s+[a-z0-9]+\s*$", "", expr.strip())
+    expr = re.sub(r"^\(\s*(.*?)\s*\)$", r"\1", expr)
+    expr = re.sub(r"^[&*]+\s*", "", expr)
+    if not re.match(r"^[A-Za-z_][A-Za-z0-9_]*(?:\s*\.\s*[A-Za-z_][A-Za-z0-9_]*)*$", expr):
+        return None, None
+    last = re.split(r"\s*\.\s*", expr)[-1]
+    tokens = name_tokens(last)
+    if not tokens:
+        return None, last
+    # A bare `s`, `h` or `us` is more often a string, hash or list than a unit.
+    if len(tokens) == 1 and len(tokens[0]) < 4 and tokens[0] != "ms":
+        return None, last
+    return UNIT_WORDS.get(tokens[-1]), last
+
+
+for path in rust_files(root):
+    try:
+        text = path.read_text(encoding="utf-8", errors="replace")
+    except OSError:
+        continue
+    if "Duration" not in text and ".as_" not in text:
+        continue
+    masked = mask_comments_and_strings(text)
+    lines = text.splitlines()
+    hits = []
+    if mode == "ctor":
+        for call in ctor_re.finditer(masked):
+            close = find_matching_paren(masked, call.end() - 1)
+            if close < 0:
+                continue
+            arg = masked[call.end():close].strip()
+            want = CTOR_UNITS[call.group(1)]
+            inner = as_re.search(arg)
+            if inner and re.search(r"\.\s*as_[a-z0-9_]+\s*\(\s*\)\s*(?:as\s+[a-z0-9]+)?$", arg) and AS_UNITS[inner.group(1)] != want:
+                hits.append((call.start(), f"Duration::{call.group(1)} fed {inner.group(1)}() ({UNIT_NAMES[AS_UNITS[inner.group(1)]]})"))
+                continue
+            unit, last = name_unit(arg)
+            if unit and unit != want:
+                hits.append((call.start(), f"Duration::{call.group(1)} given {last}, which reads as {UNIT_NAMES[unit]}"))
+    else:
+        for hit in as_re.finditer(masked):
+            unit = AS_UNITS[hit.group(1)]
+            line_start = masked.rfind("\n", 0, hit.start()) + 1
+            line_end = masked.find("\n", hit.end())
+            if line_end < 0:
+                line_end = len(masked)
+            tail = masked[hit.end():line_end]
+            op = re.match(r"\s*(?:as\s+[a-z0-9]+\s*)?(<=|>=|==|!=|<|>)(?![=>])\s*([^;{)]*)", tail)
+            other = None
+            if op:
+                other = op.group(2).strip()
+            else:
+                head = masked[line_start:hit.start()]
+                before = re.search(r"([A-Za-z_][A-Za-z0-9_.\s]*?)\s*(<=|>=|==|!=|<|>)\s*[A-Za-z_][A-Za-z0-9_.()\s]*$", head)
+                if before and not re.search(r"[<>]\s*$", before.group(1)):
+                    other = before.group(1).strip()
+            if not other:
+                continue
+            other = re.split(r"\s*(?:&&|\|\||\{)\s*", other)[0].strip()
+            other_unit, last = name_unit(other)
+            if other_unit and other_unit != unit:
+                hits.append((hit.start(), f"{hit.group(1)}() ({UNIT_NAMES[unit]}) compared with {last} ({UNIT_NAMES[other_unit]})"))
+            elif other_unit is None and last and set(name_tokens(last)) & TIME_WORDS and not re.search(r"\.\s*as_", other):
+                hits.append((hit.start(), f"{hit.group(1)}() compared with raw integer {last} of unstated unit"))
+    for offset, tag in hits:
+        line_no = line_number(masked, offset)
+        line = lines[line_no - 1] if line_no - 1 < len(lines) else ""
+        if "ubs:ignore" in line:
+            continue
+        key = (path, line_no)
         if key in seen:
             continue
         seen.add(key)
-        print(f"{path}:{line}:{code}")
+        print(f"{path}:{line_no}:{line.strip()}  [{tag}]")
 PY
 }
 
-count_format_literal_matches() {
+count_duration_unit_matches() {
+  local mode="$1"
   if [[ "$have_python3" -eq 1 ]]; then
-    rust_format_literal_matches | count_lines || true
+    cached_rust_matches rust_duration_unit_matches "$mode" | count_lines || true
   else
-    "${GREP_RN[@]}" -e "format!\(\s*([rR]?#?\"[^\{\}]*\"#?)\s*,?\s*\)" "$PROJECT_DIR" 2>/dev/null | count_lines || true
+    return 1
   fi
 }
 
-show_format_literal_examples() {
-  local limit="${1:-$DETAIL_LIMIT}"
+show_duration_unit_examples() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
   local printed=0
-  if [[ "$have_python3" -eq 1 ]]; then
-    while IFS= read -r rawline; do
-      [[ -z "$rawline" ]] && continue
-      parse_grep_line "$rawline" || continue
-      print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
-      printed=$((printed + 1))
-      [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
-    done < <(rust_format_literal_matches | head -n "$limit")
-  else
-    show_detailed_finding "format!\(\s*([rR]?#?\"[^\{\}]*\"#?)\s*,?\s*\)" "$limit"
-    return $?
-  fi
+  [[ "$have_python3" -eq 1 ]] || return 1
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"
+    printed=$((printed + 1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <(cached_rust_matches rust_duration_unit_matches "$mode" | head -n "$limit")
   [[ "$printed" -gt 0 ]]
 }
 
-collect_samples_format_literal() {
-  local limit="${1:-$DETAIL_LIMIT}"
+collect_samples_duration_unit() {
+  local mode="$1"
+  local limit="${2:-$DETAIL_LIMIT}"
   if [[ "$have_python3" -ne 1 ]]; then
-    collect_samples_rg "format!\(\s*([rR]?#?\"[^\{\}]*\"#?)\s*,?\s*\)" "$limit"
+    printf '[]'
     return
   fi
-  mapfile -t lines < <(rust_format_literal_matches | head -n "$limit")
+  mapfile -t lines < <(cached_rust_matches rust_duration_unit_matches "$mode" | head -n "$limit")
   printf '['
   local i=0
   local line
@@ -6531,7 +20474,7 @@ rule:
     - pattern: mem::transmute($X)
     - pattern: transmute($X)
 severity: error
-message: "std::mem::transmute is unsafe and error-prone; prefer safe conversions"
+message: "std::mem::transmute is unsafe and error-prone; prefer `as` casts, from_ne_bytes/to_ne_bytes, or from_raw_parts"
 YAML
 
   cat >"$AST_RULE_DIR/uninitialized.yml" <<'YAML'
@@ -6676,7 +20619,18 @@ language: rust
 rule:
   pattern: $M.lock().unwrap()
 severity: warning
-message: "Mutex::lock().unwrap(); poisoned lock panics; handle error explicitly"
+message: "Mutex::lock().unwrap(); poisoned lock panics; use expect(..) or PoisonError::into_inner"
+YAML
+
+  cat >"$AST_RULE_DIR/rwlock-unwrap.yml" <<'YAML'
+id: rust.rwlock-unwrap
+language: rust
+rule:
+  any:
+    - pattern: $M.read().unwrap()
+    - pattern: $M.write().unwrap()
+severity: warning
+message: "RwLock::read()/write().unwrap(); poisoned lock panics; use expect(..) or PoisonError::into_inner"
 YAML
 
   cat >"$AST_RULE_DIR/lock-expect.yml" <<'YAML'
@@ -7558,6 +21512,10 @@ if [[ "$LIST_CATEGORIES" -eq 1 ]]; then
   exit 0
 fi
 
+if [[ "$have_python3" -eq 1 ]]; then
+  RUST_MATCH_CACHE_DIR="$(mktemp -d 2>/dev/null || mktemp -d -t ubs-rust-matches.XXXXXX)"
+fi
+
 # relax pipefail for scanning
 begin_scan_section
 
@@ -7566,7 +21524,7 @@ begin_scan_section
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 1; then
 print_header "1. OWNERSHIP & ERROR HANDLING MACROS"
-print_category "Detects: unwrap/expect, panic/unreachable/todo/unimplemented, dbg/println" \
+print_category "Detects: unwrap/expect, let _ = / .ok() discarded Results, Results lost to shadowing, drop-order hazards, unreachable/error-swallowing match arms, error context loss, panic/unreachable/todo/unimplemented, dbg/println (library debug output)" \
   "Panic-prone and debug macros frequently leak into production and cause crashes"
 
 print_subheader "unwrap()/expect() usage"
@@ -7574,17 +21532,136 @@ print_subheader "unwrap()/expect() usage"
 unwrap_patterns=('$X.unwrap()')
 # shellcheck disable=SC2016
 expect_patterns=('$X.expect($MSG)')
-u_total=$(count_ast_or_rg "\.unwrap\(" "${unwrap_patterns[@]}")
-e_total=$(count_ast_or_rg "\.expect\(" "${expect_patterns[@]}")
-ue_total=$((u_total + e_total))
+# Guard-aware pass: unwraps inside `if x.is_some()`/`if let Some(..) = x` blocks,
+# `Some(..)`/`Ok(..)` match arms, or after an exiting `is_none()` guard are skipped.
+if [[ "$have_python3" -eq 1 ]]; then
+  ue_total=$(count_unguarded_unwrap_matches || echo 0)
+  ue_total=$(printf '%s\n' "${ue_total:-0}" | awk 'END{print $0+0}')
+else
+  u_total=$(count_ast_or_rg "\.unwrap\(" "${unwrap_patterns[@]}")
+  e_total=$(count_ast_or_rg "\.expect\(" "${expect_patterns[@]}")
+  ue_total=$((u_total + e_total))
+fi
 if [ "$ue_total" -gt 0 ]; then
   print_finding "warning" "$ue_total" "Potential panics via unwrap/expect" "Prefer \`?\` or match to propagate/handle errors"
-  show_ast_pattern_examples 5 "${unwrap_patterns[@]}" "${expect_patterns[@]}" || show_detailed_finding "\.(unwrap|expect)\(" 5
-  add_finding "warning" "$ue_total" "Potential panics via unwrap/expect" "Prefer \`?\` or match to propagate/handle errors" "${CATEGORY_NAME[1]}" "$(collect_samples_ast_or_rg "\.(unwrap|expect)\(" 5 "${unwrap_patterns[@]}" "${expect_patterns[@]}")"
+  if [[ "$have_python3" -eq 1 ]]; then
+    show_unguarded_unwrap_examples 5 || true
+    add_finding "warning" "$ue_total" "Potential panics via unwrap/expect" "Prefer \`?\` or match to propagate/handle errors" "${CATEGORY_NAME[1]}" "$(collect_samples_unguarded_unwrap 5)"
+  else
+    show_ast_pattern_examples 5 "${unwrap_patterns[@]}" "${expect_patterns[@]}" || show_detailed_finding "\.(unwrap|expect)\(" 5
+    add_finding "warning" "$ue_total" "Potential panics via unwrap/expect" "Prefer \`?\` or match to propagate/handle errors" "${CATEGORY_NAME[1]}" "$(collect_samples_ast_or_rg "\.(unwrap|expect)\(" 5 "${unwrap_patterns[@]}" "${expect_patterns[@]}")"
+  fi
 else
   print_finding "good" "No unwrap/expect detected"
 fi
 
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "let _ = discarding Results"
+  discarded_results=$(count_discarded_result_matches || echo 0)
+  discarded_results=$(printf '%s\n' "${discarded_results:-0}" | awk 'END{print $0+0}')
+  if [ "$discarded_results" -gt 0 ]; then
+    print_finding "warning" "$discarded_results" "let _ = silently discards a Result" "The call returns Result (a local fn, std::fs, File::create, write_all/flush, parse, join, channel send, ...); propagate with ?, handle the Err arm, or add a comment explaining why failure is acceptable"
+    show_discarded_result_examples 5 || true
+    add_finding "warning" "$discarded_results" "let _ = silently discards a Result" "The call returns Result (a local fn, std::fs, File::create, write_all/flush, parse, join, channel send, ...); propagate with ?, handle the Err arm, or add a comment explaining why failure is acceptable" "${CATEGORY_NAME[1]}" "$(collect_samples_discarded_result 5)"
+  else
+    print_finding "good" "No Results discarded with let _ ="
+  fi
+
+  print_subheader ".ok() discarding errors"
+  ok_statement_hits=$(count_ok_discard_matches statement || echo 0)
+  ok_statement_hits=$(printf '%s\n' "${ok_statement_hits:-0}" | awk 'END{print $0+0}')
+  ok_iflet_hits=$(count_ok_discard_matches iflet || echo 0)
+  ok_iflet_hits=$(printf '%s\n' "${ok_iflet_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$ok_statement_hits" -gt 0 ]; then
+    print_finding "warning" "$ok_statement_hits" "Result converted with .ok() and dropped" "expr.ok(); throws the error away without a trace; propagate with ?, log the Err, or leave a comment above the call explaining why failure is fine"
+    show_ok_discard_examples statement 5 || true
+    add_finding "warning" "$ok_statement_hits" "Result converted with .ok() and dropped" "expr.ok(); throws the error away without a trace; propagate with ?, log the Err, or leave a comment above the call explaining why failure is fine" "${CATEGORY_NAME[1]}" "$(collect_samples_ok_discard statement 5)"
+  fi
+  if [ "$ok_iflet_hits" -gt 0 ]; then
+    print_finding "info" "$ok_iflet_hits" "if let Some(..) = expr.ok() hides the error" "The failure branch never sees why the call failed; match on Ok/Err and log or return the error instead"
+    show_ok_discard_examples iflet 3 || true
+    add_finding "info" "$ok_iflet_hits" "if let Some(..) = expr.ok() hides the error" "The failure branch never sees why the call failed; match on Ok/Err and log or return the error instead" "${CATEGORY_NAME[1]}" "$(collect_samples_ok_discard iflet 3)"
+  fi
+  if [ "$ok_statement_hits" -eq 0 ] && [ "$ok_iflet_hits" -eq 0 ]; then
+    print_finding "good" "No errors discarded through .ok()"
+  fi
+
+  print_subheader "Results and guards lost to shadowing"
+  shadowed_results=$(count_shadowed_result_matches || echo 0)
+  shadowed_results=$(printf '%s\n' "${shadowed_results:-0}" | awk 'END{print $0+0}')
+  if [ "$shadowed_results" -gt 0 ]; then
+    print_finding "warning" "$shadowed_results" "Result or guard shadowed before it is used" "let x = f(); let x = g(); rebinds x while the first value, a Result or lock guard, was never inspected: the error is silently dropped, and a shadowed guard stays locked until the end of the scope. Handle the first value (x?, match, if let Err) or give the bindings distinct names"
+    show_shadowed_result_examples 5 || true
+    add_finding "warning" "$shadowed_results" "Result or guard shadowed before it is used" "let x = f(); let x = g(); rebinds x while the first value, a Result or lock guard, was never inspected: the error is silently dropped, and a shadowed guard stays locked until the end of the scope. Handle the first value (x?, match, if let Err) or give the bindings distinct names" "${CATEGORY_NAME[1]}" "$(collect_samples_shadowed_result 5)"
+  else
+    print_finding "good" "No Results or guards shadowed before use"
+  fi
+
+  print_subheader "Drop order of dependent resources"
+  drop_order_fields=$(count_drop_order_matches fields || echo 0)
+  drop_order_fields=$(printf '%s\n' "${drop_order_fields:-0}" | awk 'END{print $0+0}')
+  drop_order_locals=$(count_drop_order_matches locals || echo 0)
+  drop_order_locals=$(printf '%s\n' "${drop_order_locals:-0}" | awk 'END{print $0+0}')
+  if [ "$drop_order_fields" -gt 0 ]; then
+    print_finding "warning" "$drop_order_fields" "Borrowing field declared after the field it borrows from" "Struct fields drop in declaration order, so a field that borrows from a sibling through a 'static lifetime (a prepared statement, transaction, or guard over its connection) must be declared before the field it borrows from; otherwise its Drop runs against an owner that is already gone"
+    show_drop_order_examples fields 3 || true
+    add_finding "warning" "$drop_order_fields" "Borrowing field declared after the field it borrows from" "Struct fields drop in declaration order, so a field that borrows from a sibling through a 'static lifetime (a prepared statement, transaction, or guard over its connection) must be declared before the field it borrows from; otherwise its Drop runs against an owner that is already gone" "${CATEGORY_NAME[1]}" "$(collect_samples_drop_order fields 3)"
+  fi
+  if [ "$drop_order_locals" -gt 0 ]; then
+    print_finding "warning" "$drop_order_locals" "Guard declared after the resource it protects" "Locals drop in reverse declaration order: a permit or lock guard taken after the file, writer, or connection it protects is released first, so the resource is flushed or closed outside the critical section. Acquire the guard first, or drop() the resource before the guard goes out of scope"
+    show_drop_order_examples locals 3 || true
+    add_finding "warning" "$drop_order_locals" "Guard declared after the resource it protects" "Locals drop in reverse declaration order: a permit or lock guard taken after the file, writer, or connection it protects is released first, so the resource is flushed or closed outside the critical section. Acquire the guard first, or drop() the resource before the guard goes out of scope" "${CATEGORY_NAME[1]}" "$(collect_samples_drop_order locals 3)"
+  fi
+  if [ "$drop_order_fields" -eq 0 ] && [ "$drop_order_locals" -eq 0 ]; then
+    print_finding "good" "Dependent fields and guards drop in a safe order"
+  fi
+
+  print_subheader "Unreachable and error-swallowing match arms"
+  match_arm_dead=$(count_match_arm_matches unreachable || echo 0)
+  match_arm_dead=$(printf '%s\n' "${match_arm_dead:-0}" | awk 'END{print $0+0}')
+  match_arm_swallow=$(count_match_arm_matches swallow || echo 0)
+  match_arm_swallow=$(printf '%s\n' "${match_arm_swallow:-0}" | awk 'END{print $0+0}')
+  if [ "$match_arm_dead" -gt 0 ]; then
+    print_finding "warning" "$match_arm_dead" "Match arm can never run" "The arm repeats a pattern an earlier arm already matched, or follows a catch-all (_ or a bare binding) without a guard; its body is dead code and usually hides a typo in the pattern or a misordered arm"
+    show_match_arm_examples unreachable 3 || true
+    add_finding "warning" "$match_arm_dead" "Match arm can never run" "The arm repeats a pattern an earlier arm already matched, or follows a catch-all (_ or a bare binding) without a guard; its body is dead code and usually hides a typo in the pattern or a misordered arm" "${CATEGORY_NAME[1]}" "$(collect_samples_match_arm unreachable 3)"
+  fi
+  if [ "$match_arm_swallow" -gt 0 ]; then
+    print_finding "warning" "$match_arm_swallow" "Wildcard arm silently swallows error variants" "_ => {} / Err(_) => Ok(default) on a local error enum hides every variant not listed, including ones added later; name the remaining variants so the compiler flags new ones, or log/propagate the error in the fallback arm"
+    show_match_arm_examples swallow 3 || true
+    add_finding "warning" "$match_arm_swallow" "Wildcard arm silently swallows error variants" "_ => {} / Err(_) => Ok(default) on a local error enum hides every variant not listed, including ones added later; name the remaining variants so the compiler flags new ones, or log/propagate the error in the fallback arm" "${CATEGORY_NAME[1]}" "$(collect_samples_match_arm swallow 3)"
+  fi
+  if [ "$match_arm_dead" -eq 0 ] && [ "$match_arm_swallow" -eq 0 ]; then
+    print_finding "good" "No unreachable or error-swallowing match arms"
+  fi
+
+  print_subheader "Error context loss"
+  ctx_drop_hits=$(count_error_context_matches drop || echo 0)
+  ctx_drop_hits=$(printf '%s\n' "${ctx_drop_hits:-0}" | awk 'END{print $0+0}')
+  ctx_string_hits=$(count_error_context_matches stringify || echo 0)
+  ctx_string_hits=$(printf '%s\n' "${ctx_string_hits:-0}" | awk 'END{print $0+0}')
+  ctx_source_hits=$(count_error_context_matches source || echo 0)
+  ctx_source_hits=$(printf '%s\n' "${ctx_source_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$ctx_drop_hits" -gt 0 ]; then
+    print_finding "warning" "$ctx_drop_hits" "map_err discards the source error" ".map_err(|_| MyError::Generic) throws away the underlying io/parse error; carry it in a #[source]/#[from] field or use anyhow::Context so the chain stays traceable"
+    show_error_context_examples drop 3 || true
+    add_finding "warning" "$ctx_drop_hits" "map_err discards the source error" ".map_err(|_| MyError::Generic) throws away the underlying io/parse error; carry it in a #[source]/#[from] field or use anyhow::Context so the chain stays traceable" "${CATEGORY_NAME[1]}" "$(collect_samples_error_context drop 3)"
+  fi
+  if [ "$ctx_string_hits" -gt 0 ]; then
+    print_finding "info" "$ctx_string_hits" "Error flattened to a string" "map_err(|e| e.to_string()) / format!(..) into Box<dyn Error> keeps the message but loses source() and downcasting; prefer .context(..) or a typed variant"
+    show_error_context_examples stringify 3 || true
+    add_finding "info" "$ctx_string_hits" "Error flattened to a string" "map_err(|e| e.to_string()) / format!(..) into Box<dyn Error> keeps the message but loses source() and downcasting; prefer .context(..) or a typed variant" "${CATEGORY_NAME[1]}" "$(collect_samples_error_context stringify 3)"
+  fi
+  if [ "$ctx_source_hits" -gt 0 ]; then
+    print_finding "info" "$ctx_source_hits" "Wrapped error field not marked #[source]" "thiserror only reports a field from source() when it is #[from], #[source], or named source; otherwise the chain stops at this variant"
+    show_error_context_examples source 3 || true
+    add_finding "info" "$ctx_source_hits" "Wrapped error field not marked #[source]" "thiserror only reports a field from source() when it is #[from], #[source], or named source; otherwise the chain stops at this variant" "${CATEGORY_NAME[1]}" "$(collect_samples_error_context source 3)"
+  fi
+  if [ "$ctx_drop_hits" -eq 0 ] && [ "$ctx_string_hits" -eq 0 ] && [ "$ctx_source_hits" -eq 0 ]; then
+    print_finding "good" "Error conversions keep their source"
+  fi
+fi
+
 print_subheader "panic!/unreachable!/todo!/unimplemented!"
 # shellcheck disable=SC2016
 panic_patterns=('panic!($$$ARGS)')
@@ -7594,29 +21671,45 @@ unreachable_patterns=('unreachable!($$$ARGS)')
 todo_patterns=('todo!($$$ARGS)')
 # shellcheck disable=SC2016
 unimplemented_patterns=('unimplemented!($$$ARGS)')
-p_count=$(count_ast_or_rg "panic!\(" "${panic_patterns[@]}")
-u_count=$(count_ast_or_rg "unreachable!\(" "${unreachable_patterns[@]}")
-t_count=$(count_ast_or_rg "todo!\(" "${todo_patterns[@]}")
-ui_count=$(count_ast_or_rg "unimplemented!\(" "${unimplemented_patterns[@]}")
-if [ "$p_count" -gt 0 ]; then
-  print_finding "critical" "$p_count" "panic! macro(s) present" "Avoid panic! in library code"
-  show_ast_pattern_examples 5 "${panic_patterns[@]}" || show_detailed_finding "panic!\(" 5
-  add_finding "critical" "$p_count" "panic! macro(s) present" "Avoid panic! in library code" "${CATEGORY_NAME[1]}" "$(collect_samples_ast_or_rg "panic!\(" 5 "${panic_patterns[@]}")"
-else
-  print_finding "good" "No panic! macros"
-fi
-if [ "$u_count" -gt 0 ]; then
-  print_finding "warning" "$u_count" "unreachable! may panic if reached" "Double-check logic"
-  add_finding "warning" "$u_count" "unreachable! may panic if reached" "Double-check logic" "${CATEGORY_NAME[1]}" "$(collect_samples_ast_or_rg "unreachable!\(" 3 "${unreachable_patterns[@]}")"
-fi
-if [ "$t_count" -gt 0 ]; then
-  print_finding "warning" "$t_count" "todo! placeholders present" "Implement or gate with cfg(test)"
-  add_finding "warning" "$t_count" "todo! placeholders present" "Implement or gate with cfg(test)" "${CATEGORY_NAME[1]}" "$(collect_samples_ast_or_rg "todo!\(" 3 "${todo_patterns[@]}")"
-fi
-if [ "$ui_count" -gt 0 ]; then
-  print_finding "warning" "$ui_count" "unimplemented! placeholders present" "Implement or remove"
-  add_finding "warning" "$ui_count" "unimplemented! placeholders present" "Implement or remove" "${CATEGORY_NAME[1]}" "$(collect_samples_ast_or_rg "unimplemented!\(" 3 "${unimplemented_patterns[@]}")"
-fi
+declare -A panic_macro_title=(
+  [panic]="panic! macro(s) present"
+  [unreachable]="unreachable! may panic if reached"
+  [todo]="todo! placeholders present"
+  [unimplemented]="unimplemented! placeholders present"
+)
+declare -A panic_macro_desc=(
+  [panic]="Avoid panic! in library code; return a Result to the caller"
+  [unreachable]="Double-check logic"
+  [todo]="Implement or gate with cfg(test)"
+  [unimplemented]="Implement or remove"
+)
+for macro_name in panic todo unimplemented unreachable; do
+  macro_sev=$(panic_macro_severity "$macro_name")
+  [[ "$macro_sev" == "off" ]] && continue
+  if [[ "$have_python3" -eq 1 ]]; then
+    # Production paths only: tests/ and benches/ trees plus #[cfg(test)] and
+    # #[test] items are skipped.
+    macro_count=$(count_panic_macro_matches "$macro_name" || echo 0)
+    macro_count=$(printf '%s\n' "${macro_count:-0}" | awk 'END{print $0+0}')
+    if [ "$macro_count" -gt 0 ]; then
+      print_finding "$macro_sev" "$macro_count" "${panic_macro_title[$macro_name]}" "${panic_macro_desc[$macro_name]}"
+      show_panic_macro_examples "$macro_name" 3 || true
+      add_finding "$macro_sev" "$macro_count" "${panic_macro_title[$macro_name]}" "${panic_macro_desc[$macro_name]}" "${CATEGORY_NAME[1]}" "$(collect_samples_panic_macro "$macro_name" 3)"
+    elif [[ "$macro_name" == "panic" ]]; then
+      print_finding "good" "No panic! macros"
+    fi
+    continue
+  fi
+  macro_patterns="${macro_name}_patterns[@]"
+  macro_count=$(count_ast_or_rg "${macro_name}!\(" "${!macro_patterns}")
+  if [ "$macro_count" -gt 0 ]; then
+    print_finding "$macro_sev" "$macro_count" "${panic_macro_title[$macro_name]}" "${panic_macro_desc[$macro_name]}"
+    show_ast_pattern_examples 3 "${!macro_patterns}" || show_detailed_finding "${macro_name}!\(" 3
+    add_finding "$macro_sev" "$macro_count" "${panic_macro_title[$macro_name]}" "${panic_macro_desc[$macro_name]}" "${CATEGORY_NAME[1]}" "$(collect_samples_ast_or_rg "${macro_name}!\(" 3 "${!macro_patterns}")"
+  elif [[ "$macro_name" == "panic" ]]; then
+    print_finding "good" "No panic! macros"
+  fi
+done
 
 print_subheader "dbg!/println!/eprintln!"
 # shellcheck disable=SC2016
@@ -7641,6 +21734,27 @@ if [ "$epln_count" -gt 0 ]; then
   add_finding "info" "$epln_count" "eprintln! found - prefer logging" "" "${CATEGORY_NAME[1]}" "$(collect_samples_ast_or_rg "eprintln!\(" 3 "${eprintln_patterns[@]}")"
 fi
 
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Leftover debug output in library targets"
+  debug_dbg_hits=$(count_debug_output_matches dbg || echo 0)
+  debug_dbg_hits=$(printf '%s\n' "${debug_dbg_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$debug_dbg_hits" -gt 0 ]; then
+    print_finding "warning" "$debug_dbg_hits" "dbg! left in non-test code" "dbg! writes file, line, and the value to stderr on every call; it is a debugging aid, so remove it from library code or replace it with tracing::debug!/log::debug! behind the caller's subscriber"
+    show_debug_output_examples dbg 3 || true
+    add_finding "warning" "$debug_dbg_hits" "dbg! left in non-test code" "dbg! writes file, line, and the value to stderr on every call; it is a debugging aid, so remove it from library code or replace it with tracing::debug!/log::debug! behind the caller's subscriber" "${CATEGORY_NAME[1]}" "$(collect_samples_debug_output dbg 3)"
+  fi
+  debug_print_hits=$(count_debug_output_matches print || echo 0)
+  debug_print_hits=$(printf '%s\n' "${debug_print_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$debug_print_hits" -gt 0 ]; then
+    print_finding "warning" "$debug_print_hits" "println!/eprintln! in library code" "Libraries that print to stdout/stderr corrupt the output of binaries that depend on them and cannot be filtered or silenced; use tracing or log macros, or return the text to the caller. Adjust which targets are checked with --debug-output-targets"
+    show_debug_output_examples print 3 || true
+    add_finding "warning" "$debug_print_hits" "println!/eprintln! in library code" "Libraries that print to stdout/stderr corrupt the output of binaries that depend on them and cannot be filtered or silenced; use tracing or log macros, or return the text to the caller. Adjust which targets are checked with --debug-output-targets" "${CATEGORY_NAME[1]}" "$(collect_samples_debug_output print 3)"
+  fi
+  if [ "$debug_dbg_hits" -eq 0 ] && [ "$debug_print_hits" -eq 0 ]; then
+    print_finding "good" "No leftover debug output in ${DEBUG_OUTPUT_TARGETS} targets"
+  fi
+fi
+
 print_subheader "Guard clauses that still unwrap later"
 run_rust_type_narrowing_checks
 fi
@@ -7650,7 +21764,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 2; then
 print_header "2. UNSAFE & MEMORY OPERATIONS"
-print_category "Detects: unsafe blocks, transmute/uninitialized/zeroed/forget, raw ffi hazards" \
+print_category "Detects: unsafe blocks, transmute/uninitialized/zeroed/forget, raw ffi hazards, Send/Sync impls over Rc/Cell/raw pointers, lifetime-extending transmutes, repr(Rust) layout transmutes, pointers into dropped temporaries, from_raw_parts lengths unrelated to the pointer, swapped copy_nonoverlapping arguments" \
   "These patterns may introduce UB, memory leaks, or hard-to-debug crashes"
 
 print_subheader "unsafe { ... } blocks"
@@ -7664,9 +21778,37 @@ else
   print_finding "good" "No unsafe blocks detected"
 fi
 
-print_subheader "transmute, uninitialized, zeroed, assume_init, forget"
-# shellcheck disable=SC2016
-transmute_count=$(count_ast_or_rg 'transmute\(' 'std::mem::transmute($X)' 'mem::transmute($X)' 'transmute($X)')
+print_subheader "unsafe blocks / unsafe fn without // SAFETY: comments"
+if [[ "$have_python3" -eq 1 ]]; then
+  undocumented_unsafe=$(count_unsafe_safety_matches || echo 0)
+  undocumented_unsafe=$(printf '%s\n' "${undocumented_unsafe:-0}" | awk 'END{print $0+0}')
+  if [ "$undocumented_unsafe" -gt 0 ]; then
+    print_finding "warning" "$undocumented_unsafe" "unsafe without a // SAFETY: comment" "State the invariant that makes the block sound in a // SAFETY: comment (or a # Safety doc section on unsafe fn); use --unsafe-min-lines=N to report only larger blocks"
+    show_unsafe_safety_examples 3 || true
+    add_finding "warning" "$undocumented_unsafe" "unsafe without a // SAFETY: comment" "State the invariant that makes the block sound in a // SAFETY: comment (or a # Safety doc section on unsafe fn); use --unsafe-min-lines=N to report only larger blocks" "${CATEGORY_NAME[2]}" "$(collect_samples_unsafe_safety 3)"
+  else
+    print_finding "good" "Every unsafe block documents its SAFETY invariant"
+  fi
+fi
+
+print_subheader "transmute, uninitialized, zeroed, assume_init, forget/leak"
+# Size-mismatched or heap-owning -> integer transmutes are escalated separately.
+transmute_escalated=0
+transmute_lifetime=0
+transmute_layout=0
+if [[ "$have_python3" -eq 1 ]]; then
+  transmute_layout=$(count_transmute_matches layout || echo 0)
+  transmute_layout=$(printf '%s\n' "${transmute_layout:-0}" | awk 'END{print $0+0}')
+  transmute_lifetime=$(count_transmute_matches lifetime || echo 0)
+  transmute_lifetime=$(printf '%s\n' "${transmute_lifetime:-0}" | awk 'END{print $0+0}')
+  transmute_escalated=$(count_transmute_matches escalated || echo 0)
+  transmute_escalated=$(printf '%s\n' "${transmute_escalated:-0}" | awk 'END{print $0+0}')
+  transmute_count=$(count_transmute_matches plain || echo 0)
+  transmute_count=$(printf '%s\n' "${transmute_count:-0}" | awk 'END{print $0+0}')
+else
+  # shellcheck disable=SC2016
+  transmute_count=$(count_ast_or_rg 'transmute\(' 'std::mem::transmute($X)' 'mem::transmute($X)' 'transmute($X)')
+fi
 # shellcheck disable=SC2016
 uninit_count=$(count_ast_or_rg 'uninitialized::<' 'std::mem::uninitialized::<$T>()' 'mem::uninitialized::<$T>()')
 # shellcheck disable=SC2016
@@ -7677,12 +21819,33 @@ zeroed_count=$(count_ast_or_rg '(^|[^[:alnum:]_:])((std::mem::|mem::)?zeroed(::<
 assume_init_count=$(count_ast_or_rg '\.assume_init\(' "${assume_init_patterns[@]}")
 # shellcheck disable=SC2016
 forget_count=$(count_ast_or_rg 'mem::forget\(' 'std::mem::forget($X)' 'mem::forget($X)')
+if [ "$transmute_lifetime" -gt 0 ]; then
+  print_finding "critical" "$transmute_lifetime" "mem::transmute used only to extend a lifetime" "Transmuting &'a T to &'static T (or Foo<'a> to Foo<'static>) erases the borrow checker's proof; the value still dies at the end of 'a and every later use is a use-after-free. Return an owned value, Box::leak it deliberately, or restructure so the borrow outlives its users"
+  show_transmute_examples lifetime 3 || true
+  add_finding "critical" "$transmute_lifetime" "mem::transmute used only to extend a lifetime" "Transmuting &'a T to &'static T (or Foo<'a> to Foo<'static>) erases the borrow checker's proof; the value still dies at the end of 'a and every later use is a use-after-free. Return an owned value, Box::leak it deliberately, or restructure so the borrow outlives its users" "${CATEGORY_NAME[2]}" "$(collect_samples_transmute lifetime 3)"
+fi
+if [ "$transmute_escalated" -gt 0 ]; then
+  print_finding "critical" "$transmute_escalated" "mem::transmute between mismatched or heap-owning types" "Differently sized types or Vec/String/Box reinterpreted as integers is UB or leaks the allocation; use \`as\` casts, to_ne_bytes/from_ne_bytes, Box::into_raw, or into_raw_parts/from_raw_parts"
+  show_transmute_examples escalated 3 || true
+  add_finding "critical" "$transmute_escalated" "mem::transmute between mismatched or heap-owning types" "Differently sized types or Vec/String/Box reinterpreted as integers is UB or leaks the allocation; use \`as\` casts, to_ne_bytes/from_ne_bytes, Box::into_raw, or into_raw_parts/from_raw_parts" "${CATEGORY_NAME[2]}" "$(collect_samples_transmute escalated 3)"
+fi
+if [ "$transmute_layout" -gt 0 ]; then
+  print_finding "critical" "$transmute_layout" "Transmute or pointer cast between repr(Rust) structs" "Structs without #[repr(C)] or #[repr(transparent)] have unspecified field order and padding, so two identically declared repr(Rust) structs need not match; add #[repr(C)] to both sides, or convert field by field with From/Into"
+  show_transmute_examples layout 3 || true
+  add_finding "critical" "$transmute_layout" "Transmute or pointer cast between repr(Rust) structs" "Structs without #[repr(C)] or #[repr(transparent)] have unspecified field order and padding, so two identically declared repr(Rust) structs need not match; add #[repr(C)] to both sides, or convert field by field with From/Into" "${CATEGORY_NAME[2]}" "$(collect_samples_transmute layout 3)"
+fi
 if [ "$transmute_count" -gt 0 ]; then
-  print_finding "critical" "$transmute_count" "mem::transmute usage"
-  # shellcheck disable=SC2016
-  show_ast_pattern_examples 3 'std::mem::transmute($X)' 'mem::transmute($X)' 'transmute($X)' || show_detailed_finding "transmute\(" 3
-  # shellcheck disable=SC2016
-  add_finding "critical" "$transmute_count" "mem::transmute usage" "" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg "transmute\(" 3 'std::mem::transmute($X)' 'mem::transmute($X)' 'transmute($X)')"
+  if [[ "$have_python3" -eq 1 ]]; then
+    print_finding "warning" "$transmute_count" "mem::transmute usage" "Reinterprets bits with no layout or validity checks; prefer \`as\` casts, from_ne_bytes/to_ne_bytes, or slice::from_raw_parts with documented invariants"
+    show_transmute_examples plain 3 || true
+    add_finding "warning" "$transmute_count" "mem::transmute usage" "Reinterprets bits with no layout or validity checks; prefer \`as\` casts, from_ne_bytes/to_ne_bytes, or slice::from_raw_parts with documented invariants" "${CATEGORY_NAME[2]}" "$(collect_samples_transmute plain 3)"
+  else
+    print_finding "critical" "$transmute_count" "mem::transmute usage" "Reinterprets bits with no layout or validity checks; prefer \`as\` casts, from_ne_bytes/to_ne_bytes, or slice::from_raw_parts with documented invariants"
+    # shellcheck disable=SC2016
+    show_ast_pattern_examples 3 'std::mem::transmute($X)' 'mem::transmute($X)' 'transmute($X)' || show_detailed_finding "transmute\(" 3
+    # shellcheck disable=SC2016
+    add_finding "critical" "$transmute_count" "mem::transmute usage" "Reinterprets bits with no layout or validity checks; prefer \`as\` casts, from_ne_bytes/to_ne_bytes, or slice::from_raw_parts with documented invariants" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg "transmute\(" 3 'std::mem::transmute($X)' 'mem::transmute($X)' 'transmute($X)')"
+  fi
 fi
 if [ "$uninit_count" -gt 0 ]; then
   print_finding "critical" "$uninit_count" "mem::uninitialized usage"
@@ -7698,12 +21861,36 @@ if [ "$zeroed_count" -gt 0 ]; then
   # shellcheck disable=SC2016
   add_finding "critical" "$zeroed_count" "mem::zeroed usage" "" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg '(^|[^[:alnum:]_:])((std::mem::|mem::)?zeroed(::<[^>]+>)?\()' 3 "${zeroed_patterns[@]}")"
 fi
-if [ "$assume_init_count" -gt 0 ]; then
+if [[ "$have_python3" -eq 1 ]]; then
+  assume_uninit=$(count_assume_init_matches uninit || echo 0)
+  assume_uninit=$(printf '%s\n' "${assume_uninit:-0}" | awk 'END{print $0+0}')
+  assume_init_count=$(count_assume_init_matches plain || echo 0)
+  assume_init_count=$(printf '%s\n' "${assume_init_count:-0}" | awk 'END{print $0+0}')
+  if [ "$assume_uninit" -gt 0 ]; then
+    print_finding "critical" "$assume_uninit" "MaybeUninit::assume_init with no prior initialization" "No write()/as_mut_ptr() initialization is visible between uninit() and assume_init(): reading uninitialized memory is immediate UB"
+    show_assume_init_examples uninit 3 || true
+    add_finding "critical" "$assume_uninit" "MaybeUninit::assume_init with no prior initialization" "No write()/as_mut_ptr() initialization is visible between uninit() and assume_init(): reading uninitialized memory is immediate UB" "${CATEGORY_NAME[2]}" "$(collect_samples_assume_init uninit 3)"
+  fi
+  if [ "$assume_init_count" -gt 0 ]; then
+    print_finding "warning" "$assume_init_count" "MaybeUninit::assume_init usage" "Only call after every byte is initialized; prefer safe constructors or write() before assume_init"
+    show_assume_init_examples plain 3 || true
+    add_finding "warning" "$assume_init_count" "MaybeUninit::assume_init usage" "Only call after every byte is initialized; prefer safe constructors or write() before assume_init" "${CATEGORY_NAME[2]}" "$(collect_samples_assume_init plain 3)"
+  fi
+elif [ "$assume_init_count" -gt 0 ]; then
   print_finding "critical" "$assume_init_count" "MaybeUninit::assume_init usage" "Only call after every byte is initialized; prefer safe constructors or write() before assume_init"
   show_ast_pattern_examples 3 "${assume_init_patterns[@]}" || show_detailed_finding "\.assume_init\(" 3
   add_finding "critical" "$assume_init_count" "MaybeUninit::assume_init usage" "Only call after every byte is initialized; prefer safe constructors or write() before assume_init" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg "\.assume_init\(" 3 "${assume_init_patterns[@]}")"
 fi
-if [ "$forget_count" -gt 0 ]; then
+if [[ "$have_python3" -eq 1 ]]; then
+  # Skips extern "C"/#[no_mangle] handoffs, as_ptr()-then-forget, and FFI-documented sites.
+  leak_count=$(count_probable_leak_matches || echo 0)
+  leak_count=$(printf '%s\n' "${leak_count:-0}" | awk 'END{print $0+0}')
+  if [ "$leak_count" -gt 0 ]; then
+    print_finding "warning" "$leak_count" "Probable leak via mem::forget / Box::leak / ManuallyDrop" "Nothing releases the value later (no ManuallyDrop::drop/into_inner/take, no FFI handoff); let it drop normally, or document the handoff and reclaim it with Box::from_raw"
+    show_probable_leak_examples 3 || true
+    add_finding "warning" "$leak_count" "Probable leak via mem::forget / Box::leak / ManuallyDrop" "Nothing releases the value later (no ManuallyDrop::drop/into_inner/take, no FFI handoff); let it drop normally, or document the handoff and reclaim it with Box::from_raw" "${CATEGORY_NAME[2]}" "$(collect_samples_probable_leak 3)"
+  fi
+elif [ "$forget_count" -gt 0 ]; then
   print_finding "warning" "$forget_count" "mem::forget leaks memory"
   # shellcheck disable=SC2016
   show_ast_pattern_examples 3 'std::mem::forget($X)' 'mem::forget($X)' || show_detailed_finding "mem::forget\(" 3
@@ -7711,6 +21898,19 @@ if [ "$forget_count" -gt 0 ]; then
   add_finding "warning" "$forget_count" "mem::forget leaks memory" "" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg "mem::forget\(" 3 'std::mem::forget($X)' 'mem::forget($X)')"
 fi
 
+print_subheader "Raw pointers dereferenced after their source is dropped or moved"
+if [[ "$have_python3" -eq 1 ]]; then
+  raw_ptr_dangling=$(count_raw_ptr_after_drop_matches || echo 0)
+  raw_ptr_dangling=$(printf '%s\n' "${raw_ptr_dangling:-0}" | awk 'END{print $0+0}')
+  if [ "$raw_ptr_dangling" -gt 0 ]; then
+    print_finding "critical" "$raw_ptr_dangling" "Raw pointer dereferenced after its source was dropped or moved" "The pointer came from &x as *const _ or as_ptr() but x was dropped, moved, reassigned, or went out of scope first; keep the owner alive (or pin it) until the last dereference"
+    show_raw_ptr_after_drop_examples 3 || true
+    add_finding "critical" "$raw_ptr_dangling" "Raw pointer dereferenced after its source was dropped or moved" "The pointer came from &x as *const _ or as_ptr() but x was dropped, moved, reassigned, or went out of scope first; keep the owner alive (or pin it) until the last dereference" "${CATEGORY_NAME[2]}" "$(collect_samples_raw_ptr_after_drop 3)"
+  else
+    print_finding "good" "No raw pointers outlive their source"
+  fi
+fi
+
 print_subheader "CStr::from_bytes_with_nul_unchecked"
 # shellcheck disable=SC2016
 cstr_patterns=('std::ffi::CStr::from_bytes_with_nul_unchecked($BYTES)' 'CStr::from_bytes_with_nul_unchecked($BYTES)')
@@ -7721,20 +21921,69 @@ if [ "$cstr_count" -gt 0 ]; then
   add_finding "warning" "$cstr_count" "CStr unchecked conversion used" "" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg "from_bytes_with_nul_unchecked\(" 3 "${cstr_patterns[@]}")"
 fi
 
+print_subheader "CString construction and CStr::from_ptr provenance"
+if [[ "$have_python3" -eq 1 ]]; then
+  cstring_dangling=$(count_cstring_matches dangling || echo 0)
+  cstring_dangling=$(printf '%s\n' "${cstring_dangling:-0}" | awk 'END{print $0+0}')
+  cstring_unwrap=$(count_cstring_matches unwrap || echo 0)
+  cstring_unwrap=$(printf '%s\n' "${cstring_unwrap:-0}" | awk 'END{print $0+0}')
+  cstr_from_ptr=$(count_cstring_matches from_ptr || echo 0)
+  cstr_from_ptr=$(printf '%s\n' "${cstr_from_ptr:-0}" | awk 'END{print $0+0}')
+  if [ "$cstring_dangling" -gt 0 ]; then
+    print_finding "critical" "$cstring_dangling" "CString::new(..).as_ptr() outlives its temporary" "The CString is dropped at the end of the statement, so the stored pointer dangles; bind the CString to a variable that outlives every use of the pointer (or hand it off with into_raw)"
+    show_cstring_examples dangling 3 || true
+    add_finding "critical" "$cstring_dangling" "CString::new(..).as_ptr() outlives its temporary" "The CString is dropped at the end of the statement, so the stored pointer dangles; bind the CString to a variable that outlives every use of the pointer (or hand it off with into_raw)" "${CATEGORY_NAME[2]}" "$(collect_samples_cstring dangling 3)"
+  fi
+  if [ "$cstring_unwrap" -gt 0 ]; then
+    print_finding "warning" "$cstring_unwrap" "CString::new(..).unwrap() panics on interior NUL" "Non-literal input may contain a 0 byte and make CString::new fail; propagate the NulError with ? or map it to an FFI error code"
+    show_cstring_examples unwrap 3 || true
+    add_finding "warning" "$cstring_unwrap" "CString::new(..).unwrap() panics on interior NUL" "Non-literal input may contain a 0 byte and make CString::new fail; propagate the NulError with ? or map it to an FFI error code" "${CATEGORY_NAME[2]}" "$(collect_samples_cstring unwrap 3)"
+  fi
+  if [ "$cstr_from_ptr" -gt 0 ]; then
+    print_finding "warning" "$cstr_from_ptr" "CStr::from_ptr on a pointer of unknown provenance" "The pointer is neither derived from a local CString/CStr nor documented as NUL-terminated; reading past the buffer is UB, so document the caller contract in a # Safety section or check for null and a bounded length first"
+    show_cstring_examples from_ptr 3 || true
+    add_finding "warning" "$cstr_from_ptr" "CStr::from_ptr on a pointer of unknown provenance" "The pointer is neither derived from a local CString/CStr nor documented as NUL-terminated; reading past the buffer is UB, so document the caller contract in a # Safety section or check for null and a bounded length first" "${CATEGORY_NAME[2]}" "$(collect_samples_cstring from_ptr 3)"
+  fi
+  if [ "$cstring_dangling" -eq 0 ] && [ "$cstring_unwrap" -eq 0 ] && [ "$cstr_from_ptr" -eq 0 ]; then
+    print_finding "good" "CString/CStr conversions look sound"
+  fi
+fi
+
+print_subheader "Pointers and borrows into dropped temporaries"
+if [[ "$have_python3" -eq 1 ]]; then
+  dangling_temp_ptr=$(count_dangling_temp_matches ptr || echo 0)
+  dangling_temp_ptr=$(printf '%s\n' "${dangling_temp_ptr:-0}" | awk 'END{print $0+0}')
+  dangling_temp_borrow=$(count_dangling_temp_matches borrow || echo 0)
+  dangling_temp_borrow=$(printf '%s\n' "${dangling_temp_borrow:-0}" | awk 'END{print $0+0}')
+  if [ "$dangling_temp_ptr" -gt 0 ]; then
+    print_finding "critical" "$dangling_temp_ptr" "as_ptr() on a temporary Vec/String outlives its owner" "vec![..].as_ptr(), x.to_vec().as_ptr() or format!(..).as_ptr() stored in a binding, field or return value points into a buffer freed at the end of the statement; bind the owned value to a variable that outlives the pointer (or leak it with into_raw/Box::leak)"
+    show_dangling_temp_examples ptr 3 || true
+    add_finding "critical" "$dangling_temp_ptr" "as_ptr() on a temporary Vec/String outlives its owner" "vec![..].as_ptr(), x.to_vec().as_ptr() or format!(..).as_ptr() stored in a binding, field or return value points into a buffer freed at the end of the statement; bind the owned value to a variable that outlives the pointer (or leak it with into_raw/Box::leak)" "${CATEGORY_NAME[2]}" "$(collect_samples_dangling_temp ptr 3)"
+  fi
+  if [ "$dangling_temp_borrow" -gt 0 ]; then
+    print_finding "warning" "$dangling_temp_borrow" "Borrow of a temporary returned from a function" "format!(..).as_str() or x.to_vec().as_slice() as a return value borrows a String/Vec dropped when the function returns (rustc rejects it with E0515); return the owned value, a Cow, or borrow from an input instead of reaching for unsafe"
+    show_dangling_temp_examples borrow 3 || true
+    add_finding "warning" "$dangling_temp_borrow" "Borrow of a temporary returned from a function" "format!(..).as_str() or x.to_vec().as_slice() as a return value borrows a String/Vec dropped when the function returns (rustc rejects it with E0515); return the owned value, a Cow, or borrow from an input instead of reaching for unsafe" "${CATEGORY_NAME[2]}" "$(collect_samples_dangling_temp borrow 3)"
+  fi
+  if [ "$dangling_temp_ptr" -eq 0 ] && [ "$dangling_temp_borrow" -eq 0 ]; then
+    print_finding "good" "No pointers or borrows into dropped temporaries"
+  fi
+fi
+
 print_subheader "get_unchecked / from_utf8_unchecked / from_raw_parts"
 # shellcheck disable=SC2016
-get_unchecked_patterns=('$S.get_unchecked($I)' '$S.get_unchecked_mut($I)')
+get_unchecked_patterns=('$S.get_unchecked($I)' '$S.get_unchecked_mut($I)' '$S.slice_unchecked($A, $B)' '$S.slice_unchecked_mut($A, $B)')
 # shellcheck disable=SC2016
 utf8_unchecked_patterns=('std::str::from_utf8_unchecked($BYTES)' 'str::from_utf8_unchecked($BYTES)' 'std::string::String::from_utf8_unchecked($BYTES)' 'String::from_utf8_unchecked($BYTES)')
 # shellcheck disable=SC2016
 raw_parts_patterns=('std::slice::from_raw_parts($PTR, $LEN)' 'std::slice::from_raw_parts_mut($PTR, $LEN)' 'slice::from_raw_parts($PTR, $LEN)' 'slice::from_raw_parts_mut($PTR, $LEN)')
-guc_count=$(count_ast_or_rg '\.get_unchecked(_mut)?\(' "${get_unchecked_patterns[@]}")
+guc_count=$(count_ast_or_rg '\.(get|slice)_unchecked(_mut)?\(' "${get_unchecked_patterns[@]}")
 u8u_count=$(count_ast_or_rg 'from_utf8_unchecked\(' "${utf8_unchecked_patterns[@]}")
 raw_parts=$(count_ast_or_rg 'from_raw_parts(_mut)?\(' "${raw_parts_patterns[@]}")
 if [ "$guc_count" -gt 0 ]; then
   print_finding "warning" "$guc_count" "Unchecked indexing APIs in use"
-  show_ast_pattern_examples 3 "${get_unchecked_patterns[@]}" || show_detailed_finding "\.get_unchecked(_mut)?\(" 3
-  add_finding "warning" "$guc_count" "Unchecked indexing APIs in use" "" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg "\.get_unchecked(_mut)?\(" 3 "${get_unchecked_patterns[@]}")"
+  show_ast_pattern_examples 3 "${get_unchecked_patterns[@]}" || show_detailed_finding "\.(get|slice)_unchecked(_mut)?\(" 3
+  add_finding "warning" "$guc_count" "Unchecked indexing APIs in use" "" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg "\.(get|slice)_unchecked(_mut)?\(" 3 "${get_unchecked_patterns[@]}")"
 fi
 if [ "$u8u_count" -gt 0 ]; then
   print_finding "warning" "$u8u_count" "UTF-8 unchecked conversion APIs"
@@ -7746,6 +21995,57 @@ if [ "$raw_parts" -gt 0 ]; then
   show_ast_pattern_examples 3 "${raw_parts_patterns[@]}" || show_detailed_finding "from_raw_parts(_mut)?\(" 3
   add_finding "warning" "$raw_parts" "slice::from_raw_parts(_mut) usage" "" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg "from_raw_parts(_mut)?\(" 3 "${raw_parts_patterns[@]}")"
 fi
+if [[ "$have_python3" -eq 1 ]] && [ $((guc_count + u8u_count + raw_parts)) -gt 0 ]; then
+  unchecked_tainted=$(count_unchecked_tainted_matches || echo 0)
+  unchecked_tainted=$(printf '%s\n' "${unchecked_tainted:-0}" | awk 'END{print $0+0}')
+  if [ "$unchecked_tainted" -gt 0 ]; then
+    print_finding "critical" "$unchecked_tainted" "Unchecked accessor fed by parameter or IO data" "Indices, lengths, or bytes from callers, readers, sockets, args, or env reach get_unchecked/from_utf8_unchecked/from_raw_parts without a visible bounds or UTF-8 check; validate first or use the checked API"
+    show_unchecked_tainted_examples 3 || true
+    add_finding "critical" "$unchecked_tainted" "Unchecked accessor fed by parameter or IO data" "Indices, lengths, or bytes from callers, readers, sockets, args, or env reach get_unchecked/from_utf8_unchecked/from_raw_parts without a visible bounds or UTF-8 check; validate first or use the checked API" "${CATEGORY_NAME[2]}" "$(collect_samples_unchecked_tainted 3)"
+  fi
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "FFI buffer length and copy direction"
+  ffi_len_hits=$(count_ffi_buffer_matches len || echo 0)
+  ffi_len_hits=$(printf '%s\n' "${ffi_len_hits:-0}" | awk 'END{print $0+0}')
+  ffi_copy_hits=$(count_ffi_buffer_matches copy || echo 0)
+  ffi_copy_hits=$(printf '%s\n' "${ffi_copy_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$ffi_copy_hits" -gt 0 ]; then
+    print_finding "critical" "$ffi_copy_hits" "copy_nonoverlapping arguments look swapped" "ptr::copy_nonoverlapping and ptr::copy take (src, dst, count); a destination-named or as_mut_ptr() first argument overwrites the data that was meant to be copied. Check the argument order, or use dst.copy_from_slice(src)"
+    show_ffi_buffer_examples copy 3 || true
+    add_finding "critical" "$ffi_copy_hits" "copy_nonoverlapping arguments look swapped" "ptr::copy_nonoverlapping and ptr::copy take (src, dst, count); a destination-named or as_mut_ptr() first argument overwrites the data that was meant to be copied. Check the argument order, or use dst.copy_from_slice(src)" "${CATEGORY_NAME[2]}" "$(collect_samples_ffi_buffer copy 3)"
+  fi
+  if [ "$ffi_len_hits" -gt 0 ]; then
+    print_finding "warning" "$ffi_len_hits" "from_raw_parts length not tied to the pointer's allocation" "The pointer comes from one buffer but the length from another value (a header field, a different slice, a count read elsewhere), so nothing in the function ties the length to the allocation; derive it from the owning buffer's len(), clamp it with .min(buf.len()), or take the pointer and length as one caller contract"
+    show_ffi_buffer_examples len 3 || true
+    add_finding "warning" "$ffi_len_hits" "from_raw_parts length not tied to the pointer's allocation" "The pointer comes from one buffer but the length from another value (a header field, a different slice, a count read elsewhere), so nothing in the function ties the length to the allocation; derive it from the owning buffer's len(), clamp it with .min(buf.len()), or take the pointer and length as one caller contract" "${CATEGORY_NAME[2]}" "$(collect_samples_ffi_buffer len 3)"
+  fi
+  if [ "$ffi_len_hits" -eq 0 ] && [ "$ffi_copy_hits" -eq 0 ]; then
+    print_finding "good" "from_raw_parts lengths and copy directions look consistent"
+  fi
+fi
+
+print_subheader "static mut globals"
+if [[ "$have_python3" -eq 1 ]]; then
+  static_mut_hits=$(count_static_mut_matches hits || echo 0)
+  static_mut_hits=$(printf '%s\n' "${static_mut_hits:-0}" | awk 'END{print $0+0}')
+else
+  # shellcheck disable=SC2016
+  static_mut_hits=$(count_ast_or_rg "static[[:space:]]+mut[[:space:]]" 'static mut $NAME: $T = $V;')
+fi
+if [ "$static_mut_hits" -gt 0 ]; then
+  static_mut_fix=""
+  if [[ "$have_python3" -eq 1 ]]; then
+    static_mut_fix=$(rust_static_mut_matches fix | awk 'NR>1{printf "; "} {printf "%s", $0}')
+  fi
+  print_finding "critical" "$static_mut_hits" "static mut global declared or accessed" "Every read/write of a static mut is unsynchronized shared state (data races are UB and references to it are denied in edition 2024); use AtomicX, OnceLock<T>, or Mutex<T>"
+  [[ -n "$static_mut_fix" ]] && say "    ${DIM}Suggested fix: ${static_mut_fix}${RESET}"
+  if [[ "$have_python3" -eq 1 ]]; then show_static_mut_examples hits 5 || true; fi
+  add_finding "critical" "$static_mut_hits" "static mut global declared or accessed" "Every read/write of a static mut is unsynchronized shared state (data races are UB and references to it are denied in edition 2024); use AtomicX, OnceLock<T>, or Mutex<T>" "${CATEGORY_NAME[2]}" "$(if [[ "$have_python3" -eq 1 ]]; then collect_samples_static_mut hits 5; else echo '[]'; fi)" "$static_mut_fix"
+else
+  print_finding "good" "No static mut globals"
+fi
 
 print_subheader "Unsafe Send/Sync impls"
 # shellcheck disable=SC2016
@@ -7756,6 +22056,27 @@ if [ "$autos_count" -gt 0 ]; then
   show_ast_pattern_examples 3 "${unsafe_auto_trait_patterns[@]}" || show_detailed_finding "unsafe[[:space:]]+impl[[:space:]]+(Send|Sync)[[:space:]]+for" 3
   add_finding "warning" "$autos_count" "Unsafe Send/Sync implementations" "" "${CATEGORY_NAME[2]}" "$(collect_samples_ast_or_rg "unsafe[[:space:]]+impl[[:space:]]+(Send|Sync)[[:space:]]+for" 3 "${unsafe_auto_trait_patterns[@]}")"
 fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Send/Sync asserted over non-thread-safe fields"
+  send_sync_rc=$(count_unsafe_send_sync_matches rc || echo 0)
+  send_sync_rc=$(printf '%s\n' "${send_sync_rc:-0}" | awk 'END{print $0+0}')
+  send_sync_ptr=$(count_unsafe_send_sync_matches ptr || echo 0)
+  send_sync_ptr=$(printf '%s\n' "${send_sync_ptr:-0}" | awk 'END{print $0+0}')
+  if [ "$send_sync_rc" -gt 0 ]; then
+    print_finding "critical" "$send_sync_rc" "unsafe impl Send/Sync over Rc or Cell fields" "Rc's refcount is non-atomic and Cell/RefCell mutate through &self without synchronization, so these unsafe impls turn ordinary use into data races (UB); switch the field to Arc, Mutex/RwLock, or an atomic instead of asserting the trait"
+    show_unsafe_send_sync_examples rc 3 || true
+    add_finding "critical" "$send_sync_rc" "unsafe impl Send/Sync over Rc or Cell fields" "Rc's refcount is non-atomic and Cell/RefCell mutate through &self without synchronization, so these unsafe impls turn ordinary use into data races (UB); switch the field to Arc, Mutex/RwLock, or an atomic instead of asserting the trait" "${CATEGORY_NAME[2]}" "$(collect_samples_unsafe_send_sync rc 3)"
+  fi
+  if [ "$send_sync_ptr" -gt 0 ]; then
+    print_finding "info" "$send_sync_ptr" "unsafe impl Send/Sync over raw pointer fields" "A raw pointer field makes the compiler refuse Send/Sync for a reason: document who owns the pointee and why cross-thread access is synchronized (a SAFETY comment), or wrap the pointer in a type that enforces it"
+    show_unsafe_send_sync_examples ptr 3 || true
+    add_finding "info" "$send_sync_ptr" "unsafe impl Send/Sync over raw pointer fields" "A raw pointer field makes the compiler refuse Send/Sync for a reason: document who owns the pointee and why cross-thread access is synchronized (a SAFETY comment), or wrap the pointer in a type that enforces it" "${CATEGORY_NAME[2]}" "$(collect_samples_unsafe_send_sync ptr 3)"
+  fi
+  if [ "$send_sync_rc" -eq 0 ] && [ "$send_sync_ptr" -eq 0 ]; then
+    print_finding "good" "No Send/Sync impls over non-thread-safe fields"
+  fi
+fi
 fi
 
 # ═══════════════════════════════════════════════════════════════════════════
@@ -7763,7 +22084,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 3; then
 print_header "3. CONCURRENCY & ASYNC PITFALLS"
-print_category "Detects: Arc<Mutex>, Rc<RefCell>, blocking ops in async, await-in-loop, spawn misuse" \
+print_category "Detects: Arc<Mutex>, Rc<RefCell>, blocking ops in async, CPU-bound async work, await-in-loop, spawn misuse, detached std threads, locks poisoned by panicking threads, Relaxed publication flags, double-lock deadlocks, lock-order inversions, busy-wait spins, unboxed async recursion, guards dropped via let _" \
   "Concurrency misuse leads to deadlocks, head-of-line blocking, and performance issues"
 
 print_subheader "Arc<Mutex<..>> / Rc<RefCell<..>> / RwLock"
@@ -7774,33 +22095,108 @@ if [ "$arc_mutex" -gt 0 ]; then print_finding "info" "$arc_mutex" "Arc<Mutex<..>
 if [ "$rc_refcell" -gt 0 ]; then print_finding "warning" "$rc_refcell" "Rc<RefCell<..>> borrow panics possible"; add_finding "warning" "$rc_refcell" "Rc<RefCell<..>> borrow panics possible" "" "${CATEGORY_NAME[3]}"; fi
 if [ "$rwlock_count" -gt 0 ]; then print_finding "info" "$rwlock_count" "RwLock in use - verify read/write patterns"; add_finding "info" "$rwlock_count" "RwLock in use - verify read/write patterns" "" "${CATEGORY_NAME[3]}"; fi
 
-print_subheader "Mutex::lock().unwrap()/expect()"
-mu_unwrap=$(( $(ast_search '$M.lock().unwrap()' || echo 0) + $("${GREP_RN[@]}" -e "\.lock\(\)\.unwrap\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
-mu_expect=$(( $(ast_search '$M.lock().expect($MSG)' || echo 0) + $("${GREP_RN[@]}" -e "\.lock\(\)\.expect\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
-mu_total=$((mu_unwrap + mu_expect))
-if [ "$mu_total" -gt 0 ]; then print_finding "warning" "$mu_total" "Poisoned lock handling via unwrap/expect"; show_detailed_finding "\.lock\(\)\.(unwrap|expect)\(" 5; add_finding "warning" "$mu_total" "Poisoned lock handling via unwrap/expect" "" "${CATEGORY_NAME[3]}" "$(collect_samples_rg "\.lock\(\)\.(unwrap|expect)\(" 5)"; fi
+print_subheader "Rc/Arc reference cycles between structs"
+if [[ "$have_python3" -eq 1 ]]; then
+  rc_cycles=$(count_rc_cycle_matches || echo 0)
+  rc_cycles=$(printf '%s\n' "${rc_cycles:-0}" | awk 'END{print $0+0}')
+  if [ "$rc_cycles" -gt 0 ]; then
+    print_finding "warning" "$rc_cycles" "Strong Rc/Arc back-edge can form a reference cycle" "Structs that point at each other (or at themselves through RefCell/Mutex) with Rc/Arc are never freed once linked; make the back-edge Weak<..> and upgrade() on access"
+    show_rc_cycle_examples 3 || true
+    add_finding "warning" "$rc_cycles" "Strong Rc/Arc back-edge can form a reference cycle" "Structs that point at each other (or at themselves through RefCell/Mutex) with Rc/Arc are never freed once linked; make the back-edge Weak<..> and upgrade() on access" "${CATEGORY_NAME[3]}" "$(collect_samples_rc_cycle 3)"
+  else
+    print_finding "good" "No strong Rc/Arc cycles between structs"
+  fi
+fi
+
+print_subheader "RefCell borrow()/borrow_mut() without try_borrow"
+if [[ "$have_python3" -eq 1 ]]; then
+  refcell_overlap=$(count_refcell_borrow_matches overlap || echo 0)
+  refcell_overlap=$(printf '%s\n' "${refcell_overlap:-0}" | awk 'END{print $0+0}')
+  refcell_plain=$(count_refcell_borrow_matches plain || echo 0)
+  refcell_plain=$(printf '%s\n' "${refcell_plain:-0}" | awk 'END{print $0+0}')
+  if [ "$refcell_overlap" -gt 0 ]; then
+    print_finding "critical" "$refcell_overlap" "Overlapping RefCell borrows of the same cell" "A borrow_mut() while another borrow of the same RefCell is alive panics with BorrowMutError; drop the first guard, narrow its scope, or use try_borrow_mut()"
+    show_refcell_borrow_examples overlap 3 || true
+    add_finding "critical" "$refcell_overlap" "Overlapping RefCell borrows of the same cell" "A borrow_mut() while another borrow of the same RefCell is alive panics with BorrowMutError; drop the first guard, narrow its scope, or use try_borrow_mut()" "${CATEGORY_NAME[3]}" "$(collect_samples_refcell_borrow overlap 3)"
+  fi
+  if [ "$refcell_plain" -gt 0 ]; then
+    print_finding "info" "$refcell_plain" "RefCell borrow()/borrow_mut() panics on conflict" "Re-entrant callers turn these into runtime panics; prefer try_borrow()/try_borrow_mut() where the borrow can overlap"
+    show_refcell_borrow_examples plain 3 || true
+    add_finding "info" "$refcell_plain" "RefCell borrow()/borrow_mut() panics on conflict" "Re-entrant callers turn these into runtime panics; prefer try_borrow()/try_borrow_mut() where the borrow can overlap" "${CATEGORY_NAME[3]}" "$(collect_samples_refcell_borrow plain 3)"
+  fi
+  if [ "$refcell_overlap" -eq 0 ] && [ "$refcell_plain" -eq 0 ]; then
+    print_finding "good" "No RefCell borrow conflicts detected"
+  fi
+fi
+
+print_subheader "Mutex::lock() / RwLock::read()/write() poison handling"
+# shellcheck disable=SC2016
+lock_unwrap_patterns=('$M.lock().unwrap()' '$M.read().unwrap()' '$M.write().unwrap()')
+# shellcheck disable=SC2016
+lock_expect_patterns=('$M.lock().expect($MSG)' '$M.read().expect($MSG)' '$M.write().expect($MSG)')
+mu_unwrap=$(count_ast_or_rg "\.(lock|read|write)\(\)\.unwrap\(" "${lock_unwrap_patterns[@]}")
+mu_expect=$(count_ast_or_rg "\.(lock|read|write)\(\)\.expect\(" "${lock_expect_patterns[@]}")
+if [ "$mu_unwrap" -gt 0 ]; then
+  print_finding "warning" "$mu_unwrap" "Poisoned lock panics via lock()/read()/write().unwrap()" "A panic while the guard is held poisons the lock and every later unwrap() panics too; use .expect(\"reason\") or recover with unwrap_or_else(PoisonError::into_inner)"
+  show_ast_pattern_examples 5 "${lock_unwrap_patterns[@]}" || show_detailed_finding "\.(lock|read|write)\(\)\.unwrap\(" 5
+  add_finding "warning" "$mu_unwrap" "Poisoned lock panics via lock()/read()/write().unwrap()" "A panic while the guard is held poisons the lock and every later unwrap() panics too; use .expect(\"reason\") or recover with unwrap_or_else(PoisonError::into_inner)" "${CATEGORY_NAME[3]}" "$(collect_samples_ast_or_rg "\.(lock|read|write)\(\)\.unwrap\(" 5 "${lock_unwrap_patterns[@]}")"
+fi
+if [ "$mu_expect" -gt 0 ]; then
+  print_finding "info" "$mu_expect" "Lock poisoning documented via expect()" "Panics with a message on poison; switch to PoisonError::into_inner if the protected data stays valid after a panic"
+  add_finding "info" "$mu_expect" "Lock poisoning documented via expect()" "Panics with a message on poison; switch to PoisonError::into_inner if the protected data stays valid after a panic" "${CATEGORY_NAME[3]}" "$(collect_samples_ast_or_rg "\.(lock|read|write)\(\)\.expect\(" 3 "${lock_expect_patterns[@]}")"
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Locks shared with panicking threads"
+  poisoned_lock_hits=$(count_poisoned_lock_matches || echo 0)
+  poisoned_lock_hits=$(printf '%s\n' "${poisoned_lock_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$poisoned_lock_hits" -gt 0 ]; then
+    print_finding "info" "$poisoned_lock_hits" "lock().unwrap() on a mutex a panicking thread can poison" "A thread that panics while holding this guard poisons the lock, and every later lock().unwrap() on it panics in turn. Recover with lock().unwrap_or_else(PoisonError::into_inner) where the data stays consistent, call clear_poison() once the invariant is restored, or take the value out with Mutex::into_inner() after joining"
+    show_poisoned_lock_examples 3 || true
+    add_finding "info" "$poisoned_lock_hits" "lock().unwrap() on a mutex a panicking thread can poison" "A thread that panics while holding this guard poisons the lock, and every later lock().unwrap() on it panics in turn. Recover with lock().unwrap_or_else(PoisonError::into_inner) where the data stays consistent, call clear_poison() once the invariant is restored, or take the value out with Mutex::into_inner() after joining" "${CATEGORY_NAME[3]}" "$(collect_samples_poisoned_lock 3)"
+  else
+    print_finding "good" "No lock().unwrap() on mutexes held by panicking threads"
+  fi
+fi
 
 print_subheader "await inside loops (sequentialism)"
 await_loop=$(( $(ast_search 'for $P in $I { $$ $F.await $$ }' || echo 0) + $("${GREP_RN[@]}" -e "for[^(]*\{[^}]*\.[[:alnum:]_]+\.await" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
 if [ "$await_loop" -gt 0 ]; then print_finding "info" "$await_loop" "await inside loop; consider batched concurrency"; add_finding "info" "$await_loop" "await inside loop; consider batched concurrency" "" "${CATEGORY_NAME[3]}"; fi
 
-print_subheader "Blocking ops inside async (thread::sleep, std::fs)"
+print_subheader "Blocking ops inside async (thread::sleep, std::fs, std::net, stdin)"
 if [[ "$have_python3" -eq 1 ]]; then
   sleep_async=$(count_async_context_matches "sleep")
   fs_async=$(count_async_context_matches "fs")
+  net_async=$(count_async_context_matches "net")
+  stdin_async=$(count_async_context_matches "stdin")
 else
   sleep_async=$(( $(ast_search 'std::thread::sleep($$)' || echo 0) + $("${GREP_RN[@]}" -e "thread::sleep\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
   fs_async=$(( $(ast_search 'std::fs::read($$)' || echo 0) + $("${GREP_RN[@]}" -e "std::fs::(read|read_to_string|write|rename|copy|remove_file)" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
+  net_async=0
+  stdin_async=0
 fi
 if [ "$sleep_async" -gt 0 ]; then
-  print_finding "warning" "$sleep_async" "thread::sleep in async"
+  print_finding "warning" "$sleep_async" "thread::sleep in async" "Parks the executor thread; use tokio::time::sleep(..).await"
   show_async_context_examples "sleep" 3 || show_detailed_finding "thread::sleep\(" 3
-  add_finding "warning" "$sleep_async" "thread::sleep in async" "" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "sleep" 3)"
+  add_finding "warning" "$sleep_async" "thread::sleep in async" "Parks the executor thread; use tokio::time::sleep(..).await" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "sleep" 3)"
 fi
 if [ "$fs_async" -gt 0 ]; then
-  print_finding "info" "$fs_async" "Blocking std::fs in async code"
+  fs_async_fix=""
+  [[ "$have_python3" -eq 1 ]] && fs_async_fix=$(rust_async_context_matches "fs_fix" | awk 'NR>1{printf " | "} {printf "%s", $0}')
+  print_finding "warning" "$fs_async" "Blocking std::fs in async code" "Synchronous file I/O stalls every task on the worker; use tokio::fs or tokio::task::spawn_blocking"
+  [[ -n "$fs_async_fix" ]] && say "    ${DIM}Suggested fix: ${fs_async_fix}${RESET}"
   show_async_context_examples "fs" 3 || show_detailed_finding "std::fs::(read|read_to_string|write|rename|copy|remove_file)" 3
-  add_finding "info" "$fs_async" "Blocking std::fs in async code" "" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "fs" 3)"
+  add_finding "warning" "$fs_async" "Blocking std::fs in async code" "Synchronous file I/O stalls every task on the worker; use tokio::fs or tokio::task::spawn_blocking" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "fs" 3)" "$fs_async_fix"
+fi
+if [ "$net_async" -gt 0 ]; then
+  print_finding "warning" "$net_async" "Blocking std::net in async code" "std::net connects, binds, and DNS lookups block the worker; use tokio::net or tokio::net::lookup_host"
+  show_async_context_examples "net" 3 || true
+  add_finding "warning" "$net_async" "Blocking std::net in async code" "std::net connects, binds, and DNS lookups block the worker; use tokio::net or tokio::net::lookup_host" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "net" 3)"
+fi
+if [ "$stdin_async" -gt 0 ]; then
+  print_finding "warning" "$stdin_async" "Blocking stdin read in async code" "std::io::stdin() reads block until input arrives; use tokio::io::stdin() with AsyncBufReadExt or spawn_blocking"
+  show_async_context_examples "stdin" 3 || true
+  add_finding "warning" "$stdin_async" "Blocking stdin read in async code" "std::io::stdin() reads block until input arrives; use tokio::io::stdin() with AsyncBufReadExt or spawn_blocking" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "stdin" 3)"
 fi
 
 print_subheader "block_on within async context"
@@ -7810,9 +22206,9 @@ else
   block_on=$(( $(ast_search 'futures::executor::block_on($$)' || echo 0) + $(ast_search 'tokio::runtime::Runtime::block_on($$)' || echo 0) ))
 fi
 if [ "$block_on" -gt 0 ]; then
-  print_finding "warning" "$block_on" "block_on within async function"
+  print_finding "critical" "$block_on" "block_on within async function" "Runtime::block_on and Handle::block_on panic when called on a tokio worker ('Cannot start a runtime from within a runtime'), and futures::executor::block_on deadlocks it; .await the future instead, or run the sync helper through spawn_blocking"
   show_async_context_examples "block_on" 3 || true
-  add_finding "warning" "$block_on" "block_on within async function" "" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "block_on" 3)"
+  add_finding "critical" "$block_on" "block_on within async function" "Runtime::block_on and Handle::block_on panic when called on a tokio worker ('Cannot start a runtime from within a runtime'), and futures::executor::block_on deadlocks it; .await the future instead, or run the sync helper through spawn_blocking" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "block_on" 3)"
 fi
 
 print_subheader "std::thread::spawn within async"
@@ -7827,6 +22223,89 @@ if [ "$spawn_in_async" -gt 0 ]; then
   add_finding "warning" "$spawn_in_async" "std::thread::spawn inside async fn" "" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "thread_spawn" 3)"
 fi
 
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "CPU-bound work on the async executor"
+  cpu_async=$(count_async_context_matches "cpu")
+  if [ "$cpu_async" -gt 0 ]; then
+    print_finding "warning" "$cpu_async" "CPU-heavy work runs directly on the async executor" "Password hashing, compression, rayon, and long loops that never .await hold the worker thread, starving every other task scheduled on it; move the work into tokio::task::spawn_blocking or block_in_place"
+    show_async_context_examples "cpu" 3 || true
+    add_finding "warning" "$cpu_async" "CPU-heavy work runs directly on the async executor" "Password hashing, compression, rayon, and long loops that never .await hold the worker thread, starving every other task scheduled on it; move the work into tokio::task::spawn_blocking or block_in_place" "${CATEGORY_NAME[3]}" "$(collect_samples_async_context "cpu" 3)"
+  else
+    print_finding "good" "No CPU-bound work found inside async bodies"
+  fi
+fi
+
+print_subheader "unwrap()/expect() inside spawned tasks"
+if [[ "$have_python3" -eq 1 ]]; then
+  spawn_unwrap=$(count_spawn_unwrap_matches || echo 0)
+  spawn_unwrap=$(printf '%s\n' "${spawn_unwrap:-0}" | awk 'END{print $0+0}')
+  if [ "$spawn_unwrap" -gt 0 ]; then
+    print_finding "warning" "$spawn_unwrap" "unwrap/expect inside spawned task" "A panic in a spawned task or thread only surfaces as a JoinError; return Result from the task, propagate with ?, and inspect the JoinHandle output"
+    show_spawn_unwrap_examples 3 || true
+    add_finding "warning" "$spawn_unwrap" "unwrap/expect inside spawned task" "A panic in a spawned task or thread only surfaces as a JoinError; return Result from the task, propagate with ?, and inspect the JoinHandle output" "${CATEGORY_NAME[3]}" "$(collect_samples_spawn_unwrap 3)"
+  fi
+fi
+
+print_subheader "select! branches that are not cancellation-safe"
+if [[ "$have_python3" -eq 1 ]]; then
+  select_unsafe=$(count_select_cancel_matches unsafe_op || echo 0)
+  select_unsafe=$(printf '%s\n' "${select_unsafe:-0}" | awk 'END{print $0+0}')
+  select_owned=$(count_select_cancel_matches owned_move || echo 0)
+  select_owned=$(printf '%s\n' "${select_owned:-0}" | awk 'END{print $0+0}')
+  if [ "$select_unsafe" -gt 0 ]; then
+    print_finding "warning" "$select_unsafe" "Non-cancellation-safe future raced in select!" "When another branch wins, read_exact/read_line/write_all or partially consumed streams drop buffered progress; use cancel-safe primitives (read_buf, recv, next) or pin the future outside the loop"
+    show_select_cancel_examples unsafe_op 3 || true
+    add_finding "warning" "$select_unsafe" "Non-cancellation-safe future raced in select!" "When another branch wins, read_exact/read_line/write_all or partially consumed streams drop buffered progress; use cancel-safe primitives (read_buf, recv, next) or pin the future outside the loop" "${CATEGORY_NAME[3]}" "$(collect_samples_select_cancel unsafe_op 3)"
+  fi
+  if [ "$select_owned" -gt 0 ]; then
+    print_finding "warning" "$select_owned" "Owned value moved into a select! branch that may lose" "The value moved into send(..) is dropped if another branch completes first; reserve() a permit first or send a clone"
+    show_select_cancel_examples owned_move 3 || true
+    add_finding "warning" "$select_owned" "Owned value moved into a select! branch that may lose" "The value moved into send(..) is dropped if another branch completes first; reserve() a permit first or send a clone" "${CATEGORY_NAME[3]}" "$(collect_samples_select_cancel owned_move 3)"
+  fi
+  if [ "$select_unsafe" -eq 0 ] && [ "$select_owned" -eq 0 ]; then
+    print_finding "good" "No cancellation-unsafe select! branches detected"
+  fi
+fi
+
+print_subheader "Unbounded channels fed from producer loops"
+if [[ "$have_python3" -eq 1 ]]; then
+  unbounded_chan=$(count_unbounded_channel_matches || echo 0)
+  unbounded_chan=$(printf '%s\n' "${unbounded_chan:-0}" | awk 'END{print $0+0}')
+  if [ "$unbounded_chan" -gt 0 ]; then
+    print_finding "warning" "$unbounded_chan" "Unbounded channel fed from a loop" "A producer that outpaces its consumer grows the queue without limit; use mpsc::channel(capacity), crossbeam::bounded, or sync_channel so send() applies backpressure"
+    show_unbounded_channel_examples 3 || true
+    add_finding "warning" "$unbounded_chan" "Unbounded channel fed from a loop" "A producer that outpaces its consumer grows the queue without limit; use mpsc::channel(capacity), crossbeam::bounded, or sync_channel so send() applies backpressure" "${CATEGORY_NAME[3]}" "$(collect_samples_unbounded_channel 3)"
+  else
+    print_finding "good" "No unbounded channels fed from loops"
+  fi
+fi
+
+print_subheader "Channel send()/recv() unwrapped"
+if [[ "$have_python3" -eq 1 ]]; then
+  chan_unwrap=$(count_channel_unwrap_matches || echo 0)
+  chan_unwrap=$(printf '%s\n' "${chan_unwrap:-0}" | awk 'END{print $0+0}')
+  if [ "$chan_unwrap" -gt 0 ]; then
+    print_finding "warning" "$chan_unwrap" "Channel send()/recv() result unwrapped" "send() fails once the receiver is dropped and recv() once every sender is gone; match on SendError/RecvError (or None) and shut the loop down gracefully instead of panicking"
+    show_channel_unwrap_examples 3 || true
+    add_finding "warning" "$chan_unwrap" "Channel send()/recv() result unwrapped" "send() fails once the receiver is dropped and recv() once every sender is gone; match on SendError/RecvError (or None) and shut the loop down gracefully instead of panicking" "${CATEGORY_NAME[3]}" "$(collect_samples_channel_unwrap 3)"
+  else
+    print_finding "good" "No unwrapped channel send()/recv() calls"
+  fi
+fi
+
+print_subheader "Futures created but never awaited"
+if [[ "$have_python3" -eq 1 ]]; then
+  unawaited_futures=$(count_unawaited_future_matches || echo 0)
+  unawaited_futures=$(printf '%s\n' "${unawaited_futures:-0}" | awk 'END{print $0+0}')
+  if [ "$unawaited_futures" -gt 0 ]; then
+    print_finding "warning" "$unawaited_futures" "Future created but never awaited" "Futures are lazy: calling an async fn or building an async block does nothing until polled; .await it, spawn it, or return it"
+    show_unawaited_future_examples 3 || true
+    add_finding "warning" "$unawaited_futures" "Future created but never awaited" "Futures are lazy: calling an async fn or building an async block does nothing until polled; .await it, spawn it, or return it" "${CATEGORY_NAME[3]}" "$(collect_samples_unawaited_future 3)"
+  else
+    print_finding "good" "No discarded futures detected"
+  fi
+fi
+
 print_subheader "tokio::spawn usage (heuristic for detached tasks)"
 spawn_count=$("${GREP_RN[@]}" -e "tokio::spawn\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
 join_handle_used=$("${GREP_RN[@]}" -e "JoinHandle<|\.await" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
@@ -7835,7 +22314,88 @@ if [ "$spawn_count" -gt 0 ] && [ "$join_handle_used" -lt "$spawn_count" ]; then
   add_finding "info" "$((spawn_count - join_handle_used))" "spawn without awaiting JoinHandle (heuristic)" "Ensure detached tasks handle errors appropriately" "${CATEGORY_NAME[3]}"
 fi
 
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Ordering::Relaxed on atomics that publish data"
+  relaxed_hits=$(count_relaxed_publication_matches || echo 0)
+  relaxed_hits=$(printf '%s\n' "${relaxed_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$relaxed_hits" -gt 0 ]; then
+    print_finding "warning" "$relaxed_hits" "Relaxed atomic used as a publication flag" "A store that follows writes to shared data, and the loads that check it before reading that data, need Release/Acquire; Relaxed lets the reader see the flag before the data. Relaxed is fine for pure counters and stop flags"
+    show_relaxed_publication_examples 4 || true
+    add_finding "warning" "$relaxed_hits" "Relaxed atomic used as a publication flag" "A store that follows writes to shared data, and the loads that check it before reading that data, need Release/Acquire; Relaxed lets the reader see the flag before the data. Relaxed is fine for pure counters and stop flags" "${CATEGORY_NAME[3]}" "$(collect_samples_relaxed_publication 4)"
+  else
+    print_finding "good" "No Relaxed publication flags detected"
+  fi
+
+  print_subheader "Same lock acquired twice while its guard is alive"
+  double_lock_hits=$(count_double_lock_matches || echo 0)
+  double_lock_hits=$(printf '%s\n' "${double_lock_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$double_lock_hits" -gt 0 ]; then
+    print_finding "critical" "$double_lock_hits" "Mutex/RwLock locked again while its guard is alive" "std locks are not reentrant: a second lock()/write() on the same binding in the guard's scope (including if-let/match scrutinee temporaries, which live to the end of the block in edition 2021) deadlocks immediately; drop the guard first or reuse it"
+    show_double_lock_examples 4 || true
+    add_finding "critical" "$double_lock_hits" "Mutex/RwLock locked again while its guard is alive" "std locks are not reentrant: a second lock()/write() on the same binding in the guard's scope (including if-let/match scrutinee temporaries, which live to the end of the block in edition 2021) deadlocks immediately; drop the guard first or reuse it" "${CATEGORY_NAME[3]}" "$(collect_samples_double_lock 4)"
+  else
+    print_finding "good" "No same-lock double acquisition detected"
+  fi
+
+  print_subheader "Lock acquisition order inversions"
+  lock_order_hits=$(count_lock_order_matches || echo 0)
+  lock_order_hits=$(printf '%s\n' "${lock_order_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$lock_order_hits" -gt 0 ]; then
+    print_finding "critical" "$lock_order_hits" "Locks acquired in inconsistent order" "Two functions take the same pair of locks in opposite orders (directly or through a same-file helper); when both run concurrently each holds the lock the other needs. Pick one global order, or take both under a single outer lock"
+    show_lock_order_examples 4 || true
+    add_finding "critical" "$lock_order_hits" "Locks acquired in inconsistent order" "Two functions take the same pair of locks in opposite orders (directly or through a same-file helper); when both run concurrently each holds the lock the other needs. Pick one global order, or take both under a single outer lock" "${CATEGORY_NAME[3]}" "$(collect_samples_lock_order 4)"
+  else
+    print_finding "good" "No lock-order inversions detected"
+  fi
+
+  print_subheader "Busy-wait polling loops"
+  busy_wait_hits=$(count_busy_wait_matches || echo 0)
+  busy_wait_hits=$(printf '%s\n' "${busy_wait_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$busy_wait_hits" -gt 0 ]; then
+    print_finding "warning" "$busy_wait_hits" "Busy-wait loop spins without yielding" "A loop that only polls an atomic, try_lock() or try_recv() burns a full core and starves the thread that would set the flag; block on a Condvar/channel/park(), or at least call thread::yield_now(), hint::spin_loop() or sleep between polls"
+    show_busy_wait_examples 4 || true
+    add_finding "warning" "$busy_wait_hits" "Busy-wait loop spins without yielding" "A loop that only polls an atomic, try_lock() or try_recv() burns a full core and starves the thread that would set the flag; block on a Condvar/channel/park(), or at least call thread::yield_now(), hint::spin_loop() or sleep between polls" "${CATEGORY_NAME[3]}" "$(collect_samples_busy_wait 4)"
+  else
+    print_finding "good" "No busy-wait polling loops detected"
+  fi
+
+  print_subheader "Async recursion without boxing"
+  async_recursion_hits=$(count_async_recursion_matches || echo 0)
+  async_recursion_hits=$(printf '%s\n' "${async_recursion_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$async_recursion_hits" -gt 0 ]; then
+    print_finding "warning" "$async_recursion_hits" "Async function recurses without Box::pin" "An async fn (or an impl Future built from an async block) that awaits itself has an infinitely sized state machine: rustc rejects it with E0733. Wrap the recursive call in Box::pin(..), return a BoxFuture, or use #[async_recursion]"
+    show_async_recursion_examples 4 || true
+    add_finding "warning" "$async_recursion_hits" "Async function recurses without Box::pin" "An async fn (or an impl Future built from an async block) that awaits itself has an infinitely sized state machine: rustc rejects it with E0733. Wrap the recursive call in Box::pin(..), return a BoxFuture, or use #[async_recursion]" "${CATEGORY_NAME[3]}" "$(collect_samples_async_recursion 4)"
+  else
+    print_finding "good" "No unboxed async recursion detected"
+  fi
+
+  print_subheader "RAII guards bound to let _"
+  underscore_guard_hits=$(count_underscore_guard_matches || echo 0)
+  underscore_guard_hits=$(printf '%s\n' "${underscore_guard_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$underscore_guard_hits" -gt 0 ]; then
+    print_finding "warning" "$underscore_guard_hits" "RAII guard bound to let _ is dropped immediately" "let _ = x.lock()? / span.enter() / sem.acquire().await does not bind: the guard, span entry or permit is dropped at the end of the statement, so the code after it runs unprotected. Bind it to a named _guard (or drop() it explicitly where the section ends)"
+    show_underscore_guard_examples 4 || true
+    add_finding "warning" "$underscore_guard_hits" "RAII guard bound to let _ is dropped immediately" "let _ = x.lock()? / span.enter() / sem.acquire().await does not bind: the guard, span entry or permit is dropped at the end of the statement, so the code after it runs unprotected. Bind it to a named _guard (or drop() it explicitly where the section ends)" "${CATEGORY_NAME[3]}" "$(collect_samples_underscore_guard 4)"
+  else
+    print_finding "good" "No guards discarded through let _"
+  fi
+fi
+
 run_async_error_checks
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Detached std::thread::spawn"
+  detached_thread_hits=$(count_detached_thread_matches || echo 0)
+  detached_thread_hits=$(printf '%s\n' "${detached_thread_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$detached_thread_hits" -gt 0 ]; then
+    print_finding "warning" "$detached_thread_hits" "std::thread::spawn JoinHandle dropped" "A dropped std::thread JoinHandle detaches the thread: its panic is never observed and nothing waits for it at shutdown; keep the handle and join() it, store it in the owning struct, or use thread::scope. Intentional process-lifetime worker loops can carry ubs:ignore on the spawn or loop line"
+    show_detached_thread_examples 3 || true
+    add_finding "warning" "$detached_thread_hits" "std::thread::spawn JoinHandle dropped" "A dropped std::thread JoinHandle detaches the thread: its panic is never observed and nothing waits for it at shutdown; keep the handle and join() it, store it in the owning struct, or use thread::scope. Intentional process-lifetime worker loops can carry ubs:ignore on the spawn or loop line" "${CATEGORY_NAME[3]}" "$(collect_samples_detached_thread 3)"
+  else
+    print_finding "good" "std::thread JoinHandles appear joined or stored"
+  fi
+fi
 fi
 
 # ═══════════════════════════════════════════════════════════════════════════
@@ -7843,12 +22403,24 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 4; then
 print_header "4. NUMERIC & FLOATING-POINT"
-print_category "Detects: float equality, division/modulo by variable, potential overflow hints" \
+print_category "Detects: float equality, division/modulo by variable, potential overflow hints, always-true/false conditions" \
   "Numeric bugs cause subtle logic errors or panics in debug builds (overflow)"
 
 print_subheader "Floating-point equality comparisons"
+if [[ "$have_python3" -eq 1 ]]; then
+  fp_eq=$(count_float_eq_matches || echo 0)
+  fp_eq=$(printf '%s\n' "${fp_eq:-0}" | awk 'END{print $0+0}')
+  if [ "$fp_eq" -gt 0 ]; then
+    print_finding "warning" "$fp_eq" "Exact ==/!= on f32/f64 values" "Rounding makes exact float equality unreliable (and NaN never compares equal); compare (a - b).abs() <= tolerance, use total_cmp() for ordering, or pass --float-eq-ignore-zero to skip checks against 0.0"
+    show_float_eq_examples 3 || true
+    add_finding "warning" "$fp_eq" "Exact ==/!= on f32/f64 values" "Rounding makes exact float equality unreliable (and NaN never compares equal); compare (a - b).abs() <= tolerance, use total_cmp() for ordering, or pass --float-eq-ignore-zero to skip checks against 0.0" "${CATEGORY_NAME[4]}" "$(collect_samples_float_eq 3)"
+  else
+    print_finding "good" "No exact float equality checks detected"
+  fi
+else
 fp_eq=$("${GREP_RN[@]}" -e "([[:alnum:]_]\s*(==|!=)\s*[[:alnum:]_]*\.[[:alnum:]_]+)|((==|!=)[[:space:]]*[0-9]+\.[0-9]+)" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
 if [ "$fp_eq" -gt 0 ]; then print_finding "info" "$fp_eq" "Float equality/inequality check" "Consider epsilon comparisons"; show_detailed_finding "(==|!=)[[:space:]]*[0-9]+\.[0-9]+" 3; add_finding "info" "$fp_eq" "Float equality/inequality check" "Consider epsilon comparisons" "${CATEGORY_NAME[4]}" "$(collect_samples_rg "(==|!=)[[:space:]]*[0-9]+\.[0-9]+" 3)"; else print_finding "good" "No direct float equality checks detected"; fi
+fi
 
 print_subheader "Division/modulo by variable (verify non-zero)"
 div_var=$("${GREP_RN[@]}" -e "/[[:space:]]*[a-zA-Z_][a-zA-Z0-9_]*" "$PROJECT_DIR" 2>/dev/null | grep -Ev "https?://|//|/\*" | count_lines || true)
@@ -7856,6 +22428,60 @@ mod_var=$("${GREP_RN[@]}" -e "%[[:space:]]*[a-zA-Z_][a-zA-Z0-9_]*" "$PROJECT_DIR
 div_var=$(printf '%s\n' "${div_var:-0}" | awk 'END{print $0+0}'); mod_var=$(printf '%s\n' "${mod_var:-0}" | awk 'END{print $0+0}')
 if [ "$div_var" -gt 0 ]; then print_finding "info" "$div_var" "Division by variables - guard zero divisors"; add_finding "info" "$div_var" "Division by variables - guard zero divisors" "" "${CATEGORY_NAME[4]}"; fi
 if [ "$mod_var" -gt 0 ]; then print_finding "info" "$mod_var" "Modulo by variables - guard zero divisors"; add_finding "info" "$mod_var" "Modulo by variables - guard zero divisors" "" "${CATEGORY_NAME[4]}"; fi
+
+print_subheader "Integer division/modulo by a divisor never checked for zero"
+if [[ "$have_python3" -eq 1 ]]; then
+  div_zero=$(count_div_zero_matches || echo 0)
+  div_zero=$(printf '%s\n' "${div_zero:-0}" | awk 'END{print $0+0}')
+  if [ "$div_zero" -gt 0 ]; then
+    print_finding "warning" "$div_zero" "Division or modulo by a possibly-zero integer" "The divisor is an integer parameter, parsed value, or len() that the function never compares with zero; integer / and % panic on zero, so guard it, use checked_div/checked_rem, or take a NonZero type"
+    show_div_zero_examples 3 || true
+    add_finding "warning" "$div_zero" "Division or modulo by a possibly-zero integer" "The divisor is an integer parameter, parsed value, or len() that the function never compares with zero; integer / and % panic on zero, so guard it, use checked_div/checked_rem, or take a NonZero type" "${CATEGORY_NAME[4]}" "$(collect_samples_div_zero 3)"
+  else
+    print_finding "good" "Integer divisors are checked for zero"
+  fi
+fi
+
+print_subheader "Unchecked arithmetic on parsed, length, or network-derived integers"
+if [[ "$have_python3" -eq 1 ]]; then
+  untrusted_overflow=$(count_untrusted_overflow_matches || echo 0)
+  untrusted_overflow=$(printf '%s\n' "${untrusted_overflow:-0}" | awk 'END{print $0+0}')
+  if [ "$untrusted_overflow" -gt 0 ]; then
+    print_finding "warning" "$untrusted_overflow" "Unchecked arithmetic on untrusted integers" "Values from parse()/from_*_bytes()/len() can overflow or underflow (panic in debug, wrap in release); use checked_add/checked_sub/checked_mul or saturating_* and handle the None case"
+    show_untrusted_overflow_examples 3 || true
+    add_finding "warning" "$untrusted_overflow" "Unchecked arithmetic on untrusted integers" "Values from parse()/from_*_bytes()/len() can overflow or underflow (panic in debug, wrap in release); use checked_add/checked_sub/checked_mul or saturating_* and handle the None case" "${CATEGORY_NAME[4]}" "$(collect_samples_untrusted_overflow 3)"
+  else
+    print_finding "good" "No unchecked arithmetic on untrusted integers"
+  fi
+fi
+
+print_subheader "Conditions that are always true or always false"
+if [[ "$have_python3" -eq 1 ]]; then
+  const_unsigned=$(count_constant_condition_matches unsigned || echo 0)
+  const_unsigned=$(printf '%s\n' "${const_unsigned:-0}" | awk 'END{print $0+0}')
+  const_self=$(count_constant_condition_matches self || echo 0)
+  const_self=$(printf '%s\n' "${const_self:-0}" | awk 'END{print $0+0}')
+  const_contra=$(count_constant_condition_matches contradiction || echo 0)
+  const_contra=$(printf '%s\n' "${const_contra:-0}" | awk 'END{print $0+0}')
+  if [ "$const_unsigned" -gt 0 ]; then
+    print_finding "warning" "$const_unsigned" "Unsigned value compared against zero" "x >= 0 is always true and x < 0 always false when x is a uN/usize or a len(); the guard protects nothing, and the value it was meant to catch has usually already underflowed. Check before subtracting (checked_sub) or compare with the intended bound"
+    show_constant_condition_examples unsigned 3 || true
+    add_finding "warning" "$const_unsigned" "Unsigned value compared against zero" "x >= 0 is always true and x < 0 always false when x is a uN/usize or a len(); the guard protects nothing, and the value it was meant to catch has usually already underflowed. Check before subtracting (checked_sub) or compare with the intended bound" "${CATEGORY_NAME[4]}" "$(collect_samples_constant_condition unsigned 3)"
+  fi
+  if [ "$const_self" -gt 0 ]; then
+    print_finding "warning" "$const_self" "Value compared with itself" "a == a / a.x < a.x always has the same outcome and almost always means the other operand was mistyped (a.width == b.width); for floats use is_nan() instead of x != x"
+    show_constant_condition_examples self 3 || true
+    add_finding "warning" "$const_self" "Value compared with itself" "a == a / a.x < a.x always has the same outcome and almost always means the other operand was mistyped (a.width == b.width); for floats use is_nan() instead of x != x" "${CATEGORY_NAME[4]}" "$(collect_samples_constant_condition self 3)"
+  fi
+  if [ "$const_contra" -gt 0 ]; then
+    print_finding "warning" "$const_contra" "Contradictory or tautological condition chain" "x > 500 && x < 400 can never hold and x != A || x != B always holds; the branch is dead (or unconditional). Swap && and ||, or fix the bounds"
+    show_constant_condition_examples contradiction 3 || true
+    add_finding "warning" "$const_contra" "Contradictory or tautological condition chain" "x > 500 && x < 400 can never hold and x != A || x != B always holds; the branch is dead (or unconditional). Swap && and ||, or fix the bounds" "${CATEGORY_NAME[4]}" "$(collect_samples_constant_condition contradiction 3)"
+  fi
+  if [ "$const_unsigned" -eq 0 ] && [ "$const_self" -eq 0 ] && [ "$const_contra" -eq 0 ]; then
+    print_finding "good" "No constant conditions detected"
+  fi
+fi
 fi
 
 # ═══════════════════════════════════════════════════════════════════════════
@@ -7863,7 +22489,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 5; then
 print_header "5. COLLECTIONS & ITERATORS"
-print_category "Detects: clone in loops, collect then iterate, nth(0), length checks" \
+print_category "Detects: clone in loops, collect then iterate, nth(0), length checks, mutation during iteration, zip truncation, hash-order dependence" \
   "Iterator misuse often leads to unnecessary allocations or slow paths"
 
 print_subheader "clone() occurrences & clone() in loops"
@@ -7886,6 +22512,19 @@ if [ "$clone_loop" -gt 0 ]; then
   add_finding "warning" "$clone_loop" "clone() inside loops - potential perf hit" "" "${CATEGORY_NAME[5]}" "$(collect_samples_loop_context "clone" 3)"
 fi
 
+print_subheader "Read-only clone()/to_owned() of large values inside loops"
+if [[ "$have_python3" -eq 1 ]]; then
+  clone_readonly=$(count_clone_readonly_matches || echo 0)
+  clone_readonly=$(printf '%s\n' "${clone_readonly:-0}" | awk 'END{print $0+0}')
+  if [ "$clone_readonly" -gt 0 ]; then
+    print_finding "warning" "$clone_readonly" "Read-only clone of a large value inside a loop" "Each iteration copies a String/Vec/map or large struct that is only read; borrow it (&value) or clone once before the loop (tune with --clone-loop-min-bytes=N)"
+    show_clone_readonly_examples 3 || true
+    add_finding "warning" "$clone_readonly" "Read-only clone of a large value inside a loop" "Each iteration copies a String/Vec/map or large struct that is only read; borrow it (&value) or clone once before the loop (tune with --clone-loop-min-bytes=N)" "${CATEGORY_NAME[5]}" "$(collect_samples_clone_readonly 3)"
+  else
+    print_finding "good" "No read-only clones of large values inside loops"
+  fi
+fi
+
 print_subheader "collect::<Vec<_>>() then for"
 # shellcheck disable=SC2016
 collect_vec_patterns=('$I.collect::<Vec<$T>>()')
@@ -7896,6 +22535,58 @@ if [ "$collect_for" -gt 0 ]; then
   add_finding "info" "$collect_for" "collect::<Vec<_>>() usage - consider streaming" "" "${CATEGORY_NAME[5]}" "$(collect_samples_ast_or_rg "collect::<\s*Vec<" 3 "${collect_vec_patterns[@]}")"
 fi
 
+print_subheader "Needless collect into Vec"
+if [[ "$have_python3" -eq 1 ]]; then
+  needless_collect=$(count_needless_collect_matches || echo 0)
+  needless_collect=$(printf '%s\n' "${needless_collect:-0}" | awk 'END{print $0+0}')
+  if [ "$needless_collect" -gt 0 ]; then
+    print_finding "warning" "$needless_collect" "Needless collect into Vec" "The Vec is only iterated once or checked for emptiness; chain the iterator directly (.sum(), .for_each(), .count()) or use .next().is_none()/.any() instead of allocating"
+    show_needless_collect_examples 3 || true
+    add_finding "warning" "$needless_collect" "Needless collect into Vec" "The Vec is only iterated once or checked for emptiness; chain the iterator directly (.sum(), .for_each(), .count()) or use .next().is_none()/.any() instead of allocating" "${CATEGORY_NAME[5]}" "$(collect_samples_needless_collect 3)"
+  else
+    print_finding "good" "No collected Vecs that are only iterated once"
+  fi
+fi
+
+print_subheader "Collections mutated while being iterated"
+if [[ "$have_python3" -eq 1 ]]; then
+  iter_mutation=$(count_iter_mutation_matches || echo 0)
+  iter_mutation=$(printf '%s\n' "${iter_mutation:-0}" | awk 'END{print $0+0}')
+  if [ "$iter_mutation" -gt 0 ]; then
+    print_finding "warning" "$iter_mutation" "Collection mutated while it is being iterated" "remove()/insert() inside for i in 0..v.len() shifts later elements (skipping one and eventually indexing past the end), and remove(i) followed by an unconditional i += 1 skips the next element; use retain/retain_mut, drain(..)/extract_if, iterate in reverse, or collect the changes and apply them after the loop"
+    show_iter_mutation_examples 3 || true
+    add_finding "warning" "$iter_mutation" "Collection mutated while it is being iterated" "remove()/insert() inside for i in 0..v.len() shifts later elements (skipping one and eventually indexing past the end), and remove(i) followed by an unconditional i += 1 skips the next element; use retain/retain_mut, drain(..)/extract_if, iterate in reverse, or collect the changes and apply them after the loop" "${CATEGORY_NAME[5]}" "$(collect_samples_iter_mutation 3)"
+  else
+    print_finding "good" "No collections mutated while being iterated"
+  fi
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "zip over collections of unchecked length"
+  zip_truncation=$(count_zip_truncation_matches || echo 0)
+  zip_truncation=$(printf '%s\n' "${zip_truncation:-0}" | awk 'END{print $0+0}')
+  if [ "$zip_truncation" -gt 0 ]; then
+    print_finding "warning" "$zip_truncation" "zip silently truncates to the shorter collection" "zip stops at the shorter iterator without any error, so parallel data that drifts out of sync silently drops its tail; assert_eq!(a.len(), b.len()) before zipping, or use itertools::zip_eq, which panics on a mismatch"
+    show_zip_truncation_examples 3 || true
+    add_finding "warning" "$zip_truncation" "zip silently truncates to the shorter collection" "zip stops at the shorter iterator without any error, so parallel data that drifts out of sync silently drops its tail; assert_eq!(a.len(), b.len()) before zipping, or use itertools::zip_eq, which panics on a mismatch" "${CATEGORY_NAME[5]}" "$(collect_samples_zip_truncation 3)"
+  else
+    print_finding "good" "Every zip of parallel collections checks their lengths"
+  fi
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "HashMap/HashSet iteration order leaking into results"
+  hash_order_hits=$(count_hash_order_matches || echo 0)
+  hash_order_hits=$(printf '%s\n' "${hash_order_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$hash_order_hits" -gt 0 ]; then
+    print_finding "warning" "$hash_order_hits" "Result depends on HashMap/HashSet iteration order" "HashMap and HashSet iterate in a per-process random order, so output, cache keys, or a \"first\" element built from that order changes between runs; use BTreeMap/BTreeSet or IndexMap, or collect and sort the keys first"
+    show_hash_order_examples 3 || true
+    add_finding "warning" "$hash_order_hits" "Result depends on HashMap/HashSet iteration order" "HashMap and HashSet iterate in a per-process random order, so output, cache keys, or a \"first\" element built from that order changes between runs; use BTreeMap/BTreeSet or IndexMap, or collect and sort the keys first" "${CATEGORY_NAME[5]}" "$(collect_samples_hash_order 3)"
+  else
+    print_finding "good" "No output or hashing depends on HashMap/HashSet order"
+  fi
+fi
+
 print_subheader "nth(0) → next()"
 # shellcheck disable=SC2016
 nth0_patterns=('$I.nth(0)')
@@ -7912,7 +22603,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 6; then
 print_header "6. STRING & ALLOCATION SMELLS"
-print_category "Detects: needless allocations, format!(literal), to_owned().to_string()" \
+print_category "Detects: needless allocations, format!(literal), to_owned().to_string(), str slicing off char boundaries" \
   "Unnecessary allocations and conversions reduce performance"
 
 print_subheader "to_owned().to_string() chain"
@@ -7932,6 +22623,43 @@ if [ "$fmt_total" -gt 0 ]; then
   show_format_literal_examples 3 || true
   add_finding "info" "$fmt_total" "format!(literal) allocates - use .to_string()" "" "${CATEGORY_NAME[6]}" "$(collect_samples_format_literal 3)"
 fi
+
+print_subheader "String rebuilt by + or format! inside loops"
+if [[ "$have_python3" -eq 1 ]]; then
+  string_concat=$(count_string_concat_matches quadratic || echo 0)
+  string_concat=$(printf '%s\n' "${string_concat:-0}" | awk 'END{print $0+0}')
+  if [ "$string_concat" -gt 0 ]; then
+    string_concat_fix=$(rust_string_concat_matches quadratic fix | awk 'NR>1{printf " | "} {printf "%s", $0}')
+    print_finding "warning" "$string_concat" "String rebuilt with format! inside a loop" "s = format!(\"{s}...\") copies the whole accumulated string on every iteration (quadratic); append with write! (use std::fmt::Write) or push_str"
+    [[ -n "$string_concat_fix" ]] && say "    ${DIM}Suggested fix: ${string_concat_fix}${RESET}"
+    show_string_concat_examples quadratic 3 || true
+    add_finding "warning" "$string_concat" "String rebuilt with format! inside a loop" "s = format!(\"{s}...\") copies the whole accumulated string on every iteration (quadratic); append with write! (use std::fmt::Write) or push_str" "${CATEGORY_NAME[6]}" "$(collect_samples_string_concat quadratic 3)" "$string_concat_fix"
+  else
+    print_finding "good" "No quadratic string building inside loops"
+  fi
+  string_append=$(count_string_concat_matches append || echo 0)
+  string_append=$(printf '%s\n' "${string_append:-0}" | awk 'END{print $0+0}')
+  if [ "$string_append" -gt 0 ]; then
+    string_append_fix=$(rust_string_concat_matches append fix | awk 'NR>1{printf " | "} {printf "%s", $0}')
+    print_finding "info" "$string_append" "String appended with + or &format! inside a loop" "s = s + &x appends in place but reads like a rebuild and never preallocates; s += &format!(..) allocates a temporary per iteration. Preallocate with String::with_capacity and append with push_str or write!"
+    [[ -n "$string_append_fix" ]] && say "    ${DIM}Suggested fix: ${string_append_fix}${RESET}"
+    show_string_concat_examples append 3 || true
+    add_finding "info" "$string_append" "String appended with + or &format! inside a loop" "s = s + &x appends in place but reads like a rebuild and never preallocates; s += &format!(..) allocates a temporary per iteration. Preallocate with String::with_capacity and append with push_str or write!" "${CATEGORY_NAME[6]}" "$(collect_samples_string_concat append 3)" "$string_append_fix"
+  fi
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "String slicing at raw byte offsets"
+  str_slice_hits=$(count_str_slice_boundary_matches || echo 0)
+  str_slice_hits=$(printf '%s\n' "${str_slice_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$str_slice_hits" -gt 0 ]; then
+    print_finding "warning" "$str_slice_hits" "str sliced at an index that may split a UTF-8 character" "str indices are byte offsets; slicing at a fixed offset, len() - 1, or a caller-supplied index panics when it lands inside a multi-byte UTF-8 character. Use char_indices()/find() to get boundaries, s.get(..n) to fail softly, or floor_char_boundary"
+    show_str_slice_boundary_examples 3 || true
+    add_finding "warning" "$str_slice_hits" "str sliced at an index that may split a UTF-8 character" "str indices are byte offsets; slicing at a fixed offset, len() - 1, or a caller-supplied index panics when it lands inside a multi-byte UTF-8 character. Use char_indices()/find() to get boundaries, s.get(..n) to fail softly, or floor_char_boundary" "${CATEGORY_NAME[6]}" "$(collect_samples_str_slice_boundary 3)"
+  else
+    print_finding "good" "No str slicing at unchecked byte offsets"
+  fi
+fi
 fi
 
 # ═══════════════════════════════════════════════════════════════════════════
@@ -7939,7 +22667,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 7; then
 print_header "7. FILESYSTEM & PROCESS"
-print_category "Detects: blocking std::fs in async, process::Command usage heuristics" \
+print_category "Detects: blocking std::fs in async, process::Command usage heuristics, unsafe work in signal handlers, deletion of empty/unvalidated paths, check-then-use (TOCTOU) races" \
   "I/O misuse or command construction from untrusted input can be risky"
 
 print_subheader "std::fs usage (general inventory)"
@@ -7949,6 +22677,53 @@ if [ "$fs_any" -gt 0 ]; then print_finding "info" "$fs_any" "std::fs operations
 print_subheader "std::process::Command usage"
 cmd_count=$("${GREP_RN[@]}" -e "std::process::Command::new\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
 if [ "$cmd_count" -gt 0 ]; then print_finding "info" "$cmd_count" "Command::new detected - ensure args are sanitized and errors handled"; show_detailed_finding "std::process::Command::new\(" 3; add_finding "info" "$cmd_count" "Command::new detected - ensure args are sanitized and errors handled" "" "${CATEGORY_NAME[7]}" "$(collect_samples_rg "std::process::Command::new\(" 3)"; fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Signal handlers doing non-async-signal-safe work"
+  signal_handler_hits=$(count_signal_handler_matches || echo 0)
+  signal_handler_hits=$(printf '%s\n' "${signal_handler_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$signal_handler_hits" -gt 0 ]; then
+    print_finding "warning" "$signal_handler_hits" "Signal handler allocates, locks, or prints" "A signal can interrupt the thread while it holds the allocator or stdout lock, so allocating, locking, printing, or running exit handlers inside the handler can deadlock or corrupt state; only set an AtomicBool/write() to a pipe, or use signal_hook::flag / signal_hook::iterator and react on a normal thread"
+    show_signal_handler_examples 4 || true
+    add_finding "warning" "$signal_handler_hits" "Signal handler allocates, locks, or prints" "A signal can interrupt the thread while it holds the allocator or stdout lock, so allocating, locking, printing, or running exit handlers inside the handler can deadlock or corrupt state; only set an AtomicBool/write() to a pipe, or use signal_hook::flag / signal_hook::iterator and react on a normal thread" "${CATEGORY_NAME[7]}" "$(collect_samples_signal_handler 4)"
+  else
+    print_finding "good" "Signal handlers stick to async-signal-safe operations"
+  fi
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Filesystem deletion of unvalidated paths"
+  delete_empty_hits=$(count_dangerous_delete_matches empty || echo 0)
+  delete_empty_hits=$(printf '%s\n' "${delete_empty_hits:-0}" | awk 'END{print $0+0}')
+  delete_unvalidated_hits=$(count_dangerous_delete_matches unvalidated || echo 0)
+  delete_unvalidated_hits=$(printf '%s\n' "${delete_unvalidated_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$delete_empty_hits" -gt 0 ]; then
+    print_finding "critical" "$delete_empty_hits" "Deleted path may be empty or the filesystem root" "The path comes from an unwrap_or_default()/empty fallback or a literal root, so an unset variable turns remove_dir_all into a delete of \"\", \"/\", or /<name>; reject empty values and \"/\" explicitly before deleting"
+    show_dangerous_delete_examples empty 3 || true
+    add_finding "critical" "$delete_empty_hits" "Deleted path may be empty or the filesystem root" "The path comes from an unwrap_or_default()/empty fallback or a literal root, so an unset variable turns remove_dir_all into a delete of \"\", \"/\", or /<name>; reject empty values and \"/\" explicitly before deleting" "${CATEGORY_NAME[7]}" "$(collect_samples_dangerous_delete empty 3)"
+  fi
+  if [ "$delete_unvalidated_hits" -gt 0 ]; then
+    print_finding "warning" "$delete_unvalidated_hits" "Deletion of a constructed path without prefix validation" "remove_dir_all/remove_file on a path joined from request, argv, env, or caller-supplied segments can be steered outside the intended directory with ../ or an absolute segment; canonicalize the result and check starts_with(root) before deleting"
+    show_dangerous_delete_examples unvalidated 3 || true
+    add_finding "warning" "$delete_unvalidated_hits" "Deletion of a constructed path without prefix validation" "remove_dir_all/remove_file on a path joined from request, argv, env, or caller-supplied segments can be steered outside the intended directory with ../ or an absolute segment; canonicalize the result and check starts_with(root) before deleting" "${CATEGORY_NAME[7]}" "$(collect_samples_dangerous_delete unvalidated 3)"
+  fi
+  if [ "$delete_empty_hits" -eq 0 ] && [ "$delete_unvalidated_hits" -eq 0 ]; then
+    print_finding "good" "Deleted paths are fixed, temporary, or validated first"
+  fi
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Check-then-use races on filesystem paths (TOCTOU)"
+  toctou_hits=$(count_toctou_matches || echo 0)
+  toctou_hits=$(printf '%s\n' "${toctou_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$toctou_hits" -gt 0 ]; then
+    print_finding "warning" "$toctou_hits" "Path checked, then opened/created/removed separately" "Between exists()/is_file()/metadata() and the open, create, or remove, another process can create, delete, or swap the path for a symlink; skip the pre-check and act atomically: OpenOptions::new().write(true).create_new(true) for exclusive creation, and match on ErrorKind::NotFound/AlreadyExists from the operation itself"
+    show_toctou_examples 3 || true
+    add_finding "warning" "$toctou_hits" "Path checked, then opened/created/removed separately" "Between exists()/is_file()/metadata() and the open, create, or remove, another process can create, delete, or swap the path for a symlink; skip the pre-check and act atomically: OpenOptions::new().write(true).create_new(true) for exclusive creation, and match on ErrorKind::NotFound/AlreadyExists from the operation itself" "${CATEGORY_NAME[7]}" "$(collect_samples_toctou 3)"
+  else
+    print_finding "good" "No exists()/metadata() checks followed by a separate file operation"
+  fi
+fi
 fi
 
 # ═══════════════════════════════════════════════════════════════════════════
@@ -7956,12 +22731,44 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 8; then
 print_header "8. SECURITY FINDINGS"
-print_category "Detects: TLS verification disabled, weak hash algos, security-sensitive non-crypto randomness, timing-unsafe secret comparisons, JWT verification bypasses, shell command injection, request-derived response headers/open redirects/host-header absolute URLs/outbound URLs/SQL/regex, unbounded request body reads, credentialed CORS, HTTP URLs, secrets" \
+print_category "Detects: TLS verification disabled, weak hash algos, security-sensitive non-crypto randomness, timing-unsafe secret comparisons, JWT verification bypasses, shell command injection, request-derived response headers/open redirects/host-header absolute URLs/outbound URLs/SQL/regex, unbounded request body reads, uncapped socket read_to_end, unbounded deserialization, credentialed CORS, over-permissive file modes, secrets in panic/log messages, HTTP URLs, secrets" \
   "Security misconfigurations can lead to credential leaks, command injection, and MITM attacks"
 
+print_subheader "Weak crypto guarding passwords, signatures, and tokens"
+if [[ "$have_python3" -eq 1 ]]; then
+  weak_crypto_sensitive=$(count_weak_crypto_matches sensitive || echo 0)
+  weak_crypto_sensitive=$(printf '%s\n' "${weak_crypto_sensitive:-0}" | awk 'END{print $0+0}')
+  if [ "$weak_crypto_sensitive" -gt 0 ]; then
+    print_finding "critical" "$weak_crypto_sensitive" "MD5/SHA1/DES/ECB used for passwords, signatures, or tokens" "These primitives are collision-broken, brute-forceable, or leak plaintext patterns; hash passwords with Argon2/scrypt/bcrypt, sign with HMAC-SHA256 or Ed25519, and encrypt with AES-GCM or ChaCha20-Poly1305"
+    show_weak_crypto_examples sensitive 3 || true
+    add_finding "critical" "$weak_crypto_sensitive" "MD5/SHA1/DES/ECB used for passwords, signatures, or tokens" "These primitives are collision-broken, brute-forceable, or leak plaintext patterns; hash passwords with Argon2/scrypt/bcrypt, sign with HMAC-SHA256 or Ed25519, and encrypt with AES-GCM or ChaCha20-Poly1305" "${CATEGORY_NAME[8]}" "$(collect_samples_weak_crypto sensitive 3)"
+  else
+    print_finding "good" "No weak primitives near password, signature, or token handling"
+  fi
+fi
+
 print_subheader "Weak hash algorithms (MD5/SHA1)"
+if [[ "$have_python3" -eq 1 ]]; then
+  weak_hash=$(count_weak_crypto_matches hash || echo 0)
+  weak_hash=$(printf '%s\n' "${weak_hash:-0}" | awk 'END{print $0+0}')
+  if [ "$weak_hash" -gt 0 ]; then
+    print_finding "warning" "$weak_hash" "Weak hash algorithm usage (MD5/SHA1)" "Fine for non-adversarial checksums only; prefer SHA-256/BLAKE3 so the same helper is safe if it ever hashes untrusted data"
+    show_weak_crypto_examples hash 3 || true
+    add_finding "warning" "$weak_hash" "Weak hash algorithm usage (MD5/SHA1)" "Fine for non-adversarial checksums only; prefer SHA-256/BLAKE3 so the same helper is safe if it ever hashes untrusted data" "${CATEGORY_NAME[8]}" "$(collect_samples_weak_crypto hash 3)"
+  else
+    print_finding "good" "No MD5/SHA1 found"
+  fi
+  weak_cipher=$(count_weak_crypto_matches cipher || echo 0)
+  weak_cipher=$(printf '%s\n' "${weak_cipher:-0}" | awk 'END{print $0+0}')
+  if [ "$weak_cipher" -gt 0 ]; then
+    print_finding "warning" "$weak_cipher" "Weak cipher or ECB mode (DES/3DES/RC4/Blowfish/ECB)" "Use AES-GCM or ChaCha20-Poly1305; ECB encrypts equal blocks to equal ciphertext"
+    show_weak_crypto_examples cipher 3 || true
+    add_finding "warning" "$weak_cipher" "Weak cipher or ECB mode (DES/3DES/RC4/Blowfish/ECB)" "Use AES-GCM or ChaCha20-Poly1305; ECB encrypts equal blocks to equal ciphertext" "${CATEGORY_NAME[8]}" "$(collect_samples_weak_crypto cipher 3)"
+  fi
+else
 weak_hash=$(( $(ast_search 'md5::$F($$)' || echo 0) + $(ast_search 'sha1::$F($$)' || echo 0) + $("${GREP_RN[@]}" -e "SHA1_FOR_LEGACY_USE_ONLY|MessageDigest::(md5|sha1)\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
 if [ "$weak_hash" -gt 0 ]; then print_finding "warning" "$weak_hash" "Weak hash algorithm usage (MD5/SHA1)"; show_detailed_finding "md5::|sha1::|SHA1_FOR_LEGACY_USE_ONLY|MessageDigest::(md5|sha1)" 5; add_finding "warning" "$weak_hash" "Weak hash algorithm usage (MD5/SHA1)" "" "${CATEGORY_NAME[8]}" "$(collect_samples_rg "md5::|sha1::|SHA1_FOR_LEGACY_USE_ONLY|MessageDigest::(md5|sha1)" 5)"; else print_finding "good" "No MD5/SHA1 found"; fi
+fi
 
 print_subheader "TLS verification disabled"
 tls_insecure=$(( $(ast_search 'reqwest::ClientBuilder::new().danger_accept_invalid_certs(true)' || echo 0) \
@@ -7972,6 +22779,16 @@ tls_insecure=$(( $(ast_search 'reqwest::ClientBuilder::new().danger_accept_inval
   + $("${GREP_RN[@]}" -e "SslVerifyMode::NONE" "$PROJECT_DIR" 2>/dev/null | count_lines || true) \
   + $("${GREP_RN[@]}" -e "TlsConnector::builder\(\)\\.danger_accept_invalid_certs\(true\)" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
 if [ "$tls_insecure" -gt 0 ]; then print_finding "critical" "$tls_insecure" "TLS certificate or hostname verification disabled"; add_finding "critical" "$tls_insecure" "TLS certificate or hostname verification disabled" "" "${CATEGORY_NAME[8]}"; fi
+if [[ "$have_python3" -eq 1 ]]; then
+  tls_verifier=$(count_tls_verifier_matches || echo 0)
+  tls_verifier=$(printf '%s\n' "${tls_verifier:-0}" | awk 'END{print $0+0}')
+  if [ "$tls_verifier" -gt 0 ]; then
+    print_finding "critical" "$tls_verifier" "Custom certificate verifier accepts every peer" "ServerCertVerifier/ClientCertVerifier methods or set_verify_callback closures that unconditionally return Ok/true turn off chain, hostname, and signature checks; delegate to WebPkiServerVerifier or pin the expected certificate instead"
+    show_tls_verifier_examples 3
+    add_finding "critical" "$tls_verifier" "Custom certificate verifier accepts every peer" "ServerCertVerifier/ClientCertVerifier methods or set_verify_callback closures that unconditionally return Ok/true turn off chain, hostname, and signature checks; delegate to WebPkiServerVerifier or pin the expected certificate instead" "${CATEGORY_NAME[8]}" "$(collect_samples_tls_verifier 3)"
+  fi
+fi
+if [ "$tls_insecure" -eq 0 ] && [ "${tls_verifier:-0}" -eq 0 ]; then print_finding "good" "No disabled TLS verification found"; fi
 
 print_subheader "Security-sensitive non-crypto randomness"
 security_randomness_hits=$(count_security_randomness_matches || echo 0)
@@ -8043,6 +22860,27 @@ else
   print_finding "good" "No shell -c/-lc Command usage detected"
 fi
 
+print_subheader "Command injection through interpolated shell scripts and arguments"
+if [[ "$have_python3" -eq 1 ]]; then
+  command_injection_shell=$(count_command_injection_matches shell || echo 0)
+  command_injection_shell=$(printf '%s\n' "${command_injection_shell:-0}" | awk 'END{print $0+0}')
+  command_injection_argv=$(count_command_injection_matches argv || echo 0)
+  command_injection_argv=$(printf '%s\n' "${command_injection_argv:-0}" | awk 'END{print $0+0}')
+  if [ "$command_injection_shell" -gt 0 ]; then
+    print_finding "critical" "$command_injection_shell" "Shell script built from interpolated data" "The string handed to sh -c/cmd /C is assembled with format!/+/push_str, so quotes, ; and \$(..) in the data run as commands; call the program directly and pass each value as its own .arg()"
+    show_command_injection_examples shell 3 || true
+    add_finding "critical" "$command_injection_shell" "Shell script built from interpolated data" "The string handed to sh -c/cmd /C is assembled with format!/+/push_str, so quotes, ; and \$(..) in the data run as commands; call the program directly and pass each value as its own .arg()" "${CATEGORY_NAME[8]}" "$(collect_samples_command_injection shell 3)"
+  fi
+  if [ "$command_injection_argv" -gt 0 ]; then
+    print_finding "warning" "$command_injection_argv" "Command argument interpolated from untrusted input" "format!-built argv entries let input smuggle options (--upload-pack=, -o) into the child; pass the value as a separate .arg() after a literal flag or \"--\" and validate it (mark framework inputs with --taint-sources, extra spawners with --command-sinks)"
+    show_command_injection_examples argv 3 || true
+    add_finding "warning" "$command_injection_argv" "Command argument interpolated from untrusted input" "format!-built argv entries let input smuggle options (--upload-pack=, -o) into the child; pass the value as a separate .arg() after a literal flag or \"--\" and validate it (mark framework inputs with --taint-sources, extra spawners with --command-sinks)" "${CATEGORY_NAME[8]}" "$(collect_samples_command_injection argv 3)"
+  fi
+  if [ "$command_injection_shell" -eq 0 ] && [ "$command_injection_argv" -eq 0 ]; then
+    print_finding "good" "No interpolated shell scripts or untrusted argv entries detected"
+  fi
+fi
+
 print_subheader "Command::new executable from untrusted-looking value"
 command_executable_hits=$(count_command_executable_matches || echo 0)
 command_executable_hits=$(printf '%s\n' "${command_executable_hits:-0}" | awk 'END{print $0+0}')
@@ -8056,9 +22894,9 @@ print_subheader "Path join/push with untrusted-looking segment"
 path_traversal_hits=$(count_path_traversal_matches || echo 0)
 path_traversal_hits=$(printf '%s\n' "${path_traversal_hits:-0}" | awk 'END{print $0+0}')
 if [ "$path_traversal_hits" -gt 0 ]; then
-  print_finding "warning" "$path_traversal_hits" "Path join/push with untrusted-looking segment" "Reject absolute paths and '..' components; canonicalize and verify the result stays under the intended root"
+  print_finding "warning" "$path_traversal_hits" "Path join/push with untrusted-looking segment" "Request, argv, or stdin data joined onto a base path with no '..'/Component filter or canonicalize + starts_with check in the fn; an absolute or ../ segment escapes the root"
   show_path_traversal_examples 3 || true
-  add_finding "warning" "$path_traversal_hits" "Path join/push with untrusted-looking segment" "Reject absolute paths and '..' components; canonicalize and verify the result stays under the intended root" "${CATEGORY_NAME[8]}" "$(collect_samples_path_traversal 3)"
+  add_finding "warning" "$path_traversal_hits" "Path join/push with untrusted-looking segment" "Request, argv, or stdin data joined onto a base path with no '..'/Component filter or canonicalize + starts_with check in the fn; an absolute or ../ segment escapes the root" "${CATEGORY_NAME[8]}" "$(collect_samples_path_traversal 3)"
 fi
 
 print_subheader "Archive entry paths joined into extraction destination"
@@ -8079,6 +22917,27 @@ if [ "$temp_file_race_hits" -gt 0 ]; then
   add_finding "warning" "$temp_file_race_hits" "Predictable temp-file write race" "Use tempfile::NamedTempFile/tempfile::Builder or OpenOptions::create_new(true) with unpredictable names" "${CATEGORY_NAME[8]}" "$(collect_samples_temp_file_race 3)"
 fi
 
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Over-permissive file modes"
+  perm_secret_hits=$(count_file_permissions_matches secret || echo 0)
+  perm_secret_hits=$(printf '%s\n' "${perm_secret_hits:-0}" | awk 'END{print $0+0}')
+  perm_world_hits=$(count_file_permissions_matches world || echo 0)
+  perm_world_hits=$(printf '%s\n' "${perm_world_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$perm_secret_hits" -gt 0 ]; then
+    print_finding "critical" "$perm_secret_hits" "Secret or key file readable by other users" "Key, token, and credential files created with group/other read bits can be read by any local account; create them 0o600 (OpenOptionsExt::mode(0o600)) before writing the secret, and remember mode literals must be octal (0o600, not 600)"
+    show_file_permissions_examples secret 3 || true
+    add_finding "critical" "$perm_secret_hits" "Secret or key file readable by other users" "Key, token, and credential files created with group/other read bits can be read by any local account; create them 0o600 (OpenOptionsExt::mode(0o600)) before writing the secret, and remember mode literals must be octal (0o600, not 600)" "${CATEGORY_NAME[8]}" "$(collect_samples_file_permissions secret 3)"
+  fi
+  if [ "$perm_world_hits" -gt 0 ]; then
+    print_finding "warning" "$perm_world_hits" "World-writable file or directory mode" "0o777/0o666 style modes and Permissions::set_readonly(false) let every local user modify or replace the file; grant only what is needed (0o755/0o644, or 0o700/0o600 for private data) and add owner write bits with set_mode(mode | 0o200)"
+    show_file_permissions_examples world 3 || true
+    add_finding "warning" "$perm_world_hits" "World-writable file or directory mode" "0o777/0o666 style modes and Permissions::set_readonly(false) let every local user modify or replace the file; grant only what is needed (0o755/0o644, or 0o700/0o600 for private data) and add owner write bits with set_mode(mode | 0o200)" "${CATEGORY_NAME[8]}" "$(collect_samples_file_permissions world 3)"
+  fi
+  if [ "$perm_secret_hits" -eq 0 ] && [ "$perm_world_hits" -eq 0 ]; then
+    print_finding "good" "No world-writable modes or readable secret files"
+  fi
+fi
+
 print_subheader "Request-derived open redirects"
 open_redirect_hits=$(count_open_redirect_matches || echo 0)
 open_redirect_hits=$(printf '%s\n' "${open_redirect_hits:-0}" | awk 'END{print $0+0}')
@@ -8134,6 +22993,19 @@ else
   print_finding "good" "No request-derived SQL construction sinks detected"
 fi
 
+print_subheader "SQL built with format! or concatenation"
+if [[ "$have_python3" -eq 1 ]]; then
+  sql_built=$(count_sql_concat_matches || echo 0)
+  sql_built=$(printf '%s\n' "${sql_built:-0}" | awk 'END{print $0+0}')
+  if [ "$sql_built" -gt 0 ]; then
+    print_finding "warning" "$sql_built" "SQL query built with format! or concatenation" "Values spliced into the SQL text reach sqlx::query/diesel::sql_query/rusqlite/postgres unescaped; keep the query a literal with \$1/?1 placeholders and pass values with .bind(), params![..], or &[&value]"
+    show_sql_concat_examples 3 || true
+    add_finding "warning" "$sql_built" "SQL query built with format! or concatenation" "Values spliced into the SQL text reach sqlx::query/diesel::sql_query/rusqlite/postgres unescaped; keep the query a literal with \$1/?1 placeholders and pass values with .bind(), params![..], or &[&value]" "${CATEGORY_NAME[8]}" "$(collect_samples_sql_concat 3)"
+  else
+    print_finding "good" "No SQL text assembled from runtime values"
+  fi
+fi
+
 print_subheader "Request-controlled regex patterns"
 request_regex_hits=$(count_request_regex_matches || echo 0)
 request_regex_hits=$(printf '%s\n' "${request_regex_hits:-0}" | awk 'END{print $0+0}')
@@ -8156,6 +23028,40 @@ else
   print_finding "good" "No unbounded request body reads detected"
 fi
 
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Whole-stream reads from sockets"
+  stream_read_all_hits=$(count_stream_read_all_matches || echo 0)
+  stream_read_all_hits=$(printf '%s\n' "${stream_read_all_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$stream_read_all_hits" -gt 0 ]; then
+    print_finding "warning" "$stream_read_all_hits" "Socket or request stream read to end without a size cap" "read_to_end/read_to_string on a TcpStream, TLS stream, or request reader buffers whatever the peer sends; wrap the reader in .take(MAX_BYTES) before reading it to the end"
+    show_stream_read_all_examples 3 || true
+    add_finding "warning" "$stream_read_all_hits" "Socket or request stream read to end without a size cap" "read_to_end/read_to_string on a TcpStream, TLS stream, or request reader buffers whatever the peer sends; wrap the reader in .take(MAX_BYTES) before reading it to the end" "${CATEGORY_NAME[8]}" "$(collect_samples_stream_read_all 3)"
+  else
+    print_finding "good" "No uncapped read_to_end/read_to_string on sockets detected"
+  fi
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Untrusted deserialization limits"
+  deserialize_stream_hits=$(count_untrusted_deserialize_matches stream || echo 0)
+  deserialize_stream_hits=$(printf '%s\n' "${deserialize_stream_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$deserialize_stream_hits" -gt 0 ]; then
+    print_finding "warning" "$deserialize_stream_hits" "Network data deserialized without a size limit" "serde_json/bincode/rmp_serde readers fed straight from a socket or response will allocate whatever length prefix the peer sends; wrap the reader in .take(MAX), or use bincode options with_limit(MAX)"
+    show_untrusted_deserialize_examples stream 3 || true
+    add_finding "warning" "$deserialize_stream_hits" "Network data deserialized without a size limit" "serde_json/bincode/rmp_serde readers fed straight from a socket or response will allocate whatever length prefix the peer sends; wrap the reader in .take(MAX), or use bincode options with_limit(MAX)" "${CATEGORY_NAME[8]}" "$(collect_samples_untrusted_deserialize stream 3)"
+  fi
+  deserialize_fields_hits=$(count_untrusted_deserialize_matches fields || echo 0)
+  deserialize_fields_hits=$(printf '%s\n' "${deserialize_fields_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$deserialize_fields_hits" -gt 0 ]; then
+    print_finding "warning" "$deserialize_fields_hits" "Security-sensitive config struct accepts unknown fields" "Without #[serde(deny_unknown_fields)], a misspelled key such as require_mfa silently falls back to its default; add the attribute to auth/TLS/permission config structs"
+    show_untrusted_deserialize_examples fields 3 || true
+    add_finding "warning" "$deserialize_fields_hits" "Security-sensitive config struct accepts unknown fields" "Without #[serde(deny_unknown_fields)], a misspelled key such as require_mfa silently falls back to its default; add the attribute to auth/TLS/permission config structs" "${CATEGORY_NAME[8]}" "$(collect_samples_untrusted_deserialize fields 3)"
+  fi
+  if [ "$deserialize_stream_hits" -eq 0 ] && [ "$deserialize_fields_hits" -eq 0 ]; then
+    print_finding "good" "Deserialization of untrusted data is bounded"
+  fi
+fi
+
 print_subheader "CORS credential policy"
 cors_credential_hits=$(count_cors_credential_matches || echo 0)
 cors_credential_hits=$(printf '%s\n' "${cors_credential_hits:-0}" | awk 'END{print $0+0}')
@@ -8167,6 +23073,19 @@ else
   print_finding "good" "No unsafe CORS credential policy detected"
 fi
 
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Secrets interpolated into panic, print, or log messages"
+  secret_logging_hits=$(count_secret_logging_matches || echo 0)
+  secret_logging_hits=$(printf '%s\n' "${secret_logging_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$secret_logging_hits" -gt 0 ]; then
+    print_finding "warning" "$secret_logging_hits" "Secret value written to a panic or log message" "Passwords, tokens, and keys formatted into panic!/expect messages, println!, or log/tracing macros end up in crash reports, CI output, and log aggregation; log a redacted form, a length, or a fingerprint instead"
+    show_secret_logging_examples 3 || true
+    add_finding "warning" "$secret_logging_hits" "Secret value written to a panic or log message" "Passwords, tokens, and keys formatted into panic!/expect messages, println!, or log/tracing macros end up in crash reports, CI output, and log aggregation; log a redacted form, a length, or a fingerprint instead" "${CATEGORY_NAME[8]}" "$(collect_samples_secret_logging 3)"
+  else
+    print_finding "good" "No secrets interpolated into panic or log messages"
+  fi
+fi
+
 print_subheader "Plain http:// URLs"
 http_url=$(( $(ast_search '"http://$REST"' || echo 0) + $("${GREP_RN[@]}" -e "http://[A-Za-z0-9]" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
 if [ "$http_url" -gt 0 ]; then print_finding "info" "$http_url" "Plain HTTP URL(s) detected"; add_finding "info" "$http_url" "Plain HTTP URL(s) detected" "" "${CATEGORY_NAME[8]}"; fi
@@ -8175,7 +23094,10 @@ print_subheader "Hardcoded secrets/credentials"
 if ! command -v python3 >/dev/null 2>&1; then
   print_finding "info" 0 "python3 not available" "Install python3 to enable hardcoded secret checks"
 else
-  hardcoded_secret_report=$(python3 - "$PROJECT_DIR" <<'PY' 2>/dev/null
+  hardcoded_secret_report=$(UBS_RUST_SECRET_ALLOWLIST="$SECRET_ALLOWLIST" python3 - "$PROJECT_DIR" <<'PY' 2>/dev/null
+import fnmatch
+import math
+import os
 import re
 import sys
 from pathlib import Path
@@ -8210,6 +23132,23 @@ DECL_RE = re.compile(r'\b(?:pub\s+)?(?:const|static|let)(?:\s+mut)?\s+([A-Za-z_]
 FIELD_RE = re.compile(r'(?:^|[{,]\s*)([A-Za-z_][A-Za-z0-9_]*)\s*:\s*(.+)')
 INSERT_RE = re.compile(r'\.insert\s*\(\s*(' + STRING_RE.pattern + r')\s*,\s*(.+)\)')
 ENV_CALL_RE = re.compile(r'(?:std::env::var|env::var|option_env!)\s*!?\s*\(\s*(' + STRING_RE.pattern + r')\s*\)')
+# Provider token shapes are reported wherever the literal appears, whatever it is named.
+TOKEN_SHAPES = [
+    ('aws-access-key', re.compile(r'\b(?:AKIA|ASIA)[0-9A-Z]{16}\b')),
+    ('github-token', re.compile(r'\bgh[pousr]_[A-Za-z0-9]{36,}\b|\bgithub_pat_[A-Za-z0-9_]{60,}\b')),
+    ('slack-token', re.compile(r'\bxox[abposr]-[A-Za-z0-9-]{10,}\b')),
+    ('slack-webhook', re.compile(r'https://hooks\.slack\.com/services/T[A-Za-z0-9_]+/B[A-Za-z0-9_]+/[A-Za-z0-9_]+')),
+    ('stripe-live-key', re.compile(r'\b[rs]k_live_[A-Za-z0-9]{16,}\b')),
+    ('google-api-key', re.compile(r'\bAIza[0-9A-Za-z_-]{35}\b')),
+    ('private-key-pem', re.compile(r'-----BEGIN (?:RSA |EC |DSA |OPENSSH |ENCRYPTED )?PRIVATE KEY-----')),
+    # Base64 DER of PKCS#1/PKCS#8 keys starts with MII.. and runs for hundreds of chars.
+    ('base64-private-key', re.compile(r'^MII[A-Za-z0-9+/]{60,}={0,2}$')),
+]
+# Values this repetitive are placeholders ("xxxxxxxx", "********"), not credentials.
+MIN_ENTROPY = 2.0
+ALLOWLIST = [item.strip() for item in os.environ.get('UBS_RUST_SECRET_ALLOWLIST', '').split(',') if item.strip()]
+ALLOW_PATHS = [item for item in ALLOWLIST if '/' in item or '*' in item or item.endswith('.rs')]
+ALLOW_VALUES = {item for item in ALLOWLIST if item not in ALLOW_PATHS}
 
 def should_skip(path: Path) -> bool:
     return any(part in SKIP_DIRS for part in path.parts)
@@ -8308,12 +23247,41 @@ def unquote_literal(token: str) -> str:
         return token[1:-1]
     return ''
 
+def entropy(value: str) -> float:
+    if not value:
+        return 0.0
+    counts = {}
+    for ch in value:
+        counts[ch] = counts.get(ch, 0) + 1
+    return -sum((n / len(value)) * math.log2(n / len(value)) for n in counts.values())
+
+def path_allowed(rel: str) -> bool:
+    return any(fnmatch.fnmatch(rel, pattern) or fnmatch.fnmatch(rel, '*/' + pattern) for pattern in ALLOW_PATHS)
+
+def token_shape(statement: str) -> str:
+    # PEM blocks usually open a multi-line raw string, so match the header on its own.
+    if TOKEN_SHAPES[6][1].search(statement):
+        return 'private-key-pem'
+    for match in STRING_RE.finditer(statement):
+        value = unquote_literal(match.group(0)).strip()
+        if not value or value in ALLOW_VALUES:
+            continue
+        for kind, regex in TOKEN_SHAPES:
+            if regex.search(value):
+                return f'{kind}, entropy {entropy(value):.1f}'
+    return ''
+
 def risky_literal(token: str) -> bool:
     value = unquote_literal(token).strip()
     lowered = value.lower()
     if len(value) < 8:
         return False
-    if lowered in PLACEHOLDERS:
+    if lowered in PLACEHOLDERS or value in ALLOW_VALUES:
+        return False
+    if entropy(value) < MIN_ENTROPY:
+        return False
+    # SCREAMING_CASE values name an env var or key rather than holding the secret.
+    if re.fullmatch(r'[A-Z][A-Z0-9_]*', value):
         return False
     if 'example.' in lowered or lowered.startswith(('example_', 'sample_', 'dummy_')):
         return False
@@ -8352,6 +23320,8 @@ def env_fallback_literal(statement: str) -> bool:
 
 issues = []
 for path in iter_files(ROOT):
+    if path_allowed(relpath(path)):
+        continue
     try:
         lines = path.read_text(encoding='utf-8', errors='ignore').splitlines()
     except OSError:
@@ -8360,13 +23330,19 @@ for path in iter_files(ROOT):
         stripped = strip_comments(line).strip()
         if not stripped or has_ignore(lines, idx):
             continue
+        shape = token_shape(stripped) if '"' in stripped else ''
+        if shape:
+            issues.append((relpath(path), idx + 1, f'{source_line(lines, idx + 1)}  [{shape}]'))
+            continue
         if not (is_sensitive_name(stripped) or ENV_CALL_RE.search(stripped)):
             continue
         statement = statement_from(lines, idx)
         if not statement or 'ubs:ignore' in statement:
             continue
         if assignment_literal(statement) or env_fallback_literal(statement):
-            issues.append((relpath(path), idx + 1, source_line(lines, idx + 1)))
+            literal = first_risky_literal(statement)
+            score = f'  [entropy {entropy(unquote_literal(literal)):.1f}]' if literal else ''
+            issues.append((relpath(path), idx + 1, source_line(lines, idx + 1) + score))
 
 deduped = []
 seen = set()
@@ -8390,7 +23366,7 @@ PY
     esac
   done <<<"$hardcoded_secret_report"
   if [ "$hardcoded_secret_count" -gt 0 ]; then
-    print_finding "critical" "$hardcoded_secret_count" "Possible hardcoded secrets" "Use secret managers or required environment variables; do not keep literal fallbacks for secret env vars"
+    print_finding "critical" "$hardcoded_secret_count" "Possible hardcoded secrets" "Use secret managers or required environment variables; do not keep literal fallbacks for secret env vars (AWS/GitHub/Slack/Stripe token shapes and private keys are matched anywhere; exempt fixtures with --secret-allowlist)"
     sample_limit=3
     while IFS=$'\t' read -r sample_path sample_line sample_text; do
       [ -z "$sample_path" ] && continue
@@ -8398,7 +23374,7 @@ PY
       sample_limit=$((sample_limit - 1))
       [ "$sample_limit" -le 0 ] && break
     done <<<"$hardcoded_secret_samples"
-    add_finding "critical" "$hardcoded_secret_count" "Possible hardcoded secrets" "Use secret managers or required environment variables; do not keep literal fallbacks for secret env vars" "${CATEGORY_NAME[8]}"
+    add_finding "critical" "$hardcoded_secret_count" "Possible hardcoded secrets" "Use secret managers or required environment variables; do not keep literal fallbacks for secret env vars (AWS/GitHub/Slack/Stripe token shapes and private keys are matched anywhere; exempt fixtures with --secret-allowlist)" "${CATEGORY_NAME[8]}"
   else
     print_finding "good" "No hardcoded secrets detected"
   fi
@@ -8451,7 +23427,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 11; then
 print_header "11. TESTS & BENCHES HYGIENE"
-print_category "Detects: ignored tests, todo! in tests, println!/dbg! in tests" \
+print_category "Detects: ignored tests, todo! in tests, println!/dbg! in tests, sleep-based waiting in tests" \
   "Ensure tests do not hide failures or produce noisy output"
 
 print_subheader "#[ignore] tests"
@@ -8462,6 +23438,27 @@ print_subheader "todo!/unimplemented! in tests"
 test_todo=$("${GREP_RN[@]}" -e "#\[test\]" "$PROJECT_DIR" 2>/dev/null | (grep -A5 -E "todo!|unimplemented!" || true) | (grep -E "todo!|unimplemented!" || true) | count_lines)
 test_todo=${test_todo:-0}
 if [ "$test_todo" -gt 0 ]; then print_finding "info" "$test_todo" "todo!/unimplemented! seen near #[test]"; add_finding "info" "$test_todo" "todo!/unimplemented! seen near #[test]" "" "${CATEGORY_NAME[11]}"; fi
+
+print_subheader "Sleep-based waiting in tests"
+if [[ "$have_python3" -eq 1 ]]; then
+  test_sleep_spawn=$(count_test_sleep_matches spawn || echo 0)
+  test_sleep_spawn=$(printf '%s\n' "${test_sleep_spawn:-0}" | awk 'END{print $0+0}')
+  test_sleep_plain=$(count_test_sleep_matches plain || echo 0)
+  test_sleep_plain=$(printf '%s\n' "${test_sleep_plain:-0}" | awk 'END{print $0+0}')
+  if [ "$test_sleep_spawn" -gt 0 ]; then
+    print_finding "warning" "$test_sleep_spawn" "Test sleeps to wait for concurrent work" "A fixed thread::sleep/tokio::time::sleep only hopes the spawned thread or task has finished, so the test is flaky on a loaded CI box and slow everywhere else; join the handle, wait on a channel (recv_timeout), Barrier or Notify, or poll with a deadline"
+    show_test_sleep_examples spawn 3 || true
+    add_finding "warning" "$test_sleep_spawn" "Test sleeps to wait for concurrent work" "A fixed thread::sleep/tokio::time::sleep only hopes the spawned thread or task has finished, so the test is flaky on a loaded CI box and slow everywhere else; join the handle, wait on a channel (recv_timeout), Barrier or Notify, or poll with a deadline" "${CATEGORY_NAME[11]}" "$(collect_samples_test_sleep spawn 3)"
+  fi
+  if [ "$test_sleep_plain" -gt 0 ]; then
+    print_finding "info" "$test_sleep_plain" "Test sleeps on the real clock" "Timeouts and expiry can be tested without waiting: use #[tokio::test(start_paused = true)] or tokio::time::pause()/advance(), or inject a clock"
+    show_test_sleep_examples plain 3 || true
+    add_finding "info" "$test_sleep_plain" "Test sleeps on the real clock" "Timeouts and expiry can be tested without waiting: use #[tokio::test(start_paused = true)] or tokio::time::pause()/advance(), or inject a clock" "${CATEGORY_NAME[11]}" "$(collect_samples_test_sleep plain 3)"
+  fi
+  if [ "$test_sleep_spawn" -eq 0 ] && [ "$test_sleep_plain" -eq 0 ]; then
+    print_finding "good" "No sleep-based waiting in tests"
+  fi
+fi
 fi
 
 # ═══════════════════════════════════════════════════════════════════════════
@@ -8590,7 +23587,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 15; then
 print_header "15. API MISUSE (COMMON)"
-print_category "Detects: nth(0), DefaultHasher, expect_err/unwrap_err, Option::unwrap_or_default in hot paths" \
+print_category "Detects: nth(0), DefaultHasher, expect_err/unwrap_err, Option::unwrap_or_default in hot paths, discarded #[must_use] values, #[non_exhaustive] matches without a real fallback" \
   "Common footguns and readability hazards"
 
 print_subheader "std::collections::hash_map::DefaultHasher"
@@ -8605,6 +23602,38 @@ if [ "$unwrap_err" -gt 0 ] || [ "$expect_err" -gt 0 ]; then print_finding "info"
 print_subheader "Option::unwrap_or_default inventory"
 uod=$("${GREP_RN[@]}" -e "\.unwrap_or_default\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
 if [ "$uod" -gt 0 ]; then print_finding "info" "$uod" "unwrap_or_default present - validate default semantics"; add_finding "info" "$uod" "unwrap_or_default present - validate default semantics" "" "${CATEGORY_NAME[15]}"; fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Discarded #[must_use] values"
+  must_use_hits=$(count_unused_must_use_matches || echo 0)
+  must_use_hits=$(printf '%s\n' "${must_use_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$must_use_hits" -gt 0 ]; then
+    print_finding "warning" "$must_use_hits" "Result of #[must_use] call discarded" "Lazy iterator adapters, lock/borrow guards, pure str/int methods (trim, to_uppercase, wrapping_add), Results, and local #[must_use] fns do nothing useful as bare statements; bind or consume the value (also inside macro_rules! bodies, where rustc only warns at expansion sites)"
+    show_unused_must_use_examples 5 || true
+    add_finding "warning" "$must_use_hits" "Result of #[must_use] call discarded" "Lazy iterator adapters, lock/borrow guards, pure str/int methods (trim, to_uppercase, wrapping_add), Results, and local #[must_use] fns do nothing useful as bare statements; bind or consume the value (also inside macro_rules! bodies, where rustc only warns at expansion sites)" "${CATEGORY_NAME[15]}" "$(collect_samples_unused_must_use 5)"
+  else
+    print_finding "good" "No discarded #[must_use] values"
+  fi
+
+  print_subheader "Matches over #[non_exhaustive] enums"
+  non_exhaustive_missing=$(count_non_exhaustive_match_matches missing || echo 0)
+  non_exhaustive_missing=$(printf '%s\n' "${non_exhaustive_missing:-0}" | awk 'END{print $0+0}')
+  non_exhaustive_panic=$(count_non_exhaustive_match_matches panic || echo 0)
+  non_exhaustive_panic=$(printf '%s\n' "${non_exhaustive_panic:-0}" | awk 'END{print $0+0}')
+  if [ "$non_exhaustive_missing" -gt 0 ]; then
+    print_finding "warning" "$non_exhaustive_missing" "Match on a #[non_exhaustive] enum has no wildcard arm" "io::ErrorKind, syn::Expr and other #[non_exhaustive] enums from another crate can gain variants in a minor release; without a _ arm the match fails to compile (E0004) as soon as the dependency is upgraded. Add a fallback arm that handles the unknown case"
+    show_non_exhaustive_match_examples missing 3 || true
+    add_finding "warning" "$non_exhaustive_missing" "Match on a #[non_exhaustive] enum has no wildcard arm" "io::ErrorKind, syn::Expr and other #[non_exhaustive] enums from another crate can gain variants in a minor release; without a _ arm the match fails to compile (E0004) as soon as the dependency is upgraded. Add a fallback arm that handles the unknown case" "${CATEGORY_NAME[15]}" "$(collect_samples_non_exhaustive_match missing 3)"
+  fi
+  if [ "$non_exhaustive_panic" -gt 0 ]; then
+    print_finding "warning" "$non_exhaustive_panic" "Wildcard arm for a #[non_exhaustive] enum panics" "_ => unreachable!()/panic!() on io::ErrorKind, IntErrorKind or a dependency's #[non_exhaustive] enum turns the next added variant into a runtime crash; map the unknown case to a generic error or log-and-continue"
+    show_non_exhaustive_match_examples panic 3 || true
+    add_finding "warning" "$non_exhaustive_panic" "Wildcard arm for a #[non_exhaustive] enum panics" "_ => unreachable!()/panic!() on io::ErrorKind, IntErrorKind or a dependency's #[non_exhaustive] enum turns the next added variant into a runtime crash; map the unknown case to a generic error or log-and-continue" "${CATEGORY_NAME[15]}" "$(collect_samples_non_exhaustive_match panic 3)"
+  fi
+  if [ "$non_exhaustive_missing" -eq 0 ] && [ "$non_exhaustive_panic" -eq 0 ]; then
+    print_finding "good" "Matches over #[non_exhaustive] enums keep a real fallback"
+  fi
+fi
 fi
 
 # ═══════════════════════════════════════════════════════════════════════════
@@ -8612,21 +23641,66 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 16; then
 print_header "16. DOMAIN-SPECIFIC HEURISTICS"
-print_category "Detects: reqwest builder, SQL string concatenation (heuristic), serde_json::from_str without context" \
+print_category "Detects: reqwest builder, missing HTTP/TCP timeouts, Duration unit mix-ups, SQL string concatenation (heuristic), serde_json::from_str without context" \
   "Domain patterns that often hint at bugs"
 
 print_subheader "reqwest::ClientBuilder inventory"
 reqwest_builder=$("${GREP_RN[@]}" -e "reqwest::ClientBuilder::new\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
 if [ "$reqwest_builder" -gt 0 ]; then print_finding "info" "$reqwest_builder" "reqwest ClientBuilder usage - review TLS, timeouts, redirects"; add_finding "info" "$reqwest_builder" "reqwest ClientBuilder usage - review TLS, timeouts, redirects" "" "${CATEGORY_NAME[16]}"; fi
 
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Network calls without timeouts"
+  http_timeout_hits=$(count_network_timeout_matches http || echo 0)
+  http_timeout_hits=$(printf '%s\n' "${http_timeout_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$http_timeout_hits" -gt 0 ]; then
+    print_finding "warning" "$http_timeout_hits" "HTTP client without a request timeout" "reqwest::Client::new(), reqwest::get(), and builders with no .timeout(..) wait forever on a stalled server; set ClientBuilder::timeout or RequestBuilder::timeout"
+    show_network_timeout_examples http 3 || true
+    add_finding "warning" "$http_timeout_hits" "HTTP client without a request timeout" "reqwest::Client::new(), reqwest::get(), and builders with no .timeout(..) wait forever on a stalled server; set ClientBuilder::timeout or RequestBuilder::timeout" "${CATEGORY_NAME[16]}" "$(collect_samples_network_timeout http 3)"
+  fi
+  tcp_timeout_hits=$(count_network_timeout_matches tcp || echo 0)
+  tcp_timeout_hits=$(printf '%s\n' "${tcp_timeout_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$tcp_timeout_hits" -gt 0 ]; then
+    print_finding "warning" "$tcp_timeout_hits" "TcpStream connected without a read timeout" "Blocking reads on a std TcpStream hang until the peer closes; call set_read_timeout(Some(..)) after connect, or wrap tokio connects and reads in tokio::time::timeout"
+    show_network_timeout_examples tcp 3 || true
+    add_finding "warning" "$tcp_timeout_hits" "TcpStream connected without a read timeout" "Blocking reads on a std TcpStream hang until the peer closes; call set_read_timeout(Some(..)) after connect, or wrap tokio connects and reads in tokio::time::timeout" "${CATEGORY_NAME[16]}" "$(collect_samples_network_timeout tcp 3)"
+  fi
+  if [ "$http_timeout_hits" -eq 0 ] && [ "$tcp_timeout_hits" -eq 0 ]; then
+    print_finding "good" "Network clients configure timeouts"
+  fi
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Duration unit confusion"
+  duration_ctor_hits=$(count_duration_unit_matches ctor || echo 0)
+  duration_ctor_hits=$(printf '%s\n' "${duration_ctor_hits:-0}" | awk 'END{print $0+0}')
+  duration_cmp_hits=$(count_duration_unit_matches compare || echo 0)
+  duration_cmp_hits=$(printf '%s\n' "${duration_cmp_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$duration_ctor_hits" -gt 0 ]; then
+    print_finding "warning" "$duration_ctor_hits" "Duration constructed in a different unit than its input" "The constructor's unit disagrees with the unit in the argument's name (from_secs(backoff_ms), from_millis(timeout_secs)), or converts one as_*() value with a different from_*() constructor; a millisecond count read as seconds sleeps 1000x too long. Match the constructor to the unit, or keep the value as a Duration end to end"
+    show_duration_unit_examples ctor 3 || true
+    add_finding "warning" "$duration_ctor_hits" "Duration constructed in a different unit than its input" "The constructor's unit disagrees with the unit in the argument's name (from_secs(backoff_ms), from_millis(timeout_secs)), or converts one as_*() value with a different from_*() constructor; a millisecond count read as seconds sleeps 1000x too long. Match the constructor to the unit, or keep the value as a Duration end to end" "${CATEGORY_NAME[16]}" "$(collect_samples_duration_unit ctor 3)"
+  fi
+  if [ "$duration_cmp_hits" -gt 0 ]; then
+    print_finding "warning" "$duration_cmp_hits" "Duration compared with a raw integer of another unit" "Comparing as_secs()/as_millis() against an integer named in a different unit, or against a bare timeout/TTL integer whose unit is only implied, hides off-by-1000 bugs; store limits as Duration and compare Durations directly"
+    show_duration_unit_examples compare 3 || true
+    add_finding "warning" "$duration_cmp_hits" "Duration compared with a raw integer of another unit" "Comparing as_secs()/as_millis() against an integer named in a different unit, or against a bare timeout/TTL integer whose unit is only implied, hides off-by-1000 bugs; store limits as Duration and compare Durations directly" "${CATEGORY_NAME[16]}" "$(collect_samples_duration_unit compare 3)"
+  fi
+  if [ "$duration_ctor_hits" -eq 0 ] && [ "$duration_cmp_hits" -eq 0 ]; then
+    print_finding "good" "Duration units agree with the values they are built from"
+  fi
+fi
+
 print_subheader "serde_json::from_str without error context (heuristic)"
 from_str=$("${GREP_RN[@]}" -e "serde_json::from_str::<" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
 if [ "$from_str" -gt 0 ]; then print_finding "info" "$from_str" "serde_json::from_str uses - ensure error context and validation"; add_finding "info" "$from_str" "serde_json::from_str uses - ensure error context and validation" "" "${CATEGORY_NAME[16]}"; fi
 
+# With python3 the sink-aware "SQL query built with format! or concatenation" rule (category 8) covers this.
+if [[ "$have_python3" -ne 1 ]]; then
 print_subheader "SQL string concatenation (heuristic)"
 sql_concat=$("${GREP_RN[@]}" -e "(SELECT|INSERT|UPDATE|DELETE)[^;]*\+[[:space:]]*[_a-zA-Z0-9\"]" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
 if [ "$sql_concat" -gt 0 ]; then print_finding "warning" "$sql_concat" "Possible SQL construction via concatenation - prefer parameters"; add_finding "warning" "$sql_concat" "Possible SQL construction via concatenation - prefer parameters" "" "${CATEGORY_NAME[16]}"; fi
 fi
+fi
 
 # ═══════════════════════════════════════════════════════════════════════════
 # CATEGORY 17: AST-GREP RULE PACK FINDINGS (JSON/SARIF passthrough)
@@ -8676,7 +23750,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 20; then
 print_header "20. ASYNC LOCKING ACROSS AWAIT"
-print_category "Detects: locks acquired in async fns and potentially held across await" \
+print_category "Detects: locks acquired in async fns and potentially held across await, per-iteration lock scopes spanning an await in loops, tokio Mutexes that never span an await" \
   "Holding locks across await can deadlock, starve tasks, and cause latency spikes; std::sync locks can block executor threads"
 
 print_subheader "std::sync lock usage inside async fn (blocking risk)"
@@ -8688,18 +23762,57 @@ else
   print_finding "good" "No obvious std::sync lock usage inside async fns"
 fi
 
-print_subheader "Potential std::sync guard held across await (heuristic)"
-std_guard_await=$(( $(ast_search 'async fn $N($$) { $$ let $G = $M.lock().unwrap(); $$ $X.await $$ }' || echo 0) + $(ast_search 'async fn $N($$) { $$ let $G = $M.lock().expect($MSG); $$ $X.await $$ }' || echo 0) ))
+print_subheader "std::sync guard held across await"
+# Scope-aware: a guard bound with let lives until its enclosing block closes or drop(guard).
+if [[ "$have_python3" -eq 1 ]]; then
+  std_guard_await=$(count_guard_across_await_matches std || echo 0)
+  std_guard_await=$(printf '%s\n' "${std_guard_await:-0}" | awk 'END{print $0+0}')
+else
+  std_guard_await=$(( $(ast_search 'async fn $N($$) { $$ let $G = $M.lock().unwrap(); $$ $X.await $$ }' || echo 0) + $(ast_search 'async fn $N($$) { $$ let $G = $M.lock().expect($MSG); $$ $X.await $$ }' || echo 0) ))
+fi
 if [ "$std_guard_await" -gt 0 ]; then
-  print_finding "warning" "$std_guard_await" "Potential lock guard across await (std::sync)" "Drop the guard before awaiting (scoped blocks or drop(guard))"
-  add_finding "warning" "$std_guard_await" "Potential lock guard across await (std::sync)" "Drop the guard before awaiting (scoped blocks or drop(guard))" "${CATEGORY_NAME[20]}"
+  print_finding "warning" "$std_guard_await" "Potential lock guard across await (std::sync)" "A std MutexGuard/RwLock guard alive at an .await blocks other tasks and can deadlock the executor; drop the guard before awaiting (scoped blocks or drop(guard)), or switch to tokio::sync::Mutex if the guard really must live across the await"
+  show_guard_across_await_examples std 3 || true
+  add_finding "warning" "$std_guard_await" "Potential lock guard across await (std::sync)" "A std MutexGuard/RwLock guard alive at an .await blocks other tasks and can deadlock the executor; drop the guard before awaiting (scoped blocks or drop(guard)), or switch to tokio::sync::Mutex if the guard really must live across the await" "${CATEGORY_NAME[20]}" "$(collect_samples_guard_across_await std 3)"
 fi
 
-print_subheader "Potential async lock guard held across await (tokio/async locks heuristic)"
-tokio_guard_await=$(( $(ast_search 'async fn $N($$) { $$ let $G = $M.lock().await; $$ $X.await $$ }' || echo 0) + $(ast_search 'async fn $N($$) { $$ let $G = $M.read().await; $$ $X.await $$ }' || echo 0) + $(ast_search 'async fn $N($$) { $$ let $G = $M.write().await; $$ $X.await $$ }' || echo 0) + $("${GREP_RN[@]}" -e "let\s+[A-Za-z_][A-Za-z0-9_]*\s*=\s*[^;]*\.(lock|read|write)\(\)\.await" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "std::sync guard held across await inside loops"
+  loop_guard_await=$(count_guard_across_await_matches loop || echo 0)
+  loop_guard_await=$(printf '%s\n' "${loop_guard_await:-0}" | awk 'END{print $0+0}')
+  if [ "$loop_guard_await" -gt 0 ]; then
+    print_finding "warning" "$loop_guard_await" "Lock taken each loop iteration and held across .await" "Taking a std::sync lock at the top of each iteration and awaiting inside the critical section makes every task queue behind whichever one is suspended, so the loop runs the whole async workload one at a time; move the await outside the guard (copy what you need out in a scoped block first), or use tokio::sync locks if the state really must stay locked across the await"
+    show_guard_across_await_examples loop 3 || true
+    add_finding "warning" "$loop_guard_await" "Lock taken each loop iteration and held across .await" "Taking a std::sync lock at the top of each iteration and awaiting inside the critical section makes every task queue behind whichever one is suspended, so the loop runs the whole async workload one at a time; move the await outside the guard (copy what you need out in a scoped block first), or use tokio::sync locks if the state really must stay locked across the await" "${CATEGORY_NAME[20]}" "$(collect_samples_guard_across_await loop 3)"
+  else
+    print_finding "good" "No per-iteration lock scopes spanning an await"
+  fi
+fi
+
+print_subheader "Async lock guard held across await (tokio/async locks)"
+if [[ "$have_python3" -eq 1 ]]; then
+  tokio_guard_await=$(count_guard_across_await_matches async || echo 0)
+  tokio_guard_await=$(printf '%s\n' "${tokio_guard_await:-0}" | awk 'END{print $0+0}')
+else
+  tokio_guard_await=$(( $(ast_search 'async fn $N($$) { $$ let $G = $M.lock().await; $$ $X.await $$ }' || echo 0) + $(ast_search 'async fn $N($$) { $$ let $G = $M.read().await; $$ $X.await $$ }' || echo 0) + $(ast_search 'async fn $N($$) { $$ let $G = $M.write().await; $$ $X.await $$ }' || echo 0) + $("${GREP_RN[@]}" -e "let\s+[A-Za-z_][A-Za-z0-9_]*\s*=\s*[^;]*\.(lock|read|write)\(\)\.await" "$PROJECT_DIR" 2>/dev/null | count_lines || true) ))
+fi
 if [ "$tokio_guard_await" -gt 0 ]; then
   print_finding "warning" "$tokio_guard_await" "Potential async lock guard across await" "Reduce critical section; prefer copying needed data out; explicit drop() before await"
-  add_finding "warning" "$tokio_guard_await" "Potential async lock guard across await" "Reduce critical section; prefer copying needed data out; explicit drop() before await" "${CATEGORY_NAME[20]}"
+  show_guard_across_await_examples async 3 || true
+  add_finding "warning" "$tokio_guard_await" "Potential async lock guard across await" "Reduce critical section; prefer copying needed data out; explicit drop() before await" "${CATEGORY_NAME[20]}" "$(collect_samples_guard_across_await async 3)"
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "tokio Mutex never held across await"
+  tokio_sync_only=$(count_guard_across_await_matches sync_only || echo 0)
+  tokio_sync_only=$(printf '%s\n' "${tokio_sync_only:-0}" | awk 'END{print $0+0}')
+  if [ "$tokio_sync_only" -gt 0 ]; then
+    print_finding "info" "$tokio_sync_only" "tokio::sync::Mutex used only for synchronous critical sections" "Every guard of this tokio::sync::Mutex is released before the next .await, so the async lock only adds overhead; std::sync::Mutex (or parking_lot) is faster for short, non-awaiting critical sections"
+    show_guard_across_await_examples sync_only 3 || true
+    add_finding "info" "$tokio_sync_only" "tokio::sync::Mutex used only for synchronous critical sections" "Every guard of this tokio::sync::Mutex is released before the next .await, so the async lock only adds overhead; std::sync::Mutex (or parking_lot) is faster for short, non-awaiting critical sections" "${CATEGORY_NAME[20]}" "$(collect_samples_guard_across_await sync_only 3)"
+  else
+    print_finding "good" "tokio Mutexes are only used where guards span an await"
+  fi
 fi
 fi
 
@@ -8708,7 +23821,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 21; then
 print_header "21. PANIC SURFACES & UNWINDING"
-print_category "Detects: assert macros, direct indexing, unreachable_unchecked/unwrap_unchecked, panic/unwrap inside Drop" \
+print_category "Detects: assert macros, direct indexing, off-by-one range bounds, clock-skew panics, unreachable_unchecked/unwrap_unchecked, panic/unwrap inside Drop, panics across FFI, unwrap/indexing in actix/axum handlers" \
   "Panics in destructors or UB hints can crash/abort in subtle ways; these can slip past linting depending on cfg/features"
 
 print_subheader "assert!/assert_eq!/assert_ne! inventory"
@@ -8742,13 +23855,124 @@ if [ "$direct_index" -gt 0 ]; then
   add_finding "warning" "$direct_index" "Direct indexing/slicing may panic" "Use get()/get_mut(), checked ranges, or prior bounds checks when indexes can come from input" "${CATEGORY_NAME[21]}" "$(collect_samples_ast_or_rg '\[[^]]+\]' 3 '$X[$I]')"
 fi
 
+print_subheader "Likely out-of-bounds indexing (input indexes, len()-1, ..=len() loops)"
+if [[ "$have_python3" -eq 1 ]]; then
+  index_oob=$(count_index_oob_matches || echo 0)
+  index_oob=$(printf '%s\n' "${index_oob:-0}" | awk 'END{print $0+0}')
+  if [ "$index_oob" -gt 0 ]; then
+    print_finding "warning" "$index_oob" "Index likely out of bounds" "The index comes from parsed/IO input without a len() check, is len()-1 on a possibly empty collection, or is a ..=len() loop counter; use .get()/.get_mut()/.last() and handle None"
+    show_index_oob_examples 3 || true
+    add_finding "warning" "$index_oob" "Index likely out of bounds" "The index comes from parsed/IO input without a len() check, is len()-1 on a possibly empty collection, or is a ..=len() loop counter; use .get()/.get_mut()/.last() and handle None" "${CATEGORY_NAME[21]}" "$(collect_samples_index_oob 3)"
+  else
+    print_finding "good" "No likely out-of-bounds indexing detected"
+  fi
+fi
+
+print_subheader "Off-by-one range bounds"
+if [[ "$have_python3" -eq 1 ]]; then
+  off_by_one_short=$(count_off_by_one_matches short || echo 0)
+  off_by_one_short=$(printf '%s\n' "${off_by_one_short:-0}" | awk 'END{print $0+0}')
+  off_by_one_inclusive=$(count_off_by_one_matches inclusive || echo 0)
+  off_by_one_inclusive=$(printf '%s\n' "${off_by_one_inclusive:-0}" | awk 'END{print $0+0}')
+  off_by_one_loop=$(count_off_by_one_matches inclusive_loop || echo 0)
+  off_by_one_loop=$(printf '%s\n' "${off_by_one_loop:-0}" | awk 'END{print $0+0}')
+  off_by_one_le=$(count_off_by_one_matches le || echo 0)
+  off_by_one_le=$(printf '%s\n' "${off_by_one_le:-0}" | awk 'END{print $0+0}')
+  if [ "$off_by_one_short" -gt 0 ]; then
+    print_finding "warning" "$off_by_one_short" "for i in 0..v.len() - 1 skips the last element" "The loop only indexes v[i], so the final element is never visited, and len() - 1 underflows (panicking in debug) when v is empty; iterate 0..v.len() or v.iter(), or use windows(2) for pairwise walks"
+    show_off_by_one_examples short 3 || true
+    add_finding "warning" "$off_by_one_short" "for i in 0..v.len() - 1 skips the last element" "The loop only indexes v[i], so the final element is never visited, and len() - 1 underflows (panicking in debug) when v is empty; iterate 0..v.len() or v.iter(), or use windows(2) for pairwise walks" "${CATEGORY_NAME[21]}" "$(collect_samples_off_by_one short 3)"
+  fi
+  if [ "$off_by_one_inclusive" -gt 0 ]; then
+    print_finding "warning" "$off_by_one_inclusive" "Slice range ..=v.len() always panics" "An inclusive end of len() asks for one element past the end, so v[a..=v.len()] panics on every call; use the exclusive v[a..v.len()] or simply v[a..]"
+    show_off_by_one_examples inclusive 3 || true
+    add_finding "warning" "$off_by_one_inclusive" "Slice range ..=v.len() always panics" "An inclusive end of len() asks for one element past the end, so v[a..=v.len()] panics on every call; use the exclusive v[a..v.len()] or simply v[a..]" "${CATEGORY_NAME[21]}" "$(collect_samples_off_by_one inclusive 3)"
+  fi
+  if [ "$off_by_one_loop" -gt 0 ]; then
+    print_finding "warning" "$off_by_one_loop" "for i in 0..=v.len() indexes one past the end" "The inclusive range runs its last iteration with i == v.len(), so v[i] panics; iterate 0..v.len() (or v.iter()) unless the final pass is handled separately"
+    show_off_by_one_examples inclusive_loop 3 || true
+    add_finding "warning" "$off_by_one_loop" "for i in 0..=v.len() indexes one past the end" "The inclusive range runs its last iteration with i == v.len(), so v[i] panics; iterate 0..v.len() (or v.iter()) unless the final pass is handled separately" "${CATEGORY_NAME[21]}" "$(collect_samples_off_by_one inclusive_loop 3)"
+  fi
+  if [ "$off_by_one_le" -gt 0 ]; then
+    print_finding "warning" "$off_by_one_le" "while i <= v.len() indexes one past the end" "The last iteration runs with i == v.len(), so v[i] panics; loop while i < v.len() (or iterate directly)"
+    show_off_by_one_examples le 3 || true
+    add_finding "warning" "$off_by_one_le" "while i <= v.len() indexes one past the end" "The last iteration runs with i == v.len(), so v[i] panics; loop while i < v.len() (or iterate directly)" "${CATEGORY_NAME[21]}" "$(collect_samples_off_by_one le 3)"
+  fi
+  if [ "$off_by_one_short" -eq 0 ] && [ "$off_by_one_inclusive" -eq 0 ] && [ "$off_by_one_loop" -eq 0 ] && [ "$off_by_one_le" -eq 0 ]; then
+    print_finding "good" "No off-by-one range bounds detected"
+  fi
+fi
+
+print_subheader "Clock arithmetic that panics on skew"
+if [[ "$have_python3" -eq 1 ]]; then
+  clock_systime=$(count_clock_panic_matches systime || echo 0)
+  clock_systime=$(printf '%s\n' "${clock_systime:-0}" | awk 'END{print $0+0}')
+  clock_instant=$(count_clock_panic_matches instant || echo 0)
+  clock_instant=$(printf '%s\n' "${clock_instant:-0}" | awk 'END{print $0+0}')
+  if [ "$clock_systime" -gt 0 ]; then
+    print_finding "warning" "$clock_systime" "SystemTime duration_since/elapsed unwrapped" "The wall clock can jump backwards (NTP, manual changes, VM restore) and file mtimes can be in the future, so duration_since()/elapsed() return Err and unwrap()/expect() panics; use unwrap_or_default(), handle the Err, or measure intervals with a monotonic Instant"
+    show_clock_panic_examples systime 3 || true
+    add_finding "warning" "$clock_systime" "SystemTime duration_since/elapsed unwrapped" "The wall clock can jump backwards (NTP, manual changes, VM restore) and file mtimes can be in the future, so duration_since()/elapsed() return Err and unwrap()/expect() panics; use unwrap_or_default(), handle the Err, or measure intervals with a monotonic Instant" "${CATEGORY_NAME[21]}" "$(collect_samples_clock_panic systime 3)"
+  fi
+  if [ "$clock_instant" -gt 0 ]; then
+    print_finding "warning" "$clock_instant" "Instant minus Duration can panic" "Instant - Duration panics when the result would precede the platform clock's origin (shortly after boot, or with a large or caller-supplied Duration); use Instant::checked_sub and handle None"
+    show_clock_panic_examples instant 3 || true
+    add_finding "warning" "$clock_instant" "Instant minus Duration can panic" "Instant - Duration panics when the result would precede the platform clock's origin (shortly after boot, or with a large or caller-supplied Duration); use Instant::checked_sub and handle None" "${CATEGORY_NAME[21]}" "$(collect_samples_clock_panic instant 3)"
+  fi
+  if [ "$clock_systime" -eq 0 ] && [ "$clock_instant" -eq 0 ]; then
+    print_finding "good" "Clock arithmetic tolerates skew"
+  fi
+fi
+
 print_subheader "panic!/unwrap/expect inside Drop"
 drop_panic_hits=$(count_drop_panic_matches || echo 0)
 drop_panic_hits=$(printf '%s\n' "${drop_panic_hits:-0}" | awk 'END{print $0+0}')
 if [ "$drop_panic_hits" -gt 0 ]; then
-  print_finding "warning" "$drop_panic_hits" "Potential panics inside Drop implementations" "Panics during Drop + unwinding can abort; avoid unwrap/expect/panic in destructors"
+  print_finding "warning" "$drop_panic_hits" "Potential panics inside Drop implementations" "A panic in drop() while already unwinding aborts the process; avoid unwrap/expect/panic!/indexing (directly or via self methods) in destructors, or guard them with !thread::panicking()"
   show_drop_panic_examples 3 || true
-  add_finding "warning" "$drop_panic_hits" "Potential panics inside Drop implementations" "Panics during Drop + unwinding can abort; avoid unwrap/expect/panic in destructors" "${CATEGORY_NAME[21]}" "$(collect_samples_drop_panic 3)"
+  add_finding "warning" "$drop_panic_hits" "Potential panics inside Drop implementations" "A panic in drop() while already unwinding aborts the process; avoid unwrap/expect/panic!/indexing (directly or via self methods) in destructors, or guard them with !thread::panicking()" "${CATEGORY_NAME[21]}" "$(collect_samples_drop_panic 3)"
+fi
+
+print_subheader "Panics crossing extern \"C\" boundaries"
+if [[ "$have_python3" -eq 1 ]]; then
+  ffi_panic_hits=$(count_ffi_panic_matches body || echo 0)
+  ffi_panic_hits=$(printf '%s\n' "${ffi_panic_hits:-0}" | awk 'END{print $0+0}')
+  ffi_unwind_hits=$(count_ffi_panic_matches unwind || echo 0)
+  ffi_unwind_hits=$(printf '%s\n' "${ffi_unwind_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$ffi_panic_hits" -gt 0 ]; then
+    print_finding "critical" "$ffi_panic_hits" "Panic can escape an extern \"C\" function" "unwrap/expect/assert!/indexing in an extern \"C\" body without catch_unwind aborts the host process (or is UB on older toolchains); return an error code and wrap the body in std::panic::catch_unwind"
+    show_ffi_panic_examples body 3 || true
+    add_finding "critical" "$ffi_panic_hits" "Panic can escape an extern \"C\" function" "unwrap/expect/assert!/indexing in an extern \"C\" body without catch_unwind aborts the host process (or is UB on older toolchains); return an error code and wrap the body in std::panic::catch_unwind" "${CATEGORY_NAME[21]}" "$(collect_samples_ffi_panic body 3)"
+  fi
+  if [ "$ffi_unwind_hits" -gt 0 ]; then
+    print_finding "warning" "$ffi_unwind_hits" "Exported FFI function assumes panic = \"unwind\"" "A \"C-unwind\" export lets Rust panics unwind into foreign frames, and catch_unwind is a no-op when a Cargo profile sets panic = \"abort\"; convert panics to error codes at the boundary"
+    show_ffi_panic_examples unwind 3 || true
+    add_finding "warning" "$ffi_unwind_hits" "Exported FFI function assumes panic = \"unwind\"" "A \"C-unwind\" export lets Rust panics unwind into foreign frames, and catch_unwind is a no-op when a Cargo profile sets panic = \"abort\"; convert panics to error codes at the boundary" "${CATEGORY_NAME[21]}" "$(collect_samples_ffi_panic unwind 3)"
+  fi
+  if [ "$ffi_panic_hits" -eq 0 ] && [ "$ffi_unwind_hits" -eq 0 ]; then
+    print_finding "good" "No panics escaping extern \"C\" functions"
+  fi
+fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Panics inside actix/axum request handlers"
+  handler_unwrap_hits=$(count_handler_panic_matches unwrap || echo 0)
+  handler_unwrap_hits=$(printf '%s\n' "${handler_unwrap_hits:-0}" | awk 'END{print $0+0}')
+  handler_index_hits=$(count_handler_panic_matches index || echo 0)
+  handler_index_hits=$(printf '%s\n' "${handler_index_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$handler_unwrap_hits" -gt 0 ]; then
+    print_finding "warning" "$handler_unwrap_hits" "unwrap/expect inside an HTTP handler" "A panic inside an actix handler kills the worker thread that was serving it (dropping every in-flight request on that worker), and in axum it aborts the connection without a response; map failures to an error response with ?, ok_or/map_err and a ResponseError/IntoResponse type"
+    show_handler_panic_examples unwrap 3 || true
+    add_finding "warning" "$handler_unwrap_hits" "unwrap/expect inside an HTTP handler" "A panic inside an actix handler kills the worker thread that was serving it (dropping every in-flight request on that worker), and in axum it aborts the connection without a response; map failures to an error response with ?, ok_or/map_err and a ResponseError/IntoResponse type" "${CATEGORY_NAME[21]}" "$(collect_samples_handler_panic unwrap 3)"
+  fi
+  if [ "$handler_index_hits" -gt 0 ]; then
+    print_finding "warning" "$handler_index_hits" "Direct indexing inside an HTTP handler" "Indexing request-derived collections panics on short or empty input; use .get(i)/.first() and return 400/404 when the element is missing"
+    show_handler_panic_examples index 3 || true
+    add_finding "warning" "$handler_index_hits" "Direct indexing inside an HTTP handler" "Indexing request-derived collections panics on short or empty input; use .get(i)/.first() and return 400/404 when the element is missing" "${CATEGORY_NAME[21]}" "$(collect_samples_handler_panic index 3)"
+  fi
+  if [ "$handler_unwrap_hits" -eq 0 ] && [ "$handler_index_hits" -eq 0 ]; then
+    print_finding "good" "No panic surfaces in actix/axum handlers"
+  fi
 fi
 fi
 
@@ -8757,7 +23981,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 22; then
 print_header "22. SUSPICIOUS CASTS & TRUNCATION"
-print_category "Detects: pervasive \`as\` casts, try_into().unwrap, numeric narrowing patterns" \
+print_category "Detects: pervasive \`as\` casts, try_into/try_from/NonZero::new unwraps, numeric narrowing patterns" \
   "\`as\` casts can silently truncate or change sign; conversion panics may be missed in uncommon input paths"
 
 print_subheader "\`as\` cast inventory"
@@ -8787,6 +24011,27 @@ else
   print_finding "good" "No obvious \`as\` casts detected"
 fi
 
+print_subheader "Type-aware truncating / sign-changing \`as\` casts"
+if [[ "$have_python3" -eq 1 ]]; then
+  cast_truncate=$(count_lossy_cast_matches truncate || echo 0)
+  cast_truncate=$(printf '%s\n' "${cast_truncate:-0}" | awk 'END{print $0+0}')
+  cast_sign=$(count_lossy_cast_matches sign || echo 0)
+  cast_sign=$(printf '%s\n' "${cast_sign:-0}" | awk 'END{print $0+0}')
+  if [ "$cast_truncate" -gt 0 ]; then
+    print_finding "warning" "$cast_truncate" "Truncating \`as\` cast to a narrower integer" "High bits are silently dropped (e.g. u64 -> u32, usize -> u16); use u32::try_from(x) / x.try_into() and handle the error"
+    show_lossy_cast_examples truncate 3 || true
+    add_finding "warning" "$cast_truncate" "Truncating \`as\` cast to a narrower integer" "High bits are silently dropped (e.g. u64 -> u32, usize -> u16); use u32::try_from(x) / x.try_into() and handle the error" "${CATEGORY_NAME[22]}" "$(collect_samples_lossy_cast truncate 3)"
+  fi
+  if [ "$cast_sign" -gt 0 ]; then
+    print_finding "warning" "$cast_sign" "Sign-changing \`as\` cast" "Negative values wrap to huge unsigned numbers (i64 -> u64) and large unsigned values turn negative (u64 -> i64); use try_from()/try_into() or unsigned_abs() where intended"
+    show_lossy_cast_examples sign 3 || true
+    add_finding "warning" "$cast_sign" "Sign-changing \`as\` cast" "Negative values wrap to huge unsigned numbers (i64 -> u64) and large unsigned values turn negative (u64 -> i64); use try_from()/try_into() or unsigned_abs() where intended" "${CATEGORY_NAME[22]}" "$(collect_samples_lossy_cast sign 3)"
+  fi
+  if [ "$cast_truncate" -eq 0 ] && [ "$cast_sign" -eq 0 ]; then
+    print_finding "good" "No truncating or sign-changing casts on typed values"
+  fi
+fi
+
 print_subheader "len()/count() narrowed via \`as\`"
 # shellcheck disable=SC2016
 len_count_narrow_patterns=(
@@ -8812,7 +24057,18 @@ if [ "$len_count_narrow" -gt 0 ]; then
   add_finding "warning" "$len_count_narrow" "Length/count narrowed with \`as\` cast" "Use TryFrom/TryInto or explicit checked bounds before storing sizes in narrow integer fields" "${CATEGORY_NAME[22]}" "$(collect_samples_ast_or_rg "$len_count_narrow_rg" 3 "${len_count_narrow_patterns[@]}")"
 fi
 
-print_subheader "try_into().unwrap()/expect() (panic on conversion failure)"
+print_subheader "Fallible conversions unwrapped (try_into, try_from, NonZero::new)"
+if [[ "$have_python3" -eq 1 ]]; then
+  fallible_conversion=$(count_fallible_conversion_matches || echo 0)
+  fallible_conversion=$(printf '%s\n' "${fallible_conversion:-0}" | awk 'END{print $0+0}')
+  if [ "$fallible_conversion" -gt 0 ]; then
+    print_finding "warning" "$fallible_conversion" "Fallible conversion unwrapped on a non-literal value" "x.try_into().unwrap(), u32::try_from(len).unwrap() and NonZeroUsize::new(n).unwrap() panic as soon as a real input does not fit (a >4 GiB payload, a short slice, a zero worker count); propagate the error with ?, map it into your error type, or clamp/validate first"
+    show_fallible_conversion_examples 3 || true
+    add_finding "warning" "$fallible_conversion" "Fallible conversion unwrapped on a non-literal value" "x.try_into().unwrap(), u32::try_from(len).unwrap() and NonZeroUsize::new(n).unwrap() panic as soon as a real input does not fit (a >4 GiB payload, a short slice, a zero worker count); propagate the error with ?, map it into your error type, or clamp/validate first" "${CATEGORY_NAME[22]}" "$(collect_samples_fallible_conversion 3)"
+  else
+    print_finding "good" "No fallible conversions unwrapped on runtime values"
+  fi
+else
 # shellcheck disable=SC2016
 try_into_patterns=('$X.try_into().unwrap()' '$X.try_into().expect($MSG)')
 try_into_unwrap=$(count_ast_or_rg "\.try_into\(\)\.(unwrap|expect)\(" "${try_into_patterns[@]}")
@@ -8822,13 +24078,14 @@ if [ "$try_into_unwrap" -gt 0 ]; then
   add_finding "warning" "$try_into_unwrap" "try_into().unwrap()/expect() present" "Handle conversion errors explicitly; panics can be input-dependent" "${CATEGORY_NAME[22]}" "$(collect_samples_ast_or_rg "\.try_into\(\)\.(unwrap|expect)\(" 3 "${try_into_patterns[@]}")"
 fi
 fi
+fi
 
 # ═══════════════════════════════════════════════════════════════════════════
 # CATEGORY 23: PARSING & VALIDATION ROBUSTNESS
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 23; then
 print_header "23. PARSING & VALIDATION ROBUSTNESS"
-print_category "Detects: parse/from_str/env-var unwraps, decode unwraps, missing error context" \
+print_category "Detects: parse/from_str/env-var unwraps, serde unwraps on external payloads, decode unwraps, missing error context" \
   "Parsing and decoding failures often happen in prod on edge inputs; unwrap/expect turns them into panics"
 
 print_subheader "parse::<T>().unwrap()/expect()"
@@ -8841,6 +24098,30 @@ if [ "$parse_unwrap" -gt 0 ]; then
   add_finding "warning" "$parse_unwrap" "parse::<T>().unwrap()/expect() present" "Validate input or propagate errors with context" "${CATEGORY_NAME[23]}" "$(collect_samples_ast_or_rg "\.parse(<[^>]+>)?\(\)\.(unwrap|expect)\(" 3 "${parse_patterns[@]}")"
 fi
 
+print_subheader "parse()/from_str() unwrap on args, stdin, file, or HTTP input"
+if [[ "$have_python3" -eq 1 ]]; then
+  external_parse=$(count_external_parse_matches parse || echo 0)
+  external_parse=$(printf '%s\n' "${external_parse:-0}" | awk 'END{print $0+0}')
+  if [ "$external_parse" -gt 0 ]; then
+    print_finding "warning" "$external_parse" "parse()/from_str() unwrapped on external input" "The string comes from env::args, stdin, a file read, or an HTTP body, so malformed input panics; propagate with ? and attach context (map_err or anyhow's with_context) naming the offending value"
+    show_external_parse_examples parse 3 || true
+    add_finding "warning" "$external_parse" "parse()/from_str() unwrapped on external input" "The string comes from env::args, stdin, a file read, or an HTTP body, so malformed input panics; propagate with ? and attach context (map_err or anyhow's with_context) naming the offending value" "${CATEGORY_NAME[23]}" "$(collect_samples_external_parse parse 3)"
+  else
+    print_finding "good" "No parse()/from_str() unwraps on external input"
+  fi
+
+  print_subheader "serde deserialization unwrapped on HTTP, file, or env input"
+  external_serde=$(count_external_parse_matches serde || echo 0)
+  external_serde=$(printf '%s\n' "${external_serde:-0}" | awk 'END{print $0+0}')
+  if [ "$external_serde" -gt 0 ]; then
+    print_finding "warning" "$external_serde" "serde deserialization of external input unwrapped" "serde_json::from_str/from_slice/from_reader (or toml/yaml/bincode) on a request body, uploaded file, or env value panics on the first malformed payload, letting any client crash the worker; return a 400/config error with ? or map_err instead"
+    show_external_parse_examples serde 3 || true
+    add_finding "warning" "$external_serde" "serde deserialization of external input unwrapped" "serde_json::from_str/from_slice/from_reader (or toml/yaml/bincode) on a request body, uploaded file, or env value panics on the first malformed payload, letting any client crash the worker; return a 400/config error with ? or map_err instead" "${CATEGORY_NAME[23]}" "$(collect_samples_external_parse serde 3)"
+  else
+    print_finding "good" "No serde deserialization unwraps on external input"
+  fi
+fi
+
 print_subheader "serde/toml deserialization unwrap()/expect()"
 # shellcheck disable=SC2016
 serde_patterns=(
@@ -8876,11 +24157,24 @@ env_patterns=(
   'env::var_os($K).expect($MSG)'
 )
 env_pattern_rg="(std::)?env::var(_os)?\([^)]*\)\.(unwrap|expect)\("
-env_unwrap=$(count_ast_or_rg "$env_pattern_rg" "${env_patterns[@]}")
-if [ "$env_unwrap" -gt 0 ]; then
-  print_finding "warning" "$env_unwrap" "env::var(...).unwrap()/expect()" "Handle missing/invalid env vars with defaults or clear error propagation"
-  show_ast_pattern_examples 3 "${env_patterns[@]}" || show_detailed_finding "$env_pattern_rg" 3
-  add_finding "warning" "$env_unwrap" "env::var(...).unwrap()/expect()" "Handle missing/invalid env vars with defaults or clear error propagation" "${CATEGORY_NAME[23]}" "$(collect_samples_ast_or_rg "$env_pattern_rg" 3 "${env_patterns[@]}")"
+if [[ "$have_python3" -eq 1 ]]; then
+  # Non-test code only; the finding names the variables that would panic.
+  env_unwrap=$(count_env_unwrap_matches hits || echo 0)
+  env_unwrap=$(printf '%s\n' "${env_unwrap:-0}" | awk 'END{print $0+0}')
+  if [ "$env_unwrap" -gt 0 ]; then
+    env_names=$(rust_env_unwrap_matches names 2>/dev/null | head -n 8 | paste -sd, - | sed 's/,/, /g')
+    env_desc="Panics when these variables are unset or not UTF-8: ${env_names:-unknown}; fall back with unwrap_or_else(|_| default), match on the Option/Result, or return a config-loading error"
+    print_finding "warning" "$env_unwrap" "env::var(...).unwrap()/expect()" "$env_desc"
+    show_env_unwrap_examples hits 3 || true
+    add_finding "warning" "$env_unwrap" "env::var(...).unwrap()/expect()" "$env_desc" "${CATEGORY_NAME[23]}" "$(collect_samples_env_unwrap hits 3)"
+  fi
+else
+  env_unwrap=$(count_ast_or_rg "$env_pattern_rg" "${env_patterns[@]}")
+  if [ "$env_unwrap" -gt 0 ]; then
+    print_finding "warning" "$env_unwrap" "env::var(...).unwrap()/expect()" "Handle missing/invalid env vars with defaults or clear error propagation"
+    show_ast_pattern_examples 3 "${env_patterns[@]}" || show_detailed_finding "$env_pattern_rg" 3
+    add_finding "warning" "$env_unwrap" "env::var(...).unwrap()/expect()" "Handle missing/invalid env vars with defaults or clear error propagation" "${CATEGORY_NAME[23]}" "$(collect_samples_ast_or_rg "$env_pattern_rg" 3 "${env_patterns[@]}")"
+  fi
 fi
 fi
 
@@ -8889,7 +24183,7 @@ fi
 # ═══════════════════════════════════════════════════════════════════════════
 if category_enabled 24; then
 print_header "24. PERF/DoS HOTSPOTS"
-print_category "Detects: regex compilation in loops, chars().nth(n), format!/allocations in loops" \
+print_category "Detects: regex/client construction in loops, chars().nth(n) and quadratic nth(i) loops, format!/allocations in loops, eager unwrap_or/ok_or defaults, loops without exits, unbounded recursion, per-call tokio runtimes, allocations sized by untrusted input" \
   "Some perf pitfalls become DoS risks on large inputs or hot paths; these often evade linting in non-bench builds"
 
 print_subheader "Regex::new occurrences and in-loop compilation"
@@ -8902,9 +24196,9 @@ else
   regex_in_loop=$("${GREP_RN[@]}" -e "(for|while|loop)[^{]*\{[^}]*((regex::)?Regex::new)\(" "$PROJECT_DIR" 2>/dev/null | count_lines || true)
 fi
 if [ "$regex_in_loop" -gt 0 ]; then
-  print_finding "warning" "$regex_in_loop" "Regex::new compiled inside loop" "Precompile regex once (lazy_static/once_cell) to avoid repeated compilation"
+  print_finding "warning" "$regex_in_loop" "Regex::new compiled inside loop" "Precompile regex once (once_cell::sync::Lazy / std::sync::LazyLock, or hoist it out of the loop or iterator closure) to avoid repeated compilation"
   show_loop_context_examples "regex_new" 3 || show_detailed_finding "(for|while|loop)[^{]*\{[^}]*((regex::)?Regex::new)\(" 3
-  add_finding "warning" "$regex_in_loop" "Regex::new compiled inside loop" "Precompile regex once (lazy_static/once_cell) to avoid repeated compilation" "${CATEGORY_NAME[24]}" "$(collect_samples_loop_context "regex_new" 3)"
+  add_finding "warning" "$regex_in_loop" "Regex::new compiled inside loop" "Precompile regex once (once_cell::sync::Lazy / std::sync::LazyLock, or hoist it out of the loop or iterator closure) to avoid repeated compilation" "${CATEGORY_NAME[24]}" "$(collect_samples_loop_context "regex_new" 3)"
 elif [ "$regex_new" -gt 0 ]; then
   print_finding "info" "$regex_new" "Regex::new present" "Ensure regex is not compiled per request or per iteration"
   show_ast_pattern_examples 3 "${regex_new_patterns[@]}" || show_detailed_finding "(regex::)?Regex::new\(" 3
@@ -8913,6 +24207,19 @@ else
   print_finding "good" "No regex::Regex::new detected"
 fi
 
+print_subheader "HTTP clients and other compile-once constructors inside loops"
+if [[ "$have_python3" -eq 1 ]]; then
+  ctor_in_loop=$(count_loop_context_matches "expensive_ctor")
+  ctor_in_loop=$(printf '%s\n' "${ctor_in_loop:-0}" | awk 'END{print $0+0}')
+  if [ "$ctor_in_loop" -gt 0 ]; then
+    print_finding "warning" "$ctor_in_loop" "Expensive constructor called inside loop or iterator closure" "reqwest::Client, RegexSet/RegexBuilder, AhoCorasick, and TLS configs own pools or compiled automata; build them once outside the loop, or keep them in a once_cell::sync::Lazy / std::sync::LazyLock static"
+    show_loop_context_examples "expensive_ctor" 3 || true
+    add_finding "warning" "$ctor_in_loop" "Expensive constructor called inside loop or iterator closure" "reqwest::Client, RegexSet/RegexBuilder, AhoCorasick, and TLS configs own pools or compiled automata; build them once outside the loop, or keep them in a once_cell::sync::Lazy / std::sync::LazyLock static" "${CATEGORY_NAME[24]}" "$(collect_samples_loop_context "expensive_ctor" 3)"
+  else
+    print_finding "good" "No clients or compile-once constructors built inside loops"
+  fi
+fi
+
 print_subheader "chars().nth(n)/nth_back(n) (O(n))"
 # shellcheck disable=SC2016
 chars_nth_patterns=('$S.chars().nth($N)' '$S.chars().nth_back($N)')
@@ -8922,6 +24229,14 @@ if [ "$chars_nth" -gt 0 ]; then
   show_ast_pattern_examples 3 "${chars_nth_patterns[@]}" || show_detailed_finding "\.chars\(\)\.nth(_back)?\(" 3
   add_finding "info" "$chars_nth" "chars().nth(n)/nth_back(n) used" "O(n) indexing; prefer byte indexing where valid or iterators with caching" "${CATEGORY_NAME[24]}" "$(collect_samples_ast_or_rg "\.chars\(\)\.nth(_back)?\(" 3 "${chars_nth_patterns[@]}")"
 fi
+if [[ "$have_python3" -eq 1 ]]; then
+  chars_nth_loop=$(count_loop_context_matches "chars_nth")
+  if [ "$chars_nth_loop" -gt 0 ]; then
+    print_finding "warning" "$chars_nth_loop" "chars().nth(i) indexed by a loop variable" "Each chars().nth(i) walks the string from the start, so indexing by the loop counter is O(n^2); iterate once with chars()/char_indices() (zip, rev, step_by), collect into a Vec<char> first, or use as_bytes()[i] when the input is known ASCII"
+    show_loop_context_examples "chars_nth" 3 || true
+    add_finding "warning" "$chars_nth_loop" "chars().nth(i) indexed by a loop variable" "Each chars().nth(i) walks the string from the start, so indexing by the loop counter is O(n^2); iterate once with chars()/char_indices() (zip, rev, step_by), collect into a Vec<char> first, or use as_bytes()[i] when the input is known ASCII" "${CATEGORY_NAME[24]}" "$(collect_samples_loop_context "chars_nth" 3)"
+  fi
+fi
 
 print_subheader "format!/to_string/allocations inside loops (heuristic)"
 alloc_loop_rg="(for|while|loop)[^{]*\{[^}]*(format!|\.to_string\(\)|\.to_owned\(\)|String::from\()"
@@ -8935,6 +24250,94 @@ if [ "$fmt_in_loop" -gt 0 ]; then
   show_loop_context_examples "string_alloc" 3 || show_detailed_finding "$alloc_loop_rg" 3
   add_finding "warning" "$fmt_in_loop" "String allocation inside loop" "Consider preallocating buffers, using write!, or restructuring to reduce allocations" "${CATEGORY_NAME[24]}" "$(collect_samples_loop_context "string_alloc" 3)"
 fi
+
+if [[ "$have_python3" -eq 1 ]]; then
+  print_subheader "Eagerly evaluated unwrap_or/ok_or/map_or defaults"
+  eager_default_hits=$(count_eager_default_matches || echo 0)
+  eager_default_hits=$(printf '%s\n' "${eager_default_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$eager_default_hits" -gt 0 ]; then
+    print_finding "warning" "$eager_default_hits" "Fallback built eagerly even when unused" "unwrap_or(expensive()), ok_or(build_error()), and map_or(format!(..), f) run the call (and its side effects) on the happy path too; switch to unwrap_or_else/ok_or_else/map_or_else with a closure"
+    show_eager_default_examples 3 || true
+    add_finding "warning" "$eager_default_hits" "Fallback built eagerly even when unused" "unwrap_or(expensive()), ok_or(build_error()), and map_or(format!(..), f) run the call (and its side effects) on the happy path too; switch to unwrap_or_else/ok_or_else/map_or_else with a closure" "${CATEGORY_NAME[24]}" "$(collect_samples_eager_default 3)"
+  else
+    print_finding "good" "No eagerly evaluated fallbacks"
+  fi
+
+  print_subheader "loop {} without an exit path"
+  infinite_loop_hits=$(count_infinite_loop_matches default || echo 0)
+  infinite_loop_hits=$(printf '%s\n' "${infinite_loop_hits:-0}" | awk 'END{print $0+0}')
+  worker_loop_hits=$(count_infinite_loop_matches worker || echo 0)
+  worker_loop_hits=$(printf '%s\n' "${worker_loop_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$infinite_loop_hits" -gt 0 ]; then
+    print_finding "warning" "$infinite_loop_hits" "Infinite loop with no way out" "A loop with no break, return, ?, .await or exit path pins its thread forever and makes shutdown impossible; add an exit condition (channel disconnect, cancellation flag) or declare the function -> ! if that is the intent"
+    show_infinite_loop_examples default 3 || true
+    add_finding "warning" "$infinite_loop_hits" "Infinite loop with no way out" "A loop with no break, return, ?, .await or exit path pins its thread forever and makes shutdown impossible; add an exit condition (channel disconnect, cancellation flag) or declare the function -> ! if that is the intent" "${CATEGORY_NAME[24]}" "$(collect_samples_infinite_loop default 3)"
+  fi
+  if [ "$worker_loop_hits" -gt 0 ]; then
+    print_finding "info" "$worker_loop_hits" "Worker loop never terminates" "Long-lived worker loops are often intentional, but still need a shutdown path (closed channel, stop flag) so the thread can be joined"
+    show_infinite_loop_examples worker 3 || true
+    add_finding "info" "$worker_loop_hits" "Worker loop never terminates" "Long-lived worker loops are often intentional, but still need a shutdown path (closed channel, stop flag) so the thread can be joined" "${CATEGORY_NAME[24]}" "$(collect_samples_infinite_loop worker 3)"
+  fi
+  if [ "$infinite_loop_hits" -eq 0 ] && [ "$worker_loop_hits" -eq 0 ]; then
+    print_finding "good" "Every loop {} has an exit path"
+  fi
+
+  print_subheader "Unbounded recursion"
+  recursion_nobase_hits=$(count_unbounded_recursion_matches nobase || echo 0)
+  recursion_nobase_hits=$(printf '%s\n' "${recursion_nobase_hits:-0}" | awk 'END{print $0+0}')
+  recursion_depth_hits=$(count_unbounded_recursion_matches depth || echo 0)
+  recursion_depth_hits=$(printf '%s\n' "${recursion_depth_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$recursion_nobase_hits" -gt 0 ]; then
+    print_finding "critical" "$recursion_nobase_hits" "Recursive function has no base case" "A function that calls itself on every path (often a Display/Deref impl delegating to itself instead of the inner value) overflows the stack on first use; add the missing base case or call the intended inner method"
+    show_unbounded_recursion_examples nobase 3 || true
+    add_finding "critical" "$recursion_nobase_hits" "Recursive function has no base case" "A function that calls itself on every path (often a Display/Deref impl delegating to itself instead of the inner value) overflows the stack on first use; add the missing base case or call the intended inner method" "${CATEGORY_NAME[24]}" "$(collect_samples_unbounded_recursion nobase 3)"
+  fi
+  if [ "$recursion_depth_hits" -gt 0 ]; then
+    print_finding "warning" "$recursion_depth_hits" "Recursion depth controlled by input" "Recursion driven by attacker-controlled nesting or length (JSON/AST trees, slices peeled one element at a time) overflows the stack on deep input; thread a depth limit through the call or rewrite it with an explicit stack/iterator"
+    show_unbounded_recursion_examples depth 3 || true
+    add_finding "warning" "$recursion_depth_hits" "Recursion depth controlled by input" "Recursion driven by attacker-controlled nesting or length (JSON/AST trees, slices peeled one element at a time) overflows the stack on deep input; thread a depth limit through the call or rewrite it with an explicit stack/iterator" "${CATEGORY_NAME[24]}" "$(collect_samples_unbounded_recursion depth 3)"
+  fi
+  if [ "$recursion_nobase_hits" -eq 0 ] && [ "$recursion_depth_hits" -eq 0 ]; then
+    print_finding "good" "No unbounded recursion detected"
+  fi
+
+  print_subheader "tokio Runtime construction in hot paths"
+  runtime_loop_hits=$(count_runtime_ctor_matches loop || echo 0)
+  runtime_loop_hits=$(printf '%s\n' "${runtime_loop_hits:-0}" | awk 'END{print $0+0}')
+  runtime_handler_hits=$(count_runtime_ctor_matches handler || echo 0)
+  runtime_handler_hits=$(printf '%s\n' "${runtime_handler_hits:-0}" | awk 'END{print $0+0}')
+  runtime_library_hits=$(count_runtime_ctor_matches library || echo 0)
+  runtime_library_hits=$(printf '%s\n' "${runtime_library_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$runtime_loop_hits" -gt 0 ]; then
+    print_finding "warning" "$runtime_loop_hits" "Runtime built inside a loop" "Each tokio Runtime spins up a thread pool, I/O driver and timer; building one per iteration or per request costs milliseconds and threads every time. Create it once (#[tokio::main], a OnceLock/LazyLock static, or a field on a long-lived client) and reuse it"
+    show_runtime_ctor_examples loop 3 || true
+    add_finding "warning" "$runtime_loop_hits" "Runtime built inside a loop" "Each tokio Runtime spins up a thread pool, I/O driver and timer; building one per iteration or per request costs milliseconds and threads every time. Create it once (#[tokio::main], a OnceLock/LazyLock static, or a field on a long-lived client) and reuse it" "${CATEGORY_NAME[24]}" "$(collect_samples_runtime_ctor loop 3)"
+  fi
+  if [ "$runtime_handler_hits" -gt 0 ]; then
+    print_finding "warning" "$runtime_handler_hits" "Runtime built per request in a handler" "Each tokio Runtime spins up a thread pool, I/O driver and timer; building one per iteration or per request costs milliseconds and threads every time. Create it once (#[tokio::main], a OnceLock/LazyLock static, or a field on a long-lived client) and reuse it"
+    show_runtime_ctor_examples handler 3 || true
+    add_finding "warning" "$runtime_handler_hits" "Runtime built per request in a handler" "Each tokio Runtime spins up a thread pool, I/O driver and timer; building one per iteration or per request costs milliseconds and threads every time. Create it once (#[tokio::main], a OnceLock/LazyLock static, or a field on a long-lived client) and reuse it" "${CATEGORY_NAME[24]}" "$(collect_samples_runtime_ctor handler 3)"
+  fi
+  if [ "$runtime_library_hits" -gt 0 ]; then
+    print_finding "info" "$runtime_library_hits" "Library function creates its own tokio Runtime" "Libraries that build a private Runtime panic when called from async code and fight the application's own runtime; accept a Handle, expose an async API, or keep one shared runtime"
+    show_runtime_ctor_examples library 3 || true
+    add_finding "info" "$runtime_library_hits" "Library function creates its own tokio Runtime" "Libraries that build a private Runtime panic when called from async code and fight the application's own runtime; accept a Handle, expose an async API, or keep one shared runtime" "${CATEGORY_NAME[24]}" "$(collect_samples_runtime_ctor library 3)"
+  fi
+  if [ "$((runtime_loop_hits + runtime_handler_hits + runtime_library_hits))" -eq 0 ]; then
+    print_finding "good" "No tokio Runtime construction on hot paths"
+  fi
+
+  print_subheader "Allocation sized by untrusted input"
+  untrusted_alloc_hits=$(count_untrusted_alloc_matches || echo 0)
+  untrusted_alloc_hits=$(printf '%s\n' "${untrusted_alloc_hits:-0}" | awk 'END{print $0+0}')
+  if [ "$untrusted_alloc_hits" -gt 0 ]; then
+    print_finding "warning" "$untrusted_alloc_hits" "Allocation size taken from input without an upper bound" "A length parsed from arguments or read off the wire reaches Vec::with_capacity/vec![0; n]/reserve unchecked, so a single request can ask for gigabytes and abort the process; compare it against a maximum first, clamp it with .min(MAX), or read through .take(MAX)"
+    show_untrusted_alloc_examples 3 || true
+    add_finding "warning" "$untrusted_alloc_hits" "Allocation size taken from input without an upper bound" "A length parsed from arguments or read off the wire reaches Vec::with_capacity/vec![0; n]/reserve unchecked, so a single request can ask for gigabytes and abort the process; compare it against a maximum first, clamp it with .min(MAX), or read through .take(MAX)" "${CATEGORY_NAME[24]}" "$(collect_samples_untrusted_alloc 3)"
+  else
+    print_finding "good" "No allocations sized by unchecked input"
+  fi
+fi
 fi
 
 # restore pipefail
@@ -0,0 +1,1081 @@
+#!/usr/bin/env bash
+# ═══════════════════════════════════════════════════════════════════════════
+# SHELL ULTIMATE BUG SCANNER v1.0.0 (Bash) - Industrial-Grade Code Analysis
+# ═══════════════════════════════════════════════════════════════════════════
+# Static analysis for the shell scripts every repository carries (CI steps,
+# release/install scripts, git hooks) using a quote-aware python3 lexer.
+# Scans *.sh/*.bash plus extensionless files with a sh/bash shebang.
+#
+# Focus:
+#   • strict mode (set -euo pipefail)   • unquoted expansions / word splitting
+#   • rm -rf on unguarded variables     • eval / sh -c command injection
+#
+# Supports:
+#   --format text|json|sarif (json/sarif => pure machine output)
+#   --fail-on-warning, --skip, --only, --jobs, --include-ext, --exclude
+#   --ci, --no-color, --summary-json
+#   CI-friendly timestamps, robust find, safe pipelines, auto parallel jobs
+# ═══════════════════════════════════════════════════════════════════════════
+
+if [ "${BASH_VERSINFO[0]:-0}" -lt 4 ]; then
+  echo "ERROR: ubs-shell.sh requires bash >= 4.0 (you have ${BASH_VERSION:-unknown})." >&2
+  echo "       On macOS: 'brew install bash' and re-run via /opt/homebrew/bin/bash." >&2
+  exit 2
+fi
+
+set -Eeuo pipefail
+umask 022
+shopt -s lastpipe
+shopt -s extglob
+
+SCRIPT_DIR="$(cd -- "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+
+# ────────────────────────────────────────────────────────────────────────────
+# Globals & defaults
+# ────────────────────────────────────────────────────────────────────────────
+
+VERBOSE=0
+PROJECT_DIR="."
+OUTPUT_FILE=""
+FORMAT="text"          # text|json|sarif
+CI_MODE=0
+FAIL_ON_WARNING=0
+INCLUDE_EXT="sh,bash"
+QUIET=0
+NO_COLOR_FLAG=0
+EXTRA_EXCLUDES=""
+SKIP_CATEGORIES=""
+ONLY_CATEGORIES=""
+DETAIL_LIMIT=3
+MAX_DETAILED=250
+JOBS="${JOBS:-0}"
+DISABLE_PIPEFAIL_DURING_SCAN=1
+
+SUMMARY_JSON=""
+SARIF_OUT=""
+JSON_OUT=""
+
+CHECK="✓"; CROSS="✗"; WARN="⚠"; INFO="ℹ"; ARROW="→"; BULLET="•"; MAGNIFY="🔍"; BUG="🐛"; FIRE="🔥"; SPARKLE="✨"; SHIELD="🛡"; POTION="🧪"
+
+# Color handling
+USE_COLOR=1
+if [[ -n "${NO_COLOR:-}" || ! -t 1 ]]; then USE_COLOR=0; fi
+if [[ "$USE_COLOR" -eq 1 ]]; then
+  RED='\033[0;31m'; GREEN='\033[0;32m'; YELLOW='\033[1;33m'; BLUE='\033[0;34m'
+  MAGENTA='\033[0;35m'; CYAN='\033[0;36m'; WHITE='\033[1;37m'; GRAY='\033[0;90m'
+  BOLD='\033[1m'; DIM='\033[2m'; RESET='\033[0m'
+else
+  : "${RED:=}" "${GREEN:=}" "${YELLOW:=}" "${BLUE:=}" "${MAGENTA:=}" "${CYAN:=}" "${WHITE:=}" "${GRAY:=}" "${BOLD:=}" "${DIM:=}" "${RESET:=}"
+  RED=''; GREEN=''; YELLOW=''; BLUE=''; MAGENTA=''; CYAN=''; WHITE=''; GRAY=''
+  BOLD=''; DIM=''; RESET=''
+fi
+
+# ────────────────────────────────────────────────────────────────────────────
+# Error handling
+# ────────────────────────────────────────────────────────────────────────────
+
+on_err() {
+  local ec=$?; local cmd=${BASH_COMMAND}; local line=${BASH_LINENO[0]}; local src=${BASH_SOURCE[1]:-${BASH_SOURCE[0]}}
+  if [[ "${FORMAT:-text}" == "json" || "${FORMAT:-text}" == "sarif" ]]; then
+    echo "{\"error\":{\"exit\":$ec,\"file\":\"$src\",\"line\":$line,\"cmd\":\"${cmd//\"/\\\"}\"}}" >&2; exit "$ec"
+  fi
+  echo -e "\n${RED}${BOLD}Unexpected error (exit $ec)${RESET} ${DIM}at ${src}:${line}${RESET}\n${DIM}Last command:${RESET} ${WHITE}$cmd${RESET}" >&2
+  exit "$ec"
+}
+trap on_err ERR
+
+print_usage() {
+  cat >&2 <<USAGE
+Usage: $(basename "$0") [options] [PROJECT_DIR] [OUTPUT_FILE]
+
+Options:
+  -v, --verbose            More code samples per finding (DETAIL=10)
+  --very-verbose           Max code samples (DETAIL=25)
+  -q, --quiet              Reduce non-essential output
+  --format=FMT             Output format: text|json|sarif (default: text)
+  --json-out=FILE          Save full JSON report to file (text still prints)
+  --sarif-out=FILE         Save SARIF to file (text still prints)
+  --summary-json=FILE      Save brief summary counters JSON
+  --ci                     CI mode (no clear, stable timestamps)
+  --no-color               Force disable ANSI color
+  --include-ext=CSV        File extensions (default: $INCLUDE_EXT)
+  --exclude=GLOB[,..]      Additional glob(s)/dir(s) to exclude
+  --only=CSV               Only run these category numbers/names
+  --jobs=N                 Parallel jobs for ripgrep (default: auto)
+  --skip=CSV               Skip categories by number (e.g. --skip=2,7,11)
+  --fail-on-warning        Exit non-zero on warnings or critical
+  -h, --help               Show help
+Env:
+  JOBS, NO_COLOR, CI, UBS_METRICS_DIR
+Args:
+  PROJECT_DIR              Directory to scan (default: ".")
+  OUTPUT_FILE              File to save the report (optional)
+USAGE
+}
+
+# CLI parsing
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    -v|--verbose) VERBOSE=1; DETAIL_LIMIT=10; shift;;
+    --very-verbose) VERBOSE=2; DETAIL_LIMIT=25; shift;;
+    -q|--quiet)   VERBOSE=0; DETAIL_LIMIT=1; QUIET=1; shift;;
+    --format=*)   FORMAT="${1#*=}"; shift;;
+    --json-out=*) JSON_OUT="${1#*=}"; shift;;
+    --sarif-out=*) SARIF_OUT="${1#*=}"; shift;;
+    --summary-json=*) SUMMARY_JSON="${1#*=}"; shift;;
+    --ci)         CI_MODE=1; shift;;
+    --no-color)   NO_COLOR_FLAG=1; shift;;
+    --include-ext=*) INCLUDE_EXT="${1#*=}"; shift;;
+    --exclude=*)  EXTRA_EXCLUDES="${1#*=}"; shift;;
+    --only=*)     ONLY_CATEGORIES="${1#*=}"; shift;;
+    --jobs=*)     JOBS="${1#*=}"; shift;;
+    --skip=*)     SKIP_CATEGORIES="${1#*=}"; shift;;
+    --fail-on-warning) FAIL_ON_WARNING=1; shift;;
+    -h|--help)    print_usage; exit 0;;
+    *)
+      if [[ -z "$PROJECT_DIR" || "$PROJECT_DIR" == "." ]] && ! [[ "$1" =~ ^- ]]; then
+        PROJECT_DIR="$1"; shift
+      elif [[ -z "$OUTPUT_FILE" ]] && ! [[ "$1" =~ ^- ]]; then
+        if [[ -e "$1" && -s "$1" ]]; then
+          echo "error: refusing to use existing non-empty file '$1' as OUTPUT_FILE (would be overwritten)." >&2
+          echo "       To scan multiple paths, use the meta-runner 'ubs'. To save a report, pass a fresh (non-existing) path." >&2
+          exit 2
+        fi
+        OUTPUT_FILE="$1"; shift
+      else
+        echo "Unexpected argument: $1" >&2; exit 2
+      fi
+      ;;
+  esac
+done
+
+# CI auto-detect + color override
+if [[ -n "${CI:-}" ]]; then CI_MODE=1; fi
+if [[ "$NO_COLOR_FLAG" -eq 1 ]]; then USE_COLOR=0; fi
+
+# Redirect output early to capture everything (honors machine formats too)
+if [[ -n "${OUTPUT_FILE}" ]]; then
+  if command -v tee >/dev/null 2>&1; then
+    exec > >(tee "${OUTPUT_FILE}") 2>&1
+  else
+    exec > "${OUTPUT_FILE}" 2>&1
+  fi
+fi
+
+DATE_FMT='%Y-%m-%d %H:%M:%S'
+safe_date() {
+  if [[ "$CI_MODE" -eq 1 ]]; then
+    command date -u '+%Y-%m-%dT%H:%M:%SZ' 2>/dev/null || command date '+%Y-%m-%dT%H:%M:%SZ'
+  else
+    command date "+$DATE_FMT"
+  fi
+}
+is_machine_format(){ [[ "$FORMAT" == "json" || "$FORMAT" == "sarif" ]]; }
+
+# If machine format: silence all user-facing text immediately.
+if is_machine_format; then
+  QUIET=1
+  USE_COLOR=0
+fi
+
+# ────────────────────────────────────────────────────────────────────────────
+# Global Counters
+# ────────────────────────────────────────────────────────────────────────────
+CRITICAL_COUNT=0
+WARNING_COUNT=0
+INFO_COUNT=0
+TOTAL_FILES=0
+
+# ────────────────────────────────────────────────────────────────────────────
+# Global State
+# ────────────────────────────────────────────────────────────────────────────
+HAS_RIPGREP=0
+
+# ────────────────────────────────────────────────────────────────────────────
+# Utilities
+# ────────────────────────────────────────────────────────────────────────────
+maybe_clear() { if [[ -t 1 && "$CI_MODE" -eq 0 ]] && ! is_machine_format; then clear || true; fi; }
+say() { [[ "$QUIET" -eq 1 ]] && return 0; echo -e "$*"; }
+
+json_escape() {
+  local s="${1-}"
+  s=${s//\\/\\\\}
+  s=${s//\"/\\\"}
+  s=${s//$'\n'/\\n}
+  s=${s//$'\r'/\\r}
+  s=${s//$'\t'/\\t}
+  printf '%s' "$s"
+}
+
+emit_json_summary() {
+  local ts json
+  ts="$(safe_date)"
+  json="$(printf '{"project":"%s","files":%s,"critical":%s,"warning":%s,"info":%s,"timestamp":"%s","format":"json"}\n' \
+    "$(json_escape "$PROJECT_DIR")" "$TOTAL_FILES" "$CRITICAL_COUNT" "$WARNING_COUNT" "$INFO_COUNT" "$(json_escape "$ts")")"
+  printf '%s' "$json"
+  if [[ -n "$SUMMARY_JSON" ]]; then
+    mkdir -p "$(dirname "$SUMMARY_JSON")" 2>/dev/null || true
+    printf '%s' "$json" >"$SUMMARY_JSON"
+  fi
+}
+
+emit_sarif() {
+  printf '%s\n' '{"version":"2.1.0","runs":[{"tool":{"driver":{"name":"ubs-shell"}},"results":[]}]}'
+}
+print_header() { say "\n${CYAN}${BOLD}━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━${RESET}"; say "${WHITE}${BOLD}$1${RESET}"; say "${CYAN}━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━${RESET}"; }
+print_category() { say "\n${MAGENTA}${BOLD}▓▓▓ $1${RESET}"; say "${DIM}$2${RESET}"; }
+print_subheader() { say "\n${YELLOW}${BOLD}$BULLET $1${RESET}"; }
+print_finding() {
+  local severity=$1
+  case $severity in
+    good) local title=$2; say "  ${GREEN}${CHECK} OK${RESET} ${DIM}$title${RESET}" ;;
+    *)
+      local raw_count=$2; local title=$3; local description="${4:-}"
+      local count; count=$(printf '%s\n' "$raw_count" | awk 'END{print $0+0}')
+      case $severity in
+        critical) CRITICAL_COUNT=$((CRITICAL_COUNT + count)); say "  ${RED}${BOLD}${FIRE} CRITICAL${RESET} ${WHITE}($count found)${RESET}"; say "    ${RED}${BOLD}$title${RESET}"; [ -n "$description" ] && say "    ${DIM}$description${RESET}" || true ;;
+        warning)  WARNING_COUNT=$((WARNING_COUNT + count)); say "  ${YELLOW}${WARN} Warning${RESET} ${WHITE}($count found)${RESET}"; say "    ${YELLOW}$title${RESET}"; [ -n "$description" ] && say "    ${DIM}$description${RESET}" || true ;;
+        info)     INFO_COUNT=$((INFO_COUNT + count));      say "  ${BLUE}${INFO} Info${RESET} ${WHITE}($count found)${RESET}"; say "    ${BLUE}$title${RESET}"; [ -n "$description" ] && say "    ${DIM}$description${RESET}" || true ;;
+      esac
+      ;;
+  esac
+}
+print_code_sample() { local file=$1; local line=$2; local code=$3; say "${GRAY}      $file:$line${RESET}"; say "${WHITE}      $code${RESET}"; }
+
+# Parse grep/rg output line handling Windows drive letters (C:/path...)
+# Sets: PARSED_FILE, PARSED_LINE, PARSED_CODE
+parse_grep_line() {
+  local rawline="$1"
+  PARSED_FILE="" PARSED_LINE="" PARSED_CODE=""
+  if [[ "$rawline" =~ ^([A-Za-z]:.+):([0-9]+):(.*)$ ]] || [[ "$rawline" =~ ^(.+):([0-9]+):(.*)$ ]]; then
+    PARSED_FILE="${BASH_REMATCH[1]}"
+    PARSED_LINE="${BASH_REMATCH[2]}"
+    PARSED_CODE="${BASH_REMATCH[3]}"
+    return 0
+  fi
+  return 1
+}
+
+show_detailed_finding() {
+  local pattern=$1; local limit=${2:-$DETAIL_LIMIT}; local printed=0
+  while IFS= read -r rawline; do
+    [[ -z "$rawline" ]] && continue
+    [[ "$rawline" == *"ubs:ignore"* ]] && continue
+    parse_grep_line "$rawline" || continue
+    print_code_sample "$PARSED_FILE" "$PARSED_LINE" "$PARSED_CODE"; printed=$((printed+1))
+    [[ $printed -ge $limit || $printed -ge $MAX_DETAILED ]] && break
+  done < <("${GREP_RN[@]}" -e "$pattern" "$PROJECT_DIR" 2>/dev/null | head -n "$limit" || true) || true
+}
+
+shell_scan() {
+  local mode="$1"
+  python3 - "$PROJECT_DIR" "$mode" "$INCLUDE_EXT" <<'PY'
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(sys.argv[1]).resolve()
+MODE = sys.argv[2] if len(sys.argv) > 2 else ''
+EXTS = [e.strip().lower() for e in (sys.argv[3] if len(sys.argv) > 3 else 'sh,bash').split(',') if e.strip()]
+BASE_DIR = ROOT if ROOT.is_dir() else ROOT.parent
+SKIP_DIRS = {'.git', '.hg', '.svn', 'node_modules', 'target', 'vendor', 'dist', 'build', '.cache', '.venv', 'venv'}
+SHEBANG_RE = re.compile(r'^#!\s*(?:/usr/bin/env\s+(?:-\S+\s+)*)?(?:\S*/)?(?P<shell>bash|sh|dash|ksh|zsh)\b(?P<args>.*)$')
+HEREDOC_RE = re.compile(r'<<(-?)\s*(["\']?)([A-Za-z_][\w]*)\2')
+SPECIAL_PARAMS = set('?#$!-')
+NAME_RE = re.compile(r'[A-Za-z_][\w]*')
+ASSIGN_WORD_RE = re.compile(r'^[A-Za-z_][\w]*(?:\[[^\]]*\])?\+?=')
+SUBSCRIPT_RE = re.compile(r'^[A-Za-z_][\w]*\[[^\]]*$')
+DECL_WORDS = {'local', 'export', 'declare', 'readonly', 'typeset', 'integer'}
+NO_SPLIT_COMMANDS = {'exit', 'return', 'shift', 'break', 'continue'}
+RM_RECURSIVE_RE = re.compile(
+    r'(?:^\s*|[;&|({]\s*|\b(?:then|do|else|sudo|command)\s+)rm\s+'
+    r'(?P<args>(?:-[A-Za-z-]+\s+)*)(?P<targets>.*)$'
+)
+EVAL_RE = re.compile(r'(?:^\s*|[;&|({]\s*|\b(?:then|do|else)\s+)(?:builtin\s+)?eval\s+(?P<args>.+)$')
+SHELL_C_RE = re.compile(r'(?:^\s*|[;&|({]\s*|\b(?:then|do|else|sudo|exec)\s+)(?:\S*/)?(?:ba|da|k|z)?sh\s+-c\s+(?P<args>.+)$')
+VAR_REF_RE = re.compile(r'\$(?:\{(?P<braced>[A-Za-z_][\w]*)[^}]*\}|(?P<plain>[A-Za-z_][\w]*))')
+
+
+def iter_files(root):
+    def wanted(path):
+        suffix = path.suffix.lower().lstrip('.')
+        if suffix in EXTS:
+            return True
+        if suffix:
+            return False
+        try:
+            with path.open('r', encoding='utf-8', errors='ignore') as handle:
+                return bool(SHEBANG_RE.match(handle.readline().strip()))
+        except OSError:
+            return False
+
+    if root.is_file():
+        if wanted(root):
+            yield root
+        return
+    for path in sorted(root.rglob('*')):
+        if not path.is_file():
+            continue
+        if any(part in SKIP_DIRS for part in path.relative_to(root).parts):
+            continue
+        if wanted(path):
+            yield path
+
+
+def relpath(path):
+    try:
+        return path.relative_to(BASE_DIR).as_posix()
+    except ValueError:
+        return path.as_posix()
+
+
+def has_ignore(lines, line_no):
+    for idx in (line_no - 1, line_no - 2):
+        if 0 <= idx < len(lines) and 'ubs:ignore' in lines[idx]:
+            return True
+    return False
+
+
+def open_quote(text):
+    """Return True when a quote or $( ... ) opened on this text is still unterminated."""
+    stack = []
+    prev = ' '
+    idx = 0
+    while idx < len(text):
+        ch = text[idx]
+        top = stack[-1] if stack else ''
+        if top == "'":
+            if ch == "'":
+                stack.pop()
+        elif ch == '\\':
+            idx += 2
+            prev = 'x'
+            continue
+        elif top == '"':
+            if ch == '"':
+                stack.pop()
+            elif text.startswith('$(', idx):
+                stack.append('(')
+                idx += 2
+                prev = '('
+                continue
+        elif ch in ('"', "'"):
+            stack.append(ch)
+        elif text.startswith('$(', idx):
+            stack.append('(')
+            idx += 2
+            prev = '('
+            continue
+        elif ch == '(' and top == '(':
+            stack.append('(')
+        elif ch == ')' and top == '(':
+            stack.pop()
+        elif ch == '#' and (prev.isspace() or prev in ';&|('):
+            break
+        prev = ch
+        idx += 1
+    return any(item in ('"', "'") for item in stack)
+
+
+def logical_lines(lines):
+    """Yield (line_no, text) with continuations and multi-line quotes joined, heredoc bodies skipped."""
+    idx = 0
+    pending = []
+    while idx < len(lines):
+        start = idx
+        text = lines[idx]
+        while idx + 1 < len(lines):
+            if text.endswith('\\'):
+                idx += 1
+                text = text[:-1] + ' ' + lines[idx].lstrip()
+            elif open_quote(text) and idx - start < 200:
+                idx += 1
+                text = text + '\n' + lines[idx]
+            else:
+                break
+        idx += 1
+        yield start + 1, text
+        for match in HEREDOC_RE.finditer(strip_comment(text)):
+            pending.append((match.group(1) == '-', match.group(3)))
+        while pending and idx < len(lines):
+            strip_tabs, word = pending[0]
+            body = lines[idx].lstrip('\t') if strip_tabs else lines[idx]
+            idx += 1
+            if body == word:
+                pending.pop(0)
+
+
+def strip_comment(text):
+    quote = ''
+    prev = ' '
+    idx = 0
+    while idx < len(text):
+        ch = text[idx]
+        if quote:
+            if ch == '\\' and quote == '"':
+                idx += 2
+                prev = 'x'
+                continue
+            if ch == quote:
+                quote = ''
+        elif ch == '\\':
+            idx += 2
+            prev = 'x'
+            continue
+        elif ch in ('"', "'"):
+            quote = ch
+        elif ch == '#' and (prev.isspace() or prev in ';&|('):
+            return text[:idx].rstrip()
+        prev = ch
+        idx += 1
+    return text
+
+
+def braced_end(text, idx):
+    depth = 1
+    while idx < len(text):
+        if text[idx] == '\\':
+            idx += 2
+            continue
+        if text.startswith('${', idx):
+            depth += 1
+            idx += 2
+            continue
+        if text[idx] == '}':
+            depth -= 1
+            if depth == 0:
+                return idx + 1
+        idx += 1
+    return len(text)
+
+
+def unquoted_expansions(text):
+    """Return names of $VAR expansions that are subject to word splitting."""
+    found = []
+    quote = ''
+    idx = 0
+    n = len(text)
+    stack = []
+    command_words = []
+    current_word = ''
+    skip_until = None
+
+    def command_context():
+        words = [w for w in command_words if w]
+        while words and (ASSIGN_WORD_RE.match(words[0]) or words[0] in DECL_WORDS):
+            if words[0] in DECL_WORDS:
+                words = words[1:]
+                while words and words[0].startswith('-'):
+                    words = words[1:]
+                if current_word and ASSIGN_WORD_RE.match(current_word) and not words:
+                    return 'assign'
+                continue
+            words = words[1:]
+        if not words and current_word and (ASSIGN_WORD_RE.match(current_word) or SUBSCRIPT_RE.match(current_word)):
+            return 'assign'
+        head = words[0] if words else ''
+        if head in {'for', 'select'} or (words and words[-1] == 'in' and head in {'for', 'select'}):
+            return 'for'
+        if head == 'case':
+            return 'case'
+        if head in NO_SPLIT_COMMANDS:
+            return 'nosplit'
+        return 'arg'
+
+    while idx < n:
+        ch = text[idx]
+        if skip_until is not None:
+            end = text.find(skip_until, idx)
+            if end == -1:
+                break
+            idx = end + len(skip_until)
+            skip_until = None
+            continue
+        if quote == "'":
+            if ch == "'":
+                quote = ''
+            current_word += ch
+            idx += 1
+            continue
+        if ch == '\\':
+            current_word += text[idx:idx + 2]
+            idx += 2
+            continue
+        if quote == '"':
+            if ch == '"':
+                quote = ''
+            elif text.startswith('${', idx):
+                close = braced_end(text, idx + 2)
+                current_word += text[idx:close]
+                idx = close
+                continue
+            elif ch == '$' and text.startswith('$(', idx) and not text.startswith('$((', idx):
+                stack.append(('"', command_words, current_word))
+                command_words, current_word, quote = [], '', ''
+                idx += 2
+                continue
+            current_word += ch
+            idx += 1
+            continue
+        if ch in ('"', "'"):
+            quote = ch
+            current_word += ch
+            idx += 1
+            continue
+        if text.startswith('[[', idx) and (idx == 0 or text[idx - 1] in ' \t;&|(!'):
+            skip_until = ']]'
+            idx += 2
+            continue
+        if text.startswith('$((', idx) or (text.startswith('((', idx) and (idx == 0 or text[idx - 1] in ' \t;&|(')):
+            depth = 0
+            j = idx
+            while j < n:
+                if text[j] == '(':
+                    depth += 1
+                elif text[j] == ')':
+                    depth -= 1
+                    if depth == 0:
+                        break
+                j += 1
+            current_word += text[idx:j + 1]
+            idx = j + 1
+            continue
+        if text.startswith('$(', idx) or ch == '`':
+            if ch == '`':
+                end = text.find('`', idx + 1)
+                inner = text[idx + 1:end if end != -1 else n]
+                found.extend(unquoted_expansions(inner))
+                current_word += 'X'
+                idx = (end + 1) if end != -1 else n
+                continue
+            stack.append(('', command_words, current_word))
+            command_words, current_word = [], ''
+            idx += 2
+            continue
+        if ch == ')' and stack:
+            outer_quote, command_words, current_word = stack.pop()
+            quote = outer_quote
+            current_word += 'X'
+            idx += 1
+            continue
+        if ch == '$' and idx + 1 < n:
+            nxt = text[idx + 1]
+            name = ''
+            end = idx + 1
+            if nxt == '{':
+                close = text.find('}', idx + 2)
+                inner = text[idx + 2:close if close != -1 else n]
+                end = (close + 1) if close != -1 else n
+                if inner.startswith('#') or inner[:1] in SPECIAL_PARAMS:
+                    name = ''
+                else:
+                    match = NAME_RE.match(inner) or re.match(r'\d+|[@*]', inner)
+                    name = match.group(0) if match else ''
+            elif nxt.isdigit() or nxt in '@*':
+                name = nxt
+                end = idx + 2
+            else:
+                match = NAME_RE.match(text, idx + 1)
+                if match:
+                    name = match.group(0)
+                    end = match.end()
+            if name:
+                context = command_context()
+                if context == 'arg':
+                    found.append(name)
+            current_word += text[idx:end]
+            idx = end
+            continue
+        if ch in ' \t':
+            if current_word:
+                command_words.append(current_word)
+                current_word = ''
+            idx += 1
+            continue
+        if ch in ';&|(){}\n':
+            if current_word:
+                command_words.append(current_word)
+            command_words, current_word = [], ''
+            idx += 1
+            continue
+        if ch in '<>':
+            current_word = ''
+            idx += 1
+            continue
+        current_word += ch
+        idx += 1
+    return found
+
+
+FUNC_DEF_RE = re.compile(r'^\s*(?:function\s+[A-Za-z_][\w:.-]*\s*(?:\(\s*\))?|[A-Za-z_][\w:.-]*\s*\(\s*\))\s*\{')
+BRACE_RE = re.compile(r'(?:^|(?<=[\s;&|()]))([{}])(?=[\s;&|)]|$)')
+IF_RE = re.compile(r'(?:^\s*|[;&|(]\s*|\b(?:then|do|else)\s+|!\s+)if\s')
+FI_RE = re.compile(r'(?:^|[;&\s])fi\b')
+EXIT_RE = re.compile(r'\b(?:exit|return)\b')
+VAR = r'"?\$\{?([A-Za-z_]\w*)(?::?-[^}"]*)?\}?"?'
+EMPTY_TEST = r'\[\[?\s+-z\s+' + VAR + r'\s+\]\]?'
+SET_TEST = r'\[\[?\s+-[ndef]\s+' + VAR + r'(?:/[^\s\]]*)?"?\s+\]\]?'
+
+
+def scopes(logical):
+    """Map each line to the start line of its enclosing shell function (0 at top level)."""
+    owner = {}
+    stack = []
+    pending = None
+    for line_no, raw in logical:
+        code = strip_comment(raw)
+        current = next((start for start, is_fn in reversed(stack) if is_fn), 0)
+        if FUNC_DEF_RE.match(code):
+            pending = line_no
+        for brace in BRACE_RE.finditer(code):
+            if brace.group(1) == '{':
+                stack.append((pending if pending else 0, bool(pending)))
+                pending = None
+            elif stack:
+                stack.pop()
+        for offset in range(raw.count('\n') + 1):
+            owner[line_no + offset] = pending or current
+    return owner
+
+
+def guarded_vars(lines):
+    """Return (guards, owner): name -> [(scope, first_line, last_line)] where it is proven non-empty."""
+    logical = list(logical_lines(lines))
+    owner = scopes(logical)
+    scope_end = {}
+    for ln, scope in owner.items():
+        scope_end[scope] = max(scope_end.get(scope, 0), ln)
+    guards = {}
+
+    def add(name, line_no, end=None):
+        # Guards only cover later lines of the same function (or of top-level code).
+        scope = owner.get(line_no, 0)
+        guards.setdefault(name, []).append((scope, line_no, scope_end.get(scope, len(lines)) if end is None else end))
+
+    open_ifs = []
+    for line_no, raw in logical:
+        code = strip_comment(raw)
+        for pattern in (
+            r'(?:^|[\s;])(?:readonly\s+|local\s+|export\s+|declare\s+(?:-\w+\s+)?)?([A-Za-z_]\w*)=["\']?\$\{(?:[A-Za-z_]\w*|\d+):?\?',
+            r'^\s*: "?\$\{([A-Za-z_]\w*):?\?',
+            r'\b([A-Za-z_]\w*)=\$\((?:command\s+)?mktemp\b',
+            r'^\s*(?:readonly\s+|local\s+|declare\s+(?:-r\s+)?)?([A-Za-z_]\w*)=["\']?/[\w./-]+["\']?\s*$',
+        ):
+            for match in re.finditer(pattern, code):
+                add(match.group(1), line_no)
+        # [ -z "$x" ] && exit 1   /   [ -n "$x" ] || return 1
+        for match in re.finditer(EMPTY_TEST + r'\s*&&\s*\{?[^|]*?' + EXIT_RE.pattern, code):
+            add(match.group(1), line_no)
+        for match in re.finditer(SET_TEST + r'\s*\|\|\s*\{?[^&]*?' + EXIT_RE.pattern, code):
+            add(match.group(1), line_no)
+        # [ -d "$x" ] && rm -rf "$x"
+        for match in re.finditer(SET_TEST + r'\s*&&', code):
+            add(match.group(1), line_no, line_no)
+        for match in IF_RE.finditer(code):
+            open_ifs.append((line_no, code[match.end():], []))
+        if open_ifs:
+            open_ifs[-1][2].append(code)
+        for _ in FI_RE.finditer(code):
+            if not open_ifs:
+                break
+            start, cond, body = open_ifs.pop()
+            if open_ifs:
+                open_ifs[-1][2].extend(body)
+            cond = cond.split('then', 1)[0]
+            block = '\n'.join(body)
+            empty = re.match(r'\s*' + EMPTY_TEST, cond)
+            if empty and EXIT_RE.search(block) and not re.search(r'\b(?:else|elif)\b', block):
+                # if [ -z "$x" ]; then ...; exit 1; fi
+                add(empty.group(1), line_no)
+            present = re.match(r'\s*' + SET_TEST, cond)
+            if present:
+                # if [ -n "$x" ]; then rm -rf "$x"; fi
+                add(present.group(1), start, line_no)
+    return guards, owner
+
+
+def rm_targets(text, guards, owner, line_no):
+    match = RM_RECURSIVE_RE.search(text)
+    if not match:
+        return []
+    flags = match.group('args')
+    short = ''.join(f[1:] for f in flags.split() if f.startswith('-') and not f.startswith('--'))
+    long_flags = {f for f in flags.split() if f.startswith('--')}
+    recursive = 'r' in short or 'R' in short or '--recursive' in long_flags
+    if not recursive:
+        return []
+    risky = []
+    targets = re.split(r'\s*(?:&&|\|\||;|\|)\s*', match.group('targets'), maxsplit=1)[0]
+    for target in targets.split():
+        ref = re.match(r'^"?\$(?:\{(?P<braced>[A-Za-z_]\w*)(?P<op>[^}]*)\}|(?P<plain>[A-Za-z_]\w*))', target)
+        if not ref:
+            continue
+        name = ref.group('braced') or ref.group('plain')
+        op = ref.group('op') or ''
+        if op.startswith(':?') or op.startswith('?') or op.startswith(':-/') or op.startswith('-/'):
+            continue
+        scope = owner.get(line_no, 0)
+        if any(sc == scope and first <= line_no <= end for sc, first, end in guards.get(name, ())):
+            continue
+        risky.append(name)
+    return risky
+
+
+def strict_mode_issue(path, lines):
+    first = lines[0].strip() if lines else ''
+    shebang = SHEBANG_RE.match(first)
+    if not shebang:
+        return None
+    shell = shebang.group('shell')
+    options = set()
+    for flag in re.findall(r'(?:^|\s)-([A-Za-z]+)', shebang.group('args')):
+        options.update(flag)
+    text = '\n'.join(lines)
+    for match in re.finditer(r'^\s*set\s+([-+A-Za-z\s]+?(?:\s+-o\s+\w+|\s+pipefail|\s+errexit|\s+nounset)*)\s*(?:[#;]|$)', text, re.M):
+        spec = match.group(1)
+        for flag in re.findall(r'(?:^|\s)-([A-Za-z]+)', spec):
+            options.update(flag)
+        for name in re.findall(r'-o\s+(\w+)', spec):
+            options.add({'errexit': 'e', 'nounset': 'u'}.get(name, name))
+        if re.search(r'\bpipefail\b', spec):
+            options.add('pipefail')
+    missing = []
+    if 'e' not in options:
+        missing.append('-e')
+    if 'u' not in options:
+        missing.append('-u')
+    if shell in {'bash', 'zsh', 'ksh'} and 'pipefail' not in options:
+        missing.append('-o pipefail')
+    if not missing:
+        return None
+    return f"{first}  [no set {' '.join(missing)}]"
+
+
+def analyze(path, issues):
+    try:
+        text = path.read_text(encoding='utf-8', errors='ignore')
+    except OSError:
+        return
+    lines = text.split('\n')
+    if MODE == 'strict':
+        issue = strict_mode_issue(path, lines)
+        if issue and not has_ignore(lines, 2):
+            issues.append((relpath(path), 1, issue))
+        return
+    guards, owner = guarded_vars(lines) if MODE == 'rm' else ({}, {})
+    for line_no, raw in logical_lines(lines):
+        code = strip_comment(raw)
+        if not code.strip() or has_ignore(lines, line_no):
+            continue
+        if MODE == 'unquoted':
+            names = unquoted_expansions(code)
+            if names:
+                shown = ', '.join('$' + name for name in dict.fromkeys(names))
+                issues.append((relpath(path), line_no, f"{lines[line_no - 1].strip()}  [unquoted {shown}]"))
+        elif MODE == 'rm':
+            names = rm_targets(code, guards, owner, line_no)
+            if names:
+                shown = ', '.join('$' + name for name in dict.fromkeys(names))
+                issues.append((relpath(path), line_no, f"{lines[line_no - 1].strip()}  [rm -r on {shown} without an empty-value guard]"))
+        elif MODE == 'eval':
+            for regex, label in ((EVAL_RE, 'eval'), (SHELL_C_RE, 'sh -c')):
+                match = regex.search(code)
+                if not match:
+                    continue
+                if label == 'eval' and re.match(r'set\s+--\s', match.group('args')):
+                    continue
+                refs = [m.group('braced') or m.group('plain') for m in VAR_REF_RE.finditer(match.group('args'))]
+                if refs:
+                    shown = ', '.join('$' + name for name in dict.fromkeys(refs))
+                    issues.append((relpath(path), line_no, f"{lines[line_no - 1].strip()}  [{label} runs text built from {shown}]"))
+                    break
+
+
+issues = []
+for file_path in iter_files(ROOT):
+    analyze(file_path, issues)
+print(f"__COUNT__\t{len(issues)}")
+for file_name, line_no, code in issues[:25]:
+    print(f"__SAMPLE__\t{file_name}\t{line_no}\t{code}")
+PY
+}
+
+# One finding per mode; samples come from shell_scan's __SAMPLE__ rows.
+run_shell_checks() {
+  local mode="$1" severity="$2" title="$3" remediation="$4" good="$5"
+  if ! command -v python3 >/dev/null 2>&1; then
+    print_finding "info" 0 "python3 not available" "Install python3 to enable shell script checks"
+    return
+  fi
+  local printed=0
+  while IFS=$'\t' read -r tag a b c; do
+    case "$tag" in
+      __COUNT__)
+        if [[ "$a" -gt 0 ]]; then
+          print_finding "$severity" "$a" "$title" "$remediation"
+        else
+          print_finding "good" "$good"
+        fi
+        ;;
+      __SAMPLE__)
+        if [[ "$printed" -lt "$DETAIL_LIMIT" && "$printed" -lt "$MAX_DETAILED" ]]; then
+          print_code_sample "$a" "$b" "$c"
+          printed=$((printed + 1))
+        fi
+        ;;
+    esac
+  done < <(shell_scan "$mode")
+}
+
+persist_metric_json() {
+  local key=$1; local payload=$2
+  [[ -n "$key" && -n "$payload" ]] || return 0
+  [[ -n "${UBS_METRICS_DIR:-}" ]] || return 0
+  mkdir -p "$UBS_METRICS_DIR" 2>/dev/null || true
+  {
+    printf '{"%s":' "$key"
+    printf '%s' "$payload"
+    printf '}'
+  } >"$UBS_METRICS_DIR/$key.json"
+}
+
+begin_scan_section(){ if [[ "$DISABLE_PIPEFAIL_DURING_SCAN" -eq 1 ]]; then set +o pipefail; fi; set +e; trap - ERR; }
+end_scan_section(){ trap on_err ERR; set -e; if [[ "$DISABLE_PIPEFAIL_DURING_SCAN" -eq 1 ]]; then set -o pipefail; fi; }
+
+mktemp_dir() { mktemp -d 2>/dev/null || mktemp -d -t ubs-shell.XXXXXX; }
+mktemp_file(){ mktemp 2>/dev/null    || mktemp    -t ubs-shell.XXXXXX; }
+
+with_timeout() {
+  local seconds="$1"; shift || true
+  if command -v timeout >/dev/null 2>&1; then timeout "$seconds" "$@"; else "$@"; fi
+}
+
+# Path helpers & robust file discovery
+abspath() { perl -MCwd=abs_path -e 'print abs_path(shift)' -- "$1" 2>/dev/null || python3 - "$1" <<'PY'
+import os,sys; print(os.path.abspath(sys.argv[1]))
+PY
+}
+count_lines() { grep -v 'ubs:ignore' | awk 'END{print (NR>0?NR:0)}'; }
+
+LC_ALL=C
+IFS=',' read -r -a _EXT_ARR <<<"$INCLUDE_EXT"
+INCLUDE_GLOBS=(); for e in "${_EXT_ARR[@]}"; do INCLUDE_GLOBS+=( "--include=*.$(echo "$e" | xargs)" ); done
+EXCLUDE_DIRS=(.git .hg .svn .bzr node_modules vendor target dist build .cache .venv venv .idea .vscode .history tmp log)
+if [[ -n "$EXTRA_EXCLUDES" ]]; then IFS=',' read -r -a _X <<<"$EXTRA_EXCLUDES"; EXCLUDE_DIRS+=("${_X[@]}"); fi
+EXCLUDE_FLAGS=(); for d in "${EXCLUDE_DIRS[@]}"; do EXCLUDE_FLAGS+=( "--exclude-dir=$d" ); done
+
+build_find_cmd() {
+  local mode="${UBS_FIND_PRUNE_MODE:-path}" ; local -a prune=( )
+  if [[ "$mode" == "path" ]]; then
+    for d in "${EXCLUDE_DIRS[@]}"; do prune+=( -path "$PROJECT_DIR/$d" -o ); done
+  else
+    for d in "${EXCLUDE_DIRS[@]}"; do prune+=( -name "$d" -o ); done
+  fi
+  [[ ${#prune[@]} -gt 0 ]] && unset 'prune[${#prune[@]}-1]'
+  local -a names=( ); local first=1
+  for e in "${_EXT_ARR[@]}"; do if [[ $first -eq 1 ]]; then names+=( -name "*.$e" ); first=0; else names+=( -o -name "*.$e" ); fi; done
+  FIND_CMD=(find "$PROJECT_DIR" \( -type d \( "${prune[@]}" \) -prune \) -o \( -type f \( "${names[@]}" \) -print0 \))
+}
+build_find_cmd
+safe_count_files(){ tr -cd '\0' | awk 'END{print (length>0?gsub(/\0/,"")+0:0)}'; }
+
+if command -v rg >/dev/null 2>&1; then
+  HAS_RIPGREP=1
+  if [[ "${JOBS}" -eq 0 ]]; then JOBS="$( (command -v nproc >/dev/null && nproc) || sysctl -n hw.ncpu 2>/dev/null || echo 0 )"; fi
+  RG_JOBS=(); if [[ "${JOBS}" -gt 0 ]]; then RG_JOBS=(-j "$JOBS"); fi
+  RG_BASE=(--no-config --no-messages --line-number --with-filename --hidden --pcre2 "${RG_JOBS[@]}")
+  RG_EXCLUDES=(); for d in "${EXCLUDE_DIRS[@]}"; do RG_EXCLUDES+=( -g "!$d/**" ); done
+  RG_INCLUDES=(); for e in "${_EXT_ARR[@]}"; do RG_INCLUDES+=( -g "*.$(echo "$e" | xargs)" ); done
+  GREP_RN=(rg "${RG_BASE[@]}" "${RG_EXCLUDES[@]}" "${RG_INCLUDES[@]}")
+  GREP_RNI=(rg -i "${RG_BASE[@]}" "${RG_EXCLUDES[@]}" "${RG_INCLUDES[@]}")
+  GREP_RNW=(rg -w "${RG_BASE[@]}" "${RG_EXCLUDES[@]}" "${RG_INCLUDES[@]}")
+  RG_JOBS=()
+else
+  GREP_R_OPTS=(-R --binary-files=without-match "${EXCLUDE_FLAGS[@]}" "${INCLUDE_GLOBS[@]}")
+  GREP_RN=("grep" "${GREP_R_OPTS[@]}" -n -E)
+  GREP_RNI=("grep" "${GREP_R_OPTS[@]}" -n -i -E)
+  GREP_RNW=("grep" "${GREP_R_OPTS[@]}" -n -w -E)
+fi
+
+# Category gating (run if returns 0)
+run_category() {
+  local cat="$1"
+  if [[ -n "$ONLY_CATEGORIES" ]]; then
+    IFS=',' read -r -a arr <<<"$ONLY_CATEGORIES"
+    for s in "${arr[@]}"; do [[ "$s" == "$cat" ]] && return 0; done
+    return 1
+  fi
+  if [[ -z "$SKIP_CATEGORIES" ]]; then return 0; fi
+  IFS=',' read -r -a arr <<<"$SKIP_CATEGORIES"
+  for s in "${arr[@]}"; do [[ "$s" == "$cat" ]] && return 1; done
+  return 0
+}
+
+# ────────────────────────────────────────────────────────────────────────────
+# Init
+# ────────────────────────────────────────────────────────────────────────────
+maybe_clear
+
+if ! is_machine_format; then
+echo -e "${BOLD}${CYAN}"
+cat <<'BANNER'
+╔══════════════════════════════════════════════════════════════════╗
+║  ██╗   ██╗██╗  ████████╗██╗███╗   ███╗ █████╗ ████████╗███████╗  ║
+║  ██║   ██║██║  ╚══██╔══╝██║████╗ ████║██╔══██╗╚══██╔══╝██╔════╝  ║
+║  ██║   ██║██║     ██║   ██║██╔████╔██║███████║   ██║   █████╗    ║
+║  ██║   ██║██║     ██║   ██║██║╚██╔╝██║██╔══██║   ██║   ██╔══╝    ║
+║  ╚██████╔╝███████╗██║   ██║██║ ╚═╝ ██║██║  ██║   ██║   ███████╗  ║
+║   ╚═════╝ ╚══════╝╚═╝   ╚═╝╚═╝     ╚═╝╚═╝  ╚═╝   ╚═╝   ╚══════╝  ║
+║                                                                  ║
+║  Shell module • strict mode, quoting, rm -rf, eval injection     ║
+║  UBS module: shell • quote-aware lexer for sh/bash scripts       ║
+║  Run standalone: modules/ubs-shell.sh --help                     ║
+║                                                                  ║
+║  Night Owl QA                                                    ║
+║  "We see bugs before you do."                                    ║
+╚══════════════════════════════════════════════════════════════════╝
+BANNER
+echo -e "${RESET}"
+fi
+
+PROJECT_DIR="$(abspath "$PROJECT_DIR")"
+say "${WHITE}Project:${RESET}  ${CYAN}$PROJECT_DIR${RESET}"
+say "${WHITE}Started:${RESET}  ${GRAY}$(safe_date)${RESET}"
+
+# Count files with robust find
+TOTAL_FILES=$( ( set +o pipefail; "${FIND_CMD[@]}" 2>/dev/null || true ) | safe_count_files )
+TOTAL_FILES=$(( TOTAL_FILES + 0 ))
+say "${WHITE}Files:${RESET}    ${CYAN}$TOTAL_FILES source files (${INCLUDE_EXT})${RESET}"
+
+# relax pipefail for scanning (optional)
+begin_scan_section
+
+# ═══════════════════════════════════════════════════════════════════════════
+# CATEGORY 1: STRICT MODE & ERROR HANDLING
+# ═══════════════════════════════════════════════════════════════════════════
+if run_category 1; then
+print_header "1. STRICT MODE & ERROR HANDLING"
+print_category "Detects: scripts with a shebang that never enable set -e, set -u, or (bash) pipefail" \
+  "Without strict mode a failed step is ignored and the script keeps going with partial state."
+
+print_subheader "Missing set -euo pipefail"
+run_shell_checks strict "warning" "Script runs without set -euo pipefail" \
+  "Add 'set -euo pipefail' (POSIX sh: 'set -eu') right after the shebang so failures, unset variables, and broken pipes stop the script" \
+  "All scripts enable strict mode"
+fi
+
+# ═══════════════════════════════════════════════════════════════════════════
+# CATEGORY 2: QUOTING & WORD SPLITTING
+# ═══════════════════════════════════════════════════════════════════════════
+if run_category 2; then
+print_header "2. QUOTING & WORD SPLITTING"
+print_category "Detects: \$VAR / \${VAR} expanded unquoted in command arguments and [ ] tests" \
+  "Unquoted expansions split on whitespace and glob, so paths with spaces or '*' break or widen commands."
+
+print_subheader "Unquoted variable expansions"
+run_shell_checks unquoted "warning" "Variable expanded without quotes" \
+  "Quote expansions (\"\$var\", \"\${arr[@]}\"); use an array when a value must expand to several arguments" \
+  "No unquoted variable expansions in command arguments"
+fi
+
+# ═══════════════════════════════════════════════════════════════════════════
+# CATEGORY 3: DESTRUCTIVE FILESYSTEM OPERATIONS
+# ═══════════════════════════════════════════════════════════════════════════
+if run_category 3; then
+print_header "3. DESTRUCTIVE FILESYSTEM OPERATIONS"
+print_category "Detects: rm -r/-rf whose target starts with a variable that is never checked for emptiness" \
+  "An empty or unset variable turns 'rm -rf \$DIR/*' into 'rm -rf /*'."
+
+print_subheader "rm -rf on unguarded variables"
+run_shell_checks rm "critical" "rm -rf on a variable that may be empty" \
+  "Use \"\${DIR:?}\" so an empty value aborts, or test [ -n \"\$DIR\" ] before deleting" \
+  "Recursive deletes are guarded against empty variables"
+fi
+
+# ═══════════════════════════════════════════════════════════════════════════
+# CATEGORY 4: COMMAND INJECTION
+# ═══════════════════════════════════════════════════════════════════════════
+if run_category 4; then
+print_header "4. COMMAND INJECTION"
+print_category "Detects: eval or sh -c executing text assembled from variables" \
+  "Shell metacharacters in the variable become code; CI inputs such as branch names and PR titles are attacker-controlled."
+
+print_subheader "eval / sh -c with variable input"
+run_shell_checks eval "critical" "eval or sh -c runs text built from variables" \
+  "Call the command directly with quoted arguments or build an argv array; avoid eval" \
+  "No eval or sh -c with variable input"
+fi
+
+# restore pipefail if we relaxed it
+end_scan_section
+
+# ═══════════════════════════════════════════════════════════════════════════
+# FINAL SUMMARY
+# ═══════════════════════════════════════════════════════════════════════════
+
+EXIT_CODE=0
+if [ "$CRITICAL_COUNT" -gt 0 ]; then EXIT_CODE=1; fi
+if [ "$FAIL_ON_WARNING" -eq 1 ] && [ $((CRITICAL_COUNT + WARNING_COUNT)) -gt 0 ]; then EXIT_CODE=1; fi
+
+if [[ "$FORMAT" == "json" ]]; then
+  emit_json_summary
+  exit "$EXIT_CODE"
+fi
+if [[ "$FORMAT" == "sarif" ]]; then
+  emit_sarif
+  exit "$EXIT_CODE"
+fi
+
+echo ""
+say "${BOLD}${WHITE}═══════════════════════════════════════════════════════════════════════════${RESET}"
+say "${BOLD}${CYAN}                    ${POTION} SCAN COMPLETE ${POTION}                                  ${RESET}"
+say "${BOLD}${WHITE}═══════════════════════════════════════════════════════════════════════════${RESET}"
+echo ""
+
+say "${WHITE}${BOLD}Summary Statistics:${RESET}"
+say "  ${WHITE}Files scanned:${RESET}    ${CYAN}$TOTAL_FILES${RESET}"
+say "  ${RED}${BOLD}Critical issues:${RESET}  ${RED}$CRITICAL_COUNT${RESET}"
+say "  ${YELLOW}Warning issues:${RESET}   ${YELLOW}$WARNING_COUNT${RESET}"
+say "  ${BLUE}Info items:${RESET}       ${BLUE}$INFO_COUNT${RESET}"
+echo ""
+
+say "${BOLD}${WHITE}Priority Actions:${RESET}"
+if [ "$CRITICAL_COUNT" -gt 0 ]; then
+  say "  ${RED}${FIRE} ${BOLD}FIX CRITICAL ISSUES IMMEDIATELY${RESET}"
+  say "  ${DIM}These cause crashes, security vulnerabilities, or data corruption${RESET}"
+fi
+if [ "$WARNING_COUNT" -gt 0 ]; then
+  say "  ${YELLOW}${WARN} ${BOLD}Review and fix WARNING items${RESET}"
+  say "  ${DIM}These cause bugs, performance issues, or maintenance problems${RESET}"
+fi
+if [ "$INFO_COUNT" -gt 0 ]; then
+  say "  ${BLUE}${INFO} ${BOLD}Consider INFO suggestions${RESET}"
+  say "  ${DIM}Code quality improvements and best practices${RESET}"
+fi
+
+if [ "$CRITICAL_COUNT" -eq 0 ] && [ "$WARNING_COUNT" -eq 0 ]; then
+  say "\n  ${GREEN}${BOLD}${SPARKLE} EXCELLENT! No critical or warning issues found ${SPARKLE}${RESET}"
+fi
+
+echo ""
+say "${DIM}Scan completed at: $(safe_date)${RESET}"
+
+if [[ -n "$OUTPUT_FILE" ]]; then
+  say "${GREEN}${CHECK} Full report saved to: ${CYAN}$OUTPUT_FILE${RESET}"
+fi
+if [[ -n "$SUMMARY_JSON" ]]; then
+  mkdir -p "$(dirname "$SUMMARY_JSON")" 2>/dev/null || true
+  printf '{"timestamp":"%s","files":%s,"critical":%s,"warning":%s,"info":%s}\n' \
+     "$(safe_date)" "$TOTAL_FILES" "$CRITICAL_COUNT" "$WARNING_COUNT" "$INFO_COUNT" >"$SUMMARY_JSON"
+fi
+
+echo ""
+if [ "$VERBOSE" -eq 0 ]; then
+  say "${DIM}Tip: Run with -v/--verbose for more code samples per finding.${RESET}"
+elif [ "$VERBOSE" -eq 2 ]; then
+  say "${DIM}Very-verbose mode: showing up to $DETAIL_LIMIT samples per finding.${RESET}"
+fi
+say "${DIM}Add to pre-commit: ./ubs --ci --fail-on-warning . > shell-bug-scan-report.txt${RESET}"
+echo ""
+
+exit "$EXIT_CODE"
@@ -50,7 +50,8 @@ def main():
         "java": "ubs-java.sh",
         "ruby": "ubs-ruby.sh",
         "swift": "ubs-swift.sh",
-        "elixir": "ubs-elixir.sh"
+        "elixir": "ubs-elixir.sh",
+        "shell": "ubs-shell.sh"
     }
 
     new_checksums = {}
@@ -74,6 +75,7 @@ def main():
         "helpers/resource_lifecycle_java.py": "helpers/resource_lifecycle_java.py",
         "helpers/resource_lifecycle_ruby.py": "helpers/resource_lifecycle_ruby.py",
         "helpers/resource_lifecycle_swift.py": "helpers/resource_lifecycle_swift.py",
+        "helpers/rust_scan_common.py": "helpers/rust_scan_common.py",
         "helpers/type_narrowing_csharp.py": "helpers/type_narrowing_csharp.py",
         "helpers/type_narrowing_ts.js": "helpers/type_narrowing_ts.js",
         "helpers/type_narrowing_rust.py": "helpers/type_narrowing_rust.py",
@@ -0,0 +1,65 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <syslog.h>
+
+struct entry {
+    int id;
+    char name[32];
+};
+
+struct session {
+    char *user;
+    struct entry *entries;
+    size_t count;
+};
+
+/* Caller-controlled text is used as the format string. */
+void audit_login(const char *user_input) {
+    printf(user_input);
+    syslog(LOG_INFO, user_input);
+}
+
+/* Buffer overflow: no bound on the copy into a fixed-size field. */
+void set_name(struct entry *e, const char *name) {
+    strcpy(e->name, name);
+}
+
+/* count comes from the wire; count * sizeof can wrap to a tiny buffer. */
+int load_entries(struct session *s, size_t count) {
+    s->entries = malloc(count * sizeof(struct entry));
+    if (s->entries == NULL) {
+        return -1;
+    }
+    s->count = count;
+    return 0;
+}
+
+/* The scratch copy leaks on the early-return path and on success. */
+int validate_user(const char *name) {
+    char *scratch = strdup(name);
+    if (scratch == NULL) {
+        return -1;
+    }
+    if (strlen(scratch) > 16) {
+        return -1;
+    }
+    return 0;
+}
+
+/* Logs the user name after releasing it. */
+void end_session(struct session *s) {
+    char *user = s->user;
+    free(user);
+    printf("session closed for %s\n", user);
+}
+
+/* Error path frees the buffer, then the cleanup frees it again. */
+int rename_session(struct session *s, const char *next) {
+    char *copy = strdup(next);
+    if (strlen(copy) == 0) {
+        free(copy);
+    }
+    free(copy);
+    return 0;
+}
@@ -0,0 +1,73 @@
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <syslog.h>
+
+struct entry {
+    int id;
+    char name[32];
+};
+
+struct session {
+    char *user;
+    struct entry *entries;
+    size_t count;
+};
+
+void audit_login(const char *user_input) {
+    printf("%s\n", user_input);
+    syslog(LOG_INFO, "login: %s", user_input);
+}
+
+void set_name(struct entry *e, const char *name) {
+    snprintf(e->name, sizeof(e->name), "%s", name);
+}
+
+int load_entries(struct session *s, size_t count) {
+    s->entries = calloc(count, sizeof(struct entry));
+    if (s->entries == NULL) {
+        return -1;
+    }
+    s->count = count;
+    return 0;
+}
+
+int load_entries_checked(struct session *s, size_t count) {
+    if (count > SIZE_MAX / sizeof(struct entry)) {
+        return -1;
+    }
+    s->entries = malloc(count * sizeof(struct entry));
+    if (s->entries == NULL) {
+        return -1;
+    }
+    s->count = count;
+    return 0;
+}
+
+int validate_user(const char *name) {
+    char *scratch = strdup(name);
+    int ok;
+    if (scratch == NULL) {
+        return -1;
+    }
+    ok = strlen(scratch) <= 16;
+    free(scratch);
+    return ok ? 0 : -1;
+}
+
+void end_session(struct session *s) {
+    printf("session closed for %s\n", s->user);
+    free(s->user);
+    s->user = NULL;
+}
+
+int rename_session(struct session *s, const char *next) {
+    char *copy = strdup(next);
+    if (copy == NULL) {
+        return -1;
+    }
+    free(s->user);
+    s->user = copy;
+    return 0;
+}
@@ -360,7 +360,7 @@ process.on('unhandledRejection', (reason, promise) => {
 
 process.on('uncaughtException', (error) => {
   console.error('Uncaught Exception:', error);
-  gracefulShutdown('UNCAUGHT_EXCEPTION');
+  void gracefulShutdown('UNCAUGHT_EXCEPTION');
 });
 
 module.exports = { app, server, pool };
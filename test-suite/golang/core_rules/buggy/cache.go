@@ -0,0 +1,29 @@
+package inventory
+
+import (
+	"net/http"
+	"sync"
+)
+
+var hits = map[string]int{}
+
+// CountHit runs once per request, concurrently, and mutates a shared map.
+func CountHit(w http.ResponseWriter, r *http.Request) {
+	hits[r.URL.Path]++
+	w.WriteHeader(http.StatusNoContent)
+}
+
+// Index fans out one goroutine per key, all writing the same map.
+func Index(keys []string) map[string]bool {
+	seen := make(map[string]bool)
+	var wg sync.WaitGroup
+	for _, k := range keys {
+		wg.Add(1)
+		go func(k string) {
+			defer wg.Done()
+			seen[k] = true
+		}(k)
+	}
+	wg.Wait()
+	return seen
+}
@@ -0,0 +1,39 @@
+package inventory
+
+import (
+	"os"
+	"strings"
+)
+
+// LoadConfig panics instead of letting callers handle bad input.
+func LoadConfig(path string) map[string]string {
+	data, _ := os.ReadFile(path)
+	if len(data) == 0 {
+		panic("inventory: empty config")
+	}
+	out := map[string]string{}
+	for _, line := range strings.Split(string(data), "\n") {
+		if k, v, ok := strings.Cut(line, "="); ok {
+			out[k] = v
+		}
+	}
+	return out
+}
+
+// SizeAll defers every Close until the function returns.
+func SizeAll(paths []string) (int64, error) {
+	var total int64
+	for _, p := range paths {
+		f, err := os.Open(p)
+		if err != nil {
+			return 0, err
+		}
+		defer f.Close()
+		info, err := f.Stat()
+		if err != nil {
+			return 0, err
+		}
+		total += info.Size()
+	}
+	return total, nil
+}
@@ -0,0 +1,3 @@
+module example.com/ubs/core/buggy
+
+go 1.23
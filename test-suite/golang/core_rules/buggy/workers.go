@@ -0,0 +1,34 @@
+package inventory
+
+import (
+	"context"
+	"time"
+)
+
+// StartPoller loops forever; nothing ever tells it to stop.
+func StartPoller(events chan<- int) {
+	go func() {
+		for {
+			events <- poll()
+			time.Sleep(time.Second)
+		}
+	}()
+}
+
+// FetchStock abandons the goroutine on timeout, leaving its send blocked.
+func FetchStock(sku string) (int, error) {
+	result := make(chan int)
+	go func() {
+		result <- lookup(sku)
+	}()
+	select {
+	case n := <-result:
+		return n, nil
+	case <-time.After(2 * time.Second):
+		return 0, context.DeadlineExceeded
+	}
+}
+
+func poll() int { return 1 }
+
+func lookup(sku string) int { return len(sku) }
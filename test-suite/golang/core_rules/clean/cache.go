@@ -0,0 +1,37 @@
+package inventory
+
+import (
+	"net/http"
+	"sync"
+)
+
+var (
+	hitsMu sync.Mutex
+	hits   = map[string]int{}
+)
+
+// CountHit serializes writes to the shared map.
+func CountHit(w http.ResponseWriter, r *http.Request) {
+	hitsMu.Lock()
+	hits[r.URL.Path]++
+	hitsMu.Unlock()
+	w.WriteHeader(http.StatusNoContent)
+}
+
+// Index guards the map shared by its workers.
+func Index(keys []string) map[string]bool {
+	seen := make(map[string]bool)
+	var mu sync.Mutex
+	var wg sync.WaitGroup
+	for _, k := range keys {
+		wg.Add(1)
+		go func(k string) {
+			defer wg.Done()
+			mu.Lock()
+			defer mu.Unlock()
+			seen[k] = true
+		}(k)
+	}
+	wg.Wait()
+	return seen
+}
@@ -0,0 +1,3 @@
+module example.com/ubs/core/clean
+
+go 1.23
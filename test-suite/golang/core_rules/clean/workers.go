@@ -0,0 +1,42 @@
+package inventory
+
+import (
+	"context"
+	"time"
+)
+
+// StartPoller stops when ctx is cancelled.
+func StartPoller(ctx context.Context, events chan<- int) {
+	go func() {
+		ticker := time.NewTicker(time.Second)
+		defer ticker.Stop()
+		for {
+			select {
+			case <-ctx.Done():
+				return
+			case <-ticker.C:
+				events <- poll()
+			}
+		}
+	}()
+}
+
+// FetchStock buffers the result so the goroutine can always finish.
+func FetchStock(ctx context.Context, sku string) (int, error) {
+	ctx, cancel := context.WithTimeout(ctx, 2*time.Second)
+	defer cancel()
+	result := make(chan int, 1)
+	go func() {
+		result <- lookup(sku)
+	}()
+	select {
+	case n := <-result:
+		return n, nil
+	case <-ctx.Done():
+		return 0, ctx.Err()
+	}
+}
+
+func poll() int { return 1 }
+
+func lookup(sku string) int { return len(sku) }
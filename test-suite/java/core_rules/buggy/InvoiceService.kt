@@ -0,0 +1,34 @@
+package com.example.orders
+
+import java.io.File
+import java.io.FileInputStream
+import java.sql.Connection
+import java.text.SimpleDateFormat
+import java.util.Date
+
+object InvoiceFormats {
+    // Kotlin objects are singletons, so every thread shares this instance.
+    val stamp = SimpleDateFormat("yyyyMMdd-HHmmss")
+}
+
+class InvoiceService(private val connection: Connection) {
+    fun invoiceName(date: Date): String = "invoice-" + InvoiceFormats.stamp.format(date)
+
+    fun totalFor(customer: String): Long {
+        val statement = connection.createStatement()
+        val rows = statement.executeQuery("SELECT sum(total) FROM invoices WHERE customer = '$customer'")
+        return if (rows.next()) rows.getLong(1) else 0L
+    }
+
+    fun header(path: String): Int {
+        val input = FileInputStream(File(path))
+        return input.read()
+    }
+
+    fun archive(id: String) {
+        try {
+            connection.createStatement().executeUpdate("UPDATE invoices SET archived = 1 WHERE id = ${id}")
+        } catch (e: Exception) {
+        }
+    }
+}
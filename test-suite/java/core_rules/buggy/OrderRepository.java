@@ -0,0 +1,52 @@
+package com.example.orders;
+
+import java.io.BufferedReader;
+import java.io.FileReader;
+import java.io.IOException;
+import java.sql.Connection;
+import java.sql.ResultSet;
+import java.sql.SQLException;
+import java.sql.Statement;
+import java.text.ParseException;
+import java.text.SimpleDateFormat;
+import java.util.Date;
+
+public class OrderRepository {
+    // One formatter shared by every request thread.
+    private static final SimpleDateFormat ORDER_DATE = new SimpleDateFormat("yyyy-MM-dd");
+
+    private final Connection connection;
+
+    public OrderRepository(Connection connection) {
+        this.connection = connection;
+    }
+
+    public Date parseOrderDate(String raw) {
+        try {
+            return ORDER_DATE.parse(raw);
+        } catch (ParseException e) {
+        }
+        return null;
+    }
+
+    public ResultSet findByCustomer(String customerId) throws SQLException {
+        Statement statement = connection.createStatement();
+        return statement.executeQuery("SELECT id, total FROM orders WHERE customer_id = '" + customerId + "'");
+    }
+
+    public ResultSet findByStatus(String status) throws SQLException {
+        String sql = String.format("SELECT id FROM orders WHERE status = '%s'", status);
+        return connection.createStatement().executeQuery(sql);
+    }
+
+    public String firstImportLine(String path) throws IOException {
+        BufferedReader reader = new BufferedReader(new FileReader(path));
+        return reader.readLine();
+    }
+
+    public void deleteQuietly(String id) {
+        try {
+            connection.createStatement().executeUpdate("DELETE FROM orders WHERE id = " + id);
+        } catch (SQLException e) {}
+    }
+}
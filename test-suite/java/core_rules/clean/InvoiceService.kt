@@ -0,0 +1,39 @@
+package com.example.orders
+
+import java.io.File
+import java.io.FileInputStream
+import java.sql.Connection
+import java.sql.SQLException
+import java.time.LocalDateTime
+import java.time.format.DateTimeFormatter
+import java.util.logging.Level
+import java.util.logging.Logger
+
+object InvoiceFormats {
+    val stamp: DateTimeFormatter = DateTimeFormatter.ofPattern("yyyyMMdd-HHmmss")
+}
+
+class InvoiceService(private val connection: Connection) {
+    private val log = Logger.getLogger(InvoiceService::class.java.name)
+
+    fun invoiceName(at: LocalDateTime): String = "invoice-" + InvoiceFormats.stamp.format(at)
+
+    fun totalFor(customer: String): Long =
+        connection.prepareStatement("SELECT sum(total) FROM invoices WHERE customer = ?").use { statement ->
+            statement.setString(1, customer)
+            statement.executeQuery().use { rows -> if (rows.next()) rows.getLong(1) else 0L }
+        }
+
+    fun header(path: String): Int = FileInputStream(File(path)).use { input -> input.read() }
+
+    fun archive(id: String) {
+        try {
+            connection.prepareStatement("UPDATE invoices SET archived = 1 WHERE id = ?").use { statement ->
+                statement.setString(1, id)
+                statement.executeUpdate()
+            }
+        } catch (e: SQLException) {
+            log.log(Level.WARNING, "Invoice archive failed for $id", e)
+        }
+    }
+}
@@ -0,0 +1,64 @@
+package com.example.orders;
+
+import java.io.BufferedReader;
+import java.io.FileReader;
+import java.io.IOException;
+import java.io.UncheckedIOException;
+import java.sql.Connection;
+import java.sql.PreparedStatement;
+import java.sql.ResultSet;
+import java.sql.SQLException;
+import java.time.LocalDate;
+import java.time.format.DateTimeFormatter;
+import java.time.format.DateTimeParseException;
+import java.util.Optional;
+import java.util.logging.Level;
+import java.util.logging.Logger;
+
+public class OrderRepository {
+    private static final Logger LOG = Logger.getLogger(OrderRepository.class.getName());
+    // DateTimeFormatter is immutable and safe to share.
+    private static final DateTimeFormatter ORDER_DATE = DateTimeFormatter.ISO_LOCAL_DATE;
+
+    private final Connection connection;
+
+    public OrderRepository(Connection connection) {
+        this.connection = connection;
+    }
+
+    public Optional<LocalDate> parseOrderDate(String raw) {
+        try {
+            return Optional.of(LocalDate.parse(raw, ORDER_DATE));
+        } catch (DateTimeParseException e) {
+            LOG.log(Level.FINE, "Rejected order date", e);
+            return Optional.empty();
+        }
+    }
+
+    public long countByCustomer(String customerId) throws SQLException {
+        try (PreparedStatement statement = connection.prepareStatement(
+                "SELECT count(*) FROM orders WHERE customer_id = ?")) {
+            statement.setString(1, customerId);
+            try (ResultSet rows = statement.executeQuery()) {
+                return rows.next() ? rows.getLong(1) : 0L;
+            }
+        }
+    }
+
+    public String firstImportLine(String path) {
+        try (BufferedReader reader = new BufferedReader(new FileReader(path))) {
+            return reader.readLine();
+        } catch (IOException e) {
+            throw new UncheckedIOException(e);
+        }
+    }
+
+    public void deleteQuietly(String id) {
+        try (PreparedStatement statement = connection.prepareStatement("DELETE FROM orders WHERE id = ?")) {
+            statement.setString(1, id);
+            statement.executeUpdate();
+        } catch (SQLException e) {
+            LOG.log(Level.WARNING, "Order delete failed", e);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+async function persistDraft(id: string, body: string): Promise<void> {
+  const response = await fetch(`/api/drafts/${id}`, { method: "PUT", body });
+  if (!response.ok) {
+    throw new Error("draft save failed");
+  }
+}
+
+const notifyWatchers = async (id: string): Promise<void> => {
+  await fetch(`/api/drafts/${id}/notify`, { method: "POST" });
+};
+
+export class DraftEditor {
+  constructor(private readonly id: string) {}
+
+  async flush(body: string): Promise<void> {
+    await persistDraft(this.id, body);
+  }
+
+  async close(body: string): Promise<void> {
+    await this.flush(body);
+    void notifyWatchers(this.id).catch((error) => console.error("notify failed", error));
+  }
+}
+
+export async function saveAndExit(id: string, body: string): Promise<void> {
+  await persistDraft(id, body);
+  window.location.assign("/drafts");
+}
+
+export function saveInBackground(id: string, body: string): Promise<void> {
+  return persistDraft(id, body);
+}
@@ -0,0 +1,17 @@
+import { EventEmitter } from "node:events";
+
+async function shutdown(reason: string): Promise<void> {
+  await fetch("/api/sessions", { method: "DELETE" });
+  console.log(`stopped: ${reason}`);
+}
+
+export function watch(bus: EventEmitter): void {
+  bus.on("stop", (reason: string) => {
+    console.log("stop requested");
+    shutdown(reason);
+  });
+  process.on("uncaughtException", (error: Error) => {
+    console.error("uncaught", error);
+    shutdown("uncaught");
+  });
+}
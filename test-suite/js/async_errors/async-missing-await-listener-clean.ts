@@ -0,0 +1,20 @@
+import { EventEmitter } from "node:events";
+
+async function shutdown(reason: string): Promise<void> {
+  try {
+    await fetch("/api/sessions", { method: "DELETE" });
+  } finally {
+    console.log(`stopped: ${reason}`);
+  }
+}
+
+export function watch(bus: EventEmitter): void {
+  bus.on("stop", (reason: string) => {
+    console.log("stop requested");
+    void shutdown(reason);
+  });
+  process.on("uncaughtException", (error: Error) => {
+    console.error("uncaught", error);
+    shutdown("uncaught").catch((err) => console.error("shutdown failed", err));
+  });
+}
@@ -0,0 +1,16 @@
+async function syncInventory(sku: string): Promise<void> {
+  try {
+    await fetch(`/api/inventory/${sku}`, { method: "POST" });
+  } catch (error) {
+    console.error("inventory sync failed", error);
+    throw error;
+  }
+}
+
+export async function restock(sku: string): Promise<void> {
+  try {
+    syncInventory(sku);
+  } catch (error) {
+    console.error("restock failed", error);
+  }
+}
@@ -0,0 +1,11 @@
+async function shutdown(reason) {
+  await fetch('/api/sessions', { method: 'DELETE' });
+  console.log(`stopped: ${reason}`);
+}
+
+process.on('uncaughtException', (error) => {
+  console.error('Uncaught Exception:', error);
+  shutdown('UNCAUGHT_EXCEPTION');
+});
+
+module.exports = { shutdown };
@@ -0,0 +1,15 @@
+async function shutdown(reason) {
+  await fetch('/api/sessions', { method: 'DELETE' });
+  console.log(`stopped: ${reason}`);
+}
+
+process.on('uncaughtException', (error) => {
+  console.error('Uncaught Exception:', error);
+  shutdown('UNCAUGHT_EXCEPTION').catch((err) => console.error('Shutdown failed:', err));
+});
+
+process.on('SIGTERM', () => {
+  void shutdown('SIGTERM');
+});
+
+module.exports = { shutdown };
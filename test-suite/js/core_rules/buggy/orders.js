@@ -0,0 +1,37 @@
+// Core JS rule pack: each function trips one of the baseline checks.
+const _ = require('lodash');
+
+const defaults = { currency: 'USD', pageSize: 20 };
+
+async function recordAudit(event) {
+  const response = await fetch('/audit', {
+    method: 'POST',
+    body: JSON.stringify(event),
+    signal: AbortSignal.timeout(5000),
+  });
+  if (!response.ok) {
+    throw new Error('audit write failed');
+  }
+}
+
+function listOrders(query, render) {
+  if (query.limit == 0) {
+    return;
+  }
+  fetch('/api/orders', { signal: AbortSignal.timeout(5000) }).then((res) => render(res.status));
+}
+
+function applyPreferences(req) {
+  return _.merge({}, defaults, req.body);
+}
+
+function discountRate(req) {
+  return eval(req.body.formula);
+}
+
+async function cancelOrder(req) {
+  recordAudit({ type: 'cancel', id: req.params.id });
+  return { status: 204 };
+}
+
+module.exports = { listOrders, applyPreferences, discountRate, cancelOrder };
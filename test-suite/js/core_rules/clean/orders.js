@@ -0,0 +1,44 @@
+// Core JS rule pack counterpart: the same functions written defensively.
+const defaults = { currency: 'USD', pageSize: 20 };
+const DISCOUNT_RATES = { none: 0, staff: 0.2, partner: 0.1 };
+
+async function recordAudit(event) {
+  const response = await fetch('/audit', {
+    method: 'POST',
+    body: JSON.stringify(event),
+    signal: AbortSignal.timeout(5000),
+  });
+  if (!response.ok) {
+    throw new Error('audit write failed');
+  }
+}
+
+async function listOrders(query, render) {
+  if (Number(query.limit) === 0) {
+    return;
+  }
+  try {
+    const res = await fetch('/api/orders', { signal: AbortSignal.timeout(5000) });
+    render(res.status);
+  } catch (error) {
+    render(502);
+  }
+}
+
+function applyPreferences(input) {
+  return {
+    currency: typeof input.currency === 'string' ? input.currency : defaults.currency,
+    pageSize: Number.isInteger(input.pageSize) ? input.pageSize : defaults.pageSize,
+  };
+}
+
+function discountRate(tier) {
+  return Object.hasOwn(DISCOUNT_RATES, tier) ? DISCOUNT_RATES[tier] : 0;
+}
+
+async function cancelOrder(id) {
+  await recordAudit({ type: 'cancel', id });
+  return { status: 204 };
+}
+
+module.exports = { listOrders, applyPreferences, discountRate, cancelOrder };
@@ -0,0 +1,30 @@
+"""Core Python rule pack: each function trips one of the baseline checks."""
+
+import subprocess
+
+
+def add_tag(item, tags=[]):  # mutable default shared across calls
+    tags.append(item)
+    return tags
+
+
+def load_quantity(raw):
+    try:
+        return int(raw)
+    except:
+        return 0
+
+
+def archive(name):
+    # user-supplied name interpolated into a shell string
+    subprocess.run(f"tar czf /tmp/{name}.tgz data/", shell=True, check=True, timeout=30)
+
+
+def apply_formula():
+    expr = input("formula> ")
+    return eval(expr)
+
+
+def read_manifest(path):
+    handle = open(path, encoding="utf-8")
+    return handle.read()
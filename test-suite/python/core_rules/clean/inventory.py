@@ -0,0 +1,32 @@
+"""Core Python rule pack: the same operations written defensively."""
+
+import ast
+import subprocess
+
+
+def add_tag(item, tags=None):
+    if tags is None:
+        tags = []
+    tags.append(item)
+    return tags
+
+
+def load_quantity(raw):
+    try:
+        return int(raw)
+    except ValueError:
+        return 0
+
+
+def archive(name):
+    subprocess.run(["tar", "czf", f"/tmp/{name}.tgz", "data/"], check=True, timeout=30)
+
+
+def apply_formula():
+    expr = input("formula> ")
+    return ast.literal_eval(expr)
+
+
+def read_manifest(path):
+    with open(path, encoding="utf-8") as handle:
+        return handle.read()
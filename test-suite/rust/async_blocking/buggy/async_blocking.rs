@@ -0,0 +1,39 @@
+use std::fs::File;
+use std::io::{self, Read};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+async fn poll_with_backoff(attempts: u32) {
+    for _ in 0..attempts {
+        // Parks the runtime worker instead of yielding.
+        std::thread::sleep(Duration::from_millis(250));
+    }
+}
+
+async fn load_settings(path: &str) -> io::Result<String> {
+    let mut raw = String::new();
+    File::open(path)?.read_to_string(&mut raw)?;
+    std::fs::create_dir_all("cache")?;
+    Ok(raw)
+}
+
+async fn ping_upstream(host: &str) -> io::Result<()> {
+    let addrs: Vec<_> = (host, 443).to_socket_addrs()?.collect();
+    let _stream = TcpStream::connect(&addrs[..])?;
+    Ok(())
+}
+
+async fn prompt_user() -> io::Result<String> {
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    Ok(line)
+}
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    poll_with_backoff(3).await;
+    let _ = load_settings("settings.toml").await?;
+    ping_upstream("example.com").await?;
+    let _ = prompt_user().await?;
+    Ok(())
+}
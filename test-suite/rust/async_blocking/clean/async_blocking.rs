@@ -0,0 +1,61 @@
+use std::io;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::net::TcpStream;
+
+async fn poll_with_backoff(attempts: u32) {
+    for _ in 0..attempts {
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+}
+
+async fn load_settings(path: &str) -> io::Result<String> {
+    tokio::fs::create_dir_all("cache").await?;
+    tokio::fs::read_to_string(path).await
+}
+
+async fn load_legacy_settings(path: String) -> io::Result<String> {
+    // Blocking work is pushed onto the dedicated blocking pool.
+    tokio::task::spawn_blocking(move || std::fs::read_to_string(path))
+        .await
+        .map_err(io::Error::other)?
+}
+
+async fn ping_upstream(host: &str) -> io::Result<()> {
+    let _stream = TcpStream::connect((host, 443)).await?;
+    Ok(())
+}
+
+async fn listen_local() -> io::Result<()> {
+    let _listener = tokio::time::timeout(Duration::from_secs(1), tokio::net::TcpListener::bind("127.0.0.1:0"))
+        .await
+        .map_err(|_| io::Error::from(io::ErrorKind::TimedOut))??;
+    Ok(())
+}
+
+async fn prompt_user() -> io::Result<String> {
+    let mut line = String::new();
+    BufReader::new(tokio::io::stdin()).read_line(&mut line).await?;
+    Ok(line)
+}
+
+fn sync_cli_prompt() -> io::Result<String> {
+    // Synchronous functions may block freely.
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line)?;
+    std::thread::sleep(Duration::from_millis(10));
+    Ok(line)
+}
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    poll_with_backoff(3).await;
+    let _ = load_settings("settings.toml").await?;
+    let _ = load_legacy_settings("legacy.toml".to_string()).await?;
+    ping_upstream("example.com").await?;
+    listen_local().await?;
+    let _ = prompt_user().await?;
+    let _ = sync_cli_prompt()?;
+    Ok(())
+}
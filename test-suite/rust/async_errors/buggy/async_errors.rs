@@ -3,10 +3,15 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 
 async fn fetch_data() -> Result<String, &'static str> {
+    // A default client never gives up on a stalled peer.
+    let _client = reqwest::Client::new();
     Err("network")
 }
 
 async fn run() {
+    // Synchronous file I/O parks the executor thread while the disk responds.
+    let config = std::fs::read_to_string("config.toml").unwrap_or_default();
+    println!("{}", config.len());
     let body = fetch_data().await;
     println!("{:?}", body);
     let _ = fetch_data().await.unwrap();
@@ -14,6 +19,24 @@ async fn run() {
         let _ = fetch_data().await;
     });
     println!("spawned: {:?}", handle);
+    // Fire-and-forget: the JoinHandle is dropped immediately, so failures vanish.
+    tokio::spawn(async move {
+        fetch_data().await.unwrap();
+        std::fs::write("last_run", "done").ok();
+    });
+}
+
+async fn warm_cache() {
+    // Only `drain` awaits its handle; this `warmer` is never consumed.
+    let warmer = tokio::spawn(async move {
+        let _ = fetch_data().await;
+    });
+    println!("warming: {:?}", warmer);
+}
+
+async fn drain() {
+    let warmer = tokio::spawn(async move {});
+    let _ = warmer.await;
 }
 
 fn main() {
@@ -50,3 +73,13 @@ mod tokio {
         JoinHandle
     }
 }
+
+mod reqwest {
+    pub struct Client;
+
+    impl Client {
+        pub fn new() -> Self {
+            Client
+        }
+    }
+}
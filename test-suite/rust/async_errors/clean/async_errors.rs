@@ -1,12 +1,18 @@
 use std::future::Future;
-use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::pin::{pin, Pin};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
 
 async fn fetch_data() -> Result<String, &'static str> {
-    Ok(String::from("data"))
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build();
+    Ok(format!("data within {:?}", client.timeout))
 }
 
 async fn run() -> Result<(), &'static str> {
+    let config = tokio::fs::read_to_string("config.toml").await.unwrap_or_default();
+    println!("{}", config.len());
     match fetch_data().await {
         Ok(body) => println!("{}", body),
         Err(err) => {
@@ -20,16 +26,39 @@ async fn run() -> Result<(), &'static str> {
         }
     });
     let _ = handle.await;
+    tokio::spawn(async move { // ubs:ignore -- best-effort prefetch that reports its own failure
+        if let Err(err) = fetch_data().await {
+            eprintln!("prefetch failed: {err}");
+        }
+    });
     Ok(())
 }
 
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
 fn main() {
-    let _ = run();
+    if let Err(err) = block_on(run()) {
+        eprintln!("run failed: {err}");
+    }
 }
 
 mod tokio {
     use super::*;
 
+    pub mod fs {
+        pub async fn read_to_string(_path: &str) -> std::io::Result<String> {
+            Ok(String::new())
+        }
+    }
+
     pub struct JoinHandle;
 
     impl Future for JoinHandle {
@@ -51,3 +80,34 @@ mod tokio {
         JoinHandle
     }
 }
+
+mod reqwest {
+    use std::time::Duration;
+
+    pub struct Client {
+        pub timeout: Option<Duration>,
+    }
+
+    pub struct ClientBuilder {
+        timeout: Option<Duration>,
+    }
+
+    impl Client {
+        pub fn builder() -> ClientBuilder {
+            ClientBuilder { timeout: None }
+        }
+    }
+
+    impl ClientBuilder {
+        pub fn timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = Some(timeout);
+            self
+        }
+
+        pub fn build(self) -> Client {
+            Client {
+                timeout: self.timeout,
+            }
+        }
+    }
+}
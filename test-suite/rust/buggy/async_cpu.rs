@@ -0,0 +1,67 @@
+use std::io::Write;
+
+pub async fn register(password: String) -> String {
+    bcrypt::hash(&password, 12).unwrap_or_default()
+}
+
+pub async fn archive(payload: Vec<u8>) -> Vec<u8> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+    encoder.write_all(&payload).ok();
+    encoder.finish().unwrap_or_default()
+}
+
+pub async fn checksum(seed: u64) -> u64 {
+    let mut acc = seed;
+    for i in 0..5_000_000 {
+        acc = acc.wrapping_mul(31).wrapping_add(i);
+    }
+    acc
+}
+
+pub async fn export(rows: Vec<Vec<u32>>) -> Vec<String> {
+    let mut out = Vec::new();
+    for row in &rows {
+        out.push(serde_json::to_string(row).unwrap_or_default());
+    }
+    out
+}
+
+mod bcrypt {
+    pub fn hash(password: &str, cost: u32) -> Result<String, ()> {
+        Ok(format!("{password}{cost}"))
+    }
+}
+
+mod flate2 {
+    pub struct Compression;
+    impl Compression {
+        pub fn best() -> Self {
+            Compression
+        }
+    }
+    pub mod write {
+        pub struct GzEncoder<W>(pub W);
+        impl<W: std::io::Write> GzEncoder<W> {
+            pub fn new(inner: W, _level: super::Compression) -> Self {
+                GzEncoder(inner)
+            }
+            pub fn finish(self) -> std::io::Result<W> {
+                Ok(self.0)
+            }
+        }
+        impl<W: std::io::Write> std::io::Write for GzEncoder<W> {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                self.0.flush()
+            }
+        }
+    }
+}
+
+mod serde_json {
+    pub fn to_string<T: std::fmt::Debug>(value: &T) -> Result<String, ()> {
+        Ok(format!("{value:?}"))
+    }
+}
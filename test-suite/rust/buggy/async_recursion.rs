@@ -0,0 +1,31 @@
+use std::future::Future;
+use std::path::PathBuf;
+
+pub struct Node {
+    pub children: Vec<Node>,
+}
+
+pub async fn walk(dir: PathBuf, depth: usize) -> usize {
+    if depth == 0 {
+        return 1;
+    }
+    walk(dir.join("child"), depth - 1).await + 1
+}
+
+impl Node {
+    pub async fn visit(&self, level: u32) {
+        if level > 0 {
+            self.visit(level - 1).await;
+        }
+    }
+}
+
+pub fn retry(attempt: u32) -> impl Future<Output = u32> {
+    async move {
+        if attempt == 0 {
+            0
+        } else {
+            retry(attempt - 1).await
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+pub struct Client;
+
+impl Client {
+    pub async fn fetch(&self, key: &str) -> String {
+        key.to_string()
+    }
+}
+
+pub async fn refresh_all(cache: Arc<Mutex<HashMap<String, String>>>, client: Client, keys: Vec<String>) {
+    for key in keys {
+        let mut entries = cache.lock().unwrap();
+        let value = client.fetch(&key).await;
+        entries.insert(key, value);
+    }
+}
+
+pub async fn drain_queue(queue: Arc<RwLock<Vec<String>>>, client: Client) {
+    loop {
+        let mut pending = queue.write().unwrap();
+        let Some(next) = pending.pop() else {
+            break;
+        };
+        client.fetch(&next).await;
+    }
+}
+
+pub async fn poll_until_ready(state: Arc<Mutex<bool>>, client: Client) {
+    while !*state.lock().unwrap() {
+        let mut ready = state.lock().expect("state lock");
+        *ready = client.fetch("status").await == "ready";
+    }
+}
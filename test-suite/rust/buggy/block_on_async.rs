@@ -0,0 +1,49 @@
+pub struct Client {
+    runtime: tokio::runtime::Runtime,
+}
+
+impl Client {
+    fn fetch_sync(&self, key: &str) -> String {
+        self.runtime.block_on(lookup(key))
+    }
+
+    fn cached(&self, key: &str) -> String {
+        self.fetch_sync(key)
+    }
+
+    pub async fn handle(&self, key: &str) -> String {
+        self.cached(key)
+    }
+}
+
+pub async fn refresh() -> String {
+    let handle = tokio::runtime::Handle::current();
+    handle.block_on(lookup("refresh"))
+}
+
+pub async fn lookup(key: &str) -> String {
+    key.to_owned()
+}
+
+mod tokio {
+    pub mod runtime {
+        use std::future::Future;
+
+        pub struct Runtime;
+        impl Runtime {
+            pub fn block_on<F: Future>(&self, _future: F) -> F::Output {
+                unimplemented!()
+            }
+        }
+
+        pub struct Handle;
+        impl Handle {
+            pub fn current() -> Self {
+                Handle
+            }
+            pub fn block_on<F: Future>(&self, _future: F) -> F::Output {
+                unimplemented!()
+            }
+        }
+    }
+}
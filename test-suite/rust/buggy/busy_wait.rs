@@ -0,0 +1,29 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+pub fn wait_for_ready(ready: &AtomicBool) {
+    while !ready.load(Ordering::Acquire) {}
+}
+
+pub fn wait_for_shutdown(stop: &AtomicBool) -> u32 {
+    let mut polls = 0;
+    loop {
+        if stop.load(Ordering::Acquire) {
+            break;
+        }
+        polls += 1;
+    }
+    polls
+}
+
+pub fn grab(state: &Mutex<Vec<u8>>) -> usize {
+    while let Err(_) = state.try_lock() {}
+    state.lock().unwrap().len()
+}
+
+pub fn claim(owner: &AtomicBool) {
+    while owner
+        .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+        .is_err()
+    {}
+}
@@ -0,0 +1,28 @@
+pub fn is_palindrome(word: &str) -> bool {
+    let len = word.chars().count();
+    for i in 0..len / 2 {
+        if word.chars().nth(i) != word.chars().nth(len - 1 - i) {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn vowels(text: &str) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < text.chars().count() {
+        if matches!(text.chars().nth(i), Some('a' | 'e' | 'i' | 'o' | 'u')) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+pub fn every_other(text: &str) -> String {
+    (0..text.len())
+        .step_by(2)
+        .filter_map(|i| text.chars().nth(i))
+        .collect()
+}
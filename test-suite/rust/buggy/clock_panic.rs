@@ -0,0 +1,22 @@
+// Wall-clock arithmetic that unwraps, and Instant arithmetic that can
+// underflow, turn clock skew or early boot into a panic.
+use std::fs;
+use std::time::{Duration, Instant, SystemTime};
+
+pub fn age_of(path: &str) -> Duration {
+    let modified = fs::metadata(path).unwrap().modified().unwrap();
+    SystemTime::now().duration_since(modified).unwrap()
+}
+
+pub fn since(start: SystemTime) -> u64 {
+    start.elapsed().expect("clock went backwards").as_secs()
+}
+
+pub fn window_start(window: Duration) -> Instant {
+    Instant::now() - window
+}
+
+pub fn deadline_floor(timeout_secs: u64) -> Instant {
+    let now = Instant::now();
+    now - Duration::from_secs(timeout_secs)
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+pub struct Settings {
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct Window {
+    pub samples: [u64; 16],
+}
+
+pub fn matches(names: &[String], allow: Vec<String>) -> usize {
+    let mut hits = 0;
+    for name in names {
+        let allowed = allow.clone();
+        if allowed.contains(name) {
+            hits += 1;
+        }
+    }
+    hits
+}
+
+pub fn lookup(keys: &[&str], table: &HashMap<String, u32>) -> u32 {
+    let mut total = 0;
+    for key in keys {
+        total += table.clone().get(*key).copied().unwrap_or(0);
+    }
+    total
+}
+
+pub fn report(settings: &Settings, rows: &[u32]) {
+    for row in rows {
+        let label = settings.name.clone();
+        println!("{label}: {row}");
+    }
+}
+
+pub fn peaks(window: Window, rounds: usize) -> u64 {
+    let mut best = 0;
+    for _ in 0..rounds {
+        let snapshot = window.clone();
+        best = best.max(snapshot.samples[0]);
+    }
+    best
+}
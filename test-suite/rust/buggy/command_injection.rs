@@ -0,0 +1,41 @@
+use std::io::BufRead;
+use std::process::Command;
+
+pub struct Query<T>(pub T);
+
+pub fn archive(dir: &str) -> std::io::Result<()> {
+    // The directory name is spliced into a shell script: `x; rm -rf ~` runs.
+    Command::new("sh")
+        .arg("-c")
+        .arg(format!("tar czf backup.tgz {dir}"))
+        .status()?;
+    Ok(())
+}
+
+pub fn grep_logs(Query(pattern): Query<String>) -> std::io::Result<()> {
+    let script = format!("grep '{}' /var/log/app.log", pattern);
+    let mut cmd = Command::new("bash");
+    cmd.arg("-lc").arg(&script);
+    cmd.status()?;
+    Ok(())
+}
+
+pub fn convert(user_input: &str) -> std::io::Result<()> {
+    Command::new("convert")
+        .args(["-resize", &format!("{}x{}", user_input, user_input)])
+        .status()?;
+    Ok(())
+}
+
+pub fn ping_from_stdin() -> std::io::Result<()> {
+    let mut host = String::new();
+    std::io::stdin().lock().read_line(&mut host)?;
+    Command::new("cmd").args(["/C", &format!("ping {}", host.trim())]).status()?;
+    Ok(())
+}
+
+pub fn clone_repo() -> std::io::Result<()> {
+    let url = std::env::args().nth(1).unwrap_or_default();
+    Command::new("git").arg(format!("--upload-pack={url}")).status()?;
+    Ok(())
+}
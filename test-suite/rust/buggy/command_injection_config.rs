@@ -0,0 +1,19 @@
+// Framework-specific inputs and spawners are only known through
+// --taint-sources=ctx.input,Data and --command-sinks=duct::cmd.
+pub struct Ctx;
+pub struct Data<T>(pub T);
+
+impl Ctx {
+    pub fn input(&self, _name: &str) -> String {
+        String::new()
+    }
+}
+
+pub fn list(ctx: &Ctx) {
+    let dir = ctx.input("dir");
+    duct::cmd("sh", &["-c", &format!("ls {}", dir)]).run().ok();
+}
+
+pub fn greet(Data(name): Data<String>) {
+    std::process::Command::new("banner").arg(format!("--text={name}")).status().ok();
+}
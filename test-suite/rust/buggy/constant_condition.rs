@@ -0,0 +1,36 @@
+// Conditions whose outcome is fixed at compile time usually hide a typo or
+// a wrong variable.
+pub struct Window {
+    pub width: u32,
+    pub height: u32,
+}
+
+pub fn remaining(budget: usize, spent: usize) -> usize {
+    if budget >= 0 {
+        budget.saturating_sub(spent)
+    } else {
+        0
+    }
+}
+
+pub fn has_items(items: &[u8]) -> bool {
+    items.len() < 0
+}
+
+pub fn same_size(a: &Window, b: &Window) -> bool {
+    a.width == a.width && a.height == b.height
+}
+
+pub fn in_range(code: i32) -> bool {
+    if code > 500 && code < 400 {
+        return true;
+    }
+    false
+}
+
+pub fn retry(status: u16) -> bool {
+    if status != 429 || status != 503 {
+        return true;
+    }
+    false
+}
@@ -0,0 +1,45 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+extern "C" {
+    fn puts(s: *const c_char) -> i32;
+    fn getenv(name: *const c_char) -> *const c_char;
+}
+
+pub fn greet(name: &str) {
+    // Panics if the user-supplied name contains an interior NUL.
+    let owned = CString::new(name).unwrap();
+    unsafe { puts(owned.as_ptr()) };
+}
+
+pub fn dangling(path: &str) -> *const c_char {
+    // The CString temporary is freed at the end of this statement.
+    let ptr = CString::new(path).expect("no NUL").as_ptr();
+    ptr
+}
+
+pub struct Request {
+    pub url: *const c_char,
+}
+
+pub fn build(url: String) -> Request {
+    Request {
+        url: CString::new(url).unwrap().as_ptr(),
+    }
+}
+
+pub unsafe fn read_name(raw: *const c_char) -> String {
+    CStr::from_ptr(raw).to_string_lossy().into_owned()
+}
+
+pub fn home() -> String {
+    unsafe {
+        let value = getenv(CString::new("HOME").unwrap().as_ptr());
+        CStr::from_ptr(value).to_string_lossy().into_owned()
+    }
+}
+
+pub fn shell() -> String {
+    let key = CString::new("SHELL").expect("static key");
+    unsafe { CStr::from_ptr(libc::getenv(key.as_ptr())).to_string_lossy().into_owned() }
+}
@@ -0,0 +1,30 @@
+// Recursive deletion of paths that can collapse to "" or "/", or that are
+// assembled from request and environment values without any validation.
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+pub fn clear_cache() -> io::Result<()> {
+    let root = env::var("CACHE_ROOT").unwrap_or_default();
+    let cache = format!("{root}/");
+    fs::remove_dir_all(cache)
+}
+
+pub fn wipe_workspace(name: &str) -> io::Result<()> {
+    let base = env::var("WORKSPACE").unwrap_or_else(|_| String::new());
+    let dir = PathBuf::from(base).join(name);
+    fs::remove_dir_all(&dir)
+}
+
+pub fn delete_upload(upload_dir: &str, file_name: &str) -> io::Result<()> {
+    let target = PathBuf::from(upload_dir).join(file_name);
+    fs::remove_file(target)
+}
+
+pub fn drop_user_data() -> io::Result<()> {
+    let user = env::args().nth(1).unwrap_or_else(|| String::from("guest"));
+    let mut dir = PathBuf::from("/srv/data");
+    dir.push(&user);
+    fs::remove_dir_all(dir)
+}
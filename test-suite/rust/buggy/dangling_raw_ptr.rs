@@ -0,0 +1,27 @@
+pub fn after_drop() -> u32 {
+    let value = Box::new(7u32);
+    let ptr = &*value as *const u32;
+    drop(value);
+    // SAFETY: none - the box is already freed.
+    unsafe { *ptr }
+}
+
+pub fn after_move() -> u8 {
+    let bytes = vec![1u8, 2, 3];
+    let first = bytes.as_ptr();
+    let moved = bytes;
+    println!("{}", moved.len());
+    drop(moved);
+    // SAFETY: none - `bytes` was moved out and freed.
+    unsafe { first.read() }
+}
+
+pub fn after_scope() -> i32 {
+    let ptr;
+    {
+        let local = 42i32;
+        ptr = &local as *const i32;
+    }
+    // SAFETY: none - `local` went out of scope above.
+    unsafe { *ptr }
+}
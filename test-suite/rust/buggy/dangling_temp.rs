@@ -0,0 +1,39 @@
+// Pointers and borrows taken from owned temporaries outlive the value: the
+// Vec/String is dropped at the end of the statement that created it.
+pub struct Request {
+    pub data: *const u8,
+    pub len: usize,
+}
+
+extern "C" {
+    fn submit(data: *const u8, len: usize);
+}
+
+pub fn header_ptr(header: &[u8]) -> *const u8 {
+    let ptr = header.to_vec().as_ptr();
+    ptr
+}
+
+pub fn build_request(payload: &str) -> Request {
+    Request {
+        data: payload.to_string().as_ptr(),
+        len: payload.len(),
+    }
+}
+
+pub fn send_literal() {
+    let data = vec![1u8, 2, 3].as_ptr();
+    unsafe { submit(data, 3) };
+}
+
+pub fn joined(parts: &[String]) -> *const u8 {
+    return parts.iter().map(|p| p.to_uppercase()).collect::<String>().as_ptr();
+}
+
+pub fn label(id: u32) -> &'static str {
+    format!("job-{id}").as_str()
+}
+
+pub fn normalised(input: &str) -> &str {
+    return input.to_lowercase().as_str();
+}
@@ -0,0 +1,37 @@
+use std::sync::mpsc::Receiver;
+use std::thread;
+
+pub fn start_indexer(paths: Vec<String>) {
+    thread::spawn(move || {
+        for path in paths {
+            let _ = std::fs::metadata(&path).unwrap();
+        }
+    });
+}
+
+pub fn start_flusher(rx: Receiver<Vec<u8>>) {
+    let flusher = thread::spawn(move || {
+        for chunk in rx {
+            std::fs::write("journal.bin", chunk).expect("flush failed");
+        }
+    });
+    println!("flusher started: {:?}", flusher.thread().id());
+}
+
+pub fn start_named_worker() -> std::io::Result<()> {
+    thread::Builder::new()
+        .name("compactor".into())
+        .spawn(|| compact_segments())?;
+    Ok(())
+}
+
+pub fn start_heartbeat() {
+    let _guard = std::thread::spawn(|| loop {
+        thread::sleep(std::time::Duration::from_secs(5));
+        send_heartbeat();
+    });
+}
+
+fn compact_segments() {}
+
+fn send_heartbeat() {}
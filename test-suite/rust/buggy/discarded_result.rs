@@ -0,0 +1,38 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::sync::mpsc;
+use std::thread;
+
+fn save_state(path: &str, body: &str) -> io::Result<()> {
+    fs::write(path, body)
+}
+
+fn notify(tx: &mpsc::Sender<u32>) {
+    let _ = tx.send(7);
+}
+
+struct Journal {
+    file: File,
+}
+
+impl Journal {
+    fn append(&mut self, line: &str) -> io::Result<()> {
+        writeln!(self.file, "{line}")
+    }
+
+    fn record(&mut self, line: &str) {
+        let _ = self.append(line);
+        let _ = self.file.flush();
+    }
+}
+
+fn main() {
+    let _ = save_state("state.json", "{}");
+    let _ = fs::remove_file("stale.lock");
+    let _ = File::create("marker");
+    let worker = thread::spawn(|| 42);
+    let _ = worker.join();
+    let _ = "8080".parse::<u16>();
+    let state = std::sync::Mutex::new(0u32);
+    let _ = state.lock();
+}
@@ -0,0 +1,25 @@
+pub fn average(samples: &[u64]) -> u64 {
+    let total: u64 = samples.iter().sum();
+    // Panics on an empty slice.
+    total / samples.len() as u64
+}
+
+pub fn mean_latency(latencies: &[u32]) -> u32 {
+    let count = latencies.len() as u32;
+    latencies.iter().sum::<u32>() / count
+}
+
+pub fn bucket_for(hash: u64, buckets: u64) -> u64 {
+    // Panics when the caller passes zero buckets.
+    hash % buckets
+}
+
+pub fn per_page(total: usize, arg: &str) -> usize {
+    let page_size: usize = arg.parse().unwrap_or(0);
+    total / page_size
+}
+
+pub fn scale(mut value: i64, divisor: i64) -> i64 {
+    value /= divisor;
+    value
+}
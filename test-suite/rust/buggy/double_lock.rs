@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+
+pub struct Cache {
+    entries: Mutex<HashMap<String, u64>>,
+    stats: RwLock<u64>,
+}
+
+impl Cache {
+    pub fn bump(&self, key: &str) {
+        let mut entries = self.entries.lock().unwrap();
+        *entries.entry(key.to_string()).or_insert(0) += 1;
+        let total: u64 = self.entries.lock().unwrap().values().sum();
+        println!("{total}");
+    }
+
+    pub fn lookup(&self, key: &str) -> u64 {
+        if let Some(hit) = self.entries.lock().unwrap().get(key) {
+            *hit
+        } else {
+            self.entries.lock().unwrap().insert(key.to_string(), 0);
+            0
+        }
+    }
+
+    pub fn record(&self) {
+        let _read = self.stats.read().unwrap();
+        *self.stats.write().unwrap() += 1;
+    }
+
+    pub fn both(&self) -> usize {
+        self.entries.lock().unwrap().len() + self.entries.lock().unwrap().capacity()
+    }
+}
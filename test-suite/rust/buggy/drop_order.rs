@@ -0,0 +1,43 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::sync::Mutex;
+
+pub struct Connection;
+pub struct Statement<'conn>(&'conn Connection);
+
+impl Connection {
+    pub fn prepare(&self, _sql: &str) -> Statement<'_> {
+        Statement(self)
+    }
+}
+
+/// The statement is finalized against `conn`, but fields drop in declaration
+/// order, so `conn` is closed first.
+pub struct CachedQuery {
+    conn: Box<Connection>,
+    stmt: Statement<'static>,
+}
+
+impl CachedQuery {
+    pub fn new(conn: Box<Connection>) -> Self {
+        let raw: *const Connection = &*conn;
+        let stmt = unsafe { (*raw).prepare("SELECT 1") };
+        Self { conn, stmt }
+    }
+}
+
+static JOURNAL_LOCK: Mutex<()> = Mutex::new(());
+
+pub fn append_journal(path: &str, line: &str) -> std::io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    let _guard = JOURNAL_LOCK.lock().unwrap();
+    writeln!(out, "{line}")?;
+    Ok(())
+}
+
+pub async fn fetch_limited(limiter: &tokio::sync::Semaphore, addr: &str) -> std::io::Result<()> {
+    let mut conn = std::net::TcpStream::connect(addr)?;
+    let _permit = limiter.acquire().await;
+    conn.write_all(b"ping")?;
+    Ok(())
+}
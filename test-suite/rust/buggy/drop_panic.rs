@@ -0,0 +1,37 @@
+use std::fs::File;
+use std::io::Write;
+
+pub struct Journal {
+    file: File,
+    pending: Vec<String>,
+}
+
+impl Journal {
+    fn flush_pending(&mut self) {
+        for entry in self.pending.drain(..) {
+            self.file.write_all(entry.as_bytes()).unwrap();
+        }
+    }
+}
+
+impl Drop for Journal {
+    fn drop(&mut self) {
+        // Panics here while unwinding abort the whole process.
+        self.flush_pending();
+        let last = &self.pending[0];
+        self.file.write_all(last.as_bytes()).expect("journal write");
+    }
+}
+
+pub struct Slot {
+    ids: Vec<u32>,
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        let first = self.ids[0];
+        if first == 0 {
+            panic!("slot released twice");
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::time::{Duration, Instant};
+
+pub struct RetryConfig {
+    pub backoff_ms: u64,
+    pub timeout_secs: u64,
+    pub ttl: u64,
+}
+
+pub fn backoff(config: &RetryConfig) -> Duration {
+    Duration::from_secs(config.backoff_ms)
+}
+
+pub fn request_timeout(config: &RetryConfig) -> Duration {
+    Duration::from_millis(config.timeout_secs)
+}
+
+pub fn poll_interval(interval_ms: u64) -> Duration {
+    Duration::from_secs(interval_ms)
+}
+
+pub fn rescale(elapsed: Duration) -> Duration {
+    Duration::from_secs(elapsed.as_millis() as u64)
+}
+
+pub fn timed_out(started: Instant, config: &RetryConfig) -> bool {
+    started.elapsed().as_millis() > config.timeout_secs as u128
+}
+
+pub fn expired(age: Duration, config: &RetryConfig) -> bool {
+    age.as_secs() >= config.ttl
+}
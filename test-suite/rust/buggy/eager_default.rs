@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Settings {
+    pub name: String,
+    pub retries: u32,
+}
+
+fn load_defaults() -> Settings {
+    let raw = std::fs::read_to_string("/etc/app/defaults.toml").unwrap_or_default();
+    Settings { name: raw, retries: 3 }
+}
+
+fn missing(key: &str) -> std::io::Error {
+    eprintln!("lookup failed for {key}");
+    std::io::Error::new(std::io::ErrorKind::NotFound, key.to_string())
+}
+
+pub fn settings(cache: &HashMap<String, Settings>, key: &str) -> Settings {
+    let found = cache.get(key).map(|s| Settings { name: s.name.clone(), retries: s.retries });
+    found.unwrap_or(load_defaults())
+}
+
+pub fn require(cache: &HashMap<String, u32>, key: &str) -> std::io::Result<u32> {
+    cache.get(key).copied().ok_or(missing(key))
+}
+
+pub fn label(name: Option<&str>, id: u32) -> String {
+    name.map_or(format!("user-{id}"), |n| n.to_uppercase())
+}
+
+pub fn title(name: Option<String>) -> String {
+    name.unwrap_or("anonymous".to_string())
+}
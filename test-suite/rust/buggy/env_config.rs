@@ -0,0 +1,27 @@
+use std::env;
+
+const PORT_VAR: &str = "APP_PORT";
+
+pub struct Config {
+    pub database_url: String,
+    pub port: u16,
+    pub region: String,
+}
+
+pub fn load() -> Config {
+    let database_url = env::var("DATABASE_URL").unwrap();
+    let port = std::env::var(PORT_VAR)
+        .expect("APP_PORT must be set")
+        .parse()
+        .unwrap_or(8080);
+    let region = env::var_os("AWS_REGION").unwrap().to_string_lossy().into_owned();
+    Config { database_url, port, region }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn reads_test_env() {
+        let _ = std::env::var("TEST_ONLY_VAR").unwrap();
+    }
+}
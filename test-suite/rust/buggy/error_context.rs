@@ -0,0 +1,26 @@
+use std::error::Error;
+use std::fs;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("config unavailable")]
+    Generic,
+    #[error("cannot read config: {0}")]
+    Read(std::io::Error),
+    #[error("bad config at {path}")]
+    Parse { path: String, cause: toml::de::Error },
+}
+
+pub fn read(path: &str) -> Result<String, ConfigError> {
+    fs::read_to_string(path).map_err(|_| ConfigError::Generic)
+}
+
+pub fn port(raw: &str) -> Result<u16, ConfigError> {
+    raw.trim().parse::<u16>().map_err(|_err| ConfigError::Generic)
+}
+
+pub fn load(path: &str) -> Result<toml::Value, Box<dyn Error>> {
+    let body = fs::read_to_string(path).map_err(|e| format!("reading {path}: {e}"))?;
+    let value = body.parse::<toml::Value>().map_err(|e| e.to_string())?;
+    Ok(value)
+}
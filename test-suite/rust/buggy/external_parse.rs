@@ -0,0 +1,34 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+pub fn port_from_args() -> u16 {
+    let args: Vec<String> = env::args().collect();
+    args[1].parse::<u16>().unwrap()
+}
+
+pub fn read_count() -> usize {
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).expect("stdin");
+    line.trim().parse().expect("a number")
+}
+
+pub fn load_limits(path: &str) -> Vec<u32> {
+    let content = fs::read_to_string(path).unwrap_or_default();
+    content.lines().map(|l| l.parse::<u32>().unwrap()).collect()
+}
+
+pub fn sum_stdin() -> i64 {
+    let mut total = 0;
+    for line in io::stdin().lock().lines() {
+        let line = line.unwrap_or_default();
+        total += i64::from_str(line.trim()).unwrap();
+    }
+    total
+}
+
+pub async fn fetch_version(client: &reqwest::Client) -> u32 {
+    let body = client.get("https://example.com/version").send().await.unwrap().text().await.unwrap();
+    body.trim().parse().unwrap()
+}
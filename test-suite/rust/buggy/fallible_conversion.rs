@@ -0,0 +1,26 @@
+// Conversions that fail on real inputs are unwrapped instead of reported.
+use std::convert::TryFrom;
+use std::num::NonZeroUsize;
+
+pub struct Frame {
+    pub len: u32,
+}
+
+pub fn frame_for(payload: &[u8]) -> Frame {
+    Frame {
+        len: u32::try_from(payload.len()).unwrap(),
+    }
+}
+
+pub fn header(bytes: &[u8]) -> [u8; 4] {
+    bytes[..4].try_into().expect("short header")
+}
+
+pub fn pool_size(workers: usize) -> NonZeroUsize {
+    NonZeroUsize::new(workers).unwrap()
+}
+
+pub fn port(raw: i64) -> u16 {
+    let port: u16 = raw.try_into().unwrap();
+    port
+}
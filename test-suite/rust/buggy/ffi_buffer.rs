@@ -0,0 +1,40 @@
+use std::ptr;
+use std::slice;
+
+pub struct Frame {
+    pub header_len: usize,
+    pub payload: Vec<u8>,
+}
+
+pub fn payload_view(frame: &Frame) -> &[u8] {
+    // The pointer comes from `payload`, the length from the header.
+    unsafe { slice::from_raw_parts(frame.payload.as_ptr(), frame.header_len) }
+}
+
+pub fn mirror<'a>(input: &[u8], scratch: &'a mut Vec<u8>) -> &'a [u8] {
+    let ptr = scratch.as_ptr();
+    let count = input.len();
+    unsafe { slice::from_raw_parts(ptr, count) }
+}
+
+/// # Safety
+/// `data` must point to a live buffer.
+pub unsafe extern "C" fn checksum(data: *const u8, header: *const u32) -> u32 {
+    let declared = *header as usize;
+    let bytes = slice::from_raw_parts(data, declared);
+    bytes.iter().map(|b| u32::from(*b)).sum()
+}
+
+pub fn restore(src: &mut [u8], snapshot: &[u8]) {
+    let dst = snapshot.as_ptr() as *mut u8;
+    unsafe {
+        ptr::copy_nonoverlapping(dst, src.as_mut_ptr(), snapshot.len().min(src.len()));
+    }
+}
+
+pub fn fill(out: &mut [u8], input: &[u8]) {
+    let n = out.len().min(input.len());
+    unsafe {
+        ptr::copy_nonoverlapping(out.as_mut_ptr(), input.as_ptr() as *mut u8, n);
+    }
+}
@@ -0,0 +1,26 @@
+pub struct Account {
+    pub balance: f64,
+    pub limit: f64,
+}
+
+pub fn is_settled(account: &Account) -> bool {
+    account.balance == account.limit
+}
+
+pub fn same_ratio(a: f32, b: f32) -> bool {
+    a / b != 0.75
+}
+
+pub fn tripled(price: f64) -> bool {
+    let total = price * 3.0;
+    total == 0.3
+}
+
+pub fn is_empty_reading(value: f64) -> bool {
+    value == 0.0
+}
+
+pub fn check_rate(input: &str) {
+    let rate = input.parse::<f64>().unwrap_or_default();
+    assert_eq!(rate, 1.5);
+}
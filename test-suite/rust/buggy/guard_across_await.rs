@@ -0,0 +1,33 @@
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+async fn flush(count: u64) {
+    tokio::time::sleep(Duration::from_millis(count)).await;
+}
+
+async fn bump_and_flush(counter: Arc<Mutex<u64>>) {
+    let mut guard = counter.lock().unwrap();
+    *guard += 1;
+    // The std guard is still alive here: other tasks block on the mutex.
+    flush(*guard).await;
+}
+
+async fn read_config(config: Arc<RwLock<String>>) -> usize {
+    let snapshot = config.read().expect("config lock");
+    let len = snapshot.len();
+    tokio::task::yield_now().await;
+    len
+}
+
+fn spawn_block(counter: Arc<Mutex<u64>>) {
+    tokio::spawn(async move {
+        let guard = counter.lock().unwrap();
+        flush(*guard).await;
+    });
+}
+
+async fn tokio_guard(state: Arc<tokio::sync::Mutex<Vec<u8>>>) {
+    let mut buffer = state.lock().await;
+    buffer.push(1);
+    flush(buffer.len() as u64).await;
+}
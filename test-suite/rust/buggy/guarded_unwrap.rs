@@ -0,0 +1,53 @@
+struct Cursor {
+    next: Option<usize>,
+}
+
+fn either_guard(value: Option<i32>, fallback: bool) -> i32 {
+    if value.is_some() || fallback {
+        return value.unwrap() * 2;
+    }
+    0
+}
+
+fn loop_either_guard(mut cursor: Cursor, retry: bool) -> usize {
+    let mut total = 0;
+    while cursor.next.is_some() || retry {
+        total += cursor.next.unwrap();
+        cursor.next = None;
+    }
+    total
+}
+
+fn conjoined_early_exit(value: Option<u64>, strict: bool) -> u64 {
+    if value.is_none() && strict {
+        return 0;
+    }
+    value.unwrap()
+}
+
+fn reassigned_after_guard(mut value: Option<u8>) -> u8 {
+    if value.is_some() {
+        value = None;
+        return value.expect("cleared above");
+    }
+    0
+}
+
+fn reassigned_after_early_exit(mut resp: Result<u32, String>) -> u32 {
+    if resp.is_err() {
+        return 0;
+    }
+    resp = Err("retry".to_string());
+    resp.unwrap()
+}
+
+fn main() {
+    println!(
+        "{} {} {} {} {}",
+        either_guard(None, true),
+        loop_either_guard(Cursor { next: Some(1) }, false),
+        conjoined_early_exit(None, false),
+        reassigned_after_guard(Some(1)),
+        reassigned_after_early_exit(Ok(1))
+    );
+}
@@ -0,0 +1,31 @@
+// HashMap/HashSet iteration order is randomized per process, so anything
+// derived from it changes from run to run.
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::hash::{Hash, Hasher};
+
+pub fn render(counts: &HashMap<String, u32>) -> String {
+    let mut out = String::new();
+    for (word, count) in counts {
+        writeln!(out, "{word}: {count}").unwrap();
+    }
+    out
+}
+
+pub fn primary_owner(owners: &HashSet<String>) -> Option<&String> {
+    owners.iter().next()
+}
+
+pub fn cache_key(params: &HashMap<String, String>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for (key, value) in params.iter() {
+        key.hash(&mut hasher);
+        value.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+pub fn header(tags: &HashSet<String>) -> String {
+    tags.iter().cloned().collect::<Vec<_>>().join(",")
+}
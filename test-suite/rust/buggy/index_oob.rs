@@ -0,0 +1,30 @@
+pub fn pick(items: &[String], choice: &str) -> String {
+    let index: usize = choice.trim().parse().unwrap_or(0);
+    // Panics when the user types a number >= items.len().
+    items[index].clone()
+}
+
+pub fn newest(events: &[u64]) -> u64 {
+    // Underflows (and panics) on an empty slice.
+    events[events.len() - 1]
+}
+
+pub fn tail(bytes: &[u8]) -> u8 {
+    let len = bytes.len();
+    bytes[len - 1]
+}
+
+pub fn sum_all(values: &[i32]) -> i32 {
+    let mut total = 0;
+    // `..=` walks one past the end.
+    for i in 0..=values.len() {
+        total += values[i];
+    }
+    total
+}
+
+pub fn window(data: &[u8], start: &str, end: &str) -> &[u8] {
+    let from: usize = start.parse().unwrap_or(0);
+    let to: usize = end.parse().unwrap_or(0);
+    &data[from..to]
+}
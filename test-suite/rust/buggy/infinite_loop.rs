@@ -0,0 +1,39 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::Receiver;
+use std::thread;
+
+pub fn drain_queue(queue: &mut VecDeque<u32>) -> u32 {
+    let mut total = 0;
+    loop {
+        if let Some(item) = queue.pop_front() {
+            total += item;
+        }
+    }
+}
+
+pub fn retry_connect(attempts: &mut u32) {
+    loop {
+        *attempts += 1;
+        for _ in 0..3 {
+            if *attempts > 10 {
+                break;
+            }
+        }
+    }
+}
+
+pub fn forward(rx: Receiver<String>) {
+    thread::spawn(move || loop {
+        let line = rx.recv().unwrap_or_default();
+        println!("{line}");
+    });
+}
+
+pub fn spawn_ticker() {
+    thread::Builder::new()
+        .name("metrics-worker".into())
+        .spawn(|| loop {
+            thread::park();
+        })
+        .expect("spawn metrics worker");
+}
@@ -0,0 +1,44 @@
+// Removing from or inserting into a collection while walking it shifts the
+// remaining elements, so entries are skipped or the index runs past the end.
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+pub fn drop_expired(sessions: &mut Vec<u64>, now: u64) {
+    for i in 0..sessions.len() {
+        if sessions[i] < now {
+            sessions.remove(i);
+        }
+    }
+}
+
+pub fn drop_empty(lines: &mut Vec<String>) {
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].is_empty() {
+            lines.remove(i);
+        }
+        i += 1;
+    }
+}
+
+pub fn expand(counts: &RefCell<HashMap<String, u32>>) {
+    for (key, value) in counts.borrow().iter() {
+        if *value > 10 {
+            counts.borrow_mut().insert(format!("{key}-overflow"), 0);
+        }
+    }
+}
+
+pub struct Queue {
+    items: Vec<u32>,
+}
+
+impl Queue {
+    pub fn prune(&mut self) {
+        for i in 0..self.items.len() {
+            if self.items[i] == 0 {
+                self.items.swap_remove(i);
+            }
+        }
+    }
+}
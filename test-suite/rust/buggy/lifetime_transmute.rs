@@ -0,0 +1,23 @@
+use std::mem;
+
+pub struct Parser<'a> {
+    input: &'a str,
+}
+
+pub fn leak_name(name: &str) -> &'static str {
+    unsafe { mem::transmute(name) }
+}
+
+pub fn extend<'a>(value: &'a [u8]) -> &'static [u8] {
+    unsafe { std::mem::transmute::<&'a [u8], &'static [u8]>(value) }
+}
+
+pub fn detach<'a>(parser: Parser<'a>) -> Parser<'static> {
+    unsafe { mem::transmute::<Parser<'a>, Parser<'static>>(parser) }
+}
+
+impl<'a> Parser<'a> {
+    pub fn rest(&self) -> &str {
+        self.input
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Mutex;
+
+pub struct Bank {
+    accounts: Mutex<Vec<u64>>,
+    audit: Mutex<Vec<String>>,
+}
+
+impl Bank {
+    // Takes accounts, then audit.
+    pub fn transfer(&self, from: usize, to: usize, amount: u64) {
+        let mut accounts = self.accounts.lock().unwrap();
+        accounts[from] -= amount;
+        accounts[to] += amount;
+        let mut audit = self.audit.lock().unwrap();
+        audit.push(format!("{from}->{to}: {amount}"));
+    }
+
+    // Takes audit, then accounts: inverted against transfer().
+    pub fn report(&self) -> String {
+        let audit = self.audit.lock().unwrap();
+        let accounts = self.accounts.lock().unwrap();
+        format!("{} entries, {} accounts", audit.len(), accounts.len())
+    }
+}
+
+pub struct Cache {
+    index: Mutex<Vec<u32>>,
+    store: Mutex<Vec<u32>>,
+}
+
+impl Cache {
+    fn touch_store(&self) {
+        self.store.lock().unwrap().push(1);
+    }
+
+    fn touch_index(&self) {
+        self.index.lock().unwrap().push(1);
+    }
+
+    // Holds index while touch_store() takes store.
+    pub fn insert(&self) {
+        let _index = self.index.lock().unwrap();
+        self.touch_store();
+    }
+
+    // Holds store while touch_index() takes index.
+    pub fn evict(&self) {
+        let _store = self.store.lock().unwrap();
+        self.touch_index();
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+
+struct Registry {
+    hits: Mutex<u64>,
+    routes: RwLock<HashMap<String, String>>,
+}
+
+impl Registry {
+    fn record_hit(&self) {
+        // A panic in any other holder poisons the mutex; this unwrap then panics forever.
+        let mut hits = self.hits.lock().unwrap();
+        *hits += 1;
+    }
+
+    fn lookup(&self, path: &str) -> Option<String> {
+        self.routes.read().unwrap().get(path).cloned()
+    }
+
+    fn register(&self, path: &str, target: &str) {
+        self.routes.write().unwrap().insert(path.to_string(), target.to_string());
+    }
+}
+
+fn main() {
+    let registry = Registry {
+        hits: Mutex::new(0),
+        routes: RwLock::new(HashMap::new()),
+    };
+    registry.register("/", "index");
+    registry.record_hit();
+    let _ = registry.lookup("/");
+}
@@ -0,0 +1,29 @@
+use regex::{Regex, RegexSet};
+
+pub fn count_matches(lines: &[String]) -> usize {
+    lines
+        .iter()
+        .filter(|line| Regex::new(r"^\d{4}-\d{2}-\d{2}").unwrap().is_match(line))
+        .count()
+}
+
+pub fn fetch_all(urls: &[String]) -> Vec<String> {
+    let mut bodies = Vec::new();
+    for url in urls {
+        let client = reqwest::blocking::Client::new();
+        if let Ok(resp) = client.get(url).send() {
+            bodies.push(resp.text().unwrap_or_default());
+        }
+    }
+    bodies
+}
+
+pub fn classify(inputs: &[&str]) -> Vec<bool> {
+    inputs
+        .iter()
+        .map(|input| {
+            let set = RegexSet::new([r"error", r"panic"]).unwrap();
+            set.is_match(input)
+        })
+        .collect()
+}
@@ -0,0 +1,27 @@
+pub struct Packet {
+    total_len: u64,
+    offset: i64,
+}
+
+pub fn header_len(packet: &Packet) -> u32 {
+    // Drops the high 32 bits for packets over 4 GiB.
+    packet.total_len as u32
+}
+
+pub fn port(raw: usize) -> u16 {
+    raw as u16
+}
+
+pub fn seek_position(packet: &Packet) -> u64 {
+    // A negative offset becomes a huge unsigned position.
+    packet.offset as u64
+}
+
+pub fn signed_size(size: u64) -> i64 {
+    size as i64
+}
+
+pub fn widened(small: u16, signed: i32) -> (u64, i64) {
+    // Widening casts are lossless and must not be reported.
+    (small as u64, signed as i64)
+}
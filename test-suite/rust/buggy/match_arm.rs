@@ -0,0 +1,45 @@
+// Match arms that can never run, and wildcards that quietly swallow error
+// variants added later.
+#[derive(Debug)]
+pub enum StoreError {
+    NotFound,
+    Conflict,
+    Corrupted,
+}
+
+pub fn status_text(code: u16) -> &'static str {
+    match code {
+        200 => "ok",
+        404 => "missing",
+        200 => "created",
+        _ => "other",
+    }
+}
+
+pub fn describe(level: u8) -> &'static str {
+    match level {
+        0 => "off",
+        other => {
+            let _ = other;
+            "on"
+        }
+        1 => "low",
+    }
+}
+
+pub fn recover(result: Result<u32, StoreError>) -> Result<u32, StoreError> {
+    match result {
+        Ok(value) => Ok(value),
+        Err(StoreError::NotFound) => Ok(0),
+        Err(_) => Ok(0),
+    }
+}
+
+pub fn handle(err: StoreError) {
+    match err {
+        StoreError::Conflict => retry(),
+        _ => {}
+    }
+}
+
+fn retry() {}
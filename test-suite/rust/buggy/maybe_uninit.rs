@@ -0,0 +1,13 @@
+use std::mem::MaybeUninit;
+
+pub fn direct() -> u64 {
+    unsafe { MaybeUninit::<u64>::uninit().assume_init() }
+}
+
+pub fn forgot_write(flag: bool) -> [u8; 16] {
+    let buf = MaybeUninit::<[u8; 16]>::uninit();
+    if flag {
+        println!("about to read");
+    }
+    unsafe { buf.assume_init() }
+}
@@ -0,0 +1,20 @@
+use std::mem::{self, ManuallyDrop};
+
+pub struct Session {
+    name: String,
+}
+
+pub fn close(session: Session) {
+    // Skips Drop entirely: the String buffer is never freed.
+    mem::forget(session);
+}
+
+pub fn label(request_id: u64) -> &'static str {
+    // Leaks a fresh allocation on every call.
+    Box::leak(format!("req-{request_id}").into_boxed_str())
+}
+
+pub fn peek(buffer: Vec<u8>) -> usize {
+    let held = ManuallyDrop::new(buffer);
+    held.len()
+}
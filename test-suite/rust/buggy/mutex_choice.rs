@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct Sessions {
+    // Only ever locked for a quick insert/lookup; never held across an .await.
+    entries: tokio::sync::Mutex<HashMap<u64, String>>,
+}
+
+impl Sessions {
+    pub async fn insert(&self, id: u64, user: String) {
+        self.entries.lock().await.insert(id, user);
+    }
+
+    pub async fn user(&self, id: u64) -> Option<String> {
+        let entries = self.entries.lock().await;
+        entries.get(&id).cloned()
+    }
+}
+
+pub async fn refresh(cache: Arc<std::sync::Mutex<Vec<u8>>>) {
+    let mut guard = cache.lock().unwrap();
+    guard.clear();
+    reload().await;
+    guard.push(1);
+}
+
+async fn reload() {}
+
+mod tokio {
+    pub mod sync {
+        pub struct Mutex<T>(std::sync::Mutex<T>);
+        impl<T> Mutex<T> {
+            pub async fn lock(&self) -> std::sync::MutexGuard<'_, T> {
+                self.0.lock().unwrap()
+            }
+        }
+    }
+}
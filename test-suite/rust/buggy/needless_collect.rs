@@ -0,0 +1,20 @@
+pub fn has_errors(lines: &[String]) -> bool {
+    let errors: Vec<&String> = lines.iter().filter(|l| l.contains("ERROR")).collect();
+    errors.len() == 0
+}
+
+pub fn total_len(words: &[&str]) -> usize {
+    let lengths = words.iter().map(|w| w.len()).collect::<Vec<_>>();
+    lengths.iter().sum()
+}
+
+pub fn print_even(values: &[u32]) {
+    let evens: Vec<u32> = values.iter().copied().filter(|v| v % 2 == 0).collect();
+    for v in evens {
+        println!("{v}");
+    }
+}
+
+pub fn any_negative(values: &[i64]) -> bool {
+    values.iter().filter(|v| **v < 0).collect::<Vec<_>>().is_empty()
+}
@@ -0,0 +1,36 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+pub async fn fetch_profile(id: u64) -> reqwest::Result<String> {
+    let client = reqwest::Client::new();
+    client
+        .get(format!("https://api.example.test/profiles/{id}"))
+        .send()
+        .await?
+        .text()
+        .await
+}
+
+pub fn build_client() -> reqwest::Result<reqwest::blocking::Client> {
+    reqwest::blocking::Client::builder()
+        .user_agent("ubs-fixture")
+        .build()
+}
+
+pub async fn quick_status() -> reqwest::Result<u16> {
+    Ok(reqwest::get("https://status.example.test").await?.status().as_u16())
+}
+
+pub fn ping(addr: &str) -> std::io::Result<Vec<u8>> {
+    let mut stream = TcpStream::connect(addr)?;
+    stream.write_all(b"PING\r\n")?;
+    let mut reply = vec![0u8; 64];
+    let n = stream.read(&mut reply)?;
+    reply.truncate(n);
+    Ok(reply)
+}
+
+pub async fn ping_async(addr: &str) -> std::io::Result<()> {
+    let _stream = tokio::net::TcpStream::connect(addr).await?;
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// Matches over enums another crate marks #[non_exhaustive] must keep a real
+// fallback arm: new variants arrive in minor releases.
+use std::io;
+
+mod syn {
+    pub enum Expr {
+        Lit(u32),
+        Path(String),
+    }
+}
+
+pub fn classify(err: &io::Error) -> &'static str {
+    match err.kind() {
+        io::ErrorKind::NotFound => "missing",
+        io::ErrorKind::PermissionDenied => "denied",
+        _ => unreachable!("no other io errors expected"),
+    }
+}
+
+pub fn parse_code(raw: &str) -> u8 {
+    match raw.parse::<u8>() {
+        Ok(code) => code,
+        Err(e) => match e.kind() {
+            std::num::IntErrorKind::Empty => 0,
+            std::num::IntErrorKind::PosOverflow => u8::MAX,
+            _ => panic!("unexpected parse error"),
+        },
+    }
+}
+
+pub fn render(expr: &syn::Expr) -> String {
+    match expr {
+        syn::Expr::Lit(value) => value.to_string(),
+        syn::Expr::Path(path) => path.clone(),
+    }
+}
@@ -0,0 +1,36 @@
+// Range bounds that are one too short or one too long for the collection.
+pub fn checksum(bytes: &[u8]) -> u32 {
+    let mut sum = 0u32;
+    for i in 0..bytes.len() - 1 {
+        sum = sum.wrapping_add(bytes[i] as u32);
+    }
+    sum
+}
+
+pub fn whole(items: &[u32]) -> &[u32] {
+    &items[..=items.len()]
+}
+
+pub fn tail_from(items: &[u32], start: usize) -> &[u32] {
+    &items[start..=items.len()]
+}
+
+pub fn count_spaces(buf: &[u8]) -> usize {
+    let mut i = 0;
+    let mut spaces = 0;
+    while i <= buf.len() {
+        if buf[i] == b' ' {
+            spaces += 1;
+        }
+        i += 1;
+    }
+    spaces
+}
+
+pub fn total(values: &[u64]) -> u64 {
+    let mut sum = 0;
+    for i in 0..=values.len() {
+        sum += values[i];
+    }
+    sum
+}
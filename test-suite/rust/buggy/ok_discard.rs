@@ -0,0 +1,28 @@
+use std::fs;
+use std::io::Write;
+use std::net::TcpStream;
+
+pub fn persist(path: &str, body: &str) {
+    fs::write(path, body).ok();
+    fs::remove_file(format!("{path}.tmp")).ok();
+}
+
+pub fn notify(stream: &mut TcpStream, event: &str) {
+    stream.write_all(event.as_bytes()).ok();
+}
+
+pub fn load_port(path: &str) -> u16 {
+    if let Some(raw) = fs::read_to_string(path).ok() {
+        if let Some(port) = raw.trim().parse::<u16>().ok() {
+            return port;
+        }
+    }
+    8080
+}
+
+pub fn touch(path: &str) -> bool {
+    if let Some(_) = fs::File::create(path).ok() {
+        return true;
+    }
+    false
+}
@@ -0,0 +1,41 @@
+pub enum Shape {
+    Circle(f64),
+    Square(f64),
+    Polygon(Vec<(f64, f64)>),
+}
+
+pub fn area(shape: &Shape) -> f64 {
+    match shape {
+        Shape::Circle(r) => std::f64::consts::PI * r * r,
+        Shape::Square(side) => side * side,
+        Shape::Polygon(_) => todo!("polygon area"),
+    }
+}
+
+pub fn perimeter(_shape: &Shape) -> f64 {
+    unimplemented!()
+}
+
+pub fn load(config: Option<&str>) -> &str {
+    match config {
+        Some(value) => value,
+        None => panic!("config required"),
+    }
+}
+
+pub fn bucket(n: u8) -> &'static str {
+    match n % 3 {
+        0 => "a",
+        1 => "b",
+        2 => "c",
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn placeholder() {
+        todo!("write this test")
+    }
+}
@@ -0,0 +1,45 @@
+use std::path::{Path, PathBuf};
+
+pub struct Query<T>(pub T);
+pub struct Json<T>(pub T);
+pub struct HttpRequest;
+
+impl HttpRequest {
+    pub fn match_info(&self) -> &Self {
+        self
+    }
+    pub fn get(&self, _key: &str) -> Option<&str> {
+        None
+    }
+}
+
+pub struct Download {
+    pub file: String,
+}
+
+pub struct Upload {
+    pub dir: String,
+    pub name: String,
+}
+
+pub fn download(static_root: &Path, Query(params): Query<Download>) -> std::io::Result<Vec<u8>> {
+    // `?file=../../etc/passwd` walks out of the static root.
+    std::fs::read(static_root.join(&params.file))
+}
+
+pub fn avatar(req: HttpRequest) -> PathBuf {
+    let id = req.match_info().get("id").unwrap_or_default().to_string();
+    PathBuf::from("/srv/avatars").join(format!("{id}.png"))
+}
+
+pub fn save(Json(body): Json<Upload>, data: &[u8]) -> std::io::Result<()> {
+    let mut target = PathBuf::from("/srv/uploads");
+    target.push(&body.dir);
+    target.push(&body.name);
+    std::fs::write(target, data)
+}
+
+pub fn cat_from_args(base_dir: &Path) -> std::io::Result<String> {
+    let requested = std::env::args().nth(1).unwrap_or_default();
+    std::fs::read_to_string(base_dir.join(requested))
+}
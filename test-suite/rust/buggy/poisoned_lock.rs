@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+pub fn run_jobs(jobs: Vec<String>) -> usize {
+    let done = Arc::new(Mutex::new(Vec::new()));
+    let mut workers = Vec::new();
+    for job in jobs {
+        let done = Arc::clone(&done);
+        workers.push(thread::spawn(move || {
+            let mut finished = done.lock().unwrap();
+            let size: usize = job.parse().expect("job id must be numeric");
+            finished.push(size);
+        }));
+    }
+    for worker in workers {
+        let _ = worker.join();
+    }
+    let total = done.lock().unwrap().len();
+    total
+}
+
+pub fn refresh_cache(keys: Vec<String>) -> usize {
+    let cache = Arc::new(RwLock::new(HashMap::new()));
+    let writer = cache.clone();
+    let handle = thread::spawn(move || {
+        let mut map = writer.write().unwrap();
+        for key in keys {
+            assert!(!key.is_empty(), "empty cache key");
+            map.insert(key, 0u32);
+        }
+    });
+    let _ = handle.join();
+    let size = cache.read().unwrap().len();
+    size
+}
@@ -0,0 +1,26 @@
+use rand::Rng;
+
+pub struct Session {
+    pub id: u64,
+    pub csrf: String,
+}
+
+pub fn create() -> Session {
+    let mut rng = rand::thread_rng();
+    // The number is harmless until it is formatted into a session id.
+    let raw: u64 = rng.gen();
+    let sid = format!("{raw:016x}");
+    Session { id: rng.gen(), csrf: sid }
+}
+
+pub fn issue(user: &str) -> String {
+    let n = fastrand::u64(..);
+    let reset_link = format!("https://example.test/reset?u={user}&k={n}");
+    reset_link
+}
+
+pub fn mk() -> [u8; 16] {
+    let mut iv = [0u8; 16];
+    rand::thread_rng().fill(&mut iv);
+    iv
+}
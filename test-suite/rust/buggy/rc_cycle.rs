@@ -0,0 +1,27 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+struct TreeNode {
+    value: i32,
+    children: Vec<Rc<RefCell<TreeNode>>>,
+    // Strong pointer back to the parent: parent <-> child never drop.
+    parent: Option<Rc<RefCell<TreeNode>>>,
+}
+
+struct Session {
+    owner: Arc<Mutex<Client>>,
+}
+
+struct Client {
+    sessions: Vec<Arc<Session>>,
+}
+
+struct Peer {
+    link: RefCell<Option<Rc<Self>>>,
+}
+
+struct DoublyLinked {
+    next: Option<Rc<RefCell<DoublyLinked>>>,
+    prev: Option<Rc<RefCell<DoublyLinked>>>,
+}
@@ -0,0 +1,28 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Inventory {
+    items: RefCell<Vec<String>>,
+}
+
+impl Inventory {
+    fn restock(&self) {
+        let current = self.items.borrow();
+        if current.is_empty() {
+            // `current` is still alive: this borrow_mut() panics.
+            self.items.borrow_mut().push("widget".to_string());
+        }
+    }
+
+    fn append_count(&self) {
+        // Both temporaries live until the `;`.
+        self.items.borrow_mut().push(self.items.borrow().len().to_string());
+    }
+}
+
+fn shared_counter() {
+    let counter = Rc::new(RefCell::new(0u32));
+    let mut guard = counter.borrow_mut();
+    *guard += 1;
+    println!("{}", counter.borrow());
+}
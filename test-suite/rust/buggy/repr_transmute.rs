@@ -0,0 +1,34 @@
+// Reinterpreting one repr(Rust) struct as another relies on a field order
+// and padding the compiler is free to change between builds.
+use std::mem;
+
+pub struct Header {
+    pub kind: u8,
+    pub len: u32,
+    pub flags: u16,
+}
+
+pub struct WireHeader {
+    pub kind: u8,
+    pub len: u32,
+    pub flags: u16,
+}
+
+#[repr(C)]
+pub struct RawHeader {
+    pub kind: u8,
+    pub len: u32,
+    pub flags: u16,
+}
+
+pub fn to_wire(header: Header) -> WireHeader {
+    unsafe { mem::transmute::<Header, WireHeader>(header) }
+}
+
+pub fn view_raw(header: &Header) -> &RawHeader {
+    unsafe { &*(header as *const Header as *const RawHeader) }
+}
+
+pub fn view_wire(raw: *const RawHeader) -> *const WireHeader {
+    raw.cast::<WireHeader>()
+}
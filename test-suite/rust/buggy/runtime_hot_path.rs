@@ -0,0 +1,54 @@
+use tokio::runtime::Runtime;
+
+pub struct Request {
+    pub path: String,
+}
+
+pub fn process_all(urls: &[String]) -> Vec<usize> {
+    let mut sizes = Vec::new();
+    for url in urls {
+        let rt = Runtime::new().unwrap();
+        sizes.push(rt.block_on(fetch(url)));
+    }
+    sizes
+}
+
+pub fn handle_request(req: Request) -> usize {
+    let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    rt.block_on(fetch(&req.path))
+}
+
+pub fn fetch_blocking(url: &str) -> usize {
+    let rt = Runtime::new().expect("runtime");
+    rt.block_on(fetch(url))
+}
+
+pub async fn fetch(url: &str) -> usize {
+    url.len()
+}
+
+mod tokio {
+    pub mod runtime {
+        use std::future::Future;
+
+        pub struct Runtime;
+        impl Runtime {
+            pub fn new() -> std::io::Result<Self> {
+                Ok(Runtime)
+            }
+            pub fn block_on<F: Future>(&self, _future: F) -> F::Output {
+                unimplemented!()
+            }
+        }
+
+        pub struct Builder;
+        impl Builder {
+            pub fn new_current_thread() -> Self {
+                Builder
+            }
+            pub fn build(&mut self) -> std::io::Result<Runtime> {
+                Ok(Runtime)
+            }
+        }
+    }
+}
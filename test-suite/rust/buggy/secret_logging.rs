@@ -0,0 +1,33 @@
+// Secrets interpolated into panic messages and log lines end up in crash
+// reports, terminal scrollback, and log aggregation.
+macro_rules! info {
+    ($($arg:tt)*) => {
+        let _ = format!($($arg)*);
+    };
+}
+
+pub struct Config {
+    pub api_key: String,
+    pub endpoint: String,
+}
+
+pub fn connect(user: &str, password: &str) -> Result<(), String> {
+    let status: Result<(), String> = Err(String::from("refused"));
+    status.expect(&format!("login failed for {user} with {password}"));
+    Ok(())
+}
+
+pub fn authorize(cfg: &Config) {
+    println!("calling {} with key {}", cfg.endpoint, cfg.api_key);
+}
+
+pub fn refresh(access_token: &str) {
+    if access_token.is_empty() {
+        panic!("refresh rejected token {access_token}");
+    }
+    info!("refreshed session with {:?}", access_token);
+}
+
+pub fn debug_secret(client_secret: &str) {
+    eprintln!("client_secret = {}", client_secret);
+}
@@ -0,0 +1,43 @@
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{sleep, Duration};
+
+async fn read_frames(mut stream: TcpStream, mut shutdown: oneshot::Receiver<()>) {
+    let mut header = [0u8; 8];
+    loop {
+        tokio::select! {
+            // Losing this race drops however many header bytes were already read.
+            res = stream.read_exact(&mut header) => {
+                if res.is_err() {
+                    break;
+                }
+            }
+            _ = &mut shutdown => break,
+        }
+    }
+}
+
+async fn read_commands(stream: TcpStream) {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    loop {
+        tokio::select! {
+            n = reader.read_line(&mut line) => {
+                if matches!(n, Ok(0) | Err(_)) {
+                    break;
+                }
+            }
+            _ = sleep(Duration::from_secs(5)) => continue,
+        }
+    }
+}
+
+async fn forward(tx: mpsc::Sender<Vec<u8>>, payload: Vec<u8>) {
+    tokio::select! {
+        _ = tx.send(payload) => {}
+        _ = sleep(Duration::from_millis(50)) => {
+            eprintln!("send timed out; payload silently dropped");
+        }
+    }
+}
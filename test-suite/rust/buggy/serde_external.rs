@@ -0,0 +1,53 @@
+// Deserializing request bodies, files and env values with unwrap lets a
+// single malformed payload panic the service.
+use std::env;
+use std::fs;
+
+mod serde_json {
+    pub fn from_str<T: Default>(_s: &str) -> Result<T, String> {
+        Ok(T::default())
+    }
+    pub fn from_slice<T: Default>(_b: &[u8]) -> Result<T, String> {
+        Ok(T::default())
+    }
+}
+
+mod toml {
+    pub fn from_str<T: Default>(_s: &str) -> Result<T, String> {
+        Ok(T::default())
+    }
+}
+
+pub struct Bytes(Vec<u8>);
+
+impl std::ops::Deref for Bytes {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[derive(Default)]
+pub struct Order {
+    pub id: u64,
+}
+
+pub fn create_order(body: Bytes) -> u64 {
+    let order: Order = serde_json::from_slice(&body).unwrap();
+    order.id
+}
+
+pub fn update_order(payload: String) -> u64 {
+    let order: Order = serde_json::from_str(&payload).expect("valid order");
+    order.id
+}
+
+pub fn load_settings(path: &str) -> Order {
+    let raw = fs::read_to_string(path).unwrap_or_default();
+    toml::from_str(&raw).unwrap()
+}
+
+pub fn overrides() -> Order {
+    let raw = env::var("APP_OVERRIDES").unwrap_or_default();
+    serde_json::from_str(&raw).unwrap()
+}
@@ -0,0 +1,37 @@
+// The first binding holds a Result (or a lock guard) that is never looked at
+// before the name is reused, so the error is silently lost.
+use std::fs;
+use std::sync::Mutex;
+
+#[derive(Debug)]
+pub struct Config {
+    pub name: String,
+}
+
+fn save(config: &Config) -> Result<(), String> {
+    fs::write("config.toml", &config.name).map_err(|e| e.to_string())
+}
+
+pub fn reload(path: &str) -> String {
+    let contents = fs::read_to_string(path);
+    let contents = String::from("defaults");
+    contents
+}
+
+pub fn persist(config: &Config) -> u32 {
+    let status = save(config);
+    let status = 0;
+    status
+}
+
+pub fn bump(counter: &Mutex<u32>) -> u32 {
+    let value = counter.lock().unwrap();
+    let value = 1;
+    value
+}
+
+pub fn port(raw: &str) -> u16 {
+    let port: Result<u16, _> = raw.parse::<u16>();
+    let port = 8080;
+    port
+}
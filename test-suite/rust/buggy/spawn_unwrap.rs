@@ -0,0 +1,26 @@
+// Panics inside spawned threads and tasks only surface through the JoinHandle.
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+pub fn count_lines(paths: Vec<String>) -> usize {
+    let total = Arc::new(Mutex::new(0usize));
+    let shared = Arc::clone(&total);
+    let worker = thread::spawn(move || {
+        for path in paths {
+            let body = fs::read_to_string(&path).unwrap();
+            *shared.lock().unwrap() += body.lines().count();
+        }
+    });
+    let _ = worker.join();
+    let count = *total.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    count
+}
+
+pub async fn fetch_port(raw: String) -> u16 {
+    let task = tokio::spawn(async move {
+        let port: u16 = raw.trim().parse().expect("port must be numeric");
+        port
+    });
+    task.await.unwrap_or(0)
+}
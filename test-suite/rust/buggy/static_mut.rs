@@ -0,0 +1,22 @@
+static mut REQUEST_COUNT: u64 = 0;
+static mut CONFIG: Option<String> = None;
+pub static mut REGISTRY: Vec<String> = Vec::new();
+
+fn record_request() {
+    unsafe {
+        REQUEST_COUNT += 1;
+    }
+}
+
+fn config() -> &'static str {
+    unsafe {
+        if CONFIG.is_none() {
+            CONFIG = Some(std::env::var("APP_CONFIG").unwrap_or_default());
+        }
+        CONFIG.as_deref().unwrap_or("")
+    }
+}
+
+fn register(name: &str) {
+    unsafe { REGISTRY.push(name.to_string()) }
+}
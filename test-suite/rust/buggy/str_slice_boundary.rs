@@ -0,0 +1,20 @@
+pub fn preview(title: &str) -> &str {
+    if title.len() > 10 {
+        &title[..10]
+    } else {
+        title
+    }
+}
+
+pub fn truncate(name: &str, max: usize) -> &str {
+    &name[..max]
+}
+
+pub fn drop_last(input: String) -> String {
+    input[..input.len() - 1].to_string()
+}
+
+pub fn after_prefix(line: &str) -> &str {
+    let header = line.trim();
+    &header[4..]
+}
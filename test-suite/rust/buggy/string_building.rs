@@ -0,0 +1,41 @@
+pub fn join_names(names: &[String]) -> String {
+    let mut out = String::new();
+    for name in names {
+        out = out + &name + ",";
+    }
+    out
+}
+
+pub fn render_rows(rows: &[(u32, String)]) -> String {
+    let mut html = String::new();
+    for (id, label) in rows {
+        html = format!("{}<li id=\"{}\">{}</li>", html, id, label);
+    }
+    html
+}
+
+pub fn csv(values: &[f64]) -> String {
+    let mut line = String::new();
+    values.iter().for_each(|v| {
+        line += &format!("{v:.2};");
+    });
+    line
+}
+
+pub fn banner(words: &[&str]) -> String {
+    let mut text = String::new();
+    let mut i = 0;
+    while i < words.len() {
+        text = format!("{text} {}", words[i]);
+        i += 1;
+    }
+    text
+}
+
+pub fn path(parts: &[&str]) -> String {
+    let mut joined = String::new();
+    for &part in parts {
+        joined = joined + part;
+    }
+    joined
+}
@@ -0,0 +1,39 @@
+// Tests that sleep for a fixed time and hope the background work finished.
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+mod tokio {
+    pub fn spawn<F>(_f: F) {}
+    pub mod time {
+        pub async fn sleep(_d: std::time::Duration) {}
+    }
+}
+
+pub fn start_worker(tx: mpsc::Sender<u32>) {
+    thread::spawn(move || tx.send(42).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn worker_reports_result() {
+        let (tx, rx) = mpsc::channel();
+        start_worker(tx);
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(rx.try_recv().unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn background_flush() {
+        tokio::spawn(async {});
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    #[test]
+    fn cache_expires() {
+        std::thread::sleep(Duration::from_secs(1));
+    }
+}
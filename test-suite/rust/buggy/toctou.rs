@@ -0,0 +1,28 @@
+// The path can be created, replaced, or swapped for a symlink between the
+// check and the operation that trusts it.
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+pub fn write_lock(lock: &Path) -> io::Result<()> {
+    if !lock.exists() {
+        let mut file = File::create(lock)?;
+        writeln!(file, "{}", std::process::id())?;
+    }
+    Ok(())
+}
+
+pub fn remove_stale(pid_file: &str) -> io::Result<()> {
+    if Path::new(pid_file).is_file() {
+        fs::remove_file(pid_file)?;
+    }
+    Ok(())
+}
+
+pub fn append_log(log: &Path, line: &str) -> io::Result<()> {
+    if fs::metadata(log).is_ok() {
+        let mut file = OpenOptions::new().append(true).open(log)?;
+        writeln!(file, "{line}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,11 @@
+// Calling a Result-returning async fn without .await drops a Future, not a Result.
+use std::io;
+
+async fn refresh(path: &str) -> io::Result<()> {
+    tokio::fs::write(path, b"{}").await
+}
+
+pub async fn reload(path: &str) -> io::Result<()> {
+    refresh(path);
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use std::sync::mpsc::channel;
+use std::thread;
+use tokio::sync::mpsc;
+
+async fn ingest(lines: Vec<String>) {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(line) = rx.recv().await {
+            process(line).await;
+        }
+    });
+    // Nothing slows the producer down if process() falls behind.
+    for line in lines {
+        let _ = tx.send(line);
+    }
+}
+
+fn fan_out(count: usize) {
+    let (tx, rx) = channel::<u64>();
+    for worker in 0..4 {
+        let tx = tx.clone();
+        thread::spawn(move || loop {
+            let _ = tx.send(worker);
+        });
+    }
+    drop(tx);
+    for value in rx.iter().take(count) {
+        println!("{value}");
+    }
+}
+
+fn crossbeam_events() {
+    let (events_tx, events_rx) = crossbeam::channel::unbounded();
+    thread::spawn(move || {
+        let mut n = 0u64;
+        loop {
+            n += 1;
+            events_tx.send(n).ok();
+        }
+    });
+    let _ = events_rx.recv();
+}
+
+async fn process(_line: String) {}
@@ -0,0 +1,38 @@
+use std::io::Read;
+use std::net::{TcpListener, TcpStream};
+
+pub fn handle_client(mut stream: TcpStream) -> std::io::Result<Vec<u8>> {
+    let mut payload = Vec::new();
+    stream.read_to_end(&mut payload)?;
+    Ok(payload)
+}
+
+pub fn serve(listener: &TcpListener) -> std::io::Result<String> {
+    let (mut conn, _) = listener.accept()?;
+    let mut request = String::new();
+    conn.read_to_string(&mut request)?;
+    Ok(request)
+}
+
+pub fn fetch(addr: &str) -> std::io::Result<Vec<u8>> {
+    let mut upstream = TcpStream::connect(addr)?;
+    let mut reply = Vec::new();
+    upstream.read_to_end(&mut reply)?;
+    Ok(reply)
+}
+
+pub fn read_upload<R: Read>(req: &mut tiny::Request<R>) -> std::io::Result<String> {
+    let mut body = String::new();
+    req.as_reader().read_to_string(&mut body)?;
+    Ok(body)
+}
+
+pub mod tiny {
+    pub struct Request<R>(pub R);
+
+    impl<R: std::io::Read> Request<R> {
+        pub fn as_reader(&mut self) -> &mut R {
+            &mut self.0
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+pub struct Meters(pub f64);
+
+impl fmt::Display for Meters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Meant to call write!, recurses forever instead.
+        self.fmt(f)
+    }
+}
+
+pub fn countdown(n: u64) -> u64 {
+    println!("{n}");
+    countdown(n - 1)
+}
+
+pub enum Json {
+    Null,
+    Array(Vec<Json>),
+}
+
+pub fn nesting(value: &Json) -> usize {
+    match value {
+        Json::Null => 0,
+        Json::Array(items) => 1 + items.iter().map(|item| nesting(item)).max().unwrap_or(0),
+    }
+}
+
+pub fn count_parens(input: &[u8]) -> usize {
+    if input.is_empty() {
+        return 0;
+    }
+    let here = usize::from(input[0] == b'(');
+    here + count_parens(&input[1..])
+}
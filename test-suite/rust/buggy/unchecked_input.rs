@@ -0,0 +1,31 @@
+use std::io::{self, Read};
+use std::slice;
+
+pub fn nth_byte(data: &[u8], index: usize) -> u8 {
+    // Caller-controlled index, no bounds check.
+    unsafe { *data.get_unchecked(index) }
+}
+
+pub fn read_name(stream: &mut impl Read) -> String {
+    let mut buf = vec![0u8; 64];
+    let n = stream.read(&mut buf).unwrap_or(0);
+    // Bytes straight off the wire are not guaranteed UTF-8.
+    unsafe { String::from_utf8_unchecked(buf[..n].to_vec()) }
+}
+
+pub fn header(line: &str, split: usize) -> &str {
+    unsafe { line.get_unchecked(..split) }
+}
+
+pub fn fixed_table() -> u8 {
+    let table = [1u8, 2, 3];
+    unsafe { *table.get_unchecked(1) }
+}
+
+pub fn frame_body(stream: &mut impl Read, base: *const u8) -> io::Result<&'static [u8]> {
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header)?;
+    // Length prefix comes off the wire and is never compared to the buffer.
+    let len = u32::from_be_bytes(header) as usize;
+    Ok(unsafe { slice::from_raw_parts(base, len) })
+}
@@ -0,0 +1,60 @@
+// RAII guards bound to `_` are dropped at the end of the statement, so the
+// lock, span or permit they represent never covers the code that follows.
+use std::cell::RefCell;
+use std::sync::{Mutex, RwLock};
+
+mod tracing {
+    pub struct Span;
+    pub struct Entered;
+    impl Span {
+        pub fn enter(&self) -> Entered {
+            Entered
+        }
+    }
+}
+
+mod tokio {
+    pub mod sync {
+        pub struct Semaphore;
+        pub struct Permit;
+        impl Semaphore {
+            pub async fn acquire(&self) -> Result<Permit, ()> {
+                Ok(Permit)
+            }
+        }
+    }
+}
+
+pub struct Ledger {
+    balance: Mutex<i64>,
+    index: RwLock<Vec<u32>>,
+    cache: RefCell<Vec<u8>>,
+}
+
+pub fn deposit(ledger: &Ledger, balance: &mut i64, amount: i64) -> Result<(), String> {
+    let _ = ledger.balance.lock().map_err(|e| e.to_string())?;
+    *balance += amount;
+    Ok(())
+}
+
+pub fn reindex(ledger: &Ledger, entries: &mut Vec<u32>) {
+    let _ = ledger.index.write().unwrap();
+    entries.sort();
+}
+
+pub fn trace_request(span: &tracing::Span) {
+    let _ = span.enter();
+    handle();
+}
+
+pub async fn throttled(sem: &tokio::sync::Semaphore) {
+    let _ = sem.acquire().await.expect("semaphore closed");
+    handle();
+}
+
+pub fn touch_cache(ledger: &Ledger) {
+    let _ = ledger.cache.borrow_mut();
+    handle();
+}
+
+fn handle() {}
@@ -14,6 +14,14 @@ fn reinterpret(bytes: [u8; 8]) -> u64 {
     unsafe { mem::transmute(bytes) }
 }
 
+fn box_to_address(value: Box<u32>) -> usize {
+    unsafe { mem::transmute(value) }
+}
+
+fn widen_bits(value: u32) -> u64 {
+    unsafe { mem::transmute::<u32, u64>(value) }
+}
+
 fn make_zeroed_reference() -> &'static u8 {
     unsafe { std::mem::zeroed::<&'static u8>() }
 }
@@ -0,0 +1,23 @@
+pub struct RawBuffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl RawBuffer {
+    pub fn first(&self) -> u8 {
+        unsafe { *self.ptr }
+    }
+
+    pub unsafe fn set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    pub fn fill(&mut self, byte: u8) {
+        // Write every byte in the buffer.
+        unsafe {
+            for offset in 0..self.len {
+                *self.ptr.add(offset) = byte;
+            }
+        }
+    }
+}
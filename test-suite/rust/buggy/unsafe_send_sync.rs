@@ -0,0 +1,38 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+pub struct SharedConfig {
+    pub names: Rc<Vec<String>>,
+}
+
+// Rc's refcount is not atomic; moving a clone to another thread races on it.
+unsafe impl Send for SharedConfig {}
+
+pub struct Counter {
+    hits: Cell<u64>,
+    log: RefCell<Vec<String>>,
+}
+
+// &Counter on two threads lets both call Cell::set concurrently.
+unsafe impl Sync for Counter {}
+
+impl Counter {
+    pub fn hit(&self) {
+        self.hits.set(self.hits.get() + 1);
+        self.log.borrow_mut().push("hit".into());
+    }
+}
+
+pub struct Buffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+unsafe impl Send for Buffer {}
+
+impl Buffer {
+    pub fn len(&self) -> usize {
+        let _ = self.ptr;
+        self.len
+    }
+}
@@ -0,0 +1,24 @@
+// Lengths read from the wire or parsed from arguments go straight into an
+// allocation, so one crafted header can request gigabytes.
+use std::io::{self, Read};
+
+pub fn read_frame(stream: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header)?;
+    let len = u32::from_be_bytes(header) as usize;
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload)?;
+    Ok(payload)
+}
+
+pub fn parse_items(args: &[String]) -> Vec<u64> {
+    let count: usize = args[1].parse().unwrap_or(0);
+    let mut items = Vec::with_capacity(count);
+    items.push(0);
+    items
+}
+
+pub fn grow(line: &str, table: &mut Vec<String>) {
+    let extra = line.trim().parse::<usize>().unwrap_or(0);
+    table.reserve(extra * 2);
+}
@@ -0,0 +1,43 @@
+use std::io::{BufReader, Read};
+use std::net::{TcpListener, TcpStream};
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Command {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuthConfig {
+    pub jwt_issuer: String,
+    pub require_mfa: bool,
+    pub admin_roles: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ServerSettings {
+    pub bind: String,
+    pub tls_cert_path: String,
+}
+
+pub fn read_command(stream: &mut TcpStream) -> serde_json::Result<Command> {
+    serde_json::from_reader(BufReader::new(stream))
+}
+
+pub fn serve(listener: TcpListener) {
+    for conn in listener.incoming() {
+        let conn = conn.expect("accept");
+        let command: Command = bincode::deserialize_from(&conn).expect("decode");
+        println!("{command:?}");
+    }
+}
+
+pub fn fetch_frame(addr: &str) -> Option<Command> {
+    let mut socket = TcpStream::connect(addr).ok()?;
+    let mut buf = Vec::new();
+    socket.read_to_end(&mut buf).ok()?;
+    bincode::deserialize(&buf).ok()
+}
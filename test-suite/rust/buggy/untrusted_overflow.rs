@@ -0,0 +1,27 @@
+pub fn total_size(header: &str, count_field: &str) -> u32 {
+    let item_size: u32 = header.trim().parse().unwrap_or(0);
+    let count = count_field.parse::<u32>().unwrap_or(0);
+    // Both operands are attacker-controlled.
+    item_size * count
+}
+
+pub fn frame_len(bytes: [u8; 2]) -> u16 {
+    let declared = u16::from_be_bytes(bytes);
+    declared + 4
+}
+
+pub fn last_index(items: &[u8]) -> usize {
+    let len = items.len();
+    len - 1
+}
+
+pub fn offset(port: &str) -> i32 {
+    let base: i32 = port.parse().unwrap_or(0);
+    base + 1000
+}
+
+pub fn scaled_len(input: &[u8]) -> usize {
+    let n = input.len();
+    let m = n * 4;
+    m - 8
+}
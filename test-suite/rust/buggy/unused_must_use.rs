@@ -0,0 +1,27 @@
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+#[must_use]
+fn normalized(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+macro_rules! bump {
+    ($counter:expr) => {
+        $counter.wrapping_add(1);
+    };
+}
+
+pub fn tidy(names: &mut Vec<String>, label: &mut String, total: u32) -> u32 {
+    names.iter_mut().map(|name| name.make_ascii_lowercase());
+    label.trim();
+    label.to_uppercase();
+    normalized(label);
+    bump!(total);
+    total
+}
+
+pub fn touch(state: &Mutex<Vec<u8>>, cache: &RefCell<Vec<u8>>) {
+    state.lock().unwrap();
+    cache.borrow_mut();
+}
@@ -0,0 +1,33 @@
+use des::cipher::{BlockEncrypt, KeyInit};
+use hmac::{Hmac, Mac};
+use md5::{Digest, Md5};
+use sha1::Sha1;
+
+pub fn hash_password(password: &str, salt: &[u8]) -> Vec<u8> {
+    // MD5 is brute-forced at billions of guesses per second.
+    let mut hasher = Md5::new();
+    hasher.update(salt);
+    hasher.update(password.as_bytes());
+    hasher.finalize().to_vec()
+}
+
+pub fn sign_request(secret: &[u8], body: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("any key length");
+    mac.update(body);
+    mac.finalize().into_bytes().to_vec()
+}
+
+pub fn encrypt_session_token(key: &[u8; 8], block: &mut [u8; 8]) {
+    let cipher = des::Des::new(key.into());
+    cipher.encrypt_block(block.into());
+}
+
+pub fn seal_api_token(key: &[u8], token: &[u8]) -> Vec<u8> {
+    let cipher = openssl::symm::Cipher::aes_128_ecb();
+    openssl::symm::encrypt(cipher, key, None, token).unwrap_or_default()
+}
+
+pub fn cache_key(url: &str) -> String {
+    // Not security sensitive, but still worth a look.
+    format!("{:x}", md5::compute(url.as_bytes()))
+}
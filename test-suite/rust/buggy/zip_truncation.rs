@@ -0,0 +1,22 @@
+// zip stops at the shorter side, so a length mismatch between data that
+// should be parallel silently drops the tail.
+pub fn weighted_sum(values: &[f64], weights: &[f64]) -> f64 {
+    values.iter().zip(weights.iter()).map(|(v, w)| v * w).sum()
+}
+
+pub fn label_rows(names: Vec<String>, scores: Vec<u32>) -> Vec<String> {
+    names
+        .into_iter()
+        .zip(scores)
+        .map(|(name, score)| format!("{name}: {score}"))
+        .collect()
+}
+
+pub fn results_match(actual: &[i64], expected: &[i64]) -> bool {
+    for (got, want) in std::iter::zip(actual, expected) {
+        if got != want {
+            return false;
+        }
+    }
+    true
+}
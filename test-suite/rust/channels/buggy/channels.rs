@@ -0,0 +1,33 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+fn worker_pool(jobs: Vec<u32>) {
+    let (tx, rx) = mpsc::sync_channel(8);
+    let handle = thread::spawn(move || {
+        // Panics as soon as the consumer below stops listening.
+        for job in jobs {
+            tx.send(job * 2).unwrap();
+        }
+    });
+    // Panics once the producer finishes and drops its sender.
+    loop {
+        let value = rx.recv().unwrap();
+        println!("{value}");
+    }
+    #[allow(unreachable_code)]
+    handle.join().expect("producer thread panicked");
+}
+
+fn poll_status(rx: &mpsc::Receiver<String>) -> String {
+    rx.recv_timeout(Duration::from_millis(100)).expect("status update")
+}
+
+async fn relay(tx: tokio::sync::mpsc::Sender<String>, mut rx: tokio::sync::mpsc::Receiver<String>) {
+    let first = rx.recv().await.unwrap();
+    tx.send(first).await.expect("downstream alive");
+}
+
+fn main() {
+    worker_pool(vec![1, 2, 3]);
+}
@@ -0,0 +1,52 @@
+use std::net::UdpSocket;
+use std::sync::mpsc;
+use std::thread;
+
+fn worker_pool(jobs: Vec<u32>) {
+    let (tx, rx) = mpsc::sync_channel(8);
+    let handle = thread::spawn(move || {
+        for job in jobs {
+            if tx.send(job * 2).is_err() {
+                // Consumer hung up; stop producing.
+                break;
+            }
+        }
+    });
+    while let Ok(value) = rx.recv() {
+        println!("{value}");
+    }
+    if handle.join().is_err() {
+        eprintln!("producer thread panicked");
+    }
+}
+
+async fn relay(tx: tokio::sync::mpsc::Sender<String>, mut rx: tokio::sync::mpsc::Receiver<String>) {
+    while let Some(message) = rx.recv().await {
+        if let Err(err) = tx.send(message).await {
+            eprintln!("downstream closed, dropping {}", err.0);
+            return;
+        }
+    }
+}
+
+fn ping(socket: &UdpSocket) -> std::io::Result<usize> {
+    socket.send(b"ping")?;
+    let mut buf = [0u8; 64];
+    socket.recv(&mut buf)
+}
+
+fn main() {
+    worker_pool(vec![1, 2, 3]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_arrives() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(42).unwrap();
+        assert_eq!(rx.recv().unwrap(), 42);
+    }
+}
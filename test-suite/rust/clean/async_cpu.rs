@@ -0,0 +1,58 @@
+pub async fn register(password: String) -> String {
+    tokio::task::spawn_blocking(move || bcrypt::hash(&password, 12).unwrap_or_default())
+        .await
+        .unwrap_or_default()
+}
+
+pub async fn checksum(seed: u64) -> u64 {
+    tokio::task::block_in_place(|| {
+        let mut acc = seed;
+        for i in 0..5_000_000 {
+            acc = acc.wrapping_mul(31).wrapping_add(i);
+        }
+        acc
+    })
+}
+
+pub async fn retry_small(seed: u64) -> u64 {
+    let mut acc = seed;
+    for i in 0..8 {
+        acc += i;
+    }
+    acc
+}
+
+pub async fn export(rows: Vec<Vec<u32>>) -> String {
+    serde_json::to_string(&rows).unwrap_or_default()
+}
+
+pub async fn stream_rows(rows: Vec<Vec<u32>>, sink: &mut Vec<String>) {
+    for row in &rows {
+        sink.push(serde_json::to_string(row).unwrap_or_default());
+        tokio::task::yield_now().await;
+    }
+}
+
+mod bcrypt {
+    pub fn hash(password: &str, cost: u32) -> Result<String, ()> {
+        Ok(format!("{password}{cost}"))
+    }
+}
+
+mod serde_json {
+    pub fn to_string<T: std::fmt::Debug>(value: &T) -> Result<String, ()> {
+        Ok(format!("{value:?}"))
+    }
+}
+
+mod tokio {
+    pub mod task {
+        pub async fn spawn_blocking<F: FnOnce() -> R, R: Default>(f: F) -> Result<R, ()> {
+            Ok(f())
+        }
+        pub fn block_in_place<F: FnOnce() -> R, R>(f: F) -> R {
+            f()
+        }
+        pub async fn yield_now() {}
+    }
+}
@@ -0,0 +1,40 @@
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+
+pub struct Node {
+    pub children: Vec<Node>,
+}
+
+pub async fn walk(dir: PathBuf, depth: usize) -> usize {
+    if depth == 0 {
+        return 1;
+    }
+    Box::pin(walk(dir.join("child"), depth - 1)).await + 1
+}
+
+impl Node {
+    pub async fn visit(&self, level: u32) {
+        if level > 0 {
+            Box::pin(self.visit(level - 1)).await;
+        }
+    }
+}
+
+pub fn retry(attempt: u32) -> Pin<Box<dyn Future<Output = u32> + Send>> {
+    Box::pin(async move {
+        if attempt == 0 {
+            0
+        } else {
+            retry(attempt - 1).await
+        }
+    })
+}
+
+pub async fn fetch(attempt: u32) -> u32 {
+    attempt + 1
+}
+
+pub async fn fetch_twice() -> u32 {
+    fetch(1).await + fetch(2).await
+}
@@ -19,5 +19,5 @@ fn main() {
         h.join().expect("thread failed");
     }
 
-    println!("count={}", counter.lock().unwrap());
+    println!("count={}", counter.lock().expect("lock poisoned"));
 }
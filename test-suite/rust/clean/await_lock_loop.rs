@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+pub struct Client;
+
+impl Client {
+    pub async fn fetch(&self, key: &str) -> String {
+        key.to_string()
+    }
+}
+
+pub async fn refresh_all(cache: Arc<Mutex<HashMap<String, String>>>, client: Client, keys: Vec<String>) {
+    for key in keys {
+        let value = client.fetch(&key).await;
+        let mut entries = cache.lock().unwrap();
+        entries.insert(key, value);
+    }
+}
+
+pub async fn drain_queue(queue: Arc<RwLock<Vec<String>>>, client: Client) {
+    loop {
+        let next = {
+            let mut pending = queue.write().unwrap();
+            pending.pop()
+        };
+        let Some(next) = next else {
+            break;
+        };
+        client.fetch(&next).await;
+    }
+}
+
+pub async fn poll_until_ready(state: Arc<Mutex<bool>>, client: Client) {
+    while !*state.lock().unwrap() {
+        let status = client.fetch("status").await;
+        *state.lock().unwrap() = status == "ready";
+    }
+}
+
+pub async fn snapshot_then_send(cache: Arc<Mutex<HashMap<String, String>>>, client: Client) {
+    for round in 0..3 {
+        let keys: Vec<String> = {
+            let entries = cache.lock().unwrap();
+            entries.keys().cloned().collect()
+        };
+        for key in keys {
+            client.fetch(&format!("{round}:{key}")).await;
+        }
+    }
+}
+
+pub async fn count_then_send(cache: Arc<Mutex<HashMap<String, String>>>, client: Client) {
+    loop {
+        let pending = cache.lock().unwrap().len();
+        if pending == 0 {
+            break;
+        }
+        client.fetch(&format!("{pending}")).await;
+    }
+}
@@ -0,0 +1,36 @@
+pub struct Client {
+    runtime: tokio::runtime::Runtime,
+}
+
+impl Client {
+    // Sync entry point for non-async callers only.
+    pub fn fetch_sync(&self, key: &str) -> String {
+        self.runtime.block_on(lookup(key))
+    }
+
+    pub async fn handle(&self, key: &str) -> String {
+        lookup(key).await
+    }
+}
+
+pub fn main_entry() -> String {
+    let runtime = tokio::runtime::Runtime;
+    runtime.block_on(lookup("main"))
+}
+
+pub async fn lookup(key: &str) -> String {
+    key.to_owned()
+}
+
+mod tokio {
+    pub mod runtime {
+        use std::future::Future;
+
+        pub struct Runtime;
+        impl Runtime {
+            pub fn block_on<F: Future>(&self, _future: F) -> F::Output {
+                unimplemented!()
+            }
+        }
+    }
+}
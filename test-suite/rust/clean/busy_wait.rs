@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+pub fn wait_for_ready(ready: &AtomicBool) {
+    while !ready.load(Ordering::Acquire) {
+        thread::yield_now();
+    }
+}
+
+pub fn wait_for_shutdown(stop: &AtomicBool) {
+    loop {
+        if stop.load(Ordering::Acquire) {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+pub fn spin_lock(owner: &AtomicBool) {
+    while owner.swap(true, Ordering::Acquire) {
+        std::hint::spin_loop();
+    }
+}
+
+pub fn wait_on_condvar(pair: &(Mutex<bool>, Condvar)) {
+    let (lock, cvar) = pair;
+    let mut started = lock.lock().unwrap();
+    while !*started {
+        started = cvar.wait(started).unwrap();
+    }
+}
+
+pub fn drain(queue: &Mutex<Vec<u32>>) -> u32 {
+    let mut total = 0;
+    while !queue.lock().unwrap().is_empty() {
+        total += queue.lock().unwrap().pop().unwrap_or(0);
+    }
+    total
+}
+
+pub fn countdown(mut remaining: u32) {
+    let mut done = false;
+    while !done {
+        remaining -= 1;
+        done = remaining == 0;
+    }
+}
@@ -0,0 +1,21 @@
+pub fn is_palindrome(word: &str) -> bool {
+    word.chars().eq(word.chars().rev())
+}
+
+pub fn vowels(text: &str) -> usize {
+    text.chars()
+        .filter(|c| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u'))
+        .count()
+}
+
+pub fn every_other(text: &str) -> String {
+    text.chars().step_by(2).collect()
+}
+
+pub fn initials(names: &[&str]) -> Vec<Option<char>> {
+    let mut out = Vec::new();
+    for name in names {
+        out.push(name.chars().nth(0));
+    }
+    out
+}
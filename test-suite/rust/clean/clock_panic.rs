@@ -0,0 +1,21 @@
+// Skew-tolerant wall-clock math and checked Instant arithmetic.
+use std::fs;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub fn age_of(path: &str) -> Duration {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).unwrap_or(UNIX_EPOCH);
+    SystemTime::now().duration_since(modified).unwrap_or_default()
+}
+
+pub fn unix_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+}
+
+pub fn window_start(window: Duration) -> Option<Instant> {
+    Instant::now().checked_sub(window)
+}
+
+pub fn elapsed_ms(start: Instant) -> u128 {
+    let now = Instant::now();
+    (now - start).as_millis()
+}
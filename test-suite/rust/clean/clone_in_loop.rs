@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+pub struct Settings {
+    pub name: String,
+}
+
+#[derive(Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub fn matches(names: &[String], allow: &[String]) -> usize {
+    names.iter().filter(|name| allow.contains(name)).count()
+}
+
+pub fn lookup(keys: &[&str], table: &HashMap<String, u32>) -> u32 {
+    keys.iter().map(|key| table.get(*key).copied().unwrap_or(0)).sum()
+}
+
+pub fn report(settings: &Settings, rows: &[u32]) {
+    let label = &settings.name;
+    for row in rows {
+        println!("{label}: {row}");
+    }
+}
+
+pub fn fan_out(template: String, count: usize) -> Vec<String> {
+    let mut out = Vec::new();
+    for _ in 0..count {
+        // Each element needs its own owned copy.
+        out.push(template.clone());
+    }
+    out
+}
+
+pub fn offsets(origin: Point, steps: i32) -> i32 {
+    let mut sum = 0;
+    for step in 0..steps {
+        let p = origin.clone();
+        sum += p.x + p.y + step;
+    }
+    sum
+}
@@ -0,0 +1,30 @@
+use std::process::Command;
+
+pub struct Query<T>(pub T);
+
+pub fn archive(dir: &str) -> std::io::Result<()> {
+    // Each value is its own argv entry, so no shell ever parses it.
+    Command::new("tar").args(["czf", "backup.tgz", "--", dir]).status()?;
+    Ok(())
+}
+
+pub fn grep_logs(Query(pattern): Query<String>) -> std::io::Result<()> {
+    Command::new("grep")
+        .arg("-e")
+        .arg(&pattern)
+        .arg("/var/log/app.log")
+        .status()?;
+    Ok(())
+}
+
+pub fn resize(width: u32, height: u32) -> std::io::Result<()> {
+    Command::new("convert")
+        .args(["-resize", &format!("{}x{}", width, height)])
+        .status()?;
+    Ok(())
+}
+
+pub fn clone_repo(url: &str) -> std::io::Result<()> {
+    Command::new("git").args(["clone", "--", url]).status()?;
+    Ok(())
+}
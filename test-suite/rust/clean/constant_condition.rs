@@ -0,0 +1,39 @@
+// Real comparisons, NaN self-checks on floats, and satisfiable ranges.
+pub struct Window {
+    pub width: u32,
+    pub height: u32,
+}
+
+pub fn remaining(budget: i64, spent: i64) -> i64 {
+    if budget >= 0 {
+        budget - spent
+    } else {
+        0
+    }
+}
+
+pub fn has_items(items: &[u8]) -> bool {
+    items.len() > 0
+}
+
+pub fn same_size(a: &Window, b: &Window) -> bool {
+    a.width == b.width && a.height == b.height
+}
+
+pub fn is_nan(x: f64) -> bool {
+    x != x
+}
+
+pub fn in_range(code: i32) -> bool {
+    if code >= 400 && code < 500 {
+        return true;
+    }
+    false
+}
+
+pub fn retry(status: u16) -> bool {
+    if status == 429 || status == 503 {
+        return true;
+    }
+    false
+}
@@ -0,0 +1,37 @@
+use std::ffi::{CStr, CString, NulError};
+use std::os::raw::c_char;
+
+extern "C" {
+    fn puts(s: *const c_char) -> i32;
+}
+
+pub fn greet(name: &str) -> Result<(), NulError> {
+    let owned = CString::new(name)?;
+    // SAFETY: `owned` is a NUL-terminated CString that outlives the call.
+    unsafe { puts(owned.as_ptr()) };
+    Ok(())
+}
+
+pub fn banner() {
+    // SAFETY: a literal without NUL bytes cannot fail, and the temporary lives for the call.
+    unsafe { puts(CString::new("ready").unwrap().as_ptr()) };
+}
+
+pub fn owned_pointer(path: &str) -> Option<*mut c_char> {
+    let owned = CString::new(path).ok()?;
+    Some(owned.into_raw())
+}
+
+/// # Safety
+/// `raw` must point to a valid NUL-terminated string that outlives the call.
+pub unsafe fn read_name(raw: *const c_char) -> String {
+    CStr::from_ptr(raw).to_string_lossy().into_owned()
+}
+
+pub fn round_trip(text: &str) -> Option<String> {
+    let owned = CString::new(text).ok()?;
+    let ptr = owned.as_ptr();
+    // SAFETY: `ptr` comes from `owned`, which is still alive here.
+    let back = unsafe { CStr::from_ptr(ptr) };
+    Some(back.to_string_lossy().into_owned())
+}
@@ -0,0 +1,42 @@
+// Deletions either use fixed or temporary paths, or check the computed path
+// against its root before removing anything.
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const BUILD_DIR: &str = "target/ubs-tmp";
+
+pub fn clear_build() -> io::Result<()> {
+    fs::remove_dir_all(BUILD_DIR)
+}
+
+pub fn clear_cache() -> io::Result<()> {
+    let root = env::var("CACHE_ROOT").unwrap_or_default();
+    if root.is_empty() || root == "/" {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "refusing to clear an unset cache root"));
+    }
+    fs::remove_dir_all(format!("{root}/cache"))
+}
+
+pub fn delete_upload(upload_dir: &Path, file_name: &str) -> io::Result<()> {
+    let target = upload_dir.join(file_name).canonicalize()?;
+    if !target.starts_with(upload_dir) {
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, "outside upload dir"));
+    }
+    fs::remove_file(target)
+}
+
+pub fn remove_all(dir: &Path) -> io::Result<()> {
+    fs::remove_dir_all(dir)
+}
+
+pub fn scratch() -> io::Result<()> {
+    let scratch = env::temp_dir().join("ubs-scratch");
+    fs::create_dir_all(&scratch)?;
+    fs::remove_dir_all(&scratch)
+}
+
+pub fn owned(path: PathBuf) -> io::Result<()> {
+    fs::remove_file(path)
+}
@@ -0,0 +1,24 @@
+pub fn while_alive() -> u32 {
+    let value = 7u32;
+    let ptr = &value as *const u32;
+    // SAFETY: `value` is still in scope and not mutably borrowed.
+    let read = unsafe { *ptr };
+    drop(value);
+    read
+}
+
+pub fn vec_ptr() -> u8 {
+    let bytes = vec![1u8, 2, 3];
+    let first = bytes.as_ptr();
+    // SAFETY: `bytes` outlives this read and has at least one element.
+    let value = unsafe { first.read() };
+    let moved = bytes;
+    value + moved.len() as u8
+}
+
+pub fn scaled(factor: usize) -> usize {
+    let len = 4usize;
+    let ptr = &len as *const usize;
+    // SAFETY: `len` lives until the end of the function.
+    factor * unsafe { *ptr }
+}
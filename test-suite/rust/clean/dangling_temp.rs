@@ -0,0 +1,29 @@
+// The owned value is bound first, so it outlives every use of the pointer,
+// or the temporary is only used within the statement that created it.
+extern "C" {
+    fn submit(data: *const u8, len: usize);
+}
+
+pub fn send(header: &[u8]) {
+    let owned = header.to_vec();
+    let ptr = owned.as_ptr();
+    // SAFETY: `owned` outlives the call, so `ptr` stays valid for `owned.len()` bytes.
+    unsafe { submit(ptr, owned.len()) };
+}
+
+pub fn send_inline(header: &[u8]) {
+    // SAFETY: the temporary Vec lives until the end of this statement, after `submit` returns.
+    unsafe { submit(header.to_vec().as_ptr(), header.len()) };
+}
+
+pub fn label(id: u32) -> String {
+    format!("job-{id}")
+}
+
+pub fn first_word(input: &str) -> &str {
+    input.split_whitespace().next().unwrap_or("")
+}
+
+pub fn as_text(buf: &String) -> &str {
+    buf.as_str()
+}
@@ -0,0 +1,72 @@
+use std::sync::mpsc::Receiver;
+use std::thread::{self, JoinHandle};
+
+pub struct Flusher {
+    handle: JoinHandle<()>,
+}
+
+pub fn index_all(paths: Vec<String>) -> thread::Result<usize> {
+    let indexer = thread::spawn(move || paths.len());
+    indexer.join()
+}
+
+pub fn start_flusher(rx: Receiver<Vec<u8>>) -> Flusher {
+    let handle = thread::spawn(move || {
+        for chunk in rx {
+            let _ = std::fs::write("journal.bin", chunk);
+        }
+    });
+    Flusher { handle }
+}
+
+impl Flusher {
+    pub fn stop(self) {
+        let _ = self.handle.join();
+    }
+}
+
+pub fn fan_out(jobs: usize) {
+    let mut workers = Vec::new();
+    for job in 0..jobs {
+        workers.push(thread::spawn(move || job * 2));
+    }
+    for worker in workers {
+        let _ = worker.join();
+    }
+}
+
+pub fn start_named_worker() -> std::io::Result<JoinHandle<()>> {
+    thread::Builder::new()
+        .name("compactor".into())
+        .spawn(|| compact_segments())
+}
+
+pub fn start_heartbeat() {
+    // Intentionally detached: the heartbeat runs for the life of the process.
+    thread::spawn(|| loop { // ubs:ignore -- process-lifetime heartbeat worker
+        thread::sleep(std::time::Duration::from_secs(5));
+        send_heartbeat();
+    });
+}
+
+pub fn scoped_sum(values: &[u64]) -> u64 {
+    let (left, right) = values.split_at(values.len() / 2);
+    thread::scope(|scope| {
+        let a = scope.spawn(|| left.iter().sum::<u64>());
+        let b = scope.spawn(|| right.iter().sum::<u64>());
+        a.join().unwrap_or(0) + b.join().unwrap_or(0)
+    })
+}
+
+pub fn start_logger(rx: Receiver<String>) {
+    // The logger drains the channel and exits once every Sender is dropped.
+    thread::spawn(move || {
+        while let Ok(line) = rx.recv() {
+            eprintln!("{line}");
+        }
+    });
+}
+
+fn compact_segments() {}
+
+fn send_heartbeat() {}
@@ -0,0 +1,39 @@
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::sync::mpsc;
+
+fn save_state(path: &str, body: &str) -> io::Result<()> {
+    fs::write(path, body)
+}
+
+fn label(id: u32) -> String {
+    format!("item-{id}")
+}
+
+struct Journal {
+    file: File,
+}
+
+impl Drop for Journal {
+    fn drop(&mut self) {
+        let _ = self.file.flush();
+    }
+}
+
+fn main() -> io::Result<()> {
+    save_state("state.json", "{}")?;
+    let _ = label(7);
+    // Best effort: the lock file may already be gone after a crash.
+    let _ = fs::remove_file("stale.lock");
+    let mut html = String::new();
+    let _ = write!(html, "<p>{}</p>", label(1));
+    let (tx, rx) = mpsc::channel();
+    // The receiver only logs; if it has hung up there is nobody left to tell.
+    let _ = tx.send(html);
+    let _ = rx.recv().unwrap_or_default();
+    if let Err(err) = fs::remove_file("old.log") {
+        eprintln!("cleanup failed: {err}");
+    }
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::num::NonZeroU64;
+
+pub fn average(samples: &[u64]) -> Option<u64> {
+    if samples.is_empty() {
+        return None;
+    }
+    let total: u64 = samples.iter().sum();
+    Some(total / samples.len() as u64)
+}
+
+pub fn mean_latency(latencies: &[u32]) -> u32 {
+    let count = latencies.len() as u32;
+    if count == 0 {
+        return 0;
+    }
+    latencies.iter().sum::<u32>() / count
+}
+
+pub fn bucket_for(hash: u64, buckets: NonZeroU64) -> u64 {
+    hash % buckets.get()
+}
+
+pub fn per_page(total: usize, arg: &str) -> Option<usize> {
+    let page_size: usize = arg.parse().ok()?;
+    total.checked_div(page_size)
+}
+
+pub fn chunks(total: usize, arg: &str) -> usize {
+    let size: usize = arg.parse::<usize>().unwrap_or(1).max(1);
+    total / size
+}
+
+pub fn ratio(numerator: f64, denominator: f64) -> f64 {
+    numerator / denominator
+}
+
+pub fn half(value: u32) -> u32 {
+    value / 2
+}
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+pub struct Cache {
+    entries: Mutex<HashMap<String, u64>>,
+    stats: RwLock<u64>,
+}
+
+impl Cache {
+    pub fn bump(&self, key: &str) {
+        let mut entries = self.entries.lock().unwrap();
+        *entries.entry(key.to_string()).or_insert(0) += 1;
+        drop(entries);
+        let total: u64 = self.entries.lock().unwrap().values().sum();
+        println!("{total}");
+    }
+
+    pub fn lookup(&self, key: &str) -> u64 {
+        let hit = self.entries.lock().unwrap().get(key).copied();
+        match hit {
+            Some(hit) => hit,
+            None => {
+                self.entries.lock().unwrap().insert(key.to_string(), 0);
+                0
+            }
+        }
+    }
+
+    pub fn snapshot(&self) -> (u64, u64) {
+        let first = self.stats.read().unwrap();
+        let second = self.stats.read().unwrap();
+        (*first, *second)
+    }
+
+    pub fn scoped(&self) {
+        {
+            let mut entries = self.entries.lock().unwrap();
+            entries.clear();
+        }
+        self.entries.lock().unwrap().insert("reset".into(), 1);
+    }
+}
+
+pub fn fan_out(shared: Arc<Mutex<u64>>) {
+    let guard = shared.lock().unwrap();
+    let worker = {
+        let shared = Arc::clone(&shared);
+        thread::spawn(move || {
+            *shared.lock().unwrap() += 1;
+        })
+    };
+    println!("{}", *guard);
+    drop(guard);
+    worker.join().unwrap();
+}
@@ -0,0 +1,57 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::sync::Mutex;
+
+pub struct Connection;
+pub struct Statement<'conn>(&'conn Connection);
+
+impl Connection {
+    pub fn prepare(&self, _sql: &str) -> Statement<'_> {
+        Statement(self)
+    }
+}
+
+/// Fields drop in declaration order: the statement goes before its connection.
+pub struct CachedQuery {
+    stmt: Statement<'static>,
+    conn: Box<Connection>,
+}
+
+impl CachedQuery {
+    pub fn new(conn: Box<Connection>) -> Self {
+        let raw: *const Connection = &*conn;
+        // SAFETY: `raw` points into the boxed connection, which is stored alongside `stmt` and dropped after it.
+        let stmt = unsafe { (*raw).prepare("SELECT 1") };
+        Self { stmt, conn }
+    }
+}
+
+pub struct Settings {
+    name: &'static str,
+    path: String,
+}
+
+impl Settings {
+    pub fn new(path: String) -> Self {
+        let name = "default";
+        Settings { name, path }
+    }
+}
+
+static JOURNAL_LOCK: Mutex<()> = Mutex::new(());
+
+pub fn append_journal(path: &str, line: &str) -> std::io::Result<()> {
+    let _guard = JOURNAL_LOCK.lock().unwrap();
+    let mut out = BufWriter::new(File::create(path)?);
+    writeln!(out, "{line}")?;
+    out.flush()?;
+    drop(out);
+    Ok(())
+}
+
+pub async fn fetch_limited(limiter: &tokio::sync::Semaphore, addr: &str) -> std::io::Result<()> {
+    let _permit = limiter.acquire().await;
+    let mut conn = std::net::TcpStream::connect(addr)?;
+    conn.write_all(b"ping")?;
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::fs::File;
+use std::io::Write;
+
+pub struct Journal {
+    file: File,
+    pending: Vec<String>,
+}
+
+impl Journal {
+    fn flush_pending(&mut self) -> std::io::Result<()> {
+        for entry in self.pending.drain(..) {
+            self.file.write_all(entry.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Journal {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush_pending() {
+            eprintln!("journal flush failed: {err}");
+        }
+        if let Some(last) = self.pending.first() {
+            let _ = self.file.write_all(last.as_bytes());
+        }
+    }
+}
+
+pub struct Slot {
+    ids: Vec<u32>,
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            debug_assert!(self.ids.get(0).copied() != Some(0), "slot released twice");
+        }
+    }
+}
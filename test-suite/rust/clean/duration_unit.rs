@@ -0,0 +1,35 @@
+use std::time::{Duration, Instant};
+
+pub struct RetryConfig {
+    pub backoff_ms: u64,
+    pub timeout_secs: u64,
+    pub ttl: Duration,
+}
+
+pub fn backoff(config: &RetryConfig) -> Duration {
+    Duration::from_millis(config.backoff_ms)
+}
+
+pub fn request_timeout(config: &RetryConfig) -> Duration {
+    Duration::from_secs(config.timeout_secs)
+}
+
+pub fn poll_interval(interval_ms: u64) -> Duration {
+    Duration::from_secs(interval_ms / 1000)
+}
+
+pub fn rescale(elapsed: Duration) -> Duration {
+    Duration::from_millis(elapsed.as_millis() as u64)
+}
+
+pub fn timed_out(started: Instant, config: &RetryConfig) -> bool {
+    started.elapsed().as_secs() > config.timeout_secs
+}
+
+pub fn expired(age: Duration, config: &RetryConfig) -> bool {
+    age >= config.ttl
+}
+
+pub fn under_budget(started: Instant, budget_ms: u128) -> bool {
+    started.elapsed().as_millis() < budget_ms
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct Settings {
+    pub name: String,
+    pub retries: u32,
+}
+
+fn load_defaults() -> Settings {
+    let raw = std::fs::read_to_string("/etc/app/defaults.toml").unwrap_or_default();
+    Settings { name: raw, retries: 3 }
+}
+
+fn missing(key: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::NotFound, key.to_string())
+}
+
+pub fn settings(cache: &HashMap<String, Settings>, key: &str) -> Settings {
+    let found = cache.get(key).map(|s| Settings { name: s.name.clone(), retries: s.retries });
+    found.unwrap_or_else(load_defaults)
+}
+
+pub fn require(cache: &HashMap<String, u32>, key: &str) -> std::io::Result<u32> {
+    cache.get(key).copied().ok_or_else(|| missing(key))
+}
+
+pub fn label(name: Option<&str>, id: u32) -> String {
+    name.map_or_else(|| format!("user-{id}"), |n| n.to_uppercase())
+}
+
+pub fn limits(retries: Option<u32>, wait: Option<Duration>, tags: Option<Vec<String>>) -> (u32, Duration, Vec<String>, usize) {
+    let fallback = [0usize; 4];
+    (
+        retries.unwrap_or(3),
+        wait.unwrap_or(Duration::from_secs(5)),
+        tags.unwrap_or(Vec::new()),
+        Some(2).unwrap_or(fallback.len()),
+    )
+}
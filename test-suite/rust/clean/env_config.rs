@@ -0,0 +1,35 @@
+use std::env;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Missing(&'static str),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Missing(name) => write!(f, "missing environment variable {name}"),
+        }
+    }
+}
+
+pub struct Config {
+    pub database_url: String,
+    pub port: u16,
+    pub region: Option<String>,
+}
+
+pub fn load() -> Result<Config, ConfigError> {
+    let database_url = env::var("DATABASE_URL").map_err(|_| ConfigError::Missing("DATABASE_URL"))?;
+    let port = env::var("APP_PORT")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(8080);
+    let region = env::var("AWS_REGION").ok();
+    Ok(Config { database_url, port, region })
+}
+
+pub fn log_level() -> String {
+    env::var("LOG_LEVEL").unwrap_or_else(|_| "info".to_string())
+}
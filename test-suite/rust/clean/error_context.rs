@@ -0,0 +1,43 @@
+use std::env;
+use std::fs;
+
+use anyhow::Context;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("cannot read config")]
+    Read(#[from] std::io::Error),
+    #[error("bad config at {path}")]
+    Parse {
+        path: String,
+        #[source]
+        cause: toml::de::Error,
+    },
+    #[error("invalid port")]
+    Port { source: std::num::ParseIntError },
+    #[error("missing {0}")]
+    Missing(&'static str),
+}
+
+pub fn read(path: &str) -> Result<String, ConfigError> {
+    Ok(fs::read_to_string(path)?)
+}
+
+pub fn port(raw: &str) -> Result<u16, ConfigError> {
+    raw.trim().parse::<u16>().map_err(|source| ConfigError::Port { source })
+}
+
+pub fn home() -> Result<String, ConfigError> {
+    env::var("HOME").map_err(|_| ConfigError::Missing("HOME"))
+}
+
+pub fn load(path: &str) -> anyhow::Result<toml::Value> {
+    let body = fs::read_to_string(path).with_context(|| format!("reading {path}"))?;
+    body.parse::<toml::Value>()
+        .map_err(|err| ConfigError::Parse { path: path.to_string(), cause: err })
+        .context("parsing config")
+}
+
+pub fn scheme(raw: &str) -> Result<url::Url, &'static str> {
+    url::Url::parse(raw).map_err(|_| "invalid url")
+}
@@ -0,0 +1,40 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead};
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing,
+    Invalid(ParseIntError),
+}
+
+pub fn port_from_args() -> Result<u16, InputError> {
+    let arg = env::args().nth(1).ok_or(InputError::Missing)?;
+    arg.parse::<u16>().map_err(InputError::Invalid)
+}
+
+pub fn read_count() -> Result<usize, InputError> {
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).map_err(|_| InputError::Missing)?;
+    line.trim().parse().map_err(InputError::Invalid)
+}
+
+pub fn load_limits(path: &str) -> Vec<u32> {
+    let content = fs::read_to_string(path).unwrap_or_default();
+    content.lines().filter_map(|l| l.parse::<u32>().ok()).collect()
+}
+
+pub fn sum_stdin() -> i64 {
+    io::stdin()
+        .lock()
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| line.trim().parse::<i64>().ok())
+        .sum()
+}
+
+pub fn default_port() -> u16 {
+    // Constant input cannot fail to parse.
+    "8080".parse().unwrap()
+}
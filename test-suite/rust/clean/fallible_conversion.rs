@@ -0,0 +1,25 @@
+// Conversions propagate their error, or only convert values known to fit.
+use std::convert::TryFrom;
+use std::num::NonZeroUsize;
+
+const DEFAULT_WORKERS: usize = 4;
+
+pub fn frame_len(payload: &[u8]) -> Result<u32, std::num::TryFromIntError> {
+    u32::try_from(payload.len())
+}
+
+pub fn header(bytes: &[u8]) -> Option<[u8; 4]> {
+    bytes.get(..4)?.try_into().ok()
+}
+
+pub fn default_pool() -> NonZeroUsize {
+    NonZeroUsize::new(DEFAULT_WORKERS).unwrap()
+}
+
+pub fn pool_size(workers: usize) -> NonZeroUsize {
+    NonZeroUsize::new(workers.max(1)).unwrap()
+}
+
+pub fn small() -> u8 {
+    u8::try_from(200u32).unwrap()
+}
@@ -0,0 +1,55 @@
+use std::ptr;
+use std::slice;
+
+pub struct Frame {
+    pub header_len: usize,
+    pub payload: Vec<u8>,
+}
+
+pub fn payload_view(frame: &Frame) -> &[u8] {
+    let len = frame.header_len.min(frame.payload.len());
+    // SAFETY: `len` is clamped to the payload length, and the pointer comes from the same Vec.
+    unsafe { slice::from_raw_parts(frame.payload.as_ptr(), len) }
+}
+
+pub fn mirror(scratch: &mut Vec<u8>) -> &mut [u8] {
+    let ptr = scratch.as_mut_ptr();
+    let count = scratch.len();
+    // SAFETY: `ptr` and `count` both come from `scratch`, which stays borrowed for the result.
+    unsafe { slice::from_raw_parts_mut(ptr, count) }
+}
+
+/// # Safety
+/// `data` must point to `len` readable bytes.
+pub unsafe extern "C" fn checksum(data: *const u8, len: usize) -> u32 {
+    let bytes = slice::from_raw_parts(data, len);
+    bytes.iter().map(|b| u32::from(*b)).sum()
+}
+
+pub struct RawBuf {
+    ptr: *const u8,
+    len: usize,
+}
+
+impl RawBuf {
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: `RawBuf` is only built from a live allocation of `len` bytes.
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+pub fn restore(dst: &mut [u8], snapshot: &[u8]) {
+    let n = snapshot.len().min(dst.len());
+    // SAFETY: `n` is at most the length of both slices, which cannot overlap.
+    unsafe {
+        ptr::copy_nonoverlapping(snapshot.as_ptr(), dst.as_mut_ptr(), n);
+    }
+}
+
+pub fn fill(out: &mut [u8], input: &[u8]) {
+    let n = out.len().min(input.len());
+    // SAFETY: `n` is at most the length of both slices, which cannot overlap.
+    unsafe {
+        ptr::copy_nonoverlapping(input.as_ptr(), out.as_mut_ptr(), n);
+    }
+}
@@ -0,0 +1,30 @@
+// Secrets are owner-only, shared files are at most group-readable, and
+// writability is granted to the owner explicitly.
+use std::fs::{self, OpenOptions, Permissions};
+use std::io;
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+use std::path::Path;
+
+pub fn publish_socket_dir(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    fs::set_permissions(dir, Permissions::from_mode(0o750))
+}
+
+pub fn save_private_key(pem: &[u8]) -> io::Result<()> {
+    fs::write("keys/server.pem", pem)?;
+    fs::set_permissions("keys/server.pem", Permissions::from_mode(0o600))
+}
+
+pub fn open_token_cache(path: &Path) -> io::Result<fs::File> {
+    OpenOptions::new().create(true).write(true).mode(0o600).open(path.join("token"))
+}
+
+pub fn publish_report(path: &Path) -> io::Result<()> {
+    fs::set_permissions(path, Permissions::from_mode(0o644))
+}
+
+pub fn unlock(path: &Path) -> io::Result<()> {
+    let mut perms = fs::metadata(path)?.permissions();
+    perms.set_mode(perms.mode() | 0o200);
+    fs::set_permissions(path, perms)
+}
@@ -0,0 +1,29 @@
+use std::cmp::Ordering;
+
+pub struct Account {
+    pub balance: f64,
+    pub limit: f64,
+    pub id: u64,
+}
+
+const TOLERANCE: f64 = 1e-9;
+
+pub fn is_settled(account: &Account) -> bool {
+    (account.balance - account.limit).abs() <= TOLERANCE
+}
+
+pub fn same_ratio(a: f32, b: f32) -> bool {
+    ((a / b) - 0.75).abs() <= f32::EPSILON
+}
+
+pub fn order(a: f64, b: f64) -> Ordering {
+    a.total_cmp(&b)
+}
+
+pub fn same_account(left: &Account, right: &Account) -> bool {
+    left.id == right.id
+}
+
+pub fn counts_match(a: usize, b: usize) -> bool {
+    a == b
+}
@@ -0,0 +1,7 @@
+pub fn is_unset(weight: f64) -> bool {
+    weight == 0.0
+}
+
+pub fn has_value(scale: f32) -> bool {
+    scale != 0.0f32
+}
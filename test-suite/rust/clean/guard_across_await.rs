@@ -0,0 +1,43 @@
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+async fn flush(count: u64) {
+    tokio::time::sleep(Duration::from_millis(count)).await;
+}
+
+async fn bump_then_flush(counter: Arc<Mutex<u64>>) {
+    let value = {
+        let mut guard = counter.lock().unwrap();
+        *guard += 1;
+        *guard
+    };
+    flush(value).await;
+}
+
+async fn read_then_yield(config: Arc<RwLock<String>>) -> usize {
+    let snapshot = config.read().expect("config lock");
+    let len = snapshot.len();
+    drop(snapshot);
+    tokio::task::yield_now().await;
+    len
+}
+
+async fn copy_out(counter: Arc<Mutex<u64>>) {
+    let value = *counter.lock().unwrap();
+    flush(value).await;
+}
+
+async fn tokio_scoped(state: Arc<tokio::sync::Mutex<Vec<u8>>>) {
+    let len = {
+        let mut buffer = state.lock().await;
+        buffer.push(1);
+        buffer.len()
+    };
+    flush(len as u64).await;
+}
+
+async fn temporary_guard(queue: Arc<Mutex<Vec<u8>>>) {
+    // The guard is a temporary dropped at the end of the `let`; only the length survives.
+    let pending = queue.lock().unwrap().len();
+    flush(pending as u64).await;
+}
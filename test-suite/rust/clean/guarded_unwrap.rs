@@ -0,0 +1,80 @@
+struct Config {
+    port: Option<u16>,
+}
+
+fn is_some_guard(value: Option<i32>) -> i32 {
+    if value.is_some() {
+        return value.unwrap() * 2;
+    }
+    0
+}
+
+fn is_ok_guard(resp: Result<u32, String>) -> u32 {
+    if resp.is_ok() && resp.clone().unwrap() > 10 {
+        resp.expect("checked with is_ok")
+    } else {
+        0
+    }
+}
+
+fn match_some_arm(value: Option<&str>) -> usize {
+    match value {
+        Some(_) => value.unwrap().len(),
+        None => 0,
+    }
+}
+
+fn match_ok_arm(resp: &Result<u8, String>) -> u8 {
+    match resp.as_ref() {
+        Ok(_) => {
+            let byte = *resp.as_ref().unwrap();
+            byte + 1
+        }
+        Err(_) => 0,
+    }
+}
+
+fn early_exit_guard(value: Option<u64>) -> u64 {
+    if value.is_none() {
+        return 0;
+    }
+    value.unwrap()
+}
+
+fn conjoined_guard(value: Option<i32>, enabled: bool) -> i32 {
+    if enabled && value.is_some() && value.unwrap() > 0 {
+        value.unwrap()
+    } else {
+        0
+    }
+}
+
+fn disjoined_early_exit(value: Option<u64>, disabled: bool) -> u64 {
+    if disabled || value.is_none() {
+        return 0;
+    }
+    value.unwrap()
+}
+
+fn field_guard(config: &Config) -> u16 {
+    if config.port.is_some() {
+        config.port.unwrap()
+    } else {
+        8080
+    }
+}
+
+fn main() {
+    let config = Config { port: Some(80) };
+    println!(
+        "{} {} {} {} {} {} {} {}",
+        is_some_guard(Some(1)),
+        is_ok_guard(Ok(11)),
+        match_some_arm(Some("ok")),
+        match_ok_arm(&Ok(1)),
+        early_exit_guard(Some(3)),
+        conjoined_guard(Some(4), true),
+        disjoined_early_exit(Some(5), false),
+        field_guard(&config)
+    );
+}
@@ -0,0 +1,18 @@
+use std::env;
+
+pub const DEPLOY_USER: &str = "deploy";
+pub const ADMIN_PASSWORD_ENV: &str = "ADMIN_PASSWORD";
+// Repetitive placeholders are left for the operator to replace.
+pub const DEFAULT_PASSWORD_MASK: &str = "********";
+
+pub fn admin_password() -> Result<String, env::VarError> {
+    env::var(ADMIN_PASSWORD_ENV)
+}
+
+pub fn github_token() -> Result<String, env::VarError> {
+    env::var("GITHUB_TOKEN")
+}
+
+pub fn docs_url() -> &'static str {
+    "https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_access-keys.html"
+}
@@ -0,0 +1,40 @@
+// Output, hashes, and "first element" choices come from sorted keys or
+// ordered collections, so they are stable across runs.
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+
+pub fn render(counts: &HashMap<String, u32>) -> String {
+    let mut words: Vec<_> = counts.iter().collect();
+    words.sort();
+    let mut out = String::new();
+    for (word, count) in words {
+        writeln!(out, "{word}: {count}").unwrap();
+    }
+    out
+}
+
+pub fn primary_owner(owners: &HashSet<String>) -> Option<&String> {
+    owners.iter().min()
+}
+
+pub fn render_ordered(counts: &BTreeMap<String, u32>) -> String {
+    let mut out = String::new();
+    for (word, count) in counts {
+        writeln!(out, "{word}: {count}").unwrap();
+    }
+    out
+}
+
+pub fn total(counts: &HashMap<String, u32>) -> u32 {
+    let mut sum = 0;
+    for count in counts.values() {
+        sum += count;
+    }
+    sum
+}
+
+pub fn header(tags: &HashSet<String>) -> String {
+    let mut sorted: Vec<_> = tags.iter().cloned().collect();
+    sorted.sort();
+    sorted.join(",")
+}
@@ -0,0 +1,42 @@
+pub fn pick(items: &[String], choice: &str) -> Option<String> {
+    let index: usize = choice.trim().parse().ok()?;
+    items.get(index).cloned()
+}
+
+pub fn pick_checked(items: &[String], choice: &str) -> String {
+    let index: usize = choice.trim().parse().unwrap_or(0);
+    if index >= items.len() {
+        return String::new();
+    }
+    items[index].clone()
+}
+
+pub fn newest(events: &[u64]) -> Option<u64> {
+    events.last().copied()
+}
+
+pub fn tail(bytes: &[u8]) -> u8 {
+    if bytes.is_empty() {
+        return 0;
+    }
+    let len = bytes.len();
+    if len > 0 {
+        bytes[len - 1]
+    } else {
+        0
+    }
+}
+
+pub fn sum_all(values: &[i32]) -> i32 {
+    let mut total = 0;
+    for i in 0..values.len() {
+        total += values[i];
+    }
+    total
+}
+
+pub fn window<'a>(data: &'a [u8], start: &str, end: &str) -> Option<&'a [u8]> {
+    let from: usize = start.parse().ok()?;
+    let to: usize = end.parse().ok()?;
+    data.get(from..to)
+}
@@ -0,0 +1,79 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::Receiver;
+use std::thread;
+
+pub fn drain_queue(queue: &mut VecDeque<u32>) -> u32 {
+    let mut total = 0;
+    loop {
+        match queue.pop_front() {
+            Some(item) => total += item,
+            None => break total,
+        }
+    }
+}
+
+pub fn retry_connect(attempts: &mut u32) {
+    'outer: loop {
+        *attempts += 1;
+        for _ in 0..3 {
+            if *attempts > 10 {
+                break 'outer;
+            }
+        }
+    }
+}
+
+pub fn forward(rx: Receiver<String>) -> Result<(), std::sync::mpsc::RecvError> {
+    loop {
+        let line = rx.recv()?;
+        println!("{line}");
+    }
+}
+
+pub fn first_even(values: &[u32]) -> Option<u32> {
+    let mut iter = values.iter();
+    loop {
+        let value = iter.next()?;
+        if value % 2 == 0 {
+            return Some(*value);
+        }
+    }
+}
+
+pub fn serve_forever(rx: Receiver<String>) -> ! {
+    loop {
+        if let Ok(line) = rx.recv() {
+            println!("{line}");
+        }
+    }
+}
+
+pub async fn pump(mut rx: tokio::sync::mpsc::Receiver<u32>) {
+    loop {
+        let Some(value) = rx.recv().await else {
+            break;
+        };
+        println!("{value}");
+    }
+}
+
+pub fn spawn_reader(rx: Receiver<String>) {
+    thread::spawn(move || {
+        for line in rx {
+            println!("{line}");
+        }
+    });
+}
+
+mod tokio {
+    pub mod sync {
+        pub mod mpsc {
+            pub struct Receiver<T>(pub Option<T>);
+            impl<T> Receiver<T> {
+                pub async fn recv(&mut self) -> Option<T> {
+                    self.0.take()
+                }
+            }
+        }
+    }
+}
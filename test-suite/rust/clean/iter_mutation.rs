@@ -0,0 +1,41 @@
+// retain, reverse index loops, and remove-then-break keep iteration sound.
+use std::collections::HashMap;
+
+pub fn drop_expired(sessions: &mut Vec<u64>, now: u64) {
+    sessions.retain(|&t| t >= now);
+}
+
+pub fn drop_from_back(sessions: &mut Vec<u64>, now: u64) {
+    for i in (0..sessions.len()).rev() {
+        if sessions[i] < now {
+            sessions.remove(i);
+        }
+    }
+}
+
+pub fn drop_empty(lines: &mut Vec<String>) {
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].is_empty() {
+            lines.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+pub fn remove_first(ids: &mut Vec<u32>, target: u32) {
+    for i in 0..ids.len() {
+        if ids[i] == target {
+            ids.remove(i);
+            break;
+        }
+    }
+}
+
+pub fn expand(counts: &mut HashMap<String, u32>) {
+    let overflow: Vec<String> = counts.iter().filter(|(_, v)| **v > 10).map(|(k, _)| format!("{k}-overflow")).collect();
+    for key in overflow {
+        counts.insert(key, 0);
+    }
+}
@@ -0,0 +1,19 @@
+pub struct Parser<'a> {
+    input: &'a str,
+}
+
+pub fn leak_name(name: &str) -> &'static str {
+    Box::leak(name.to_owned().into_boxed_str())
+}
+
+pub fn extend(value: &[u8]) -> Vec<u8> {
+    value.to_vec()
+}
+
+pub fn detach(parser: Parser<'_>) -> String {
+    parser.input.to_owned()
+}
+
+pub fn bits(value: f32) -> u32 {
+    value.to_bits()
+}
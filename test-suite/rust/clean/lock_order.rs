@@ -0,0 +1,32 @@
+use std::sync::Mutex;
+
+pub struct Bank {
+    accounts: Mutex<Vec<u64>>,
+    audit: Mutex<Vec<String>>,
+}
+
+impl Bank {
+    // Every path takes accounts before audit.
+    pub fn transfer(&self, from: usize, to: usize, amount: u64) {
+        let mut accounts = self.accounts.lock().unwrap();
+        accounts[from] -= amount;
+        accounts[to] += amount;
+        let mut audit = self.audit.lock().unwrap();
+        audit.push(format!("{from}->{to}: {amount}"));
+    }
+
+    pub fn report(&self) -> String {
+        let accounts = self.accounts.lock().unwrap();
+        let audit = self.audit.lock().unwrap();
+        format!("{} entries, {} accounts", audit.len(), accounts.len())
+    }
+
+    // The first guard is released before the second lock is taken.
+    pub fn audit_then_count(&self) -> usize {
+        let audit = self.audit.lock().unwrap();
+        let entries = audit.len();
+        drop(audit);
+        let accounts = self.accounts.lock().unwrap();
+        entries + accounts.len()
+    }
+}
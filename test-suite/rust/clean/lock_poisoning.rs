@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError, RwLock};
+
+struct Registry {
+    hits: Mutex<u64>,
+    routes: RwLock<HashMap<String, String>>,
+}
+
+impl Registry {
+    fn record_hit(&self) {
+        // The counter stays valid even if another holder panicked, so recover the guard.
+        let mut hits = self.hits.lock().unwrap_or_else(PoisonError::into_inner);
+        *hits += 1;
+    }
+
+    fn lookup(&self, path: &str) -> Option<String> {
+        let routes = self.routes.read().ok()?;
+        routes.get(path).cloned()
+    }
+
+    fn register(&self, path: &str, target: &str) -> Result<(), String> {
+        let mut routes = self.routes.write().map_err(|e| e.to_string())?;
+        routes.insert(path.to_string(), target.to_string());
+        Ok(())
+    }
+}
+
+fn main() -> Result<(), String> {
+    let registry = Registry {
+        hits: Mutex::new(0),
+        routes: RwLock::new(HashMap::new()),
+    };
+    registry.register("/", "index")?;
+    registry.record_hit();
+    let _ = registry.lookup("/");
+    Ok(())
+}
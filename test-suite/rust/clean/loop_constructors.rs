@@ -0,0 +1,25 @@
+use std::sync::LazyLock;
+
+use regex::{Regex, RegexSet};
+
+static DATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}").unwrap());
+
+pub fn count_matches(lines: &[String]) -> usize {
+    lines.iter().filter(|line| DATE.is_match(line)).count()
+}
+
+pub fn fetch_all(urls: &[String]) -> Vec<String> {
+    let client = reqwest::blocking::Client::new();
+    let mut bodies = Vec::new();
+    for url in urls {
+        if let Ok(resp) = client.get(url).send() {
+            bodies.push(resp.text().unwrap_or_default());
+        }
+    }
+    bodies
+}
+
+pub fn classify(inputs: &[&str]) -> Vec<bool> {
+    let set = RegexSet::new([r"error", r"panic"]).unwrap();
+    inputs.iter().map(|input| set.is_match(input)).collect()
+}
@@ -0,0 +1,26 @@
+use std::convert::TryFrom;
+
+pub struct Packet {
+    total_len: u64,
+    offset: i64,
+}
+
+pub fn header_len(packet: &Packet) -> Result<u32, std::num::TryFromIntError> {
+    u32::try_from(packet.total_len)
+}
+
+pub fn port(raw: usize) -> Option<u16> {
+    u16::try_from(raw).ok()
+}
+
+pub fn seek_position(packet: &Packet) -> Option<u64> {
+    u64::try_from(packet.offset).ok()
+}
+
+pub fn low_byte(value: u32) -> u8 {
+    (value & 0xFF) as u8
+}
+
+pub fn widened(small: u16, signed: i32, count: u8) -> (u64, i64, i32) {
+    (small as u64, signed as i64, count as i32)
+}
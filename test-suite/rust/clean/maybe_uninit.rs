@@ -0,0 +1,34 @@
+use std::mem::MaybeUninit;
+
+pub fn written() -> u64 {
+    let mut slot = MaybeUninit::<u64>::uninit();
+    slot.write(42);
+    // SAFETY: `write` initialized the value above.
+    unsafe { slot.assume_init() }
+}
+
+pub fn via_pointer() -> [u8; 4] {
+    let mut buf = MaybeUninit::<[u8; 4]>::uninit();
+    // SAFETY: all four bytes are written before assume_init.
+    unsafe {
+        buf.as_mut_ptr().write([1, 2, 3, 4]);
+        buf.assume_init()
+    }
+}
+
+pub fn slots() -> [MaybeUninit<u8>; 4] {
+    // SAFETY: an array of `MaybeUninit` is valid without initialization.
+    let slots: [MaybeUninit<u8>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+    slots
+}
+
+pub fn nested() -> [MaybeUninit<u32>; 8] {
+    // SAFETY: the payload is itself `MaybeUninit`, so no bytes are read as initialized.
+    unsafe { MaybeUninit::<[MaybeUninit<u32>; 8]>::uninit().assume_init() }
+}
+
+pub fn declared() -> [MaybeUninit<u16>; 2] {
+    let raw: MaybeUninit<[MaybeUninit<u16>; 2]> = MaybeUninit::uninit();
+    // SAFETY: an array of `MaybeUninit` needs no initialization.
+    unsafe { raw.assume_init() }
+}
@@ -0,0 +1,28 @@
+use std::mem::{self, ManuallyDrop};
+use std::sync::OnceLock;
+
+pub fn banner() -> &'static str {
+    static BANNER: OnceLock<&'static str> = OnceLock::new();
+    BANNER.get_or_init(|| Box::leak(String::from("ubs").into_boxed_str()))
+}
+
+/// Hands the buffer to C; the C side calls `release_buffer` to free it.
+pub fn into_c_buffer(mut bytes: Vec<u8>) -> (*mut u8, usize, usize) {
+    let ptr = bytes.as_mut_ptr();
+    let parts = (ptr, bytes.len(), bytes.capacity());
+    mem::forget(bytes);
+    parts
+}
+
+#[no_mangle]
+pub extern "C" fn export_name() -> *const u8 {
+    let name = Box::new(*b"ubs\0");
+    let ptr = name.as_ptr();
+    mem::forget(name);
+    ptr
+}
+
+pub fn consume(buffer: Vec<u8>) -> Vec<u8> {
+    let held = ManuallyDrop::new(buffer);
+    ManuallyDrop::into_inner(held)
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub struct Sessions {
+    entries: Mutex<HashMap<u64, String>>,
+}
+
+impl Sessions {
+    pub async fn insert(&self, id: u64, user: String) {
+        self.entries.lock().unwrap().insert(id, user);
+    }
+}
+
+pub struct Connection {
+    // The guard must live across the write, so an async-aware Mutex is right here.
+    socket: tokio::sync::Mutex<Vec<u8>>,
+}
+
+impl Connection {
+    pub async fn send(&self, frame: &[u8]) {
+        let mut socket = self.socket.lock().await;
+        flush().await;
+        socket.extend_from_slice(frame);
+    }
+}
+
+pub async fn refresh(cache: Arc<Mutex<Vec<u8>>>) {
+    {
+        let mut guard = cache.lock().unwrap();
+        guard.clear();
+    }
+    flush().await;
+}
+
+async fn flush() {}
+
+mod tokio {
+    pub mod sync {
+        pub struct Mutex<T>(std::sync::Mutex<T>);
+        impl<T> Mutex<T> {
+            pub async fn lock(&self) -> std::sync::MutexGuard<'_, T> {
+                self.0.lock().unwrap()
+            }
+        }
+    }
+}
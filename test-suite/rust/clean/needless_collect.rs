@@ -0,0 +1,20 @@
+pub fn has_errors(lines: &[String]) -> bool {
+    lines.iter().any(|l| l.contains("ERROR"))
+}
+
+pub fn total_len(words: &[&str]) -> usize {
+    words.iter().map(|w| w.len()).sum()
+}
+
+pub fn stats(values: &[u32]) -> (usize, u32) {
+    // Collected once and walked twice, so the Vec pays for itself.
+    let evens: Vec<u32> = values.iter().copied().filter(|v| v % 2 == 0).collect();
+    let max = evens.iter().copied().max().unwrap_or(0);
+    (evens.len(), max)
+}
+
+pub fn sorted(values: &[u32]) -> Vec<u32> {
+    let mut out: Vec<u32> = values.to_vec().into_iter().collect();
+    out.sort_unstable();
+    out
+}
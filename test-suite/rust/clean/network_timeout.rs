@@ -0,0 +1,47 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+pub async fn fetch_profile(id: u64) -> reqwest::Result<String> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()?;
+    client
+        .get(format!("https://api.example.test/profiles/{id}"))
+        .send()
+        .await?
+        .text()
+        .await
+}
+
+pub async fn fetch_with_request_timeout(client: &reqwest::Client) -> reqwest::Result<String> {
+    client
+        .get("https://api.example.test/health")
+        .timeout(Duration::from_secs(2))
+        .send()
+        .await?
+        .text()
+        .await
+}
+
+pub fn ping(addr: &str) -> std::io::Result<Vec<u8>> {
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(5)))?;
+    stream.write_all(b"PING\r\n")?;
+    let mut reply = vec![0u8; 64];
+    let n = stream.read(&mut reply)?;
+    reply.truncate(n);
+    Ok(reply)
+}
+
+pub async fn ping_async(addr: &str) -> std::io::Result<()> {
+    let _stream = tokio::time::timeout(Duration::from_secs(3), tokio::net::TcpStream::connect(addr))
+        .await
+        .map_err(|_| std::io::ErrorKind::TimedOut)??;
+    Ok(())
+}
+
+pub fn dial(addr: &str) -> std::io::Result<TcpStream> {
+    TcpStream::connect(addr)
+}
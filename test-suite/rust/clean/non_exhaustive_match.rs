@@ -0,0 +1,42 @@
+// Fallback arms on #[non_exhaustive] enums handle the unknown case instead of
+// panicking, and local non_exhaustive enums can be matched exhaustively.
+use std::io;
+
+#[non_exhaustive]
+pub enum Mode {
+    Read,
+    Write,
+}
+
+pub fn classify(err: &io::Error) -> &'static str {
+    match err.kind() {
+        io::ErrorKind::NotFound => "missing",
+        io::ErrorKind::PermissionDenied => "denied",
+        _ => "io error",
+    }
+}
+
+pub fn parse_code(raw: &str) -> Result<u8, String> {
+    match raw.parse::<u8>() {
+        Ok(code) => Ok(code),
+        Err(e) => match e.kind() {
+            std::num::IntErrorKind::Empty => Ok(0),
+            other => Err(format!("bad code: {other:?}")),
+        },
+    }
+}
+
+pub fn label(mode: &Mode) -> &'static str {
+    match mode {
+        Mode::Read => "r",
+        Mode::Write => "w",
+    }
+}
+
+pub fn create_marker(path: &std::path::Path) -> io::Result<()> {
+    match std::fs::File::create_new(path) {
+        Ok(_) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(()),
+        Err(err) => Err(err),
+    }
+}
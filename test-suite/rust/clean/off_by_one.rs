@@ -0,0 +1,61 @@
+// Exclusive ranges over the full length, pairwise windows, and prefix slicing.
+pub fn checksum(bytes: &[u8]) -> u32 {
+    let mut sum = 0u32;
+    for i in 0..bytes.len() {
+        sum = sum.wrapping_add(bytes[i] as u32);
+    }
+    sum
+}
+
+pub fn is_sorted(items: &[u32]) -> bool {
+    if items.is_empty() {
+        return true;
+    }
+    for i in 0..items.len() - 1 {
+        if items[i] > items[i + 1] {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn all_but_last(items: &[u32]) -> &[u32] {
+    &items[..items.len().saturating_sub(1)]
+}
+
+pub fn prefixes(word: &str) -> Vec<&str> {
+    (0..=word.len()).filter(|&i| word.is_char_boundary(i)).map(|i| &word[..i]).collect()
+}
+
+pub fn count_spaces(buf: &[u8]) -> usize {
+    let mut i = 0;
+    let mut spaces = 0;
+    while i < buf.len() {
+        if buf[i] == b' ' {
+            spaces += 1;
+        }
+        i += 1;
+    }
+    spaces
+}
+
+pub fn total(values: &[u64]) -> u64 {
+    let mut sum = 0;
+    for i in 0..values.len() {
+        sum += values[i];
+    }
+    sum
+}
+
+pub fn running_totals(values: &[u64]) -> Vec<u64> {
+    let mut out = Vec::with_capacity(values.len() + 1);
+    let mut sum = 0;
+    for i in 0..=values.len() {
+        out.push(sum);
+        if i == values.len() {
+            break;
+        }
+        sum += values[i];
+    }
+    out
+}
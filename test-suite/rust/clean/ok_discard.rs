@@ -0,0 +1,43 @@
+use std::env;
+use std::fs;
+use std::sync::mpsc::Receiver;
+
+pub fn persist(path: &str, body: &str) -> std::io::Result<()> {
+    fs::write(path, body)?;
+    // Best effort: a missing temp file just means the previous run finished cleanly.
+    fs::remove_file(format!("{path}.tmp")).ok();
+    Ok(())
+}
+
+pub fn load_port(path: &str) -> u16 {
+    match fs::read_to_string(path) {
+        Ok(raw) => match raw.trim().parse::<u16>() {
+            Ok(port) => port,
+            Err(err) => {
+                eprintln!("invalid port in {path}: {err}");
+                8080
+            }
+        },
+        Err(err) => {
+            eprintln!("cannot read {path}: {err}");
+            8080
+        }
+    }
+}
+
+pub fn region() -> String {
+    if let Some(region) = env::var("AWS_REGION").ok() {
+        return region;
+    }
+    String::from("us-east-1")
+}
+
+pub fn drain(rx: &Receiver<String>) -> Vec<String> {
+    let mut out = Vec::new();
+    while let Some(line) = rx.try_recv().ok() {
+        out.push(line);
+    }
+    let cached = env::var("CACHE_DIR").ok();
+    out.extend(cached);
+    out
+}
@@ -0,0 +1,40 @@
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+pub struct Query<T>(pub T);
+
+pub struct Download {
+    pub file: String,
+}
+
+pub fn download(static_root: &Path, Query(params): Query<Download>) -> io::Result<Vec<u8>> {
+    let candidate = static_root.join(&params.file).canonicalize()?;
+    if !candidate.starts_with(static_root.canonicalize()?) {
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, "outside static root"));
+    }
+    std::fs::read(candidate)
+}
+
+pub fn save(Query(params): Query<Download>, data: &[u8]) -> io::Result<()> {
+    let relative = Path::new(&params.file);
+    if relative.components().any(|c| !matches!(c, Component::Normal(_))) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "unexpected path component"));
+    }
+    std::fs::write(PathBuf::from("/srv/uploads").join(relative), data)
+}
+
+pub fn avatar(Query(params): Query<Download>) -> io::Result<PathBuf> {
+    // Only the final component survives, so "../" segments are dropped.
+    let name = Path::new(&params.file)
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing file name"))?;
+    Ok(PathBuf::from("/srv/avatars").join(name))
+}
+
+pub fn cat_from_args(base_dir: &Path) -> io::Result<String> {
+    let requested = std::env::args().nth(1).unwrap_or_default();
+    if requested.contains("..") || Path::new(&requested).is_absolute() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "path escapes base"));
+    }
+    std::fs::read_to_string(base_dir.join(requested))
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::thread;
+
+pub fn run_jobs(jobs: Vec<String>) -> usize {
+    let done = Arc::new(Mutex::new(Vec::new()));
+    let mut workers = Vec::new();
+    for job in jobs {
+        let done = Arc::clone(&done);
+        workers.push(thread::spawn(move || {
+            let size: usize = job.parse().unwrap_or(0);
+            let mut finished = done.lock().unwrap_or_else(PoisonError::into_inner);
+            finished.push(size);
+        }));
+    }
+    for worker in workers {
+        let _ = worker.join();
+    }
+    let total = done.lock().unwrap_or_else(PoisonError::into_inner).len();
+    total
+}
+
+pub fn refresh_cache(keys: Vec<String>) -> usize {
+    let cache = Arc::new(RwLock::new(HashMap::new()));
+    let writer = cache.clone();
+    let handle = thread::spawn(move || {
+        let mut map = writer.write().unwrap_or_else(PoisonError::into_inner);
+        for key in keys.into_iter().filter(|key| !key.is_empty()) {
+            map.insert(key, 0u32);
+        }
+    });
+    let _ = handle.join();
+    if cache.is_poisoned() {
+        cache.clear_poison();
+    }
+    let size = cache.read().map(|map| map.len()).unwrap_or(0);
+    size
+}
+
+pub fn tally(values: Vec<u32>) -> u32 {
+    let sum = Arc::new(Mutex::new(0u32));
+    let worker_sum = Arc::clone(&sum);
+    let handle = thread::spawn(move || {
+        let parsed: Vec<u32> = values.iter().map(|v| v * 2).collect();
+        let mut guard = worker_sum.lock().unwrap_or_else(PoisonError::into_inner);
+        *guard += parsed.iter().sum::<u32>();
+    });
+    let _ = handle.join();
+    let total = *sum.lock().unwrap_or_else(PoisonError::into_inner);
+    total
+}
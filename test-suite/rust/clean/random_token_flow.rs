@@ -0,0 +1,28 @@
+use rand::rngs::OsRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+pub struct Session {
+    pub id: u64,
+    pub csrf: String,
+}
+
+pub fn create() -> Session {
+    let raw: u64 = OsRng.next_u64();
+    let sid = format!("{raw:016x}");
+    Session { id: OsRng.next_u64(), csrf: sid }
+}
+
+pub fn mk() -> [u8; 16] {
+    let mut iv = [0u8; 16];
+    OsRng.fill_bytes(&mut iv);
+    iv
+}
+
+pub fn shuffle_deck(deck: &mut [u8]) -> u32 {
+    // Game randomness never becomes a credential.
+    let mut rng = rand::thread_rng();
+    deck.shuffle(&mut rng);
+    let jitter: u32 = rng.gen_range(0..100);
+    jitter
+}
@@ -0,0 +1,23 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::sync::{self, Arc, Mutex};
+
+struct TreeNode {
+    value: i32,
+    children: Vec<Rc<RefCell<TreeNode>>>,
+    parent: RefCell<Weak<RefCell<TreeNode>>>,
+}
+
+struct Session {
+    owner: sync::Weak<Mutex<Client>>,
+}
+
+struct Client {
+    sessions: Vec<Arc<Session>>,
+}
+
+// Immutable singly-linked list: no way to point a node back at itself.
+struct ListNode {
+    value: u32,
+    next: Option<Rc<ListNode>>,
+}
@@ -0,0 +1,29 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Inventory {
+    items: RefCell<Vec<String>>,
+}
+
+impl Inventory {
+    fn restock(&self) {
+        let empty = match self.items.try_borrow() {
+            Ok(current) => current.is_empty(),
+            Err(_) => return,
+        };
+        if empty {
+            if let Ok(mut items) = self.items.try_borrow_mut() {
+                items.push("widget".to_string());
+            }
+        }
+    }
+}
+
+fn shared_counter() {
+    let counter = Rc::new(RefCell::new(0u32));
+    let mut guard = counter.borrow_mut();
+    *guard += 1;
+    drop(guard);
+    let snapshot = counter.try_borrow().map(|value| *value).unwrap_or_default();
+    println!("{snapshot}");
+}
@@ -0,0 +1,42 @@
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+pub struct Mailbox {
+    ready: AtomicBool,
+    value: UnsafeCell<u64>,
+}
+
+unsafe impl Sync for Mailbox {}
+
+impl Mailbox {
+    pub fn publish(&self, value: u64) {
+        // SAFETY: only the single publisher writes `value`, and readers wait for the Release store below.
+        unsafe { *self.value.get() = value };
+        self.ready.store(true, Ordering::Release);
+    }
+
+    pub fn wait(&self) -> u64 {
+        while !self.ready.load(Ordering::Acquire) {
+            std::hint::spin_loop();
+        }
+        // SAFETY: the Acquire load above synchronizes with the publisher's Release store.
+        unsafe { *self.value.get() }
+    }
+}
+
+static HITS: AtomicUsize = AtomicUsize::new(0);
+static RUNNING: AtomicBool = AtomicBool::new(true);
+
+pub fn record_hit() -> usize {
+    HITS.fetch_add(1, Ordering::Relaxed)
+}
+
+pub fn stop() {
+    RUNNING.store(false, Ordering::Relaxed);
+}
+
+pub fn worker(mut tick: impl FnMut()) {
+    while RUNNING.load(Ordering::Relaxed) {
+        tick();
+    }
+}
@@ -0,0 +1,45 @@
+// Layout-dependent reinterpretation only happens between #[repr(C)] structs;
+// everything else converts field by field.
+#[repr(C)]
+pub struct RawHeader {
+    pub kind: u8,
+    pub len: u32,
+    pub flags: u16,
+}
+
+#[repr(C)]
+pub struct WireHeader {
+    pub kind: u8,
+    pub len: u32,
+    pub flags: u16,
+}
+
+pub struct Header {
+    pub kind: u8,
+    pub len: u32,
+    pub flags: u16,
+}
+
+#[repr(transparent)]
+pub struct Tagged(RawHeader);
+
+impl From<&Header> for RawHeader {
+    fn from(header: &Header) -> Self {
+        RawHeader { kind: header.kind, len: header.len, flags: header.flags }
+    }
+}
+
+pub fn view_wire(raw: &RawHeader) -> &WireHeader {
+    // SAFETY: both structs are #[repr(C)] with identical field lists.
+    unsafe { &*(raw as *const RawHeader as *const WireHeader) }
+}
+
+pub fn tagged(raw: &RawHeader) -> &Tagged {
+    let ptr: *const RawHeader = raw;
+    // SAFETY: Tagged is #[repr(transparent)] over RawHeader.
+    unsafe { &*ptr.cast::<Tagged>() }
+}
+
+pub fn header_ptr(header: &Header) -> *const Header {
+    header as *const Header
+}
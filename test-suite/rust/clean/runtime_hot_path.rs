@@ -0,0 +1,52 @@
+use std::sync::OnceLock;
+use tokio::runtime::Runtime;
+
+fn shared_runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| Runtime::new().expect("runtime"))
+}
+
+pub struct Client {
+    runtime: Runtime,
+}
+
+impl Client {
+    pub fn new() -> std::io::Result<Self> {
+        Ok(Self { runtime: Runtime::new()? })
+    }
+
+    pub fn fetch_blocking(&self, url: &str) -> usize {
+        self.runtime.block_on(fetch(url))
+    }
+}
+
+pub fn process_all(urls: &[String]) -> Vec<usize> {
+    let rt = shared_runtime();
+    urls.iter().map(|url| rt.block_on(fetch(url))).collect()
+}
+
+pub async fn fetch(url: &str) -> usize {
+    url.len()
+}
+
+#[test]
+fn fetch_counts_bytes() {
+    let rt = Runtime::new().unwrap();
+    assert_eq!(rt.block_on(fetch("abc")), 3);
+}
+
+mod tokio {
+    pub mod runtime {
+        use std::future::Future;
+
+        pub struct Runtime;
+        impl Runtime {
+            pub fn new() -> std::io::Result<Self> {
+                Ok(Runtime)
+            }
+            pub fn block_on<F: Future>(&self, _future: F) -> F::Output {
+                unimplemented!()
+            }
+        }
+    }
+}
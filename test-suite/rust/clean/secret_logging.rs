@@ -0,0 +1,35 @@
+// Log lines and panic messages describe secrets without including them.
+macro_rules! info {
+    ($($arg:tt)*) => {
+        let _ = format!($($arg)*);
+    };
+}
+
+pub struct Config {
+    pub api_key: String,
+    pub endpoint: String,
+}
+
+fn redact(value: &str) -> String {
+    format!("{}***", &value[..value.len().min(2)])
+}
+
+pub fn connect(user: &str, password: &str) -> Result<(), String> {
+    let status: Result<(), String> = Err(String::from("refused"));
+    status.expect(&format!("login failed for {user}"));
+    if password.is_empty() {
+        return Err(format!("empty password for {user}"));
+    }
+    Ok(())
+}
+
+pub fn authorize(cfg: &Config) {
+    println!("calling {} with key {}", cfg.endpoint, redact(&cfg.api_key));
+    println!("api key length {}", cfg.api_key.len());
+}
+
+pub fn refresh(access_token: &str, token_count: usize) {
+    info!("refreshed {} tokens", token_count);
+    let header = format!("Bearer {access_token}");
+    let _ = header;
+}
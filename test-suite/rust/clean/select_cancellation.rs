@@ -0,0 +1,40 @@
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{sleep, Duration};
+
+async fn read_frames(mut stream: TcpStream, mut shutdown: oneshot::Receiver<()>) {
+    let mut buf = Vec::with_capacity(8);
+    loop {
+        tokio::select! {
+            // read_buf is cancellation-safe: bytes already read stay in `buf`.
+            res = stream.read_buf(&mut buf) => {
+                if matches!(res, Ok(0) | Err(_)) {
+                    break;
+                }
+            }
+            _ = &mut shutdown => break,
+        }
+    }
+}
+
+async fn drain(mut rx: mpsc::Receiver<u32>) {
+    loop {
+        tokio::select! {
+            Some(value) = rx.recv() => println!("{value}"),
+            _ = sleep(Duration::from_secs(1)) => break,
+            else => break,
+        }
+    }
+}
+
+async fn forward(tx: mpsc::Sender<Vec<u8>>, payload: Vec<u8>) {
+    tokio::select! {
+        permit = tx.reserve() => {
+            if let Ok(permit) = permit {
+                permit.send(payload);
+            }
+        }
+        _ = sleep(Duration::from_millis(50)) => {}
+    }
+}
@@ -0,0 +1,33 @@
+// External payloads are deserialized with errors propagated; only embedded
+// constants are unwrapped.
+use std::fs;
+
+mod serde_json {
+    pub fn from_str<T: Default>(_s: &str) -> Result<T, String> {
+        Ok(T::default())
+    }
+    pub fn from_slice<T: Default>(_b: &[u8]) -> Result<T, String> {
+        Ok(T::default())
+    }
+}
+
+#[derive(Default)]
+pub struct Order {
+    pub id: u64,
+}
+
+const DEFAULT_ORDER: &str = "{\"id\": 0}";
+
+pub fn create_order(body: &[u8]) -> Result<u64, String> {
+    let order: Order = serde_json::from_slice(body)?;
+    Ok(order.id)
+}
+
+pub fn load_settings(path: &str) -> Result<Order, String> {
+    let raw = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&raw).map_err(|e| format!("{path}: {e}"))
+}
+
+pub fn default_order() -> Order {
+    serde_json::from_str(DEFAULT_ORDER).unwrap()
+}
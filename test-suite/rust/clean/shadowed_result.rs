@@ -0,0 +1,33 @@
+// Shadowing that consumes the earlier Result is the idiomatic unwrap-in-place.
+use std::fs;
+
+fn save(name: &str) -> Result<(), String> {
+    fs::write("config.toml", name).map_err(|e| e.to_string())
+}
+
+pub fn reload(path: &str) -> String {
+    let contents = fs::read_to_string(path);
+    let contents = contents.unwrap_or_default();
+    contents
+}
+
+pub fn persist(name: &str) -> Result<u32, String> {
+    let status = save(name);
+    if let Err(e) = &status {
+        eprintln!("save failed: {e}");
+    }
+    let status = 0;
+    Ok(status)
+}
+
+pub fn port(raw: &str) -> u16 {
+    let port = raw.parse::<u16>().unwrap_or(8080);
+    let port = port.max(1024);
+    port
+}
+
+pub fn name(raw: &str) -> String {
+    let name = raw.trim();
+    let name = name.to_lowercase();
+    name
+}
@@ -0,0 +1,42 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static TERMINATE: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn on_sigterm(_signum: i32) {
+    TERMINATE.store(true, Ordering::SeqCst);
+    let msg = b"terminating\n";
+    // SAFETY: write(2) is async-signal-safe and `msg` is a static byte string.
+    unsafe {
+        libc::write(2, msg.as_ptr().cast(), msg.len());
+    }
+}
+
+pub fn install() {
+    // SAFETY: `on_sigterm` only touches an atomic and calls async-signal-safe write(2).
+    unsafe {
+        libc::signal(libc::SIGTERM, on_sigterm as libc::sighandler_t);
+    }
+}
+
+pub fn run() {
+    while !TERMINATE.load(Ordering::SeqCst) {
+        println!("working");
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    println!("shutting down cleanly");
+}
+
+mod libc {
+    pub type sighandler_t = usize;
+    pub const SIGTERM: i32 = 15;
+    /// # Safety
+    /// Stand-in for libc::signal; callers must pass a valid handler.
+    pub unsafe fn signal(_signum: i32, _handler: sighandler_t) -> sighandler_t {
+        0
+    }
+    /// # Safety
+    /// Stand-in for libc::write; `_buf` must be readable for `count` bytes.
+    pub unsafe fn write(_fd: i32, _buf: *const u8, count: usize) -> isize {
+        count as isize
+    }
+}
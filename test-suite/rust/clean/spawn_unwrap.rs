@@ -0,0 +1,35 @@
+// Spawned threads and tasks return their errors so the caller can inspect the JoinHandle.
+use std::fs;
+use std::io;
+use std::num::ParseIntError;
+use std::thread;
+
+pub fn count_lines(paths: Vec<String>) -> io::Result<usize> {
+    let worker = thread::spawn(move || -> io::Result<usize> {
+        let mut total = 0;
+        for path in paths {
+            total += fs::read_to_string(&path)?.lines().count();
+        }
+        Ok(total)
+    });
+    match worker.join() {
+        Ok(result) => result,
+        Err(_) => Err(io::Error::new(io::ErrorKind::Other, "line counter panicked")),
+    }
+}
+
+pub async fn fetch_port(raw: String) -> Option<u16> {
+    let task = tokio::spawn(async move { raw.trim().parse::<u16>() });
+    match task.await {
+        Ok(Ok(port)) => Some(port),
+        Ok(Err(err)) => {
+            report(&err);
+            None
+        }
+        Err(_) => None,
+    }
+}
+
+fn report(err: &ParseIntError) {
+    eprintln!("invalid port: {err}");
+}
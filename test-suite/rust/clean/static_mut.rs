@@ -0,0 +1,20 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+static REQUEST_COUNT: AtomicU64 = AtomicU64::new(0);
+static CONFIG: OnceLock<String> = OnceLock::new();
+static REGISTRY: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn record_request() {
+    REQUEST_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+fn config() -> &'static str {
+    CONFIG.get_or_init(|| std::env::var("APP_CONFIG").unwrap_or_default())
+}
+
+fn register(name: &str) {
+    if let Ok(mut registry) = REGISTRY.lock() {
+        registry.push(name.to_string());
+    }
+}
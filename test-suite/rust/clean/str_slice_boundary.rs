@@ -0,0 +1,34 @@
+pub fn preview(title: &str) -> &str {
+    match title.char_indices().nth(10) {
+        Some((idx, _)) => &title[..idx],
+        None => title,
+    }
+}
+
+pub fn truncate(name: &str, max: usize) -> &str {
+    name.get(..max).unwrap_or(name)
+}
+
+pub fn drop_last(input: String) -> String {
+    let mut chars = input.chars();
+    chars.next_back();
+    chars.as_str().to_string()
+}
+
+pub fn after_colon(line: &str) -> &str {
+    match line.find(':') {
+        Some(pos) => &line[pos..],
+        None => line,
+    }
+}
+
+pub fn ascii_code(code: &str) -> &str {
+    if !code.is_ascii() || code.len() < 3 {
+        return code;
+    }
+    &code[..3]
+}
+
+pub fn bytes_head(data: &[u8]) -> &[u8] {
+    &data[..4]
+}
@@ -0,0 +1,30 @@
+use std::fmt::Write;
+
+pub fn join_names(names: &[String]) -> String {
+    let mut out = String::with_capacity(names.iter().map(|n| n.len() + 1).sum());
+    for name in names {
+        out.push_str(name);
+        out.push(',');
+    }
+    out
+}
+
+pub fn render_rows(rows: &[(u32, String)]) -> String {
+    let mut html = String::new();
+    for (id, label) in rows {
+        let _ = write!(html, "<li id=\"{id}\">{label}</li>");
+    }
+    html
+}
+
+pub fn total(values: &[u64]) -> u64 {
+    let mut sum = 0;
+    for v in values {
+        sum = sum + v;
+    }
+    sum
+}
+
+pub fn banner(words: &[&str]) -> String {
+    words.join(" ")
+}
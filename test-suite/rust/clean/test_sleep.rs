@@ -0,0 +1,43 @@
+// Tests that wait on the work itself, or run on a paused clock.
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+mod tokio {
+    pub mod time {
+        pub fn pause() {}
+        pub async fn sleep(_d: std::time::Duration) {}
+    }
+}
+
+pub fn start_worker(tx: mpsc::Sender<u32>) -> thread::JoinHandle<()> {
+    thread::spawn(move || tx.send(42).unwrap())
+}
+
+pub fn backoff(attempt: u32) {
+    thread::sleep(Duration::from_millis(10 * u64::from(attempt)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn worker_reports_result() {
+        let (tx, rx) = mpsc::channel();
+        let handle = start_worker(tx);
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap(), 42);
+        handle.join().unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cache_expires() {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+    }
+
+    #[tokio::test]
+    async fn manual_pause() {
+        tokio::time::pause();
+        tokio::time::sleep(Duration::from_secs(60)).await;
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use native_tls::TlsConnector;
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use reqwest::{Certificate, ClientBuilder};
@@ -5,7 +7,10 @@ use reqwest::{Certificate, ClientBuilder};
 const ACCEPT_INVALID_CERTS: bool = false;
 
 fn reqwest_uses_default_tls() -> reqwest::Result<reqwest::Client> {
-    ClientBuilder::new().https_only(true).build()
+    ClientBuilder::new()
+        .https_only(true)
+        .timeout(Duration::from_secs(10))
+        .build()
 }
 
 fn reqwest_uses_private_root(ca: Certificate) -> reqwest::Result<reqwest::Client> {
@@ -13,6 +18,7 @@ fn reqwest_uses_private_root(ca: Certificate) -> reqwest::Result<reqwest::Client
         .add_root_certificate(ca)
         .danger_accept_invalid_certs(false)
         .danger_accept_invalid_hostnames(false)
+        .timeout(Duration::from_secs(10))
         .build()
 }
 
@@ -22,6 +28,7 @@ fn reqwest_uses_private_root_with_safe_constant(
     ClientBuilder::new()
         .add_root_certificate(ca)
         .danger_accept_invalid_certs(ACCEPT_INVALID_CERTS)
+        .timeout(Duration::from_secs(10))
         .build()
 }
 
@@ -29,6 +36,7 @@ fn reqwest_keeps_hostname_verification_from_local() -> reqwest::Result<reqwest::
     let accept_invalid_hostnames = false;
     ClientBuilder::new()
         .danger_accept_invalid_hostnames(accept_invalid_hostnames)
+        .timeout(Duration::from_secs(10))
         .build()
 }
 
@@ -41,3 +49,63 @@ fn openssl_keeps_peer_verification() -> Result<SslConnector, openssl::error::Err
     builder.set_verify(SslVerifyMode::PEER);
     Ok(builder.build())
 }
+
+mod pinned {
+    use std::sync::Arc;
+
+    use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+    use rustls::client::WebPkiServerVerifier;
+    use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+    use rustls::{DigitallySignedStruct, Error, SignatureScheme};
+
+    #[derive(Debug)]
+    pub struct PinnedVerifier {
+        pub inner: Arc<WebPkiServerVerifier>,
+        pub pin: Vec<u8>,
+    }
+
+    impl ServerCertVerifier for PinnedVerifier {
+        fn verify_server_cert(
+            &self,
+            end_entity: &CertificateDer<'_>,
+            intermediates: &[CertificateDer<'_>],
+            server_name: &ServerName<'_>,
+            ocsp_response: &[u8],
+            now: UnixTime,
+        ) -> Result<ServerCertVerified, Error> {
+            if end_entity.as_ref() != self.pin.as_slice() {
+                return Err(Error::General("certificate pin mismatch".into()));
+            }
+            self.inner
+                .verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, Error> {
+            self.inner.verify_tls12_signature(message, cert, dss)
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, Error> {
+            self.inner.verify_tls13_signature(message, cert, dss)
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.inner.supported_verify_schemes()
+        }
+    }
+}
+
+fn openssl_callback_keeps_preverify() -> Result<SslConnector, openssl::error::ErrorStack> {
+    let mut builder = SslConnector::builder(SslMethod::tls())?;
+    builder.set_verify_callback(SslVerifyMode::PEER, |preverify, _ctx| preverify);
+    Ok(builder.build())
+}
@@ -0,0 +1,34 @@
+// Creation and removal are single atomic calls whose errors say whether
+// the path already existed.
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::Path;
+
+pub fn write_lock(lock: &Path) -> io::Result<()> {
+    match OpenOptions::new().write(true).create_new(true).open(lock) {
+        Ok(mut file) => writeln!(file, "{}", std::process::id()),
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn remove_stale(pid_file: &str) -> io::Result<()> {
+    match fs::remove_file(pid_file) {
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+        other => other,
+    }
+}
+
+pub fn ensure_dir(dir: &Path) -> io::Result<()> {
+    if !dir.is_dir() {
+        fs::create_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+pub fn describe(path: &Path, other: &Path) -> io::Result<String> {
+    if path.exists() {
+        return fs::read_to_string(other);
+    }
+    Ok(String::new())
+}
@@ -0,0 +1,52 @@
+use std::sync::mpsc::sync_channel;
+use std::thread;
+use tokio::sync::mpsc;
+
+async fn ingest(lines: Vec<String>) {
+    // Bounded: send().await waits once 64 lines are queued.
+    let (tx, mut rx) = mpsc::channel(64);
+    let consumer = tokio::spawn(async move {
+        while let Some(line) = rx.recv().await {
+            process(line).await;
+        }
+    });
+    for line in lines {
+        if tx.send(line).await.is_err() {
+            break;
+        }
+    }
+    drop(tx);
+    if let Err(err) = consumer.await {
+        eprintln!("consumer task failed: {err}");
+    }
+}
+
+fn fan_out(count: usize) {
+    let (tx, rx) = sync_channel::<u64>(16);
+    let mut workers = Vec::new();
+    for worker in 0..4 {
+        let tx = tx.clone();
+        workers.push(thread::spawn(move || loop {
+            if tx.send(worker).is_err() {
+                break;
+            }
+        }));
+    }
+    drop(tx);
+    for value in rx.iter().take(count) {
+        println!("{value}");
+    }
+    drop(rx);
+    for handle in workers {
+        let _ = handle.join();
+    }
+}
+
+async fn shutdown_signal() {
+    // A one-shot notification through an unbounded channel never grows.
+    let (done_tx, mut done_rx) = mpsc::unbounded_channel::<()>();
+    let _ = done_tx.send(());
+    let _ = done_rx.recv().await;
+}
+
+async fn process(_line: String) {}
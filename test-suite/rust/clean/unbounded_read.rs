@@ -0,0 +1,49 @@
+use std::fs::File;
+use std::io::Read;
+use std::net::{TcpListener, TcpStream};
+
+const MAX_PAYLOAD: u64 = 64 * 1024;
+
+pub fn handle_client(stream: TcpStream) -> std::io::Result<Vec<u8>> {
+    let mut payload = Vec::new();
+    stream.take(MAX_PAYLOAD).read_to_end(&mut payload)?;
+    Ok(payload)
+}
+
+pub fn serve(listener: &TcpListener) -> std::io::Result<String> {
+    let (conn, _) = listener.accept()?;
+    let mut limited = conn.take(MAX_PAYLOAD);
+    let mut request = String::new();
+    limited.read_to_string(&mut request)?;
+    Ok(request)
+}
+
+pub fn fetch(addr: &str) -> std::io::Result<Vec<u8>> {
+    let upstream = TcpStream::connect(addr)?;
+    let mut reply = Vec::new();
+    upstream.take(MAX_PAYLOAD).read_to_end(&mut reply)?;
+    Ok(reply)
+}
+
+pub fn load_config(path: &str) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut text = String::new();
+    file.read_to_string(&mut text)?;
+    Ok(text)
+}
+
+pub fn read_upload<R: Read>(req: &mut tiny::Request<R>) -> std::io::Result<String> {
+    let mut body = String::new();
+    req.as_reader().take(MAX_PAYLOAD).read_to_string(&mut body)?;
+    Ok(body)
+}
+
+pub mod tiny {
+    pub struct Request<R>(pub R);
+
+    impl<R: std::io::Read> Request<R> {
+        pub fn as_reader(&mut self) -> &mut R {
+            &mut self.0
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+
+pub struct Meters(pub f64);
+
+impl fmt::Display for Meters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}m", self.0)
+    }
+}
+
+pub fn countdown(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    countdown(n - 1)
+}
+
+pub enum Json {
+    Null,
+    Array(Vec<Json>),
+}
+
+const MAX_DEPTH: usize = 64;
+
+pub fn nesting(value: &Json, depth: usize) -> Option<usize> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    match value {
+        Json::Null => Some(0),
+        Json::Array(items) => {
+            let mut deepest = 0;
+            for item in items {
+                deepest = deepest.max(nesting(item, depth + 1)?);
+            }
+            Some(deepest + 1)
+        }
+    }
+}
+
+pub fn count_parens(input: &[u8]) -> usize {
+    input.iter().filter(|byte| **byte == b'(').count()
+}
+
+pub struct Tree {
+    pub children: Vec<Tree>,
+}
+
+impl Tree {
+    pub fn size(&self) -> usize {
+        1 + self.children.iter().map(Tree::size).sum::<usize>()
+    }
+}
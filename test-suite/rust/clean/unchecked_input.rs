@@ -0,0 +1,22 @@
+use std::io::Read;
+
+pub fn nth_byte(data: &[u8], index: usize) -> Option<u8> {
+    data.get(index).copied()
+}
+
+pub fn nth_byte_fast(data: &[u8], index: usize) -> u8 {
+    assert!(index < data.len());
+    // SAFETY: `index < data.len()` was asserted above.
+    unsafe { *data.get_unchecked(index) }
+}
+
+pub fn read_name(stream: &mut impl Read) -> Option<String> {
+    let mut buf = vec![0u8; 64];
+    let n = stream.read(&mut buf).ok()?;
+    buf.truncate(n);
+    String::from_utf8(buf).ok()
+}
+
+pub fn header(line: &str, split: usize) -> Option<&str> {
+    line.get(..split)
+}
@@ -0,0 +1,46 @@
+// Guards bound to a named `_guard` live until the end of the enclosing scope.
+use std::cell::RefCell;
+use std::sync::{Mutex, RwLock};
+
+mod tracing {
+    pub struct Span;
+    pub struct Entered;
+    impl Span {
+        pub fn enter(&self) -> Entered {
+            Entered
+        }
+    }
+}
+
+pub struct Ledger {
+    balance: Mutex<i64>,
+    index: RwLock<Vec<u32>>,
+    cache: RefCell<Vec<u8>>,
+}
+
+pub fn deposit(ledger: &Ledger, amount: i64) -> Result<(), String> {
+    let mut balance = ledger.balance.lock().map_err(|e| e.to_string())?;
+    *balance += amount;
+    Ok(())
+}
+
+pub fn reindex(ledger: &Ledger) {
+    let mut index = ledger.index.write().unwrap();
+    index.sort();
+}
+
+pub fn trace_request(span: &tracing::Span) {
+    let _entered = span.enter();
+    handle();
+}
+
+pub fn touch_cache(ledger: &Ledger) {
+    let _borrow = ledger.cache.borrow_mut();
+    handle();
+}
+
+pub fn read_config(path: &str) {
+    let _ = std::fs::read(path);
+}
+
+fn handle() {}
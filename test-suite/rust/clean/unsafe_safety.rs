@@ -0,0 +1,41 @@
+pub struct RawBuffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl RawBuffer {
+    pub fn first(&self) -> Option<u8> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: `ptr` is valid for `len` bytes and we checked `len > 0`.
+        Some(unsafe { *self.ptr })
+    }
+
+    /// Overrides the logical length.
+    ///
+    /// # Safety
+    ///
+    /// `len` must not exceed the allocation behind `ptr`.
+    pub unsafe fn set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    pub fn fill(&mut self, byte: u8) {
+        // SAFETY: every offset in 0..len is inside the allocation.
+        unsafe {
+            std::ptr::write_bytes(self.ptr, byte, self.len);
+        }
+    }
+
+    pub fn head_or(&self, default: u8) -> u8 {
+        if self.len == 0 {
+            return default;
+        }
+        // SAFETY: `len > 0`, so `ptr` points to at least one readable byte.
+        let head = std::convert::identity(
+            unsafe { self.ptr.read() },
+        );
+        head.max(default)
+    }
+}
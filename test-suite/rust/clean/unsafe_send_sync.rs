@@ -0,0 +1,60 @@
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+pub struct SharedConfig {
+    pub names: Arc<Vec<String>>,
+}
+
+pub struct Counter {
+    hits: Mutex<std::cell::Cell<u64>>,
+}
+
+// The Cell is only reachable through the Mutex guard.
+unsafe impl Sync for Counter {}
+
+impl Counter {
+    pub fn hit(&self) {
+        let hits = self.hits.lock().unwrap();
+        hits.set(hits.get() + 1);
+    }
+}
+
+pub struct SpinCell<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// Access to `value` is serialized by the `locked` flag.
+unsafe impl<T: Send> Sync for SpinCell<T> {}
+
+impl<T> SpinCell<T> {
+    pub fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        while self.locked.swap(true, Ordering::Acquire) {
+            std::hint::spin_loop();
+        }
+        // SAFETY: the `locked` flag acquired above gives this thread exclusive access.
+        let result = f(unsafe { &mut *self.value.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
+
+pub struct Mapping {
+    ptr: *mut u8,
+    len: usize,
+}
+
+// SAFETY: `Mapping` owns the region exclusively; the pointer is never
+// aliased and is only freed in `Drop`, so moving it across threads is sound.
+unsafe impl Send for Mapping {}
+
+impl Mapping {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0 || self.ptr.is_null()
+    }
+}
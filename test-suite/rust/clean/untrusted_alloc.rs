@@ -0,0 +1,35 @@
+// Lengths from the wire are checked against a limit or clamped before they
+// size an allocation.
+use std::io::{self, Read};
+
+const MAX_FRAME: usize = 1 << 20;
+
+pub fn read_frame(stream: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header)?;
+    let len = u32::from_be_bytes(header) as usize;
+    if len > MAX_FRAME {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "frame too large"));
+    }
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload)?;
+    Ok(payload)
+}
+
+pub fn parse_items(args: &[String]) -> Vec<u64> {
+    let count: usize = args[1].parse().unwrap_or(0);
+    Vec::with_capacity(count.min(1024))
+}
+
+pub fn read_tag(stream: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut byte = [0u8; 1];
+    stream.read_exact(&mut byte)?;
+    let len: u8 = u8::from_be_bytes(byte);
+    let mut tag = vec![0u8; usize::from(len)];
+    stream.read_exact(&mut tag)?;
+    Ok(tag)
+}
+
+pub fn fixed(rows: usize) -> Vec<u8> {
+    vec![0u8; rows * 8]
+}
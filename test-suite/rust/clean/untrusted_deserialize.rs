@@ -0,0 +1,50 @@
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::net::TcpStream;
+
+use bincode::Options;
+use serde::Deserialize;
+
+const MAX_FRAME: u64 = 64 * 1024;
+
+#[derive(Debug, Deserialize)]
+pub struct Command {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthConfig {
+    pub jwt_issuer: String,
+    pub require_mfa: bool,
+    pub admin_roles: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThemeOptions {
+    pub accent: String,
+    pub dark_mode: bool,
+}
+
+pub fn read_command(stream: &mut TcpStream) -> serde_json::Result<Command> {
+    serde_json::from_reader(BufReader::new(stream.take(MAX_FRAME)))
+}
+
+pub fn read_frame(stream: &mut TcpStream) -> bincode::Result<Command> {
+    bincode::DefaultOptions::new()
+        .with_limit(MAX_FRAME)
+        .deserialize_from(stream)
+}
+
+pub fn read_limited(stream: TcpStream) -> Option<Command> {
+    let mut limited = stream.take(MAX_FRAME);
+    let mut buf = Vec::new();
+    limited.read_to_end(&mut buf).ok()?;
+    bincode::deserialize(&buf).ok()
+}
+
+pub fn load_auth(path: &str) -> Option<AuthConfig> {
+    let file = File::open(path).ok()?;
+    serde_json::from_reader(BufReader::new(file)).ok()
+}
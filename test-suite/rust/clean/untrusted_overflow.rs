@@ -0,0 +1,28 @@
+pub fn total_size(header: &str, count_field: &str) -> Option<u32> {
+    let item_size: u32 = header.trim().parse().ok()?;
+    let count = count_field.parse::<u32>().ok()?;
+    item_size.checked_mul(count)
+}
+
+pub fn frame_len(bytes: [u8; 2]) -> u16 {
+    let declared = u16::from_be_bytes(bytes);
+    declared.saturating_add(4)
+}
+
+pub fn last_index(items: &[u8]) -> Option<usize> {
+    let len = items.len();
+    len.checked_sub(1)
+}
+
+pub fn bounded(value: &str) -> u64 {
+    let requested: u64 = value.parse().unwrap_or(0);
+    if requested > 1_000 {
+        return 0;
+    }
+    requested * 2
+}
+
+pub fn scale(ratio: &str) -> f64 {
+    let factor: f64 = ratio.parse().unwrap_or(1.0);
+    factor * 2.0
+}
@@ -0,0 +1,27 @@
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+#[must_use]
+fn normalized(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+macro_rules! bump {
+    ($counter:expr) => {
+        $counter = $counter.wrapping_add(1);
+    };
+}
+
+pub fn tidy(names: &mut Vec<String>, label: &mut String, mut total: u32) -> u32 {
+    names.iter_mut().for_each(|name| name.make_ascii_lowercase());
+    *label = label.trim().to_uppercase();
+    let key = normalized(label);
+    names.push(key);
+    bump!(total);
+    total
+}
+
+pub fn touch(state: &Mutex<Vec<u8>>, cache: &RefCell<Vec<u8>>) {
+    state.lock().unwrap().push(1);
+    cache.borrow_mut().clear();
+}
@@ -0,0 +1,28 @@
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::password_hash::{PasswordHasher, SaltString};
+use argon2::Argon2;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+pub fn hash_password(password: &str, salt: &SaltString) -> Option<String> {
+    Argon2::default()
+        .hash_password(password.as_bytes(), salt)
+        .ok()
+        .map(|hash| hash.to_string())
+}
+
+pub fn sign_request(secret: &[u8], body: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("any key length");
+    mac.update(body);
+    mac.finalize().into_bytes().to_vec()
+}
+
+pub fn seal_api_token(key: &[u8; 32], nonce: &[u8; 12], token: &[u8]) -> Option<Vec<u8>> {
+    let cipher = Aes256Gcm::new(key.into());
+    cipher.encrypt(Nonce::from_slice(nonce), token).ok()
+}
+
+pub fn cache_key(url: &str) -> String {
+    format!("{:x}", Sha256::digest(url.as_bytes()))
+}
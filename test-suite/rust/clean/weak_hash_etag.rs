@@ -0,0 +1,12 @@
+use md5::{Digest, Md5};
+
+pub struct Response {
+    pub auth: String,
+    pub etag: String,
+}
+
+pub fn respond(session_token: &str, body: &[u8]) -> Response {
+    let auth = format!("Bearer {session_token}");
+    let etag = format!("\"{:x}\"", Md5::digest(body));
+    Response { auth, etag }
+}
@@ -0,0 +1,29 @@
+// Parallel data is length-checked before zipping; zips against ranges,
+// repeats, and offsets of the same slice are intentional.
+pub fn weighted_sum(values: &[f64], weights: &[f64]) -> f64 {
+    assert_eq!(values.len(), weights.len(), "one weight per value");
+    values.iter().zip(weights.iter()).map(|(v, w)| v * w).sum()
+}
+
+pub fn results_match(actual: &[i64], expected: &[i64]) -> bool {
+    if actual.len() != expected.len() {
+        return false;
+    }
+    actual.iter().zip(expected).all(|(got, want)| got == want)
+}
+
+pub fn deltas(samples: &[i64]) -> Vec<i64> {
+    samples.iter().zip(samples.iter().skip(1)).map(|(a, b)| b - a).collect()
+}
+
+pub fn numbered(lines: &[String]) -> Vec<String> {
+    lines.iter().zip(1..).map(|(line, n)| format!("{n}: {line}")).collect()
+}
+
+pub fn padded(cells: &[String]) -> Vec<(String, char)> {
+    cells.iter().cloned().zip(std::iter::repeat(' ')).collect()
+}
+
+pub fn dot(a: &[f32; 4], b: &[f32; 4]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
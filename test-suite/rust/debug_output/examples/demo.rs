@@ -0,0 +1,3 @@
+fn main() {
+    println!("demo: {}", ubs_debug_output::summarize(&[1, 2, 3]));
+}
@@ -0,0 +1,28 @@
+// Library code that prints is the bug; the binary, example, build script,
+// and tests below are allowed to write to the terminal.
+mod report;
+
+pub use report::summarize;
+
+pub fn checksum(data: &[u8]) -> u32 {
+    let total = data.iter().map(|b| u32::from(*b)).sum::<u32>();
+    dbg!(total);
+    println!("checksum of {} bytes", data.len());
+    total
+}
+
+pub fn trace_enabled(data: &[u8]) -> usize {
+    if cfg!(debug_assertions) {
+        eprintln!("debug build: {} bytes", data.len());
+    }
+    data.len()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn checksum_sums_bytes() {
+        println!("running checksum test");
+        assert_eq!(super::checksum(&[1, 2]), 3);
+    }
+}
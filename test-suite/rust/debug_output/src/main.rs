@@ -0,0 +1,4 @@
+fn main() {
+    let total = ubs_debug_output::checksum(b"abc");
+    println!("{total}");
+}
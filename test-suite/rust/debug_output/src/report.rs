@@ -0,0 +1,5 @@
+pub fn summarize(values: &[u32]) -> u32 {
+    let max = values.iter().copied().max().unwrap_or(0);
+    eprintln!("max value {max}");
+    max
+}
@@ -0,0 +1,4 @@
+#[test]
+fn smoke() {
+    println!("smoke: {}", ubs_debug_output::checksum(b"x"));
+}
@@ -0,0 +1,29 @@
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::panic;
+
+#[no_mangle]
+pub unsafe extern "C" fn parse_port(raw: *const c_char) -> c_int {
+    let text = CStr::from_ptr(raw).to_str().unwrap();
+    text.parse::<c_int>().expect("port must be numeric")
+}
+
+#[no_mangle]
+pub extern "C" fn checksum(data: *const u8, len: usize) -> u8 {
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+    assert!(len > 0, "empty buffer");
+    bytes[0] ^ bytes[len - 1]
+}
+
+// Lets a Rust panic unwind straight into the C caller.
+#[no_mangle]
+pub extern "C-unwind" fn run_callback(cb: extern "C-unwind" fn()) {
+    cb();
+}
+
+// catch_unwind never runs: the release profile sets panic = "abort".
+#[no_mangle]
+pub extern "C" fn guarded_parse(raw: *const c_char) -> c_int {
+    let result = panic::catch_unwind(|| unsafe { parse_port(raw) });
+    result.unwrap_or(-1)
+}
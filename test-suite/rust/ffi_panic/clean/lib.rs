@@ -0,0 +1,42 @@
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::panic;
+
+fn parse(text: &str) -> Option<c_int> {
+    text.parse::<c_int>().ok()
+}
+
+/// # Safety
+/// `raw` must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn parse_port(raw: *const c_char) -> c_int {
+    if raw.is_null() {
+        return -1;
+    }
+    // SAFETY: the caller guarantees `raw` is NUL-terminated.
+    let Ok(text) = unsafe { CStr::from_ptr(raw) }.to_str() else {
+        return -1;
+    };
+    parse(text).unwrap_or(-1)
+}
+
+#[no_mangle]
+pub extern "C" fn checksum(data: *const u8, len: usize) -> u8 {
+    if data.is_null() || len == 0 {
+        return 0;
+    }
+    // SAFETY: the caller passes a buffer of `len` readable bytes.
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+    match (bytes.first(), bytes.last()) {
+        (Some(first), Some(last)) => first ^ last,
+        _ => 0,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn run_callback(cb: extern "C" fn()) -> c_int {
+    match panic::catch_unwind(|| cb()) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
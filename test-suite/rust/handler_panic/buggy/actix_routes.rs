@@ -0,0 +1,39 @@
+use actix_web::{get, post, web, App, HttpResponse, HttpServer};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Signup {
+    email: String,
+    age: String,
+}
+
+#[get("/users/{id}")]
+async fn show_user(path: web::Path<String>) -> HttpResponse {
+    let id: u64 = path.into_inner().parse().unwrap();
+    HttpResponse::Ok().body(format!("user {id}"))
+}
+
+#[post("/signup")]
+async fn signup(form: web::Json<Signup>) -> HttpResponse {
+    let age: u8 = form.age.parse().expect("age must be a number");
+    let domain = form.email.split('@').collect::<Vec<_>>()[1].to_string();
+    HttpResponse::Ok().body(format!("{age} {domain}"))
+}
+
+async fn health(query: web::Query<Vec<(String, String)>>) -> HttpResponse {
+    let first = &query[0].1;
+    HttpResponse::Ok().body(first.clone())
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    HttpServer::new(|| {
+        App::new()
+            .service(show_user)
+            .service(signup)
+            .route("/health", web::get().to(health))
+    })
+    .bind(("127.0.0.1", 8080))?
+    .run()
+    .await
+}
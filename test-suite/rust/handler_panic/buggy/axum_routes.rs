@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+type Db = Arc<Mutex<HashMap<u64, String>>>;
+
+async fn get_item(State(db): State<Db>, Path(id): Path<u64>) -> String {
+    let items = db.lock().unwrap();
+    items.get(&id).cloned().unwrap()
+}
+
+async fn create_item(State(db): State<Db>, Json(parts): Json<Vec<String>>) -> String {
+    let name = parts[0].clone();
+    db.lock().expect("db lock").insert(1, name.clone());
+    name
+}
+
+pub fn app(db: Db) -> Router {
+    Router::new()
+        .route("/items/:id", get(get_item))
+        .route("/items", post(create_item))
+        .with_state(db)
+}
@@ -0,0 +1,50 @@
+use actix_web::{get, post, web, App, HttpResponse, HttpServer};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Signup {
+    email: String,
+    age: String,
+}
+
+#[get("/users/{id}")]
+async fn show_user(path: web::Path<String>) -> HttpResponse {
+    match path.into_inner().parse::<u64>() {
+        Ok(id) => HttpResponse::Ok().body(format!("user {id}")),
+        Err(_) => HttpResponse::BadRequest().finish(),
+    }
+}
+
+#[post("/signup")]
+async fn signup(form: web::Json<Signup>) -> HttpResponse {
+    let Ok(age) = form.age.parse::<u8>() else {
+        return HttpResponse::BadRequest().body("age must be a number");
+    };
+    let Some(domain) = form.email.split('@').nth(1) else {
+        return HttpResponse::BadRequest().body("email must contain a domain");
+    };
+    HttpResponse::Ok().body(format!("{age} {domain}"))
+}
+
+async fn health(query: web::Query<Vec<(String, String)>>) -> HttpResponse {
+    let first = query.first().map(|pair| pair.1.clone()).unwrap_or_default();
+    HttpResponse::Ok().body(first)
+}
+
+fn startup_config() -> u16 {
+    std::env::var("PORT").ok().and_then(|port| port.parse().ok()).unwrap_or(8080)
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let port = startup_config();
+    HttpServer::new(|| {
+        App::new()
+            .service(show_user)
+            .service(signup)
+            .route("/health", web::get().to(health))
+    })
+    .bind(("127.0.0.1", port))?
+    .run()
+    .await
+}
@@ -0,0 +1,28 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+type Db = Arc<Mutex<HashMap<u64, String>>>;
+
+async fn get_item(State(db): State<Db>, Path(id): Path<u64>) -> Result<String, StatusCode> {
+    let items = db.lock().unwrap_or_else(PoisonError::into_inner);
+    items.get(&id).cloned().ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn create_item(State(db): State<Db>, Json(parts): Json<Vec<String>>) -> Result<String, StatusCode> {
+    let name = parts.first().cloned().ok_or(StatusCode::BAD_REQUEST)?;
+    db.lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .insert(1, name.clone());
+    Ok(name)
+}
+
+pub fn app(db: Db) -> Router {
+    Router::new()
+        .route("/items/:id", get(get_item))
+        .route("/items", post(create_item))
+        .with_state(db)
+}
@@ -0,0 +1,36 @@
+#[derive(Debug)]
+pub enum AreaError {
+    Unsupported,
+}
+
+pub enum Shape {
+    Circle(f64),
+    Square(f64),
+    Polygon(Vec<(f64, f64)>),
+}
+
+pub fn area(shape: &Shape) -> Result<f64, AreaError> {
+    match shape {
+        Shape::Circle(r) => Ok(std::f64::consts::PI * r * r),
+        Shape::Square(side) => Ok(side * side),
+        Shape::Polygon(_) => Err(AreaError::Unsupported),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square() {
+        match area(&Shape::Square(2.0)) {
+            Ok(value) => assert!((value - 4.0).abs() < 1e-9),
+            Err(_) => panic!("square area is supported"),
+        }
+    }
+
+    #[test]
+    fn polygon() {
+        unimplemented!("polygon fixtures")
+    }
+}
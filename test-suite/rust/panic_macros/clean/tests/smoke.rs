@@ -0,0 +1,13 @@
+use ubs_panic_macros_clean::{area, Shape};
+
+#[test]
+fn circle_area() {
+    if area(&Shape::Circle(1.0)).is_err() {
+        panic!("circle area is supported");
+    }
+}
+
+#[test]
+fn pending() {
+    todo!("cover Polygon once supported")
+}
@@ -0,0 +1,9 @@
+use std::env;
+
+pub fn github_token() -> Result<String, env::VarError> {
+    env::var("GITHUB_TOKEN")
+}
+
+pub fn redact(token: &str) -> String {
+    format!("{}…", &token[..token.len().min(4)])
+}
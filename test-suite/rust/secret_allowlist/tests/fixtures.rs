@@ -0,0 +1,7 @@
+// Canned credentials for the redaction tests; allowlisted via --secret-allowlist.
+mod fixtures;
+
+#[test]
+fn redacts_known_token_shapes() {
+    assert!(ubs_secret_allowlist::redact(fixtures::tokens::GITHUB).starts_with("ghp_"));
+}
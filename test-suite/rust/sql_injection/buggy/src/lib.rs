@@ -0,0 +1,37 @@
+use diesel::prelude::*;
+use diesel::sql_query;
+use sqlx::PgPool;
+
+pub struct User {
+    pub id: i64,
+    pub name: String,
+}
+
+pub async fn find_user(pool: &PgPool, name: &str) -> sqlx::Result<Option<(i64,)>> {
+    sqlx::query_as::<_, (i64,)>(&format!("SELECT id FROM users WHERE name = '{}'", name))
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn delete_order(pool: &PgPool, order_id: &str) -> sqlx::Result<()> {
+    let sql = format!("DELETE FROM orders WHERE id = {order_id}");
+    sqlx::query(&sql).execute(pool).await?;
+    Ok(())
+}
+
+pub fn rename(conn: &rusqlite::Connection, id: i64, name: &str) -> rusqlite::Result<usize> {
+    let stmt = "UPDATE users SET name = '".to_string() + name + "' WHERE id = " + &id.to_string();
+    conn.execute(&stmt, [])
+}
+
+pub fn search(client: &mut postgres::Client, term: &str, sort: &str) -> Result<Vec<postgres::Row>, postgres::Error> {
+    let mut sql = String::from("SELECT * FROM products WHERE 1 = 1");
+    sql.push_str(&format!(" AND title LIKE '%{}%'", term));
+    sql.push_str(" ORDER BY ");
+    sql.push_str(sort);
+    client.query(sql.as_str(), &[])
+}
+
+pub fn purge(conn: &mut PgConnection, tenant: &str) -> QueryResult<usize> {
+    sql_query(format!("DELETE FROM sessions WHERE tenant = '{}'", tenant)).execute(conn)
+}
@@ -0,0 +1,36 @@
+use diesel::prelude::*;
+use diesel::sql_query;
+use diesel::sql_types::Text;
+use sqlx::PgPool;
+
+const USERS_TABLE: &str = "users";
+
+pub async fn find_user(pool: &PgPool, name: &str) -> sqlx::Result<Option<(i64,)>> {
+    sqlx::query_as::<_, (i64,)>("SELECT id FROM users WHERE name = $1")
+        .bind(name)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn count_users(pool: &PgPool) -> sqlx::Result<(i64,)> {
+    // Only a compile-time constant is spliced in; the value is still bound.
+    let sql = format!("SELECT count(*) FROM {} WHERE active = $1", USERS_TABLE);
+    sqlx::query_as(&sql).bind(true).fetch_one(pool).await
+}
+
+pub fn rename(conn: &rusqlite::Connection, id: i64, name: &str) -> rusqlite::Result<usize> {
+    conn.execute("UPDATE users SET name = ?1 WHERE id = ?2", rusqlite::params![name, id])
+}
+
+pub fn search(client: &mut postgres::Client, term: &str) -> Result<Vec<postgres::Row>, postgres::Error> {
+    let mut sql = String::from("SELECT * FROM products WHERE 1 = 1");
+    sql.push_str(" AND title LIKE $1");
+    sql.push_str(" ORDER BY title");
+    client.query(sql.as_str(), &[&format!("%{term}%")])
+}
+
+pub fn purge(conn: &mut PgConnection, tenant: &str) -> QueryResult<usize> {
+    sql_query("DELETE FROM sessions WHERE tenant = $1")
+        .bind::<Text, _>(tenant)
+        .execute(conn)
+}
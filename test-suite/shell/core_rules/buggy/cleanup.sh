@@ -0,0 +1,9 @@
+#!/bin/sh
+# Nightly cache cleanup for CI runners.
+
+CACHE_ROOT="$RUNNER_CACHE"
+rm -rf "$CACHE_ROOT/"
+
+for dir in $(ls $CACHE_ROOT); do
+  echo "pruned $dir"
+done
@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+# Removes per-job scratch space after a CI run.
+set -euo pipefail
+
+require_workspace() {
+  [ -z "$WORKSPACE" ] && exit 1
+}
+
+prune_workspace() {
+  # The guard lives in another function that may never have run.
+  rm -rf "$WORKSPACE"
+}
+
+prune_scratch() {
+  if [ -z "$scratch" ]; then
+    echo "scratch is not set" >&2
+  fi
+  # The test above only logs; nothing stops an empty $scratch.
+  rm -rf "$scratch"
+}
+
+prune_logs() {
+  rm -rf "$log_dir"
+  [ -z "$log_dir" ] && return 1
+}
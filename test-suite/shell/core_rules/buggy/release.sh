@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+# Packages a release tarball and publishes it to the artifact bucket.
+
+VERSION=$1
+BUILD_DIR=$2
+ARTIFACT_DIR="dist/$VERSION"
+
+# BUILD_DIR is unchecked: an empty argument turns this into rm -rf /*
+rm -rf $BUILD_DIR/*
+mkdir -p $ARTIFACT_DIR
+
+for src in $SOURCES; do
+  cp $src "$ARTIFACT_DIR/"
+done
+
+if [ $VERSION = "latest" ]; then
+  echo "refusing to publish latest"
+  exit 1
+fi
+
+tar -czf "$ARTIFACT_DIR.tar.gz" -C dist $VERSION
+
+# PUBLISH_CMD comes from the CI environment and is run verbatim
+eval "$PUBLISH_CMD $ARTIFACT_DIR.tar.gz"
+bash -c "notify --channel releases $VERSION"
@@ -0,0 +1,15 @@
+#!/bin/sh
+# Nightly cache cleanup for CI runners.
+set -eu
+
+CACHE_ROOT="${RUNNER_CACHE:-}"
+if [ -z "$CACHE_ROOT" ]; then
+  echo "RUNNER_CACHE is not set" >&2
+  exit 1
+fi
+rm -rf "$CACHE_ROOT/"
+
+for dir in "$CACHE_ROOT"/*; do
+  [ -e "$dir" ] || continue
+  echo "pruned $dir"
+done
@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+# Removes per-job scratch space after a CI run.
+set -euo pipefail
+
+prune_workspace() {
+  local workspace="${1:?usage: prune_workspace DIR}"
+  rm -rf "$workspace"
+}
+
+prune_scratch() {
+  local scratch="${2:-}"
+  if [ -z "$scratch" ]; then
+    echo "scratch is not set" >&2
+    return 1
+  fi
+  rm -rf "$scratch"
+}
+
+prune_cache() {
+  local cache="${CACHE_DIR:-}"
+  [ -d "$cache" ] && rm -rf "$cache"
+  if [ -n "${LOG_DIR:-}" ]; then
+    rm -rf "$LOG_DIR"
+  fi
+}
@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+# Packages a release tarball and publishes it to the artifact bucket.
+set -euo pipefail
+
+VERSION="${1:?usage: release.sh VERSION BUILD_DIR}"
+BUILD_DIR="${2:?usage: release.sh VERSION BUILD_DIR}"
+ARTIFACT_DIR="dist/$VERSION"
+SOURCES=(README.md LICENSE bin/app)
+
+rm -rf "${BUILD_DIR:?}/"*
+mkdir -p "$ARTIFACT_DIR"
+
+for src in "${SOURCES[@]}"; do
+  cp "$src" "$ARTIFACT_DIR/"
+done
+
+if [[ "$VERSION" == "latest" ]]; then
+  echo "refusing to publish latest"
+  exit 1
+fi
+
+tar -czf "$ARTIFACT_DIR.tar.gz" -C dist "$VERSION"
+
+publish_cmd=(aws s3 cp "$ARTIFACT_DIR.tar.gz" "s3://releases/$VERSION/")
+"${publish_cmd[@]}"
+notify --channel releases "$VERSION"